@@ -8,7 +8,7 @@ use thiserror::Error;
 
 // 定义一个名为 StudentIntroError 的枚举，用于表示可能发生的错误类型。
 // 这个枚举会被用于智能合约中以表达特定的错误情况。
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum StudentIntroError {
     // 表示账户尚未初始化的错误。
     // 当尝试操作一个尚未初始化的账户时会遇到这种错误。
@@ -26,6 +26,15 @@ pub enum StudentIntroError {
     InvalidDataLength,
 }
 
+impl StudentIntroError {
+    // 全部错误，按枚举定义顺序排列，用于生成 IDL 错误码。
+    pub const ALL: [StudentIntroError; 3] = [
+        StudentIntroError::UninitializedAccount,
+        StudentIntroError::InvalidPDA,
+        StudentIntroError::InvalidDataLength,
+    ];
+}
+
 // 为 StudentIntroError 实现 From trait，使其可以被转换为 ProgramError。
 // 这是将自定义错误与 Solana 程序中使用的标准错误类型 ProgramError 联系起来的关键部分。
 impl From<StudentIntroError> for ProgramError {
@@ -0,0 +1,48 @@
+// 引入 IDL 构建工具。
+// 学生介绍程序的 IDL 描述，指令判别符和错误码取自 IntroInstruction / StudentIntroError，
+// 字段顺序与 instruction.rs / state.rs 中的 Borsh 布局保持一致（由 tests/idl.rs 校验）。
+use crate::{error::StudentIntroError, instruction::IntroInstruction};
+use native_idl::{account, field, Idl, IdlType};
+
+// 构建学生介绍程序的 IDL。
+pub fn idl() -> Idl {
+    Idl::new("basic_security_validation", env!("CARGO_PKG_VERSION"))
+        // InitUserInput 指令：创建以 initializer 为种子的 PDA 并写入介绍。
+        .instruction(
+            IntroInstruction::INIT_USER_INPUT,
+            "initUserInput",
+            vec![
+                account("initializer", true, true),
+                account("userAccount", true, false),
+                account("systemProgram", false, false),
+            ],
+            vec![
+                field("name", IdlType::String),
+                field("message", IdlType::String),
+            ],
+        )
+        // UpdateStudentIntro 指令：更新已有 PDA 中的介绍内容。
+        .instruction(
+            IntroInstruction::UPDATE_STUDENT_INTRO,
+            "updateStudentIntro",
+            vec![
+                account("initializer", false, true),
+                account("userAccount", true, false),
+            ],
+            vec![
+                field("name", IdlType::String),
+                field("message", IdlType::String),
+            ],
+        )
+        // StudentInfo 账户状态。
+        .account(
+            "StudentInfo",
+            vec![
+                field("isInitialized", IdlType::Bool),
+                field("name", IdlType::String),
+                field("msg", IdlType::String),
+            ],
+        )
+        // StudentIntroError 错误码，取枚举值作为错误码。
+        .errors(StudentIntroError::ALL, |e| *e as u32)
+}
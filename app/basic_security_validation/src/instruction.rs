@@ -21,10 +21,14 @@ struct StudentIntroPayload {
 
 // 为 IntroInstruction 枚举实现 unpack 方法，用于从原始字节数据中提取指令。
 impl IntroInstruction {
+    // 指令数据首字节的判别符，IDL 中的指令判别符也取自这里。
+    pub const INIT_USER_INPUT: u8 = 0;
+    pub const UPDATE_STUDENT_INTRO: u8 = 1;
+
     // unpack 方法接收一个字节数组并尝试将其转换为 IntroInstruction 枚举的一个变量。
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
         // 尝试将输入数据的第一个字节（表示指令类型的变量）和剩余部分分开。
-        let (variant, rest) = input
+        let (&variant, rest) = input
             .split_first()
             .ok_or(ProgramError::InvalidInstructionData)?;
 
@@ -33,13 +37,13 @@ impl IntroInstruction {
 
         // 根据 variant 的值来确定指令类型，并构造相应的 IntroInstruction 枚举变量。
         Ok(match variant {
-            // 如果 variant 是 0，则表示这是一个 InitUserInput 指令。
-            0 => Self::InitUserInput {
+            // 如果 variant 是 INIT_USER_INPUT（0），则表示这是一个 InitUserInput 指令。
+            Self::INIT_USER_INPUT => Self::InitUserInput {
                 name: payload.name,
                 message: payload.message,
             },
-            // 如果 variant 是 1，则表示这是一个 UpdateStudentIntro 指令。
-            1 => Self::UpdateStudentIntro {
+            // 如果 variant 是 UPDATE_STUDENT_INTRO（1），则表示这是一个 UpdateStudentIntro 指令。
+            Self::UPDATE_STUDENT_INTRO => Self::UpdateStudentIntro {
                 name: payload.name,
                 message: payload.message,
             },
@@ -0,0 +1,69 @@
+#![cfg(feature = "idl")]
+
+use basic_security_validation::{
+    error::StudentIntroError, idl::idl, instruction::IntroInstruction, state::StudentInfo,
+};
+use native_idl::testing::Samples;
+
+// 新增 StudentIntroError 变体时这里无法编译，需同时补充 StudentIntroError::ALL
+fn error_name(e: StudentIntroError) -> &'static str {
+    match e {
+        StudentIntroError::UninitializedAccount => "UninitializedAccount",
+        StudentIntroError::InvalidPDA => "InvalidPDA",
+        StudentIntroError::InvalidDataLength => "InvalidDataLength",
+    }
+}
+
+#[test]
+fn error_codes_match_student_intro_error() {
+    let idl = idl();
+    assert_eq!(idl.errors.len(), StudentIntroError::ALL.len());
+
+    for (i, (code, e)) in idl.errors.iter().zip(StudentIntroError::ALL).enumerate() {
+        assert_eq!(
+            e as u32, i as u32,
+            "StudentIntroError::ALL 顺序与枚举定义不一致"
+        );
+        assert_eq!(code.code, e as u32);
+        assert_eq!(code.name, error_name(e));
+        assert_eq!(code.msg, e.to_string());
+    }
+}
+
+#[test]
+fn instructions_match_unpack() {
+    let idl = idl();
+    let names: Vec<_> = idl.instructions.iter().map(|ix| ix.name.as_str()).collect();
+    assert_eq!(names, ["initUserInput", "updateStudentIntro"]);
+
+    for ix in &idl.instructions {
+        let args = Samples::args(ix);
+        // 新增 IntroInstruction 变体时这里无法编译，需同时补充 IDL 指令
+        let (name, unpacked_name, message) =
+            match IntroInstruction::unpack(&args.instruction_data(ix)).unwrap() {
+                IntroInstruction::InitUserInput { name, message } => {
+                    ("initUserInput", name, message)
+                }
+                IntroInstruction::UpdateStudentIntro { name, message } => {
+                    ("updateStudentIntro", name, message)
+                }
+            };
+        assert_eq!(name, ix.name);
+        assert_eq!(unpacked_name, args.string("name"));
+        assert_eq!(message, args.string("message"));
+    }
+}
+
+#[test]
+fn account_fields_match_borsh_layout() {
+    let idl = idl();
+    assert_eq!(idl.accounts.len(), 1);
+
+    let s = Samples::account(&idl, "StudentInfo");
+    let info = StudentInfo {
+        is_initialized: s.bool("isInitialized"),
+        name: s.string("name"),
+        msg: s.string("msg"),
+    };
+    assert_eq!(borsh::to_vec(&info).unwrap(), s.encode());
+}
@@ -0,0 +1,13 @@
+use std::{env, path::PathBuf};
+
+// 生成 IDL 文件，默认输出到 crate 目录下的 idl/program_derived_address.json
+fn main() -> std::io::Result<()> {
+    let path = env::args().nth(1).map(PathBuf::from).unwrap_or_else(|| {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("idl/program_derived_address.json")
+    });
+
+    program_derived_address::idl::idl().write_to(&path)?;
+    println!("IDL written to {}", path.display());
+
+    Ok(())
+}
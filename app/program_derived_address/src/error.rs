@@ -1,7 +1,7 @@
 use solana_program::program_error::ProgramError;
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
 pub enum ReviewError {
     #[error("Account not initialized yet")]
     UninitializedAccount,
@@ -14,6 +14,36 @@ pub enum ReviewError {
 
     #[error("Rating greater than 5 or less than 1")]
     InvalidRating,
+
+    #[error("Reviewer has already reviewed this movie")]
+    ReviewAlreadyExists,
+
+    #[error("Account version is not supported")]
+    UnsupportedAccountVersion,
+
+    #[error("Arithmetic overflow")]
+    Overflow,
+
+    #[error("Voter has already voted on this review")]
+    AlreadyVoted,
+
+    #[error("Account discriminator does not match the expected account type")]
+    InvalidAccountType,
+}
+
+impl ReviewError {
+    // 全部错误，按枚举定义顺序排列，用于生成 IDL 错误码
+    pub const ALL: [ReviewError; 9] = [
+        ReviewError::UninitializedAccount,
+        ReviewError::InvalidPDA,
+        ReviewError::InvalidDataLength,
+        ReviewError::InvalidRating,
+        ReviewError::ReviewAlreadyExists,
+        ReviewError::UnsupportedAccountVersion,
+        ReviewError::Overflow,
+        ReviewError::AlreadyVoted,
+        ReviewError::InvalidAccountType,
+    ];
 }
 
 impl From<ReviewError> for ProgramError {
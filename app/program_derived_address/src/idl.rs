@@ -0,0 +1,109 @@
+// 影评程序的 IDL 描述，指令判别符和错误码取自 MovieInstruction / ReviewError，
+// 字段顺序与 instruction.rs / state.rs 中的 Borsh 布局保持一致（由 tests/idl.rs 校验）
+use crate::{error::ReviewError, instruction::MovieInstruction};
+use native_idl::{account, field, optional_account, Idl, IdlType};
+
+pub fn idl() -> Idl {
+    Idl::new("program_derived_address", env!("CARGO_PKG_VERSION"))
+        .instruction(
+            MovieInstruction::ADD_MOVIE_REVIEW,
+            "addMovieReview",
+            vec![
+                account("initializer", true, true),
+                account("pdaAccount", true, false),
+                account("pdaCounter", true, false),
+                account("systemProgram", false, false),
+            ],
+            vec![
+                field("title", IdlType::String),
+                field("rating", IdlType::U8),
+                field("description", IdlType::String),
+            ],
+        )
+        .instruction(
+            MovieInstruction::UPDATE_MOVIE_REVIEW,
+            "updateMovieReview",
+            vec![
+                account("initializer", true, true),
+                account("pdaAccount", true, false),
+                optional_account(
+                    "systemProgram",
+                    false,
+                    false,
+                    "Required only when the new description grows the account and rent must be topped up",
+                ),
+            ],
+            vec![
+                field("title", IdlType::String),
+                field("rating", IdlType::U8),
+                field("description", IdlType::String),
+            ],
+        )
+        .instruction(
+            MovieInstruction::ADD_COMMENT,
+            "addComment",
+            vec![
+                account("commenter", true, true),
+                account("pdaReview", false, false),
+                account("pdaCounter", true, false),
+                account("pdaComment", true, false),
+                account("systemProgram", false, false),
+            ],
+            vec![field("comment", IdlType::String)],
+        )
+        .instruction(
+            MovieInstruction::VOTE_REVIEW,
+            "voteReview",
+            vec![
+                account("voter", true, true),
+                account("pdaReview", true, false),
+                account("pdaVote", true, false),
+                account("systemProgram", false, false),
+            ],
+            vec![field("upvote", IdlType::Bool)],
+        )
+        .account(
+            "MovieAccountState",
+            vec![
+                field("discriminator", IdlType::String),
+                field("version", IdlType::U8),
+                field("isInitialized", IdlType::Bool),
+                field("reviewer", IdlType::PublicKey),
+                field("rating", IdlType::U8),
+                field("upvotes", IdlType::U64),
+                field("downvotes", IdlType::U64),
+                field("title", IdlType::String),
+                field("description", IdlType::String),
+            ],
+        )
+        .account(
+            "MovieCommentCounter",
+            vec![
+                field("discriminator", IdlType::String),
+                field("isInitialized", IdlType::Bool),
+                field("counter", IdlType::U64),
+            ],
+        )
+        .account(
+            "MovieComment",
+            vec![
+                field("discriminator", IdlType::String),
+                field("isInitialized", IdlType::Bool),
+                field("review", IdlType::PublicKey),
+                field("commenter", IdlType::PublicKey),
+                field("comment", IdlType::String),
+                field("count", IdlType::U64),
+            ],
+        )
+        .account(
+            "MovieVote",
+            vec![
+                field("discriminator", IdlType::String),
+                field("isInitialized", IdlType::Bool),
+                field("review", IdlType::PublicKey),
+                field("voter", IdlType::PublicKey),
+                field("upvote", IdlType::Bool),
+            ],
+        )
+        .errors(ReviewError::ALL, |e| *e as u32)
+}
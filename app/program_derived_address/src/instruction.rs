@@ -8,6 +8,9 @@ pub enum MovieInstruction {
         rating: u8,
         description: String,
     },
+    // 账户：initializer（签名）、pda_account，以及可选的 system_program。
+    // 只有新描述更长、需要 initializer 补足租金时才必须传入 system_program，
+    // 因此只传前两个账户的旧客户端仍然可用
     UpdateMovieReview {
         title: String,
         rating: u8,
@@ -16,6 +19,9 @@ pub enum MovieInstruction {
     AddComment {
         comment: String,
     },
+    VoteReview {
+        upvote: bool,
+    },
 }
 
 // 定义 MovieReviewPayload 结构体，用于解析添加和更新影评的指令数据
@@ -32,14 +38,26 @@ struct CommentPayload {
     comment: String,
 }
 
+// 定义 VotePayload 结构体，用于解析投票的指令数据
+#[derive(BorshDeserialize)]
+struct VotePayload {
+    upvote: bool,
+}
+
 impl MovieInstruction {
+    // 指令数据首字节的判别符，IDL 中的指令判别符也取自这里
+    pub const ADD_MOVIE_REVIEW: u8 = 0;
+    pub const UPDATE_MOVIE_REVIEW: u8 = 1;
+    pub const ADD_COMMENT: u8 = 2;
+    pub const VOTE_REVIEW: u8 = 3;
+
     // 解包指令数据并返回 MovieInstruction 枚举
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
         let (&variant, rest) = input
             .split_first()
             .ok_or(ProgramError::InvalidInstructionData)?;
         Ok(match variant {
-            0 => {
+            Self::ADD_MOVIE_REVIEW => {
                 let payload = MovieReviewPayload::try_from_slice(rest).unwrap();
                 Self::AddMovieReview {
                     title: payload.title,
@@ -47,7 +65,7 @@ impl MovieInstruction {
                     description: payload.description,
                 }
             }
-            1 => {
+            Self::UPDATE_MOVIE_REVIEW => {
                 let payload = MovieReviewPayload::try_from_slice(rest).unwrap();
                 Self::UpdateMovieReview {
                     title: payload.title,
@@ -55,12 +73,19 @@ impl MovieInstruction {
                     description: payload.description,
                 }
             }
-            2 => {
+            Self::ADD_COMMENT => {
                 let payload = CommentPayload::try_from_slice(rest).unwrap();
                 Self::AddComment {
                     comment: payload.comment,
                 }
             }
+            Self::VOTE_REVIEW => {
+                let payload = VotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::VoteReview {
+                    upvote: payload.upvote,
+                }
+            }
             _ => return Err(ProgramError::InvalidInstructionData),
         })
     }
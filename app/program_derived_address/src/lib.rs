@@ -1,5 +1,7 @@
 pub mod entrypoint;
 pub mod error;
+#[cfg(feature = "idl")]
+pub mod idl;
 pub mod instruction;
 pub mod processor;
 pub mod state;
@@ -1,12 +1,12 @@
 use crate::error::ReviewError;
 use crate::instruction::MovieInstruction;
-use crate::state::{MovieAccountState, MovieComment, MovieCommentCounter};
+use crate::state::{MovieAccountState, MovieComment, MovieCommentCounter, MovieVote};
 use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
     entrypoint::ProgramResult,
     msg,
-    program::invoke_signed,
+    program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_pack::IsInitialized,
     pubkey::Pubkey,
@@ -46,6 +46,7 @@ pub fn process_instruction(
         } => update_movie_review(program_id, accounts, title, rating, description),
 
         MovieInstruction::AddComment { comment } => add_comment(program_id, accounts, comment),
+        MovieInstruction::VoteReview { upvote } => vote_review(program_id, accounts, upvote),
     }
 }
 
@@ -73,11 +74,16 @@ pub fn add_movie_review(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let (pda, bump_seed) =
-        Pubkey::find_program_address(&[initializer.key.as_ref(), title.as_bytes()], program_id);
+    let (pda, bump_seed) = MovieAccountState::find_pda(initializer.key, &title, program_id);
     if pda != *pda_account.key {
         msg!("Invalid seeds for PDA");
-        return Err(ProgramError::InvalidArgument);
+        return Err(ReviewError::InvalidPDA.into());
+    }
+
+    // 每个钱包对同一部电影只能创建一条影评
+    if !pda_account.data_is_empty() || pda_account.lamports() > 0 {
+        msg!("Review already exists for this reviewer and title");
+        return Err(ReviewError::ReviewAlreadyExists.into());
     }
 
     if !(1..=5).contains(&rating) {
@@ -85,13 +91,14 @@ pub fn add_movie_review(
         return Err(ReviewError::InvalidRating.into());
     }
 
-    let account_len: usize = 1000;
-
-    if MovieAccountState::get_account_size(title.clone(), description.clone()) > account_len {
-        msg!("Data length is larger than 1000 bytes");
+    if !MovieAccountState::validate_lengths(&title, &description) {
+        msg!("Title or description exceeds max length");
         return Err(ReviewError::InvalidDataLength.into());
     }
 
+    // 按实际内容分配空间，后续更新时再按需扩容
+    let account_len = MovieAccountState::get_account_size(title.clone(), description.clone());
+
     let rent = Rent::get()?;
     let rent_lamports = rent.minimum_balance(account_len);
 
@@ -108,7 +115,11 @@ pub fn add_movie_review(
             pda_account.clone(),
             system_program.clone(),
         ],
-        &[&[initializer.key.as_ref(), title.as_bytes(), &[bump_seed]]],
+        &[&[
+            initializer.key.as_ref(),
+            &MovieAccountState::title_seed(&title),
+            &[bump_seed],
+        ]],
     )?;
 
     msg!("PDA created: {}", pda);
@@ -125,9 +136,12 @@ pub fn add_movie_review(
     }
 
     account_data.discriminator = MovieAccountState::DISCRIMINATOR.to_string();
+    account_data.version = MovieAccountState::VERSION;
     account_data.title = title;
     account_data.reviewer = *initializer.key;
     account_data.rating = rating;
+    account_data.upvotes = 0;
+    account_data.downvotes = 0;
     account_data.description = description;
     account_data.is_initialized = true;
 
@@ -194,6 +208,8 @@ pub fn update_movie_review(
 
     let initializer = next_account_info(account_info_iter)?;
     let pda_account = next_account_info(account_info_iter)?;
+    // system_program 为可选的末尾账户，只在扩容需要补足租金时使用
+    let system_program = next_account_info(account_info_iter).ok();
 
     if pda_account.owner != program_id {
         return Err(ProgramError::IllegalOwner);
@@ -209,10 +225,8 @@ pub fn update_movie_review(
         my_try_from_slice_unchecked::<MovieAccountState>(&pda_account.data.borrow()).unwrap();
     msg!("review title: {}", account_data.title);
 
-    let (pda, _bump_seed) = Pubkey::find_program_address(
-        &[initializer.key.as_ref(), account_data.title.as_bytes()],
-        program_id,
-    );
+    let (pda, _bump_seed) =
+        MovieAccountState::find_pda(initializer.key, &account_data.title, program_id);
     if pda != *pda_account.key {
         msg!("Invalid seeds for PDA");
         return Err(ReviewError::InvalidPDA.into());
@@ -224,16 +238,51 @@ pub fn update_movie_review(
         return Err(ReviewError::UninitializedAccount.into());
     }
 
+    if account_data.version != MovieAccountState::VERSION {
+        msg!("Unsupported account version: {}", account_data.version);
+        return Err(ReviewError::UnsupportedAccountVersion.into());
+    }
+
     if !(1..=5).contains(&rating) {
         msg!("Invalid Rating");
         return Err(ReviewError::InvalidRating.into());
     }
 
-    let update_len = MovieAccountState::get_account_size(title, description.clone());
-    if update_len > 1000 {
-        msg!("Data length is larger than 1000 bytes");
+    if !MovieAccountState::validate_lengths(&account_data.title, &description) {
+        msg!("Title or description exceeds max length");
         return Err(ReviewError::InvalidDataLength.into());
     }
+    if title != account_data.title {
+        msg!("Title cannot be changed, ignoring: {}", title);
+    }
+
+    // 新描述更长时扩容账户，由 initializer 补足租金差额
+    let update_len =
+        MovieAccountState::get_account_size(account_data.title.clone(), description.clone());
+    if update_len > pda_account.data_len() {
+        let rent = Rent::get()?;
+        let required_lamports = rent.minimum_balance(update_len);
+        let lamports_diff = required_lamports.saturating_sub(pda_account.lamports());
+
+        if lamports_diff > 0 {
+            let system_program = system_program.ok_or_else(|| {
+                msg!("System program account is required to fund the rent delta");
+                ProgramError::NotEnoughAccountKeys
+            })?;
+            msg!("Funding rent delta: {} lamports", lamports_diff);
+            invoke(
+                &system_instruction::transfer(initializer.key, pda_account.key, lamports_diff),
+                &[
+                    initializer.clone(),
+                    pda_account.clone(),
+                    system_program.clone(),
+                ],
+            )?;
+        }
+
+        msg!("Reallocating account to {} bytes", update_len);
+        pda_account.realloc(update_len, false)?;
+    }
 
     msg!("Review before update:");
     msg!("Title: {}", account_data.title);
@@ -274,6 +323,11 @@ pub fn add_comment(
     let mut counter_data =
         my_try_from_slice_unchecked::<MovieCommentCounter>(&pda_counter.data.borrow()).unwrap();
 
+    if comment.len() > MovieComment::MAX_COMMENT_LEN {
+        msg!("Comment exceeds max length");
+        return Err(ReviewError::InvalidDataLength.into());
+    }
+
     let account_len = MovieComment::get_account_size(comment.clone());
 
     let rent = Rent::get()?;
@@ -330,8 +384,114 @@ pub fn add_comment(
     comment_data.serialize(&mut &mut pda_comment.data.borrow_mut()[..])?;
 
     msg!("Comment Count: {}", counter_data.counter);
-    counter_data.counter += 1;
+    counter_data.counter = counter_data
+        .counter
+        .checked_add(1)
+        .ok_or(ReviewError::Overflow)?;
     counter_data.serialize(&mut &mut pda_counter.data.borrow_mut()[..])?;
 
     Ok(())
 }
+
+pub fn vote_review(program_id: &Pubkey, accounts: &[AccountInfo], upvote: bool) -> ProgramResult {
+    msg!("Voting on review...");
+    msg!("Upvote: {}", upvote);
+
+    let account_info_iter = &mut accounts.iter();
+
+    let voter = next_account_info(account_info_iter)?;
+    let pda_review = next_account_info(account_info_iter)?;
+    let pda_vote = next_account_info(account_info_iter)?;
+    let system_program = next_account_info(account_info_iter)?;
+
+    if !voter.is_signer {
+        msg!("Missing required signature");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if pda_review.owner != program_id {
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    let mut review_data =
+        my_try_from_slice_unchecked::<MovieAccountState>(&pda_review.data.borrow())?;
+    // 只允许对影评账户投票，布局兼容的其他程序账户在这里被拒绝
+    if review_data.discriminator != MovieAccountState::DISCRIMINATOR {
+        msg!(
+            "Account is not a movie review: {}",
+            review_data.discriminator
+        );
+        return Err(ReviewError::InvalidAccountType.into());
+    }
+    if !review_data.is_initialized() {
+        msg!("Review account is not initialized");
+        return Err(ReviewError::UninitializedAccount.into());
+    }
+    if review_data.version != MovieAccountState::VERSION {
+        msg!("Unsupported account version: {}", review_data.version);
+        return Err(ReviewError::UnsupportedAccountVersion.into());
+    }
+
+    let (pda, bump_seed) = MovieVote::find_pda(pda_review.key, voter.key, program_id);
+    if pda != *pda_vote.key {
+        msg!("Invalid seeds for PDA");
+        return Err(ReviewError::InvalidPDA.into());
+    }
+
+    // 投票 PDA 已存在说明该钱包已经投过票
+    if !pda_vote.data_is_empty() || pda_vote.lamports() > 0 {
+        msg!("Voter has already voted on this review");
+        return Err(ReviewError::AlreadyVoted.into());
+    }
+
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(MovieVote::SIZE);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            voter.key,
+            pda_vote.key,
+            rent_lamports,
+            MovieVote::SIZE.try_into().unwrap(),
+            program_id,
+        ),
+        &[voter.clone(), pda_vote.clone(), system_program.clone()],
+        &[&[
+            pda_review.key.as_ref(),
+            voter.key.as_ref(),
+            MovieVote::DISCRIMINATOR.as_bytes(),
+            &[bump_seed],
+        ]],
+    )?;
+
+    msg!("Created Vote Account");
+
+    let mut vote_data = my_try_from_slice_unchecked::<MovieVote>(&pda_vote.data.borrow())?;
+    vote_data.discriminator = MovieVote::DISCRIMINATOR.to_string();
+    vote_data.review = *pda_review.key;
+    vote_data.voter = *voter.key;
+    vote_data.upvote = upvote;
+    vote_data.is_initialized = true;
+    vote_data.serialize(&mut &mut pda_vote.data.borrow_mut()[..])?;
+
+    if upvote {
+        review_data.upvotes = review_data
+            .upvotes
+            .checked_add(1)
+            .ok_or(ReviewError::Overflow)?;
+    } else {
+        review_data.downvotes = review_data
+            .downvotes
+            .checked_add(1)
+            .ok_or(ReviewError::Overflow)?;
+    }
+
+    msg!(
+        "Votes: {} up / {} down",
+        review_data.upvotes,
+        review_data.downvotes
+    );
+    review_data.serialize(&mut &mut pda_review.data.borrow_mut()[..])?;
+
+    Ok(())
+}
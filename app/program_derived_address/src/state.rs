@@ -1,6 +1,7 @@
 // 导入所需的库和模块
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
+    hash::hash,
     program_pack::{IsInitialized, Sealed},
     pubkey::Pubkey,
 };
@@ -9,9 +10,12 @@ use solana_program::{
 #[derive(BorshSerialize, BorshDeserialize)]
 pub struct MovieAccountState {
     pub discriminator: String, // 鉴别器字段，用于区分不同类型的账户
+    pub version: u8,           // 账户布局版本，便于后续迁移
     pub is_initialized: bool,  // 是否已初始化
     pub reviewer: Pubkey,      // 影评者的公钥
     pub rating: u8,            // 评分
+    pub upvotes: u64,          // 点赞数
+    pub downvotes: u64,        // 点踩数
     pub title: String,         // 影片标题
     pub description: String,   // 影片描述
 }
@@ -19,15 +23,41 @@ pub struct MovieAccountState {
 // MovieAccountState 结构体的实现块
 impl MovieAccountState {
     pub const DISCRIMINATOR: &'static str = "review"; // 鉴别器常量，标识为"review"
+    pub const VERSION: u8 = 3; // 当前账户布局版本，v2 起 PDA 种子使用标题哈希，v3 增加投票统计
+    pub const MAX_TITLE_LEN: usize = 100; // 标题最大字节数
+    pub const MAX_DESCRIPTION_LEN: usize = 800; // 描述最大字节数
+
+    // 校验标题和描述长度是否在允许范围内
+    pub fn validate_lengths(title: &str, description: &str) -> bool {
+        title.len() <= MovieAccountState::MAX_TITLE_LEN
+            && description.len() <= MovieAccountState::MAX_DESCRIPTION_LEN
+    }
 
     // 计算账户的大小，以便初始化
     pub fn get_account_size(title: String, description: String) -> usize {
         (4 + MovieAccountState::DISCRIMINATOR.len())
             + 1
             + 1
+            + 32
+            + 1
+            + 8
+            + 8
             + (4 + title.len())
             + (4 + description.len())
     }
+
+    // 计算标题的种子：对标题做 sha256，避免标题超过 32 字节时无法作为种子
+    pub fn title_seed(title: &str) -> [u8; 32] {
+        hash(title.as_bytes()).to_bytes()
+    }
+
+    // 根据影评者和标题推导影评 PDA，同一钱包对同一部电影只能得到唯一地址
+    pub fn find_pda(reviewer: &Pubkey, title: &str, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[reviewer.as_ref(), &MovieAccountState::title_seed(title)],
+            program_id,
+        )
+    }
 }
 
 // 定义 MovieCommentCounter 结构体，表示评论计数器账户的状态
@@ -58,6 +88,7 @@ pub struct MovieComment {
 // MovieComment 结构体的实现块
 impl MovieComment {
     pub const DISCRIMINATOR: &'static str = "comment"; // 鉴别器常量，标识为"comment"
+    pub const MAX_COMMENT_LEN: usize = 500; // 评论最大字节数
 
     // 计算账户的大小，以便初始化
     pub fn get_account_size(comment: String) -> usize {
@@ -65,6 +96,34 @@ impl MovieComment {
     }
 }
 
+// 定义 MovieVote 结构体，表示投票者对某条影评的投票记录
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct MovieVote {
+    pub discriminator: String, // 鉴别器字段，用于区分不同类型的账户
+    pub is_initialized: bool,  // 是否已初始化
+    pub review: Pubkey,        // 关联的影评的公钥
+    pub voter: Pubkey,         // 投票者的公钥
+    pub upvote: bool,          // true 为点赞，false 为点踩
+}
+
+// MovieVote 结构体的实现块
+impl MovieVote {
+    pub const DISCRIMINATOR: &'static str = "vote"; // 鉴别器常量，标识为"vote"
+    pub const SIZE: usize = (4 + MovieVote::DISCRIMINATOR.len()) + 1 + 32 + 32 + 1; // 计算账户的大小
+
+    // 根据影评和投票者推导投票 PDA，每个投票者对每条影评只能有一个投票账户
+    pub fn find_pda(review: &Pubkey, voter: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                review.as_ref(),
+                voter.as_ref(),
+                MovieVote::DISCRIMINATOR.as_bytes(),
+            ],
+            program_id,
+        )
+    }
+}
+
 // 下面是对 IsInitialized 特征的实现
 impl Sealed for MovieAccountState {}
 
@@ -85,3 +144,9 @@ impl IsInitialized for MovieComment {
         self.is_initialized
     }
 }
+
+impl IsInitialized for MovieVote {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
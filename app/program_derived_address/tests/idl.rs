@@ -0,0 +1,191 @@
+#![cfg(feature = "idl")]
+
+use native_idl::{testing::Samples, IdlInstruction};
+use program_derived_address::{
+    error::ReviewError,
+    idl::idl,
+    instruction::MovieInstruction,
+    state::{MovieAccountState, MovieComment, MovieCommentCounter, MovieVote},
+};
+use solana_program::pubkey::Pubkey;
+
+fn instruction(name: &str) -> IdlInstruction {
+    idl()
+        .instructions
+        .into_iter()
+        .find(|ix| ix.name == name)
+        .unwrap_or_else(|| panic!("IDL 中缺少指令 {}", name))
+}
+
+// 新增 ReviewError 变体时这里无法编译，需同时补充 ReviewError::ALL
+fn error_name(e: ReviewError) -> &'static str {
+    match e {
+        ReviewError::UninitializedAccount => "UninitializedAccount",
+        ReviewError::InvalidPDA => "InvalidPDA",
+        ReviewError::InvalidDataLength => "InvalidDataLength",
+        ReviewError::InvalidRating => "InvalidRating",
+        ReviewError::ReviewAlreadyExists => "ReviewAlreadyExists",
+        ReviewError::UnsupportedAccountVersion => "UnsupportedAccountVersion",
+        ReviewError::Overflow => "Overflow",
+        ReviewError::AlreadyVoted => "AlreadyVoted",
+        ReviewError::InvalidAccountType => "InvalidAccountType",
+    }
+}
+
+// 新增 MovieInstruction 变体时这里无法编译，需同时补充 IDL 指令
+fn instruction_name(ix: &MovieInstruction) -> &'static str {
+    match ix {
+        MovieInstruction::AddMovieReview { .. } => "addMovieReview",
+        MovieInstruction::UpdateMovieReview { .. } => "updateMovieReview",
+        MovieInstruction::AddComment { .. } => "addComment",
+        MovieInstruction::VoteReview { .. } => "voteReview",
+    }
+}
+
+#[test]
+fn error_codes_match_review_error() {
+    let idl = idl();
+    assert_eq!(idl.errors.len(), ReviewError::ALL.len());
+
+    for (i, (code, e)) in idl.errors.iter().zip(ReviewError::ALL).enumerate() {
+        assert_eq!(e as u32, i as u32, "ReviewError::ALL 顺序与枚举定义不一致");
+        assert_eq!(code.code, e as u32);
+        assert_eq!(code.name, error_name(e));
+        assert_eq!(code.msg, e.to_string());
+    }
+}
+
+#[test]
+fn instruction_discriminants_match_unpack() {
+    let idl = idl();
+    let names: Vec<_> = idl.instructions.iter().map(|ix| ix.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "addMovieReview",
+            "updateMovieReview",
+            "addComment",
+            "voteReview"
+        ]
+    );
+
+    for ix in &idl.instructions {
+        let args = Samples::args(ix);
+        let unpacked = MovieInstruction::unpack(&args.instruction_data(ix)).unwrap();
+        assert_eq!(instruction_name(&unpacked), ix.name);
+    }
+}
+
+#[test]
+fn review_args_match_payload() {
+    for name in ["addMovieReview", "updateMovieReview"] {
+        let ix = instruction(name);
+        let args = Samples::args(&ix);
+        match MovieInstruction::unpack(&args.instruction_data(&ix)).unwrap() {
+            MovieInstruction::AddMovieReview {
+                title,
+                rating,
+                description,
+            }
+            | MovieInstruction::UpdateMovieReview {
+                title,
+                rating,
+                description,
+            } => {
+                assert_eq!(title, args.string("title"));
+                assert_eq!(rating, args.u8("rating"));
+                assert_eq!(description, args.string("description"));
+            }
+            _ => panic!("{} 解包为错误的指令", name),
+        }
+    }
+}
+
+#[test]
+fn comment_and_vote_args_match_payload() {
+    let ix = instruction("addComment");
+    let args = Samples::args(&ix);
+    match MovieInstruction::unpack(&args.instruction_data(&ix)).unwrap() {
+        MovieInstruction::AddComment { comment } => assert_eq!(comment, args.string("comment")),
+        _ => panic!("addComment 解包为错误的指令"),
+    }
+
+    let ix = instruction("voteReview");
+    let args = Samples::args(&ix);
+    match MovieInstruction::unpack(&args.instruction_data(&ix)).unwrap() {
+        MovieInstruction::VoteReview { upvote } => assert_eq!(upvote, args.bool("upvote")),
+        _ => panic!("voteReview 解包为错误的指令"),
+    }
+}
+
+#[test]
+fn account_fields_match_borsh_layout() {
+    let idl = idl();
+    assert_eq!(idl.accounts.len(), 4);
+
+    let s = Samples::account(&idl, "MovieAccountState");
+    let review = MovieAccountState {
+        discriminator: s.string("discriminator"),
+        version: s.u8("version"),
+        is_initialized: s.bool("isInitialized"),
+        reviewer: Pubkey::new_from_array(s.pubkey_bytes("reviewer")),
+        rating: s.u8("rating"),
+        upvotes: s.u64("upvotes"),
+        downvotes: s.u64("downvotes"),
+        title: s.string("title"),
+        description: s.string("description"),
+    };
+    assert_eq!(borsh::to_vec(&review).unwrap(), s.encode());
+
+    let s = Samples::account(&idl, "MovieCommentCounter");
+    let counter = MovieCommentCounter {
+        discriminator: s.string("discriminator"),
+        is_initialized: s.bool("isInitialized"),
+        counter: s.u64("counter"),
+    };
+    assert_eq!(borsh::to_vec(&counter).unwrap(), s.encode());
+
+    let s = Samples::account(&idl, "MovieComment");
+    let comment = MovieComment {
+        discriminator: s.string("discriminator"),
+        is_initialized: s.bool("isInitialized"),
+        review: Pubkey::new_from_array(s.pubkey_bytes("review")),
+        commenter: Pubkey::new_from_array(s.pubkey_bytes("commenter")),
+        comment: s.string("comment"),
+        count: s.u64("count"),
+    };
+    assert_eq!(borsh::to_vec(&comment).unwrap(), s.encode());
+
+    let s = Samples::account(&idl, "MovieVote");
+    let vote = MovieVote {
+        discriminator: s.string("discriminator"),
+        is_initialized: s.bool("isInitialized"),
+        review: Pubkey::new_from_array(s.pubkey_bytes("review")),
+        voter: Pubkey::new_from_array(s.pubkey_bytes("voter")),
+        upvote: s.bool("upvote"),
+    };
+    assert_eq!(borsh::to_vec(&vote).unwrap(), s.encode());
+}
+
+#[test]
+fn optional_accounts_are_trailing() {
+    for ix in idl().instructions {
+        let first_optional = ix
+            .accounts
+            .iter()
+            .position(|account| account.is_optional)
+            .unwrap_or(ix.accounts.len());
+        assert!(
+            ix.accounts[first_optional..]
+                .iter()
+                .all(|account| account.is_optional),
+            "{} 的可选账户必须位于末尾",
+            ix.name
+        );
+    }
+
+    let update = instruction("updateMovieReview");
+    let names: Vec<_> = update.accounts.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, ["initializer", "pdaAccount", "systemProgram"]);
+    assert!(update.accounts[2].is_optional);
+}
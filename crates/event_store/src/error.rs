@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// -- 事件存储错误类型
+#[derive(Debug, Error)]
+pub enum StoreError {
+    /// IO 操作错误
+    #[error("IO 错误: {0}")]
+    IoError(#[from] std::io::Error),
+
+    /// JSON 解析错误
+    #[error("JSON 解析错误: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    /// 哈希链校验失败
+    #[error("事件哈希链在第 {0} 行断裂")]
+    ChainBroken(usize),
+}
+
+/// -- 事件存储操作的返回类型
+pub type StoreResult<T> = Result<T, StoreError>;
@@ -0,0 +1,324 @@
+//! # 事件存储
+//!
+//! 基于 JSONL 文件的只追加事件存储，提供追加、回放、订阅和压缩。事件之间以 SHA-256
+//! 哈希链相连，审计日志、监控事件和钱包账本都通过它持久化与回放。
+use serde::{
+    de::{DeserializeOwned, IgnoredAny},
+    Serialize,
+};
+use serde_json::value::RawValue;
+use sha2::{Digest, Sha256};
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Mutex, MutexGuard},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::broadcast;
+
+pub mod error;
+pub mod model;
+
+pub use error::*;
+pub use model::*;
+
+/// -- 链首事件的前置哈希（32 字节全零的 base58 编码）
+pub const GENESIS_HASH: &str = "11111111111111111111111111111111";
+
+/// -- 订阅通道容量，订阅者落后超过该数量时会丢失最早的事件
+const SUBSCRIBER_CAPACITY: usize = 1024;
+
+/// -- 只追加的事件存储
+///
+/// 以 JSONL 格式保存事件，写入后立即落盘；打开已有文件时从最后一条事件继续序号和哈希链。
+/// 同一进程内的新事件会广播给所有订阅者。
+pub struct EventStore<E> {
+    path: PathBuf,                             // -- 存储文件路径
+    state: Mutex<StoreState>,                  // -- 文件句柄和链尾状态
+    sender: broadcast::Sender<StoredEvent<E>>, // -- 新事件广播
+}
+
+struct StoreState {
+    file: File,         // -- 追加模式打开的存储文件
+    next_sequence: u64, // -- 下一条事件的序号
+    last_hash: String,  // -- 链尾事件哈希
+}
+
+impl<E> EventStore<E>
+where
+    E: Serialize + DeserializeOwned + Clone,
+{
+    /// -- 打开（或创建）事件存储
+    ///
+    /// # 参数
+    /// * `path` - 存储文件路径，父目录不存在时会自动创建
+    pub fn open(path: impl AsRef<Path>) -> StoreResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+
+        let (next_sequence, last_hash) = if path.exists() {
+            chain_tail(&path)?
+        } else {
+            (0, GENESIS_HASH.to_string())
+        };
+        let file = open_append(&path)?;
+        let (sender, _) = broadcast::channel(SUBSCRIBER_CAPACITY);
+
+        Ok(Self {
+            path,
+            state: Mutex::new(StoreState {
+                file,
+                next_sequence,
+                last_hash,
+            }),
+            sender,
+        })
+    }
+
+    /// -- 存储文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// -- 下一条事件的序号
+    pub fn next_sequence(&self) -> u64 {
+        self.lock().next_sequence
+    }
+
+    /// -- 追加事件并广播给订阅者
+    ///
+    /// # 参数
+    /// * `event` - 事件内容
+    ///
+    /// # 返回
+    /// * `StoreResult<StoredEvent<E>>` - 写入的事件（包含序号和哈希）
+    pub fn append(&self, event: E) -> StoreResult<StoredEvent<E>> {
+        let raw = serde_json::to_string(&event)?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let mut state = self.lock();
+        let sequence = state.next_sequence;
+        let record = StoredEvent {
+            sequence,
+            timestamp,
+            prev_hash: state.last_hash.clone(),
+            hash: chain_hash(&state.last_hash, sequence, timestamp, &raw),
+            event: RawValue::from_string(raw)?,
+        };
+
+        let mut line = serde_json::to_string(&record)?;
+        line.push('\n');
+        state.file.write_all(line.as_bytes())?;
+        state.file.sync_data()?;
+        state.next_sequence += 1;
+        state.last_hash = record.hash.clone();
+
+        let stored = StoredEvent {
+            sequence,
+            timestamp,
+            prev_hash: record.prev_hash,
+            hash: record.hash,
+            event,
+        };
+        // -- 持有锁时广播，保证订阅者收到的顺序与写入顺序一致；没有订阅者时忽略
+        let _ = self.sender.send(stored.clone());
+
+        Ok(stored)
+    }
+
+    /// -- 按写入顺序回放全部事件
+    pub fn replay(&self) -> StoreResult<Vec<StoredEvent<E>>> {
+        self.replay_from(0)
+    }
+
+    /// -- 回放序号不小于 `sequence` 的事件
+    ///
+    /// # 参数
+    /// * `sequence` - 起始序号，通常为上次处理到的序号 + 1
+    pub fn replay_from(&self, sequence: u64) -> StoreResult<Vec<StoredEvent<E>>> {
+        let _state = self.lock();
+
+        read_records(&self.path)?
+            .into_iter()
+            .filter(|record| record.sequence >= sequence)
+            .map(decode_record)
+            .collect()
+    }
+
+    /// -- 订阅之后写入的新事件
+    ///
+    /// 只接收订阅之后的事件，需要历史事件时先调用 [`EventStore::replay`]。
+    pub fn subscribe(&self) -> broadcast::Receiver<StoredEvent<E>> {
+        self.sender.subscribe()
+    }
+
+    /// -- 校验哈希链
+    ///
+    /// # 返回
+    /// * `StoreResult<usize>` - 校验通过返回事件数量
+    pub fn verify(&self) -> StoreResult<usize> {
+        let _state = self.lock();
+        verify_file(&self.path)
+    }
+
+    /// -- 压缩存储，只保留 `retain` 返回 true 的事件
+    ///
+    /// 保留事件的序号、时间戳和内容不变，哈希链从链首重新计算；
+    /// 先写临时文件再替换，压缩中断不会损坏原文件。
+    ///
+    /// # 参数
+    /// * `retain` - 判断事件是否保留
+    pub fn compact<F>(&self, mut retain: F) -> StoreResult<CompactionStats>
+    where
+        F: FnMut(&StoredEvent<E>) -> bool,
+    {
+        let mut state = self.lock();
+        let records = read_records(&self.path)?;
+        let before = records.len();
+
+        let temp = self.path.with_extension("compact");
+        let mut writer = BufWriter::new(File::create(&temp)?);
+        let mut last_hash = GENESIS_HASH.to_string();
+        let mut retained = 0;
+
+        for record in records {
+            if !retain(&decode_record(record.clone())?) {
+                continue;
+            }
+
+            let hash = chain_hash(
+                &last_hash,
+                record.sequence,
+                record.timestamp,
+                record.event.get(),
+            );
+            let rewritten = StoredEvent {
+                prev_hash: last_hash,
+                hash: hash.clone(),
+                ..record
+            };
+            serde_json::to_writer(&mut writer, &rewritten)?;
+            writer.write_all(b"\n")?;
+            last_hash = hash;
+            retained += 1;
+        }
+
+        writer.flush()?;
+        writer.get_ref().sync_all()?;
+        drop(writer);
+        fs::rename(&temp, &self.path)?;
+
+        state.file = open_append(&self.path)?;
+        state.last_hash = last_hash;
+
+        Ok(CompactionStats { before, retained })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StoreState> {
+        self.state.lock().unwrap()
+    }
+}
+
+/// -- 校验存储文件的哈希链，不需要知道事件类型
+///
+/// # 参数
+/// * `path` - 存储文件路径
+///
+/// # 返回
+/// * `StoreResult<usize>` - 校验通过返回事件数量，否则返回首个异常事件的行号
+pub fn verify_file(path: impl AsRef<Path>) -> StoreResult<usize> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut prev_hash = GENESIS_HASH.to_string();
+    let mut count = 0;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record: StoredEvent<Box<RawValue>> = serde_json::from_str(&line)?;
+        let expected = chain_hash(
+            &record.prev_hash,
+            record.sequence,
+            record.timestamp,
+            record.event.get(),
+        );
+        if record.prev_hash != prev_hash || record.hash != expected {
+            return Err(StoreError::ChainBroken(index + 1));
+        }
+
+        prev_hash = record.hash;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// -- 计算事件哈希：sha256(前置哈希 || 序号 || 时间戳 || 事件 JSON)
+fn chain_hash(prev_hash: &str, sequence: u64, timestamp: u64, event: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(prev_hash.as_bytes());
+    hasher.update(sequence.to_le_bytes());
+    hasher.update(timestamp.to_le_bytes());
+    hasher.update(event.as_bytes());
+    bs58::encode(hasher.finalize()).into_string()
+}
+
+/// -- 读取全部事件，事件内容保持原始 JSON
+fn read_records(path: &Path) -> StoreResult<Vec<StoredEvent<Box<RawValue>>>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            records.push(serde_json::from_str(&line)?);
+        }
+    }
+
+    Ok(records)
+}
+
+/// -- 将原始 JSON 事件解析为具体类型
+fn decode_record<E: DeserializeOwned>(
+    record: StoredEvent<Box<RawValue>>,
+) -> StoreResult<StoredEvent<E>> {
+    Ok(StoredEvent {
+        event: serde_json::from_str(record.event.get())?,
+        sequence: record.sequence,
+        timestamp: record.timestamp,
+        prev_hash: record.prev_hash,
+        hash: record.hash,
+    })
+}
+
+/// -- 读取最后一条事件，返回下一条事件的序号和链尾哈希
+fn chain_tail(path: &Path) -> StoreResult<(u64, String)> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut last = None;
+    for line in reader.lines() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            last = Some(line);
+        }
+    }
+
+    match last {
+        Some(line) => {
+            let record: StoredEvent<IgnoredAny> = serde_json::from_str(&line)?;
+            Ok((record.sequence + 1, record.hash))
+        }
+        None => Ok((0, GENESIS_HASH.to_string())),
+    }
+}
+
+fn open_append(path: &Path) -> StoreResult<File> {
+    Ok(OpenOptions::new().create(true).append(true).open(path)?)
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// -- 已写入存储的事件
+///
+/// 每条事件记录上一条事件的哈希（`prev_hash`）和自身的哈希（`hash`），
+/// 哈希覆盖序号、时间戳和事件原始 JSON，删除或修改任意历史事件都会被校验发现。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredEvent<E> {
+    pub sequence: u64,
+    pub timestamp: u64,
+    pub prev_hash: String,
+    pub hash: String,
+    pub event: E,
+}
+
+/// -- 压缩结果
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionStats {
+    pub before: usize,   // -- 压缩前的事件数量
+    pub retained: usize, // -- 保留的事件数量
+}
+
+impl CompactionStats {
+    /// -- 被移除的事件数量
+    pub fn removed(&self) -> usize {
+        self.before - self.retained
+    }
+}
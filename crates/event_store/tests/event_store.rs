@@ -0,0 +1,115 @@
+use event_store::{verify_file, EventStore, StoreError};
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Transfer {
+    to: String,
+    lamports: u64,
+}
+
+fn transfer(lamports: u64) -> Transfer {
+    Transfer {
+        to: "wallet".to_string(),
+        lamports,
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    env::temp_dir().join(format!("event-store-{}-{}.jsonl", name, nanos))
+}
+
+#[test]
+fn append_and_replay_survive_reopen() {
+    let path = temp_path("reopen");
+    {
+        let store = EventStore::open(&path).unwrap();
+        store.append(transfer(1)).unwrap();
+        store.append(transfer(2)).unwrap();
+    }
+
+    let store = EventStore::<Transfer>::open(&path).unwrap();
+    let third = store.append(transfer(3)).unwrap();
+    assert_eq!(third.sequence, 2);
+
+    let events = store.replay().unwrap();
+    let amounts: Vec<u64> = events.iter().map(|e| e.event.lamports).collect();
+    assert_eq!(amounts, vec![1, 2, 3]);
+    assert_eq!(events[2].prev_hash, events[1].hash);
+    assert_eq!(store.replay_from(1).unwrap().len(), 2);
+    assert_eq!(store.verify().unwrap(), 3);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn verify_detects_tampering() {
+    let path = temp_path("tamper");
+    let store = EventStore::open(&path).unwrap();
+    for lamports in 1..=3 {
+        store.append(transfer(lamports)).unwrap();
+    }
+    drop(store);
+
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(
+        &path,
+        content.replacen("\"lamports\":2", "\"lamports\":20", 1),
+    )
+    .unwrap();
+
+    assert!(matches!(
+        verify_file(&path),
+        Err(StoreError::ChainBroken(2))
+    ));
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn compaction_keeps_sequences_and_rechains() {
+    let path = temp_path("compact");
+    let store = EventStore::open(&path).unwrap();
+    for lamports in 1..=5 {
+        store.append(transfer(lamports)).unwrap();
+    }
+
+    let stats = store.compact(|e| e.event.lamports % 2 == 1).unwrap();
+    assert_eq!(stats.before, 5);
+    assert_eq!(stats.retained, 3);
+    assert_eq!(stats.removed(), 2);
+    assert_eq!(store.verify().unwrap(), 3);
+
+    let next = store.append(transfer(6)).unwrap();
+    assert_eq!(next.sequence, 5);
+
+    let sequences: Vec<u64> = store.replay().unwrap().iter().map(|e| e.sequence).collect();
+    assert_eq!(sequences, vec![0, 2, 4, 5]);
+    assert_eq!(verify_file(&path).unwrap(), 4);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn subscribers_receive_new_events() {
+    let path = temp_path("subscribe");
+    let store = EventStore::open(&path).unwrap();
+    store.append(transfer(1)).unwrap();
+
+    let mut receiver = store.subscribe();
+    store.append(transfer(2)).unwrap();
+
+    let received = receiver.recv().await.unwrap();
+    assert_eq!(received.sequence, 1);
+    assert_eq!(received.event, transfer(2));
+
+    fs::remove_file(&path).unwrap();
+}
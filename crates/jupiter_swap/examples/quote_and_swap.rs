@@ -0,0 +1,38 @@
+use jupiter_swap::{JupiterClient, QuoteRequest, SwapOptions, SOL_MINT, USDC_MINT};
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::error::Error;
+use tracing::info;
+use utils::{init_rpc_client, init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    let client = JupiterClient::default();
+
+    // -- 查询 0.01 SOL -> USDC 的最优报价，滑点 1%
+    let request = QuoteRequest::new(SOL_MINT, USDC_MINT, 10_000_000).slippage_bps(100);
+    let quote = client.best_quote(&request).await?;
+
+    info!("路由: {}", quote.route_labels());
+    info!("预计获得: {} USDC", quote.out_amount() as f64 / 1e6);
+    info!("最少获得: {} USDC", quote.min_out_amount() as f64 / 1e6);
+
+    // -- 设置 SWAP=1 时实际执行交换
+    if std::env::var("SWAP").is_ok() {
+        let rpc_client = init_rpc_client(CommitmentConfig::confirmed())?;
+        let wallet_path = std::env::var("WALLET_PATH")?;
+        let key_str = std::fs::read_to_string(wallet_path)?;
+        let private_key: String = serde_json::from_str(&key_str)?;
+        let wallet = solana_sdk::signature::Keypair::from_base58_string(&private_key);
+
+        let result = client
+            .swap(&rpc_client, &wallet, &request, &SwapOptions::default())
+            .await?;
+        info!("交易签名: {}", result.signature);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+/// -- Jupiter 交换错误类型
+#[derive(Debug, Error)]
+pub enum JupiterError {
+    /// HTTP 请求错误
+    #[error("HTTP 错误: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    /// Jupiter API 返回错误
+    #[error("Jupiter API 错误: {0}")]
+    ApiError(String),
+
+    /// RPC 客户端错误
+    #[error("RPC 错误: {0}")]
+    RpcError(#[from] solana_client::client_error::ClientError),
+
+    /// 未找到可用路由
+    #[error("未找到可用路由")]
+    NoRoute,
+
+    /// 数据解码错误
+    #[error("解码错误: {0}")]
+    DecodeError(String),
+
+    /// 交易构建错误
+    #[error("交易构建错误: {0}")]
+    BuildError(String),
+
+    /// 交易执行错误
+    #[error("交易错误: {0}")]
+    TransactionError(String),
+}
+
+/// -- 自定义 Result 类型
+pub type JupiterResult<T> = Result<T, JupiterError>;
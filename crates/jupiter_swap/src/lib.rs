@@ -0,0 +1,313 @@
+//! # Jupiter v6 交换
+//!
+//! 提供 Jupiter 聚合器的报价获取、路由选择、交易构建（包含地址查找表）和执行功能，
+//! 以及基于 Jupiter 价格 API 的代币 USD 价格查询。
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::json;
+use solana_sdk::{
+    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    instruction::{AccountMeta, Instruction},
+    message::{v0, VersionedMessage},
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::VersionedTransaction,
+};
+use std::str::FromStr;
+use tracing::{debug, info, instrument};
+use utils::RpcApi;
+
+pub mod error;
+pub mod model;
+pub mod price;
+
+pub use error::*;
+pub use model::*;
+
+/// -- 默认 Jupiter v6 API 地址
+pub const DEFAULT_API_URL: &str = "https://quote-api.jup.ag/v6";
+/// -- Wrapped SOL Mint
+pub const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+/// -- USDC Mint
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+/// -- Jupiter 客户端
+///
+/// 封装 Jupiter v6 HTTP API，交易构建和发送使用调用方提供的 RPC 客户端
+pub struct JupiterClient {
+    http: reqwest::Client,
+    api_url: String,
+}
+
+impl Default for JupiterClient {
+    fn default() -> Self {
+        Self::new(DEFAULT_API_URL)
+    }
+}
+
+impl JupiterClient {
+    /// -- 使用指定 API 地址创建客户端
+    pub fn new(api_url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// -- 获取报价
+    ///
+    /// # 参数
+    /// * `request` - 报价请求参数
+    ///
+    /// # 返回
+    /// * `JupiterResult<QuoteResponse>` - 最优报价
+    #[instrument(skip(self), fields(input = %request.input_mint, output = %request.output_mint))]
+    pub async fn get_quote(&self, request: &QuoteRequest) -> JupiterResult<QuoteResponse> {
+        let swap_mode = match request.swap_mode {
+            SwapMode::ExactIn => "ExactIn",
+            SwapMode::ExactOut => "ExactOut",
+        };
+        let mut query = vec![
+            ("inputMint", request.input_mint.clone()),
+            ("outputMint", request.output_mint.clone()),
+            ("amount", request.amount.to_string()),
+            ("slippageBps", request.slippage_bps.to_string()),
+            ("swapMode", swap_mode.to_string()),
+            ("onlyDirectRoutes", request.only_direct_routes.to_string()),
+        ];
+        if let Some(max_accounts) = request.max_accounts {
+            query.push(("maxAccounts", max_accounts.to_string()));
+        }
+
+        let response = self
+            .http
+            .get(format!("{}/quote", self.api_url))
+            .query(&query)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(JupiterError::ApiError(body));
+        }
+
+        let quote: QuoteResponse = response.json().await?;
+        debug!(
+            "报价: {} -> {}, 路由: {}",
+            quote.in_amount,
+            quote.out_amount,
+            quote.route_labels()
+        );
+
+        Ok(quote)
+    }
+
+    /// -- 路由选择
+    ///
+    /// 分别请求多跳和单跳路由，返回输出数量最多的报价。
+    /// 单跳路由交易更小、落地率更高，输出相同时优先选择单跳。
+    pub async fn best_quote(&self, request: &QuoteRequest) -> JupiterResult<QuoteResponse> {
+        let mut direct_request = request.clone();
+        direct_request.only_direct_routes = true;
+
+        let multi_hop = self.get_quote(request).await;
+        let direct = self.get_quote(&direct_request).await;
+
+        let best = match (multi_hop, direct) {
+            (Ok(multi_hop), Ok(direct)) => {
+                if direct.out_amount() >= multi_hop.out_amount() {
+                    direct
+                } else {
+                    multi_hop
+                }
+            }
+            (Ok(quote), Err(_)) | (Err(_), Ok(quote)) => quote,
+            (Err(e), Err(_)) => return Err(e),
+        };
+
+        if best.out_amount() == 0 {
+            return Err(JupiterError::NoRoute);
+        }
+
+        info!(
+            "选择路由: {}, 预计输出: {}, 价格影响: {:.4}%",
+            best.route_labels(),
+            best.out_amount,
+            best.price_impact()
+        );
+
+        Ok(best)
+    }
+
+    /// -- 获取交换指令
+    pub async fn get_swap_instructions(
+        &self,
+        quote: &QuoteResponse,
+        user: &Pubkey,
+        options: &SwapOptions,
+    ) -> JupiterResult<SwapInstructionsResponse> {
+        let prioritization_fee = match options.priority_fee {
+            PriorityFee::None => json!(0),
+            PriorityFee::Auto => json!("auto"),
+            PriorityFee::Lamports(lamports) => json!(lamports),
+        };
+
+        let body = json!({
+            "quoteResponse": quote,
+            "userPublicKey": user.to_string(),
+            "wrapAndUnwrapSol": options.wrap_and_unwrap_sol,
+            "dynamicComputeUnitLimit": options.dynamic_compute_unit_limit,
+            "prioritizationFeeLamports": prioritization_fee,
+        });
+
+        let response = self
+            .http
+            .post(format!("{}/swap-instructions", self.api_url))
+            .json(&body)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let body = response.text().await.unwrap_or_default();
+            return Err(JupiterError::ApiError(body));
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// -- 构建交换交易
+    ///
+    /// 将 Jupiter 返回的指令按顺序组装，并加载地址查找表构建 v0 交易。
+    pub async fn build_swap_transaction<R: RpcApi>(
+        &self,
+        rpc_client: &R,
+        wallet: &impl Signer,
+        quote: &QuoteResponse,
+        options: &SwapOptions,
+    ) -> JupiterResult<VersionedTransaction> {
+        let swap_ixs = self
+            .get_swap_instructions(quote, &wallet.pubkey(), options)
+            .await?;
+
+        let mut instructions = Vec::new();
+        for ix in &swap_ixs.compute_budget_instructions {
+            instructions.push(to_instruction(ix)?);
+        }
+        for ix in &swap_ixs.setup_instructions {
+            instructions.push(to_instruction(ix)?);
+        }
+        if let Some(ix) = &swap_ixs.token_ledger_instruction {
+            instructions.push(to_instruction(ix)?);
+        }
+        instructions.push(to_instruction(&swap_ixs.swap_instruction)?);
+        if let Some(ix) = &swap_ixs.cleanup_instruction {
+            instructions.push(to_instruction(ix)?);
+        }
+
+        let lookup_tables =
+            load_lookup_tables(rpc_client, &swap_ixs.address_lookup_table_addresses)?;
+        debug!("加载地址查找表: {} 个", lookup_tables.len());
+
+        let blockhash = rpc_client.get_latest_blockhash()?;
+        let message =
+            v0::Message::try_compile(&wallet.pubkey(), &instructions, &lookup_tables, blockhash)
+                .map_err(|e| JupiterError::BuildError(e.to_string()))?;
+
+        VersionedTransaction::try_new(VersionedMessage::V0(message), &[wallet])
+            .map_err(|e| JupiterError::BuildError(e.to_string()))
+    }
+
+    /// -- 执行交换
+    ///
+    /// 获取最优报价、构建交易并发送确认。
+    ///
+    /// # 参数
+    /// * `rpc_client` - RPC 客户端
+    /// * `wallet` - 钱包签名者
+    /// * `request` - 报价请求参数（包含滑点）
+    /// * `options` - 执行选项（包含优先费）
+    pub async fn swap<R: RpcApi>(
+        &self,
+        rpc_client: &R,
+        wallet: &impl Signer,
+        request: &QuoteRequest,
+        options: &SwapOptions,
+    ) -> JupiterResult<SwapResult> {
+        let quote = self.best_quote(request).await?;
+        let transaction = self
+            .build_swap_transaction(rpc_client, wallet, &quote, options)
+            .await?;
+
+        let signature = rpc_client
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|e| JupiterError::TransactionError(e.to_string()))?;
+
+        info!("交换成功，交易签名: {}", signature);
+
+        Ok(SwapResult {
+            signature: signature.to_string(),
+            in_amount: quote.in_amount.parse().unwrap_or(request.amount),
+            out_amount: quote.out_amount(),
+            min_out_amount: quote.min_out_amount(),
+            route: quote.route_labels(),
+        })
+    }
+}
+
+/// -- 将 API 指令转换为 Solana 指令
+fn to_instruction(ix: &ApiInstruction) -> JupiterResult<Instruction> {
+    let program_id =
+        Pubkey::from_str(&ix.program_id).map_err(|e| JupiterError::DecodeError(e.to_string()))?;
+    let accounts = ix
+        .accounts
+        .iter()
+        .map(|meta| {
+            let pubkey = Pubkey::from_str(&meta.pubkey)
+                .map_err(|e| JupiterError::DecodeError(e.to_string()))?;
+            Ok(if meta.is_writable {
+                AccountMeta::new(pubkey, meta.is_signer)
+            } else {
+                AccountMeta::new_readonly(pubkey, meta.is_signer)
+            })
+        })
+        .collect::<JupiterResult<Vec<_>>>()?;
+    let data = STANDARD
+        .decode(&ix.data)
+        .map_err(|e| JupiterError::DecodeError(e.to_string()))?;
+
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data,
+    })
+}
+
+/// -- 加载地址查找表
+pub fn load_lookup_tables<R: RpcApi>(
+    rpc_client: &R,
+    addresses: &[String],
+) -> JupiterResult<Vec<AddressLookupTableAccount>> {
+    let keys = addresses
+        .iter()
+        .map(|address| {
+            Pubkey::from_str(address).map_err(|e| JupiterError::DecodeError(e.to_string()))
+        })
+        .collect::<JupiterResult<Vec<_>>>()?;
+
+    let mut tables = Vec::with_capacity(keys.len());
+    for (key, account) in keys.iter().zip(rpc_client.get_multiple_accounts(&keys)?) {
+        let Some(account) = account else {
+            return Err(JupiterError::BuildError(format!(
+                "地址查找表不存在: {}",
+                key
+            )));
+        };
+        let table = AddressLookupTable::deserialize(&account.data)
+            .map_err(|e| JupiterError::DecodeError(e.to_string()))?;
+        tables.push(AddressLookupTableAccount {
+            key: *key,
+            addresses: table.addresses.to_vec(),
+        });
+    }
+
+    Ok(tables)
+}
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// -- 交换模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SwapMode {
+    #[default]
+    ExactIn,
+    ExactOut,
+}
+
+/// -- 报价请求参数
+#[derive(Debug, Clone)]
+pub struct QuoteRequest {
+    pub input_mint: String,          // -- 输入代币 Mint
+    pub output_mint: String,         // -- 输出代币 Mint
+    pub amount: u64,                 // -- 数量（最小单位）
+    pub slippage_bps: u16,           // -- 滑点（基点）
+    pub swap_mode: SwapMode,         // -- 交换模式
+    pub only_direct_routes: bool,    // -- 是否只使用单跳路由
+    pub max_accounts: Option<usize>, // -- 路由最多使用的账户数，用于控制交易大小
+}
+
+impl QuoteRequest {
+    /// -- 创建 ExactIn 报价请求，默认滑点 0.5%
+    pub fn new(input_mint: &str, output_mint: &str, amount: u64) -> Self {
+        Self {
+            input_mint: input_mint.to_string(),
+            output_mint: output_mint.to_string(),
+            amount,
+            slippage_bps: 50,
+            swap_mode: SwapMode::ExactIn,
+            only_direct_routes: false,
+            max_accounts: None,
+        }
+    }
+
+    /// -- 设置滑点
+    pub fn slippage_bps(mut self, slippage_bps: u16) -> Self {
+        self.slippage_bps = slippage_bps;
+        self
+    }
+}
+
+/// -- 单跳路由信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapInfo {
+    pub amm_key: String,
+    pub label: Option<String>,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub in_amount: String,
+    pub out_amount: String,
+    pub fee_amount: String,
+    pub fee_mint: String,
+}
+
+/// -- 路由计划
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoutePlan {
+    pub swap_info: SwapInfo,
+    pub percent: u8,
+}
+
+/// -- 报价响应
+///
+/// 除已知字段外保留其余字段，保证原样回传给 `/swap-instructions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteResponse {
+    pub input_mint: String,
+    pub in_amount: String,
+    pub output_mint: String,
+    pub out_amount: String,
+    pub other_amount_threshold: String,
+    pub swap_mode: SwapMode,
+    pub slippage_bps: u16,
+    pub price_impact_pct: String,
+    pub route_plan: Vec<RoutePlan>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl QuoteResponse {
+    /// -- 预计输出数量
+    pub fn out_amount(&self) -> u64 {
+        self.out_amount.parse().unwrap_or(0)
+    }
+
+    /// -- 考虑滑点后的最少输出数量
+    pub fn min_out_amount(&self) -> u64 {
+        self.other_amount_threshold.parse().unwrap_or(0)
+    }
+
+    /// -- 价格影响（百分比）
+    pub fn price_impact(&self) -> f64 {
+        self.price_impact_pct.parse::<f64>().unwrap_or(0.0) * 100.0
+    }
+
+    /// -- 路由描述，如 "Raydium -> Orca"
+    pub fn route_labels(&self) -> String {
+        self.route_plan
+            .iter()
+            .map(|plan| {
+                plan.swap_info
+                    .label
+                    .clone()
+                    .unwrap_or_else(|| "unknown".to_string())
+            })
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+/// -- 优先费设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriorityFee {
+    /// 不设置优先费
+    None,
+    /// 由 Jupiter 自动估算
+    #[default]
+    Auto,
+    /// 固定优先费（lamports）
+    Lamports(u64),
+}
+
+/// -- 交换执行选项
+#[derive(Debug, Clone)]
+pub struct SwapOptions {
+    pub priority_fee: PriorityFee,        // -- 优先费
+    pub wrap_and_unwrap_sol: bool,        // -- 是否自动包装/解包 SOL
+    pub dynamic_compute_unit_limit: bool, // -- 是否按模拟结果设置 CU 上限
+}
+
+impl Default for SwapOptions {
+    fn default() -> Self {
+        Self {
+            priority_fee: PriorityFee::Auto,
+            wrap_and_unwrap_sol: true,
+            dynamic_compute_unit_limit: true,
+        }
+    }
+}
+
+/// -- `/swap-instructions` 返回的账户元信息
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiAccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+/// -- `/swap-instructions` 返回的指令
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiInstruction {
+    pub program_id: String,
+    pub accounts: Vec<ApiAccountMeta>,
+    pub data: String,
+}
+
+/// -- `/swap-instructions` 响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapInstructionsResponse {
+    pub token_ledger_instruction: Option<ApiInstruction>,
+    #[serde(default)]
+    pub compute_budget_instructions: Vec<ApiInstruction>,
+    #[serde(default)]
+    pub setup_instructions: Vec<ApiInstruction>,
+    pub swap_instruction: ApiInstruction,
+    pub cleanup_instruction: Option<ApiInstruction>,
+    #[serde(default)]
+    pub address_lookup_table_addresses: Vec<String>,
+}
+
+/// -- 交换执行结果
+#[derive(Debug, Clone)]
+pub struct SwapResult {
+    pub signature: String,   // -- 交易签名
+    pub in_amount: u64,      // -- 输入数量
+    pub out_amount: u64,     // -- 预计输出数量
+    pub min_out_amount: u64, // -- 最少输出数量
+    pub route: String,       // -- 路由描述
+}
@@ -0,0 +1,85 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use tracing::{debug, warn};
+
+use crate::{JupiterError, JupiterResult};
+
+/// -- 默认 Jupiter 价格 API 地址
+pub const DEFAULT_PRICE_API_URL: &str = "https://api.jup.ag/price/v2";
+
+/// -- 每次请求最多查询的 Mint 数量
+const MAX_IDS_PER_REQUEST: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct PriceResponse {
+    data: HashMap<String, Option<PriceData>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PriceData {
+    price: String,
+}
+
+/// -- 价格来源
+///
+/// 以 USD 计价返回代币单价，查询不到价格的 Mint 不会出现在结果中
+#[allow(async_fn_in_trait)]
+pub trait PriceSource {
+    async fn get_prices(&self, mints: &[String]) -> JupiterResult<HashMap<String, f64>>;
+}
+
+/// -- Jupiter 价格客户端
+pub struct JupiterPriceClient {
+    http: reqwest::Client,
+    api_url: String,
+}
+
+impl Default for JupiterPriceClient {
+    fn default() -> Self {
+        Self::new(DEFAULT_PRICE_API_URL)
+    }
+}
+
+impl JupiterPriceClient {
+    /// -- 使用指定 API 地址创建客户端
+    pub fn new(api_url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            api_url: api_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl PriceSource for JupiterPriceClient {
+    async fn get_prices(&self, mints: &[String]) -> JupiterResult<HashMap<String, f64>> {
+        let mut prices = HashMap::new();
+
+        for chunk in mints.chunks(MAX_IDS_PER_REQUEST) {
+            let response = self
+                .http
+                .get(&self.api_url)
+                .query(&[("ids", chunk.join(","))])
+                .send()
+                .await?;
+
+            if !response.status().is_success() {
+                let body = response.text().await.unwrap_or_default();
+                return Err(JupiterError::ApiError(body));
+            }
+
+            let body: PriceResponse = response.json().await?;
+            for (mint, data) in body.data {
+                match data.and_then(|d| d.price.parse::<f64>().ok()) {
+                    Some(price) => {
+                        prices.insert(mint, price);
+                    }
+                    None => warn!("未查询到价格: {}", mint),
+                }
+            }
+        }
+
+        debug!("查询到 {} 个代币价格", prices.len());
+
+        Ok(prices)
+    }
+}
@@ -0,0 +1,180 @@
+use anyhow::{Context, Result};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    system_instruction,
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use spl_token::{instruction as token_instruction, state::Mint};
+use std::{
+    env, fs,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// -- 测试钱包
+///
+/// 密钥按 `TokenAccountManager` 要求的格式（base58 字符串的 JSON）写入临时文件
+pub struct TestWallet {
+    pub keypair: Keypair, // -- 钱包密钥对
+    pub path: PathBuf,    // -- 密钥文件路径
+}
+
+impl TestWallet {
+    /// -- 创建新钱包并通过空投注资
+    ///
+    /// # 参数
+    /// * `client` - 本地节点 RPC 客户端
+    /// * `sol` - 空投数量（SOL）
+    pub fn funded(client: &RpcClient, sol: u64) -> Result<Self> {
+        let keypair = Keypair::new();
+        let path = env::temp_dir().join(format!("solana-use-wallet-{}.json", keypair.pubkey()));
+        fs::write(&path, serde_json::to_string(&keypair.to_base58_string())?)?;
+
+        let signature = client.request_airdrop(&keypair.pubkey(), sol * LAMPORTS_PER_SOL)?;
+        wait_for_signature(client, &signature)?;
+
+        Ok(Self { keypair, path })
+    }
+
+    /// -- 钱包地址
+    pub fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    /// -- 密钥文件路径字符串
+    pub fn path_str(&self) -> &str {
+        self.path.to_str().expect("临时目录路径不是有效的 UTF-8")
+    }
+}
+
+impl Drop for TestWallet {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// -- 创建测试 Mint，钱包为铸币和冻结权限
+///
+/// # 参数
+/// * `client` - 本地节点 RPC 客户端
+/// * `payer` - 支付者和权限账户
+/// * `decimals` - 代币精度
+pub fn create_mint(client: &RpcClient, payer: &Keypair, decimals: u8) -> Result<Pubkey> {
+    let mint = Keypair::new();
+    let rent = client.get_minimum_balance_for_rent_exemption(Mint::LEN)?;
+
+    let instructions = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            rent,
+            Mint::LEN as u64,
+            &spl_token::id(),
+        ),
+        token_instruction::initialize_mint2(
+            &spl_token::id(),
+            &mint.pubkey(),
+            &payer.pubkey(),
+            Some(&payer.pubkey()),
+            decimals,
+        )?,
+    ];
+    send(client, payer, &instructions, &[&mint])?;
+
+    Ok(mint.pubkey())
+}
+
+/// -- 为钱包创建 ATA，并可选铸造代币
+///
+/// # 参数
+/// * `client` - 本地节点 RPC 客户端
+/// * `owner` - 钱包（同时为 Mint 权限）
+/// * `mint` - 代币 Mint
+/// * `amount` - 铸造数量，0 表示创建空账户
+pub fn create_token_account(
+    client: &RpcClient,
+    owner: &Keypair,
+    mint: &Pubkey,
+    amount: u64,
+) -> Result<Pubkey> {
+    let ata = get_associated_token_address(&owner.pubkey(), mint);
+    let mut instructions = vec![create_associated_token_account(
+        &owner.pubkey(),
+        &owner.pubkey(),
+        mint,
+        &spl_token::id(),
+    )];
+    if amount > 0 {
+        instructions.push(token_instruction::mint_to(
+            &spl_token::id(),
+            mint,
+            &ata,
+            &owner.pubkey(),
+            &[],
+            amount,
+        )?);
+    }
+    send(client, owner, &instructions, &[])?;
+
+    Ok(ata)
+}
+
+/// -- 批量创建空代币账户（每个账户对应一个新 Mint）
+///
+/// # 参数
+/// * `client` - 本地节点 RPC 客户端
+/// * `owner` - 钱包
+/// * `count` - 账户数量
+pub fn create_empty_token_accounts(
+    client: &RpcClient,
+    owner: &Keypair,
+    count: usize,
+) -> Result<Vec<Pubkey>> {
+    (0..count)
+        .map(|_| {
+            let mint = create_mint(client, owner, 6)?;
+            create_token_account(client, owner, &mint, 0)
+        })
+        .collect()
+}
+
+/// -- 签名并发送交易
+fn send(
+    client: &RpcClient,
+    payer: &Keypair,
+    instructions: &[solana_sdk::instruction::Instruction],
+    extra_signers: &[&Keypair],
+) -> Result<Signature> {
+    let mut signers = vec![payer];
+    signers.extend_from_slice(extra_signers);
+
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&payer.pubkey()),
+        &signers,
+        client.get_latest_blockhash()?,
+    );
+    client
+        .send_and_confirm_transaction(&transaction)
+        .context("发送测试交易失败")
+}
+
+/// -- 等待交易确认
+fn wait_for_signature(client: &RpcClient, signature: &Signature) -> Result<()> {
+    let started = Instant::now();
+    while started.elapsed() < Duration::from_secs(30) {
+        if client.confirm_transaction(signature)? {
+            return Ok(());
+        }
+        std::thread::sleep(Duration::from_millis(300));
+    }
+    anyhow::bail!("交易确认超时: {}", signature)
+}
@@ -0,0 +1,22 @@
+//! # 本地集成测试工具
+//!
+//! 启动 `solana-test-validator`（克隆 Token Metadata 程序），为测试钱包空投 SOL，
+//! 创建测试 Mint 和代币账户，用于在真实节点上端到端验证关闭、销毁流程，
+//! 而不必依赖主网试运行。
+//!
+//! ```no_run
+//! use localnet_harness::{create_empty_token_accounts, LocalValidator, TestWallet};
+//!
+//! let validator = LocalValidator::start()?;
+//! validator.export_rpc_url();
+//!
+//! let client = validator.rpc_client();
+//! let wallet = TestWallet::funded(&client, 10)?;
+//! create_empty_token_accounts(&client, &wallet.keypair, 3)?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+pub mod fixtures;
+pub mod validator;
+
+pub use fixtures::*;
+pub use validator::*;
@@ -0,0 +1,154 @@
+use anyhow::{bail, Context, Result};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::{
+    env,
+    net::TcpListener,
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+/// -- Metaplex Token Metadata 程序 ID
+pub const TOKEN_METADATA_PROGRAM_ID: &str = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
+
+/// -- 本地验证节点配置
+#[derive(Debug, Clone)]
+pub struct LocalValidatorConfig {
+    /// `solana-test-validator` 可执行文件路径
+    pub binary: String,
+    /// 从该集群克隆程序，如 mainnet-beta；为空时不克隆
+    pub clone_url: Option<String>,
+    /// 需要克隆的可升级程序（默认包含 Token Metadata，零值账户判断依赖元数据）
+    pub clone_programs: Vec<String>,
+    /// 等待节点就绪的超时时间
+    pub startup_timeout: Duration,
+}
+
+impl Default for LocalValidatorConfig {
+    fn default() -> Self {
+        Self {
+            binary: env::var("SOLANA_TEST_VALIDATOR")
+                .unwrap_or_else(|_| "solana-test-validator".to_string()),
+            clone_url: Some("https://api.mainnet-beta.solana.com".to_string()),
+            clone_programs: vec![TOKEN_METADATA_PROGRAM_ID.to_string()],
+            startup_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// -- 本地验证节点
+///
+/// 启动独立账本目录和随机端口的 `solana-test-validator`，离开作用域时自动关闭并清理账本
+pub struct LocalValidator {
+    process: Child,
+    ledger_dir: PathBuf,
+    rpc_url: String,
+}
+
+impl LocalValidator {
+    /// -- 使用默认配置启动本地验证节点
+    pub fn start() -> Result<Self> {
+        Self::start_with_config(LocalValidatorConfig::default())
+    }
+
+    /// -- 使用自定义配置启动本地验证节点
+    ///
+    /// # 参数
+    /// * `config` - 本地验证节点配置
+    pub fn start_with_config(config: LocalValidatorConfig) -> Result<Self> {
+        let rpc_port = free_port()?;
+        let faucet_port = free_port()?;
+        let ledger_dir = env::temp_dir().join(format!(
+            "solana-use-ledger-{}-{}",
+            std::process::id(),
+            rpc_port
+        ));
+
+        let mut command = Command::new(&config.binary);
+        command
+            .arg("--reset")
+            .arg("--quiet")
+            .arg("--ledger")
+            .arg(&ledger_dir)
+            .arg("--rpc-port")
+            .arg(rpc_port.to_string())
+            .arg("--faucet-port")
+            .arg(faucet_port.to_string())
+            // -- 动态端口范围避免与其他并行运行的节点冲突
+            .arg("--dynamic-port-range")
+            .arg(format!("{}-{}", rpc_port + 2, rpc_port + 30))
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        if let Some(url) = &config.clone_url {
+            command.arg("--url").arg(url);
+            for program in &config.clone_programs {
+                command.arg("--clone-upgradeable-program").arg(program);
+            }
+        }
+
+        let process = command
+            .spawn()
+            .with_context(|| format!("启动 {} 失败，请确认已安装 Solana CLI", config.binary))?;
+
+        let validator = Self {
+            process,
+            ledger_dir,
+            rpc_url: format!("http://127.0.0.1:{}", rpc_port),
+        };
+        validator.wait_until_ready(config.startup_timeout)?;
+        info!("本地验证节点已就绪: {}", validator.rpc_url);
+
+        Ok(validator)
+    }
+
+    /// -- RPC 地址
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+
+    /// -- 创建连接到本地节点的 RPC 客户端
+    pub fn rpc_client(&self) -> RpcClient {
+        RpcClient::new_with_commitment(self.rpc_url.clone(), CommitmentConfig::confirmed())
+    }
+
+    /// -- 将 `RPC_URL` 指向本地节点
+    ///
+    /// `TokenAccountManager` 通过该环境变量创建 RPC 客户端。
+    /// 环境变量是进程级的，同一进程内的多个测试需要串行执行。
+    pub fn export_rpc_url(&self) {
+        env::set_var("RPC_URL", &self.rpc_url);
+    }
+
+    fn wait_until_ready(&self, timeout: Duration) -> Result<()> {
+        let client = self.rpc_client();
+        let started = Instant::now();
+
+        while started.elapsed() < timeout {
+            if client.get_health().is_ok() && client.get_slot().map(|s| s > 0).unwrap_or(false) {
+                return Ok(());
+            }
+            std::thread::sleep(Duration::from_millis(500));
+        }
+
+        bail!("本地验证节点在 {:?} 内未就绪", timeout)
+    }
+}
+
+impl Drop for LocalValidator {
+    fn drop(&mut self) {
+        if let Err(e) = self.process.kill() {
+            warn!("关闭本地验证节点失败: {}", e);
+        }
+        let _ = self.process.wait();
+        let _ = std::fs::remove_dir_all(&self.ledger_dir);
+    }
+}
+
+/// -- 获取一个空闲端口
+fn free_port() -> Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    Ok(listener.local_addr()?.port())
+}
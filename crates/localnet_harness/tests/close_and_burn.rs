@@ -0,0 +1,89 @@
+//! 需要本地安装 `solana-test-validator`，以及访问主网以克隆 Token Metadata 程序:
+//!
+//! ```bash
+//! cargo test -p localnet_harness -- --ignored --test-threads=1
+//! ```
+use localnet_harness::{create_empty_token_accounts, LocalValidator, TestWallet};
+use solana_toolkits::TokenAccountManager;
+
+#[tokio::test]
+#[ignore = "需要 solana-test-validator"]
+async fn closes_empty_token_accounts() -> anyhow::Result<()> {
+    let validator = LocalValidator::start()?;
+    validator.export_rpc_url();
+
+    let client = validator.rpc_client();
+    let wallet = TestWallet::funded(&client, 10)?;
+    create_empty_token_accounts(&client, &wallet.keypair, 3)?;
+
+    let manager = TokenAccountManager::new(wallet.path_str())?;
+    let before = manager.get_closeable_accounts().await?;
+    assert_eq!(before.closable_accounts, 3);
+
+    manager
+        .batch_close_accounts(&before.accounts, 5, true)
+        .await?;
+
+    let after = manager.get_closeable_accounts().await?;
+    assert_eq!(after.total_accounts, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "需要 solana-test-validator"]
+async fn closes_single_account_and_recovers_rent() -> anyhow::Result<()> {
+    let validator = LocalValidator::start()?;
+    validator.export_rpc_url();
+
+    let client = validator.rpc_client();
+    let wallet = TestWallet::funded(&client, 10)?;
+    let accounts = create_empty_token_accounts(&client, &wallet.keypair, 1)?;
+
+    let manager = TokenAccountManager::new(wallet.path_str())?;
+    let result = manager.close_account(&accounts[0]).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(result.rent_recovered > 0.0);
+    assert!(client.get_account(&accounts[0]).is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "需要 solana-test-validator"]
+async fn burns_and_closes_zero_value_accounts() -> anyhow::Result<()> {
+    use solana_toolkits::token_creation::CreateTokenParams;
+
+    let validator = LocalValidator::start()?;
+    validator.export_rpc_url();
+
+    let client = validator.rpc_client();
+    let wallet = TestWallet::funded(&client, 10)?;
+    let manager = TokenAccountManager::new(wallet.path_str())?;
+
+    // -- 创建带元数据的测试代币，持有余额的账户会被识别为零值代币账户
+    manager
+        .create_token(&CreateTokenParams {
+            name: "Fixture".to_string(),
+            symbol: "FIX".to_string(),
+            uri: String::new(),
+            decimals: 6,
+            initial_supply: 1_000_000,
+            ..Default::default()
+        })
+        .await?;
+
+    let before = manager.get_closeable_accounts().await?;
+    assert_eq!(before.zero_value_accounts, 1);
+
+    let address = before.zero_value_accounts_list[0].address.parse()?;
+    let result = manager.burn_and_close_account(&address).await;
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.burned_amount, 1_000_000);
+
+    let after = manager.get_closeable_accounts().await?;
+    assert_eq!(after.total_accounts, 0);
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! # 原生程序 IDL 描述
+//!
+//! 为不使用 Anchor 的原生 Solana 程序生成与 Anchor/Shank 兼容的 IDL，
+//! 指令使用单字节判别符（而不是 Anchor 的 8 字节哈希），因此在 `metadata.origin`
+//! 中标记为 `native`，方便 Kinobi/Solita 等工具识别。
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, io, path::Path};
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+/// -- IDL 根结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Idl {
+    pub version: String,
+    pub name: String,
+    pub instructions: Vec<IdlInstruction>,
+    pub accounts: Vec<IdlTypeDef>,
+    pub types: Vec<IdlTypeDef>,
+    pub errors: Vec<IdlErrorCode>,
+    pub metadata: IdlMetadata,
+}
+
+/// -- IDL 元数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdlMetadata {
+    pub origin: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+/// -- 指令描述
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub accounts: Vec<IdlAccountItem>,
+    pub args: Vec<IdlField>,
+    pub discriminant: IdlDiscriminant,
+}
+
+/// -- 指令判别符（原生程序使用首字节区分指令）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdlDiscriminant {
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+    pub value: u8,
+}
+
+/// -- 指令所需账户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlAccountItem {
+    pub name: String,
+    pub is_mut: bool,
+    pub is_signer: bool,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_optional: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub desc: Option<String>,
+}
+
+/// -- 字段描述
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdlField {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlType,
+}
+
+/// -- 账户或自定义类型定义
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdlTypeDef {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub ty: IdlTypeDefTy,
+}
+
+/// -- 类型定义主体，目前只需要结构体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum IdlTypeDefTy {
+    Struct { fields: Vec<IdlField> },
+}
+
+/// -- Borsh 基础类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IdlType {
+    Bool,
+    U8,
+    U32,
+    U64,
+    I64,
+    String,
+    PublicKey,
+}
+
+/// -- 自定义错误码
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdlErrorCode {
+    pub code: u32,
+    pub name: String,
+    pub msg: String,
+}
+
+impl Idl {
+    /// -- 创建空 IDL
+    pub fn new(name: &str, version: &str) -> Self {
+        Self {
+            version: version.to_string(),
+            name: name.to_string(),
+            instructions: Vec::new(),
+            accounts: Vec::new(),
+            types: Vec::new(),
+            errors: Vec::new(),
+            metadata: IdlMetadata {
+                origin: "native".to_string(),
+                address: None,
+            },
+        }
+    }
+
+    /// -- 设置部署地址
+    pub fn address(mut self, address: &str) -> Self {
+        self.metadata.address = Some(address.to_string());
+        self
+    }
+
+    /// -- 添加指令，`value` 为 `unpack` 匹配的首字节判别符
+    pub fn instruction(
+        mut self,
+        value: u8,
+        name: &str,
+        accounts: Vec<IdlAccountItem>,
+        args: Vec<IdlField>,
+    ) -> Self {
+        self.instructions.push(IdlInstruction {
+            name: name.to_string(),
+            accounts,
+            args,
+            discriminant: IdlDiscriminant {
+                ty: IdlType::U8,
+                value,
+            },
+        });
+        self
+    }
+
+    /// -- 添加账户状态结构
+    pub fn account(mut self, name: &str, fields: Vec<IdlField>) -> Self {
+        self.accounts.push(IdlTypeDef {
+            name: name.to_string(),
+            ty: IdlTypeDefTy::Struct { fields },
+        });
+        self
+    }
+
+    /// -- 从错误枚举添加错误码
+    ///
+    /// 错误码取 `code(e)`（与 `ProgramError::Custom(e as u32)` 一致），名称取 `Debug` 输出，
+    /// 说明取 `Display` 输出。
+    pub fn errors<E, I, F>(mut self, errors: I, code: F) -> Self
+    where
+        E: fmt::Debug + fmt::Display,
+        I: IntoIterator<Item = E>,
+        F: Fn(&E) -> u32,
+    {
+        for e in errors {
+            self.errors.push(IdlErrorCode {
+                code: code(&e),
+                name: format!("{:?}", e),
+                msg: e.to_string(),
+            });
+        }
+        self
+    }
+
+    /// -- 序列化为格式化 JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// -- 写入 IDL 文件，自动创建父目录
+    pub fn write_to(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = self.to_json().map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+}
+
+/// -- 构造账户项
+pub fn account(name: &str, is_mut: bool, is_signer: bool) -> IdlAccountItem {
+    IdlAccountItem {
+        name: name.to_string(),
+        is_mut,
+        is_signer,
+        is_optional: false,
+        desc: None,
+    }
+}
+
+/// -- 构造可选账户项，只能放在指令账户列表末尾，`desc` 说明何时需要传入
+pub fn optional_account(name: &str, is_mut: bool, is_signer: bool, desc: &str) -> IdlAccountItem {
+    IdlAccountItem {
+        is_optional: true,
+        desc: Some(desc.to_string()),
+        ..account(name, is_mut, is_signer)
+    }
+}
+
+/// -- 构造字段
+pub fn field(name: &str, ty: IdlType) -> IdlField {
+    IdlField {
+        name: name.to_string(),
+        ty,
+    }
+}
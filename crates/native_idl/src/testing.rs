@@ -0,0 +1,165 @@
+//! # IDL 一致性测试辅助
+//!
+//! 按 IDL 字段列表生成样例值并编码为 Borsh 字节，程序的测试用同样的样例值构造 Rust
+//! 结构体后比较序列化结果，字段名、顺序或类型与 IDL 不一致时测试失败。
+use crate::{Idl, IdlField, IdlInstruction, IdlType, IdlTypeDefTy};
+
+/// -- 单个字段的样例值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sample {
+    Bool(bool),
+    U8(u8),
+    U32(u32),
+    U64(u64),
+    I64(i64),
+    String(String),
+    PublicKey([u8; 32]),
+}
+
+impl Sample {
+    /// -- 按字段生成样例值，不同位置的字段取不同的值，便于发现顺序错误
+    fn new(index: usize, field: &IdlField) -> Self {
+        let n = index as u8 + 1;
+        match field.ty {
+            IdlType::Bool => Sample::Bool(index.is_multiple_of(2)),
+            IdlType::U8 => Sample::U8(n),
+            IdlType::U32 => Sample::U32(n as u32 * 1_000),
+            IdlType::U64 => Sample::U64(n as u64 * 1_000_000),
+            IdlType::I64 => Sample::I64(-(n as i64) * 1_000_000),
+            IdlType::String => Sample::String(format!("{}-{}", field.name, n)),
+            IdlType::PublicKey => Sample::PublicKey([n; 32]),
+        }
+    }
+
+    /// -- 按 Borsh 规则编码
+    fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            Sample::Bool(v) => out.push(*v as u8),
+            Sample::U8(v) => out.push(*v),
+            Sample::U32(v) => out.extend_from_slice(&v.to_le_bytes()),
+            Sample::U64(v) => out.extend_from_slice(&v.to_le_bytes()),
+            Sample::I64(v) => out.extend_from_slice(&v.to_le_bytes()),
+            Sample::String(v) => {
+                out.extend_from_slice(&(v.len() as u32).to_le_bytes());
+                out.extend_from_slice(v.as_bytes());
+            }
+            Sample::PublicKey(v) => out.extend_from_slice(v),
+        }
+    }
+}
+
+/// -- 一组字段的样例值，按 IDL 字段顺序排列
+#[derive(Debug, Clone)]
+pub struct Samples {
+    values: Vec<(String, Sample)>, // 字段名和样例值
+}
+
+impl Samples {
+    /// -- 为字段列表生成样例值
+    pub fn new(fields: &[IdlField]) -> Self {
+        let values = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (field.name.clone(), Sample::new(i, field)))
+            .collect();
+        Self { values }
+    }
+
+    /// -- 为账户结构生成样例值，账户不存在时 panic
+    pub fn account(idl: &Idl, name: &str) -> Self {
+        let def = idl
+            .accounts
+            .iter()
+            .find(|def| def.name == name)
+            .unwrap_or_else(|| panic!("IDL 中缺少账户 {}", name));
+        match &def.ty {
+            IdlTypeDefTy::Struct { fields } => Self::new(fields),
+        }
+    }
+
+    /// -- 为指令参数生成样例值
+    pub fn args(instruction: &IdlInstruction) -> Self {
+        Self::new(&instruction.args)
+    }
+
+    /// -- 按字段顺序编码全部样例值
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for (_, sample) in &self.values {
+            sample.encode(&mut out);
+        }
+        out
+    }
+
+    /// -- 指令数据：判别符加参数编码
+    pub fn instruction_data(&self, instruction: &IdlInstruction) -> Vec<u8> {
+        let mut data = vec![instruction.discriminant.value];
+        data.extend(self.encode());
+        data
+    }
+
+    /// -- 字段的样例值，字段不存在时 panic
+    pub fn get(&self, name: &str) -> &Sample {
+        self.values
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, sample)| sample)
+            .unwrap_or_else(|| panic!("IDL 中缺少字段 {}", name))
+    }
+
+    /// -- 取 bool 字段，字段不存在或类型不符时 panic
+    pub fn bool(&self, name: &str) -> bool {
+        match self.get(name) {
+            Sample::Bool(v) => *v,
+            other => panic!("字段 {} 应为 bool，IDL 中为 {:?}", name, other),
+        }
+    }
+
+    /// -- 取 u8 字段，字段不存在或类型不符时 panic
+    pub fn u8(&self, name: &str) -> u8 {
+        match self.get(name) {
+            Sample::U8(v) => *v,
+            other => panic!("字段 {} 应为 u8，IDL 中为 {:?}", name, other),
+        }
+    }
+
+    /// -- 取 u32 字段，字段不存在或类型不符时 panic
+    pub fn u32(&self, name: &str) -> u32 {
+        match self.get(name) {
+            Sample::U32(v) => *v,
+            other => panic!("字段 {} 应为 u32，IDL 中为 {:?}", name, other),
+        }
+    }
+
+    /// -- 取 u64 字段，字段不存在或类型不符时 panic
+    pub fn u64(&self, name: &str) -> u64 {
+        match self.get(name) {
+            Sample::U64(v) => *v,
+            other => panic!("字段 {} 应为 u64，IDL 中为 {:?}", name, other),
+        }
+    }
+
+    /// -- 取 i64 字段，字段不存在或类型不符时 panic
+    pub fn i64(&self, name: &str) -> i64 {
+        match self.get(name) {
+            Sample::I64(v) => *v,
+            other => panic!("字段 {} 应为 i64，IDL 中为 {:?}", name, other),
+        }
+    }
+
+    /// -- 取字符串字段，字段不存在或类型不符时 panic
+    pub fn string(&self, name: &str) -> String {
+        match self.get(name) {
+            Sample::String(v) => v.clone(),
+            other => panic!("字段 {} 应为 string，IDL 中为 {:?}", name, other),
+        }
+    }
+
+    /// -- 取公钥字段的原始字节，字段不存在或类型不符时 panic
+    pub fn pubkey_bytes(&self, name: &str) -> [u8; 32] {
+        match self.get(name) {
+            Sample::PublicKey(v) => *v,
+            other => panic!("字段 {} 应为 publicKey，IDL 中为 {:?}", name, other),
+        }
+    }
+}
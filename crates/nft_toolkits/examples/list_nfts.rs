@@ -0,0 +1,26 @@
+use anyhow::Result;
+use nft_toolkits::NftManager;
+use std::error::Error;
+use utils::{init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    // -- 初始化 NFT 管理器，RPC_URL 需要支持 DAS 接口
+    let wallet_path = std::env::var("WALLET_PATH")?;
+    let mut manager = NftManager::new(&wallet_path)?;
+
+    // -- 保护指定集合不被销毁
+    if let Ok(collections) = std::env::var("PROTECTED_COLLECTIONS") {
+        let collections: Vec<&str> = collections.split(',').collect();
+        manager.whitelist_mut().add_collections(&collections);
+    }
+
+    // -- 列出所有 NFT
+    manager.list_nfts().await?;
+
+    Ok(())
+}
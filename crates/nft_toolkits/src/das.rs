@@ -0,0 +1,156 @@
+use crate::{NftError, NftResult};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+/// -- 每页资产数量（DAS 上限为 1000）
+const PAGE_LIMIT: u32 = 1000;
+
+/// -- DAS 资产
+#[derive(Debug, Clone, Deserialize)]
+pub struct DasAsset {
+    pub id: String,
+    pub interface: String,
+    #[serde(default)]
+    pub content: Option<DasContent>,
+    #[serde(default)]
+    pub grouping: Vec<DasGrouping>,
+    #[serde(default)]
+    pub compression: Option<DasCompression>,
+    #[serde(default)]
+    pub ownership: Option<DasOwnership>,
+    #[serde(default)]
+    pub token_info: Option<DasTokenInfo>,
+    #[serde(default)]
+    pub burnt: bool,
+}
+
+/// -- DAS 资产内容
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DasContent {
+    pub json_uri: String,
+    pub metadata: Option<DasMetadata>,
+}
+
+/// -- DAS 元数据
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DasMetadata {
+    pub name: String,
+    pub symbol: String,
+}
+
+/// -- DAS 分组
+#[derive(Debug, Clone, Deserialize)]
+pub struct DasGrouping {
+    pub group_key: String,
+    pub group_value: String,
+}
+
+/// -- DAS 压缩信息
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DasCompression {
+    pub compressed: bool,
+}
+
+/// -- DAS 所有权信息
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DasOwnership {
+    pub owner: String,
+    pub frozen: bool,
+}
+
+/// -- DAS 代币信息
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DasTokenInfo {
+    pub token_program: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DasPage {
+    total: u32,
+    items: Vec<DasAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DasResponse {
+    result: Option<DasPage>,
+    error: Option<serde_json::Value>,
+}
+
+/// -- DAS 接口客户端
+///
+/// 需要支持 DAS API 的 RPC 节点（如 Helius、Triton）
+pub struct DasClient {
+    http: reqwest::Client,
+    rpc_url: String,
+}
+
+impl DasClient {
+    /// -- 创建 DAS 客户端
+    ///
+    /// # 参数
+    /// * `rpc_url` - 支持 DAS 的 RPC 地址
+    pub fn new(rpc_url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            rpc_url: rpc_url.to_string(),
+        }
+    }
+
+    /// -- 分页获取钱包持有的所有资产
+    ///
+    /// # 参数
+    /// * `owner` - 钱包地址
+    ///
+    /// # 返回
+    /// * `NftResult<Vec<DasAsset>>` - 资产列表（不含已销毁资产）
+    pub async fn get_assets_by_owner(&self, owner: &str) -> NftResult<Vec<DasAsset>> {
+        let mut assets = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let body = json!({
+                "jsonrpc": "2.0",
+                "id": "nft_toolkits",
+                "method": "getAssetsByOwner",
+                "params": {
+                    "ownerAddress": owner,
+                    "page": page,
+                    "limit": PAGE_LIMIT,
+                },
+            });
+
+            let response: DasResponse = self
+                .http
+                .post(&self.rpc_url)
+                .json(&body)
+                .send()
+                .await?
+                .json()
+                .await?;
+
+            if let Some(error) = response.error {
+                return Err(NftError::DasError(error.to_string()));
+            }
+            let result = response
+                .result
+                .ok_or_else(|| NftError::DasError("响应缺少 result 字段".to_string()))?;
+
+            debug!("DAS 第 {} 页: {} 个资产", page, result.items.len());
+            let count = result.items.len() as u32;
+            assets.extend(result.items.into_iter().filter(|a| !a.burnt));
+
+            if count < PAGE_LIMIT || result.total < PAGE_LIMIT {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(assets)
+    }
+}
@@ -0,0 +1,48 @@
+use thiserror::Error;
+
+/// -- NFT 管理错误类型
+#[derive(Debug, Error)]
+pub enum NftError {
+    /// RPC 客户端错误
+    #[error("RPC 错误: {0}")]
+    RpcError(#[from] solana_client::client_error::ClientError),
+
+    /// DAS 接口 HTTP 请求错误
+    #[error("HTTP 请求错误: {0}")]
+    HttpError(#[from] reqwest::Error),
+
+    /// DAS 接口返回错误
+    #[error("DAS 接口错误: {0}")]
+    DasError(String),
+
+    /// 密钥格式无效
+    #[error("无效的密钥格式")]
+    InvalidKeyFormat,
+
+    /// 账户数据解析错误
+    #[error("账户解析错误: {0}")]
+    AccountParseError(String),
+
+    /// NFT 受白名单保护
+    #[error("NFT 受白名单保护: {0}")]
+    Protected(String),
+
+    /// 不支持的操作（如压缩 NFT 的销毁和转移）
+    #[error("不支持的操作: {0}")]
+    Unsupported(String),
+
+    /// 交易执行错误
+    #[error("交易错误: {0}")]
+    TransactionError(String),
+
+    /// IO 操作错误
+    #[error("IO 错误: {0}")]
+    IoError(#[from] std::io::Error),
+
+    /// JSON 解析错误
+    #[error("JSON 解析错误: {0}")]
+    JsonError(#[from] serde_json::Error),
+}
+
+/// -- 自定义 Result 类型
+pub type NftResult<T> = Result<T, NftError>;
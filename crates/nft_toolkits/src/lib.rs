@@ -0,0 +1,330 @@
+//! # NFT 管理工具
+//!
+//! 通过 DAS 接口列出钱包持有的 NFT 和压缩 NFT，销毁无价值的 NFT 回收元数据租金，
+//! 并支持转移指定 NFT。与代币账户工具一致，使用白名单保护指定集合不被误删。
+use das::DasClient;
+use mpl_token_metadata::{
+    accounts::{MasterEdition, Metadata, TokenRecord},
+    instructions::{BurnV1Builder, TransferV1Builder},
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Keypair,
+    signer::Signer, transaction::Transaction,
+};
+use solana_toolkits::config::TokenAccountConfig;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use std::{fs::read_to_string, str::FromStr};
+use tracing::{error, info, warn};
+use utils::{init_rpc_client, RpcApi};
+use whitelist::NftWhitelist;
+
+pub mod das;
+mod error;
+pub mod model;
+pub mod whitelist;
+
+pub use error::*;
+pub use model::*;
+
+/// -- NFT 管理器
+///
+/// 提供 NFT 的查询、销毁和转移功能，支持按集合设置白名单
+pub struct NftManager {
+    /// RPC 客户端连接
+    pub connection: RpcClient,
+    /// 钱包密钥对
+    pub wallet: Keypair,
+    /// DAS 接口客户端
+    das: DasClient,
+    /// NFT 白名单
+    whitelist: NftWhitelist,
+    /// 管理器配置
+    config: TokenAccountConfig,
+}
+
+impl NftManager {
+    /// -- 创建新的 NFT 管理器实例
+    ///
+    /// DAS 请求使用与 RPC 客户端相同的 `RPC_URL`，需要节点支持 DAS 接口。
+    ///
+    /// # 参数
+    /// * `wallet_key_path` - 钱包密钥文件路径
+    pub fn new(wallet_key_path: &str) -> NftResult<Self> {
+        Self::with_config(wallet_key_path, TokenAccountConfig::default())
+    }
+
+    /// -- 使用自定义配置创建 NFT 管理器实例
+    ///
+    /// # 参数
+    /// * `wallet_key_path` - 钱包密钥文件路径
+    /// * `config` - 自定义配置参数
+    pub fn with_config(wallet_key_path: &str, config: TokenAccountConfig) -> NftResult<Self> {
+        let connection = init_rpc_client(config.commitment)?;
+        let das = DasClient::new(&connection.url());
+
+        let key_str = read_to_string(wallet_key_path)?;
+        let key_value: serde_json::Value = serde_json::from_str(&key_str)?;
+        let private_key = key_value.as_str().ok_or(NftError::InvalidKeyFormat)?;
+
+        let wallet = Keypair::from_base58_string(private_key);
+
+        Ok(Self {
+            connection,
+            wallet,
+            das,
+            whitelist: NftWhitelist::new(),
+            config,
+        })
+    }
+
+    /// -- 获取白名单的可变引用，用于添加受保护的集合或 NFT
+    pub fn whitelist_mut(&mut self) -> &mut NftWhitelist {
+        &mut self.whitelist
+    }
+
+    /// -- 检查 NFT 是否受白名单保护
+    pub fn is_protected(&self, nft: &NftInfo) -> bool {
+        self.whitelist.is_protected(nft)
+    }
+
+    /// -- 获取钱包持有的所有 NFT
+    ///
+    /// # 返回
+    /// * `NftResult<NftListResult>` - NFT 列表及统计信息
+    pub async fn list_nfts(&self) -> NftResult<NftListResult> {
+        let assets = self
+            .das
+            .get_assets_by_owner(&self.wallet.pubkey().to_string())
+            .await?;
+
+        let nfts: Vec<NftInfo> = assets.iter().filter_map(NftInfo::from_asset).collect();
+        let result = NftListResult {
+            total: nfts.len(),
+            compressed: nfts.iter().filter(|n| n.compressed).count(),
+            protected: nfts.iter().filter(|n| self.is_protected(n)).count(),
+            nfts,
+        };
+
+        info!("{}", "=".repeat(50));
+        info!("NFT 统计");
+        info!("{}", "=".repeat(50));
+        info!("总数量: {}", result.total);
+        info!("压缩 NFT: {}", result.compressed);
+        info!("受保护: {}", result.protected);
+        for (index, nft) in result.nfts.iter().enumerate() {
+            info!("[NFT {}]", index + 1);
+            info!("名称: {} ({})", nft.name, nft.symbol);
+            info!("地址: {}", nft.mint);
+            if let Some(collection) = &nft.collection {
+                info!("集合: {}", collection);
+            }
+        }
+        info!("{}", "=".repeat(50));
+
+        Ok(result)
+    }
+
+    /// -- 销毁 NFT 并回收元数据、版本和代币账户租金
+    ///
+    /// 受白名单保护的 NFT 和压缩 NFT 会被拒绝。
+    ///
+    /// # 参数
+    /// * `nft` - 要销毁的 NFT
+    pub async fn burn_nft(&self, nft: &NftInfo) -> NftOperationResult {
+        let result = self.burn_nft_internal(nft).await;
+        to_result(result, &nft.mint)
+    }
+
+    /// -- 批量销毁 NFT
+    ///
+    /// # 参数
+    /// * `nfts` - 要销毁的 NFT 列表
+    pub async fn batch_burn_nfts(&self, nfts: &[NftInfo]) -> Vec<NftOperationResult> {
+        let mut results = Vec::with_capacity(nfts.len());
+        let mut total_rent = 0.0;
+
+        for (index, nft) in nfts.iter().enumerate() {
+            info!("销毁第 {}/{} 个 NFT: {}", index + 1, nfts.len(), nft.name);
+            let result = self.burn_nft(nft).await;
+            if result.success {
+                total_rent += result.rent_recovered;
+            } else if let Some(e) = &result.error {
+                error!("销毁失败: {}, {}", nft.mint, e);
+            }
+            results.push(result);
+
+            if index < nfts.len() - 1 {
+                tokio::time::sleep(self.config.batch_delay).await;
+            }
+        }
+
+        info!("批量销毁完成，共回收租金: {:.6} SOL", total_rent);
+        results
+    }
+
+    /// -- 转移 NFT 到指定钱包
+    ///
+    /// 使用元数据程序的 TransferV1 指令，同时支持普通 NFT 和 pNFT，目标 ATA 会自动创建。
+    ///
+    /// # 参数
+    /// * `nft` - 要转移的 NFT
+    /// * `destination` - 接收钱包地址
+    pub async fn transfer_nft(&self, nft: &NftInfo, destination: &Pubkey) -> NftOperationResult {
+        let result = self.transfer_nft_internal(nft, destination).await;
+        to_result(result, &nft.mint)
+    }
+
+    async fn burn_nft_internal(&self, nft: &NftInfo) -> NftResult<(String, f64)> {
+        if self.is_protected(nft) {
+            return Err(NftError::Protected(nft.mint.clone()));
+        }
+        if nft.compressed {
+            return Err(NftError::Unsupported("压缩 NFT 暂不支持销毁".to_string()));
+        }
+
+        let (mint, token_program) = parse_mint(nft)?;
+        let owner = self.wallet.pubkey();
+        let token = get_associated_token_address_with_program_id(&owner, &mint, &token_program);
+        let (metadata, _) = Metadata::find_pda(&mint);
+        let (edition, _) = MasterEdition::find_pda(&mint);
+
+        let collection_metadata = nft
+            .collection
+            .as_ref()
+            .and_then(|c| Pubkey::from_str(c).ok())
+            .map(|c| Metadata::find_pda(&c).0);
+        let token_record = nft
+            .programmable
+            .then(|| TokenRecord::find_pda(&mint, &token).0);
+
+        let balance_before = self.connection.get_balance(&owner)?;
+
+        let instruction = BurnV1Builder::new()
+            .authority(owner)
+            .collection_metadata(collection_metadata)
+            .metadata(metadata)
+            .edition(Some(edition))
+            .mint(mint)
+            .token(token)
+            .token_record(token_record)
+            .spl_token_program(token_program)
+            .amount(1)
+            .instruction();
+
+        let signature = self.send_with_retry(&[instruction]).await?;
+
+        let balance_after = self.connection.get_balance(&owner)?;
+        let rent_recovered =
+            balance_after.saturating_sub(balance_before) as f64 / LAMPORTS_PER_SOL as f64;
+        info!(
+            "销毁成功: {}, 回收租金: {:.6} SOL",
+            nft.mint, rent_recovered
+        );
+
+        Ok((signature, rent_recovered))
+    }
+
+    async fn transfer_nft_internal(
+        &self,
+        nft: &NftInfo,
+        destination: &Pubkey,
+    ) -> NftResult<(String, f64)> {
+        if nft.compressed {
+            return Err(NftError::Unsupported("压缩 NFT 暂不支持转移".to_string()));
+        }
+        if nft.frozen && !nft.programmable {
+            return Err(NftError::Unsupported(format!("NFT 已冻结: {}", nft.mint)));
+        }
+
+        let (mint, token_program) = parse_mint(nft)?;
+        let owner = self.wallet.pubkey();
+        let token = get_associated_token_address_with_program_id(&owner, &mint, &token_program);
+        let destination_token =
+            get_associated_token_address_with_program_id(destination, &mint, &token_program);
+        let (metadata, _) = Metadata::find_pda(&mint);
+        let (edition, _) = MasterEdition::find_pda(&mint);
+
+        let (token_record, destination_token_record) = if nft.programmable {
+            (
+                Some(TokenRecord::find_pda(&mint, &token).0),
+                Some(TokenRecord::find_pda(&mint, &destination_token).0),
+            )
+        } else {
+            (None, None)
+        };
+
+        let instruction = TransferV1Builder::new()
+            .token(token)
+            .token_owner(owner)
+            .destination_token(destination_token)
+            .destination_owner(*destination)
+            .mint(mint)
+            .metadata(metadata)
+            .edition(Some(edition))
+            .token_record(token_record)
+            .destination_token_record(destination_token_record)
+            .authority(owner)
+            .payer(owner)
+            .spl_token_program(token_program)
+            .amount(1)
+            .instruction();
+
+        let signature = self.send_with_retry(&[instruction]).await?;
+        info!("转移成功: {} -> {}", nft.mint, destination);
+
+        Ok((signature, 0.0))
+    }
+
+    /// -- 签名并发送交易，失败时按配置重试
+    async fn send_with_retry(&self, instructions: &[Instruction]) -> NftResult<String> {
+        let mut retries = 0;
+        loop {
+            let transaction = Transaction::new_signed_with_payer(
+                instructions,
+                Some(&self.wallet.pubkey()),
+                &[&self.wallet],
+                self.connection.get_latest_blockhash()?,
+            );
+
+            match RpcApi::send_and_confirm_transaction(&self.connection, &transaction) {
+                Ok(signature) => return Ok(signature.to_string()),
+                Err(e) if retries < self.config.max_retries => {
+                    retries += 1;
+                    warn!("重试第 {} 次: {}", retries, e);
+                    tokio::time::sleep(self.config.retry_delay).await;
+                }
+                Err(e) => return Err(NftError::TransactionError(e.to_string())),
+            }
+        }
+    }
+}
+
+/// -- 解析 NFT 的 Mint 和代币程序地址
+fn parse_mint(nft: &NftInfo) -> NftResult<(Pubkey, Pubkey)> {
+    let mint =
+        Pubkey::from_str(&nft.mint).map_err(|e| NftError::AccountParseError(e.to_string()))?;
+    let token_program = Pubkey::from_str(&nft.token_program)
+        .map_err(|e| NftError::AccountParseError(e.to_string()))?;
+    Ok((mint, token_program))
+}
+
+/// -- 将操作结果转换为友好的结果格式
+fn to_result(result: NftResult<(String, f64)>, mint: &str) -> NftOperationResult {
+    match result {
+        Ok((signature, rent_recovered)) => NftOperationResult {
+            success: true,
+            signature: Some(signature),
+            error: None,
+            mint: mint.to_string(),
+            rent_recovered,
+        },
+        Err(e) => NftOperationResult {
+            success: false,
+            signature: None,
+            error: Some(e.to_string()),
+            mint: mint.to_string(),
+            rent_recovered: 0.0,
+        },
+    }
+}
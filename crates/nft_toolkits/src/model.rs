@@ -0,0 +1,78 @@
+use crate::das::DasAsset;
+
+/// -- 元数据程序中可编程 NFT 的接口类型
+const PROGRAMMABLE_INTERFACE: &str = "ProgrammableNFT";
+
+/// -- NFT 信息结构体
+#[derive(Debug, Clone)]
+pub struct NftInfo {
+    pub mint: String,               // -- Mint 地址（压缩 NFT 为资产 ID）
+    pub name: String,               // -- 名称
+    pub symbol: String,             // -- 符号
+    pub uri: String,                // -- 链下元数据 URI
+    pub collection: Option<String>, // -- 所属集合 Mint 地址
+    pub compressed: bool,           // -- 是否为压缩 NFT
+    pub programmable: bool,         // -- 是否为可编程 NFT (pNFT)
+    pub frozen: bool,               // -- 是否被冻结
+    pub token_program: String,      // -- 代币程序 ID
+}
+
+impl NftInfo {
+    /// -- 从 DAS 资产转换
+    ///
+    /// 非 NFT 类型（如 FungibleToken）返回 None
+    pub fn from_asset(asset: &DasAsset) -> Option<Self> {
+        if !matches!(
+            asset.interface.as_str(),
+            "V1_NFT" | "V2_NFT" | "LEGACY_NFT" | PROGRAMMABLE_INTERFACE
+        ) {
+            return None;
+        }
+
+        let content = asset.content.clone().unwrap_or_default();
+        let metadata = content.metadata.unwrap_or_default();
+
+        Some(Self {
+            mint: asset.id.clone(),
+            name: metadata.name,
+            symbol: metadata.symbol,
+            uri: content.json_uri,
+            collection: asset
+                .grouping
+                .iter()
+                .find(|g| g.group_key == "collection")
+                .map(|g| g.group_value.clone()),
+            compressed: asset
+                .compression
+                .as_ref()
+                .map(|c| c.compressed)
+                .unwrap_or(false),
+            programmable: asset.interface == PROGRAMMABLE_INTERFACE,
+            frozen: asset.ownership.as_ref().map(|o| o.frozen).unwrap_or(false),
+            token_program: asset
+                .token_info
+                .as_ref()
+                .and_then(|t| t.token_program.clone())
+                .unwrap_or_else(|| spl_token::id().to_string()),
+        })
+    }
+}
+
+/// -- NFT 查询结果结构体
+#[derive(Debug, Default)]
+pub struct NftListResult {
+    pub total: usize,       // -- NFT 总数量
+    pub compressed: usize,  // -- 压缩 NFT 数量
+    pub protected: usize,   // -- 受白名单保护的数量
+    pub nfts: Vec<NftInfo>, // -- 所有 NFT
+}
+
+/// -- NFT 操作结果结构体
+#[derive(Debug)]
+pub struct NftOperationResult {
+    pub success: bool,             // -- 操作是否成功
+    pub signature: Option<String>, // -- 成功时的交易签名
+    pub error: Option<String>,     // -- 失败时的错误信息
+    pub mint: String,              // -- NFT Mint 地址
+    pub rent_recovered: f64,       // -- 回收的租金数量（以 SOL 为单位）
+}
@@ -0,0 +1,48 @@
+use crate::model::NftInfo;
+use std::collections::HashSet;
+
+/// -- NFT 白名单管理器
+///
+/// 与代币白名单对应，用于保护指定集合或单个 NFT 不被销毁
+#[derive(Debug, Default)]
+pub struct NftWhitelist {
+    collections: HashSet<String>, // -- 集合 Mint 地址白名单
+    mints: HashSet<String>,       // -- NFT Mint 地址白名单
+}
+
+impl NftWhitelist {
+    /// -- 创建新的 NFT 白名单管理器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// -- 添加集合到白名单（单个添加）
+    pub fn add_collection(&mut self, collection: &str) {
+        self.collections.insert(collection.to_string());
+    }
+
+    /// -- 批量添加集合到白名单
+    pub fn add_collections(&mut self, collections: &[&str]) {
+        self.collections
+            .extend(collections.iter().map(|s| s.to_string()));
+    }
+
+    /// -- 添加 NFT Mint 地址到白名单（单个添加）
+    pub fn add_mint(&mut self, mint: &str) {
+        self.mints.insert(mint.to_string());
+    }
+
+    /// -- 批量添加 NFT Mint 地址到白名单
+    pub fn add_mints(&mut self, mints: &[&str]) {
+        self.mints.extend(mints.iter().map(|s| s.to_string()));
+    }
+
+    /// -- 检查 NFT 是否受保护
+    pub fn is_protected(&self, nft: &NftInfo) -> bool {
+        self.mints.contains(&nft.mint)
+            || nft
+                .collection
+                .as_ref()
+                .is_some_and(|c| self.collections.contains(c))
+    }
+}
@@ -0,0 +1,27 @@
+use jupiter_swap::price::JupiterPriceClient;
+use portfolio::PortfolioValuator;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use std::{error::Error, str::FromStr};
+use tracing::info;
+use utils::{init_rpc_client, init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    // -- 从 WALLETS 读取逗号分隔的钱包地址
+    let wallets = std::env::var("WALLETS")?
+        .split(',')
+        .map(|s| Pubkey::from_str(s.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rpc_client = init_rpc_client(CommitmentConfig::confirmed())?;
+    let valuator = PortfolioValuator::new(&rpc_client, JupiterPriceClient::default());
+    let snapshot = valuator.snapshot(&wallets).await?;
+
+    info!("资产快照:\n{}", serde_json::to_string_pretty(&snapshot)?);
+
+    Ok(())
+}
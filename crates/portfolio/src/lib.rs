@@ -0,0 +1,227 @@
+//! # 钱包资产估值
+//!
+//! 汇总一个或多个钱包的 SOL、SPL Token、Token-2022 余额以及质押 SOL，
+//! 通过 Jupiter 价格 API 计算 USD 价值，生成可序列化的资产快照。
+use anyhow::Result;
+use jupiter_swap::price::PriceSource;
+use solana_account_decoder::UiAccountData;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+    rpc_request::TokenAccountsFilter,
+};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    stake::{self, state::StakeStateV2},
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{info, instrument, warn};
+
+pub mod model;
+
+pub use model::*;
+
+/// -- Wrapped SOL Mint，用于查询 SOL 价格
+pub const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+/// -- 质押账户中 withdrawer 字段的偏移量
+///
+/// StakeStateV2 布局: enum tag(4) + rent_exempt_reserve(8) + staker(32) + withdrawer(32)
+const STAKE_WITHDRAWER_OFFSET: usize = 4 + 8 + 32;
+
+/// -- 资产估值器
+pub struct PortfolioValuator<'a, P: PriceSource> {
+    rpc_client: &'a RpcClient,
+    price_source: P,
+}
+
+impl<'a, P: PriceSource> PortfolioValuator<'a, P> {
+    /// -- 创建估值器
+    ///
+    /// # 参数
+    /// * `rpc_client` - RPC 客户端
+    /// * `price_source` - 价格来源
+    pub fn new(rpc_client: &'a RpcClient, price_source: P) -> Self {
+        Self {
+            rpc_client,
+            price_source,
+        }
+    }
+
+    /// -- 生成多钱包资产快照
+    ///
+    /// # 参数
+    /// * `wallets` - 钱包地址列表
+    ///
+    /// # 返回
+    /// * `Result<PortfolioSnapshot>` - 资产快照
+    #[instrument(skip(self, wallets), fields(wallets = wallets.len()))]
+    pub async fn snapshot(&self, wallets: &[Pubkey]) -> Result<PortfolioSnapshot> {
+        let mut portfolios = Vec::with_capacity(wallets.len());
+        for wallet in wallets {
+            portfolios.push(self.scan_wallet(wallet)?);
+        }
+
+        // -- 统一查询所有出现过的 Mint 价格，减少 API 调用
+        let mut mints: Vec<String> = portfolios
+            .iter()
+            .flat_map(|p| p.tokens.iter().map(|t| t.mint.clone()))
+            .collect();
+        mints.push(SOL_MINT.to_string());
+        mints.sort();
+        mints.dedup();
+
+        let prices = match self.price_source.get_prices(&mints).await {
+            Ok(prices) => prices,
+            Err(e) => {
+                warn!("获取价格失败: {}, 仅输出余额", e);
+                Default::default()
+            }
+        };
+        let sol_price = prices.get(SOL_MINT).copied();
+
+        for portfolio in &mut portfolios {
+            let mut total = 0.0;
+
+            portfolio.sol_value_usd = sol_price.map(|p| p * portfolio.sol_balance);
+            total += portfolio.sol_value_usd.unwrap_or(0.0);
+
+            for stake in &mut portfolio.stakes {
+                stake.value_usd = sol_price.map(|p| p * stake.sol);
+                total += stake.value_usd.unwrap_or(0.0);
+            }
+
+            for token in &mut portfolio.tokens {
+                token.price_usd = prices.get(&token.mint).copied();
+                token.value_usd = token.price_usd.map(|p| p * token.ui_amount);
+                total += token.value_usd.unwrap_or(0.0);
+            }
+
+            portfolio.total_value_usd = total;
+            info!("钱包 {} 总价值: {:.2} USD", portfolio.wallet, total);
+        }
+
+        let total_value_usd = portfolios.iter().map(|p| p.total_value_usd).sum();
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Ok(PortfolioSnapshot {
+            timestamp,
+            sol_price_usd: sol_price,
+            wallets: portfolios,
+            total_value_usd,
+        })
+    }
+
+    /// -- 扫描单个钱包的余额（不含价格）
+    pub fn scan_wallet(&self, wallet: &Pubkey) -> Result<WalletPortfolio> {
+        let lamports = self.rpc_client.get_balance(wallet)?;
+
+        let mut tokens = self.scan_token_accounts(wallet, &spl_token::id())?;
+        tokens.extend(self.scan_token_accounts(wallet, &spl_token_2022::id())?);
+
+        let stakes = self.scan_stake_accounts(wallet)?;
+        let staked_sol = stakes.iter().map(|s| s.sol).sum();
+
+        Ok(WalletPortfolio {
+            wallet: wallet.to_string(),
+            sol_balance: lamports as f64 / LAMPORTS_PER_SOL as f64,
+            sol_value_usd: None,
+            tokens,
+            stakes,
+            staked_sol,
+            total_value_usd: 0.0,
+        })
+    }
+
+    /// -- 扫描指定代币程序下的代币账户，忽略余额为 0 的账户
+    fn scan_token_accounts(
+        &self,
+        wallet: &Pubkey,
+        token_program: &Pubkey,
+    ) -> Result<Vec<TokenHolding>> {
+        let accounts = self
+            .rpc_client
+            .get_token_accounts_by_owner(wallet, TokenAccountsFilter::ProgramId(*token_program))?;
+
+        let mut holdings = Vec::new();
+        for account in accounts {
+            let UiAccountData::Json(parsed_data) = &account.account.data else {
+                continue;
+            };
+            let Some(info) = parsed_data.parsed.get("info") else {
+                continue;
+            };
+            let Some(mint) = info.get("mint").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let Some(token_amount) = info.get("tokenAmount") else {
+                continue;
+            };
+
+            let amount = token_amount
+                .get("amount")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<u64>().ok())
+                .unwrap_or(0);
+            if amount == 0 {
+                continue;
+            }
+            let decimals = token_amount
+                .get("decimals")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as u8;
+
+            holdings.push(TokenHolding {
+                account: account.pubkey.to_string(),
+                mint: mint.to_string(),
+                token_program: token_program.to_string(),
+                amount,
+                decimals,
+                ui_amount: amount as f64 / 10f64.powi(decimals as i32),
+                price_usd: None,
+                value_usd: None,
+            });
+        }
+
+        Ok(holdings)
+    }
+
+    /// -- 扫描以该钱包为 withdrawer 的质押账户
+    fn scan_stake_accounts(&self, wallet: &Pubkey) -> Result<Vec<StakeHolding>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                STAKE_WITHDRAWER_OFFSET,
+                wallet.as_ref(),
+            ))]),
+            account_config: RpcAccountInfoConfig::default(),
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let accounts = self
+            .rpc_client
+            .get_program_accounts_with_config(&stake::program::id(), config)?;
+
+        Ok(accounts
+            .into_iter()
+            .map(|(pubkey, account)| {
+                let voter = bincode::deserialize::<StakeStateV2>(&account.data)
+                    .ok()
+                    .and_then(|state| state.delegation())
+                    .map(|delegation| delegation.voter_pubkey.to_string());
+
+                StakeHolding {
+                    account: pubkey.to_string(),
+                    lamports: account.lamports,
+                    sol: account.lamports as f64 / LAMPORTS_PER_SOL as f64,
+                    voter,
+                    value_usd: None,
+                }
+            })
+            .collect())
+    }
+}
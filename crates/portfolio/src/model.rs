@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// -- 代币持仓
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenHolding {
+    pub account: String,        // -- 代币账户地址
+    pub mint: String,           // -- 代币的 Mint 地址
+    pub token_program: String,  // -- 所属代币程序（SPL Token / Token-2022）
+    pub amount: u64,            // -- 余额（最小单位）
+    pub decimals: u8,           // -- 精度
+    pub ui_amount: f64,         // -- 余额（按精度换算）
+    pub price_usd: Option<f64>, // -- 单价（USD）
+    pub value_usd: Option<f64>, // -- 价值（USD）
+}
+
+/// -- 质押账户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StakeHolding {
+    pub account: String,        // -- 质押账户地址
+    pub lamports: u64,          // -- 账户总 lamports
+    pub sol: f64,               // -- 账户总 SOL
+    pub voter: Option<String>,  // -- 委托的验证者投票账户
+    pub value_usd: Option<f64>, // -- 价值（USD）
+}
+
+/// -- 单个钱包的资产快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletPortfolio {
+    pub wallet: String,             // -- 钱包地址
+    pub sol_balance: f64,           // -- SOL 余额
+    pub sol_value_usd: Option<f64>, // -- SOL 价值（USD）
+    pub tokens: Vec<TokenHolding>,  // -- 代币持仓
+    pub stakes: Vec<StakeHolding>,  // -- 质押账户
+    pub staked_sol: f64,            // -- 质押 SOL 总量
+    pub total_value_usd: f64,       // -- 总价值（USD，不含无价格代币）
+}
+
+/// -- 多钱包资产快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioSnapshot {
+    pub timestamp: u64,                // -- 快照时间（Unix 秒）
+    pub sol_price_usd: Option<f64>,    // -- SOL 单价（USD）
+    pub wallets: Vec<WalletPortfolio>, // -- 各钱包快照
+    pub total_value_usd: f64,          // -- 总价值（USD）
+}
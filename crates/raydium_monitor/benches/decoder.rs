@@ -0,0 +1,94 @@
+//! 解码热路径基准测试
+//!
+//! 运行：`cargo bench -p raydium_monitor`
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use raydium_monitor::{
+    decoder::{decode_instruction_data, decode_ix_data},
+    model::RaydiumInstruction,
+    services::{extract_amm_events, is_pool_initialization},
+    synthetic::{
+        replay_pipeline, synthetic_batch, synthetic_initialize2, synthetic_initialize2_data,
+        synthetic_logs, synthetic_swap, synthetic_swap_data,
+    },
+};
+
+/// 每批交易数量
+const BATCH: usize = 1_000;
+
+/// 每批中创建流动性池交易的间隔
+const POOL_EVERY: usize = 10;
+
+fn bench_decode(c: &mut Criterion) {
+    let initialize2 = synthetic_initialize2_data(42);
+    let swap = Some(synthetic_swap_data(42));
+
+    let mut group = c.benchmark_group("decode_ix_data");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("initialize2", |b| {
+        b.iter(|| decode_ix_data::<RaydiumInstruction>(black_box(&initialize2)).unwrap())
+    });
+    group.bench_function("swap", |b| {
+        b.iter(|| decode_instruction_data(black_box(&swap)).unwrap())
+    });
+    group.finish();
+}
+
+fn bench_extract_amm_events(c: &mut Criterion) {
+    let initialize2 = synthetic_initialize2(42);
+    let swap = synthetic_swap(42);
+
+    let mut group = c.benchmark_group("extract_amm_events");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("initialize2", |b| {
+        b.iter(|| extract_amm_events(black_box(&initialize2), "initialize2"))
+    });
+    group.bench_function("swap", |b| {
+        b.iter(|| extract_amm_events(black_box(&swap), "swap"))
+    });
+    group.finish();
+}
+
+fn bench_filter(c: &mut Criterion) {
+    let logs: Vec<_> = (0..BATCH as u64)
+        .map(|seed| synthetic_logs(seed, seed % POOL_EVERY as u64 == 0))
+        .collect();
+
+    let mut group = c.benchmark_group("filter");
+    group.throughput(Throughput::Elements(BATCH as u64));
+    group.bench_function("is_pool_initialization", |b| {
+        b.iter(|| {
+            logs.iter()
+                .filter(|logs| is_pool_initialization(black_box(logs)))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn bench_pipeline(c: &mut Criterion) {
+    let transactions = synthetic_batch(BATCH, POOL_EVERY);
+    let logs: Vec<_> = (0..BATCH as u64)
+        .map(|seed| synthetic_logs(seed, seed % POOL_EVERY as u64 == 0))
+        .collect();
+
+    let mut group = c.benchmark_group("pipeline");
+    group.throughput(Throughput::Elements(BATCH as u64));
+    group.bench_function("filter_process_decode", |b| {
+        b.iter(|| {
+            logs.iter()
+                .zip(&transactions)
+                .filter(|(logs, tx)| replay_pipeline(black_box(logs), black_box(tx)))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_decode,
+    bench_extract_amm_events,
+    bench_filter,
+    bench_pipeline
+);
+criterion_main!(benches);
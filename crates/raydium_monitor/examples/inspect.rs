@@ -0,0 +1,15 @@
+use anyhow::Result;
+use raydium_monitor::{inspector::inspect, utils::init_tracing};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    init_tracing();
+
+    let report = inspect(
+        "3VAxEp6xee6AdufMqCeYz6b2xJmNHH6kWMJhNcjgGZStgiDHsiXEm7UiGek954wjhh9r416Dyxt8xJL1C4piYGbo",
+    )
+    .await?;
+    println!("{}", report);
+
+    Ok(())
+}
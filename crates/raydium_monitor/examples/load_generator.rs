@@ -0,0 +1,106 @@
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use raydium_monitor::{
+    synthetic::{load_captured, replay_pipeline, synthetic_batch, synthetic_logs},
+    utils::load_env,
+};
+
+/// 每个节拍的时长，按节拍均匀投放交易
+const TICK: Duration = Duration::from_millis(10);
+
+/// 合成交易数量（循环复用）
+const SYNTHETIC_COUNT: usize = 10_000;
+
+/// 负载生成器
+///
+/// 以固定速率把交易重复送入监控热路径（日志过滤 -> 指令提取 -> 解码），
+/// 统计实际吞吐和单笔延迟分位数，用于衡量优化效果或发现性能回退。
+///
+/// 环境变量：
+/// - `LOAD_TPS`：目标每秒交易数，默认 5000
+/// - `LOAD_SECONDS`：持续时间（秒），默认 10
+/// - `LOAD_POOL_EVERY`：每多少笔中有一笔创建流动性池交易，默认 10
+/// - `LOAD_CAPTURE`：抓取的交易文件（JSON 数组或 JSONL），未设置时使用合成交易
+fn main() -> Result<()> {
+    // -- 热路径中的 info 日志会拖慢吞吐并淹没输出，这里不初始化 tracing
+    load_env()?;
+
+    let tps: usize = env_or("LOAD_TPS", 5_000);
+    let seconds: u64 = env_or("LOAD_SECONDS", 10);
+    let pool_every: usize = env_or("LOAD_POOL_EVERY", 10);
+    anyhow::ensure!(
+        tps > 0 && seconds > 0,
+        "LOAD_TPS 和 LOAD_SECONDS 必须大于 0"
+    );
+
+    let transactions = match env::var("LOAD_CAPTURE") {
+        Ok(path) => load_captured(path)?,
+        Err(_) => synthetic_batch(SYNTHETIC_COUNT, pool_every),
+    };
+    anyhow::ensure!(!transactions.is_empty(), "没有可回放的交易");
+    let logs: Vec<_> = (0..transactions.len() as u64)
+        .map(|seed| synthetic_logs(seed, pool_every > 0 && seed % pool_every as u64 == 0))
+        .collect();
+
+    println!(
+        "回放 {} 笔交易: 目标 {} tx/s, 持续 {} 秒",
+        transactions.len(),
+        tps,
+        seconds
+    );
+
+    let per_tick = (tps as f64 * TICK.as_secs_f64()).ceil() as usize;
+    let deadline = Instant::now() + Duration::from_secs(seconds);
+    let started = Instant::now();
+    let mut latencies = Vec::with_capacity(tps * seconds as usize);
+    let mut decoded = 0usize;
+    let mut cursor = 0usize;
+    let mut next_tick = Instant::now();
+
+    while Instant::now() < deadline {
+        for _ in 0..per_tick {
+            let index = cursor % transactions.len();
+            cursor += 1;
+
+            let begin = Instant::now();
+            decoded += replay_pipeline(&logs[index], &transactions[index]) as usize;
+            latencies.push(begin.elapsed());
+        }
+
+        next_tick += TICK;
+        // -- 处理速度跟不上目标速率时不再等待，直接进入下一个节拍
+        if let Some(wait) = next_tick.checked_duration_since(Instant::now()) {
+            std::thread::sleep(wait);
+        }
+    }
+
+    let elapsed = started.elapsed();
+    latencies.sort_unstable();
+    let percentile = |p: f64| latencies[((latencies.len() - 1) as f64 * p) as usize];
+
+    println!("处理交易: {} 笔, 解码成功: {} 笔", latencies.len(), decoded);
+    println!(
+        "实际吞吐: {:.0} tx/s（目标 {} tx/s）",
+        latencies.len() as f64 / elapsed.as_secs_f64(),
+        tps
+    );
+    println!(
+        "单笔延迟: p50 {:?}, p99 {:?}, max {:?}",
+        percentile(0.5),
+        percentile(0.99),
+        latencies[latencies.len() - 1]
+    );
+
+    Ok(())
+}
+
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    env::var(key)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
@@ -1,7 +1,9 @@
-use std::env;
+use std::{env, sync::Arc};
 
 use anyhow::Result;
-use raydium_monitor::{services::subscribe_to_logs, utils::load_env};
+use event_store::EventStore;
+use futures::StreamExt;
+use raydium_monitor::{model::MonitorEvent, services::RaydiumMonitor, utils::load_env};
 use tracing::info;
 use utils::init_tracing;
 
@@ -12,7 +14,24 @@ async fn main() -> Result<()> {
 
     let ws_url = env::var("HELIUS_WS_RPC_URL")?;
     info!("Helius WS RPC URL: {}", ws_url);
-    subscribe_to_logs(&ws_url).await?;
+
+    let mut monitor = RaydiumMonitor::new(ws_url)?;
+    // -- 设置 MONITOR_EVENT_STORE 时将新建流动性池事件写入事件存储
+    if let Ok(path) = env::var("MONITOR_EVENT_STORE") {
+        let store = EventStore::open(&path)?;
+        info!("已有 {} 条历史事件", store.replay()?.len());
+        monitor = monitor.with_store(Arc::new(store));
+    }
+
+    let mut events = Box::pin(monitor.stream());
+    while let Some(event) = events.next().await {
+        if let MonitorEvent::PoolCreated(pool) = event {
+            info!(
+                "新流动性池: {}，{} / {}",
+                pool.lp_account, pool.token_a.name, pool.token_b.name
+            );
+        }
+    }
 
     Ok(())
 }
@@ -9,6 +9,7 @@ use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTra
 use tracing::{debug, info, instrument};
 
 pub use utils::init_rpc_client;
+use utils::RpcApi;
 
 /// 异步获取交易详情
 ///
@@ -34,6 +35,24 @@ pub async fn get_transaction_details(
     // 使用 "confirmed" 提交配置初始化 RPC 客户端
     let client = init_rpc_client(CommitmentConfig::confirmed())?;
 
+    get_transaction_details_with(&client, signature).await
+}
+
+/// 使用指定的 RPC 客户端获取交易详情
+///
+/// # 参数
+///
+/// * `client` - 实现了 `RpcApi` 的 RPC 客户端
+/// * `signature` - 交易的签名字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<EncodedConfirmedTransactionWithStatusMeta>`
+#[instrument(skip(client, signature), fields(signature = %signature))]
+pub async fn get_transaction_details_with<R: RpcApi>(
+    client: &R,
+    signature: &str,
+) -> Result<EncodedConfirmedTransactionWithStatusMeta> {
     // 步骤 2：解析交易签名
     // 将输入的字符串签名转换为 Solana 的 Signature 类型
     let sign = Signature::from_str(signature)?;
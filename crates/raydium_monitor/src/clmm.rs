@@ -0,0 +1,391 @@
+use anyhow::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+
+use crate::model::MonitorError;
+
+/// Raydium CLMM（集中流动性）程序 ID
+pub const RAYDIUM_CLMM: &str = "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK";
+
+/// 最小 tick
+pub const MIN_TICK: i32 = -443_636;
+
+/// 最大 tick
+pub const MAX_TICK: i32 = 443_636;
+
+/// Q64.64 定点数的 1.0
+const Q64: f64 = 18_446_744_073_709_551_616.0;
+
+/// Anchor 指令标识：sha256("global:<指令名>") 的前 8 个字节
+const CREATE_POOL: [u8; 8] = [233, 146, 209, 142, 207, 104, 64, 188];
+const SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+const SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+const OPEN_POSITION: [u8; 8] = [135, 128, 47, 77, 15, 152, 240, 49];
+const OPEN_POSITION_V2: [u8; 8] = [77, 184, 74, 214, 112, 86, 241, 199];
+const OPEN_POSITION_WITH_TOKEN22_NFT: [u8; 8] = [77, 255, 174, 82, 125, 29, 201, 46];
+const CLOSE_POSITION: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+
+/// 需要解析的 CLMM 指令在日志中的名称
+const CLMM_ACTION_LOGS: [&str; 7] = [
+    "Instruction: CreatePool",
+    "Instruction: Swap",
+    "Instruction: SwapV2",
+    "Instruction: OpenPosition",
+    "Instruction: OpenPositionV2",
+    "Instruction: OpenPositionWithToken22Nft",
+    "Instruction: ClosePosition",
+];
+
+/// create_pool 指令参数
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct CreatePoolArgs {
+    pub sqrt_price_x64: u128, // 初始价格的平方根（Q64.64）
+    pub open_time: u64,       // 流动性池开放时间（Unix 时间戳）
+}
+
+/// swap / swap_v2 指令参数
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct SwapArgs {
+    pub amount: u64,                 // 指定的输入（或输出）数量
+    pub other_amount_threshold: u64, // 另一侧的最少输出（或最多输入）数量
+    pub sqrt_price_limit_x64: u128,  // 价格限制的平方根（Q64.64），0 表示不限制
+    pub is_base_input: bool,         // `amount` 是否为输入数量
+}
+
+/// open_position 系列指令参数（v2 和 token22 版本在末尾追加的参数不解析）
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct OpenPositionArgs {
+    pub tick_lower_index: i32,             // 价格区间下限 tick
+    pub tick_upper_index: i32,             // 价格区间上限 tick
+    pub tick_array_lower_start_index: i32, // 下限所在 tick array 的起始 tick
+    pub tick_array_upper_start_index: i32, // 上限所在 tick array 的起始 tick
+    pub liquidity: u128,                   // 添加的流动性
+    pub amount_0_max: u64,                 // 最多存入的 token 0 数量
+    pub amount_1_max: u64,                 // 最多存入的 token 1 数量
+}
+
+/// Raydium CLMM 指令
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ClmmIx {
+    /// 创建流动性池
+    CreatePool(CreatePoolArgs),
+    /// 兑换
+    Swap(SwapArgs),
+    /// 兑换（支持 Token-2022）
+    SwapV2(SwapArgs),
+    /// 开仓
+    OpenPosition(OpenPositionArgs),
+    /// 开仓（可选是否创建 NFT 元数据）
+    OpenPositionV2(OpenPositionArgs),
+    /// 开仓（仓位 NFT 使用 Token-2022）
+    OpenPositionWithToken22Nft(OpenPositionArgs),
+    /// 关闭仓位
+    ClosePosition,
+}
+
+impl ClmmIx {
+    /// 指令名称，与 Raydium CLMM IDL 中的命名一致
+    pub fn name(&self) -> &'static str {
+        match self {
+            ClmmIx::CreatePool(_) => "createPool",
+            ClmmIx::Swap(_) => "swap",
+            ClmmIx::SwapV2(_) => "swapV2",
+            ClmmIx::OpenPosition(_) => "openPosition",
+            ClmmIx::OpenPositionV2(_) => "openPositionV2",
+            ClmmIx::OpenPositionWithToken22Nft(_) => "openPositionWithToken22Nft",
+            ClmmIx::ClosePosition => "closePosition",
+        }
+    }
+}
+
+/// 解码 Raydium CLMM 指令数据
+///
+/// # 参数
+///
+/// * `data` - base58 编码的指令数据字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<ClmmIx>`，不需要解析的指令返回 `MonitorError::NoMatchingInstruction`
+pub fn decode_clmm_ix(data: &str) -> Result<ClmmIx> {
+    let bytes = bs58::decode(data).into_vec()?;
+    if bytes.len() < 8 {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    }
+    let (discriminator, mut args) = bytes.split_at(8);
+
+    let ix = match <[u8; 8]>::try_from(discriminator)? {
+        CREATE_POOL => ClmmIx::CreatePool(CreatePoolArgs::deserialize(&mut args)?),
+        SWAP => ClmmIx::Swap(SwapArgs::deserialize(&mut args)?),
+        SWAP_V2 => ClmmIx::SwapV2(SwapArgs::deserialize(&mut args)?),
+        OPEN_POSITION => ClmmIx::OpenPosition(OpenPositionArgs::deserialize(&mut args)?),
+        OPEN_POSITION_V2 => ClmmIx::OpenPositionV2(OpenPositionArgs::deserialize(&mut args)?),
+        OPEN_POSITION_WITH_TOKEN22_NFT => {
+            ClmmIx::OpenPositionWithToken22Nft(OpenPositionArgs::deserialize(&mut args)?)
+        }
+        CLOSE_POSITION => ClmmIx::ClosePosition,
+        _ => return Err(MonitorError::NoMatchingInstruction.into()),
+    };
+
+    Ok(ix)
+}
+
+/// 判断交易日志中是否包含创建 CLMM 流动性池的指令
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_clmm_pool_creation(logs: &[String]) -> bool {
+    logs.iter()
+        .any(|log| log == "Program log: Instruction: CreatePool")
+}
+
+/// 判断交易日志中是否包含需要解析的 CLMM 指令（建池、兑换、开仓、平仓）
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_clmm_action(logs: &[String]) -> bool {
+    logs.iter().any(|log| {
+        log.strip_prefix("Program log: ")
+            .is_some_and(|log| CLMM_ACTION_LOGS.contains(&log))
+    })
+}
+
+/// create_pool 指令的账户布局
+///
+/// 0. pool_creator
+/// 1. amm_config
+/// 2. pool_state
+/// 3. token_mint_0
+/// 4. token_mint_1
+/// 5. token_vault_0
+/// 6. token_vault_1
+/// 7. observation_state
+/// 8. tick_array_bitmap
+/// 9. token_program_0
+/// 10. token_program_1
+/// 11. system_program
+/// 12. rent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClmmCreatePoolAccounts {
+    pub pool_creator: String,  // 创建流动性池的钱包
+    pub amm_config: String,    // 费率配置账户
+    pub pool_state: String,    // 流动性池地址
+    pub token_mint_0: String,  // token 0 Mint 地址
+    pub token_mint_1: String,  // token 1 Mint 地址
+    pub token_vault_0: String, // 流动性池的 token 0 账户
+    pub token_vault_1: String, // 流动性池的 token 1 账户
+}
+
+impl ClmmCreatePoolAccounts {
+    /// create_pool 指令涉及的账户数量
+    pub const LEN: usize = 13;
+
+    /// 按 create_pool 指令定义解析账户列表
+    pub fn from_accounts(accounts: &[String]) -> Result<Self, MonitorError> {
+        check_len("createPool", accounts, Self::LEN)?;
+
+        Ok(Self {
+            pool_creator: accounts[0].clone(),
+            amm_config: accounts[1].clone(),
+            pool_state: accounts[2].clone(),
+            token_mint_0: accounts[3].clone(),
+            token_mint_1: accounts[4].clone(),
+            token_vault_0: accounts[5].clone(),
+            token_vault_1: accounts[6].clone(),
+        })
+    }
+}
+
+/// swap / swap_v2 指令的账户布局
+///
+/// 两条指令前 8 个账户相同，之后是 token 程序和 tick array 等账户：
+///
+/// 0. payer
+/// 1. amm_config
+/// 2. pool_state
+/// 3. input_token_account
+/// 4. output_token_account
+/// 5. input_vault
+/// 6. output_vault
+/// 7. observation_state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClmmSwapAccounts {
+    pub payer: String,                // 用户钱包（交易签名者）
+    pub pool_state: String,           // 流动性池地址
+    pub input_token_account: String,  // 用户支付代币的账户
+    pub output_token_account: String, // 用户接收代币的账户
+    pub input_vault: String,          // 流动性池接收输入代币的账户
+    pub output_vault: String,         // 流动性池支付输出代币的账户
+}
+
+impl ClmmSwapAccounts {
+    /// swap 指令至少涉及的账户数量（不含剩余的 tick array）
+    pub const MIN_LEN: usize = 10;
+
+    /// 按 swap 指令定义解析账户列表
+    pub fn from_accounts(accounts: &[String]) -> Result<Self, MonitorError> {
+        check_len("swap", accounts, Self::MIN_LEN)?;
+
+        Ok(Self {
+            payer: accounts[0].clone(),
+            pool_state: accounts[2].clone(),
+            input_token_account: accounts[3].clone(),
+            output_token_account: accounts[4].clone(),
+            input_vault: accounts[5].clone(),
+            output_vault: accounts[6].clone(),
+        })
+    }
+}
+
+/// 开仓和平仓指令的账户布局
+///
+/// open_position / open_position_v2：1 为仓位所有者，2 为仓位 NFT Mint，5 为流动性池，
+/// 12、13 为流动性池的 token 0、token 1 账户；open_position_with_token22_nft 没有元数据账户，
+/// 流动性池及之后的账户前移一位。close_position：0 为仓位所有者，1 为仓位 NFT Mint。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClmmPositionAccounts {
+    pub owner: String,                 // 仓位所有者
+    pub position_nft_mint: String,     // 仓位 NFT Mint 地址
+    pub pool_state: Option<String>,    // 流动性池地址，平仓指令中没有
+    pub token_vault_0: Option<String>, // 流动性池的 token 0 账户，平仓指令中没有
+    pub token_vault_1: Option<String>, // 流动性池的 token 1 账户，平仓指令中没有
+}
+
+impl ClmmPositionAccounts {
+    /// 按开仓指令定义解析账户列表
+    ///
+    /// # 参数
+    ///
+    /// * `accounts` - 指令的账户地址列表
+    /// * `token22_nft` - 是否为 open_position_with_token22_nft 指令
+    pub fn from_open_position(
+        accounts: &[String],
+        token22_nft: bool,
+    ) -> Result<Self, MonitorError> {
+        let offset = if token22_nft { 1 } else { 0 };
+        check_len("openPosition", accounts, 14 - offset)?;
+
+        Ok(Self {
+            owner: accounts[1].clone(),
+            position_nft_mint: accounts[2].clone(),
+            pool_state: Some(accounts[5 - offset].clone()),
+            token_vault_0: Some(accounts[12 - offset].clone()),
+            token_vault_1: Some(accounts[13 - offset].clone()),
+        })
+    }
+
+    /// 按 close_position 指令定义解析账户列表
+    pub fn from_close_position(accounts: &[String]) -> Result<Self, MonitorError> {
+        check_len("closePosition", accounts, 2)?;
+
+        Ok(Self {
+            owner: accounts[0].clone(),
+            position_nft_mint: accounts[1].clone(),
+            pool_state: None,
+            token_vault_0: None,
+            token_vault_1: None,
+        })
+    }
+}
+
+/// 检查账户数量不少于指令定义的数量
+fn check_len(
+    instruction: &'static str,
+    accounts: &[String],
+    min: usize,
+) -> Result<(), MonitorError> {
+    if accounts.len() < min {
+        return Err(MonitorError::AccountLayoutMismatch {
+            instruction,
+            actual: accounts.len(),
+        });
+    }
+    Ok(())
+}
+
+/// 将 Q64.64 格式的价格平方根换算为价格（每单位 token 0 价值多少 token 1）
+///
+/// # 参数
+///
+/// * `sqrt_price_x64` - 价格的平方根（Q64.64）
+/// * `decimals_0` - token 0 精度
+/// * `decimals_1` - token 1 精度
+pub fn sqrt_price_x64_to_price(sqrt_price_x64: u128, decimals_0: u8, decimals_1: u8) -> f64 {
+    let sqrt_price = sqrt_price_x64 as f64 / Q64;
+    sqrt_price * sqrt_price * decimals_factor(decimals_0, decimals_1)
+}
+
+/// 将价格换算为 Q64.64 格式的价格平方根，是 [`sqrt_price_x64_to_price`] 的逆运算
+///
+/// # 参数
+///
+/// * `price` - 每单位 token 0 价值多少 token 1
+/// * `decimals_0` - token 0 精度
+/// * `decimals_1` - token 1 精度
+pub fn price_to_sqrt_price_x64(price: f64, decimals_0: u8, decimals_1: u8) -> u128 {
+    ((price / decimals_factor(decimals_0, decimals_1)).sqrt() * Q64) as u128
+}
+
+/// 将 tick 换算为价格：price = 1.0001^tick
+///
+/// # 参数
+///
+/// * `tick` - tick 序号
+/// * `decimals_0` - token 0 精度
+/// * `decimals_1` - token 1 精度
+pub fn tick_to_price(tick: i32, decimals_0: u8, decimals_1: u8) -> f64 {
+    1.0001f64.powi(tick) * decimals_factor(decimals_0, decimals_1)
+}
+
+/// 将价格换算为不大于该价格的最大 tick，结果限制在 [`MIN_TICK`, `MAX_TICK`] 之间
+///
+/// # 参数
+///
+/// * `price` - 每单位 token 0 价值多少 token 1
+/// * `decimals_0` - token 0 精度
+/// * `decimals_1` - token 1 精度
+pub fn price_to_tick(price: f64, decimals_0: u8, decimals_1: u8) -> i32 {
+    let raw_price = price / decimals_factor(decimals_0, decimals_1);
+    let tick = (raw_price.ln() / 1.0001f64.ln()).floor();
+    // 浮点误差可能让恰好落在 tick 上的价格向下偏一格
+    let tick = if 1.0001f64.powf(tick + 1.0) <= raw_price * (1.0 + 1e-12) {
+        tick + 1.0
+    } else {
+        tick
+    };
+    (tick as i64).clamp(MIN_TICK as i64, MAX_TICK as i64) as i32
+}
+
+/// 按实际成交数量计算成交价格（每单位输入代币兑换多少输出代币）
+///
+/// # 参数
+///
+/// * `amount_in` - 输入代币原始数量
+/// * `decimals_in` - 输入代币精度
+/// * `amount_out` - 输出代币原始数量
+/// * `decimals_out` - 输出代币精度
+///
+/// # 返回值
+///
+/// 输入数量为 0 时返回 None
+pub fn executed_price(
+    amount_in: u64,
+    decimals_in: u8,
+    amount_out: u64,
+    decimals_out: u8,
+) -> Option<f64> {
+    if amount_in == 0 {
+        return None;
+    }
+    let amount_in = amount_in as f64 / 10f64.powi(decimals_in as i32);
+    let amount_out = amount_out as f64 / 10f64.powi(decimals_out as i32);
+    Some(amount_out / amount_in)
+}
+
+/// 原始价格换算为按精度调整后的价格时乘以的系数
+fn decimals_factor(decimals_0: u8, decimals_1: u8) -> f64 {
+    10f64.powi(decimals_0 as i32 - decimals_1 as i32)
+}
@@ -0,0 +1,187 @@
+use anyhow::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+
+use crate::jupiter::decode_anchor_event;
+use crate::model::MonitorError;
+
+/// Jupiter DCA（定投）程序 ID
+pub const JUPITER_DCA: &str = "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M";
+
+/// Anchor 指令标识：sha256("global:<指令名>") 的前 8 个字节
+const OPEN_DCA: [u8; 8] = [36, 65, 185, 54, 1, 210, 100, 163];
+const OPEN_DCA_V2: [u8; 8] = [142, 119, 43, 109, 162, 52, 11, 177];
+const CLOSE_DCA: [u8; 8] = [22, 7, 33, 98, 168, 183, 34, 243];
+const END_AND_CLOSE: [u8; 8] = [83, 125, 166, 69, 247, 252, 103, 133];
+
+/// Anchor 事件标识：sha256("event:<事件名>") 的前 8 个字节
+const OPENED_EVENT: [u8; 8] = [1, 18, 232, 58, 208, 100, 13, 252];
+const FILLED_EVENT: [u8; 8] = [28, 65, 191, 210, 123, 85, 50, 188];
+const CLOSED_EVENT: [u8; 8] = [4, 69, 230, 189, 244, 220, 87, 241];
+
+/// 创建、成交或关闭定投订单的指令在日志中的名称
+const DCA_ACTION_LOGS: [&str; 6] = [
+    "Instruction: OpenDca",
+    "Instruction: OpenDcaV2",
+    "Instruction: CloseDca",
+    "Instruction: EndAndClose",
+    "Instruction: FulfillFlashFill",
+    "Instruction: FulfillDlmmFill",
+];
+
+/// open_dca 指令参数
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct OpenDcaArgs {
+    pub application_idx: u64,            // 用于派生订单地址的序号
+    pub in_amount: u64,                  // 存入的输入代币数量
+    pub in_amount_per_cycle: u64,        // 每期买入使用的输入代币数量
+    pub cycle_frequency: i64,            // 每期间隔（秒）
+    pub min_price: Option<u64>,          // 最低成交价格
+    pub max_price: Option<u64>,          // 最高成交价格
+    pub start_at: Option<i64>,           // 开始时间（Unix 时间戳）
+    pub close_wsol_in_ata: Option<bool>, // 结束后是否关闭 wSOL 账户
+}
+
+/// open_dca_v2 指令参数
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct OpenDcaV2Args {
+    pub application_idx: u64,        // 用于派生订单地址的序号
+    pub in_amount: u64,              // 存入的输入代币数量
+    pub in_amount_per_cycle: u64,    // 每期买入使用的输入代币数量
+    pub cycle_frequency: i64,        // 每期间隔（秒）
+    pub min_out_amount: Option<u64>, // 每期最少买入数量
+    pub max_out_amount: Option<u64>, // 每期最多买入数量
+    pub start_at: Option<i64>,       // 开始时间（Unix 时间戳）
+}
+
+/// Jupiter DCA 指令
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DcaIx {
+    /// 创建定投订单
+    OpenDca(OpenDcaArgs),
+    /// 创建定投订单（可由其他账户支付租金）
+    OpenDcaV2(OpenDcaV2Args),
+    /// 用户关闭定投订单
+    CloseDca,
+    /// 订单完成后由 keeper 关闭
+    EndAndClose,
+}
+
+impl DcaIx {
+    /// 指令名称，与 Jupiter DCA IDL 中的命名一致
+    pub fn name(&self) -> &'static str {
+        match self {
+            DcaIx::OpenDca(_) => "openDca",
+            DcaIx::OpenDcaV2(_) => "openDcaV2",
+            DcaIx::CloseDca => "closeDca",
+            DcaIx::EndAndClose => "endAndClose",
+        }
+    }
+}
+
+/// 解码 Jupiter DCA 指令数据
+///
+/// # 参数
+///
+/// * `data` - base58 编码的指令数据字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<DcaIx>`，不需要解析的指令返回 `MonitorError::NoMatchingInstruction`
+pub fn decode_dca_ix(data: &str) -> Result<DcaIx> {
+    let bytes = bs58::decode(data).into_vec()?;
+    if bytes.len() < 8 {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    }
+    let (discriminator, mut args) = bytes.split_at(8);
+
+    let ix = match <[u8; 8]>::try_from(discriminator)? {
+        OPEN_DCA => DcaIx::OpenDca(OpenDcaArgs::deserialize(&mut args)?),
+        OPEN_DCA_V2 => DcaIx::OpenDcaV2(OpenDcaV2Args::deserialize(&mut args)?),
+        CLOSE_DCA => DcaIx::CloseDca,
+        END_AND_CLOSE => DcaIx::EndAndClose,
+        _ => return Err(MonitorError::NoMatchingInstruction.into()),
+    };
+
+    Ok(ix)
+}
+
+/// 创建定投订单时记录的事件
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct OpenedEvent {
+    pub user_key: [u8; 32],       // 用户钱包
+    pub dca_key: [u8; 32],        // 定投订单地址
+    pub in_deposited: u64,        // 存入的输入代币数量
+    pub input_mint: [u8; 32],     // 输入代币 Mint 地址
+    pub output_mint: [u8; 32],    // 买入代币 Mint 地址
+    pub cycle_frequency: i64,     // 每期间隔（秒）
+    pub in_amount_per_cycle: u64, // 每期买入使用的输入代币数量
+    pub created_at: i64,          // 创建时间（Unix 时间戳）
+}
+
+/// 每期买入成交时记录的事件
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct FilledEvent {
+    pub user_key: [u8; 32],    // 用户钱包
+    pub dca_key: [u8; 32],     // 定投订单地址
+    pub input_mint: [u8; 32],  // 输入代币 Mint 地址
+    pub output_mint: [u8; 32], // 买入代币 Mint 地址
+    pub in_amount: u64,        // 本期使用的输入代币数量
+    pub out_amount: u64,       // 本期买入数量
+    pub fee_mint: [u8; 32],    // 手续费代币 Mint 地址
+    pub fee: u64,              // 手续费
+}
+
+/// 关闭定投订单时记录的事件
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct ClosedEvent {
+    pub user_key: [u8; 32],       // 用户钱包
+    pub dca_key: [u8; 32],        // 定投订单地址
+    pub in_deposited: u64,        // 存入的输入代币数量
+    pub input_mint: [u8; 32],     // 输入代币 Mint 地址
+    pub output_mint: [u8; 32],    // 买入代币 Mint 地址
+    pub cycle_frequency: i64,     // 每期间隔（秒）
+    pub in_amount_per_cycle: u64, // 每期买入使用的输入代币数量
+    pub created_at: i64,          // 创建时间（Unix 时间戳）
+    pub total_in_withdrawn: u64,  // 取回的输入代币数量合计
+    pub total_out_withdrawn: u64, // 取回的买入代币数量合计
+    pub unfilled_amount: u64,     // 未成交的输入代币数量
+    pub user_closed: bool,        // 是否由用户主动关闭
+}
+
+/// Jupiter DCA 程序记录的事件
+#[derive(Debug, Clone)]
+pub enum DcaEvent {
+    Opened(OpenedEvent),
+    Filled(FilledEvent),
+    Closed(ClosedEvent),
+}
+
+/// 解码 Jupiter DCA 程序通过调用自身记录的事件
+///
+/// # 参数
+///
+/// * `data` - base58 编码的内部指令数据字符串
+///
+/// # 返回值
+///
+/// 不是创建、成交或关闭事件时返回 None
+pub fn decode_dca_event(data: &str) -> Option<DcaEvent> {
+    decode_anchor_event(data, OPENED_EVENT)
+        .map(DcaEvent::Opened)
+        .or_else(|| decode_anchor_event(data, FILLED_EVENT).map(DcaEvent::Filled))
+        .or_else(|| decode_anchor_event(data, CLOSED_EVENT).map(DcaEvent::Closed))
+}
+
+/// 判断交易日志中是否包含创建、成交或关闭定投订单的指令
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_dca_action(logs: &[String]) -> bool {
+    logs.iter().any(|log| {
+        log.strip_prefix("Program log: ")
+            .is_some_and(|log| DCA_ACTION_LOGS.contains(&log))
+    })
+}
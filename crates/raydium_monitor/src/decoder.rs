@@ -1,8 +1,7 @@
 use anyhow::Result;
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_sdk::borsh1::try_from_slice_unchecked;
 
-use crate::model::SwapIxData;
+use crate::model::{DepositIxData, MonitorError, RaydiumIx, SwapIxData, WithdrawIxData};
 
 /// 解码 Raydium 指令数据
 ///
@@ -21,8 +20,8 @@ where
 {
     // 将 base58 编码的数据解码为字节数组
     let data_slice = bs58::decode(data).into_vec()?;
-    // 反序列化为指定的结构体类型
-    let d = try_from_slice_unchecked::<T>(&data_slice)?;
+    // 反序列化为指定的结构体类型（允许尾部存在多余字节）
+    let d = T::deserialize(&mut data_slice.as_slice())?;
 
     Ok(d)
 }
@@ -32,3 +31,65 @@ pub fn decode_instruction_data(data: &Option<String>) -> Result<Option<SwapIxDat
         .map(|d| decode_ix_data::<SwapIxData>(d))
         .transpose()
 }
+
+/// 解码 Raydium AMM v4 指令数据
+///
+/// 根据第一个字节的指令标识选择对应的数据结构。deposit 和 withdraw 新版指令在末尾
+/// 追加了最少数量参数，存在时一并解析。
+///
+/// # 参数
+///
+/// * `data` - base58 编码的指令数据字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<RaydiumIx>`，未知的指令标识返回 `MonitorError::UnknownInstruction`
+pub fn decode_raydium_ix(data: &str) -> Result<RaydiumIx> {
+    let bytes = bs58::decode(data).into_vec()?;
+    let Some(&tag) = bytes.first() else {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    };
+
+    let ix = match tag {
+        0 => RaydiumIx::Initialize(parse(&bytes)?),
+        1 => RaydiumIx::Initialize2(parse(&bytes)?),
+        2 => RaydiumIx::MonitorStep(parse(&bytes)?),
+        3 => {
+            let mut ix: DepositIxData = parse(&bytes)?;
+            ix.other_amount_min = read_trailing_u64(&bytes, 25);
+            RaydiumIx::Deposit(ix)
+        }
+        4 => {
+            let mut ix: WithdrawIxData = parse(&bytes)?;
+            ix.min_coin_amount = read_trailing_u64(&bytes, 9);
+            ix.min_pc_amount = read_trailing_u64(&bytes, 17);
+            RaydiumIx::Withdraw(ix)
+        }
+        5 => RaydiumIx::MigrateToOpenBook,
+        6 => RaydiumIx::SetParams(parse(&bytes)?),
+        7 => RaydiumIx::WithdrawPnl,
+        8 => RaydiumIx::WithdrawSrm(parse(&bytes)?),
+        9 => RaydiumIx::SwapBaseIn(parse(&bytes)?),
+        10 => RaydiumIx::PreInitialize(parse(&bytes)?),
+        11 => RaydiumIx::SwapBaseOut(parse(&bytes)?),
+        12 => RaydiumIx::SimulateInfo(parse(&bytes)?),
+        13 => RaydiumIx::AdminCancelOrders(parse(&bytes)?),
+        14 => RaydiumIx::CreateConfigAccount,
+        15 => RaydiumIx::UpdateConfigAccount(parse(&bytes)?),
+        other => return Err(MonitorError::UnknownInstruction(other).into()),
+    };
+
+    Ok(ix)
+}
+
+/// 反序列化已解码的指令数据（允许尾部存在多余字节）
+fn parse<T: BorshDeserialize>(bytes: &[u8]) -> Result<T> {
+    Ok(T::deserialize(&mut &bytes[..])?)
+}
+
+/// 读取固定字段之后可选的小端序 u64，数据长度不足时返回 None
+fn read_trailing_u64(bytes: &[u8], offset: usize) -> Option<u64> {
+    bytes
+        .get(offset..offset + 8)
+        .map(|slice| u64::from_le_bytes(slice.try_into().unwrap()))
+}
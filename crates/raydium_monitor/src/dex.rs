@@ -0,0 +1,946 @@
+use std::collections::HashMap;
+
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiInstruction, UiMessage,
+    UiParsedInstruction, UiPartiallyDecodedInstruction,
+};
+use tracing::warn;
+
+use crate::clmm::{
+    contains_clmm_action, contains_clmm_pool_creation, decode_clmm_ix, executed_price,
+    sqrt_price_x64_to_price, tick_to_price, ClmmCreatePoolAccounts, ClmmIx, ClmmPositionAccounts,
+    ClmmSwapAccounts, RAYDIUM_CLMM,
+};
+use crate::dca::{contains_dca_action, decode_dca_event, DcaEvent, JUPITER_DCA};
+use crate::decoder::decode_raydium_ix;
+use crate::extract::{contains_amm_action, contains_pool_initialization};
+use crate::jupiter::{
+    contains_jupiter_route, decode_jupiter_ix, decode_swap_event, SwapEvent, JUPITER_V6,
+};
+use crate::layout::{Initialize2Accounts, LiquidityAccounts, SwapAccounts};
+use crate::model::{
+    ClmmPoolCreated, ClmmPositionClosed, ClmmPositionOpened, ClmmSwap, DcaClosed, DcaFilled,
+    DcaOpened, Dex, JupiterRoute, LiquidityChange, MonitorError, MonitorEvent, NewPool, RaydiumIx,
+    RouteLeg, Swap, TokenAmount,
+};
+use crate::phoenix::{contains_phoenix_fill, decode_phoenix_log, orderbook_fills, PHOENIX};
+use crate::whirlpool::{
+    contains_whirlpool_action, contains_whirlpool_pool_creation, decode_whirlpool_ix,
+    InitializePoolArgs, InitializePoolV2Args, WhirlpoolInitializePoolAccounts, WhirlpoolIx,
+    WhirlpoolSwapAccounts, ORCA_WHIRLPOOL,
+};
+
+/// Raydium 流动性池 v4 程序 ID
+const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+/// DEX 协议解码器
+///
+/// 每个解码器负责一个程序：根据日志判断是否需要获取交易，并把该程序的指令（连同它下层的内部指令）
+/// 解码为 [`MonitorEvent`]。直接调用和经由聚合器调用的指令都会交给解码器，内置解码器见
+/// [`AmmV4Decoder`]、[`ClmmDecoder`]、[`WhirlpoolDecoder`]、[`PhoenixDecoder`]、[`JupiterDecoder`]
+/// 和 [`DcaDecoder`]，其他协议实现该 trait 后注册到 `services::DecoderRegistry` 即可接入监控。
+pub trait DexDecoder: Send + Sync {
+    /// 解码器名称，用于日志
+    fn name(&self) -> &str;
+
+    /// 解码的程序 ID，监控按该地址订阅日志
+    fn program_id(&self) -> &str;
+
+    /// 根据日志判断是否需要获取交易
+    ///
+    /// 日志订阅的过滤条件，每条推送都会经过这里，需保持轻量。
+    ///
+    /// # 参数
+    ///
+    /// * `logs` - 交易日志
+    /// * `new_pools_only` - 是否只推送新建流动性池事件
+    fn is_relevant(&self, logs: &[String], new_pools_only: bool) -> bool;
+
+    /// 解码一条指令
+    ///
+    /// # 参数
+    ///
+    /// * `ix` - 调用该程序的指令
+    /// * `inner` - 该指令下层的内部指令
+    /// * `ctx` - 交易签名和代币账户信息
+    ///
+    /// # 返回值
+    ///
+    /// 返回该指令产生的事件，不需要解析的指令返回空列表
+    fn decode(
+        &self,
+        ix: &UiPartiallyDecodedInstruction,
+        inner: &[UiInstruction],
+        ctx: &DecodeContext,
+    ) -> Vec<MonitorEvent>;
+}
+
+/// 解码指令时可用的交易信息
+pub struct DecodeContext<'a> {
+    signature: &'a str,                            // 交易签名
+    signer: &'a str,                               // 交易签名者（手续费支付者）
+    slot: u64,                                     // 所在 slot
+    timestamp: Option<i64>,                        // 区块时间（Unix 时间戳）
+    token_accounts: HashMap<String, (String, u8)>, // 代币账户地址到（Mint 地址，精度）的映射
+}
+
+impl DecodeContext<'_> {
+    /// 交易签名
+    pub fn signature(&self) -> &str {
+        self.signature
+    }
+
+    /// 交易签名者（手续费支付者）
+    pub fn signer(&self) -> &str {
+        self.signer
+    }
+
+    /// 交易所在 slot
+    pub fn slot(&self) -> u64 {
+        self.slot
+    }
+
+    /// 区块时间（Unix 时间戳），节点未记录时为 None
+    pub fn timestamp(&self) -> Option<i64> {
+        self.timestamp
+    }
+
+    /// 代币账户的 Mint 地址，取自交易的代币余额记录
+    pub fn mint(&self, account: &str) -> Option<String> {
+        self.token_accounts
+            .get(account)
+            .map(|(mint, _)| mint.clone())
+    }
+
+    /// 代币账户的精度，取自交易的代币余额记录
+    pub fn decimals(&self, account: &str) -> Option<u8> {
+        self.token_accounts
+            .get(account)
+            .map(|(_, decimals)| *decimals)
+    }
+
+    /// 按代币账户的 Mint 地址和精度构造标准化事件中的代币数量，Mint 地址未知时返回 None
+    pub fn token_amount(&self, account: &str, amount: u64) -> Option<TokenAmount> {
+        self.token_accounts
+            .get(account)
+            .map(|(mint, decimals)| TokenAmount {
+                mint: mint.clone(),
+                amount,
+                decimals: Some(*decimals),
+            })
+    }
+}
+
+/// 使用指定的解码器从交易中提取事件
+///
+/// 按指令顺序遍历 `jsonParsed` 编码的交易中调用解码器程序的指令，包括顶层指令和内部指令。
+///
+/// # 参数
+///
+/// * `decoder` - DEX 协议解码器
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+///
+/// # 返回值
+///
+/// 返回按指令顺序排列的事件
+pub fn decode_transaction(
+    decoder: &dyn DexDecoder,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<MonitorEvent> {
+    let EncodedTransaction::Json(t) = &tx.transaction.transaction else {
+        return Vec::new();
+    };
+    let UiMessage::Parsed(message) = &t.message else {
+        return Vec::new();
+    };
+
+    let account_keys: Vec<&str> = message
+        .account_keys
+        .iter()
+        .map(|key| key.pubkey.as_str())
+        .collect();
+    let ctx = DecodeContext {
+        signature,
+        signer: account_keys.first().copied().unwrap_or_default(),
+        slot: tx.slot,
+        timestamp: tx.block_time,
+        token_accounts: token_accounts(tx, &account_keys),
+    };
+    let program_id = decoder.program_id();
+
+    let mut events = Vec::new();
+    for (index, ix) in message.instructions.iter().enumerate() {
+        let inner = inner_instructions(tx, index);
+        if let Some(ix) = program_instruction(ix, program_id) {
+            events.extend(decoder.decode(ix, inner, &ctx));
+        }
+        // 经由聚合器或其他程序调用的指令
+        for (position, ix) in inner.iter().enumerate() {
+            if let Some(ix) = program_instruction(ix, program_id) {
+                events.extend(decoder.decode(ix, nested_instructions(inner, position), &ctx));
+            }
+        }
+    }
+    events
+}
+
+/// Raydium AMM v4 解码器：新建流动性池、兑换、添加和移除流动性
+///
+/// 解码为标准化的 `NewPool`、`Swap`、`LiquidityAdded`、`LiquidityRemoved` 事件，实际数量取自该指令的
+/// 内部转账，代币 Mint 地址和精度取自交易的代币余额记录。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AmmV4Decoder;
+
+impl DexDecoder for AmmV4Decoder {
+    fn name(&self) -> &str {
+        "AMM v4"
+    }
+
+    fn program_id(&self) -> &str {
+        RAYDIUM_AMM_V4
+    }
+
+    fn is_relevant(&self, logs: &[String], new_pools_only: bool) -> bool {
+        contains_pool_initialization(logs) || (!new_pools_only && contains_amm_action(logs))
+    }
+
+    fn decode(
+        &self,
+        ix: &UiPartiallyDecodedInstruction,
+        inner: &[UiInstruction],
+        ctx: &DecodeContext,
+    ) -> Vec<MonitorEvent> {
+        let Ok(decoded) = decode_raydium_ix(&ix.data) else {
+            return Vec::new();
+        };
+        let signature = ctx.signature().to_string();
+        let signer = ctx.signer().to_string();
+
+        let event = match decoded {
+            RaydiumIx::Initialize2(args) => {
+                Initialize2Accounts::from_accounts(&ix.accounts).map(|accounts| {
+                    let token = |mint: String, vault: &str, amount: u64| TokenAmount {
+                        mint,
+                        amount,
+                        decimals: ctx.decimals(vault),
+                    };
+                    Some(MonitorEvent::NewPool(NewPool {
+                        program_id: RAYDIUM_AMM_V4.to_string(),
+                        signature,
+                        signer,
+                        slot: ctx.slot(),
+                        timestamp: ctx.timestamp(),
+                        pool: accounts.amm,
+                        token_a: token(
+                            accounts.coin_mint,
+                            &accounts.coin_vault,
+                            args.init_coin_amount,
+                        ),
+                        token_b: token(accounts.pc_mint, &accounts.pc_vault, args.init_pc_amount),
+                        open_time: Some(args.opentime),
+                    }))
+                })
+            }
+            RaydiumIx::SwapBaseIn(_) | RaydiumIx::SwapBaseOut(_) => {
+                // 指令参数中的滑点保护数量
+                let (min_amount_out, max_amount_in) = match &decoded {
+                    RaydiumIx::SwapBaseIn(args) => (Some(args.minimum_amount_out), None),
+                    RaydiumIx::SwapBaseOut(args) => (None, Some(args.max_amount_in)),
+                    _ => (None, None),
+                };
+                SwapAccounts::from_accounts(&ix.accounts).map(|accounts| {
+                    // 转入基础代币账户的是卖出基础代币的兑换
+                    let (input_vault, output_vault) =
+                        if transfer_amount(inner, "destination", &accounts.coin_vault).is_some() {
+                            (&accounts.coin_vault, &accounts.pc_vault)
+                        } else {
+                            (&accounts.pc_vault, &accounts.coin_vault)
+                        };
+                    let amount_in = transfer_amount(inner, "destination", input_vault)?;
+                    let amount_out = transfer_amount(inner, "source", output_vault)?;
+                    Some(MonitorEvent::Swap(Swap {
+                        program_id: RAYDIUM_AMM_V4.to_string(),
+                        signature,
+                        signer,
+                        slot: ctx.slot(),
+                        timestamp: ctx.timestamp(),
+                        input: ctx.token_amount(input_vault, amount_in)?,
+                        output: ctx.token_amount(output_vault, amount_out)?,
+                        min_amount_out,
+                        max_amount_in,
+                        pool: accounts.amm.clone(),
+                    }))
+                })
+            }
+            RaydiumIx::Deposit(_) | RaydiumIx::Withdraw(_) => {
+                let deposit = matches!(decoded, RaydiumIx::Deposit(_));
+                LiquidityAccounts::from_accounts(&ix.accounts, decoded.name()).map(|accounts| {
+                    // 添加流动性时转入流动性池，移除时从流动性池转出
+                    let field = if deposit { "destination" } else { "source" };
+                    let amount = |vault: &str| {
+                        ctx.token_amount(vault, transfer_amount(inner, field, vault)?)
+                    };
+                    let change = LiquidityChange {
+                        program_id: RAYDIUM_AMM_V4.to_string(),
+                        signature,
+                        signer,
+                        slot: ctx.slot(),
+                        timestamp: ctx.timestamp(),
+                        token_a: amount(&accounts.coin_vault)?,
+                        token_b: amount(&accounts.pc_vault)?,
+                        pool: accounts.amm,
+                    };
+                    Some(if deposit {
+                        MonitorEvent::LiquidityAdded(change)
+                    } else {
+                        MonitorEvent::LiquidityRemoved(change)
+                    })
+                })
+            }
+            _ => return Vec::new(),
+        };
+
+        match event {
+            Ok(event) => event.into_iter().collect(),
+            Err(e) => {
+                warn!("解析 AMM v4 指令失败: {}, {}", ctx.signature(), e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Raydium CLMM 解码器：建池、兑换、开仓、平仓
+///
+/// 代币精度取自交易的代币余额记录，兑换的实际数量取自该指令的内部转账。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ClmmDecoder;
+
+impl DexDecoder for ClmmDecoder {
+    fn name(&self) -> &str {
+        "CLMM"
+    }
+
+    fn program_id(&self) -> &str {
+        RAYDIUM_CLMM
+    }
+
+    fn is_relevant(&self, logs: &[String], new_pools_only: bool) -> bool {
+        if new_pools_only {
+            contains_clmm_pool_creation(logs)
+        } else {
+            contains_clmm_action(logs)
+        }
+    }
+
+    fn decode(
+        &self,
+        ix: &UiPartiallyDecodedInstruction,
+        inner: &[UiInstruction],
+        ctx: &DecodeContext,
+    ) -> Vec<MonitorEvent> {
+        let Ok(decoded) = decode_clmm_ix(&ix.data) else {
+            return Vec::new();
+        };
+        let signature = ctx.signature();
+        let decimals = |account: &str| ctx.decimals(account);
+
+        // token22 版本的开仓指令没有元数据账户，账户布局不同
+        let token22_nft = matches!(decoded, ClmmIx::OpenPositionWithToken22Nft(_));
+        let event = match decoded {
+            ClmmIx::CreatePool(args) => {
+                ClmmCreatePoolAccounts::from_accounts(&ix.accounts).map(|accounts| {
+                    let price = decimals(&accounts.token_vault_0)
+                        .zip(decimals(&accounts.token_vault_1))
+                        .map(|(d0, d1)| sqrt_price_x64_to_price(args.sqrt_price_x64, d0, d1));
+                    MonitorEvent::ClmmPoolCreated(ClmmPoolCreated {
+                        dex: Dex::RaydiumClmm,
+                        signature: signature.to_string(),
+                        pool: accounts.pool_state,
+                        creator: accounts.pool_creator,
+                        token_mint_0: accounts.token_mint_0,
+                        token_mint_1: accounts.token_mint_1,
+                        open_time: args.open_time,
+                        price,
+                    })
+                })
+            }
+            ClmmIx::Swap(_) | ClmmIx::SwapV2(_) => ClmmSwapAccounts::from_accounts(&ix.accounts)
+                .map(|accounts| {
+                    let amount_in =
+                        transfer_amount(inner, "destination", &accounts.input_vault).unwrap_or(0);
+                    let amount_out =
+                        transfer_amount(inner, "source", &accounts.output_vault).unwrap_or(0);
+                    let price = decimals(&accounts.input_vault)
+                        .zip(decimals(&accounts.output_vault))
+                        .and_then(|(d_in, d_out)| {
+                            executed_price(amount_in, d_in, amount_out, d_out)
+                        });
+                    MonitorEvent::ClmmSwap(ClmmSwap {
+                        dex: Dex::RaydiumClmm,
+                        signature: signature.to_string(),
+                        input_mint: ctx.mint(&accounts.input_vault),
+                        output_mint: ctx.mint(&accounts.output_vault),
+                        pool: accounts.pool_state,
+                        owner: accounts.payer,
+                        amount_in,
+                        amount_out,
+                        price,
+                    })
+                }),
+            ClmmIx::OpenPosition(args)
+            | ClmmIx::OpenPositionV2(args)
+            | ClmmIx::OpenPositionWithToken22Nft(args) => {
+                ClmmPositionAccounts::from_open_position(&ix.accounts, token22_nft).map(
+                    |accounts| {
+                        let pool_decimals = accounts
+                            .token_vault_0
+                            .as_deref()
+                            .and_then(decimals)
+                            .zip(accounts.token_vault_1.as_deref().and_then(decimals));
+                        MonitorEvent::ClmmPositionOpened(ClmmPositionOpened {
+                            signature: signature.to_string(),
+                            pool: accounts.pool_state.unwrap_or_default(),
+                            owner: accounts.owner,
+                            position_nft_mint: accounts.position_nft_mint,
+                            tick_lower: args.tick_lower_index,
+                            tick_upper: args.tick_upper_index,
+                            price_lower: pool_decimals
+                                .map(|(d0, d1)| tick_to_price(args.tick_lower_index, d0, d1)),
+                            price_upper: pool_decimals
+                                .map(|(d0, d1)| tick_to_price(args.tick_upper_index, d0, d1)),
+                        })
+                    },
+                )
+            }
+            ClmmIx::ClosePosition => {
+                ClmmPositionAccounts::from_close_position(&ix.accounts).map(|accounts| {
+                    MonitorEvent::ClmmPositionClosed(ClmmPositionClosed {
+                        signature: signature.to_string(),
+                        owner: accounts.owner,
+                        position_nft_mint: accounts.position_nft_mint,
+                    })
+                })
+            }
+        };
+
+        match event {
+            Ok(event) => vec![event],
+            Err(e) => {
+                warn!("解析 CLMM 指令失败: {}, {}", signature, e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Orca Whirlpool 解码器：建池、兑换，与 CLMM 事件使用相同的事件模型
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhirlpoolDecoder;
+
+impl DexDecoder for WhirlpoolDecoder {
+    fn name(&self) -> &str {
+        "Whirlpool"
+    }
+
+    fn program_id(&self) -> &str {
+        ORCA_WHIRLPOOL
+    }
+
+    fn is_relevant(&self, logs: &[String], new_pools_only: bool) -> bool {
+        if new_pools_only {
+            contains_whirlpool_pool_creation(logs)
+        } else {
+            contains_whirlpool_action(logs)
+        }
+    }
+
+    fn decode(
+        &self,
+        ix: &UiPartiallyDecodedInstruction,
+        inner: &[UiInstruction],
+        ctx: &DecodeContext,
+    ) -> Vec<MonitorEvent> {
+        let Ok(decoded) = decode_whirlpool_ix(&ix.data) else {
+            return Vec::new();
+        };
+        let signature = ctx.signature();
+        let decimals = |account: &str| ctx.decimals(account);
+        let v2 = matches!(
+            decoded,
+            WhirlpoolIx::InitializePoolV2(_) | WhirlpoolIx::SwapV2(_)
+        );
+
+        let event = match decoded {
+            WhirlpoolIx::InitializePool(InitializePoolArgs {
+                initial_sqrt_price, ..
+            })
+            | WhirlpoolIx::InitializePoolV2(InitializePoolV2Args {
+                initial_sqrt_price, ..
+            }) => {
+                WhirlpoolInitializePoolAccounts::from_accounts(&ix.accounts, v2).map(|accounts| {
+                    let price = decimals(&accounts.token_vault_a)
+                        .zip(decimals(&accounts.token_vault_b))
+                        .map(|(da, db)| sqrt_price_x64_to_price(initial_sqrt_price, da, db));
+                    MonitorEvent::ClmmPoolCreated(ClmmPoolCreated {
+                        dex: Dex::OrcaWhirlpool,
+                        signature: signature.to_string(),
+                        pool: accounts.whirlpool,
+                        creator: accounts.funder,
+                        token_mint_0: accounts.token_mint_a,
+                        token_mint_1: accounts.token_mint_b,
+                        open_time: 0,
+                        price,
+                    })
+                })
+            }
+            WhirlpoolIx::Swap(ref args) | WhirlpoolIx::SwapV2(ref args) => {
+                WhirlpoolSwapAccounts::from_accounts(&ix.accounts, v2).map(|accounts| {
+                    let (input_vault, output_vault) = accounts.vaults(args.a_to_b);
+                    let amount_in = transfer_amount(inner, "destination", input_vault).unwrap_or(0);
+                    let amount_out = transfer_amount(inner, "source", output_vault).unwrap_or(0);
+                    let price = decimals(input_vault).zip(decimals(output_vault)).and_then(
+                        |(d_in, d_out)| executed_price(amount_in, d_in, amount_out, d_out),
+                    );
+                    MonitorEvent::ClmmSwap(ClmmSwap {
+                        dex: Dex::OrcaWhirlpool,
+                        signature: signature.to_string(),
+                        input_mint: ctx.mint(input_vault),
+                        output_mint: ctx.mint(output_vault),
+                        pool: accounts.whirlpool.clone(),
+                        owner: accounts.token_authority.clone(),
+                        amount_in,
+                        amount_out,
+                        price,
+                    })
+                })
+            }
+        };
+
+        match event {
+            Ok(event) => vec![event],
+            Err(e) => {
+                warn!("解析 Whirlpool 指令失败: {}, {}", signature, e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Phoenix 解码器：成交
+///
+/// Phoenix 每条会产生事件的指令都会通过调用自身的 Log 指令记录市场事件，解码器只解析 Log 指令。
+/// 返回的成交事件只包含 tick 和 lot，需要调用
+/// [`PhoenixMarket::apply`](crate::phoenix::PhoenixMarket::apply) 换算价格和数量。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhoenixDecoder;
+
+impl DexDecoder for PhoenixDecoder {
+    fn name(&self) -> &str {
+        "Phoenix"
+    }
+
+    fn program_id(&self) -> &str {
+        PHOENIX
+    }
+
+    /// 订单簿没有建池事件，开启 `new_pools_only` 时不获取交易
+    fn is_relevant(&self, logs: &[String], new_pools_only: bool) -> bool {
+        !new_pools_only && contains_phoenix_fill(logs)
+    }
+
+    fn decode(
+        &self,
+        ix: &UiPartiallyDecodedInstruction,
+        _inner: &[UiInstruction],
+        ctx: &DecodeContext,
+    ) -> Vec<MonitorEvent> {
+        let Ok((header, events)) = decode_phoenix_log(&ix.data) else {
+            return Vec::new();
+        };
+        orderbook_fills(&header, &events, ctx.signature())
+            .into_iter()
+            .map(MonitorEvent::OrderbookFill)
+            .collect()
+    }
+}
+
+/// Jupiter v6 解码器：路由兑换
+///
+/// 每一跳的 DEX 和实际数量优先取自 Jupiter 记录的 SwapEvent；没有 SwapEvent 的交易按每一跳
+/// 内部转账的第一笔和最后一笔计算。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JupiterDecoder;
+
+impl DexDecoder for JupiterDecoder {
+    fn name(&self) -> &str {
+        "Jupiter"
+    }
+
+    fn program_id(&self) -> &str {
+        JUPITER_V6
+    }
+
+    /// 路由没有建池事件，开启 `new_pools_only` 时不获取交易
+    fn is_relevant(&self, logs: &[String], new_pools_only: bool) -> bool {
+        !new_pools_only && contains_jupiter_route(logs)
+    }
+
+    fn decode(
+        &self,
+        ix: &UiPartiallyDecodedInstruction,
+        inner: &[UiInstruction],
+        ctx: &DecodeContext,
+    ) -> Vec<MonitorEvent> {
+        let Ok(decoded) = decode_jupiter_ix(&ix.data) else {
+            return Vec::new();
+        };
+        let Some(user) = ix.accounts.get(decoded.user_account_index()) else {
+            warn!(
+                "解析 Jupiter 指令失败: {}, {}",
+                ctx.signature(),
+                MonitorError::AccountLayoutMismatch {
+                    instruction: decoded.name(),
+                    actual: ix.accounts.len(),
+                }
+            );
+            return Vec::new();
+        };
+
+        // 每一跳是路由指令下一层的 DEX 指令
+        let hop_height = ix.stack_height.unwrap_or(1) + 1;
+        let legs = route_legs(inner, hop_height, ctx);
+        let input_mint = legs.first().and_then(|leg| leg.input_mint.clone());
+        let output_mint = legs.last().and_then(|leg| leg.output_mint.clone());
+        // 拆单路由中多条路径的输入（输出）代币相同，实际数量按代币合计
+        let in_amount = legs
+            .iter()
+            .filter(|leg| leg.input_mint == input_mint)
+            .map(|leg| leg.input_amount)
+            .sum();
+        let out_amount = legs
+            .iter()
+            .filter(|leg| leg.output_mint == output_mint)
+            .map(|leg| leg.output_amount)
+            .sum();
+
+        vec![MonitorEvent::JupiterRoute(JupiterRoute {
+            signature: ctx.signature().to_string(),
+            user: user.clone(),
+            instruction: decoded,
+            input_mint,
+            output_mint,
+            in_amount,
+            out_amount,
+            legs,
+        })]
+    }
+}
+
+/// Jupiter DCA 解码器：创建、成交、关闭定投订单
+///
+/// 定投订单的成交由 keeper 发起，事件取自 DCA 程序通过调用自身记录的事件。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DcaDecoder;
+
+impl DexDecoder for DcaDecoder {
+    fn name(&self) -> &str {
+        "DCA"
+    }
+
+    fn program_id(&self) -> &str {
+        JUPITER_DCA
+    }
+
+    fn is_relevant(&self, logs: &[String], _new_pools_only: bool) -> bool {
+        contains_dca_action(logs)
+    }
+
+    fn decode(
+        &self,
+        ix: &UiPartiallyDecodedInstruction,
+        _inner: &[UiInstruction],
+        ctx: &DecodeContext,
+    ) -> Vec<MonitorEvent> {
+        let Some(event) = decode_dca_event(&ix.data) else {
+            return Vec::new();
+        };
+        let signature = ctx.signature().to_string();
+        let key = |key: [u8; 32]| bs58::encode(key).into_string();
+        let event = match event {
+            DcaEvent::Opened(event) => MonitorEvent::DcaOpened(DcaOpened {
+                signature,
+                dca: key(event.dca_key),
+                user: key(event.user_key),
+                input_mint: key(event.input_mint),
+                output_mint: key(event.output_mint),
+                in_deposited: event.in_deposited,
+                in_amount_per_cycle: event.in_amount_per_cycle,
+                cycle_frequency: event.cycle_frequency,
+                created_at: event.created_at,
+            }),
+            DcaEvent::Filled(event) => MonitorEvent::DcaFilled(DcaFilled {
+                signature,
+                dca: key(event.dca_key),
+                user: key(event.user_key),
+                input_mint: key(event.input_mint),
+                output_mint: key(event.output_mint),
+                in_amount: event.in_amount,
+                out_amount: event.out_amount,
+                fee_mint: key(event.fee_mint),
+                fee: event.fee,
+            }),
+            DcaEvent::Closed(event) => MonitorEvent::DcaClosed(DcaClosed {
+                signature,
+                dca: key(event.dca_key),
+                user: key(event.user_key),
+                input_mint: key(event.input_mint),
+                output_mint: key(event.output_mint),
+                in_deposited: event.in_deposited,
+                total_in_withdrawn: event.total_in_withdrawn,
+                total_out_withdrawn: event.total_out_withdrawn,
+                unfilled_amount: event.unfilled_amount,
+                user_closed: event.user_closed,
+            }),
+        };
+        vec![event]
+    }
+}
+
+/// Jupiter 路由内部指令中的一步
+enum RouteStep<'a> {
+    /// 调用 DEX 程序的一跳，以及它下层的内部指令
+    Hop(&'a UiPartiallyDecodedInstruction, &'a [UiInstruction]),
+    /// 一跳完成后 Jupiter 记录的 SwapEvent
+    Event(SwapEvent),
+}
+
+/// 遍历 Jupiter 路由指令的内部指令，得到每一跳的 DEX、流动性池和数量
+fn route_legs(
+    instructions: &[UiInstruction],
+    hop_height: u32,
+    ctx: &DecodeContext,
+) -> Vec<RouteLeg> {
+    let steps: Vec<RouteStep> = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(position, ix)| {
+            let UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(ix)) = ix else {
+                return None;
+            };
+            if ix.program_id == JUPITER_V6 {
+                decode_swap_event(&ix.data).map(RouteStep::Event)
+            } else if ix.stack_height == Some(hop_height) {
+                Some(RouteStep::Hop(
+                    ix,
+                    nested_instructions(instructions, position),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if !steps.iter().any(|step| matches!(step, RouteStep::Event(_))) {
+        return steps
+            .iter()
+            .filter_map(|step| match step {
+                RouteStep::Hop(ix, nested) => Some(transfer_leg(ix, nested, ctx)),
+                RouteStep::Event(_) => None,
+            })
+            .collect();
+    }
+
+    let mut legs = Vec::new();
+    let mut hop = None;
+    for step in steps {
+        match step {
+            RouteStep::Hop(ix, _) => hop = Some(ix),
+            RouteStep::Event(event) => {
+                let program_id = bs58::encode(event.amm).into_string();
+                // SwapEvent 紧跟在对应的一跳之后
+                let pool = hop
+                    .take()
+                    .filter(|ix| ix.program_id == program_id)
+                    .and_then(hop_pool);
+                legs.push(RouteLeg {
+                    program_id,
+                    pool,
+                    input_mint: Some(bs58::encode(event.input_mint).into_string()),
+                    input_amount: event.input_amount,
+                    output_mint: Some(bs58::encode(event.output_mint).into_string()),
+                    output_amount: event.output_amount,
+                });
+            }
+        }
+    }
+    legs
+}
+
+/// 按一跳内部转账的第一笔（转入流动性池）和最后一笔（转出流动性池）计算数量
+fn transfer_leg(
+    ix: &UiPartiallyDecodedInstruction,
+    nested: &[UiInstruction],
+    ctx: &DecodeContext,
+) -> RouteLeg {
+    let transfers: Vec<_> = nested.iter().filter_map(token_transfer).collect();
+    let first = transfers.first();
+    let last = transfers.last();
+    RouteLeg {
+        program_id: ix.program_id.clone(),
+        pool: hop_pool(ix),
+        input_mint: first.and_then(|(_, destination, _)| ctx.mint(destination)),
+        input_amount: first.map_or(0, |(_, _, amount)| *amount),
+        output_mint: last.and_then(|(source, _, _)| ctx.mint(source)),
+        output_amount: last.map_or(0, |(_, _, amount)| *amount),
+    }
+}
+
+/// 按已知 DEX 的兑换指令账户布局读取流动性池地址
+fn hop_pool(ix: &UiPartiallyDecodedInstruction) -> Option<String> {
+    match ix.program_id.as_str() {
+        RAYDIUM_AMM_V4 => SwapAccounts::from_accounts(&ix.accounts)
+            .ok()
+            .map(|accounts| accounts.amm),
+        RAYDIUM_CLMM => ClmmSwapAccounts::from_accounts(&ix.accounts)
+            .ok()
+            .map(|accounts| accounts.pool_state),
+        ORCA_WHIRLPOOL => {
+            let v2 = matches!(decode_whirlpool_ix(&ix.data).ok()?, WhirlpoolIx::SwapV2(_));
+            WhirlpoolSwapAccounts::from_accounts(&ix.accounts, v2)
+                .ok()
+                .map(|accounts| accounts.whirlpool)
+        }
+        _ => None,
+    }
+}
+
+/// 解析代币转账指令，返回（转出账户，转入账户，数量）
+fn token_transfer(ix: &UiInstruction) -> Option<(&str, &str, u64)> {
+    let UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) = ix else {
+        return None;
+    };
+    let info = parsed.parsed.get("info")?;
+    let amount = info
+        .get("amount")
+        .or_else(|| info.get("tokenAmount")?.get("amount"))?
+        .as_str()?
+        .parse()
+        .ok()?;
+    Some((
+        info.get("source")?.as_str()?,
+        info.get("destination")?.as_str()?,
+        amount,
+    ))
+}
+
+/// 在内部指令中查找涉及指定代币账户的转账数量
+///
+/// 支持 `transfer` 和 `transferChecked` 两种转账指令。
+///
+/// # 参数
+///
+/// * `instructions` - 内部指令
+/// * `field` - 匹配的账户字段（`source` 或 `destination`）
+/// * `account` - 代币账户地址
+fn transfer_amount(instructions: &[UiInstruction], field: &str, account: &str) -> Option<u64> {
+    instructions.iter().find_map(|ix| {
+        let UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) = ix else {
+            return None;
+        };
+        let info = parsed.parsed.get("info")?;
+        if info.get(field)?.as_str()? != account {
+            return None;
+        }
+        info.get("amount")
+            .or_else(|| info.get("tokenAmount")?.get("amount"))?
+            .as_str()?
+            .parse()
+            .ok()
+    })
+}
+
+/// 只接受调用指定程序、未被 RPC 解析的指令
+fn program_instruction<'a>(
+    ix: &'a UiInstruction,
+    program_id: &str,
+) -> Option<&'a UiPartiallyDecodedInstruction> {
+    match ix {
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(ix))
+            if ix.program_id == program_id =>
+        {
+            Some(ix)
+        }
+        _ => None,
+    }
+}
+
+/// 返回内部指令列表中指定指令下层的内部指令（调用深度更大的连续指令）
+fn nested_instructions(instructions: &[UiInstruction], position: usize) -> &[UiInstruction] {
+    let Some(height) = stack_height(&instructions[position]) else {
+        return &[];
+    };
+    let rest = &instructions[position + 1..];
+    let len = rest
+        .iter()
+        .position(|ix| stack_height(ix).is_none_or(|h| h <= height))
+        .unwrap_or(rest.len());
+    &rest[..len]
+}
+
+/// 指令的调用深度，顶层指令为 1
+fn stack_height(ix: &UiInstruction) -> Option<u32> {
+    match ix {
+        UiInstruction::Compiled(ix) => ix.stack_height,
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(ix)) => ix.stack_height,
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(ix)) => ix.stack_height,
+    }
+}
+
+/// 从交易的代币余额记录中收集代币账户的 Mint 地址和精度
+fn token_accounts(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    account_keys: &[&str],
+) -> HashMap<String, (String, u8)> {
+    let mut accounts = HashMap::new();
+    let Some(meta) = &tx.transaction.meta else {
+        return accounts;
+    };
+
+    for balances in [&meta.pre_token_balances, &meta.post_token_balances] {
+        if let OptionSerializer::Some(balances) = balances {
+            for balance in balances {
+                if let Some(address) = account_keys.get(balance.account_index as usize) {
+                    accounts.insert(
+                        address.to_string(),
+                        (balance.mint.clone(), balance.ui_token_amount.decimals),
+                    );
+                }
+            }
+        }
+    }
+    accounts
+}
+
+/// 获取指定指令的内部指令
+fn inner_instructions(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    index: usize,
+) -> &[UiInstruction] {
+    match tx
+        .transaction
+        .meta
+        .as_ref()
+        .map(|meta| &meta.inner_instructions)
+    {
+        Some(OptionSerializer::Some(inner)) => inner
+            .iter()
+            .find(|inner| inner.index as usize == index)
+            .map(|inner| inner.instructions.as_slice())
+            .unwrap_or_default(),
+        _ => &[],
+    }
+}
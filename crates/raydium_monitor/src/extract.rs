@@ -0,0 +1,155 @@
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+use crate::dex::{
+    decode_transaction, AmmV4Decoder, ClmmDecoder, DcaDecoder, JupiterDecoder, PhoenixDecoder,
+    WhirlpoolDecoder,
+};
+use crate::model::{JupiterRoute, MonitorEvent, OrderbookFill};
+
+/// 判断交易日志中是否包含 initialize2（创建流动性池）指令
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_pool_initialization(logs: &[String]) -> bool {
+    logs.iter().any(|log| log.contains("initialize2"))
+}
+
+/// 判断交易日志中是否包含 AMM v4 记录的 ray_log（创建流动性池、兑换、添加或移除流动性）
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_amm_action(logs: &[String]) -> bool {
+    logs.iter()
+        .any(|log| log.starts_with("Program log: ray_log"))
+}
+
+/// 从交易中提取 Raydium AMM v4 标准化事件（新建流动性池、兑换、添加和移除流动性）
+///
+/// 处理 `jsonParsed` 编码的交易中调用 AMM v4 程序的指令，包括经由聚合器调用的指令，
+/// 解码规则见 [`AmmV4Decoder`]。
+///
+/// # 参数
+///
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+///
+/// # 返回值
+///
+/// 返回按指令顺序排列的事件
+pub fn extract_amm_events(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<MonitorEvent> {
+    decode_transaction(&AmmV4Decoder, tx, signature)
+}
+
+/// 从交易中提取 Raydium CLMM 事件（建池、兑换、开仓、平仓）
+///
+/// 处理 `jsonParsed` 编码的交易中调用 CLMM 程序的指令，包括经由聚合器调用的指令，
+/// 解码规则见 [`ClmmDecoder`]。
+///
+/// # 参数
+///
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+///
+/// # 返回值
+///
+/// 返回按指令顺序排列的 CLMM 事件，账户布局不匹配的指令会被跳过
+pub fn extract_clmm_events(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<MonitorEvent> {
+    decode_transaction(&ClmmDecoder, tx, signature)
+}
+
+/// 从交易中提取 Orca Whirlpool 事件（建池、兑换），与 CLMM 事件使用相同的事件模型
+///
+/// # 参数
+///
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+///
+/// # 返回值
+///
+/// 返回按指令顺序排列的 Whirlpool 事件，账户布局不匹配的指令会被跳过
+pub fn extract_whirlpool_events(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<MonitorEvent> {
+    decode_transaction(&WhirlpoolDecoder, tx, signature)
+}
+
+/// 从交易的内部指令中提取 Phoenix 成交事件
+///
+/// Phoenix 每条会产生事件的指令都会通过内部 Log 指令记录市场事件，无论是直接调用还是经由聚合器调用。
+/// 返回的事件只包含 tick 和 lot，需要调用 [`PhoenixMarket::apply`](crate::phoenix::PhoenixMarket::apply)
+/// 换算价格和数量。
+///
+/// # 参数
+///
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+///
+/// # 返回值
+///
+/// 返回按事件顺序排列的成交事件
+pub fn extract_phoenix_fills(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<OrderbookFill> {
+    decode_transaction(&PhoenixDecoder, tx, signature)
+        .into_iter()
+        .filter_map(|event| match event {
+            MonitorEvent::OrderbookFill(fill) => Some(fill),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 从交易中提取经由 Jupiter v6 路由的兑换
+///
+/// 每一跳的 DEX 和实际数量优先取自 Jupiter 记录的 SwapEvent；没有 SwapEvent 的交易按每一跳
+/// 内部转账的第一笔和最后一笔计算。
+///
+/// # 参数
+///
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+///
+/// # 返回值
+///
+/// 返回按指令顺序排列的路由兑换
+pub fn extract_jupiter_routes(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<JupiterRoute> {
+    decode_transaction(&JupiterDecoder, tx, signature)
+        .into_iter()
+        .filter_map(|event| match event {
+            MonitorEvent::JupiterRoute(route) => Some(route),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 从交易的内部指令中提取 Jupiter DCA 事件（创建、成交、关闭定投订单）
+///
+/// 定投订单的成交由 keeper 发起，事件取自 DCA 程序通过调用自身记录的事件。
+///
+/// # 参数
+///
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+///
+/// # 返回值
+///
+/// 返回按事件顺序排列的 DCA 事件
+pub fn extract_dca_events(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<MonitorEvent> {
+    decode_transaction(&DcaDecoder, tx, signature)
+}
@@ -0,0 +1,428 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiInstruction, UiMessage,
+    UiParsedInstruction, UiTransactionTokenBalance,
+};
+use utils::address_book::address_book;
+use utils::RpcApi;
+
+use crate::client::{get_transaction_details_with, init_rpc_client};
+use crate::dca::{decode_dca_ix, JUPITER_DCA};
+use crate::decoder::decode_raydium_ix;
+
+/// Raydium 流动性池 v4 程序 ID
+const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+/// 计算预算程序 ID
+const COMPUTE_BUDGET: &str = "ComputeBudget111111111111111111111111111111";
+
+/// 交易检查报告
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionReport {
+    pub signature: String,                    // 交易签名
+    pub slot: u64,                            // 所在区块
+    pub block_time: Option<i64>,              // 区块时间（Unix 时间戳）
+    pub success: bool,                        // 是否执行成功
+    pub error: Option<String>,                // 失败原因
+    pub fee_sol: f64,                         // 手续费（SOL）
+    pub compute_units: Option<u64>,           // 消耗的计算单元
+    pub instructions: Vec<InstructionReport>, // 外部指令和内部指令
+    pub sol_changes: Vec<SolChange>,          // SOL 余额变化
+    pub token_changes: Vec<TokenChange>,      // 代币余额变化
+    pub logs: Vec<String>,                    // 程序日志
+}
+
+/// 单条指令的解析结果
+#[derive(Debug, Clone, Serialize)]
+pub struct InstructionReport {
+    pub index: String,                // 序号，内部指令形如 `2.1`
+    pub program_id: String,           // 程序 ID
+    pub program_name: Option<String>, // 程序名称
+    pub kind: Option<String>,         // 指令类型
+    pub detail: Value,                // 解码后的参数，无法解码时为原始数据
+    pub accounts: Vec<String>,        // 涉及的账户
+}
+
+/// 账户的 SOL 余额变化
+#[derive(Debug, Clone, Serialize)]
+pub struct SolChange {
+    pub account: String,      // 账户地址
+    pub name: Option<String>, // 地址簿中的名称
+    pub pre: f64,             // 交易前余额（SOL）
+    pub post: f64,            // 交易后余额（SOL）
+    pub change: f64,          // 变化量（SOL）
+}
+
+/// 代币账户的余额变化
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenChange {
+    pub account: String,           // 代币账户地址
+    pub owner: Option<String>,     // 代币账户所有者
+    pub mint: String,              // 代币 Mint 地址
+    pub mint_name: Option<String>, // 地址簿中的代币名称
+    pub decimals: u8,              // 代币精度
+    pub pre: f64,                  // 交易前余额
+    pub post: f64,                 // 交易后余额
+    pub change: f64,               // 变化量
+}
+
+/// 检查任意交易
+///
+/// 获取交易并生成完整的可读报告：程序名称、可解码的指令参数、
+/// 代币和 SOL 余额变化以及手续费。
+///
+/// # 参数
+///
+/// * `signature` - 交易签名字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<TransactionReport>`
+pub async fn inspect(signature: &str) -> Result<TransactionReport> {
+    let rpc_client = init_rpc_client(CommitmentConfig::confirmed())?;
+
+    inspect_with(&rpc_client, signature).await
+}
+
+/// 使用指定的 RPC 客户端检查交易
+///
+/// # 参数
+///
+/// * `rpc_client` - 实现了 `RpcApi` 的 RPC 客户端
+/// * `signature` - 交易签名字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<TransactionReport>`
+pub async fn inspect_with<R: RpcApi>(rpc_client: &R, signature: &str) -> Result<TransactionReport> {
+    let tx = get_transaction_details_with(rpc_client, signature).await?;
+
+    inspect_transaction(signature, &tx)
+}
+
+/// 从已获取的交易（jsonParsed 编码）生成检查报告
+///
+/// # 参数
+///
+/// * `signature` - 交易签名字符串
+/// * `tx` - 交易详情
+///
+/// # 返回值
+///
+/// 返回 `Result<TransactionReport>`
+pub fn inspect_transaction(
+    signature: &str,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<TransactionReport> {
+    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
+        return Err(anyhow!("不支持的交易编码，请使用 jsonParsed"));
+    };
+    let UiMessage::Parsed(message) = &ui_tx.message else {
+        return Err(anyhow!("不支持的消息格式，请使用 jsonParsed"));
+    };
+    let account_keys: Vec<String> = message
+        .account_keys
+        .iter()
+        .map(|key| key.pubkey.clone())
+        .collect();
+
+    // 步骤 1：外部指令及其内部指令
+    let mut instructions = Vec::new();
+    for (index, ix) in message.instructions.iter().enumerate() {
+        instructions.push(instruction_report(
+            (index + 1).to_string(),
+            ix,
+            &account_keys,
+        ));
+
+        let inner = tx
+            .transaction
+            .meta
+            .as_ref()
+            .and_then(|meta| match &meta.inner_instructions {
+                OptionSerializer::Some(inner) => {
+                    inner.iter().find(|inner| inner.index as usize == index)
+                }
+                _ => None,
+            });
+        if let Some(inner) = inner {
+            for (inner_index, ix) in inner.instructions.iter().enumerate() {
+                instructions.push(instruction_report(
+                    format!("{}.{}", index + 1, inner_index + 1),
+                    ix,
+                    &account_keys,
+                ));
+            }
+        }
+    }
+
+    let mut report = TransactionReport {
+        signature: signature.to_string(),
+        slot: tx.slot,
+        block_time: tx.block_time,
+        success: true,
+        error: None,
+        fee_sol: 0.0,
+        compute_units: None,
+        instructions,
+        sol_changes: Vec::new(),
+        token_changes: Vec::new(),
+        logs: Vec::new(),
+    };
+
+    // 步骤 2：执行结果、手续费和余额变化
+    if let Some(meta) = &tx.transaction.meta {
+        report.success = meta.err.is_none();
+        report.error = meta.err.as_ref().map(|e| e.to_string());
+        report.fee_sol = lamports_to_sol(meta.fee);
+        report.compute_units = match &meta.compute_units_consumed {
+            OptionSerializer::Some(units) => Some(*units),
+            _ => None,
+        };
+        if let OptionSerializer::Some(logs) = &meta.log_messages {
+            report.logs = logs.clone();
+        }
+
+        report.sol_changes = account_keys
+            .iter()
+            .zip(meta.pre_balances.iter().zip(&meta.post_balances))
+            .filter(|(_, (pre, post))| pre != post)
+            .map(|(account, (pre, post))| SolChange {
+                account: account.clone(),
+                name: address_book().name_of(account).map(str::to_string),
+                pre: lamports_to_sol(*pre),
+                post: lamports_to_sol(*post),
+                change: lamports_to_sol(*post) - lamports_to_sol(*pre),
+            })
+            .collect();
+
+        report.token_changes = token_changes(
+            &account_keys,
+            &meta.pre_token_balances,
+            &meta.post_token_balances,
+        );
+    }
+
+    Ok(report)
+}
+
+/// 解析单条指令
+fn instruction_report(
+    index: String,
+    ix: &UiInstruction,
+    account_keys: &[String],
+) -> InstructionReport {
+    let (program_id, kind, detail, accounts) = match ix {
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => (
+            parsed.program_id.clone(),
+            parsed
+                .parsed
+                .get("type")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            parsed
+                .parsed
+                .get("info")
+                .cloned()
+                .unwrap_or_else(|| parsed.parsed.clone()),
+            Vec::new(),
+        ),
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(partial)) => {
+            let (kind, detail) = decode_known(&partial.program_id, &partial.data);
+            (
+                partial.program_id.clone(),
+                kind,
+                detail,
+                partial.accounts.clone(),
+            )
+        }
+        UiInstruction::Compiled(compiled) => {
+            let program_id = account_keys
+                .get(compiled.program_id_index as usize)
+                .cloned()
+                .unwrap_or_default();
+            let (kind, detail) = decode_known(&program_id, &compiled.data);
+            let accounts = compiled
+                .accounts
+                .iter()
+                .filter_map(|i| account_keys.get(*i as usize).cloned())
+                .collect();
+            (program_id, kind, detail, accounts)
+        }
+    };
+
+    InstructionReport {
+        index,
+        program_name: address_book().name_of(&program_id).map(str::to_string),
+        program_id,
+        kind,
+        detail,
+        accounts,
+    }
+}
+
+/// 使用已有的解码器解析指令数据，无法解码时返回原始数据
+fn decode_known(program_id: &str, data: &str) -> (Option<String>, Value) {
+    let raw = || json!({ "data": data });
+    let Ok(bytes) = bs58::decode(data).into_vec() else {
+        return (None, raw());
+    };
+
+    let decoded = match (program_id, bytes.first()) {
+        (RAYDIUM_AMM_V4, Some(_)) => decode_raydium_ix(data)
+            .ok()
+            .map(|ix| (ix.name(), json!(ix))),
+        (JUPITER_DCA, Some(_)) => decode_dca_ix(data).ok().map(|ix| (ix.name(), json!(ix))),
+        (COMPUTE_BUDGET, Some(2)) if bytes.len() >= 5 => {
+            let units = u32::from_le_bytes(bytes[1..5].try_into().unwrap());
+            Some(("setComputeUnitLimit", json!({ "units": units })))
+        }
+        (COMPUTE_BUDGET, Some(3)) if bytes.len() >= 9 => {
+            let price = u64::from_le_bytes(bytes[1..9].try_into().unwrap());
+            Some(("setComputeUnitPrice", json!({ "microLamports": price })))
+        }
+        _ => None,
+    };
+
+    match decoded {
+        Some((kind, detail)) => (Some(kind.to_string()), detail),
+        None => (None, raw()),
+    }
+}
+
+/// 同一代币账户交易前后的余额
+type BalancePair<'a> = (
+    Option<&'a UiTransactionTokenBalance>,
+    Option<&'a UiTransactionTokenBalance>,
+);
+
+/// 根据交易前后的代币余额计算每个代币账户的变化
+fn token_changes(
+    account_keys: &[String],
+    pre: &OptionSerializer<Vec<UiTransactionTokenBalance>>,
+    post: &OptionSerializer<Vec<UiTransactionTokenBalance>>,
+) -> Vec<TokenChange> {
+    // 账户序号 -> (交易前, 交易后)
+    let mut balances: BTreeMap<u8, BalancePair> = BTreeMap::new();
+    if let OptionSerializer::Some(pre) = pre {
+        for balance in pre {
+            balances.entry(balance.account_index).or_default().0 = Some(balance);
+        }
+    }
+    if let OptionSerializer::Some(post) = post {
+        for balance in post {
+            balances.entry(balance.account_index).or_default().1 = Some(balance);
+        }
+    }
+
+    balances
+        .into_iter()
+        .filter_map(|(index, (pre, post))| {
+            let reference = post.or(pre)?;
+            let amount = |balance: Option<&UiTransactionTokenBalance>| {
+                balance
+                    .and_then(|b| b.ui_token_amount.amount.parse::<i128>().ok())
+                    .unwrap_or(0)
+            };
+            let (pre_amount, post_amount) = (amount(pre), amount(post));
+            if pre_amount == post_amount {
+                return None;
+            }
+
+            let decimals = reference.ui_token_amount.decimals;
+            let scale = 10f64.powi(decimals as i32);
+            let owner = match &reference.owner {
+                OptionSerializer::Some(owner) => Some(owner.clone()),
+                _ => None,
+            };
+
+            Some(TokenChange {
+                account: account_keys
+                    .get(index as usize)
+                    .cloned()
+                    .unwrap_or_default(),
+                owner,
+                mint: reference.mint.clone(),
+                mint_name: address_book().name_of(&reference.mint).map(str::to_string),
+                decimals,
+                pre: pre_amount as f64 / scale,
+                post: post_amount as f64 / scale,
+                change: (post_amount - pre_amount) as f64 / scale,
+            })
+        })
+        .collect()
+}
+
+impl fmt::Display for TransactionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let book = address_book();
+
+        writeln!(f, "交易: {}", self.signature)?;
+        writeln!(f, "区块: {}", self.slot)?;
+        if let Some(block_time) = self.block_time {
+            writeln!(f, "时间: {}", block_time)?;
+        }
+        match &self.error {
+            None => writeln!(f, "状态: 成功")?,
+            Some(error) => writeln!(f, "状态: 失败 ({})", error)?,
+        }
+        writeln!(f, "手续费: {:.9} SOL", self.fee_sol)?;
+        if let Some(units) = self.compute_units {
+            writeln!(f, "计算单元: {}", units)?;
+        }
+
+        writeln!(f, "\n指令:")?;
+        for ix in &self.instructions {
+            let indent = if ix.index.contains('.') { "    " } else { "  " };
+            writeln!(
+                f,
+                "{}#{} {} {}",
+                indent,
+                ix.index,
+                book.label(&ix.program_id),
+                ix.kind.as_deref().unwrap_or("未知指令")
+            )?;
+            writeln!(f, "{}    {}", indent, ix.detail)?;
+        }
+
+        if !self.sol_changes.is_empty() {
+            writeln!(f, "\nSOL 余额变化:")?;
+            for change in &self.sol_changes {
+                writeln!(
+                    f,
+                    "  {}: {:.9} -> {:.9} ({:+.9})",
+                    book.label(&change.account),
+                    change.pre,
+                    change.post,
+                    change.change
+                )?;
+            }
+        }
+
+        if !self.token_changes.is_empty() {
+            writeln!(f, "\n代币余额变化:")?;
+            for change in &self.token_changes {
+                let owner = change.owner.as_deref().unwrap_or(&change.account);
+                writeln!(
+                    f,
+                    "  {} | {}: {} -> {} ({:+})",
+                    book.label(owner),
+                    book.label(&change.mint),
+                    change.pre,
+                    change.post,
+                    change.change
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
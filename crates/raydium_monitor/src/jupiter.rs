@@ -0,0 +1,204 @@
+use anyhow::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::model::MonitorError;
+
+/// Jupiter 聚合器 v6 程序 ID
+pub const JUPITER_V6: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+
+/// Anchor 指令标识：sha256("global:<指令名>") 的前 8 个字节
+const ROUTE: [u8; 8] = [229, 23, 203, 151, 122, 227, 173, 42];
+const SHARED_ACCOUNTS_ROUTE: [u8; 8] = [193, 32, 155, 51, 65, 214, 156, 129];
+const EXACT_OUT_ROUTE: [u8; 8] = [208, 51, 239, 151, 123, 43, 237, 92];
+const SHARED_ACCOUNTS_EXACT_OUT_ROUTE: [u8; 8] = [176, 209, 105, 168, 154, 125, 69, 62];
+
+/// Anchor 通过调用自身记录事件时的指令标识（小端序的 0x1d9acb512ea545e4）
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Anchor 事件标识：sha256("event:SwapEvent") 的前 8 个字节
+const SWAP_EVENT: [u8; 8] = [64, 198, 205, 232, 38, 8, 113, 226];
+
+/// 路由指令在日志中的名称
+const ROUTE_LOGS: [&str; 4] = [
+    "Instruction: Route",
+    "Instruction: SharedAccountsRoute",
+    "Instruction: ExactOutRoute",
+    "Instruction: SharedAccountsExactOutRoute",
+];
+
+/// 路由参数末尾固定字段的长度：两个 u64、slippage_bps 和 platform_fee_bps
+const ROUTE_TAIL_LEN: usize = 19;
+
+/// route / shared_accounts_route 指令参数
+///
+/// 路由步骤中的 `Swap` 枚举随 Jupiter 接入新的 DEX 不断增加变体，这里只记录步骤数量，
+/// 每一跳的 DEX 和实际数量从内部指令中获取。
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RouteArgs {
+    #[borsh(skip)]
+    pub route_plan_len: u32, // 路由步骤数量
+    pub in_amount: u64,         // 输入数量
+    pub quoted_out_amount: u64, // 报价的输出数量
+    pub slippage_bps: u16,      // 允许的滑点（基点）
+    pub platform_fee_bps: u8,   // 平台手续费（基点）
+}
+
+/// exact_out_route / shared_accounts_exact_out_route 指令参数
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ExactOutRouteArgs {
+    #[borsh(skip)]
+    pub route_plan_len: u32, // 路由步骤数量
+    pub out_amount: u64,       // 输出数量
+    pub quoted_in_amount: u64, // 报价的输入数量
+    pub slippage_bps: u16,     // 允许的滑点（基点）
+    pub platform_fee_bps: u8,  // 平台手续费（基点）
+}
+
+/// Jupiter v6 路由指令
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum JupiterIx {
+    /// 指定输入数量的路由兑换
+    Route(RouteArgs),
+    /// 使用程序共享中转账户的路由兑换
+    SharedAccountsRoute(RouteArgs),
+    /// 指定输出数量的路由兑换
+    ExactOutRoute(ExactOutRouteArgs),
+    /// 使用程序共享中转账户、指定输出数量的路由兑换
+    SharedAccountsExactOutRoute(ExactOutRouteArgs),
+}
+
+impl JupiterIx {
+    /// 指令名称，与 Jupiter IDL 中的命名一致
+    pub fn name(&self) -> &'static str {
+        match self {
+            JupiterIx::Route(_) => "route",
+            JupiterIx::SharedAccountsRoute(_) => "sharedAccountsRoute",
+            JupiterIx::ExactOutRoute(_) => "exactOutRoute",
+            JupiterIx::SharedAccountsExactOutRoute(_) => "sharedAccountsExactOutRoute",
+        }
+    }
+
+    /// 用户钱包在指令账户列表中的位置
+    pub fn user_account_index(&self) -> usize {
+        match self {
+            JupiterIx::Route(_) | JupiterIx::ExactOutRoute(_) => 1,
+            JupiterIx::SharedAccountsRoute(_) | JupiterIx::SharedAccountsExactOutRoute(_) => 2,
+        }
+    }
+}
+
+/// 解码 Jupiter v6 路由指令数据
+///
+/// # 参数
+///
+/// * `data` - base58 编码的指令数据字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<JupiterIx>`，不需要解析的指令返回 `MonitorError::NoMatchingInstruction`
+pub fn decode_jupiter_ix(data: &str) -> Result<JupiterIx> {
+    let bytes = bs58::decode(data).into_vec()?;
+    if bytes.len() < 8 {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    }
+    let (discriminator, args) = bytes.split_at(8);
+    let discriminator = <[u8; 8]>::try_from(discriminator)?;
+
+    // shared_accounts 系列指令在路由步骤之前多一个 id 参数
+    let shared = matches!(
+        discriminator,
+        SHARED_ACCOUNTS_ROUTE | SHARED_ACCOUNTS_EXACT_OUT_ROUTE
+    );
+    let args = if shared {
+        args.get(1..).unwrap_or_default()
+    } else {
+        args
+    };
+    if args.len() < 4 + ROUTE_TAIL_LEN {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    }
+    let route_plan_len = u32::from_le_bytes(args[..4].try_into()?);
+    let mut tail = &args[args.len() - ROUTE_TAIL_LEN..];
+
+    let ix = match discriminator {
+        ROUTE | SHARED_ACCOUNTS_ROUTE => {
+            let args = RouteArgs {
+                route_plan_len,
+                ..RouteArgs::deserialize(&mut tail)?
+            };
+            if shared {
+                JupiterIx::SharedAccountsRoute(args)
+            } else {
+                JupiterIx::Route(args)
+            }
+        }
+        EXACT_OUT_ROUTE | SHARED_ACCOUNTS_EXACT_OUT_ROUTE => {
+            let args = ExactOutRouteArgs {
+                route_plan_len,
+                ..ExactOutRouteArgs::deserialize(&mut tail)?
+            };
+            if shared {
+                JupiterIx::SharedAccountsExactOutRoute(args)
+            } else {
+                JupiterIx::ExactOutRoute(args)
+            }
+        }
+        _ => return Err(MonitorError::NoMatchingInstruction.into()),
+    };
+
+    Ok(ix)
+}
+
+/// 判断交易日志中是否包含 Jupiter 路由指令
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_jupiter_route(logs: &[String]) -> bool {
+    logs.iter().any(|log| {
+        log.strip_prefix("Program log: ")
+            .is_some_and(|log| ROUTE_LOGS.contains(&log))
+    })
+}
+
+/// Jupiter 每完成一跳兑换记录的事件
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct SwapEvent {
+    pub amm: [u8; 32],         // 执行兑换的 DEX 程序
+    pub input_mint: [u8; 32],  // 输入代币 Mint 地址
+    pub input_amount: u64,     // 输入数量（原始数量）
+    pub output_mint: [u8; 32], // 输出代币 Mint 地址
+    pub output_amount: u64,    // 输出数量（原始数量）
+}
+
+/// 解码 Jupiter 通过调用自身记录的 SwapEvent
+///
+/// # 参数
+///
+/// * `data` - base58 编码的内部指令数据字符串
+///
+/// # 返回值
+///
+/// 不是 SwapEvent 时返回 None
+pub fn decode_swap_event(data: &str) -> Option<SwapEvent> {
+    decode_anchor_event(data, SWAP_EVENT)
+}
+
+/// 解码 Anchor 程序通过调用自身（`emit_cpi!`）记录的事件
+///
+/// # 参数
+///
+/// * `data` - base58 编码的内部指令数据字符串
+/// * `discriminator` - 事件标识：sha256("event:<事件名>") 的前 8 个字节
+pub(crate) fn decode_anchor_event<T: BorshDeserialize>(
+    data: &str,
+    discriminator: [u8; 8],
+) -> Option<T> {
+    let bytes = bs58::decode(data).into_vec().ok()?;
+    let mut rest = bytes
+        .strip_prefix(&EVENT_IX_TAG)?
+        .strip_prefix(&discriminator)?;
+    T::deserialize(&mut rest).ok()
+}
@@ -0,0 +1,202 @@
+use crate::model::MonitorError;
+
+/// initialize2 指令的账户布局
+///
+/// 按 Raydium AMM v4 `Initialize2` 指令定义的账户顺序解析：
+///
+/// 0. token_program
+/// 1. associated_token_program
+/// 2. system_program
+/// 3. rent
+/// 4. amm
+/// 5. amm_authority
+/// 6. amm_open_orders
+/// 7. lp_mint
+/// 8. coin_mint
+/// 9. pc_mint
+/// 10. pool_coin_token_account
+/// 11. pool_pc_token_account
+/// 12. pool_withdraw_queue
+/// 13. amm_target_orders
+/// 14. pool_temp_lp
+/// 15. serum_program
+/// 16. serum_market
+/// 17. user_wallet
+/// 18. user_token_coin
+/// 19. user_token_pc
+/// 20. user_lp_token_account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Initialize2Accounts {
+    pub amm: String,             // 流动性池（AMM）地址
+    pub amm_open_orders: String, // 流动性池在 OpenBook 市场的 open orders 账户
+    pub lp_mint: String,         // LP 代币 Mint 地址
+    pub coin_mint: String,       // 基础代币 Mint 地址
+    pub pc_mint: String,         // 报价代币 Mint 地址
+    pub coin_vault: String,      // 流动性池的基础代币账户
+    pub pc_vault: String,        // 流动性池的报价代币账户
+    pub market: String,          // OpenBook 市场地址
+    pub user_wallet: String,     // 创建流动性池的钱包
+}
+
+impl Initialize2Accounts {
+    /// initialize2 指令涉及的账户数量
+    pub const LEN: usize = 21;
+
+    /// 按 initialize2 指令定义解析账户列表
+    ///
+    /// # 参数
+    ///
+    /// * `accounts` - 指令的账户地址列表
+    ///
+    /// # 返回值
+    ///
+    /// 账户数量不匹配时返回 `MonitorError::AccountLayoutMismatch`
+    pub fn from_accounts(accounts: &[String]) -> Result<Self, MonitorError> {
+        if accounts.len() != Self::LEN {
+            return Err(MonitorError::AccountLayoutMismatch {
+                instruction: "initialize2",
+                actual: accounts.len(),
+            });
+        }
+
+        Ok(Self {
+            amm: accounts[4].clone(),
+            amm_open_orders: accounts[6].clone(),
+            lp_mint: accounts[7].clone(),
+            coin_mint: accounts[8].clone(),
+            pc_mint: accounts[9].clone(),
+            coin_vault: accounts[10].clone(),
+            pc_vault: accounts[11].clone(),
+            market: accounts[16].clone(),
+            user_wallet: accounts[17].clone(),
+        })
+    }
+}
+
+/// swapBaseIn / swapBaseOut 指令的账户布局
+///
+/// 两条指令的账户顺序相同，按是否包含 `amm_target_orders` 分为 18 个和 17 个账户两种：
+///
+/// 0. token_program
+/// 1. amm
+/// 2. amm_authority
+/// 3. amm_open_orders
+/// 4. amm_target_orders（仅 18 个账户时存在）
+/// 5. pool_coin_token_account
+/// 6. pool_pc_token_account
+/// 7. serum_program
+/// 8. serum_market
+/// 9. serum_bids
+/// 10. serum_asks
+/// 11. serum_event_queue
+/// 12. serum_coin_vault_account
+/// 13. serum_pc_vault_account
+/// 14. serum_vault_signer
+/// 15. user_source_token_account
+/// 16. user_destination_token_account
+/// 17. user_source_owner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapAccounts {
+    pub amm: String,              // 流动性池（AMM）地址
+    pub coin_vault: String,       // 流动性池的基础代币账户
+    pub pc_vault: String,         // 流动性池的报价代币账户
+    pub market: String,           // OpenBook 市场地址
+    pub user_source: String,      // 用户支付代币的账户
+    pub user_destination: String, // 用户接收代币的账户
+    pub user_owner: String,       // 用户钱包（交易签名者）
+}
+
+impl SwapAccounts {
+    /// 包含 `amm_target_orders` 时的账户数量
+    pub const LEN_WITH_TARGET_ORDERS: usize = 18;
+
+    /// 不包含 `amm_target_orders` 时的账户数量
+    pub const LEN: usize = 17;
+
+    /// 按 swap 指令定义解析账户列表
+    ///
+    /// # 参数
+    ///
+    /// * `accounts` - 指令的账户地址列表
+    ///
+    /// # 返回值
+    ///
+    /// 账户数量既不是 17 也不是 18 时返回 `MonitorError::AccountLayoutMismatch`
+    pub fn from_accounts(accounts: &[String]) -> Result<Self, MonitorError> {
+        // 不包含 amm_target_orders 时，其后的账户整体前移一位
+        let offset = match accounts.len() {
+            Self::LEN_WITH_TARGET_ORDERS => 1,
+            Self::LEN => 0,
+            actual => {
+                return Err(MonitorError::AccountLayoutMismatch {
+                    instruction: "swap",
+                    actual,
+                })
+            }
+        };
+
+        Ok(Self {
+            amm: accounts[1].clone(),
+            coin_vault: accounts[4 + offset].clone(),
+            pc_vault: accounts[5 + offset].clone(),
+            market: accounts[7 + offset].clone(),
+            user_source: accounts[14 + offset].clone(),
+            user_destination: accounts[15 + offset].clone(),
+            user_owner: accounts[16 + offset].clone(),
+        })
+    }
+}
+
+/// deposit / withdraw 指令的账户布局
+///
+/// 两条指令的前 8 个账户顺序相同：
+///
+/// 0. token_program
+/// 1. amm
+/// 2. amm_authority
+/// 3. amm_open_orders
+/// 4. amm_target_orders
+/// 5. lp_mint
+/// 6. pool_coin_token_account
+/// 7. pool_pc_token_account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiquidityAccounts {
+    pub amm: String,        // 流动性池（AMM）地址
+    pub lp_mint: String,    // LP 代币 Mint 地址
+    pub coin_vault: String, // 流动性池的基础代币账户
+    pub pc_vault: String,   // 流动性池的报价代币账户
+}
+
+impl LiquidityAccounts {
+    /// deposit 和 withdraw 共有的账户数量
+    pub const MIN_LEN: usize = 8;
+
+    /// 按 deposit / withdraw 指令定义解析账户列表
+    ///
+    /// # 参数
+    ///
+    /// * `accounts` - 指令的账户地址列表
+    /// * `instruction` - 指令名称，用于错误信息
+    ///
+    /// # 返回值
+    ///
+    /// 账户数量少于 8 个时返回 `MonitorError::AccountLayoutMismatch`
+    pub fn from_accounts(
+        accounts: &[String],
+        instruction: &'static str,
+    ) -> Result<Self, MonitorError> {
+        if accounts.len() < Self::MIN_LEN {
+            return Err(MonitorError::AccountLayoutMismatch {
+                instruction,
+                actual: accounts.len(),
+            });
+        }
+
+        Ok(Self {
+            amm: accounts[1].clone(),
+            lp_mint: accounts[5].clone(),
+            coin_vault: accounts[6].clone(),
+            pc_vault: accounts[7].clone(),
+        })
+    }
+}
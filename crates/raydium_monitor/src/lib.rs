@@ -18,16 +18,47 @@
 //! 16. Raydium Liquidity Pool v4 - 675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8
 //! 17. Memo Program v2 - MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr
 //! 18. Jupiter DCA Program : DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M
+//! 19. Raydium CLMM - CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK
 //!
 //! token address:
 //!
 //! 1. Sol token address - So11111111111111111111111111111111111111112
 //! 2. USDT token address - Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB
 //!
+//! 特性划分（默认全部启用）：
+//!
+//! - 无特性：只有 `decoder`、`layout`、`clmm`、`whirlpool`、`phoenix`、`jupiter`、`dca` 和
+//!   `model`，可编译到 wasm32
+//! - `transaction`：`extract`、`dex`，从已获取的交易中提取指令，只依赖 solana-transaction-status，
+//!   适合索引器等自行获取交易的场景；`dex::DexDecoder` 用于接入新的协议
+//! - `rpc`：`client`、`token_info`、`inspector`，通过 HTTP RPC 获取交易和代币账户，检查任意交易
+//! - `metadata`：`swap_analyzer`、`utils`，查询代币元数据并分析兑换
+//! - `websocket`：`services`、`sink`、`synthetic`，日志订阅、事件输出端和事件存储
+#[cfg(feature = "rpc")]
 pub mod client;
+pub mod clmm;
+pub mod dca;
 pub mod decoder;
+#[cfg(feature = "transaction")]
+pub mod dex;
+#[cfg(feature = "transaction")]
+pub mod extract;
+#[cfg(feature = "rpc")]
+pub mod inspector;
+pub mod jupiter;
+pub mod layout;
 pub mod model;
+pub mod phoenix;
+#[cfg(feature = "websocket")]
 pub mod services;
+#[cfg(feature = "websocket")]
+pub mod sink;
+#[cfg(feature = "metadata")]
 pub mod swap_analyzer;
+#[cfg(feature = "websocket")]
+pub mod synthetic;
+#[cfg(feature = "rpc")]
 pub mod token_info;
+#[cfg(feature = "metadata")]
 pub mod utils;
+pub mod whirlpool;
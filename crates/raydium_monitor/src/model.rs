@@ -1,6 +1,9 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::jupiter::JupiterIx;
+
 /// 定义监控错误枚举，用于处理各种可能出现的错误情况
 #[derive(Debug, Error)]
 pub enum MonitorError {
@@ -10,11 +13,23 @@ pub enum MonitorError {
     UnsupportedTransactionFormat,
     #[error("未找到匹配的指令")]
     NoMatchingInstruction,
+    #[error("不支持的 Raydium 指令: {0}")]
+    UnknownInstruction(u8),
+    #[error("{instruction} 指令的账户数量不匹配: {actual}")]
+    AccountLayoutMismatch {
+        instruction: &'static str, // 指令名称
+        actual: usize,             // 实际账户数量
+    },
+    #[error("{account} 账户数据长度不足: {actual}")]
+    AccountDataTooShort {
+        account: &'static str, // 账户类型
+        actual: usize,         // 实际数据长度
+    },
 }
 
 /// 定义 Raydium 指令结构体，用于序列化和反序列化
 /// 这个结构体表示 Raydium 协议中的一个具体指令
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
 pub struct RaydiumInstruction {
     pub discriminator: u8,     // 用于区分不同类型指令的标识符
     pub nonce: u8,             // 用于防止重放攻击的随机数
@@ -24,25 +39,468 @@ pub struct RaydiumInstruction {
 }
 
 /// 定义数据版本 2 结构体，可能用于新版本的 Raydium 指令
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
 pub struct SwapIxData {
     pub discriminator: u8,       // 指令类型标识符
     pub amount_in: u64,          // 输入代币数量
     pub minimum_amount_out: u64, // 最小输出代币数量（滑点保护）
 }
 
-/// 定义指令数据值枚举，用于存储不同类型的指令数据
-#[derive(Debug, Clone)]
-pub enum InstructionDataValue {
-    AccountsAndData {
-        accounts: Vec<String>, // 相关账户地址列表
-        data: Option<String>,  // 可选的额外数据
-    },
-    Amount(u64), // 代币数量
+/// swapBaseOut 指令数据，指定输出数量兑换
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct SwapBaseOutIxData {
+    pub discriminator: u8,  // 指令类型标识符
+    pub max_amount_in: u64, // 最大输入代币数量（滑点保护）
+    pub amount_out: u64,    // 输出代币数量
+}
+
+/// initialize 指令数据（旧版创建流动性池）
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct InitializeIxData {
+    pub discriminator: u8, // 指令类型标识符
+    pub nonce: u8,         // 流动性池权限账户的 nonce
+    pub opentime: u64,     // 流动性池开放时间（Unix 时间戳）
+}
+
+/// preInitialize 指令数据（旧版创建流动性池前的准备）
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct PreInitializeIxData {
+    pub discriminator: u8, // 指令类型标识符
+    pub nonce: u8,         // 流动性池权限账户的 nonce
+}
+
+/// deposit 指令数据，添加流动性
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct DepositIxData {
+    pub discriminator: u8,    // 指令类型标识符
+    pub max_coin_amount: u64, // 最多存入的基础代币数量
+    pub max_pc_amount: u64,   // 最多存入的报价代币数量
+    pub base_side: u64,       // 以哪一侧为基准计算（0 为基础代币，1 为报价代币）
+    #[borsh(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub other_amount_min: Option<u64>, // 另一侧最少存入数量，旧版指令没有该字段
+}
+
+/// withdraw 指令数据，移除流动性
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct WithdrawIxData {
+    pub discriminator: u8, // 指令类型标识符
+    pub amount: u64,       // 销毁的 LP 代币数量
+    #[borsh(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_coin_amount: Option<u64>, // 最少取回的基础代币数量，旧版指令没有该字段
+    #[borsh(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_pc_amount: Option<u64>, // 最少取回的报价代币数量，旧版指令没有该字段
+}
+
+/// monitorStep 指令数据，由 AMM 管理员驱动挂单
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct MonitorStepIxData {
+    pub discriminator: u8,       // 指令类型标识符
+    pub plan_order_limit: u16,   // 计划挂单数量上限
+    pub place_order_limit: u16,  // 下单数量上限
+    pub cancel_order_limit: u16, // 撤单数量上限
+}
+
+/// 只携带数量的管理员指令数据（withdrawSrm）
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct AdminAmountIxData {
+    pub discriminator: u8, // 指令类型标识符
+    pub amount: u64,       // 数量
+}
+
+/// adminCancelOrders 指令数据
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct AdminCancelOrdersIxData {
+    pub discriminator: u8, // 指令类型标识符
+    pub limit: u16,        // 撤单数量上限
+}
+
+/// 以参数编号开头的管理员指令数据（setParams、simulateInfo、updateConfigAccount）
+///
+/// 参数值的编码随参数编号变化，这里只解析参数编号。
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug)]
+pub struct AdminParamIxData {
+    pub discriminator: u8, // 指令类型标识符
+    pub param: u8,         // 参数编号
+}
+
+/// Raydium AMM v4 指令，按指令标识（第一个字节）区分
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RaydiumIx {
+    /// 0：旧版创建流动性池
+    Initialize(InitializeIxData),
+    /// 1：创建流动性池
+    Initialize2(RaydiumInstruction),
+    /// 2：管理员驱动挂单
+    MonitorStep(MonitorStepIxData),
+    /// 3：添加流动性
+    Deposit(DepositIxData),
+    /// 4：移除流动性
+    Withdraw(WithdrawIxData),
+    /// 5：迁移到 OpenBook 市场（管理员）
+    MigrateToOpenBook,
+    /// 6：修改流动性池参数（管理员）
+    SetParams(AdminParamIxData),
+    /// 7：提取协议收益（管理员）
+    WithdrawPnl,
+    /// 8：提取 SRM（管理员）
+    WithdrawSrm(AdminAmountIxData),
+    /// 9：指定输入数量兑换
+    SwapBaseIn(SwapIxData),
+    /// 10：旧版创建流动性池前的准备
+    PreInitialize(PreInitializeIxData),
+    /// 11：指定输出数量兑换
+    SwapBaseOut(SwapBaseOutIxData),
+    /// 12：模拟查询池子信息
+    SimulateInfo(AdminParamIxData),
+    /// 13：撤销挂单（管理员）
+    AdminCancelOrders(AdminCancelOrdersIxData),
+    /// 14：创建配置账户（管理员）
+    CreateConfigAccount,
+    /// 15：修改配置账户（管理员）
+    UpdateConfigAccount(AdminParamIxData),
+}
+
+impl RaydiumIx {
+    /// 指令名称，与 Raydium SDK 中的命名一致
+    pub fn name(&self) -> &'static str {
+        match self {
+            RaydiumIx::Initialize(_) => "initialize",
+            RaydiumIx::Initialize2(_) => "initialize2",
+            RaydiumIx::MonitorStep(_) => "monitorStep",
+            RaydiumIx::Deposit(_) => "deposit",
+            RaydiumIx::Withdraw(_) => "withdraw",
+            RaydiumIx::MigrateToOpenBook => "migrateToOpenBook",
+            RaydiumIx::SetParams(_) => "setParams",
+            RaydiumIx::WithdrawPnl => "withdrawPnl",
+            RaydiumIx::WithdrawSrm(_) => "withdrawSrm",
+            RaydiumIx::SwapBaseIn(_) => "swapBaseIn",
+            RaydiumIx::PreInitialize(_) => "preInitialize",
+            RaydiumIx::SwapBaseOut(_) => "swapBaseOut",
+            RaydiumIx::SimulateInfo(_) => "simulateInfo",
+            RaydiumIx::AdminCancelOrders(_) => "adminCancelOrders",
+            RaydiumIx::CreateConfigAccount => "createConfigAccount",
+            RaydiumIx::UpdateConfigAccount(_) => "updateConfigAccount",
+        }
+    }
+
+    /// 是否为只能由 AMM 管理员调用的指令
+    pub fn is_admin(&self) -> bool {
+        matches!(
+            self,
+            RaydiumIx::MonitorStep(_)
+                | RaydiumIx::MigrateToOpenBook
+                | RaydiumIx::SetParams(_)
+                | RaydiumIx::WithdrawPnl
+                | RaydiumIx::WithdrawSrm(_)
+                | RaydiumIx::AdminCancelOrders(_)
+                | RaydiumIx::CreateConfigAccount
+                | RaydiumIx::UpdateConfigAccount(_)
+        )
+    }
+}
+
+/// 新建流动性池中的代币信息
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PoolToken {
+    pub mint: String, // 代币 Mint 地址
+    pub name: String, // 代币名称
+    pub amount: f64,  // 初始数量（按精度换算）
+    #[serde(default)]
+    pub raw_amount: u64, // 初始数量（最小单位），旧版本写入的事件中为 0
+    pub decimals: u8, // 代币精度
+}
+
+/// 新建流动性池事件，可写入事件存储用于回放
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PoolCreated {
+    pub signature: String,  // 创建交易签名
+    pub lp_account: String, // LP 地址
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lp_name: Option<String>, // 地址簿中的 LP 名称
+    pub open_time: u64,     // 流动性池开放时间（Unix 时间戳）
+    pub token_a: PoolToken, // 基础代币
+    pub token_b: PoolToken, // 报价代币
+}
+
+/// 集中流动性 DEX
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Dex {
+    /// Raydium CLMM
+    #[default]
+    RaydiumClmm,
+    /// Orca Whirlpool
+    OrcaWhirlpool,
+}
+
+/// 集中流动性（Raydium CLMM、Orca Whirlpool）新建流动性池事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClmmPoolCreated {
+    #[serde(default)]
+    pub dex: Dex, // 所属 DEX
+    pub signature: String,    // 创建交易签名
+    pub pool: String,         // 流动性池地址
+    pub creator: String,      // 创建流动性池的钱包
+    pub token_mint_0: String, // token 0（Whirlpool 为 token A）Mint 地址
+    pub token_mint_1: String, // token 1（Whirlpool 为 token B）Mint 地址
+    pub open_time: u64,       // 流动性池开放时间（Unix 时间戳），Whirlpool 没有开放时间，为 0
+    pub price: Option<f64>,   // 初始价格（每单位 token 0 价值多少 token 1），精度未知时为 None
 }
 
-/// 指令数据结构体，用于存储从交易中提取的指令信息
-#[derive(Debug, Clone)]
-pub struct InstructionData {
-    pub value: InstructionDataValue,
+/// 集中流动性（Raydium CLMM、Orca Whirlpool）兑换事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClmmSwap {
+    #[serde(default)]
+    pub dex: Dex, // 所属 DEX
+    pub signature: String,           // 交易签名
+    pub pool: String,                // 流动性池地址
+    pub owner: String,               // 用户钱包
+    pub input_mint: Option<String>,  // 输入代币 Mint 地址
+    pub output_mint: Option<String>, // 输出代币 Mint 地址
+    pub amount_in: u64,              // 实际输入数量（原始数量）
+    pub amount_out: u64,             // 实际输出数量（原始数量）
+    pub price: Option<f64>, // 成交价格（每单位输入代币兑换多少输出代币），精度未知时为 None
+}
+
+/// Raydium CLMM 开仓事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClmmPositionOpened {
+    pub signature: String,         // 交易签名
+    pub pool: String,              // 流动性池地址
+    pub owner: String,             // 仓位所有者
+    pub position_nft_mint: String, // 仓位 NFT Mint 地址
+    pub tick_lower: i32,           // 价格区间下限 tick
+    pub tick_upper: i32,           // 价格区间上限 tick
+    pub price_lower: Option<f64>,  // 价格区间下限，精度未知时为 None
+    pub price_upper: Option<f64>,  // 价格区间上限，精度未知时为 None
+}
+
+/// Raydium CLMM 平仓事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ClmmPositionClosed {
+    pub signature: String,         // 交易签名
+    pub owner: String,             // 仓位所有者
+    pub position_nft_mint: String, // 仓位 NFT Mint 地址
+}
+
+/// 订单方向
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    /// 买入 base 代币
+    Bid,
+    /// 卖出 base 代币
+    Ask,
+}
+
+impl Side {
+    /// 根据挂单序号判断挂单方向，买单序号的最高位为 1
+    ///
+    /// # 参数
+    ///
+    /// * `order_sequence_number` - 挂单序号
+    pub fn from_order_sequence_number(order_sequence_number: u64) -> Self {
+        if order_sequence_number >> 63 == 1 {
+            Side::Bid
+        } else {
+            Side::Ask
+        }
+    }
+
+    /// 对手方方向
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Bid => Side::Ask,
+            Side::Ask => Side::Bid,
+        }
+    }
+}
+
+/// 订单簿成交事件（Phoenix），每笔吃单与挂单的成交对应一个事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OrderbookFill {
+    pub signature: String,          // 交易签名
+    pub market: String,             // 市场地址
+    pub taker: String,              // 吃单方（交易签名者）
+    pub maker: String,              // 挂单方
+    pub side: Side,                 // 吃单方向，Bid 为买入 base 代币
+    pub price_in_ticks: u64,        // 成交价格（tick）
+    pub base_lots: u64,             // 成交数量（base lot）
+    pub base_mint: Option<String>,  // base 代币 Mint 地址，未获取市场参数时为 None
+    pub quote_mint: Option<String>, // quote 代币 Mint 地址，未获取市场参数时为 None
+    pub price: Option<f64>,         // 成交价格（每个 base 代币值多少 quote 代币）
+    pub size: Option<f64>,          // 成交数量（base 代币）
+    pub slot: u64,                  // 所在 slot
+    pub timestamp: i64,             // 区块时间（Unix 时间戳）
+}
+
+/// Jupiter 路由中的一跳兑换
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RouteLeg {
+    pub program_id: String,          // 执行兑换的 DEX 程序
+    pub pool: Option<String>,        // 流动性池地址，只识别 Raydium AMM v4、CLMM 和 Whirlpool
+    pub input_mint: Option<String>,  // 输入代币 Mint 地址
+    pub input_amount: u64,           // 输入数量（原始数量）
+    pub output_mint: Option<String>, // 输出代币 Mint 地址
+    pub output_amount: u64,          // 输出数量（原始数量）
+}
+
+/// Jupiter v6 路由兑换事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct JupiterRoute {
+    pub signature: String,           // 交易签名
+    pub user: String,                // 用户钱包
+    pub instruction: JupiterIx,      // 路由指令及参数
+    pub input_mint: Option<String>,  // 输入代币 Mint 地址（第一跳的输入）
+    pub output_mint: Option<String>, // 输出代币 Mint 地址（最后一跳的输出）
+    pub in_amount: u64,              // 实际输入数量合计（原始数量）
+    pub out_amount: u64,             // 实际输出数量合计（原始数量）
+    pub legs: Vec<RouteLeg>,         // 按执行顺序排列的每一跳
+}
+
+/// Jupiter DCA 创建定投订单事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DcaOpened {
+    pub signature: String,        // 交易签名
+    pub dca: String,              // 定投订单地址
+    pub user: String,             // 用户钱包
+    pub input_mint: String,       // 输入代币 Mint 地址
+    pub output_mint: String,      // 买入代币 Mint 地址
+    pub in_deposited: u64,        // 存入的输入代币数量（原始数量）
+    pub in_amount_per_cycle: u64, // 每期买入使用的输入代币数量（原始数量）
+    pub cycle_frequency: i64,     // 每期间隔（秒）
+    pub created_at: i64,          // 创建时间（Unix 时间戳）
+}
+
+/// Jupiter DCA 每期买入成交事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DcaFilled {
+    pub signature: String,   // 交易签名
+    pub dca: String,         // 定投订单地址
+    pub user: String,        // 用户钱包
+    pub input_mint: String,  // 输入代币 Mint 地址
+    pub output_mint: String, // 买入代币 Mint 地址
+    pub in_amount: u64,      // 本期使用的输入代币数量（原始数量）
+    pub out_amount: u64,     // 本期买入数量（原始数量）
+    pub fee_mint: String,    // 手续费代币 Mint 地址
+    pub fee: u64,            // 手续费（原始数量）
+}
+
+/// Jupiter DCA 关闭定投订单事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DcaClosed {
+    pub signature: String,        // 交易签名
+    pub dca: String,              // 定投订单地址
+    pub user: String,             // 用户钱包
+    pub input_mint: String,       // 输入代币 Mint 地址
+    pub output_mint: String,      // 买入代币 Mint 地址
+    pub in_deposited: u64,        // 存入的输入代币数量（原始数量）
+    pub total_in_withdrawn: u64,  // 取回的输入代币数量合计（原始数量）
+    pub total_out_withdrawn: u64, // 取回的买入代币数量合计（原始数量）
+    pub unfilled_amount: u64,     // 未成交的输入代币数量（原始数量）
+    pub user_closed: bool,        // 是否由用户主动关闭（否则为全部成交后关闭）
+}
+
+/// 标准化事件中的代币数量
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TokenAmount {
+    pub mint: String,         // 代币 Mint 地址
+    pub amount: u64,          // 原始数量
+    pub decimals: Option<u8>, // 代币精度，未知时为 None
+}
+
+impl TokenAmount {
+    /// 按精度换算后的数量，精度未知时为 None
+    pub fn ui_amount(&self) -> Option<f64> {
+        self.decimals
+            .map(|decimals| self.amount as f64 / 10f64.powi(decimals as i32))
+    }
+}
+
+/// 标准化的新建流动性池事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NewPool {
+    pub program_id: String,     // DEX 程序 ID
+    pub signature: String,      // 交易签名
+    pub signer: String,         // 交易签名者（手续费支付者）
+    pub slot: u64,              // 所在 slot
+    pub timestamp: Option<i64>, // 区块时间（Unix 时间戳），节点未记录时为 None
+    pub pool: String,           // 流动性池地址
+    pub token_a: TokenAmount,   // 基础代币及初始数量
+    pub token_b: TokenAmount,   // 报价代币及初始数量
+    pub open_time: Option<u64>, // 流动性池开放时间（Unix 时间戳），协议没有开放时间时为 None
+}
+
+/// 标准化的兑换事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Swap {
+    pub program_id: String,     // DEX 程序 ID
+    pub signature: String,      // 交易签名
+    pub signer: String,         // 交易签名者（手续费支付者）
+    pub slot: u64,              // 所在 slot
+    pub timestamp: Option<i64>, // 区块时间（Unix 时间戳），节点未记录时为 None
+    pub pool: String,           // 流动性池地址
+    pub input: TokenAmount,     // 实际输入
+    pub output: TokenAmount,    // 实际输出
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_amount_out: Option<u64>, // 指令要求的最少输出（原始数量），只在按输入数量兑换时记录
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_amount_in: Option<u64>, // 指令允许的最多输入（原始数量），只在按输出数量兑换时记录
+}
+
+/// 标准化的添加或移除流动性事件
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LiquidityChange {
+    pub program_id: String,     // DEX 程序 ID
+    pub signature: String,      // 交易签名
+    pub signer: String,         // 交易签名者（手续费支付者）
+    pub slot: u64,              // 所在 slot
+    pub timestamp: Option<i64>, // 区块时间（Unix 时间戳），节点未记录时为 None
+    pub pool: String,           // 流动性池地址
+    pub token_a: TokenAmount,   // 基础代币实际存入（取回）数量
+    pub token_b: TokenAmount,   // 报价代币实际存入（取回）数量
+}
+
+/// 监控事件，由 `services::RaydiumMonitor` 推送
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MonitorEvent {
+    /// 新建流动性池（initialize2 交易）
+    PoolCreated(PoolCreated),
+    /// 集中流动性（CLMM、Whirlpool）新建流动性池
+    ClmmPoolCreated(ClmmPoolCreated),
+    /// 集中流动性（CLMM、Whirlpool）兑换，只在未开启 `new_pools_only` 时推送
+    ClmmSwap(ClmmSwap),
+    /// CLMM 开仓，只在未开启 `new_pools_only` 时推送
+    ClmmPositionOpened(ClmmPositionOpened),
+    /// CLMM 平仓，只在未开启 `new_pools_only` 时推送
+    ClmmPositionClosed(ClmmPositionClosed),
+    /// 订单簿（Phoenix）成交，只在未开启 `new_pools_only` 时推送
+    OrderbookFill(OrderbookFill),
+    /// 经由 Jupiter v6 路由的兑换，只在未开启 `new_pools_only` 时推送
+    JupiterRoute(JupiterRoute),
+    /// Jupiter DCA 创建定投订单
+    DcaOpened(DcaOpened),
+    /// Jupiter DCA 每期买入成交
+    DcaFilled(DcaFilled),
+    /// Jupiter DCA 关闭定投订单
+    DcaClosed(DcaClosed),
+    /// 新建流动性池（标准化）
+    NewPool(NewPool),
+    /// 兑换（标准化），只在未开启 `new_pools_only` 时推送
+    Swap(Swap),
+    /// 添加流动性（标准化），只在未开启 `new_pools_only` 时推送
+    LiquidityAdded(LiquidityChange),
+    /// 移除流动性（标准化），只在未开启 `new_pools_only` 时推送
+    LiquidityRemoved(LiquidityChange),
+    /// 其他提及 Raydium 程序、没有可解析的兑换或流动性变动的成功交易，只在未开启 `new_pools_only` 时推送
+    Logs {
+        signature: String, // 交易签名
+        slot: u64,         // 所在 slot
+        logs: Vec<String>, // 程序日志
+    },
 }
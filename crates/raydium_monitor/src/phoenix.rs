@@ -0,0 +1,282 @@
+use anyhow::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+
+use crate::model::{MonitorError, OrderbookFill, Side};
+
+/// Phoenix 订单簿程序 ID
+pub const PHOENIX: &str = "PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY";
+
+/// Log 指令标识，Phoenix 通过调用自身的 Log 指令把市场事件写入内部指令
+const LOG: u8 = 15;
+
+/// 会产生成交的 Phoenix 指令在日志中的名称
+const FILL_LOGS: [&str; 6] = [
+    "Instruction: Swap",
+    "Instruction: SwapWithFreeFunds",
+    "Instruction: PlaceLimitOrder",
+    "Instruction: PlaceLimitOrderWithFreeFunds",
+    "Instruction: PlaceMultiplePostOnlyOrders",
+    "Instruction: PlaceMultiplePostOnlyOrdersWithFreeFunds",
+];
+
+/// 市场账户中参数的结束位置（raw_base_units_per_base_unit 之后）
+const MARKET_HEADER_LEN: usize = 316;
+
+/// 市场事件批次的头部
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct AuditLogHeader {
+    pub instruction: u8,      // 产生事件的指令标识
+    pub sequence_number: u64, // 市场序号
+    pub timestamp: i64,       // 区块时间（Unix 时间戳）
+    pub slot: u64,            // 所在 slot
+    pub market: [u8; 32],     // 市场地址
+    pub signer: [u8; 32],     // 交易签名者（吃单方）
+    pub total_events: u16,    // 本次指令产生的事件总数
+}
+
+/// 成交事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct FillEvent {
+    pub index: u16,                 // 事件序号
+    pub maker_id: [u8; 32],         // 挂单方钱包
+    pub order_sequence_number: u64, // 挂单序号
+    pub price_in_ticks: u64,        // 成交价格（tick）
+    pub base_lots_filled: u64,      // 成交数量（base lot）
+    pub base_lots_remaining: u64,   // 挂单剩余数量（base lot）
+}
+
+/// 挂单事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct PlaceEvent {
+    pub index: u16,                 // 事件序号
+    pub order_sequence_number: u64, // 挂单序号
+    pub client_order_id: u128,      // 客户端订单 ID
+    pub price_in_ticks: u64,        // 挂单价格（tick）
+    pub base_lots_placed: u64,      // 挂单数量（base lot）
+}
+
+/// 撤单（减少挂单）事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct ReduceEvent {
+    pub index: u16,                 // 事件序号
+    pub order_sequence_number: u64, // 挂单序号
+    pub price_in_ticks: u64,        // 挂单价格（tick）
+    pub base_lots_removed: u64,     // 撤销数量（base lot）
+    pub base_lots_remaining: u64,   // 挂单剩余数量（base lot）
+}
+
+/// 挂单被挤出订单簿事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct EvictEvent {
+    pub index: u16,                 // 事件序号
+    pub maker_id: [u8; 32],         // 挂单方钱包
+    pub order_sequence_number: u64, // 挂单序号
+    pub price_in_ticks: u64,        // 挂单价格（tick）
+    pub base_lots_evicted: u64,     // 被挤出的数量（base lot）
+}
+
+/// 吃单成交汇总事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct FillSummaryEvent {
+    pub index: u16,                   // 事件序号
+    pub client_order_id: u128,        // 客户端订单 ID
+    pub total_base_lots_filled: u64,  // 成交数量合计（base lot）
+    pub total_quote_lots_filled: u64, // 成交金额合计（quote lot）
+    pub total_fee_in_quote_lots: u64, // 手续费合计（quote lot）
+}
+
+/// 手续费领取事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct FeeEvent {
+    pub index: u16,                        // 事件序号
+    pub fees_collected_in_quote_lots: u64, // 领取的手续费（quote lot）
+}
+
+/// 挂单有效期事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct TimeInForceEvent {
+    pub index: u16,                                // 事件序号
+    pub order_sequence_number: u64,                // 挂单序号
+    pub last_valid_slot: u64,                      // 最后有效 slot
+    pub last_valid_unix_timestamp_in_seconds: u64, // 最后有效时间（Unix 时间戳）
+}
+
+/// 挂单过期事件
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct ExpiredOrderEvent {
+    pub index: u16,                 // 事件序号
+    pub maker_id: [u8; 32],         // 挂单方钱包
+    pub order_sequence_number: u64, // 挂单序号
+    pub price_in_ticks: u64,        // 挂单价格（tick）
+    pub base_lots_removed: u64,     // 移除的数量（base lot）
+}
+
+/// Phoenix 市场事件，变体顺序与链上定义一致
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MarketEvent {
+    Uninitialized,
+    Header(AuditLogHeader),
+    Fill(FillEvent),
+    Place(PlaceEvent),
+    Reduce(ReduceEvent),
+    Evict(EvictEvent),
+    FillSummary(FillSummaryEvent),
+    Fee(FeeEvent),
+    TimeInForce(TimeInForceEvent),
+    ExpiredOrder(ExpiredOrderEvent),
+}
+
+/// 解码 Phoenix Log 指令中的市场事件
+///
+/// Log 指令数据为指令标识 15，之后依次是 `MarketEvent::Header` 和 borsh 编码的事件列表。
+///
+/// # 参数
+///
+/// * `data` - base58 编码的指令数据字符串
+///
+/// # 返回值
+///
+/// 返回事件头部和事件列表，不是 Log 指令时返回 `MonitorError::NoMatchingInstruction`
+pub fn decode_phoenix_log(data: &str) -> Result<(AuditLogHeader, Vec<MarketEvent>)> {
+    let bytes = bs58::decode(data).into_vec()?;
+    let Some((&LOG, mut data)) = bytes.split_first() else {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    };
+
+    let MarketEvent::Header(header) = MarketEvent::deserialize(&mut data)? else {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    };
+    let events = Vec::<MarketEvent>::deserialize(&mut data)?;
+
+    Ok((header, events))
+}
+
+/// 将一批市场事件中的成交事件转换为订单簿成交事件
+///
+/// 吃单方向取挂单方向的反方向；价格和数量需要调用 [`PhoenixMarket::apply`] 换算。
+///
+/// # 参数
+///
+/// * `header` - 事件头部
+/// * `events` - 市场事件列表
+/// * `signature` - 交易签名
+pub fn orderbook_fills(
+    header: &AuditLogHeader,
+    events: &[MarketEvent],
+    signature: &str,
+) -> Vec<OrderbookFill> {
+    let market = bs58::encode(header.market).into_string();
+    let taker = bs58::encode(header.signer).into_string();
+    events
+        .iter()
+        .filter_map(|event| match event {
+            MarketEvent::Fill(fill) => Some(OrderbookFill {
+                signature: signature.to_string(),
+                market: market.clone(),
+                taker: taker.clone(),
+                maker: bs58::encode(fill.maker_id).into_string(),
+                side: Side::from_order_sequence_number(fill.order_sequence_number).opposite(),
+                price_in_ticks: fill.price_in_ticks,
+                base_lots: fill.base_lots_filled,
+                base_mint: None,
+                quote_mint: None,
+                price: None,
+                size: None,
+                slot: header.slot,
+                timestamp: header.timestamp,
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 判断交易日志中是否包含可能产生成交的 Phoenix 指令
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_phoenix_fill(logs: &[String]) -> bool {
+    logs.iter().any(|log| {
+        log.strip_prefix("Program log: ")
+            .is_some_and(|log| FILL_LOGS.contains(&log))
+    })
+}
+
+/// Phoenix 市场参数，用于把 tick 和 lot 换算为实际价格和数量
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhoenixMarket {
+    pub base_mint: String,                           // base 代币 Mint 地址
+    pub quote_mint: String,                          // quote 代币 Mint 地址
+    pub base_decimals: u32,                          // base 代币精度
+    pub quote_decimals: u32,                         // quote 代币精度
+    pub base_lot_size: u64,                          // 每个 base lot 的原始数量
+    pub quote_lot_size: u64,                         // 每个 quote lot 的原始数量
+    pub tick_size_in_quote_atoms_per_base_unit: u64, // 每个 tick 对应的原始 quote 数量
+    pub raw_base_units_per_base_unit: u32,           // 每个 base unit 包含的 base 代币数量
+}
+
+impl PhoenixMarket {
+    /// 从市场账户数据中读取市场参数
+    ///
+    /// # 参数
+    ///
+    /// * `data` - 市场账户数据
+    pub fn from_account_data(data: &[u8]) -> Result<Self, MonitorError> {
+        if data.len() < MARKET_HEADER_LEN {
+            return Err(MonitorError::AccountDataTooShort {
+                account: "phoenixMarket",
+                actual: data.len(),
+            });
+        }
+
+        let u32_at =
+            |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let u64_at =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        let key_at = |offset: usize| bs58::encode(&data[offset..offset + 32]).into_string();
+        Ok(Self {
+            base_decimals: u32_at(40),
+            base_mint: key_at(48),
+            base_lot_size: u64_at(112),
+            quote_decimals: u32_at(120),
+            quote_mint: key_at(128),
+            quote_lot_size: u64_at(192),
+            tick_size_in_quote_atoms_per_base_unit: u64_at(200),
+            raw_base_units_per_base_unit: u32_at(312).max(1),
+        })
+    }
+
+    /// 将 tick 换算为价格（每个 base 代币值多少 quote 代币）
+    ///
+    /// # 参数
+    ///
+    /// * `price_in_ticks` - 价格（tick）
+    pub fn price(&self, price_in_ticks: u64) -> f64 {
+        price_in_ticks as f64 * self.tick_size_in_quote_atoms_per_base_unit as f64
+            / 10f64.powi(self.quote_decimals as i32)
+            / self.raw_base_units_per_base_unit as f64
+    }
+
+    /// 将 base lot 换算为 base 代币数量
+    ///
+    /// # 参数
+    ///
+    /// * `base_lots` - 数量（base lot）
+    pub fn base_amount(&self, base_lots: u64) -> f64 {
+        base_lots as f64 * self.base_lot_size as f64 / 10f64.powi(self.base_decimals as i32)
+    }
+
+    /// 填充成交事件的代币 Mint 地址、价格和数量
+    ///
+    /// # 参数
+    ///
+    /// * `fill` - 该市场的成交事件
+    pub fn apply(&self, fill: &mut OrderbookFill) {
+        fill.base_mint = Some(self.base_mint.clone());
+        fill.quote_mint = Some(self.quote_mint.clone());
+        fill.price = Some(self.price(fill.price_in_ticks));
+        fill.size = Some(self.base_amount(fill.base_lots));
+    }
+}
@@ -1,24 +1,372 @@
-use anyhow::Result;
-use serde_json::{json, Value};
-use solana_client::pubsub_client::PubsubClient;
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Result};
+use event_store::EventStore;
+use futures::{stream, Stream, StreamExt};
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_client::RpcClient;
 use solana_client::rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter};
+use solana_client::rpc_response::{Response, RpcLogsResponse};
 use solana_sdk::commitment_config::CommitmentConfig;
-use solana_transaction_status::option_serializer::OptionSerializer;
-use solana_transaction_status::{
-    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiInnerInstructions,
-    UiInstruction, UiMessage, UiParsedInstruction,
-};
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
 use tracing::{debug, error, info, instrument, warn};
-use utils::{fetch_token_info, init_rpc_client};
+use utils::{
+    address_book::{address_book, address_label},
+    fetch_token_info, init_rpc_client, RpcApi,
+};
+
+use crate::client::get_transaction_details_with;
+use crate::dex::{
+    decode_transaction, AmmV4Decoder, ClmmDecoder, DcaDecoder, DexDecoder, JupiterDecoder,
+    PhoenixDecoder, WhirlpoolDecoder,
+};
+use crate::model::{MonitorEvent, NewPool, PoolCreated, PoolToken, TokenAmount};
+use crate::phoenix::PhoenixMarket;
+use crate::sink::{ChannelSink, EventSink, LogSink};
+
+// 指令提取已移至 `extract` 模块（无需 RPC），这里保留原有路径
+pub use crate::extract::{
+    contains_pool_initialization, extract_amm_events, extract_clmm_events, extract_dca_events,
+    extract_jupiter_routes, extract_phoenix_fills, extract_whirlpool_events,
+};
+
+/// Raydium 流动性池 v4 程序 ID
+const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+/// 常见的报价代币（wSOL、USDC、USDT），不视为新代币
+const QUOTE_MINTS: [&str; 3] = [
+    "So11111111111111111111111111111111111111112",
+    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+    "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB",
+];
+
+/// 事件通道默认容量，消费者处理过慢时订阅会等待
+pub const DEFAULT_EVENT_CHANNEL_SIZE: usize = 128;
+
+/// DEX 协议解码器注册表
+///
+/// 监控为每个解码器的程序单独订阅日志，并用该解码器解析交易；同一程序 ID 只保留最后注册的解码器。
+/// AMM v4 新建流动性池由监控内置处理，不需要注册。
+#[derive(Clone, Default)]
+pub struct DecoderRegistry {
+    decoders: Vec<Arc<dyn DexDecoder>>, // 已注册的解码器，按注册顺序订阅
+}
+
+impl DecoderRegistry {
+    /// 创建空的注册表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册解码器，替换同一程序 ID 已注册的解码器
+    ///
+    /// # 参数
+    ///
+    /// * `decoder` - DEX 协议解码器
+    pub fn register(&mut self, decoder: Arc<dyn DexDecoder>) {
+        self.remove(decoder.program_id());
+        self.decoders.push(decoder);
+    }
+
+    /// 移除指定程序 ID 的解码器
+    ///
+    /// # 参数
+    ///
+    /// * `program_id` - 程序 ID 字符串
+    ///
+    /// # 返回值
+    ///
+    /// 返回被移除的解码器，未注册时返回 None
+    pub fn remove(&mut self, program_id: &str) -> Option<Arc<dyn DexDecoder>> {
+        let position = self
+            .decoders
+            .iter()
+            .position(|decoder| decoder.program_id() == program_id)?;
+        Some(self.decoders.remove(position))
+    }
+
+    /// 查找指定程序 ID 的解码器
+    ///
+    /// # 参数
+    ///
+    /// * `program_id` - 程序 ID 字符串
+    pub fn get(&self, program_id: &str) -> Option<&Arc<dyn DexDecoder>> {
+        self.decoders
+            .iter()
+            .find(|decoder| decoder.program_id() == program_id)
+    }
+
+    /// 创建包含全部内置解码器（CLMM、Whirlpool、Phoenix、Jupiter v6、Jupiter DCA）的注册表
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register(Arc::new(ClmmDecoder));
+        registry.register(Arc::new(WhirlpoolDecoder));
+        registry.register(Arc::new(PhoenixDecoder));
+        registry.register(Arc::new(JupiterDecoder));
+        registry.register(Arc::new(DcaDecoder));
+        registry
+    }
+
+    /// 按注册顺序遍历解码器
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn DexDecoder>> {
+        self.decoders.iter()
+    }
+}
+
+/// Raydium 异步监控
+///
+/// 使用非阻塞 `PubsubClient` 订阅 Raydium AMM v4（可选 Raydium CLMM、Orca Whirlpool、Phoenix、
+/// Jupiter v6、Jupiter DCA 以及通过 [`with_decoder`](Self::with_decoder) 接入的其他协议）程序日志，
+/// 把新建流动性池等交易解析为 [`MonitorEvent`] 推送给调用方，调用方可以将事件流接入自己的处理流程。
+///
+/// 单笔交易获取或解析失败只记录警告并跳过，不会中断订阅。事件还会依次交给通过
+/// [`with_sink`](Self::with_sink) 注册的输出端（日志、NDJSON 文件、事件存储等）。
+///
+/// # 示例
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use raydium_monitor::{model::MonitorEvent, services::RaydiumMonitor};
+///
+/// async fn example() -> anyhow::Result<()> {
+///     let monitor = RaydiumMonitor::new("wss://api.mainnet-beta.solana.com")?;
+///     let mut events = Box::pin(monitor.stream());
+///     while let Some(event) = events.next().await {
+///         if let MonitorEvent::PoolCreated(pool) = event {
+///             println!("新流动性池: {}", pool.lp_account);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct RaydiumMonitor<R = RpcClient> {
+    connection: R,                  // RPC 客户端，用于查询交易和代币信息
+    ws_url: String,                 // WebSocket URL
+    new_pools_only: bool,           // 是否只推送新建流动性池事件
+    decoders: DecoderRegistry,      // 同时订阅的其他程序的解码器
+    sinks: Vec<Arc<dyn EventSink>>, // 事件输出端，按注册顺序调用
+    channel_size: usize,            // 事件通道容量
+}
+
+impl RaydiumMonitor<RpcClient> {
+    /// 使用环境变量中的 RPC 配置创建监控
+    ///
+    /// # 参数
+    ///
+    /// * `ws_url` - WebSocket URL 字符串
+    pub fn new(ws_url: impl Into<String>) -> Result<Self> {
+        let connection = init_rpc_client(CommitmentConfig::confirmed())?;
+        Ok(Self::with_connection(connection, ws_url))
+    }
+}
+
+impl<R: RpcApi + 'static> RaydiumMonitor<R> {
+    /// 使用指定的 RPC 客户端创建监控，默认只推送新建流动性池事件
+    ///
+    /// # 参数
+    ///
+    /// * `connection` - 实现了 `RpcApi` 的 RPC 客户端，用于查询交易和代币信息
+    /// * `ws_url` - WebSocket URL 字符串
+    pub fn with_connection(connection: R, ws_url: impl Into<String>) -> Self {
+        Self {
+            connection,
+            ws_url: ws_url.into(),
+            new_pools_only: true,
+            decoders: DecoderRegistry::new(),
+            sinks: Vec::new(),
+            channel_size: DEFAULT_EVENT_CHANNEL_SIZE,
+        }
+    }
+
+    /// 设置是否只推送新建流动性池事件
+    ///
+    /// 关闭后还会推送 AMM v4 的兑换和流动性变动（`Swap`、`LiquidityAdded`、`LiquidityRemoved`），
+    /// 没有可解析事件的其他成功交易以 `MonitorEvent::Logs` 推送。
+    pub fn new_pools_only(mut self, new_pools_only: bool) -> Self {
+        self.new_pools_only = new_pools_only;
+        self
+    }
+
+    /// 设置是否同时订阅 Raydium CLMM（集中流动性）程序
+    ///
+    /// 开启后推送 CLMM 建池事件；未开启 `new_pools_only` 时还会推送兑换、开仓和平仓事件。
+    /// 事件存储只记录 AMM v4 新建流动性池事件。
+    pub fn with_clmm(self, clmm: bool) -> Self {
+        self.toggle_decoder(clmm, ClmmDecoder)
+    }
+
+    /// 设置是否同时订阅 Orca Whirlpool 程序
+    ///
+    /// 建池和兑换事件与 CLMM 共用 `ClmmPoolCreated`、`ClmmSwap`，通过 `dex` 字段区分；
+    /// 开启 `new_pools_only` 时只推送建池事件。
+    pub fn with_whirlpool(self, whirlpool: bool) -> Self {
+        self.toggle_decoder(whirlpool, WhirlpoolDecoder)
+    }
+
+    /// 设置是否同时订阅 Phoenix 订单簿程序
+    ///
+    /// 开启后以 `MonitorEvent::OrderbookFill` 推送成交事件，价格和数量按市场参数换算；
+    /// 订单簿没有建池事件，开启 `new_pools_only` 时不获取交易。
+    pub fn with_phoenix(self, phoenix: bool) -> Self {
+        self.toggle_decoder(phoenix, PhoenixDecoder)
+    }
 
-use crate::client::get_transaction_details;
-use crate::decoder::decode_ix_data;
-use crate::model::{InstructionData, InstructionDataValue, MonitorError, RaydiumInstruction};
+    /// 设置是否同时订阅 Jupiter v6 程序
+    ///
+    /// 开启后以 `MonitorEvent::JupiterRoute` 推送经由 Jupiter 路由的兑换，包含每一跳的 DEX、
+    /// 流动性池和实际数量；路由没有建池事件，开启 `new_pools_only` 时不获取交易。
+    pub fn with_jupiter(self, jupiter: bool) -> Self {
+        self.toggle_decoder(jupiter, JupiterDecoder)
+    }
+
+    /// 设置是否同时订阅 Jupiter DCA（定投）程序
+    ///
+    /// 开启后推送定投订单的创建、每期成交和关闭事件；开启 `new_pools_only` 时只推送买入代币为
+    /// 本次监控期间新建流动性池中代币（不含 wSOL、USDC、USDT）的事件，用于观察新代币的定投资金流入。
+    pub fn with_dca(self, dca: bool) -> Self {
+        self.toggle_decoder(dca, DcaDecoder)
+    }
+
+    /// 同时订阅指定解码器的程序，替换同一程序 ID 已有的解码器
+    ///
+    /// 开启 `new_pools_only` 时只推送建池事件，是否获取交易由解码器的
+    /// [`is_relevant`](DexDecoder::is_relevant) 决定。
+    ///
+    /// # 参数
+    ///
+    /// * `decoder` - DEX 协议解码器
+    pub fn with_decoder(mut self, decoder: impl DexDecoder + 'static) -> Self {
+        self.decoders.register(Arc::new(decoder));
+        self
+    }
+
+    /// 使用指定的解码器注册表替换当前注册的全部解码器
+    pub fn with_decoders(mut self, decoders: DecoderRegistry) -> Self {
+        self.decoders = decoders;
+        self
+    }
+
+    /// 注册或移除内置解码器
+    fn toggle_decoder(mut self, enabled: bool, decoder: impl DexDecoder + 'static) -> Self {
+        if enabled {
+            self.decoders.register(Arc::new(decoder));
+        } else {
+            self.decoders.remove(decoder.program_id());
+        }
+        self
+    }
+
+    /// 将新建流动性池事件写入事件存储，写入失败只记录警告
+    pub fn with_store(mut self, store: Arc<EventStore<PoolCreated>>) -> Self {
+        self.sinks.push(store);
+        self
+    }
+
+    /// 注册事件输出端，每个推送的事件都会交给它处理，处理失败只记录警告
+    ///
+    /// # 参数
+    ///
+    /// * `sink` - 事件输出端
+    pub fn with_sink(mut self, sink: impl EventSink + 'static) -> Self {
+        self.sinks.push(Arc::new(sink));
+        self
+    }
+
+    /// 设置事件通道容量
+    pub fn channel_size(mut self, channel_size: usize) -> Self {
+        self.channel_size = channel_size.max(1);
+        self
+    }
+
+    /// 在当前任务中订阅日志，将事件交给已注册的输出端后发送到通道
+    ///
+    /// 订阅中断或接收端关闭时返回。
+    ///
+    /// # 参数
+    ///
+    /// * `sender` - 事件发送端
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `Result<()>`，连接或订阅失败时返回错误
+    pub async fn run(&self, sender: mpsc::Sender<MonitorEvent>) -> Result<()> {
+        let channel = ChannelSink::new(sender);
+        let sinks: Vec<&dyn EventSink> = self
+            .sinks
+            .iter()
+            .map(|sink| sink.as_ref())
+            .chain(iter::once(&channel as &dyn EventSink))
+            .collect();
+        forward_events(
+            &self.connection,
+            &self.ws_url,
+            &self.decoders,
+            self.new_pools_only,
+            &sinks,
+        )
+        .await
+    }
+
+    /// 在当前任务中订阅日志，只将事件交给已注册的输出端
+    ///
+    /// 适合把监控作为数据源嵌入其他服务、不需要事件通道的场景；订阅中断或任一输出端关闭时返回。
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `Result<()>`，连接或订阅失败时返回错误
+    pub async fn run_sinks(&self) -> Result<()> {
+        let sinks: Vec<&dyn EventSink> = self.sinks.iter().map(|sink| sink.as_ref()).collect();
+        forward_events(
+            &self.connection,
+            &self.ws_url,
+            &self.decoders,
+            self.new_pools_only,
+            &sinks,
+        )
+        .await
+    }
+
+    /// 在后台任务中订阅日志
+    ///
+    /// # 返回值
+    ///
+    /// 返回事件接收端和后台任务句柄，任务结束时接收端随之关闭，
+    /// 可通过任务句柄获取连接或订阅错误
+    pub fn spawn(self) -> (mpsc::Receiver<MonitorEvent>, JoinHandle<Result<()>>) {
+        let (sender, receiver) = mpsc::channel(self.channel_size);
+        let handle = tokio::spawn(async move { self.run(sender).await });
+        (receiver, handle)
+    }
+
+    /// 在后台任务中订阅日志，并以 `Stream` 形式返回事件
+    ///
+    /// 订阅结束后流随之结束，连接或订阅错误只记录日志；需要获取错误时使用 [`spawn`](Self::spawn)。
+    pub fn stream(self) -> impl Stream<Item = MonitorEvent> {
+        let (receiver, handle) = self.spawn();
+        stream::unfold((receiver, handle), |(mut receiver, handle)| async move {
+            if let Some(event) = receiver.recv().await {
+                return Some((event, (receiver, handle)));
+            }
+            // 接收端关闭说明后台任务已结束，记录订阅错误
+            match handle.await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => error!("日志订阅失败: {:?}", e),
+                Err(e) => error!("日志订阅任务异常退出: {}", e),
+            }
+            None
+        })
+    }
+}
 
 /// 订阅并处理 Solana 日志
 ///
 /// 该函数连接到指定的 WebSocket URL，订阅特定程序 ID 的日志，
-/// 并处理与新建流动性池相关的交易。
+/// 并以日志形式输出新建流动性池信息。需要自行处理事件时使用 [`RaydiumMonitor`]。
 ///
 /// # 参数
 ///
@@ -31,266 +379,300 @@ use crate::model::{InstructionData, InstructionDataValue, MonitorError, RaydiumI
 pub async fn subscribe_to_logs(ws_url: &str) -> Result<()> {
     let connection = init_rpc_client(CommitmentConfig::confirmed())?;
 
+    subscribe_to_logs_with(&connection, ws_url).await
+}
+
+/// 使用指定的 RPC 客户端订阅并处理 Solana 日志
+///
+/// # 参数
+///
+/// * `connection` - 实现了 `RpcApi` 的 RPC 客户端，用于查询交易和代币信息
+/// * `ws_url` - WebSocket URL 字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<()>`，表示操作成功或失败
+#[instrument(skip(connection))]
+pub async fn subscribe_to_logs_with<R: RpcApi>(connection: &R, ws_url: &str) -> Result<()> {
+    run_log_subscription(connection, ws_url, None).await
+}
+
+/// 订阅 Solana 日志，并将新建流动性池事件写入事件存储
+///
+/// 写入失败只记录警告，不会中断订阅；历史事件可通过 `EventStore::replay` 回放。
+///
+/// # 参数
+///
+/// * `connection` - 实现了 `RpcApi` 的 RPC 客户端，用于查询交易和代币信息
+/// * `ws_url` - WebSocket URL 字符串
+/// * `store` - 新建流动性池事件存储
+///
+/// # 返回值
+///
+/// 返回 `Result<()>`，表示操作成功或失败
+#[instrument(skip(connection, store))]
+pub async fn subscribe_to_logs_with_store<R: RpcApi>(
+    connection: &R,
+    ws_url: &str,
+    store: &EventStore<PoolCreated>,
+) -> Result<()> {
+    run_log_subscription(connection, ws_url, Some(store)).await
+}
+
+/// 订阅日志并在同一任务中输出新建流动性池信息
+async fn run_log_subscription<R: RpcApi>(
+    connection: &R,
+    ws_url: &str,
+    store: Option<&EventStore<PoolCreated>>,
+) -> Result<()> {
+    let mut sinks: Vec<&dyn EventSink> = Vec::new();
+    if let Some(store) = store {
+        sinks.push(store);
+    }
+    sinks.push(&LogSink);
+
+    // 只订阅 AMM v4
+    forward_events(connection, ws_url, &DecoderRegistry::new(), true, &sinks).await
+}
+
+/// 订阅日志并将解析后的事件依次交给输出端
+async fn forward_events<R: RpcApi>(
+    connection: &R,
+    ws_url: &str,
+    decoders: &DecoderRegistry,
+    new_pools_only: bool,
+    sinks: &[&dyn EventSink],
+) -> Result<()> {
     info!("正在订阅日志");
-    // 步骤 1：连接 WebSocket 并订阅特定程序 ID 的日志
-    let (_, logs_receiver) = PubsubClient::logs_subscribe(
-        ws_url,
-        RpcTransactionLogsFilter::Mentions(vec![
-            "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".to_string()
-        ]),
-        RpcTransactionLogsConfig {
-            commitment: Some(CommitmentConfig::confirmed()),
-        },
-    )?;
+    // 步骤 1：连接 WebSocket，按程序 ID 分别订阅日志（每个订阅只能过滤一个地址），
+    // AMM v4 始终订阅，其他程序由对应的解码器解析
+    let client = PubsubClient::new(ws_url).await?;
+    let mut subscriptions = Vec::new();
+    let mut unsubscribes = Vec::new();
+    for decoder in iter::once(None).chain(decoders.iter().cloned().map(Some)) {
+        let program_id = decoder
+            .as_ref()
+            .map_or(RAYDIUM_AMM_V4, |decoder| decoder.program_id());
+        let (logs, unsubscribe) = client
+            .logs_subscribe(
+                RpcTransactionLogsFilter::Mentions(vec![program_id.to_string()]),
+                RpcTransactionLogsConfig {
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )
+            .await?;
+        subscriptions.push(
+            logs.map(move |response| (decoder.clone(), response))
+                .boxed(),
+        );
+        unsubscribes.push(unsubscribe);
+    }
+    let mut logs = stream::select_all(subscriptions);
+    let mut phoenix_markets = HashMap::new();
+    let mut new_tokens = HashSet::new();
 
     info!("成功订阅日志");
 
-    // 步骤 2：持续处理接收到的日志
-    loop {
-        match logs_receiver.recv() {
-            Ok(response) => {
-                debug!("收到日志响应");
-                // 步骤 3：检查是否为 initialize2 指令的日志
-                if response.value.err.is_none()
-                    && response
-                        .value
-                        .logs
-                        .iter()
-                        .any(|log| log.contains("initialize2"))
-                {
-                    let signature = response.value.signature;
-                    info!("正在处理交易，签名: {}", signature);
-
-                    // 步骤 4：获取交易详情
-                    let tx = get_transaction_details(&signature).await?;
-
-                    let ray = String::from("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
-
-                    // 步骤 5：处理交易数据
-                    let (instruction_data, _) = process_transaction(&tx, &ray)?;
-
-                    // 步骤 6：根据指令数据类型进行处理
-                    match instruction_data.value {
-                        InstructionDataValue::AccountsAndData { accounts, data } => {
-                            // 获取相关账户地址
-                            let lp_account = &accounts[4];
-                            let token_a_account = &accounts[8];
-                            let token_b_account = &accounts[9];
-
-                            // 步骤 7：获取代币信息
-                            info!("正在获取代币 A 的信息: {}", token_a_account);
-                            let token_a = fetch_token_info(&connection, token_a_account)?;
-                            info!("正在获取代币 B 的信息: {}", token_b_account);
-                            let token_b = fetch_token_info(&connection, token_b_account)?;
-
-                            // 步骤 8：解码指令数据
-                            let decoded_ix_data =
-                                decode_ix_data::<RaydiumInstruction>(&data.unwrap())?;
-
-                            // 步骤 9：打印新流动性池信息
-                            info!("新流动性池创建成功!");
-                            info!("交易链接：https://solscan.io/tx/{}", signature);
-                            info!("新的 LP 地址：{}", lp_account);
-
-                            // 步骤 10：构建并打印显示数据
-                            let display_data = vec![
-                                json!({
-                                    "代币": token_a.0.name.trim_matches(char::from(0)),
-                                    "账户公钥": token_a_account,
-                                    "数量": decoded_ix_data.init_coin_amount as f64 / 10f64.powi(token_a.1.decimals as i32),
-                                    "代币精度": token_a.1.decimals,
-                                }),
-                                json!({
-                                    "代币": token_b.0.name.trim_matches(char::from(0)),
-                                    "账户公钥": token_b_account,
-                                    "数量": decoded_ix_data.init_pc_amount as f64 / 10f64.powi(token_b.1.decimals as i32),
-                                    "代币精度": token_b.1.decimals,
-                                }),
-                            ];
-
-                            info!(
-                                "流动性池详情:\n{}",
-                                serde_json::to_string_pretty(&display_data)?
-                            );
-
-                            info!("交易处理成功");
-                        }
-                        InstructionDataValue::Amount(amount) => {
-                            println!("Amount: {}", amount);
-                        }
-                    }
-                }
+    // 步骤 2：持续处理接收到的日志，输出端关闭时停止
+    'receive: while let Some((decoder, response)) = logs.next().await {
+        debug!("收到日志响应");
+        let events = if let Some(decoder) = decoder {
+            // 步骤 3：获取交易并使用解码器提取事件
+            let mut events = decoder_events(
+                connection,
+                decoder.as_ref(),
+                &response.value,
+                new_pools_only,
+            )
+            .await;
+            apply_phoenix_markets(connection, &mut events, &mut phoenix_markets);
+            if new_pools_only {
+                events.retain(|event| is_new_pool_event(event, &new_tokens));
             }
-            Err(e) => {
-                error!("账户订阅错误: {:?}", e);
-                break;
+            events
+        } else {
+            // 步骤 3：获取并解析 AMM v4 交易
+            amm_events(connection, response, new_pools_only).await
+        };
+
+        // 步骤 4：按注册顺序将事件交给输出端
+        for event in events {
+            if sinks.iter().any(|sink| sink.is_closed()) {
+                info!("事件输出端已关闭，停止订阅");
+                break 'receive;
+            }
+            record_new_tokens(&event, &mut new_tokens);
+            for sink in sinks {
+                if let Err(e) = sink.handle(&event).await {
+                    warn!("事件输出失败: {:#}", e);
+                }
             }
         }
     }
 
+    drop(logs);
+    for unsubscribe in unsubscribes {
+        unsubscribe().await;
+    }
+    client.shutdown().await?;
+    if !sinks.iter().any(|sink| sink.is_closed()) {
+        return Err(anyhow!("日志订阅已中断"));
+    }
     Ok(())
 }
 
-/// 处理交易数据，提取指定程序 ID 的指令信息
-///
-/// 该函数解析交易数据，寻找与目标程序 ID 匹配的指令，并返回相关的指令数据。
-///
-/// # 参数
-///
-/// * `tx` - 编码后的确认交易及其元数据
-/// * `target_program_id` - 目标程序 ID 字符串
+/// 获取解码器需要的交易并提取事件
 ///
-/// # 返回值
+/// 只获取成功执行、且日志满足解码器过滤条件的交易。
+async fn decoder_events<R: RpcApi>(
+    connection: &R,
+    decoder: &dyn DexDecoder,
+    logs: &RpcLogsResponse,
+    new_pools_only: bool,
+) -> Vec<MonitorEvent> {
+    if logs.err.is_some() || !decoder.is_relevant(&logs.logs, new_pools_only) {
+        return Vec::new();
+    }
+
+    info!("正在处理 {} 交易，签名: {}", decoder.name(), logs.signature);
+    match get_transaction_details_with(connection, &logs.signature).await {
+        Ok(tx) => decode_transaction(decoder, &tx, &logs.signature),
+        Err(e) => {
+            warn!(
+                "获取 {} 交易失败: {}, {}",
+                decoder.name(),
+                logs.signature,
+                e
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// 按市场参数换算 Phoenix 成交事件的价格和数量
 ///
-/// 返回 `Result<InstructionData, MonitorError>`，包含匹配指令的数据，
-/// 或在未找到匹配指令或遇到其他错误时返回 `MonitorError`。
-#[instrument(skip(tx), fields(target_program_id = %target_program_id))]
-pub fn process_transaction(
-    tx: &EncodedConfirmedTransactionWithStatusMeta,
-    target_program_id: &str,
-) -> Result<(InstructionData, Option<UiInnerInstructions>), MonitorError> {
-    info!("开始处理交易");
-    match &tx.transaction.transaction {
-        EncodedTransaction::Json(t) => match &t.message {
-            UiMessage::Raw(message) => {
-                // 步骤 1：查找目标程序 ID 的索引
-                let ray_index = message
-                    .account_keys
-                    .iter()
-                    .position(|key| key == target_program_id)
-                    .ok_or_else(|| {
-                        error!("未找到目标程序 ID");
-                        MonitorError::ProgramIdNotFound
-                    })?;
-
-                info!(ray_index, "找到目标程序索引");
-
-                // 步骤 2：返回所有账户信息
-                Ok((
-                    InstructionData {
-                        value: InstructionDataValue::AccountsAndData {
-                            accounts: message.account_keys.clone(),
-                            data: None,
-                        },
-                    },
-                    None,
-                ))
-            }
-            UiMessage::Parsed(message) => {
-                debug!("处理已解析的消息");
-                // 步骤 3：处理指令并返回第一个匹配的 InstructionData 和相应的 InnerInstruction
-                message
-                    .instructions
-                    .iter()
-                    .enumerate()
-                    .find_map(|(index, ix)| {
-                        let data = process_instruction(ix, target_program_id);
-                        if data.is_some() {
-                            info!(instruction_index = index, "找到匹配的指令");
-                        }
-                        // 步骤 4：查找对应的内部指令
-                        if let Some(meta) = &tx.transaction.meta {
-                            match &meta.inner_instructions {
-                                OptionSerializer::Some(inner_instructions) => {
-                                    let inner_instruction = inner_instructions
-                                        .iter()
-                                        .find(|inner_instruction| {
-                                            inner_instruction.index == index as u8
-                                        })
-                                        .cloned();
-
-                                    if let Some(d) = data {
-                                        return Some((d, inner_instruction));
-                                    }
-                                }
-                                _ => {
-                                    warn!("未找到内部指令");
-                                }
-                            }
-                        }
-
-                        data.map(|d| (d, None))
-                    })
-                    .ok_or(MonitorError::NoMatchingInstruction)
+/// 市场参数按市场地址缓存，获取失败时只推送 tick 和 lot，下次遇到该市场时重试。
+fn apply_phoenix_markets<R: RpcApi>(
+    connection: &R,
+    events: &mut [MonitorEvent],
+    markets: &mut HashMap<String, PhoenixMarket>,
+) {
+    for event in events {
+        let MonitorEvent::OrderbookFill(fill) = event else {
+            continue;
+        };
+        if !markets.contains_key(&fill.market) {
+            match fetch_phoenix_market(connection, &fill.market) {
+                Ok(market) => {
+                    markets.insert(fill.market.clone(), market);
+                }
+                Err(e) => warn!("获取 Phoenix 市场参数失败: {}, {}", fill.market, e),
             }
-        },
-        _ => {
-            error!("不支持的交易格式");
-            Err(MonitorError::UnsupportedTransactionFormat)
+        }
+        if let Some(market) = markets.get(&fill.market) {
+            market.apply(fill);
         }
     }
 }
 
-/// 处理单个指令，提取与目标程序 ID 匹配的指令数据
+/// 获取 AMM v4 交易并提取事件
 ///
-/// # 参数
+/// 新建流动性池补充代币名称后以 `PoolCreated` 推送；未开启 `new_pools_only` 时，
+/// 没有可解析的兑换或流动性变动的成功交易以 `MonitorEvent::Logs` 推送。
+async fn amm_events<R: RpcApi>(
+    connection: &R,
+    response: Response<RpcLogsResponse>,
+    new_pools_only: bool,
+) -> Vec<MonitorEvent> {
+    let mut events = Vec::new();
+    for event in decoder_events(connection, &AmmV4Decoder, &response.value, new_pools_only).await {
+        let MonitorEvent::NewPool(new_pool) = event else {
+            events.push(event);
+            continue;
+        };
+        match pool_created(connection, &new_pool) {
+            Ok(pool) => events.push(MonitorEvent::PoolCreated(pool)),
+            Err(e) => warn!("解析新流动性池失败: {}, {}", new_pool.signature, e),
+        }
+    }
+
+    if events.is_empty() && !new_pools_only && response.value.err.is_none() {
+        events.push(MonitorEvent::Logs {
+            signature: response.value.signature,
+            slot: response.context.slot,
+            logs: response.value.logs,
+        });
+    }
+    events
+}
+
+/// 只推送新建流动性池事件时保留的事件：建池事件，以及买入代币为新代币的定投事件
+fn is_new_pool_event(event: &MonitorEvent, new_tokens: &HashSet<String>) -> bool {
+    let output_mint = match event {
+        MonitorEvent::PoolCreated(_)
+        | MonitorEvent::ClmmPoolCreated(_)
+        | MonitorEvent::NewPool(_) => return true,
+        MonitorEvent::DcaOpened(dca) => &dca.output_mint,
+        MonitorEvent::DcaFilled(dca) => &dca.output_mint,
+        MonitorEvent::DcaClosed(dca) => &dca.output_mint,
+        _ => return false,
+    };
+    new_tokens.contains(output_mint)
+}
+
+/// 记录新建流动性池中的代币，报价代币除外
+fn record_new_tokens(event: &MonitorEvent, new_tokens: &mut HashSet<String>) {
+    let mints = match event {
+        MonitorEvent::PoolCreated(pool) => [&pool.token_a.mint, &pool.token_b.mint],
+        MonitorEvent::ClmmPoolCreated(pool) => [&pool.token_mint_0, &pool.token_mint_1],
+        MonitorEvent::NewPool(pool) => [&pool.token_a.mint, &pool.token_b.mint],
+        _ => return,
+    };
+    new_tokens.extend(
+        mints
+            .into_iter()
+            .filter(|mint| !QUOTE_MINTS.contains(&mint.as_str()))
+            .cloned(),
+    );
+}
+
+/// 获取 Phoenix 市场账户并读取市场参数
+fn fetch_phoenix_market<R: RpcApi>(connection: &R, market: &str) -> Result<PhoenixMarket> {
+    let data = connection.get_account_data(&Pubkey::from_str(market)?)?;
+    Ok(PhoenixMarket::from_account_data(&data)?)
+}
+
+/// 查询代币名称，将标准化的新建流动性池事件转换为可写入事件存储的 `PoolCreated`
+fn pool_created<R: RpcApi>(connection: &R, pool: &NewPool) -> Result<PoolCreated> {
+    let token = |token: &TokenAmount| -> Result<PoolToken> {
+        info!("正在获取代币信息: {}", address_label(&token.mint));
+        let (metadata, mint) = fetch_token_info(connection, &token.mint)?;
+        Ok(PoolToken {
+            mint: token.mint.clone(),
+            name: metadata.name.trim_matches(char::from(0)).to_string(),
+            amount: token.amount as f64 / 10f64.powi(mint.decimals as i32),
+            raw_amount: token.amount,
+            decimals: mint.decimals,
+        })
+    };
+
+    Ok(PoolCreated {
+        signature: pool.signature.clone(),
+        lp_account: pool.pool.clone(),
+        lp_name: address_book().name_of(&pool.pool).map(str::to_string),
+        open_time: pool.open_time.unwrap_or_default(),
+        token_a: token(&pool.token_a)?,
+        token_b: token(&pool.token_b)?,
+    })
+}
+
+/// 判断日志推送是否为成功执行的 initialize2（创建流动性池）交易
 ///
-/// * `instruction` - UI 指令对象
-/// * `target_program_id` - 目标程序 ID 字符串
+/// 日志订阅的过滤条件，每条推送都会经过这里，需保持轻量。
 ///
-/// # 返回值
+/// # 参数
 ///
-/// 返回 `Option<InstructionData>`，如果找到匹配的指令则返回 Some(InstructionData)，
-/// 否则返回 None。
-#[instrument(skip(instruction), fields(target_program_id = %target_program_id))]
-pub fn process_instruction(
-    instruction: &UiInstruction,
-    target_program_id: &str,
-) -> Option<InstructionData> {
-    match instruction {
-        UiInstruction::Compiled(compiled_instruction) => {
-            debug!("处理已编译的指令");
-            // 步骤 1：处理已编译的指令
-            Some(InstructionData {
-                value: InstructionDataValue::AccountsAndData {
-                    accounts: compiled_instruction
-                        .accounts
-                        .iter()
-                        .map(|&i| i.to_string())
-                        .collect(),
-                    data: Some(compiled_instruction.data.clone()),
-                },
-            })
-        }
-        UiInstruction::Parsed(parsed_instruction) => match parsed_instruction {
-            UiParsedInstruction::Parsed(parsed_ix) => {
-                debug!("处理完全解析的指令");
-                // 步骤 2：处理完全解析的指令
-                match &parsed_ix.parsed {
-                    Value::Object(map) => {
-                        if let Some(nested_obj) = map.get("info").and_then(|v| v.as_object()) {
-                            if let Some(inner_value) =
-                                nested_obj.get("amount").and_then(|v| v.as_str())
-                            {
-                                if let Ok(amount) = inner_value.parse::<u64>() {
-                                    debug!("解析到金额: {}", amount);
-
-                                    return Some(InstructionData {
-                                        value: InstructionDataValue::Amount(amount),
-                                    });
-                                }
-                            }
-                        }
-                        None
-                    }
-                    _ => None,
-                }
-            }
-            UiParsedInstruction::PartiallyDecoded(partially_decoded_instruction) => {
-                // 步骤 3：处理部分解码的指令
-                if partially_decoded_instruction.program_id == target_program_id {
-                    info!("找到匹配的部分解码指令");
-                    Some(InstructionData {
-                        value: InstructionDataValue::AccountsAndData {
-                            accounts: partially_decoded_instruction.accounts.clone(),
-                            data: Some(partially_decoded_instruction.data.clone()),
-                        },
-                    })
-                } else {
-                    debug!("部分解码指令不匹配目标程序 ID");
-                    None
-                }
-            }
-        },
-    }
+/// * `logs` - 日志订阅推送
+pub fn is_pool_initialization(logs: &RpcLogsResponse) -> bool {
+    logs.err.is_none() && contains_pool_initialization(&logs.logs)
 }
@@ -0,0 +1,176 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, Context, Result};
+use event_store::EventStore;
+use futures::future::BoxFuture;
+use serde_json::json;
+use tokio::sync::mpsc;
+use tracing::info;
+use utils::address_book::address_label;
+
+use crate::model::{MonitorEvent, PoolCreated};
+
+/// 事件输出端
+///
+/// 监控按注册顺序把每个事件交给全部输出端，输出端返回错误时只记录警告，不会中断订阅。
+/// 通过 [`RaydiumMonitor::with_sink`](crate::services::RaydiumMonitor::with_sink) 注册，
+/// 内置日志（[`LogSink`]）、NDJSON 文件（[`NdjsonFileSink`]）、通道（[`ChannelSink`]）和
+/// 事件存储（`EventStore<PoolCreated>`，只记录 AMM v4 新建流动性池）输出端。
+pub trait EventSink: Send + Sync {
+    /// 处理一个监控事件
+    ///
+    /// # 参数
+    ///
+    /// * `event` - 监控事件
+    fn handle<'a>(&'a self, event: &'a MonitorEvent) -> BoxFuture<'a, Result<()>>;
+
+    /// 输出端是否已关闭，任一输出端关闭时监控停止订阅
+    fn is_closed(&self) -> bool {
+        false
+    }
+}
+
+/// 以日志形式输出事件
+///
+/// 新建流动性池输出可读的流动性池详情，其他事件输出格式化的 JSON。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LogSink;
+
+impl EventSink for LogSink {
+    fn handle<'a>(&'a self, event: &'a MonitorEvent) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            match event {
+                MonitorEvent::PoolCreated(pool) => print_pool(pool),
+                _ => {
+                    info!("监控事件:\n{}", serde_json::to_string_pretty(event)?);
+                    Ok(())
+                }
+            }
+        })
+    }
+}
+
+/// 以 NDJSON 格式追加写入文件，每个事件一行
+///
+/// 每行写入后立即落盘，可以用 `tail -f` 或其他进程按行读取。
+#[derive(Debug)]
+pub struct NdjsonFileSink {
+    path: PathBuf,     // 输出文件路径
+    file: Mutex<File>, // 追加模式打开的输出文件
+}
+
+impl NdjsonFileSink {
+    /// 打开（或创建）输出文件，已有内容会保留
+    ///
+    /// # 参数
+    ///
+    /// * `path` - 输出文件路径，父目录不存在时会自动创建
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("打开事件输出文件失败: {}", path.display()))?;
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+        })
+    }
+
+    /// 输出文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl EventSink for NdjsonFileSink {
+    fn handle<'a>(&'a self, event: &'a MonitorEvent) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let mut line = serde_json::to_vec(event)?;
+            line.push(b'\n');
+            // 整行一次写入，避免多个监控写同一文件时行内容交错
+            let mut file = self
+                .file
+                .lock()
+                .map_err(|_| anyhow!("事件输出文件锁已损坏"))?;
+            file.write_all(&line)?;
+            file.flush()?;
+            Ok(())
+        })
+    }
+}
+
+/// 将事件发送到通道，供调用方在其他任务中处理
+///
+/// 接收端关闭后输出端随之关闭，监控停止订阅。
+#[derive(Debug, Clone)]
+pub struct ChannelSink {
+    sender: mpsc::Sender<MonitorEvent>, // 事件发送端
+}
+
+impl ChannelSink {
+    /// 使用指定的发送端创建通道输出端
+    ///
+    /// # 参数
+    ///
+    /// * `sender` - 事件发送端，通道已满时等待接收端处理
+    pub fn new(sender: mpsc::Sender<MonitorEvent>) -> Self {
+        Self { sender }
+    }
+}
+
+impl EventSink for ChannelSink {
+    fn handle<'a>(&'a self, event: &'a MonitorEvent) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.sender
+                .send(event.clone())
+                .await
+                .map_err(|_| anyhow!("事件接收端已关闭"))
+        })
+    }
+
+    fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+/// 将 AMM v4 新建流动性池事件写入事件存储，其他事件忽略
+impl EventSink for EventStore<PoolCreated> {
+    fn handle<'a>(&'a self, event: &'a MonitorEvent) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            if let MonitorEvent::PoolCreated(pool) = event {
+                self.append(pool.clone()).context("写入事件存储失败")?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// 打印新流动性池信息
+fn print_pool(pool: &PoolCreated) -> Result<()> {
+    info!("新流动性池创建成功!");
+    info!("交易链接：https://solscan.io/tx/{}", pool.signature);
+    info!("新的 LP 地址：{}", address_label(&pool.lp_account));
+
+    let display_data = [&pool.token_a, &pool.token_b].map(|token| {
+        json!({
+            "代币": token.name,
+            "账户公钥": token.mint,
+            "数量": token.amount,
+            "代币精度": token.decimals,
+        })
+    });
+    info!(
+        "流动性池详情:\n{}",
+        serde_json::to_string_pretty(&display_data)?
+    );
+    info!("交易处理成功");
+    Ok(())
+}
@@ -0,0 +1,301 @@
+use std::{fs, iter, path::Path};
+
+use anyhow::Result;
+use borsh::BorshSerialize;
+use serde_json::{json, Value};
+use solana_client::rpc_response::RpcLogsResponse;
+use solana_sdk::pubkey::Pubkey;
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+use crate::extract::extract_amm_events;
+use crate::layout::{Initialize2Accounts, SwapAccounts};
+use crate::model::{MonitorEvent, RaydiumInstruction, SwapIxData};
+use crate::services::is_pool_initialization;
+
+/// Raydium 流动性池 v4 程序 ID
+pub const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+/// 计算预算程序 ID
+const COMPUTE_BUDGET: &str = "ComputeBudget111111111111111111111111111111";
+
+/// 代币程序 ID
+const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+
+/// 报价代币（wSOL）Mint 地址
+const WSOL: &str = "So11111111111111111111111111111111111111112";
+
+/// 根据种子生成确定性的地址，保证基准测试每次输入一致
+pub fn synthetic_pubkey(seed: u64, index: u64) -> Pubkey {
+    let mut bytes = [0u8; 32];
+    bytes[..8].copy_from_slice(&seed.to_le_bytes());
+    bytes[8..16].copy_from_slice(&index.to_le_bytes());
+    bytes[31] = 1;
+    Pubkey::new_from_array(bytes)
+}
+
+/// 生成 base58 编码的 initialize2 指令数据
+pub fn synthetic_initialize2_data(seed: u64) -> String {
+    let ix = RaydiumInstruction {
+        discriminator: 1,
+        nonce: (seed % 255) as u8,
+        opentime: 1_700_000_000 + seed,
+        init_pc_amount: 1_000_000_000 + seed * 7,
+        init_coin_amount: 5_000_000_000_000 + seed * 13,
+    };
+    encode(&ix)
+}
+
+/// 生成 base58 编码的 swap 指令数据
+pub fn synthetic_swap_data(seed: u64) -> String {
+    let ix = SwapIxData {
+        discriminator: 9,
+        amount_in: 10_000_000 + seed,
+        minimum_amount_out: 9_000_000 + seed / 2,
+    };
+    encode(&ix)
+}
+
+/// 生成一笔创建流动性池的交易
+///
+/// 结构与 `jsonParsed` 编码返回的交易一致：先是一条计算预算指令，
+/// 再是部分解码的 Raydium initialize2 指令。
+pub fn synthetic_initialize2(seed: u64) -> EncodedConfirmedTransactionWithStatusMeta {
+    let accounts = synthetic_accounts(seed, Initialize2Accounts::LEN);
+    let vaults = [(10, accounts[8].clone()), (11, accounts[9].clone())];
+    synthetic_transaction(
+        seed,
+        &accounts,
+        synthetic_initialize2_data(seed),
+        vaults,
+        Vec::new(),
+    )
+}
+
+/// 生成一笔 Raydium swap 交易
+///
+/// 用报价代币买入基础代币：内部指令先从用户账户转入报价代币账户，再从基础代币账户转给用户。
+pub fn synthetic_swap(seed: u64) -> EncodedConfirmedTransactionWithStatusMeta {
+    let accounts = synthetic_accounts(seed, SwapAccounts::LEN_WITH_TARGET_ORDERS);
+    let vaults = [
+        (5, synthetic_pubkey(seed, 100).to_string()),
+        (6, WSOL.to_string()),
+    ];
+    let transfers = vec![
+        synthetic_transfer(
+            &accounts[15],
+            &accounts[6],
+            &accounts[17],
+            10_000_000 + seed,
+        ),
+        synthetic_transfer(
+            &accounts[5],
+            &accounts[16],
+            &accounts[2],
+            9_500_000 + seed / 2,
+        ),
+    ];
+    synthetic_transaction(
+        seed,
+        &accounts,
+        synthetic_swap_data(seed),
+        vaults,
+        transfers,
+    )
+}
+
+/// 生成一批交易，每 `pool_every` 笔中包含一笔创建流动性池交易，其余为 swap
+///
+/// # 参数
+///
+/// * `count` - 交易数量
+/// * `pool_every` - 创建流动性池交易的间隔，0 表示全部为 swap
+pub fn synthetic_batch(
+    count: usize,
+    pool_every: usize,
+) -> Vec<EncodedConfirmedTransactionWithStatusMeta> {
+    (0..count as u64)
+        .map(|seed| {
+            if pool_every > 0 && seed % pool_every as u64 == 0 {
+                synthetic_initialize2(seed)
+            } else {
+                synthetic_swap(seed)
+            }
+        })
+        .collect()
+}
+
+/// 生成一条日志订阅推送
+///
+/// # 参数
+///
+/// * `seed` - 种子
+/// * `initialize` - 是否为 initialize2 日志，否则为 swap 日志
+pub fn synthetic_logs(seed: u64, initialize: bool) -> RpcLogsResponse {
+    let instruction = if initialize {
+        "Program log: initialize2: InitializeInstruction2 { nonce: 254, open_time: 1700000000, init_pc_amount: 1000000000, init_coin_amount: 5000000000000 }"
+    } else {
+        "Program log: ray_log: A0BCDwAAAAAAAAAAAAAAAAACAAAAAAAAAEBCDwAAAAAA"
+    };
+
+    RpcLogsResponse {
+        signature: bs58::encode(seed.to_le_bytes().repeat(8)).into_string(),
+        err: None,
+        logs: vec![
+            format!("Program {} invoke [1]", COMPUTE_BUDGET),
+            format!("Program {} success", COMPUTE_BUDGET),
+            format!("Program {} invoke [1]", RAYDIUM_AMM_V4),
+            instruction.to_string(),
+            format!(
+                "Program {} consumed 31337 of 200000 compute units",
+                RAYDIUM_AMM_V4
+            ),
+            format!("Program {} success", RAYDIUM_AMM_V4),
+        ],
+    }
+}
+
+/// 依次执行日志过滤和 AMM v4 事件提取，模拟监控的热路径
+///
+/// 通过过滤的推送应解码出新建流动性池事件，其余应解码出兑换事件。
+///
+/// # 返回值
+///
+/// 返回是否提取到预期的事件
+pub fn replay_pipeline(
+    logs: &RpcLogsResponse,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> bool {
+    let is_pool = is_pool_initialization(logs);
+    extract_amm_events(tx, &logs.signature)
+        .iter()
+        .any(|event| match event {
+            MonitorEvent::NewPool(_) => is_pool,
+            MonitorEvent::Swap(_) => !is_pool,
+            _ => false,
+        })
+}
+
+/// 读取抓取的交易，支持 JSON 数组或每行一笔交易的 JSONL
+///
+/// 文件内容为 `getTransaction`（`jsonParsed` 编码）的返回结果。
+pub fn load_captured(
+    path: impl AsRef<Path>,
+) -> Result<Vec<EncodedConfirmedTransactionWithStatusMeta>> {
+    let content = fs::read_to_string(path)?;
+    if content.trim_start().starts_with('[') {
+        return Ok(serde_json::from_str(&content)?);
+    }
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Ok(serde_json::from_str(line)?))
+        .collect()
+}
+
+fn synthetic_accounts(seed: u64, count: usize) -> Vec<String> {
+    (0..count as u64)
+        .map(|index| synthetic_pubkey(seed, index).to_string())
+        .collect()
+}
+
+/// 生成一条已解析的代币转账内部指令
+fn synthetic_transfer(source: &str, destination: &str, authority: &str, amount: u64) -> Value {
+    json!({
+        "program": "spl-token",
+        "programId": TOKEN_PROGRAM,
+        "parsed": {
+            "type": "transfer",
+            "info": {
+                "source": source,
+                "destination": destination,
+                "authority": authority,
+                "amount": amount.to_string(),
+            },
+        },
+        "stackHeight": 2,
+    })
+}
+
+/// 按 `getTransaction`（`jsonParsed` 编码）的返回格式构造交易
+///
+/// 账户列表依次为签名者、Raydium 指令的账户和程序 ID；`vaults` 为流动性池基础代币和报价代币账户
+/// 在指令账户中的位置及其 Mint 地址，写入代币余额记录。
+fn synthetic_transaction(
+    seed: u64,
+    accounts: &[String],
+    data: String,
+    vaults: [(usize, String); 2],
+    transfers: Vec<Value>,
+) -> EncodedConfirmedTransactionWithStatusMeta {
+    let payer = synthetic_pubkey(seed, u64::MAX - 1).to_string();
+    let account_keys: Vec<Value> = iter::once((payer.as_str(), true))
+        .chain(accounts.iter().map(|account| (account.as_str(), false)))
+        .chain([RAYDIUM_AMM_V4, COMPUTE_BUDGET, TOKEN_PROGRAM].map(|program| (program, false)))
+        .map(|(pubkey, signer)| {
+            json!({
+                "pubkey": pubkey,
+                "writable": signer,
+                "signer": signer,
+                "source": "transaction",
+            })
+        })
+        .collect();
+    let token_balances: Vec<Value> = vaults
+        .into_iter()
+        .zip([6u8, 9])
+        .map(|((position, mint), decimals)| {
+            json!({
+                "accountIndex": position + 1,
+                "mint": mint,
+                "uiTokenAmount": {
+                    "uiAmount": null,
+                    "decimals": decimals,
+                    "amount": "0",
+                    "uiAmountString": "0",
+                },
+            })
+        })
+        .collect();
+
+    let tx = json!({
+        "slot": 300_000_000 + seed,
+        "blockTime": 1_700_000_000 + seed as i64,
+        "transaction": {
+            "signatures": [bs58::encode(seed.to_le_bytes().repeat(8)).into_string()],
+            "message": {
+                "accountKeys": account_keys,
+                "recentBlockhash": synthetic_pubkey(seed, u64::MAX).to_string(),
+                "instructions": [
+                    {
+                        "programId": COMPUTE_BUDGET,
+                        "accounts": [],
+                        "data": "3DTZbgwsozUF",
+                    },
+                    {
+                        "programId": RAYDIUM_AMM_V4,
+                        "accounts": accounts,
+                        "data": data,
+                    },
+                ],
+            },
+        },
+        "meta": {
+            "err": null,
+            "status": { "Ok": null },
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "innerInstructions": [{ "index": 1, "instructions": transfers }],
+            "logMessages": [],
+            "preTokenBalances": token_balances,
+            "postTokenBalances": token_balances,
+            "rewards": [],
+        },
+    });
+    serde_json::from_value(tx).expect("构造合成交易失败")
+}
+
+fn encode<T: BorshSerialize>(value: &T) -> String {
+    bs58::encode(borsh::to_vec(value).expect("序列化指令数据失败")).into_string()
+}
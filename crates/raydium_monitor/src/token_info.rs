@@ -1,31 +1,36 @@
 use std::str::FromStr;
 
 use anyhow::Result;
-use solana_account_decoder::UiAccountEncoding;
-use solana_client::rpc_client::RpcClient;
-use solana_client::rpc_config::RpcAccountInfoConfig;
 use solana_sdk::account::ReadableAccount;
-use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::program_pack::Pack;
 use solana_sdk::pubkey::Pubkey;
 use spl_token::state::Account;
+use utils::RpcApi;
 
-pub async fn get_token_addresses(
-    rpc_client: &RpcClient,
-    accounts: &[String],
+use crate::layout::SwapAccounts;
+
+/// 查询 swap 中用户支付和接收代币账户的 Mint 地址
+///
+/// # 参数
+///
+/// * `rpc_client` - 实现了 `RpcApi` 的 RPC 客户端
+/// * `accounts` - 按 swap 指令定义解析的账户
+///
+/// # 返回值
+///
+/// 返回支付和接收代币的 Mint 地址，账户不存在或不是代币账户时为 None
+pub async fn get_token_addresses<R: RpcApi>(
+    rpc_client: &R,
+    accounts: &SwapAccounts,
 ) -> Result<(Option<Pubkey>, Option<Pubkey>)> {
-    let config = RpcAccountInfoConfig {
-        encoding: Some(UiAccountEncoding::Base64),
-        commitment: Some(CommitmentConfig::confirmed()),
-        ..RpcAccountInfoConfig::default()
-    };
+    let source = Pubkey::from_str(&accounts.user_source)?;
+    let destination = Pubkey::from_str(&accounts.user_destination)?;
 
-    let source_token_account_info = rpc_client
-        .get_account_with_config(&Pubkey::from_str(&accounts[15])?, config.clone())?
-        .value;
-    let destination_token_account_info = rpc_client
-        .get_account_with_config(&Pubkey::from_str(&accounts[16])?, config.clone())?
-        .value;
+    let mut token_accounts = rpc_client
+        .get_multiple_accounts(&[source, destination])?
+        .into_iter();
+    let source_token_account_info = token_accounts.next().flatten();
+    let destination_token_account_info = token_accounts.next().flatten();
 
     let source_token_address = source_token_account_info
         .and_then(|info| Account::unpack_unchecked(info.data()).ok())
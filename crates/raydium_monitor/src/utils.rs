@@ -1,30 +1,28 @@
 use anyhow::Result;
-use solana_client::rpc_client::RpcClient;
-use solana_sdk::pubkey::Pubkey;
-use solana_transaction_status::UiInnerInstructions;
 use tracing::info;
 
-use crate::model::SwapIxData;
-use crate::swap_analyzer::{calculate_slippage, get_actual_amount};
-use utils::fetch_token_info;
+use crate::model::{Swap, TokenAmount};
+use crate::swap_analyzer::calculate_slippage;
+use utils::{fetch_token_info, RpcApi};
 
 pub use utils::{init_tracing, load_env};
 
-pub fn log_swap_operation(
-    rpc_client: &RpcClient,
-    accounts: Vec<String>,
-    source_address: Option<Pubkey>,
-    dest_address: Option<Pubkey>,
-    decoded_data: Option<SwapIxData>,
-    inner_ixs: Option<UiInnerInstructions>,
-) -> Result<()> {
-    match (source_address, dest_address, decoded_data) {
-        (Some(source), Some(dest), Some(decoded)) => {
-            log_sell_operation(rpc_client, accounts, source, dest, decoded, inner_ixs)
-        }
-        (None, Some(dest), Some(decoded)) => {
-            log_buy_operation(rpc_client, accounts, dest, decoded, inner_ixs)
-        }
+/// wSOL Mint 地址
+const WSOL: &str = "So11111111111111111111111111111111111111112";
+
+/// 根据兑换方向记录日志，支付 SOL 的为买入，获得 SOL 的为卖出，其他兑换不记录
+///
+/// # 参数
+///
+/// * `swap` - AMM v4 兑换事件
+///
+/// # 返回值
+///
+/// 返回 `Result<()>`
+pub fn log_swap_operation<R: RpcApi>(rpc_client: &R, swap: &Swap) -> Result<()> {
+    match (swap.input.mint.as_str(), swap.output.mint.as_str()) {
+        (WSOL, _) => log_buy_operation(rpc_client, swap),
+        (_, WSOL) => log_sell_operation(rpc_client, swap),
         _ => Ok(()),
     }
 }
@@ -33,45 +31,30 @@ pub fn log_swap_operation(
 ///
 /// # 参数
 ///
-/// * `accounts` - 账户列表
-/// * `destination_token_address` - 目标代币地址
-/// * `decoded_ix` - 解码后的指令数据
-/// * `inner_ix` - 内部指令
+/// * `swap` - 支付 SOL 买入代币的兑换事件
 ///
 /// # 返回值
 ///
 /// 返回 `Result<()>`
-pub fn log_buy_operation(
-    rpc_client: &RpcClient,
-    accounts: Vec<String>,
-    destination_token_address: Pubkey,
-    decoded_ix: SwapIxData,
-    inner_ix: Option<UiInnerInstructions>,
-) -> Result<()> {
-    let token_info = fetch_token_info(rpc_client, destination_token_address)?;
-    let actual_amount = get_actual_amount(token_info.1.decimals, inner_ix);
-    let slippage_rate = calculate_slippage(
-        actual_amount as f64,
-        decoded_ix.minimum_amount_out as f64 / 10f64.powi(token_info.1.decimals as i32),
-    );
+pub fn log_buy_operation<R: RpcApi>(rpc_client: &R, swap: &Swap) -> Result<()> {
+    let (metadata, mint) = fetch_token_info(rpc_client, &swap.output.mint)?;
+    let actual_amount = ui_amount(&swap.output, mint.decimals);
 
-    info!(
-        "代币全称: {}",
-        token_info.0.name.trim_matches(char::from(0))
-    );
-    info!("代币简称: {}", token_info.0.symbol);
-    info!("操作地址：{}", accounts[17]);
-    info!(
-        "预期花费: {} Sol",
-        decoded_ix.amount_in as f64 / 10f64.powi(9)
-    );
-    info!(
-        "预期最少获得: {} {}",
-        decoded_ix.minimum_amount_out as f64 / 10f64.powi(token_info.1.decimals as i32),
-        token_info.0.symbol
-    );
-    info!("实际获得: {} {}", actual_amount as f64, token_info.0.symbol);
-    info!("滑点: {:.2}%", slippage_rate);
+    info!("代币全称: {}", metadata.name.trim_matches(char::from(0)));
+    info!("代币简称: {}", metadata.symbol);
+    info!("操作地址：{}", swap.signer);
+    info!("实际花费: {} Sol", ui_amount(&swap.input, 9));
+    if let Some(max_amount_in) = swap.max_amount_in {
+        info!("预期最多花费: {} Sol", max_amount_in as f64 / 10f64.powi(9));
+    }
+    if let Some(min_amount_out) = swap.min_amount_out {
+        let expected = min_amount_out as f64 / 10f64.powi(mint.decimals as i32);
+        info!("预期最少获得: {} {}", expected, metadata.symbol);
+        info!("实际获得: {} {}", actual_amount, metadata.symbol);
+        info!("滑点: {:.2}%", calculate_slippage(actual_amount, expected));
+    } else {
+        info!("实际获得: {} {}", actual_amount, metadata.symbol);
+    }
 
     Ok(())
 }
@@ -80,48 +63,38 @@ pub fn log_buy_operation(
 ///
 /// # 参数
 ///
-/// * `accounts` - 账户列表
-/// * `source_token_address` - 源代币地址
-/// * `destination_token_address` - 目标代币地址
-/// * `decoded_ix` - 解码后的指令数据
-/// * `inner_ix` - 内部指令
+/// * `swap` - 卖出代币获得 SOL 的兑换事件
 ///
 /// # 返回值
 ///
 /// 返回 `Result<()>`
-pub fn log_sell_operation(
-    rpc_client: &RpcClient,
-    accounts: Vec<String>,
-    source_token_address: Pubkey,
-    destination_token_address: Pubkey,
-    decoded_ix: SwapIxData,
-    inner_ix: Option<UiInnerInstructions>,
-) -> Result<()> {
-    let source_token_info = fetch_token_info(rpc_client, source_token_address)?;
-    let destination_token_info = fetch_token_info(rpc_client, destination_token_address)?;
-    let actual_amount = get_actual_amount(destination_token_info.1.decimals, inner_ix);
-    let slippage_rate = calculate_slippage(
-        actual_amount as f64,
-        decoded_ix.minimum_amount_out as f64 / 10f64.powi(9),
-    );
+pub fn log_sell_operation<R: RpcApi>(rpc_client: &R, swap: &Swap) -> Result<()> {
+    let (metadata, mint) = fetch_token_info(rpc_client, &swap.input.mint)?;
+    let actual_amount = ui_amount(&swap.output, 9);
 
     info!("正在处理 Sell 操作");
-    info!(
-        "卖出代币: {}",
-        source_token_info.0.name.trim_matches(char::from(0))
-    );
-    info!("操作地址：{}", accounts[17]);
+    info!("卖出代币: {}", metadata.name.trim_matches(char::from(0)));
+    info!("操作地址：{}", swap.signer);
     info!(
         "卖出数量: {} {}",
-        decoded_ix.amount_in as f64 / 10f64.powi(source_token_info.1.decimals as i32),
-        source_token_info.0.symbol
-    );
-    info!(
-        "预期获得: {} Sol",
-        decoded_ix.minimum_amount_out as f64 / 10f64.powi(9),
+        ui_amount(&swap.input, mint.decimals),
+        metadata.symbol
     );
-    info!("实际获得: {} Sol", actual_amount as f64);
-    info!("滑点: {:.2}%", slippage_rate);
+    if let Some(min_amount_out) = swap.min_amount_out {
+        let expected = min_amount_out as f64 / 10f64.powi(9);
+        info!("预期获得: {} Sol", expected);
+        info!("实际获得: {} Sol", actual_amount);
+        info!("滑点: {:.2}%", calculate_slippage(actual_amount, expected));
+    } else {
+        info!("实际获得: {} Sol", actual_amount);
+    }
 
     Ok(())
 }
+
+/// 按精度换算数量，事件中没有记录精度时使用代币账户查询到的精度
+fn ui_amount(amount: &TokenAmount, decimals: u8) -> f64 {
+    amount
+        .ui_amount()
+        .unwrap_or_else(|| amount.amount as f64 / 10f64.powi(decimals as i32))
+}
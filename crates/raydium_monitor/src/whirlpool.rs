@@ -0,0 +1,251 @@
+use anyhow::Result;
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::Serialize;
+
+use crate::model::MonitorError;
+
+/// Orca Whirlpool 程序 ID
+pub const ORCA_WHIRLPOOL: &str = "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc";
+
+/// Anchor 指令标识：sha256("global:<指令名>") 的前 8 个字节
+const INITIALIZE_POOL: [u8; 8] = [95, 180, 10, 172, 84, 174, 232, 40];
+const INITIALIZE_POOL_V2: [u8; 8] = [207, 45, 87, 242, 27, 63, 204, 67];
+const SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+const SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+/// 创建流动性池指令在日志中的名称
+const POOL_CREATION_LOGS: [&str; 2] = [
+    "Instruction: InitializePool",
+    "Instruction: InitializePoolV2",
+];
+
+/// 兑换指令在日志中的名称
+const SWAP_LOGS: [&str; 2] = ["Instruction: Swap", "Instruction: SwapV2"];
+
+/// initialize_pool 指令参数
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct InitializePoolArgs {
+    pub whirlpool_bump: u8,       // 流动性池 PDA 的 bump
+    pub tick_spacing: u16,        // tick 间距
+    pub initial_sqrt_price: u128, // 初始价格的平方根（Q64.64）
+}
+
+/// initialize_pool_v2 指令参数
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct InitializePoolV2Args {
+    pub tick_spacing: u16,        // tick 间距
+    pub initial_sqrt_price: u128, // 初始价格的平方根（Q64.64）
+}
+
+/// swap / swap_v2 指令参数（swap_v2 末尾的 remaining_accounts_info 不解析）
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Debug, Clone)]
+pub struct WhirlpoolSwapArgs {
+    pub amount: u64,                     // 指定的输入（或输出）数量
+    pub other_amount_threshold: u64,     // 另一侧的最少输出（或最多输入）数量
+    pub sqrt_price_limit: u128,          // 价格限制的平方根（Q64.64）
+    pub amount_specified_is_input: bool, // `amount` 是否为输入数量
+    pub a_to_b: bool,                    // 是否由 token A 兑换为 token B
+}
+
+/// Orca Whirlpool 指令
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WhirlpoolIx {
+    /// 创建流动性池
+    InitializePool(InitializePoolArgs),
+    /// 创建流动性池（支持 Token-2022）
+    InitializePoolV2(InitializePoolV2Args),
+    /// 兑换
+    Swap(WhirlpoolSwapArgs),
+    /// 兑换（支持 Token-2022）
+    SwapV2(WhirlpoolSwapArgs),
+}
+
+impl WhirlpoolIx {
+    /// 指令名称，与 Whirlpool IDL 中的命名一致
+    pub fn name(&self) -> &'static str {
+        match self {
+            WhirlpoolIx::InitializePool(_) => "initializePool",
+            WhirlpoolIx::InitializePoolV2(_) => "initializePoolV2",
+            WhirlpoolIx::Swap(_) => "swap",
+            WhirlpoolIx::SwapV2(_) => "swapV2",
+        }
+    }
+}
+
+/// 解码 Orca Whirlpool 指令数据
+///
+/// # 参数
+///
+/// * `data` - base58 编码的指令数据字符串
+///
+/// # 返回值
+///
+/// 返回 `Result<WhirlpoolIx>`，不需要解析的指令返回 `MonitorError::NoMatchingInstruction`
+pub fn decode_whirlpool_ix(data: &str) -> Result<WhirlpoolIx> {
+    let bytes = bs58::decode(data).into_vec()?;
+    if bytes.len() < 8 {
+        return Err(MonitorError::NoMatchingInstruction.into());
+    }
+    let (discriminator, mut args) = bytes.split_at(8);
+
+    let ix = match <[u8; 8]>::try_from(discriminator)? {
+        INITIALIZE_POOL => WhirlpoolIx::InitializePool(InitializePoolArgs::deserialize(&mut args)?),
+        INITIALIZE_POOL_V2 => {
+            WhirlpoolIx::InitializePoolV2(InitializePoolV2Args::deserialize(&mut args)?)
+        }
+        SWAP => WhirlpoolIx::Swap(WhirlpoolSwapArgs::deserialize(&mut args)?),
+        SWAP_V2 => WhirlpoolIx::SwapV2(WhirlpoolSwapArgs::deserialize(&mut args)?),
+        _ => return Err(MonitorError::NoMatchingInstruction.into()),
+    };
+
+    Ok(ix)
+}
+
+/// 判断交易日志中是否包含创建 Whirlpool 流动性池的指令
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_whirlpool_pool_creation(logs: &[String]) -> bool {
+    contains_instruction(logs, &POOL_CREATION_LOGS)
+}
+
+/// 判断交易日志中是否包含需要解析的 Whirlpool 指令（建池、兑换）
+///
+/// # 参数
+///
+/// * `logs` - 交易日志
+pub fn contains_whirlpool_action(logs: &[String]) -> bool {
+    contains_whirlpool_pool_creation(logs) || contains_instruction(logs, &SWAP_LOGS)
+}
+
+fn contains_instruction(logs: &[String], names: &[&str]) -> bool {
+    logs.iter().any(|log| {
+        log.strip_prefix("Program log: ")
+            .is_some_and(|log| names.contains(&log))
+    })
+}
+
+/// initialize_pool / initialize_pool_v2 指令的账户布局
+///
+/// initialize_pool：0 whirlpools_config、1 token_mint_a、2 token_mint_b、3 funder、4 whirlpool、
+/// 5 token_vault_a、6 token_vault_b、7 fee_tier，之后为程序账户，共 11 个；
+/// initialize_pool_v2 在 token_mint_b 之后插入 token_badge_a、token_badge_b，共 14 个。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhirlpoolInitializePoolAccounts {
+    pub whirlpools_config: String, // Whirlpool 配置账户
+    pub token_mint_a: String,      // token A Mint 地址
+    pub token_mint_b: String,      // token B Mint 地址
+    pub funder: String,            // 创建流动性池的钱包
+    pub whirlpool: String,         // 流动性池地址
+    pub token_vault_a: String,     // 流动性池的 token A 账户
+    pub token_vault_b: String,     // 流动性池的 token B 账户
+}
+
+impl WhirlpoolInitializePoolAccounts {
+    /// initialize_pool 指令涉及的账户数量
+    pub const LEN: usize = 11;
+
+    /// initialize_pool_v2 指令涉及的账户数量
+    pub const LEN_V2: usize = 14;
+
+    /// 按创建流动性池指令定义解析账户列表
+    ///
+    /// # 参数
+    ///
+    /// * `accounts` - 指令的账户地址列表
+    /// * `v2` - 是否为 initialize_pool_v2 指令
+    pub fn from_accounts(accounts: &[String], v2: bool) -> Result<Self, MonitorError> {
+        let (len, offset) = if v2 {
+            (Self::LEN_V2, 2)
+        } else {
+            (Self::LEN, 0)
+        };
+        if accounts.len() < len {
+            return Err(MonitorError::AccountLayoutMismatch {
+                instruction: "initializePool",
+                actual: accounts.len(),
+            });
+        }
+
+        Ok(Self {
+            whirlpools_config: accounts[0].clone(),
+            token_mint_a: accounts[1].clone(),
+            token_mint_b: accounts[2].clone(),
+            funder: accounts[3 + offset].clone(),
+            whirlpool: accounts[4 + offset].clone(),
+            token_vault_a: accounts[5 + offset].clone(),
+            token_vault_b: accounts[6 + offset].clone(),
+        })
+    }
+}
+
+/// swap / swap_v2 指令的账户布局
+///
+/// swap：0 token_program、1 token_authority、2 whirlpool、3 token_owner_account_a、
+/// 4 token_vault_a、5 token_owner_account_b、6 token_vault_b，之后为 tick array 和 oracle，共 11 个；
+/// swap_v2：0、1 为两个 token 程序，2 memo_program、3 token_authority、4 whirlpool、
+/// 5 token_mint_a、6 token_mint_b、7 token_owner_account_a、8 token_vault_a、
+/// 9 token_owner_account_b、10 token_vault_b，之后为 tick array 和 oracle，共 15 个。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhirlpoolSwapAccounts {
+    pub token_authority: String,       // 用户钱包（交易签名者）
+    pub whirlpool: String,             // 流动性池地址
+    pub token_owner_account_a: String, // 用户的 token A 账户
+    pub token_vault_a: String,         // 流动性池的 token A 账户
+    pub token_owner_account_b: String, // 用户的 token B 账户
+    pub token_vault_b: String,         // 流动性池的 token B 账户
+}
+
+impl WhirlpoolSwapAccounts {
+    /// swap 指令涉及的账户数量
+    pub const LEN: usize = 11;
+
+    /// swap_v2 指令涉及的账户数量
+    pub const LEN_V2: usize = 15;
+
+    /// 按兑换指令定义解析账户列表
+    ///
+    /// # 参数
+    ///
+    /// * `accounts` - 指令的账户地址列表
+    /// * `v2` - 是否为 swap_v2 指令
+    pub fn from_accounts(accounts: &[String], v2: bool) -> Result<Self, MonitorError> {
+        let len = if v2 { Self::LEN_V2 } else { Self::LEN };
+        if accounts.len() < len {
+            return Err(MonitorError::AccountLayoutMismatch {
+                instruction: "swap",
+                actual: accounts.len(),
+            });
+        }
+
+        // swap_v2 多出 token 程序、memo 程序和两个 Mint 账户
+        let [authority, whirlpool, owner_a, vault_a, owner_b, vault_b] = if v2 {
+            [3, 4, 7, 8, 9, 10]
+        } else {
+            [1, 2, 3, 4, 5, 6]
+        };
+        Ok(Self {
+            token_authority: accounts[authority].clone(),
+            whirlpool: accounts[whirlpool].clone(),
+            token_owner_account_a: accounts[owner_a].clone(),
+            token_vault_a: accounts[vault_a].clone(),
+            token_owner_account_b: accounts[owner_b].clone(),
+            token_vault_b: accounts[vault_b].clone(),
+        })
+    }
+
+    /// 按兑换方向返回流动性池接收输入代币和支付输出代币的账户
+    ///
+    /// # 参数
+    ///
+    /// * `a_to_b` - 是否由 token A 兑换为 token B
+    pub fn vaults(&self, a_to_b: bool) -> (&str, &str) {
+        if a_to_b {
+            (&self.token_vault_a, &self.token_vault_b)
+        } else {
+            (&self.token_vault_b, &self.token_vault_a)
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! # Raydium 解码器 WASM 绑定
+//!
+//! 将 `raydium_monitor` 的指令解码器、交易事件解码器和数据模型导出到 wasm32，
+//! Web 看板可以在浏览器中使用与 Rust 监控完全相同的逻辑解码交易，得到相同的 `MonitorEvent`。
+//!
+//! 构建:
+//! ```bash
+//! wasm-pack build crates/raydium_monitor_wasm --target web
+//! ```
+use raydium_monitor::dex::{
+    decode_transaction, AmmV4Decoder, ClmmDecoder, DcaDecoder, DexDecoder, JupiterDecoder,
+    PhoenixDecoder, WhirlpoolDecoder,
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+pub use raydium_monitor::decoder::decode_raydium_ix;
+pub use raydium_monitor::extract::extract_amm_events;
+pub use raydium_monitor::model::{MonitorEvent, RaydiumIx};
+
+/// -- 内置的全部协议解码器，与 Rust 监控使用的解码器相同
+const DECODERS: [&dyn DexDecoder; 6] = [
+    &AmmV4Decoder,
+    &ClmmDecoder,
+    &WhirlpoolDecoder,
+    &PhoenixDecoder,
+    &JupiterDecoder,
+    &DcaDecoder,
+];
+
+/// -- 解析 `getTransaction`（`jsonParsed` 编码）返回的交易 JSON
+///
+/// # 参数
+/// * `json` - 交易 JSON
+pub fn parse_transaction(
+    json: &str,
+) -> serde_json::Result<EncodedConfirmedTransactionWithStatusMeta> {
+    serde_json::from_str(json)
+}
+
+/// -- 使用全部内置解码器解码交易
+///
+/// 依次解码 AMM v4、CLMM、Whirlpool、Phoenix、Jupiter v6 和 Jupiter DCA 的指令，
+/// 事件按解码器顺序、同一解码器内按指令顺序排列。
+///
+/// # 参数
+/// * `tx` - 编码后的确认交易及其元数据
+/// * `signature` - 交易签名
+pub fn decode_transaction_events(
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    signature: &str,
+) -> Vec<MonitorEvent> {
+    DECODERS
+        .iter()
+        .flat_map(|decoder| decode_transaction(*decoder, tx, signature))
+        .collect()
+}
+
+#[cfg(target_arch = "wasm32")]
+mod bindings {
+    use super::*;
+    use raydium_monitor::decoder::decode_ix_data;
+    use raydium_monitor::model::{RaydiumInstruction, SwapIxData};
+    use serde::Serialize;
+    use wasm_bindgen::prelude::*;
+
+    fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+        serde_wasm_bindgen::to_value(value).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// -- 事件中的 u64 数量可能超过 JS 安全整数范围，统一转换为 BigInt
+    fn events_to_js(events: &[MonitorEvent]) -> Result<JsValue, JsError> {
+        let serializer =
+            serde_wasm_bindgen::Serializer::new().serialize_large_number_types_as_bigints(true);
+        events
+            .serialize(&serializer)
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    fn parse_tx(json: &str) -> Result<EncodedConfirmedTransactionWithStatusMeta, JsError> {
+        parse_transaction(json).map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// -- 解码 swapBaseIn 指令数据
+    #[wasm_bindgen(js_name = decodeSwapIx)]
+    pub fn decode_swap_ix(data: &str) -> Result<JsValue, JsError> {
+        let ix: SwapIxData = decode_ix_data(data).map_err(|e| JsError::new(&e.to_string()))?;
+        to_js(&ix)
+    }
+
+    /// -- 解码 initialize2 指令数据
+    #[wasm_bindgen(js_name = decodeInitializeIx)]
+    pub fn decode_initialize_ix(data: &str) -> Result<JsValue, JsError> {
+        let ix: RaydiumInstruction =
+            decode_ix_data(data).map_err(|e| JsError::new(&e.to_string()))?;
+        to_js(&ix)
+    }
+
+    /// -- 根据指令标识自动解码 Raydium 指令数据
+    #[wasm_bindgen(js_name = decodeRaydiumIx)]
+    pub fn decode_raydium_ix_js(data: &str) -> Result<JsValue, JsError> {
+        let ix = decode_raydium_ix(data).map_err(|e| JsError::new(&e.to_string()))?;
+        to_js(&ix)
+    }
+
+    /// -- 从交易 JSON 中解码 AMM v4 事件（新建流动性池、兑换、添加和移除流动性）
+    #[wasm_bindgen(js_name = decodeAmmEvents)]
+    pub fn decode_amm_events(tx_json: &str, signature: &str) -> Result<JsValue, JsError> {
+        events_to_js(&extract_amm_events(&parse_tx(tx_json)?, signature))
+    }
+
+    /// -- 使用全部内置解码器从交易 JSON 中解码监控事件
+    #[wasm_bindgen(js_name = decodeTransactionEvents)]
+    pub fn decode_transaction_events_js(
+        tx_json: &str,
+        signature: &str,
+    ) -> Result<JsValue, JsError> {
+        events_to_js(&decode_transaction_events(&parse_tx(tx_json)?, signature))
+    }
+}
@@ -0,0 +1,235 @@
+use crate::schedule::CronSchedule;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{fs, path::Path, time::Duration};
+use time::Weekday;
+
+/// -- 守护进程配置
+///
+/// 从 TOML 文件加载，描述需要监控的程序、过滤条件、通知目标和自动清理计划
+#[derive(Debug, Clone, Deserialize)]
+pub struct DaemonConfig {
+    /// 钱包密钥文件路径，自动清理时需要
+    pub wallet_path: Option<String>,
+    /// WebSocket 地址，未设置时读取 `HELIUS_WS_RPC_URL`
+    pub ws_url: Option<String>,
+    /// 监控任务
+    #[serde(default)]
+    pub watch: Vec<WatchConfig>,
+    /// 自动清理计划
+    pub cleanup: Option<CleanupConfig>,
+    /// 定时任务
+    #[serde(default)]
+    pub schedule: Vec<ScheduleConfig>,
+    /// 通知目标
+    #[serde(default)]
+    pub notify: Vec<NotifyConfig>,
+    /// 重启策略
+    #[serde(default)]
+    pub restart: RestartConfig,
+    /// Prometheus 指标监听地址，例如 `0.0.0.0:9184`；未设置时不提供 `/metrics` 接口
+    pub metrics_addr: Option<String>,
+}
+
+/// -- 监控任务配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct WatchConfig {
+    /// 任务名称，用于日志和通知
+    pub name: String,
+    /// 订阅的程序 ID
+    pub program_ids: Vec<String>,
+    /// 日志过滤条件，任意一条日志包含其中任意字符串即匹配；为空时匹配所有成功交易
+    #[serde(default)]
+    pub log_contains: Vec<String>,
+}
+
+/// -- 自动清理配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct CleanupConfig {
+    /// 每周执行的日期，如 "Sunday"；为空时每天执行
+    pub weekday: Option<String>,
+    /// 执行时间（小时，按 `TZ_OFFSET` 时区）
+    #[serde(default)]
+    pub hour: u8,
+    /// 执行时间（分钟）
+    #[serde(default)]
+    pub minute: u8,
+    /// 每批处理的账户数量
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    /// 是否合并为批量交易
+    #[serde(default)]
+    pub use_batch_tx: bool,
+    /// 是否同时销毁并关闭零值代币账户
+    #[serde(default)]
+    pub burn_zero_value: bool,
+}
+
+/// -- 定时任务配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScheduleConfig {
+    /// 任务名称，用于日志和通知
+    pub name: String,
+    /// cron 表达式（分 时 日 月 周，按 `TZ_OFFSET` 时区），也支持 `@hourly`、`@daily` 等简写
+    pub cron: String,
+    /// 任务内容
+    #[serde(flatten)]
+    pub job: JobConfig,
+    /// 是否通知每次执行结果；失败和重叠跳过总会通知
+    #[serde(default = "default_true")]
+    pub notify: bool,
+}
+
+/// -- 定时任务内容，由 `job` 字段区分
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "job", rename_all = "snake_case")]
+pub enum JobConfig {
+    /// 扫描代币账户，汇报可回收的租金
+    Scan,
+    /// 关闭零余额账户
+    Cleanup {
+        /// 每批处理的账户数量
+        #[serde(default = "default_batch_size")]
+        batch_size: usize,
+        /// 是否合并为批量交易
+        #[serde(default)]
+        use_batch_tx: bool,
+        /// 是否同时销毁并关闭零值代币账户
+        #[serde(default)]
+        burn_zero_value: bool,
+    },
+    /// 生成资产快照
+    PortfolioSnapshot {
+        /// 钱包地址，为空时使用 `wallet_path` 对应的钱包
+        #[serde(default)]
+        wallets: Vec<String>,
+        /// 快照 JSON 输出目录，为空时只发送通知
+        output_dir: Option<String>,
+    },
+    /// 检查 RPC 和监控任务是否正常
+    MonitorHealth {
+        /// 监控任务超过该时间（秒）没有事件即视为异常
+        #[serde(default = "default_max_silence_secs")]
+        max_silence_secs: u64,
+    },
+}
+
+/// -- 通知目标配置
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotifyConfig {
+    /// Webhook 地址
+    pub webhook_url: String,
+}
+
+/// -- 重启策略
+#[derive(Debug, Clone, Deserialize)]
+pub struct RestartConfig {
+    /// 首次重启等待时间（秒）
+    pub initial_backoff_secs: u64,
+    /// 最长重启等待时间（秒）
+    pub max_backoff_secs: u64,
+}
+
+impl Default for RestartConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_secs: 5,
+            max_backoff_secs: 300,
+        }
+    }
+}
+
+impl RestartConfig {
+    /// -- 首次重启等待时间
+    pub fn initial_backoff(&self) -> Duration {
+        Duration::from_secs(self.initial_backoff_secs)
+    }
+
+    /// -- 最长重启等待时间
+    pub fn max_backoff(&self) -> Duration {
+        Duration::from_secs(self.max_backoff_secs)
+    }
+}
+
+fn default_batch_size() -> usize {
+    5
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_silence_secs() -> u64 {
+    3600
+}
+
+impl CleanupConfig {
+    /// -- 解析执行日期
+    pub fn weekday(&self) -> Result<Option<Weekday>> {
+        let Some(weekday) = &self.weekday else {
+            return Ok(None);
+        };
+        let weekday = match weekday.to_ascii_lowercase().as_str() {
+            "monday" | "mon" => Weekday::Monday,
+            "tuesday" | "tue" => Weekday::Tuesday,
+            "wednesday" | "wed" => Weekday::Wednesday,
+            "thursday" | "thu" => Weekday::Thursday,
+            "friday" | "fri" => Weekday::Friday,
+            "saturday" | "sat" => Weekday::Saturday,
+            "sunday" | "sun" => Weekday::Sunday,
+            other => anyhow::bail!("无效的日期: {}", other),
+        };
+        Ok(Some(weekday))
+    }
+}
+
+impl DaemonConfig {
+    /// -- 从 TOML 文件加载配置
+    ///
+    /// # 参数
+    /// * `path` - 配置文件路径
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let content =
+            fs::read_to_string(path).with_context(|| format!("读取配置失败: {:?}", path))?;
+        let config: Self = toml::from_str(&content)?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// -- 校验配置
+    fn validate(&self) -> Result<()> {
+        if let Some(cleanup) = &self.cleanup {
+            anyhow::ensure!(
+                self.wallet_path.is_some(),
+                "启用自动清理时必须配置 wallet_path"
+            );
+            anyhow::ensure!(cleanup.hour < 24 && cleanup.minute < 60, "清理时间无效");
+            cleanup.weekday()?;
+        }
+        for schedule in &self.schedule {
+            schedule
+                .cron
+                .parse::<CronSchedule>()
+                .with_context(|| format!("定时任务 {} 的 cron 表达式无效", schedule.name))?;
+            let needs_wallet = match &schedule.job {
+                JobConfig::Scan | JobConfig::Cleanup { .. } => true,
+                JobConfig::PortfolioSnapshot { wallets, .. } => wallets.is_empty(),
+                JobConfig::MonitorHealth { .. } => false,
+            };
+            anyhow::ensure!(
+                !needs_wallet || self.wallet_path.is_some(),
+                "定时任务 {} 需要配置 wallet_path",
+                schedule.name
+            );
+        }
+        for watch in &self.watch {
+            anyhow::ensure!(
+                !watch.program_ids.is_empty(),
+                "监控任务 {} 未配置程序 ID",
+                watch.name
+            );
+        }
+        Ok(())
+    }
+}
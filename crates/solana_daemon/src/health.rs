@@ -0,0 +1,36 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// -- 监控任务健康状态
+///
+/// 记录每个监控任务最近一次建立订阅或收到事件的时间，供健康检查任务判断是否长时间无响应
+#[derive(Clone, Default)]
+pub struct HealthRegistry {
+    last_seen: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl HealthRegistry {
+    /// -- 记录任务活跃
+    pub fn touch(&self, name: &str) {
+        self.last_seen
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), Instant::now());
+    }
+
+    /// -- 各任务距离最近一次活跃的时间，按任务名称排序
+    pub fn idle_times(&self) -> Vec<(String, Duration)> {
+        let mut idle: Vec<(String, Duration)> = self
+            .last_seen
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, seen)| (name.clone(), seen.elapsed()))
+            .collect();
+        idle.sort_by(|a, b| a.0.cmp(&b.0));
+        idle
+    }
+}
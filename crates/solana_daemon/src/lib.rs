@@ -0,0 +1,125 @@
+//! # 守护进程
+//!
+//! 从声明式配置加载监控任务、过滤条件、通知目标、自动清理计划和 cron 定时任务，
+//! 统一监督 Raydium 监控与代币账户清理，任务失败后按指数退避自动重启。
+//! 配置了 `metrics_addr` 时通过 `/metrics` 接口提供清理操作的 Prometheus 指标。
+use anyhow::{Context, Result};
+use futures::future::{join_all, BoxFuture};
+use health::HealthRegistry;
+use notify::Notifier;
+use scheduler::JobContext;
+use solana_toolkits::metrics::{serve_metrics, CleanupMetrics};
+use std::sync::Arc;
+use supervisor::supervise;
+use tracing::{info, warn};
+
+pub mod config;
+pub mod health;
+pub mod notify;
+pub mod scheduler;
+pub mod supervisor;
+pub mod tasks;
+
+pub use config::DaemonConfig;
+pub use utils::schedule;
+
+/// -- 运行守护进程，直到进程退出
+///
+/// # 参数
+/// * `config` - 守护进程配置
+pub async fn run(config: DaemonConfig) -> Result<()> {
+    let notifier = Notifier::new(config.notify.clone());
+    let health = HealthRegistry::default();
+    let mut tasks: Vec<BoxFuture<'static, ()>> = Vec::new();
+
+    let metrics = Arc::new(CleanupMetrics::default());
+    if let Some(addr) = &config.metrics_addr {
+        let (addr, metrics) = (addr.clone(), metrics.clone());
+        tasks.push(Box::pin(supervise(
+            "metrics".to_string(),
+            config.restart.clone(),
+            notifier.clone(),
+            move || {
+                let (addr, metrics) = (addr.clone(), metrics.clone());
+                async move { Ok(serve_metrics(addr, metrics).await?) }
+            },
+        )));
+    }
+
+    if !config.watch.is_empty() {
+        let ws_url = match &config.ws_url {
+            Some(url) => url.clone(),
+            None => std::env::var("HELIUS_WS_RPC_URL").context("未配置 ws_url")?,
+        };
+
+        for watch in config.watch.clone() {
+            let (ws_url, notifier, restart, health) = (
+                ws_url.clone(),
+                notifier.clone(),
+                config.restart.clone(),
+                health.clone(),
+            );
+            tasks.push(Box::pin(supervise(
+                format!("watch:{}", watch.name),
+                restart,
+                notifier.clone(),
+                move || {
+                    tasks::run_watch(
+                        ws_url.clone(),
+                        watch.clone(),
+                        notifier.clone(),
+                        health.clone(),
+                    )
+                },
+            )));
+        }
+    }
+
+    if let (Some(cleanup), Some(wallet_path)) = (&config.cleanup, &config.wallet_path) {
+        let (cleanup, wallet_path, notifier, metrics) = (
+            cleanup.clone(),
+            wallet_path.clone(),
+            notifier.clone(),
+            metrics.clone(),
+        );
+        tasks.push(Box::pin(supervise(
+            "cleanup".to_string(),
+            config.restart.clone(),
+            notifier.clone(),
+            move || {
+                tasks::run_cleanup(
+                    wallet_path.clone(),
+                    cleanup.clone(),
+                    notifier.clone(),
+                    metrics.clone(),
+                )
+            },
+        )));
+    }
+
+    let context = JobContext {
+        wallet_path: config.wallet_path.clone(),
+        notifier: notifier.clone(),
+        health,
+        metrics,
+    };
+    for schedule in config.schedule.clone() {
+        let context = context.clone();
+        tasks.push(Box::pin(supervise(
+            format!("schedule:{}", schedule.name),
+            config.restart.clone(),
+            notifier.clone(),
+            move || scheduler::run_schedule(schedule.clone(), context.clone()),
+        )));
+    }
+
+    if tasks.is_empty() {
+        warn!("配置中没有任何任务");
+        return Ok(());
+    }
+
+    info!("守护进程已启动，共 {} 个任务", tasks.len());
+    join_all(tasks).await;
+
+    Ok(())
+}
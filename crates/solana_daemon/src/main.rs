@@ -0,0 +1,18 @@
+use anyhow::Result;
+use solana_daemon::DaemonConfig;
+use utils::{init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    // -- 加载配置并运行
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "daemon.toml".to_string());
+    let config = DaemonConfig::load(&path)?;
+
+    solana_daemon::run(config).await
+}
@@ -0,0 +1,39 @@
+use crate::config::NotifyConfig;
+use serde_json::json;
+use tracing::warn;
+
+/// -- Webhook 通知器
+///
+/// 向所有配置的地址发送 `{"text": "..."}`，发送失败只记录日志不影响任务
+#[derive(Clone)]
+pub struct Notifier {
+    http: reqwest::Client,
+    targets: Vec<NotifyConfig>,
+}
+
+impl Notifier {
+    /// -- 创建通知器
+    pub fn new(targets: Vec<NotifyConfig>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            targets,
+        }
+    }
+
+    /// -- 发送通知
+    pub async fn send(&self, text: &str) {
+        for target in &self.targets {
+            let result = self
+                .http
+                .post(&target.webhook_url)
+                .json(&json!({ "text": text }))
+                .send()
+                .await
+                .and_then(|r| r.error_for_status());
+
+            if let Err(e) = result {
+                warn!("发送通知失败: {}, {}", target.webhook_url, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::config::{CleanupConfig, JobConfig, ScheduleConfig};
+use crate::health::HealthRegistry;
+use crate::notify::Notifier;
+use crate::schedule::CronSchedule;
+use crate::tasks::{cleanup_once, local_offset};
+use anyhow::{anyhow, ensure, Context, Result};
+use jupiter_swap::price::JupiterPriceClient;
+use portfolio::PortfolioValuator;
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signer::Signer};
+use solana_toolkits::{metrics::CleanupMetrics, TokenAccountManager};
+use std::{
+    fs,
+    path::Path,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use time::OffsetDateTime;
+use tokio::runtime::Handle;
+use tracing::{error, info, warn};
+use utils::init_rpc_client;
+
+/// -- 定时任务共享的上下文
+#[derive(Clone)]
+pub struct JobContext {
+    pub wallet_path: Option<String>,  // -- 钱包密钥文件路径
+    pub notifier: Notifier,           // -- 通知器
+    pub health: HealthRegistry,       // -- 监控任务健康状态
+    pub metrics: Arc<CleanupMetrics>, // -- 清理操作指标
+}
+
+/// -- 按 cron 表达式循环执行定时任务
+///
+/// 每次到点后在独立任务中执行；上一次执行尚未结束时跳过本次并发送通知，避免重叠执行。
+///
+/// # 参数
+/// * `schedule` - 定时任务配置
+/// * `context` - 共享上下文
+pub async fn run_schedule(schedule: ScheduleConfig, context: JobContext) -> Result<()> {
+    let cron: CronSchedule = schedule.cron.parse()?;
+    let offset = local_offset();
+    let running = Arc::new(AtomicBool::new(false));
+
+    loop {
+        let now = OffsetDateTime::now_utc().to_offset(offset);
+        let next = cron
+            .next_after(now)
+            .ok_or_else(|| anyhow!("cron 表达式无法满足: {}", cron))?;
+        info!("[{}] 下一次执行时间: {}", schedule.name, next);
+        tokio::time::sleep((next - now).unsigned_abs()).await;
+
+        if running.swap(true, Ordering::SeqCst) {
+            warn!("[{}] 上一次执行尚未结束，跳过本次", schedule.name);
+            context
+                .notifier
+                .send(&format!(
+                    "[schedule:{}] 上一次执行尚未结束，跳过本次",
+                    schedule.name
+                ))
+                .await;
+            continue;
+        }
+
+        let (schedule, context, running) = (schedule.clone(), context.clone(), running.clone());
+        tokio::spawn(async move {
+            info!("[{}] 开始执行", schedule.name);
+            let result = run_job(&schedule.job, &context).await;
+            running.store(false, Ordering::SeqCst);
+
+            match result {
+                Ok(summary) => {
+                    info!("[{}] {}", schedule.name, summary);
+                    if schedule.notify {
+                        context
+                            .notifier
+                            .send(&format!("[schedule:{}] {}", schedule.name, summary))
+                            .await;
+                    }
+                }
+                Err(e) => {
+                    error!("[{}] 执行失败: {}", schedule.name, e);
+                    context
+                        .notifier
+                        .send(&format!("[schedule:{}] 执行失败: {}", schedule.name, e))
+                        .await;
+                }
+            }
+        });
+    }
+}
+
+/// -- 执行一次任务，返回结果摘要
+async fn run_job(job: &JobConfig, context: &JobContext) -> Result<String> {
+    match job {
+        JobConfig::Scan => scan(wallet_path(context)?, context.metrics.clone()).await,
+        JobConfig::Cleanup {
+            batch_size,
+            use_batch_tx,
+            burn_zero_value,
+        } => {
+            let cleanup = CleanupConfig {
+                weekday: None,
+                hour: 0,
+                minute: 0,
+                batch_size: *batch_size,
+                use_batch_tx: *use_batch_tx,
+                burn_zero_value: *burn_zero_value,
+            };
+            cleanup_once(wallet_path(context)?, cleanup, context.metrics.clone()).await
+        }
+        JobConfig::PortfolioSnapshot {
+            wallets,
+            output_dir,
+        } => portfolio_snapshot(wallets.clone(), output_dir.clone(), context).await,
+        JobConfig::MonitorHealth { max_silence_secs } => {
+            monitor_health(Duration::from_secs(*max_silence_secs), &context.health).await
+        }
+    }
+}
+
+/// -- 获取钱包路径
+fn wallet_path(context: &JobContext) -> Result<String> {
+    context
+        .wallet_path
+        .clone()
+        .ok_or_else(|| anyhow!("未配置 wallet_path"))
+}
+
+/// -- 扫描代币账户
+async fn scan(wallet_path: String, metrics: Arc<CleanupMetrics>) -> Result<String> {
+    let handle = Handle::current();
+
+    // -- 扫描过程使用阻塞 RPC，放到阻塞线程执行
+    tokio::task::spawn_blocking(move || {
+        handle.block_on(async {
+            let mut manager = TokenAccountManager::new(&wallet_path)?;
+            manager.set_observer(metrics);
+            let accounts = manager.get_closeable_accounts().await?;
+
+            Ok::<_, anyhow::Error>(format!(
+                "账户扫描完成: 共 {} 个账户, 零余额 {} 个, 零值代币 {} 个, 可回收 {:.6} SOL",
+                accounts.total_accounts,
+                accounts.closable_accounts,
+                accounts.zero_value_accounts,
+                accounts.total_rent_sol
+            ))
+        })
+    })
+    .await?
+}
+
+/// -- 生成资产快照，配置了输出目录时写入 JSON 文件
+async fn portfolio_snapshot(
+    wallets: Vec<String>,
+    output_dir: Option<String>,
+    context: &JobContext,
+) -> Result<String> {
+    let wallets = if wallets.is_empty() {
+        let manager = TokenAccountManager::new(&wallet_path(context)?)?;
+        vec![manager.wallet.pubkey()]
+    } else {
+        wallets
+            .iter()
+            .map(|wallet| {
+                Pubkey::from_str(wallet).with_context(|| format!("无效的钱包地址: {}", wallet))
+            })
+            .collect::<Result<Vec<_>>>()?
+    };
+    let handle = Handle::current();
+
+    tokio::task::spawn_blocking(move || {
+        handle.block_on(async {
+            let rpc_client = init_rpc_client(CommitmentConfig::confirmed())?;
+            let valuator = PortfolioValuator::new(&rpc_client, JupiterPriceClient::default());
+            let snapshot = valuator.snapshot(&wallets).await?;
+
+            let mut summary = format!(
+                "资产快照完成: {} 个钱包, 总价值 {:.2} USD",
+                snapshot.wallets.len(),
+                snapshot.total_value_usd
+            );
+            if let Some(dir) = output_dir {
+                fs::create_dir_all(&dir)?;
+                let path = Path::new(&dir).join(format!("portfolio-{}.json", snapshot.timestamp));
+                fs::write(&path, serde_json::to_string_pretty(&snapshot)?)?;
+                summary.push_str(&format!(", 已保存到 {}", path.display()));
+            }
+
+            Ok::<_, anyhow::Error>(summary)
+        })
+    })
+    .await?
+}
+
+/// -- 检查 RPC 是否可用以及监控任务是否长时间没有事件
+async fn monitor_health(max_silence: Duration, health: &HealthRegistry) -> Result<String> {
+    let slot = tokio::task::spawn_blocking(|| {
+        let rpc_client = init_rpc_client(CommitmentConfig::confirmed())?;
+        Ok::<_, anyhow::Error>(rpc_client.get_slot()?)
+    })
+    .await??;
+
+    let idle = health.idle_times();
+    let silent: Vec<String> = idle
+        .iter()
+        .filter(|(_, elapsed)| *elapsed > max_silence)
+        .map(|(name, elapsed)| format!("{} ({} 秒无事件)", name, elapsed.as_secs()))
+        .collect();
+    ensure!(silent.is_empty(), "监控任务异常: {}", silent.join(", "));
+
+    Ok(format!(
+        "健康检查通过: 当前 slot {}, {} 个监控任务正常",
+        slot,
+        idle.len()
+    ))
+}
@@ -0,0 +1,45 @@
+use crate::{config::RestartConfig, notify::Notifier};
+use anyhow::Result;
+use std::future::Future;
+use tracing::{error, info, warn};
+
+/// -- 监督任务运行，失败或意外退出时按指数退避重启
+///
+/// 任务正常返回 `Ok(())` 也视为意外退出，守护进程中的任务都应长期运行。
+///
+/// # 参数
+/// * `name` - 任务名称
+/// * `restart` - 重启策略
+/// * `notifier` - 通知器，任务失败时发送通知
+/// * `task` - 任务工厂，每次重启调用一次
+pub async fn supervise<F, Fut>(name: String, restart: RestartConfig, notifier: Notifier, task: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<()>>,
+{
+    let mut backoff = restart.initial_backoff();
+
+    loop {
+        info!("启动任务: {}", name);
+        let started = std::time::Instant::now();
+
+        match task().await {
+            Ok(()) => warn!("任务意外退出: {}", name),
+            Err(e) => {
+                error!("任务失败: {}, {}", name, e);
+                notifier
+                    .send(&format!("[solana-daemon] 任务 {} 失败: {}", name, e))
+                    .await;
+            }
+        }
+
+        // -- 运行时间超过最长退避时间则认为已恢复，重置退避
+        if started.elapsed() > restart.max_backoff() {
+            backoff = restart.initial_backoff();
+        }
+
+        warn!("{} 秒后重启任务: {}", backoff.as_secs(), name);
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(restart.max_backoff());
+    }
+}
@@ -0,0 +1,199 @@
+use crate::config::{CleanupConfig, WatchConfig};
+use crate::health::HealthRegistry;
+use crate::notify::Notifier;
+use anyhow::{anyhow, Result};
+use solana_client::{
+    pubsub_client::PubsubClient,
+    rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter},
+};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_toolkits::{
+    account_info::BatchCloseReport, metrics::CleanupMetrics, TokenAccountManager,
+};
+use std::{env, sync::Arc};
+use time::{Duration, OffsetDateTime, Time, UtcOffset};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+/// -- 监控事件通道容量
+const EVENT_CHANNEL_SIZE: usize = 128;
+
+/// -- 运行监控任务
+///
+/// 订阅配置的程序日志，匹配过滤条件的交易会发送通知。订阅中断时返回错误，由监督器重启。
+///
+/// # 参数
+/// * `ws_url` - WebSocket 地址
+/// * `watch` - 监控任务配置
+/// * `notifier` - 通知器
+/// * `health` - 健康状态，订阅建立和收到事件时更新
+pub async fn run_watch(
+    ws_url: String,
+    watch: WatchConfig,
+    notifier: Notifier,
+    health: HealthRegistry,
+) -> Result<()> {
+    let (sender, mut receiver) = mpsc::channel::<Result<String, String>>(EVENT_CHANNEL_SIZE);
+    let filter = watch.log_contains.clone();
+
+    // -- 日志订阅是阻塞接口，放到阻塞线程执行
+    tokio::task::spawn_blocking(move || {
+        let (_subscription, logs) = match PubsubClient::logs_subscribe(
+            &ws_url,
+            RpcTransactionLogsFilter::Mentions(watch.program_ids),
+            RpcTransactionLogsConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        ) {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                let _ = sender.blocking_send(Err(e.to_string()));
+                return;
+            }
+        };
+
+        while let Ok(response) = logs.recv() {
+            let matched = response.value.err.is_none()
+                && (filter.is_empty()
+                    || response
+                        .value
+                        .logs
+                        .iter()
+                        .any(|log| filter.iter().any(|f| log.contains(f))));
+
+            if matched && sender.blocking_send(Ok(response.value.signature)).is_err() {
+                return;
+            }
+        }
+        let _ = sender.blocking_send(Err("日志订阅已中断".to_string()));
+    });
+
+    info!("监控任务已启动: {}", watch.name);
+    health.touch(&watch.name);
+    while let Some(event) = receiver.recv().await {
+        let signature = event.map_err(|e| anyhow!(e))?;
+        health.touch(&watch.name);
+        info!("[{}] 匹配交易: {}", watch.name, signature);
+        notifier
+            .send(&format!(
+                "[{}] https://solscan.io/tx/{}",
+                watch.name, signature
+            ))
+            .await;
+    }
+
+    Err(anyhow!("监控事件通道已关闭"))
+}
+
+/// -- 运行自动清理任务
+///
+/// 按配置的时间循环执行：等待到下一次执行时间，扫描并关闭零余额账户，发送结果通知。
+///
+/// # 参数
+/// * `wallet_path` - 钱包密钥文件路径
+/// * `cleanup` - 自动清理配置
+/// * `notifier` - 通知器
+/// * `metrics` - 清理操作指标
+pub async fn run_cleanup(
+    wallet_path: String,
+    cleanup: CleanupConfig,
+    notifier: Notifier,
+    metrics: Arc<CleanupMetrics>,
+) -> Result<()> {
+    let weekday = cleanup.weekday()?;
+    let offset = local_offset();
+
+    loop {
+        let now = OffsetDateTime::now_utc().to_offset(offset);
+        let next = next_run(now, weekday, cleanup.hour, cleanup.minute)?;
+        info!("下一次自动清理时间: {}", next);
+        tokio::time::sleep((next - now).unsigned_abs()).await;
+
+        let summary = cleanup_once(wallet_path.clone(), cleanup.clone(), metrics.clone()).await?;
+        info!("{}", summary);
+        notifier.send(&format!("[solana-daemon] {}", summary)).await;
+    }
+}
+
+/// -- 执行一次清理，返回结果摘要
+pub(crate) async fn cleanup_once(
+    wallet_path: String,
+    cleanup: CleanupConfig,
+    metrics: Arc<CleanupMetrics>,
+) -> Result<String> {
+    let handle = tokio::runtime::Handle::current();
+
+    // -- 清理过程使用阻塞 RPC，放到阻塞线程执行
+    tokio::task::spawn_blocking(move || {
+        handle.block_on(async {
+            let mut manager = TokenAccountManager::new(&wallet_path)?;
+            manager.set_observer(metrics);
+            let accounts = manager.get_closeable_accounts().await?;
+
+            let closed = manager
+                .batch_close_accounts(
+                    &accounts.accounts,
+                    cleanup.batch_size,
+                    cleanup.use_batch_tx,
+                )
+                .await?;
+
+            let burned = if cleanup.burn_zero_value {
+                manager
+                    .batch_burn_and_close_zero_value_accounts(
+                        &accounts.zero_value_accounts_list,
+                        cleanup.batch_size,
+                    )
+                    .await?
+            } else {
+                BatchCloseReport::default()
+            };
+
+            Ok::<_, anyhow::Error>(format!(
+                "自动清理完成: 关闭 {} 个零余额账户, 销毁并关闭 {} 个零值代币账户, 失败 {} 个, 回收 {:.6} SOL",
+                closed.succeeded,
+                burned.succeeded,
+                closed.failed + burned.failed,
+                closed.rent_recovered_sol + burned.rent_recovered_sol
+            ))
+        })
+    })
+    .await?
+}
+
+/// -- 计算下一次执行时间
+///
+/// # 参数
+/// * `now` - 当前时间
+/// * `weekday` - 每周执行日期，None 表示每天执行
+/// * `hour` - 小时
+/// * `minute` - 分钟
+pub fn next_run(
+    now: OffsetDateTime,
+    weekday: Option<time::Weekday>,
+    hour: u8,
+    minute: u8,
+) -> Result<OffsetDateTime> {
+    let mut next = now.replace_time(Time::from_hms(hour, minute, 0)?);
+    if next <= now {
+        next += Duration::days(1);
+    }
+    if let Some(weekday) = weekday {
+        while next.weekday() != weekday {
+            next += Duration::days(1);
+        }
+    }
+    Ok(next)
+}
+
+/// -- 从 `TZ_OFFSET` 读取时区，与日志时区保持一致，默认东八区
+pub(crate) fn local_offset() -> UtcOffset {
+    let hours = env::var("TZ_OFFSET")
+        .ok()
+        .and_then(|x| x.parse::<i8>().ok())
+        .unwrap_or(8);
+    UtcOffset::from_hms(hours, 0, 0).unwrap_or_else(|e| {
+        warn!("无效的 TZ_OFFSET: {}, 使用 UTC", e);
+        UtcOffset::UTC
+    })
+}
@@ -17,9 +17,19 @@ async fn main() -> Result<(), Box<dyn Error>> {
     let accounts = manager.get_closeable_accounts().await?;
 
     // -- 批量关闭账户，每批处理 5 个
-    manager
+    let report = manager
         .batch_close_accounts(&accounts.accounts, 5, false)
         .await?;
 
+    // -- 输出失败的账户
+    for failure in report.failures() {
+        println!(
+            "{} 关闭失败 ({:?}): {}",
+            failure.account_address,
+            failure.failure_kind,
+            failure.error.as_deref().unwrap_or("-")
+        );
+    }
+
     Ok(())
 }
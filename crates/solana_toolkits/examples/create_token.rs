@@ -0,0 +1,32 @@
+use anyhow::Result;
+use solana_toolkits::token_creation::{CreateTokenParams, TokenProgram};
+use solana_toolkits::TokenAccountManager;
+use std::error::Error;
+use utils::{init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    // -- 初始化账户管理器（建议将 RPC_URL 指向 devnet）
+    let wallet_path = std::env::var("WALLET_PATH")?;
+    let manager = TokenAccountManager::new(&wallet_path)?;
+
+    // -- 创建测试代币并铸造 1,000,000 枚
+    let params = CreateTokenParams {
+        name: "Test Token".to_string(),
+        symbol: "TEST".to_string(),
+        uri: "https://example.com/test-token.json".to_string(),
+        decimals: 6,
+        initial_supply: 1_000_000 * 10u64.pow(6),
+        token_program: TokenProgram::Spl,
+        revoke_mint_authority: false,
+        revoke_freeze_authority: true,
+    };
+    let token = manager.create_token(&params).await?;
+    println!("{:#?}", token);
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anyhow::Result;
+use solana_toolkits::multisend::{read_multisend_csv, MultisendConfig};
+use solana_toolkits::TokenAccountManager;
+use std::error::Error;
+use utils::{init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    // -- 初始化账户管理器
+    let wallet_path = std::env::var("WALLET_PATH")?;
+    let manager = TokenAccountManager::new(&wallet_path)?;
+
+    // -- 读取转账列表: recipient,amount[,mint]
+    let csv_path = std::env::var("MULTISEND_CSV").unwrap_or_else(|_| "multisend.csv".to_string());
+    let rows = read_multisend_csv(&csv_path)?;
+
+    // -- 执行批量转账并输出报告
+    let report = manager
+        .multisend(&rows, &MultisendConfig::default())
+        .await?;
+    report.write_csv("multisend_report.csv")?;
+
+    Ok(())
+}
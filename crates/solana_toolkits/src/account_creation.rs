@@ -0,0 +1,151 @@
+use crate::operations::{
+    is_token_program, payer_and_signers, submit_with_fresh_blockhash, with_compute_budget,
+};
+use crate::TokenAccountManager;
+use serde::Serialize;
+use solana_sdk::{
+    instruction::Instruction, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signer::Signer,
+    system_instruction, transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::{create_associated_token_account, create_associated_token_account_idempotent},
+};
+use spl_token_2022::instruction::sync_native;
+use tracing::info;
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 账户创建结果
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedAccount {
+    pub address: String,           // -- 代币账户（ATA）地址
+    pub mint: String,              // -- 代币的 Mint 地址
+    pub program_id: String,        // -- 账户所属的代币程序 ID
+    pub wrapped_lamports: u64,     // -- 包装为 wSOL 的数量（lamports），创建 ATA 时为 0
+    pub signature: Option<String>, // -- 交易签名，模拟模式下为 None
+    pub simulated: bool,           // -- 是否为模拟执行（未发送交易）
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 为钱包创建指定代币的关联代币账户（ATA）
+    ///
+    /// 账户已存在时交易会执行失败，不确定账户是否存在时使用 [`create_ata_idempotent`](Self::create_ata_idempotent)。
+    /// 代币程序（SPL Token 或 Token-2022）按 Mint 的所有者自动选择，
+    /// 账户租金由手续费支付者（未设置时为钱包）支付。
+    ///
+    /// # 参数
+    /// * `mint` - 代币的 Mint 地址
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<CreatedAccount>` - 成功返回 ATA 地址和交易签名
+    pub async fn create_ata(&self, mint: &Pubkey) -> TokenAccountResult<CreatedAccount> {
+        self.create_ata_with(mint, false)
+    }
+
+    /// -- 为钱包创建指定代币的关联代币账户（ATA），账户已存在时不做任何修改
+    ///
+    /// # 参数
+    /// * `mint` - 代币的 Mint 地址
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<CreatedAccount>` - 成功返回 ATA 地址和交易签名
+    pub async fn create_ata_idempotent(&self, mint: &Pubkey) -> TokenAccountResult<CreatedAccount> {
+        self.create_ata_with(mint, true)
+    }
+
+    /// -- 将钱包中的 SOL 包装为 wSOL
+    ///
+    /// 在一笔交易中创建 wSOL 的 ATA（已存在时跳过）、转入 SOL 并同步余额。
+    /// 包装的 SOL 在关闭账户时随租金一起返还钱包。
+    ///
+    /// # 参数
+    /// * `lamports` - 要包装的数量（lamports）
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<CreatedAccount>` - 成功返回 wSOL 账户地址和交易签名
+    pub async fn wrap_sol(&self, lamports: u64) -> TokenAccountResult<CreatedAccount> {
+        if lamports == 0 {
+            return Err(TokenAccountError::Other(String::from(
+                "包装的 SOL 数量必须大于 0",
+            )));
+        }
+
+        let owner = self.wallet.pubkey();
+        let mint = spl_token::native_mint::ID;
+        let program_id = spl_token::ID;
+        let payer = self.fee_payer_pubkey();
+        let address = get_associated_token_address_with_program_id(&owner, &mint, &program_id);
+        let instructions = vec![
+            create_associated_token_account_idempotent(&payer, &owner, &mint, &program_id),
+            system_instruction::transfer(&owner, &address, lamports),
+            sync_native(&program_id, &address)?,
+        ];
+
+        let mut created = self.submit_creation(instructions, address, mint, program_id)?;
+        created.wrapped_lamports = lamports;
+        info!(
+            "已包装 {} SOL 到 wSOL 账户: {}",
+            lamports as f64 / LAMPORTS_PER_SOL as f64,
+            address
+        );
+        Ok(created)
+    }
+
+    /// -- 构建并提交创建 ATA 的交易
+    fn create_ata_with(
+        &self,
+        mint: &Pubkey,
+        idempotent: bool,
+    ) -> TokenAccountResult<CreatedAccount> {
+        let program_id = self.connection.get_account(mint)?.owner;
+        if !is_token_program(&program_id) {
+            return Err(TokenAccountError::AccountParseError(format!(
+                "{} 不是代币 Mint，所有者为 {}",
+                mint, program_id
+            )));
+        }
+
+        let owner = self.wallet.pubkey();
+        let payer = self.fee_payer_pubkey();
+        let address = get_associated_token_address_with_program_id(&owner, mint, &program_id);
+        let instruction = if idempotent {
+            create_associated_token_account_idempotent(&payer, &owner, mint, &program_id)
+        } else {
+            create_associated_token_account(&payer, &owner, mint, &program_id)
+        };
+
+        let created = self.submit_creation(vec![instruction], address, *mint, program_id)?;
+        info!("已创建代币账户: {} (Mint: {})", address, mint);
+        Ok(created)
+    }
+
+    /// -- 签名并提交账户创建交易，模拟模式下只模拟执行
+    fn submit_creation(
+        &self,
+        instructions: Vec<Instruction>,
+        address: Pubkey,
+        mint: Pubkey,
+        program_id: Pubkey,
+    ) -> TokenAccountResult<CreatedAccount> {
+        let instructions = with_compute_budget(&self.connection, &self.config, instructions);
+        let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+        let submission =
+            submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+                Ok(Transaction::new_signed_with_payer(
+                    &instructions,
+                    Some(&payer),
+                    &signers,
+                    blockhash,
+                ))
+            })?;
+
+        Ok(CreatedAccount {
+            address: address.to_string(),
+            mint: mint.to_string(),
+            program_id: program_id.to_string(),
+            wrapped_lamports: 0,
+            signature: submission.signature,
+            simulated: self.config.dry_run,
+        })
+    }
+}
@@ -1,69 +1,579 @@
+use crate::spam::SuspectedSpamInfo;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey};
+use utils::{
+    locale::{current_locale, Locale},
+    TokenAccountError, TokenAccountResult,
+};
+
 /// -- 代币账户信息结构体
 /// 存储单个代币账户的基本信息，包括地址、Mint、租金等
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenAccountInfo {
-    pub address: String,    // -- 账户地址
-    pub mint: String,       // -- 代币的 Mint 地址
-    pub rent_lamports: u64, // -- 租金（以 lamports 为单位）
-    pub rent_sol: f64,      // -- 租金（以 SOL 为单位）
-    pub symbol: String,     // -- 代币符号
+    pub address: String,     // -- 账户地址
+    pub mint: String,        // -- 代币的 Mint 地址
+    pub rent_lamports: u64,  // -- 租金（以 lamports 为单位）
+    pub rent_sol: f64,       // -- 租金（以 SOL 为单位）
+    pub symbol: String,      // -- 代币符号
+    pub program_id: String,  // -- 所属代币程序 ID（SPL Token 或 Token-2022）
+    pub is_associated: bool, // -- 是否为钱包的关联代币账户（ATA），否则为辅助账户
+    #[serde(default)]
+    pub withheld_amount: u64, // -- Token-2022 暂扣的转账手续费，关闭前需提取到 Mint
 }
 
 /// -- 零值代币账户信息结构体
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZeroValueTokenInfo {
-    pub address: String,    // -- 账户地址
-    pub mint: String,       // -- 代币的 Mint 地址
-    pub balance: u64,       // -- 代币余额
-    pub rent_lamports: u64, // -- 租金（以 lamports 为单位）
-    pub rent_sol: f64,      // -- 租金（以 SOL 为单位）
-    pub symbol: String,     // -- 代币符号
+    pub address: String,     // -- 账户地址
+    pub mint: String,        // -- 代币的 Mint 地址
+    pub balance: u64,        // -- 代币余额
+    pub decimals: u8,        // -- 代币小数位数
+    pub is_nft: bool,        // -- 是否为 NFT
+    pub rent_lamports: u64,  // -- 租金（以 lamports 为单位）
+    pub rent_sol: f64,       // -- 租金（以 SOL 为单位）
+    pub symbol: String,      // -- 代币符号
+    pub program_id: String,  // -- 所属代币程序 ID（SPL Token 或 Token-2022）
+    pub is_associated: bool, // -- 是否为钱包的关联代币账户（ATA），否则为辅助账户
+    #[serde(default)]
+    pub decimals_verified: bool, // -- 小数位数是否取自代币账户或 Mint，否则为占位值
+}
+
+impl ZeroValueTokenInfo {
+    /// -- 销毁时用于校验的小数位数，扫描时未能确认则返回 None，直接使用链上精度
+    pub fn expected_decimals(&self) -> Option<u8> {
+        self.decimals_verified.then_some(self.decimals)
+    }
+}
+
+/// -- 扫描时单个代币账户的分类结果
+#[derive(Debug, Clone, Serialize)]
+pub enum ScanItem {
+    /// 余额为 0，可以直接关闭
+    Closeable(TokenAccountInfo),
+    /// 零值代币，可以销毁并关闭（包括黑名单代币）
+    ZeroValue(ZeroValueTokenInfo),
+    /// 白名单代币，不处理
+    Whitelisted(ZeroValueTokenInfo),
+    /// NFT，默认不销毁
+    Nft(ZeroValueTokenInfo),
+    /// 已冻结，无法关闭或销毁
+    Frozen(TokenAccountInfo),
+    /// 关闭权限属于其他地址，钱包无法关闭
+    NoAuthority(TokenAccountInfo),
+    /// 有余额的 wSOL 账户，关闭即可解包
+    WrappedSol(ZeroValueTokenInfo),
+    /// 有余额但查询不到元数据，不处理
+    Unknown(TokenAccountInfo),
+    /// 疑似垃圾或蜜罐代币（配置了 `spam_heuristics` 时识别）
+    SuspectedSpam(SuspectedSpamInfo),
+}
+
+impl ScanItem {
+    /// -- 账户地址
+    pub fn address(&self) -> &str {
+        match self {
+            ScanItem::Closeable(account)
+            | ScanItem::Frozen(account)
+            | ScanItem::NoAuthority(account)
+            | ScanItem::Unknown(account) => &account.address,
+            ScanItem::ZeroValue(account)
+            | ScanItem::Whitelisted(account)
+            | ScanItem::Nft(account)
+            | ScanItem::WrappedSol(account) => &account.address,
+            ScanItem::SuspectedSpam(spam) => &spam.account.address,
+        }
+    }
+
+    /// -- 账户租金（lamports）
+    pub fn rent_lamports(&self) -> u64 {
+        match self {
+            ScanItem::Closeable(account)
+            | ScanItem::Frozen(account)
+            | ScanItem::NoAuthority(account)
+            | ScanItem::Unknown(account) => account.rent_lamports,
+            ScanItem::ZeroValue(account)
+            | ScanItem::Whitelisted(account)
+            | ScanItem::Nft(account)
+            | ScanItem::WrappedSol(account) => account.rent_lamports,
+            ScanItem::SuspectedSpam(spam) => spam.account.rent_lamports,
+        }
+    }
+
+    /// -- 代币 Mint 地址
+    pub fn mint(&self) -> &str {
+        match self {
+            ScanItem::Closeable(account)
+            | ScanItem::Frozen(account)
+            | ScanItem::NoAuthority(account)
+            | ScanItem::Unknown(account) => &account.mint,
+            ScanItem::ZeroValue(account)
+            | ScanItem::Whitelisted(account)
+            | ScanItem::Nft(account)
+            | ScanItem::WrappedSol(account) => &account.mint,
+            ScanItem::SuspectedSpam(spam) => &spam.account.mint,
+        }
+    }
+
+    /// -- 代币符号，没有元数据时为 `unknown`
+    pub fn symbol(&self) -> &str {
+        match self {
+            ScanItem::Closeable(account)
+            | ScanItem::Frozen(account)
+            | ScanItem::NoAuthority(account)
+            | ScanItem::Unknown(account) => &account.symbol,
+            ScanItem::ZeroValue(account)
+            | ScanItem::Whitelisted(account)
+            | ScanItem::Nft(account)
+            | ScanItem::WrappedSol(account) => &account.symbol,
+            ScanItem::SuspectedSpam(spam) => &spam.account.symbol,
+        }
+    }
+
+    /// -- 是否为钱包的关联代币账户（ATA）
+    pub fn is_associated(&self) -> bool {
+        match self {
+            ScanItem::Closeable(account)
+            | ScanItem::Frozen(account)
+            | ScanItem::NoAuthority(account)
+            | ScanItem::Unknown(account) => account.is_associated,
+            ScanItem::ZeroValue(account)
+            | ScanItem::Whitelisted(account)
+            | ScanItem::Nft(account)
+            | ScanItem::WrappedSol(account) => account.is_associated,
+            ScanItem::SuspectedSpam(spam) => spam.account.is_associated,
+        }
+    }
+
+    /// -- 账户所属的代币程序
+    pub fn program_id(&self) -> &str {
+        match self {
+            ScanItem::Closeable(account)
+            | ScanItem::Frozen(account)
+            | ScanItem::NoAuthority(account)
+            | ScanItem::Unknown(account) => &account.program_id,
+            ScanItem::ZeroValue(account)
+            | ScanItem::Whitelisted(account)
+            | ScanItem::Nft(account)
+            | ScanItem::WrappedSol(account) => &account.program_id,
+            ScanItem::SuspectedSpam(spam) => &spam.account.program_id,
+        }
+    }
 }
 
 /// -- 代币账户查询结果结构体
-/// 包含查询到的所有代币账户统计信息
-#[derive(Debug)]
+/// 包含查询到的所有代币账户统计信息，可以通过 `export_csv`、`export_json` 导出
+#[derive(Debug, Serialize, Deserialize)]
 pub struct TokenAccountsResult {
-    pub total_accounts: usize,                             // -- 总账户数量
-    pub closable_accounts: usize,                          // -- 可关闭的账户数量（余额为 0）
-    pub zero_value_accounts: usize,                        // -- 零值代币账户数量
-    pub accounts: Vec<TokenAccountInfo>,                   // -- 可关闭账户列表（余额为 0）
-    pub zero_value_accounts_list: Vec<ZeroValueTokenInfo>, // -- 零值代币账户列表
-    pub total_rent_lamports: u64,                          // -- 总租金（以 lamports 为单位）
-    pub total_rent_sol: f64,                               // -- 总租金（以 SOL 为单位）
+    pub total_accounts: usize,                              // -- 总账户数量
+    pub closable_accounts: usize,                           // -- 可关闭的账户数量（余额为 0）
+    pub zero_value_accounts: usize,                         // -- 零值代币账户数量
+    pub accounts: Vec<TokenAccountInfo>,                    // -- 可关闭账户列表（余额为 0）
+    pub zero_value_accounts_list: Vec<ZeroValueTokenInfo>,  // -- 零值代币账户列表
+    pub whitelisted_accounts_list: Vec<ZeroValueTokenInfo>, // -- 被白名单保护的有余额账户列表
+    pub nft_accounts_list: Vec<ZeroValueTokenInfo>,         // -- 持有 NFT 的账户列表（默认不销毁）
+    pub frozen_accounts_list: Vec<TokenAccountInfo>,        // -- 被冻结的账户列表（无法关闭或销毁）
+    pub no_authority_accounts_list: Vec<TokenAccountInfo>,  // -- 关闭权限属于其他地址的账户列表
+    pub wrapped_sol_accounts_list: Vec<ZeroValueTokenInfo>, // -- 有余额的 wSOL 账户列表（关闭即解包）
+    #[serde(default)]
+    pub suspected_spam_accounts_list: Vec<SuspectedSpamInfo>, // -- 疑似垃圾或蜜罐代币账户列表
+    pub total_rent_lamports: u64,                           // -- 总租金（以 lamports 为单位）
+    pub total_rent_sol: f64,                                // -- 总租金（以 SOL 为单位）
+}
+
+impl TokenAccountsResult {
+    /// -- 各分类中非关联代币账户（辅助账户）的地址
+    ///
+    /// 钱包在每个 Mint 下的关联代币账户（ATA）之外的账户，通常由程序或旧版钱包创建，
+    /// 可以配合 `ScanFilter::only_auxiliary` 只清理这些账户而保留 ATA。
+    pub fn auxiliary_accounts(&self) -> Vec<&str> {
+        let accounts = self
+            .accounts
+            .iter()
+            .chain(&self.frozen_accounts_list)
+            .chain(&self.no_authority_accounts_list)
+            .filter(|account| !account.is_associated)
+            .map(|account| account.address.as_str());
+        let token_accounts = self
+            .zero_value_accounts_list
+            .iter()
+            .chain(&self.whitelisted_accounts_list)
+            .chain(&self.nft_accounts_list)
+            .chain(&self.wrapped_sol_accounts_list)
+            .chain(
+                self.suspected_spam_accounts_list
+                    .iter()
+                    .map(|spam| &spam.account),
+            )
+            .filter(|account| !account.is_associated)
+            .map(|account| account.address.as_str());
+        accounts.chain(token_accounts).collect()
+    }
+
+    /// -- 疑似垃圾代币的账户信息，可以直接传给 `batch_burn_and_close_zero_value_accounts`
+    pub fn suspected_spam_tokens(&self) -> Vec<ZeroValueTokenInfo> {
+        self.suspected_spam_accounts_list
+            .iter()
+            .map(|spam| spam.account.clone())
+            .collect()
+    }
+}
+
+/// -- 存在授权代理的代币账户信息结构体
+#[derive(Debug, Clone, Serialize)]
+pub struct DelegatedAccountInfo {
+    pub address: String,       // -- 账户地址
+    pub mint: String,          // -- 代币的 Mint 地址
+    pub symbol: String,        // -- 代币符号
+    pub balance: u64,          // -- 代币余额
+    pub delegate: String,      // -- 授权代理地址
+    pub delegated_amount: u64, // -- 代理可转出的剩余数量
+    pub program_id: String,    // -- 所属代币程序 ID（SPL Token 或 Token-2022）
+}
+
+/// -- 撤销授权结果结构体
+#[derive(Debug, Clone, Serialize)]
+pub struct RevokeOutcome {
+    pub account_address: String,   // -- 账户地址
+    pub delegate: String,          // -- 被撤销的代理地址
+    pub success: bool,             // -- 操作是否成功
+    pub signature: Option<String>, // -- 撤销交易签名（模拟模式下为 None）
+    pub error: Option<String>,     // -- 失败时的错误信息
 }
 
 /// -- 账户关闭结果结构体
 /// 记录单个账户关闭操作的结果
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ClosureResult {
-    pub success: bool,             // -- 操作是否成功
-    pub signature: Option<String>, // -- 成功时的交易签名
-    pub error: Option<String>,     // -- 失败时的错误信息
-    pub account_address: String,   // -- 被关闭的账户地址
-    pub rent_recovered: f64,       // -- 回收的租金数量（以 SOL 为单位）
+    pub success: bool,                        // -- 操作是否成功
+    pub signature: Option<String>,            // -- 成功时的交易签名
+    pub error: Option<String>,                // -- 失败时的错误信息
+    pub account_address: String,              // -- 被关闭的账户地址
+    pub rent_recovered: f64,                  // -- 回收的租金数量（以 SOL 为单位）
+    pub simulated: bool,                      // -- 是否为模拟执行（未发送交易）
+    pub simulation: Option<SimulationReport>, // -- 发送前模拟的日志和计算单元消耗
 }
 
 /// -- 代币账户详细信息结构体
 /// 存储代币账户的完整信息
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct TokenAccountDetails {
-    pub pubkey: String,     // -- 账户公钥
-    pub balance: u64,       // -- 账户余额
-    pub rent_lamports: u64, // -- 租金（以 lamports 为单位）
-    pub rent_sol: f64,      // -- 租金（以 SOL 为单位）
-    pub mint: String,       // -- 代币的 Mint 地址
-    pub owner: String,      // -- 账户所有者地址
+    pub pubkey: String,                  // -- 账户公钥
+    pub balance: u64,                    // -- 账户余额
+    pub rent_lamports: u64,              // -- 租金（以 lamports 为单位）
+    pub rent_sol: f64,                   // -- 租金（以 SOL 为单位）
+    pub mint: String,                    // -- 代币的 Mint 地址
+    pub owner: String,                   // -- 账户所有者地址
+    pub program_id: String,              // -- 所属代币程序 ID（SPL Token 或 Token-2022）
+    pub is_frozen: bool,                 // -- 账户是否被冻结
+    pub is_native: bool,                 // -- 是否为 wSOL 账户（关闭时余额随租金一起返还）
+    pub delegate: Option<String>,        // -- 授权代理地址（未授权时为 None）
+    pub withheld_amount: u64,            // -- Token-2022 暂扣的转账手续费（关闭前需提取到 Mint）
+    pub close_authority: Option<String>, // -- 关闭权限地址（未设置时由所有者关闭）
+}
+
+impl TokenAccountDetails {
+    /// -- 账户被冻结时返回错误，避免发送必然失败的交易
+    pub fn ensure_not_frozen(&self) -> TokenAccountResult<()> {
+        if self.is_frozen {
+            return Err(TokenAccountError::AccountFrozen(self.pubkey.clone()));
+        }
+        Ok(())
+    }
+
+    /// -- 钱包不是账户的实际关闭权限时返回错误
+    ///
+    /// 设置了 `close_authority` 的账户只能由该地址关闭，否则由所有者关闭。
+    ///
+    /// # 参数
+    /// * `wallet` - 签名关闭交易的钱包地址
+    pub fn ensure_closable_by(&self, wallet: &Pubkey) -> TokenAccountResult<()> {
+        let authority = self.close_authority.as_deref().unwrap_or(&self.owner);
+        if authority != wallet.to_string() {
+            return Err(TokenAccountError::NotCloseAuthority {
+                account: self.pubkey.clone(),
+                authority: authority.to_string(),
+            });
+        }
+        Ok(())
+    }
 }
 
 /// -- 销毁代币并回收账户结果结构体
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct BurnAndCloseResult {
-    pub success: bool,                   // -- 操作是否成功
-    pub burn_signature: Option<String>,  // -- 销毁代币的交易签名
-    pub close_signature: Option<String>, // -- 关闭账户的交易签名
-    pub error: Option<String>,           // -- 失败时的错误信息
-    pub account_address: String,         // -- 被操作的账户地址
-    pub burned_amount: u64,              // -- 销毁的代币数量
-    pub rent_recovered: f64,             // -- 回收的租金数量（以 SOL 为单位）
+    pub success: bool,                        // -- 操作是否成功
+    pub burn_signature: Option<String>,       // -- 销毁代币的交易签名
+    pub close_signature: Option<String>,      // -- 关闭账户的交易签名
+    pub error: Option<String>,                // -- 失败时的错误信息
+    pub account_address: String,              // -- 被操作的账户地址
+    pub burned_amount: u64,                   // -- 销毁的代币数量
+    pub rent_recovered: f64,                  // -- 回收的租金数量（以 SOL 为单位）
+    pub simulated: bool,                      // -- 是否为模拟执行（未发送交易）
+    pub failure_kind: Option<FailureKind>,    // -- 失败原因分类
+    pub simulation: Option<SimulationReport>, // -- 发送前模拟的日志和计算单元消耗（销毁与关闭合并）
+    pub swap: Option<SwapSummary>,            // -- 卖出剩余代币的结果（卖出时不再销毁）
+    pub unwrapped_lamports: u64,              // -- 解包 wSOL 返还的 SOL（lamports，不含租金）
+}
+
+/// -- 剩余代币卖出结果结构体
+#[derive(Debug, Clone, Serialize)]
+pub struct SwapSummary {
+    pub signature: Option<String>, // -- 交换交易签名（模拟模式下为 None）
+    pub input_amount: u64,         // -- 卖出的代币数量
+    pub output_mint: String,       // -- 换得代币的 Mint 地址
+    pub output_amount: u64,        // -- 预计换得的数量（最小单位）
+    pub route: String,             // -- 路由描述
+}
+
+/// -- 转出剩余代币并回收账户结果结构体
+#[derive(Debug, Serialize)]
+pub struct SweepAndCloseResult {
+    pub success: bool,                        // -- 操作是否成功
+    pub signature: Option<String>,            // -- 转出并关闭的交易签名（模拟模式下为 None）
+    pub error: Option<String>,                // -- 失败时的错误信息
+    pub account_address: String,              // -- 被操作的账户地址
+    pub destination_account: Option<String>,  // -- 接收代币的目标 ATA 地址
+    pub swept_amount: u64,                    // -- 转出的代币数量
+    pub rent_recovered: f64,                  // -- 回收的租金数量（以 SOL 为单位）
+    pub simulated: bool,                      // -- 是否为模拟执行（未发送交易）
+    pub failure_kind: Option<FailureKind>,    // -- 失败原因分类
+    pub simulation: Option<SimulationReport>, // -- 发送前模拟的日志和计算单元消耗
+}
+
+/// -- 交易模拟结果
+///
+/// 每笔交易发送前都会先模拟执行，记录程序日志和消耗的计算单元。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SimulationReport {
+    pub units_consumed: Option<u64>, // -- 消耗的计算单元
+    pub logs: Vec<String>,           // -- 程序日志
+}
+
+impl SimulationReport {
+    /// -- 合并另一笔交易的模拟结果（计算单元累加，日志追加）
+    pub fn merge(&mut self, other: SimulationReport) {
+        self.units_consumed = match (self.units_consumed, other.units_consumed) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        self.logs.extend(other.logs);
+    }
+}
+
+/// -- 批量操作失败原因分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum FailureKind {
+    NonZeroBalance, // -- 账户余额不为 0
+    Frozen,         // -- 账户已被冻结
+    CloseAuthority, // -- 关闭权限属于其他地址
+    InvalidAccount, // -- 账户地址或数据无效
+    Rpc,            // -- RPC 请求失败
+    Transaction,    // -- 交易发送或模拟失败
+    LowBalance,     // -- 钱包余额低于保留余额，未发送交易
+    Other,          // -- 其他错误
+}
+
+impl From<&TokenAccountError> for FailureKind {
+    fn from(error: &TokenAccountError) -> Self {
+        match error {
+            TokenAccountError::NonZeroBalance(_) => FailureKind::NonZeroBalance,
+            TokenAccountError::AccountFrozen(_) => FailureKind::Frozen,
+            TokenAccountError::NotCloseAuthority { .. } => FailureKind::CloseAuthority,
+            TokenAccountError::AccountParseError(_)
+            | TokenAccountError::AccountNotFound(_)
+            | TokenAccountError::InvalidKeyFormat
+            | TokenAccountError::DecimalsMismatch { .. } => FailureKind::InvalidAccount,
+            TokenAccountError::RpcError(_) => FailureKind::Rpc,
+            TokenAccountError::InsufficientReserve { .. } => FailureKind::LowBalance,
+            TokenAccountError::TransactionError(_)
+            | TokenAccountError::SplTokenError(_)
+            | TokenAccountError::ProgramError(_) => FailureKind::Transaction,
+            _ => FailureKind::Other,
+        }
+    }
+}
+
+impl FailureKind {
+    /// -- 失败原因的说明文字
+    pub fn label(&self, locale: Locale) -> &'static str {
+        match self {
+            FailureKind::NonZeroBalance => locale.text("余额不为 0", "Non-zero balance"),
+            FailureKind::Frozen => locale.text("账户已冻结", "Account frozen"),
+            FailureKind::CloseAuthority => {
+                locale.text("关闭权限属于其他地址", "Close authority is another address")
+            }
+            FailureKind::InvalidAccount => locale.text("账户无效", "Invalid account"),
+            FailureKind::Rpc => locale.text("RPC 请求失败", "RPC request failed"),
+            FailureKind::Transaction => locale.text("交易失败", "Transaction failed"),
+            FailureKind::LowBalance => locale.text("钱包余额不足", "Wallet balance too low"),
+            FailureKind::Other => locale.text("其他错误", "Other error"),
+        }
+    }
+}
+
+/// -- 批量操作中单个账户的处理结果
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountOutcome {
+    pub account_address: String,              // -- 账户地址
+    pub mint: String,                         // -- 代币的 Mint 地址
+    pub symbol: String,                       // -- 代币符号
+    pub success: bool,                        // -- 操作是否成功
+    pub signature: Option<String>,            // -- 关闭账户的交易签名（模拟模式下为 None）
+    pub burn_signature: Option<String>,       // -- 销毁代币的交易签名（仅销毁并关闭时）
+    pub burned_amount: u64,                   // -- 销毁的代币数量
+    pub swept_amount: u64,                    // -- 转出到目标钱包的代币数量（仅转出并关闭时）
+    pub swap: Option<SwapSummary>,            // -- 卖出剩余代币的结果（仅卖出并关闭时）
+    pub unwrapped_lamports: u64,              // -- 解包返还的 SOL（lamports，仅 wSOL 账户）
+    pub rent_lamports: u64,                   // -- 回收的租金（以 lamports 为单位）
+    pub error: Option<String>,                // -- 失败时的错误信息
+    pub failure_kind: Option<FailureKind>,    // -- 失败原因分类
+    pub simulation: Option<SimulationReport>, // -- 发送前模拟的日志和计算单元消耗
+    pub already_closed: bool,                 // -- 处理前账户已被关闭（AlreadyClosed），未发送交易
+}
+
+impl AccountOutcome {
+    /// -- 创建关闭成功的结果
+    pub fn closed(
+        account: &TokenAccountInfo,
+        signature: Option<String>,
+        simulation: SimulationReport,
+    ) -> Self {
+        Self {
+            account_address: account.address.clone(),
+            mint: account.mint.clone(),
+            symbol: account.symbol.clone(),
+            success: true,
+            signature,
+            burn_signature: None,
+            burned_amount: 0,
+            swept_amount: 0,
+            swap: None,
+            unwrapped_lamports: 0,
+            rent_lamports: account.rent_lamports,
+            error: None,
+            failure_kind: None,
+            simulation: Some(simulation),
+            already_closed: false,
+        }
+    }
+
+    /// -- 创建账户已被关闭的结果
+    ///
+    /// 恢复或重试批量关闭时，之前已成功关闭的账户在链上已不存在，
+    /// 标记为 `AlreadyClosed` 而不是失败，也不计入回收的租金。
+    pub fn already_closed(account: &TokenAccountInfo) -> Self {
+        Self {
+            account_address: account.address.clone(),
+            mint: account.mint.clone(),
+            symbol: account.symbol.clone(),
+            success: true,
+            signature: None,
+            burn_signature: None,
+            burned_amount: 0,
+            swept_amount: 0,
+            swap: None,
+            unwrapped_lamports: 0,
+            rent_lamports: 0,
+            error: None,
+            failure_kind: None,
+            simulation: None,
+            already_closed: true,
+        }
+    }
+
+    /// -- 创建失败结果，错误信息使用当前界面语言（见 `utils::locale::set_locale`）
+    pub fn failed(
+        account_address: &str,
+        mint: &str,
+        symbol: &str,
+        error: &TokenAccountError,
+    ) -> Self {
+        Self {
+            account_address: account_address.to_string(),
+            mint: mint.to_string(),
+            symbol: symbol.to_string(),
+            success: false,
+            signature: None,
+            burn_signature: None,
+            burned_amount: 0,
+            swept_amount: 0,
+            swap: None,
+            unwrapped_lamports: 0,
+            rent_lamports: 0,
+            error: Some(error.localized(current_locale())),
+            failure_kind: Some(FailureKind::from(error)),
+            simulation: None,
+            already_closed: false,
+        }
+    }
+}
+
+/// -- 批量关闭（或销毁并关闭）结果
+///
+/// 由 `batch_close_accounts` 和 `batch_burn_and_close_zero_value_accounts` 返回，
+/// 记录每个账户的处理结果以及整体统计信息。
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BatchCloseReport {
+    pub outcomes: Vec<AccountOutcome>, // -- 每个账户的处理结果
+    pub signatures: Vec<String>,       // -- 发送的全部交易签名
+    pub succeeded: usize,              // -- 成功处理的账户数量（不含已被关闭的账户）
+    pub failed: usize,                 // -- 失败的账户数量
+    pub already_closed: usize,         // -- 处理前已被关闭的账户数量（不计为失败）
+    pub rent_recovered_lamports: u64,  // -- 回收的租金（以 lamports 为单位）
+    pub rent_recovered_sol: f64,       // -- 回收的租金（以 SOL 为单位）
+    pub unwrapped_lamports: u64,       // -- 解包 wSOL 返还的 SOL（以 lamports 为单位，不含租金）
+    pub unwrapped_sol: f64,            // -- 解包 wSOL 返还的 SOL（以 SOL 为单位）
+    pub balance_before: u64,           // -- 执行前钱包余额（lamports）
+    pub balance_after: u64,            // -- 执行后钱包余额（lamports）
+    pub fees_lamports: u64,            // -- 交易费用（根据余额变化估算，lamports）
+    pub estimated_fees_lamports: u64,  // -- 执行前预计的交易费用上限（lamports）
+    pub simulated: bool,               // -- 是否为模拟执行（未发送交易）
+}
+
+impl BatchCloseReport {
+    /// -- 失败的账户结果
+    pub fn failures(&self) -> impl Iterator<Item = &AccountOutcome> {
+        self.outcomes.iter().filter(|outcome| !outcome.success)
+    }
+
+    /// -- 是否全部成功
+    pub fn is_success(&self) -> bool {
+        self.failed == 0
+    }
+
+    /// -- 根据账户结果和执行前后的钱包余额汇总统计信息
+    pub(crate) fn finalize(&mut self, balance_before: u64, balance_after: u64) {
+        self.already_closed = self.outcomes.iter().filter(|o| o.already_closed).count();
+        self.succeeded = self
+            .outcomes
+            .iter()
+            .filter(|o| o.success && !o.already_closed)
+            .count();
+        self.failed = self.outcomes.len() - self.succeeded - self.already_closed;
+        self.rent_recovered_lamports = self.outcomes.iter().map(|o| o.rent_lamports).sum();
+        self.rent_recovered_sol = self.rent_recovered_lamports as f64 / LAMPORTS_PER_SOL as f64;
+        self.unwrapped_lamports = self.outcomes.iter().map(|o| o.unwrapped_lamports).sum();
+        self.unwrapped_sol = self.unwrapped_lamports as f64 / LAMPORTS_PER_SOL as f64;
+        self.balance_before = balance_before;
+        self.balance_after = balance_after;
+        self.fees_lamports = if self.simulated {
+            0
+        } else {
+            (balance_before + self.rent_recovered_lamports + self.unwrapped_lamports)
+                .saturating_sub(balance_after)
+        };
+
+        let mut signatures = Vec::new();
+        for outcome in &self.outcomes {
+            for signature in [&outcome.burn_signature, &outcome.signature]
+                .into_iter()
+                .flatten()
+            {
+                if !signatures.contains(signature) {
+                    signatures.push(signature.clone());
+                }
+            }
+        }
+        self.signatures = signatures;
+    }
 }
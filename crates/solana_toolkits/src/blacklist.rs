@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+/// -- 代币黑名单管理器
+/// 黑名单中的 Mint 总是被销毁并关闭，即使代币符号与白名单重复（诈骗代币经常冒用 "USDC" 等符号）
+#[derive(Debug, Default)]
+pub struct TokenBlacklist {
+    mints: HashSet<String>, // -- 代币 Mint 地址黑名单
+}
+
+impl TokenBlacklist {
+    /// -- 创建空的黑名单管理器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// -- 添加 Mint 地址到黑名单（单个添加）
+    pub fn add_mint(&mut self, mint: &str) {
+        self.mints.insert(mint.to_string());
+    }
+
+    /// -- 批量添加 Mint 地址到黑名单
+    pub fn add_mints(&mut self, mints: &[&str]) {
+        self.mints.extend(mints.iter().map(|s| s.to_string()));
+    }
+
+    /// -- 从黑名单中移除 Mint 地址
+    pub fn remove_mint(&mut self, mint: &str) {
+        self.mints.remove(mint);
+    }
+
+    /// -- 检查 Mint 是否在黑名单中
+    pub fn is_blacklisted(&self, mint: &str) -> bool {
+        self.mints.contains(mint)
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{
+    blacklist::TokenBlacklist, config::TokenAccountConfig, filter::ScanFilter, load_whitelist,
+    notify::Notifier, observer::BatchObserver, retry::RetryPolicy, spam::SpamHeuristics,
+    whitelist::TokenWhitelist, TokenAccountManager,
+};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+use std::{path::PathBuf, sync::Arc, time::Duration};
+use utils::{
+    cluster::Cluster, init_cluster_rpc_client, wallet::load_keypair, FailoverRpc, RpcApi,
+    TokenAccountError, TokenAccountResult,
+};
+
+/// -- 根据最终配置创建 RPC 客户端
+type Connect<R> = Box<dyn FnOnce(&TokenAccountConfig) -> TokenAccountResult<R> + Send>;
+
+/// -- 创建钱包签名者
+type LoadWallet<S> = Box<dyn FnOnce() -> TokenAccountResult<S> + Send>;
+
+/// -- 代币账户管理器构建器
+///
+/// 通过 [`TokenAccountManager::builder`] 创建，可以注入已有的 `Keypair`、任意 `Signer` 或 RPC 客户端，
+/// 不必经过密钥文件和环境变量。未设置 RPC 时使用配置的集群，未设置集群时读取 `RPC_URL` 环境变量，未设置白名单时按配置加载。
+///
+/// # 示例
+/// ```no_run
+/// use solana_sdk::signature::Keypair;
+/// use solana_toolkits::TokenAccountManager;
+///
+/// fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     let manager = TokenAccountManager::builder()
+///         .rpc_url("https://api.devnet.solana.com")
+///         .keypair(Keypair::new())
+///         .build()?;
+///     Ok(())
+/// }
+/// ```
+pub struct TokenAccountManagerBuilder<R: RpcApi = RpcClient, S: Signer = Keypair> {
+    connect: Connect<R>,                              // -- RPC 客户端的创建方式
+    wallet: Option<LoadWallet<S>>,                    // -- 钱包签名者的创建方式
+    config: TokenAccountConfig,                       // -- 管理器配置
+    whitelist: Option<TokenWhitelist>,                // -- 白名单，None 表示按配置加载
+    blacklist: TokenBlacklist,                        // -- 黑名单
+    observer: Option<Arc<dyn BatchObserver>>,         // -- 批量操作进度观察者
+    notifiers: Vec<Arc<dyn Notifier>>,                // -- 批量处理完成后的通知目标
+    fee_payer: Option<Arc<dyn Signer + Send + Sync>>, // -- 独立的手续费支付者
+}
+
+impl TokenAccountManager {
+    /// -- 创建代币账户管理器构建器
+    pub fn builder() -> TokenAccountManagerBuilder {
+        TokenAccountManagerBuilder {
+            connect: Box::new(|config| {
+                Ok(init_cluster_rpc_client(
+                    &config.cluster(),
+                    config.commitment,
+                )?)
+            }),
+            wallet: None,
+            config: TokenAccountConfig::default(),
+            whitelist: None,
+            blacklist: TokenBlacklist::new(),
+            observer: None,
+            notifiers: Vec::new(),
+            fee_payer: None,
+        }
+    }
+}
+
+impl<S: Signer> TokenAccountManagerBuilder<RpcClient, S> {
+    /// -- 使用指定地址的 RPC 节点
+    ///
+    /// # 参数
+    /// * `rpc_url` - RPC 地址，提交级别取自最终配置
+    pub fn rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        let rpc_url = rpc_url.into();
+        self.connect =
+            Box::new(move |config| Ok(RpcClient::new_with_commitment(rpc_url, config.commitment)));
+        self
+    }
+
+    /// -- 使用多个 RPC 节点，限流或连接错误时自动切换
+    ///
+    /// # 参数
+    /// * `rpc_urls` - RPC 地址列表，靠前的地址优先使用；探测间隔取自最终配置
+    pub fn rpc_pool(self, rpc_urls: Vec<String>) -> TokenAccountManagerBuilder<FailoverRpc, S> {
+        self.replace_connection(move |config| {
+            Ok(FailoverRpc::new(&rpc_urls, config.commitment)
+                .with_probe_interval(config.rpc_probe_interval))
+        })
+    }
+}
+
+impl<R: RpcApi> TokenAccountManagerBuilder<R> {
+    /// -- 使用已加载的钱包密钥对
+    pub fn keypair(self, keypair: Keypair) -> Self {
+        self.signer(keypair)
+    }
+
+    /// -- 使用钱包密钥文件，构建时加载（支持的格式见 [`TokenAccountManager::new`]）
+    pub fn wallet_path(mut self, path: impl Into<String>) -> Self {
+        let path = path.into();
+        self.wallet = Some(Box::new(move || load_keypair(&path)));
+        self
+    }
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManagerBuilder<R, S> {
+    /// -- 使用已创建的 RPC 客户端
+    ///
+    /// # 参数
+    /// * `connection` - 实现了 `RpcApi` 的 RPC 客户端（如 `RpcClient`、`FailoverRpc`、`MockRpc`）
+    pub fn rpc_client<C: RpcApi + 'static>(
+        self,
+        connection: C,
+    ) -> TokenAccountManagerBuilder<C, S> {
+        self.replace_connection(move |_| Ok(connection))
+    }
+
+    /// -- 替换 RPC 客户端的创建方式，其余设置保持不变
+    fn replace_connection<C: RpcApi>(
+        self,
+        connect: impl FnOnce(&TokenAccountConfig) -> TokenAccountResult<C> + Send + 'static,
+    ) -> TokenAccountManagerBuilder<C, S> {
+        TokenAccountManagerBuilder {
+            connect: Box::new(connect),
+            wallet: self.wallet,
+            config: self.config,
+            whitelist: self.whitelist,
+            blacklist: self.blacklist,
+            observer: self.observer,
+            notifiers: self.notifiers,
+            fee_payer: self.fee_payer,
+        }
+    }
+
+    /// -- 使用任意签名者作为钱包（如 Ledger 硬件钱包或远程签名服务）
+    ///
+    /// # 参数
+    /// * `signer` - 实现了 `Signer` 的签名者，关闭、销毁等交易都由它签名
+    pub fn signer<T: Signer + Send + 'static>(self, signer: T) -> TokenAccountManagerBuilder<R, T> {
+        TokenAccountManagerBuilder {
+            connect: self.connect,
+            wallet: Some(Box::new(move || Ok(signer))),
+            config: self.config,
+            whitelist: self.whitelist,
+            blacklist: self.blacklist,
+            observer: self.observer,
+            notifiers: self.notifiers,
+            fee_payer: self.fee_payer,
+        }
+    }
+
+    /// -- 使用独立的手续费支付者，关闭、销毁交易的费用由它支付，钱包只作为账户所有者签名
+    pub fn fee_payer<T: Signer + Send + Sync + 'static>(mut self, fee_payer: T) -> Self {
+        self.fee_payer = Some(Arc::new(fee_payer));
+        self
+    }
+
+    /// -- 替换整个配置，会覆盖之前通过其他方法设置的配置项
+    pub fn config(mut self, config: TokenAccountConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// -- 设置连接的集群，未调用 `rpc_url`、`rpc_client` 或 `rpc_pool` 时使用该集群的 RPC 地址
+    pub fn cluster(mut self, cluster: Cluster) -> Self {
+        self.config.cluster = Some(cluster);
+        self
+    }
+
+    /// -- 设置提交级别
+    pub fn commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.config.commitment = commitment;
+        self
+    }
+
+    /// -- 设置批次失败时的重试策略
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.config.retry_policy = retry_policy;
+        self
+    }
+
+    /// -- 设置批处理操作间隔时间
+    pub fn batch_delay(mut self, batch_delay: Duration) -> Self {
+        self.config.batch_delay = batch_delay;
+        self
+    }
+
+    /// -- 设置批量交易模式下同时签名并发送的交易数
+    pub fn max_concurrent_transactions(mut self, max_concurrent_transactions: usize) -> Self {
+        self.config.max_concurrent_transactions = max_concurrent_transactions;
+        self
+    }
+
+    /// -- 设置疑似垃圾代币的识别规则
+    pub fn spam_heuristics(mut self, heuristics: SpamHeuristics) -> Self {
+        self.config.spam_heuristics = Some(heuristics);
+        self
+    }
+
+    /// -- 设置运行历史文件路径，每次批量处理完成后追加一条记录
+    pub fn history_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.history_path = Some(path.into());
+        self
+    }
+
+    /// -- 设置可读运行报告路径（Markdown 或 HTML），每次批量处理完成后覆盖写入
+    pub fn run_report_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config.run_report_path = Some(path.into());
+        self
+    }
+
+    /// -- 设置模拟模式
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.config.dry_run = dry_run;
+        self
+    }
+
+    /// -- 设置扫描账户时的过滤条件
+    pub fn scan_filter(mut self, scan_filter: ScanFilter) -> Self {
+        self.config.scan_filter = scan_filter;
+        self
+    }
+
+    /// -- 使用指定的白名单，不再按配置中的 `whitelist_path` 加载
+    pub fn whitelist(mut self, whitelist: TokenWhitelist) -> Self {
+        self.whitelist = Some(whitelist);
+        self
+    }
+
+    /// -- 使用指定的黑名单
+    pub fn blacklist(mut self, blacklist: TokenBlacklist) -> Self {
+        self.blacklist = blacklist;
+        self
+    }
+
+    /// -- 设置批量操作进度观察者
+    pub fn observer(mut self, observer: Arc<dyn BatchObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// -- 添加批量处理完成后的通知目标，可以多次调用
+    pub fn notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifiers.push(notifier);
+        self
+    }
+
+    /// -- 创建代币账户管理器
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<TokenAccountManager<R, S>>` - 未设置钱包、密钥或白名单文件加载失败时返回错误
+    pub fn build(self) -> TokenAccountResult<TokenAccountManager<R, S>> {
+        let wallet = match self.wallet {
+            Some(load_wallet) => load_wallet()?,
+            None => return Err(TokenAccountError::Other(String::from("未设置钱包"))),
+        };
+        let whitelist = match self.whitelist {
+            Some(whitelist) => whitelist,
+            None => load_whitelist(&self.config)?,
+        };
+        let connection = (self.connect)(&self.config)?;
+
+        let mut manager =
+            TokenAccountManager::from_parts(connection, wallet, self.config, whitelist);
+        manager.blacklist = self.blacklist;
+        if let Some(observer) = self.observer {
+            manager.observer = observer;
+        }
+        manager.notifiers = self.notifiers;
+        manager.fee_payer = self.fee_payer;
+        Ok(manager)
+    }
+}
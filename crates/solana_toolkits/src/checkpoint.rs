@@ -0,0 +1,178 @@
+use crate::account_info::{AccountOutcome, TokenAccountInfo};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+use tracing::{info, warn};
+use utils::TokenAccountResult;
+
+/// -- 检查点中单个账户的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckpointStatus {
+    /// 尚未处理
+    Pending,
+    /// 已确认关闭
+    Closed,
+    /// 处理失败，恢复时重新处理
+    Failed,
+}
+
+/// -- 检查点中的单个账户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointEntry {
+    pub account: TokenAccountInfo, // -- 账户信息
+    pub status: CheckpointStatus,  // -- 处理状态
+    pub signature: Option<String>, // -- 关闭交易签名
+    pub error: Option<String>,     // -- 失败时的错误信息
+}
+
+/// -- 批量关闭检查点
+///
+/// 记录一次批量关闭中每个账户的处理状态，运行中断后可以据此跳过已关闭的账户继续执行。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCheckpoint {
+    pub wallet: String,                // -- 钱包地址，恢复时用于校验
+    pub entries: Vec<CheckpointEntry>, // -- 每个账户的处理状态
+}
+
+impl BatchCheckpoint {
+    /// -- 从文件加载检查点
+    ///
+    /// # 参数
+    /// * `path` - 检查点文件路径
+    pub fn load(path: impl AsRef<Path>) -> TokenAccountResult<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// -- 保存检查点到文件
+    ///
+    /// # 参数
+    /// * `path` - 检查点文件路径，上级目录不存在时自动创建
+    pub fn save(&self, path: impl AsRef<Path>) -> TokenAccountResult<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// -- 尚未确认关闭的账户（未处理和处理失败的账户）
+    pub fn pending_accounts(&self) -> Vec<TokenAccountInfo> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.status != CheckpointStatus::Closed)
+            .map(|entry| entry.account.clone())
+            .collect()
+    }
+
+    /// -- 已确认关闭的账户数量
+    pub fn closed(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.status == CheckpointStatus::Closed)
+            .count()
+    }
+
+    /// -- 将账户加入检查点，已存在的账户保留关闭状态，其余重置为未处理
+    fn track(&mut self, accounts: &[TokenAccountInfo]) {
+        for account in accounts {
+            match self
+                .entries
+                .iter_mut()
+                .find(|entry| entry.account.address == account.address)
+            {
+                Some(entry) if entry.status == CheckpointStatus::Closed => {}
+                Some(entry) => {
+                    entry.status = CheckpointStatus::Pending;
+                    entry.error = None;
+                }
+                None => self.entries.push(CheckpointEntry {
+                    account: account.clone(),
+                    status: CheckpointStatus::Pending,
+                    signature: None,
+                    error: None,
+                }),
+            }
+        }
+    }
+
+    /// -- 根据账户处理结果更新状态
+    fn record(&mut self, outcome: &AccountOutcome) {
+        if let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.account.address == outcome.account_address)
+        {
+            entry.status = if outcome.success {
+                CheckpointStatus::Closed
+            } else {
+                CheckpointStatus::Failed
+            };
+            entry.signature = outcome.signature.clone();
+            entry.error = outcome.error.clone();
+        }
+    }
+}
+
+/// -- 批量关闭过程中持续写入检查点文件
+pub(crate) struct CheckpointWriter {
+    path: PathBuf,                      // -- 检查点文件路径
+    checkpoint: Mutex<BatchCheckpoint>, // -- 当前检查点
+}
+
+impl CheckpointWriter {
+    /// -- 开始记录一次批量关闭
+    ///
+    /// 同一钱包已有检查点时在其基础上继续记录，否则新建检查点。
+    pub(crate) fn start(
+        path: &Path,
+        wallet: &str,
+        accounts: &[TokenAccountInfo],
+    ) -> TokenAccountResult<Self> {
+        let mut checkpoint = match BatchCheckpoint::load(path) {
+            Ok(checkpoint) if checkpoint.wallet == wallet => checkpoint,
+            _ => BatchCheckpoint {
+                wallet: wallet.to_string(),
+                entries: Vec::new(),
+            },
+        };
+        checkpoint.track(accounts);
+        checkpoint.save(path)?;
+        info!("检查点文件: {}", path.display());
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            checkpoint: Mutex::new(checkpoint),
+        })
+    }
+
+    /// -- 记录单个账户的处理结果并立即写入文件
+    pub(crate) fn record(&self, outcome: &AccountOutcome) {
+        let mut checkpoint = self.checkpoint.lock().unwrap();
+        checkpoint.record(outcome);
+        if let Err(e) = checkpoint.save(&self.path) {
+            warn!("写入检查点文件失败: {}", e);
+        }
+    }
+
+    /// -- 批量关闭结束：全部账户都已关闭时删除检查点文件，否则保留以便恢复
+    pub(crate) fn finish(&self) {
+        let pending = self.checkpoint.lock().unwrap().pending_accounts().len();
+        if pending == 0 {
+            let _ = fs::remove_file(&self.path);
+        } else {
+            warn!(
+                "{} 个账户未关闭，检查点已保存到 {}",
+                pending,
+                self.path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{account_info::BatchCloseReport, TokenAccountManager};
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{signature::Keypair, signer::Signer};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use time::{OffsetDateTime, UtcOffset};
+use tokio::sync::watch;
+use tracing::{info, warn};
+use utils::{schedule::CronSchedule, RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 定时清理配置
+#[derive(Debug, Clone)]
+pub struct CleanupDaemonConfig {
+    /// 执行计划，默认每天 03:00（`0 3 * * *`）
+    pub schedule: CronSchedule,
+    /// 计算执行时间使用的时区，默认 UTC
+    pub utc_offset: UtcOffset,
+    /// 每批处理的账户数量
+    pub batch_size: usize,
+    /// 关闭空账户时是否把一批账户合并到一笔交易
+    pub use_batch_tx: bool,
+    /// 是否销毁并关闭零价值代币账户，销毁范围受白名单、NFT 保护和 `max_usd_value_to_burn` 限制
+    pub burn_zero_value: bool,
+    /// 状态文件路径，`None` 时状态只保存在内存中
+    pub state_path: Option<PathBuf>,
+    /// 同一账户连续失败达到该次数后不再处理，0 表示不限制
+    pub max_failures: u32,
+}
+
+impl Default for CleanupDaemonConfig {
+    fn default() -> Self {
+        Self {
+            schedule: "0 3 * * *".parse().expect("默认 cron 表达式有效"),
+            utc_offset: UtcOffset::UTC,
+            batch_size: 10,
+            use_batch_tx: true,
+            burn_zero_value: false,
+            state_path: None,
+            max_failures: 3,
+        }
+    }
+}
+
+/// -- 连续处理失败的账户
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailedAccount {
+    pub attempts: u32,         // -- 连续失败次数
+    pub error: Option<String>, // -- 最近一次的错误信息
+}
+
+/// -- 定时清理的持久化状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CleanupState {
+    pub last_run: Option<i64>,        // -- 上一次执行时间（Unix 时间戳，秒）
+    pub runs: u64,                    // -- 累计执行次数
+    pub rent_recovered_lamports: u64, // -- 累计回收的租金（lamports）
+    pub failures: HashMap<String, FailedAccount>, // -- 连续失败的账户，成功后移除
+}
+
+impl CleanupState {
+    /// -- 从文件加载状态，文件不存在时返回空状态
+    ///
+    /// # 参数
+    /// * `path` - 状态文件路径
+    pub fn load(path: impl AsRef<Path>) -> TokenAccountResult<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// -- 保存状态到文件
+    ///
+    /// # 参数
+    /// * `path` - 状态文件路径，上级目录不存在时自动创建
+    pub fn save(&self, path: impl AsRef<Path>) -> TokenAccountResult<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// -- 账户是否已连续失败 `max_failures` 次
+    fn is_exhausted(&self, address: &str, max_failures: u32) -> bool {
+        max_failures > 0
+            && self
+                .failures
+                .get(address)
+                .is_some_and(|failed| failed.attempts >= max_failures)
+    }
+
+    /// -- 根据批量处理报告更新失败记录和租金统计
+    fn record(&mut self, report: &BatchCloseReport) {
+        self.rent_recovered_lamports += report.rent_recovered_lamports;
+        for outcome in &report.outcomes {
+            if outcome.success {
+                self.failures.remove(&outcome.account_address);
+            } else {
+                let failed = self
+                    .failures
+                    .entry(outcome.account_address.clone())
+                    .or_insert(FailedAccount {
+                        attempts: 0,
+                        error: None,
+                    });
+                failed.attempts += 1;
+                failed.error = outcome.error.clone();
+            }
+        }
+    }
+}
+
+/// -- 单次定时清理的结果
+#[derive(Debug)]
+pub struct CleanupRunSummary {
+    pub scanned: usize,                   // -- 扫描到的代币账户数量
+    pub skipped: usize,                   // -- 因连续失败被跳过的账户数量
+    pub closed: BatchCloseReport,         // -- 关闭空账户的报告
+    pub burned: Option<BatchCloseReport>, // -- 销毁零价值账户的报告，未执行销毁时为 None
+}
+
+/// -- 定时清理的停止句柄
+///
+/// 可以克隆后交给信号处理等其它任务。正在执行的清理会完成当前阶段后退出，不会中断发送中的批次。
+#[derive(Debug, Clone)]
+pub struct ShutdownHandle {
+    sender: Arc<watch::Sender<bool>>,
+}
+
+impl ShutdownHandle {
+    /// -- 请求停止定时清理
+    pub fn shutdown(&self) {
+        self.sender.send_replace(true);
+    }
+
+    /// -- 是否已请求停止
+    pub fn is_shutdown(&self) -> bool {
+        *self.sender.borrow()
+    }
+}
+
+/// -- 按 cron 计划定期扫描并清理代币账户
+///
+/// 每次执行先扫描，再批量关闭空账户，启用 `burn_zero_value` 时继续销毁并关闭零价值账户。
+/// 白名单、黑名单、NFT 保护、扫描过滤和 `max_usd_value_to_burn` 等规则沿用管理器的配置。
+/// 状态文件记录上一次执行时间和连续失败的账户：重启后如果错过了计划的执行时间会立即补跑一次，
+/// 连续失败达到 `max_failures` 次的账户不再重复处理，直到从状态文件中移除。
+///
+/// # 示例
+/// ```no_run
+/// use solana_toolkits::{
+///     cleanup_daemon::{CleanupDaemon, CleanupDaemonConfig},
+///     TokenAccountManager,
+/// };
+///
+/// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     let manager = TokenAccountManager::new("wallet.json")?;
+///     let config = CleanupDaemonConfig {
+///         schedule: "@daily".parse()?,
+///         burn_zero_value: true,
+///         state_path: Some("cleanup-state.json".into()),
+///         ..CleanupDaemonConfig::default()
+///     };
+///     let mut daemon = CleanupDaemon::new(manager, config)?;
+///
+///     let handle = daemon.shutdown_handle();
+///     tokio::spawn(async move {
+///         let _ = tokio::signal::ctrl_c().await;
+///         handle.shutdown();
+///     });
+///     daemon.run().await?;
+///     Ok(())
+/// }
+/// ```
+pub struct CleanupDaemon<R: RpcApi = RpcClient, S: Signer = Keypair> {
+    manager: TokenAccountManager<R, S>, // -- 执行清理的管理器
+    config: CleanupDaemonConfig,        // -- 定时清理配置
+    state: CleanupState,                // -- 持久化状态
+    shutdown: Arc<watch::Sender<bool>>, // -- 停止信号
+}
+
+impl<R: RpcApi, S: Signer> CleanupDaemon<R, S> {
+    /// -- 创建定时清理，配置了 `state_path` 时从文件加载状态
+    ///
+    /// # 参数
+    /// * `manager` - 代币账户管理器
+    /// * `config` - 定时清理配置
+    pub fn new(
+        manager: TokenAccountManager<R, S>,
+        config: CleanupDaemonConfig,
+    ) -> TokenAccountResult<Self> {
+        let state = match &config.state_path {
+            Some(path) => CleanupState::load(path)?,
+            None => CleanupState::default(),
+        };
+        Ok(Self {
+            manager,
+            config,
+            state,
+            shutdown: Arc::new(watch::channel(false).0),
+        })
+    }
+
+    /// -- 获取停止句柄
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            sender: self.shutdown.clone(),
+        }
+    }
+
+    /// -- 当前状态
+    pub fn state(&self) -> &CleanupState {
+        &self.state
+    }
+
+    /// -- 执行清理的管理器
+    pub fn manager(&self) -> &TokenAccountManager<R, S> {
+        &self.manager
+    }
+
+    /// -- 按计划循环执行，直到收到停止请求
+    ///
+    /// 单次执行失败只记录日志，等待下一次计划时间；只有 cron 表达式无法满足时返回错误。
+    pub async fn run(&mut self) -> TokenAccountResult<()> {
+        let mut shutdown = self.shutdown.subscribe();
+        let offset = self.config.utc_offset;
+
+        if !*shutdown.borrow() && self.missed_run(OffsetDateTime::now_utc().to_offset(offset)) {
+            info!("错过了计划的执行时间，立即补跑一次清理");
+            self.run_logged().await;
+        }
+
+        while !*shutdown.borrow_and_update() {
+            let now = OffsetDateTime::now_utc().to_offset(offset);
+            let next = self.config.schedule.next_after(now).ok_or_else(|| {
+                TokenAccountError::Other(format!("cron 表达式无法满足: {}", self.config.schedule))
+            })?;
+            info!("下一次定时清理时间: {}", next);
+
+            tokio::select! {
+                _ = tokio::time::sleep((next - now).unsigned_abs()) => self.run_logged().await,
+                _ = shutdown.changed() => {}
+            }
+        }
+
+        info!("定时清理已停止");
+        Ok(())
+    }
+
+    /// -- 立即执行一次清理并保存状态
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<CleanupRunSummary>` - 本次扫描、关闭和销毁的结果
+    pub async fn run_once(&mut self) -> TokenAccountResult<CleanupRunSummary> {
+        let result = self.manager.get_closeable_accounts().await?;
+        let max_failures = self.config.max_failures;
+
+        let (accounts, skipped_closeable): (Vec<_>, Vec<_>) = result
+            .accounts
+            .into_iter()
+            .partition(|account| !self.state.is_exhausted(&account.address, max_failures));
+        let closed = self
+            .manager
+            .batch_close_accounts(&accounts, self.config.batch_size, self.config.use_batch_tx)
+            .await?;
+        self.state.record(&closed);
+
+        let mut skipped = skipped_closeable.len();
+        let burned = if self.config.burn_zero_value && !*self.shutdown.borrow() {
+            let (zero_value, exhausted): (Vec<_>, Vec<_>) = result
+                .zero_value_accounts_list
+                .into_iter()
+                .partition(|account| !self.state.is_exhausted(&account.address, max_failures));
+            skipped += exhausted.len();
+            match self
+                .manager
+                .batch_burn_and_close_zero_value_accounts(&zero_value, self.config.batch_size)
+                .await
+            {
+                Ok(report) => {
+                    self.state.record(&report);
+                    Some(report)
+                }
+                Err(e) => {
+                    self.save_state()?;
+                    return Err(e);
+                }
+            }
+        } else {
+            None
+        };
+
+        self.state.last_run = Some(OffsetDateTime::now_utc().unix_timestamp());
+        self.state.runs += 1;
+        self.save_state()?;
+
+        Ok(CleanupRunSummary {
+            scanned: result.total_accounts,
+            skipped,
+            closed,
+            burned,
+        })
+    }
+
+    /// -- 执行一次清理，失败只记录日志，等待下一次计划时间
+    async fn run_logged(&mut self) {
+        match self.run_once().await {
+            Ok(summary) => {
+                let burned = summary.burned.as_ref();
+                info!(
+                    "定时清理完成: 扫描 {} 个账户，关闭 {} 个，销毁 {} 个，失败 {} 个，跳过 {} 个",
+                    summary.scanned,
+                    summary.closed.succeeded,
+                    burned.map_or(0, |report| report.succeeded),
+                    summary.closed.failed + burned.map_or(0, |report| report.failed),
+                    summary.skipped
+                );
+            }
+            Err(e) => warn!("定时清理失败: {}", e),
+        }
+    }
+
+    /// -- 上一次执行之后是否有计划时间已经过去
+    fn missed_run(&self, now: OffsetDateTime) -> bool {
+        let Some(last_run) = self
+            .state
+            .last_run
+            .and_then(|timestamp| OffsetDateTime::from_unix_timestamp(timestamp).ok())
+        else {
+            return false;
+        };
+        self.config
+            .schedule
+            .next_after(last_run.to_offset(self.config.utc_offset))
+            .is_some_and(|next| next <= now)
+    }
+
+    /// -- 配置了 `state_path` 时保存状态
+    fn save_state(&self) -> TokenAccountResult<()> {
+        if let Some(path) = &self.config.state_path {
+            self.state.save(path)?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,19 @@
-use solana_sdk::commitment_config::CommitmentConfig;
-use std::time::Duration;
+use crate::{filter::ScanFilter, notify::NotifyPolicy, retry::RetryPolicy, spam::SpamHeuristics};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, compute_budget::ComputeBudgetInstruction,
+    instruction::Instruction, pubkey::Pubkey,
+};
+use std::{path::PathBuf, str::FromStr, time::Duration};
+use utils::cluster::Cluster;
+
+/// -- 每个签名的交易费用（lamports）
+const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+/// -- 未设置计算单元上限时运行时为每条指令分配的计算单元
+const DEFAULT_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// -- 单笔交易的计算单元上限
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
 
 /// -- 代币账户管理配置
 ///
@@ -10,10 +24,68 @@ pub struct TokenAccountConfig {
     pub commitment: CommitmentConfig,
     /// 批处理操作间隔时间
     pub batch_delay: Duration,
-    /// 最大重试次数
-    pub max_retries: u32,
-    /// 重试间隔时间
-    pub retry_delay: Duration,
+    /// 批次失败时的重试策略
+    pub retry_policy: RetryPolicy,
+    /// 模拟模式：关闭和销毁操作只模拟执行交易，不发送上链
+    pub dry_run: bool,
+    /// 每笔关闭、销毁交易的计算单元上限，None 表示使用运行时默认值
+    pub compute_unit_limit: Option<u32>,
+    /// 每笔关闭、销毁交易的计算单元价格（优先费）
+    pub compute_unit_price: ComputeUnitPrice,
+    /// 批量关闭、销毁交易的提交方式
+    pub submit_mode: SubmitMode,
+    /// 批量关闭使用的地址查找表，设置后生成 v0 交易
+    pub lookup_table: Option<Pubkey>,
+    /// 发送前模拟失败时的处理方式
+    pub preflight: PreflightPolicy,
+    /// 扫描账户时并发查询代币元数据的数量
+    pub metadata_concurrency: usize,
+    /// 扫描账户时每秒最多发起的元数据查询数，None 表示不限制
+    pub metadata_requests_per_second: Option<u32>,
+    /// 代币元数据缓存的有效期
+    pub metadata_cache_ttl: Duration,
+    /// 代币元数据磁盘缓存文件路径，None 表示只使用内存缓存
+    pub metadata_cache_path: Option<PathBuf>,
+    /// 销毁前先通过 Jupiter 卖出仍有价值的剩余代币，None 表示直接销毁
+    pub dust_swap: Option<DustSwapConfig>,
+    /// 批量销毁时允许销毁的最高 USD 价值，价值更高的账户无论是否在白名单中都不会被销毁；None 表示不检查
+    pub max_usd_value_to_burn: Option<f64>,
+    /// 查询代币 USD 价格的 Jupiter 价格 API 地址
+    pub price_api_url: String,
+    /// 是否允许销毁 NFT，默认 NFT 账户单独归类且批量销毁时跳过
+    pub burn_nfts: bool,
+    /// 关闭账户前撤销仍然存在的授权代理
+    pub revoke_delegates: bool,
+    /// 白名单文件路径（TOML 或 JSON），设置后创建管理器时自动加载
+    pub whitelist_path: Option<PathBuf>,
+    /// 运行历史文件路径，设置后每次批量关闭、销毁、转出或广播完成后追加一条记录（模拟模式不记录）
+    pub history_path: Option<PathBuf>,
+    /// 可读运行报告路径（`.md` 或 `.html`，按扩展名选择格式），设置后每次批量处理完成后覆盖写入；None 表示不生成
+    pub run_report_path: Option<PathBuf>,
+    /// 批量关闭检查点文件路径，设置后记录每个账户的处理状态，中断后可以恢复
+    pub checkpoint_path: Option<PathBuf>,
+    /// 连接的集群，None 表示读取 RPC_URL 环境变量，未设置时使用 mainnet
+    pub cluster: Option<Cluster>,
+    /// 多节点模式使用的 RPC 地址，靠前的优先使用；为空时使用 `cluster`，都未设置时读取 RPC_URLS 或 RPC_URL 环境变量
+    pub rpc_urls: Vec<String>,
+    /// 多节点模式下故障节点的重新探测间隔
+    pub rpc_probe_interval: Duration,
+    /// 关闭、销毁、归集交易发送后的确认方式
+    pub confirmation: ConfirmationStrategy,
+    /// 扫描账户时的过滤条件，默认不过滤
+    pub scan_filter: ScanFilter,
+    /// 批量处理完成后发送通知的时机
+    pub notify_policy: NotifyPolicy,
+    /// 钱包需要保留的最低 SOL 余额，每批处理前检查，预计费用会使余额低于该值时停止处理；None 表示不检查
+    pub min_wallet_sol_reserve: Option<f64>,
+    /// 通过 Memo 程序附加到每笔关闭、销毁交易的备注，便于在浏览器中审计；会略微增加交易大小和计算单元消耗
+    pub memo: Option<String>,
+    /// 单次批量处理预计交易费用（签名费、优先费和 Jito 小费）的上限（SOL），执行前估算，超过时拒绝执行；None 表示不限制
+    pub max_fee_sol: Option<f64>,
+    /// 批量交易模式下同时签名并发送的交易数，大于 1 时各批次并发提交且不再等待 `batch_delay`，默认 1 即逐批顺序发送
+    pub max_concurrent_transactions: usize,
+    /// 扫描时识别疑似垃圾或蜜罐代币的规则，命中的账户单独归入 `suspected_spam_accounts_list`；None 表示不识别
+    pub spam_heuristics: Option<SpamHeuristics>,
 }
 
 impl Default for TokenAccountConfig {
@@ -21,8 +93,297 @@ impl Default for TokenAccountConfig {
         Self {
             commitment: CommitmentConfig::confirmed(),
             batch_delay: Duration::from_millis(2000),
-            max_retries: 3,
-            retry_delay: Duration::from_millis(1000),
+            retry_policy: RetryPolicy::default(),
+            dry_run: false,
+            compute_unit_limit: None,
+            compute_unit_price: ComputeUnitPrice::None,
+            submit_mode: SubmitMode::Rpc,
+            lookup_table: None,
+            preflight: PreflightPolicy::Abort,
+            metadata_concurrency: 8,
+            metadata_requests_per_second: None,
+            metadata_cache_ttl: Duration::from_secs(3600),
+            metadata_cache_path: None,
+            dust_swap: None,
+            max_usd_value_to_burn: None,
+            price_api_url: jupiter_swap::price::DEFAULT_PRICE_API_URL.to_string(),
+            burn_nfts: false,
+            revoke_delegates: false,
+            whitelist_path: None,
+            history_path: None,
+            run_report_path: None,
+            checkpoint_path: None,
+            cluster: None,
+            rpc_urls: Vec::new(),
+            rpc_probe_interval: utils::DEFAULT_PROBE_INTERVAL,
+            confirmation: ConfirmationStrategy::Rpc,
+            scan_filter: ScanFilter::default(),
+            notify_policy: NotifyPolicy::Always,
+            min_wallet_sol_reserve: None,
+            memo: None,
+            max_fee_sol: None,
+            max_concurrent_transactions: 1,
+            spam_heuristics: None,
+        }
+    }
+}
+
+impl TokenAccountConfig {
+    /// -- 要连接的集群，未配置时读取 RPC_URL 环境变量
+    pub fn cluster(&self) -> Cluster {
+        self.cluster.clone().unwrap_or_else(Cluster::from_env)
+    }
+
+    /// -- 估算单笔交易的最高费用（lamports）
+    ///
+    /// 包括签名费、按计算单元上限计算的优先费（自动模式按价格上限计算）以及 Jito 小费。
+    /// 未设置计算单元上限时按运行时默认值估算：每条指令 200k，单笔交易最多 1.4M。
+    ///
+    /// # 参数
+    /// * `signers` - 交易的签名数量，使用独立的手续费支付者时为 2
+    /// * `instructions` - 交易中的业务指令数量（不含计算预算指令，备注指令自动计入）
+    ///
+    /// # 返回
+    /// * `u64` - 预计的最高费用（lamports）
+    pub fn estimated_fee_lamports(&self, signers: usize, instructions: usize) -> u64 {
+        let price = match self.compute_unit_price {
+            ComputeUnitPrice::None => 0,
+            ComputeUnitPrice::Fixed(price) => price,
+            ComputeUnitPrice::Auto { max_micro_lamports } => max_micro_lamports,
+        };
+        let instructions = instructions + self.memo.is_some() as usize;
+        let units = match self.compute_unit_limit {
+            Some(limit) => limit as u64,
+            None => (DEFAULT_COMPUTE_UNIT_LIMIT as u64 * instructions as u64)
+                .min(MAX_COMPUTE_UNIT_LIMIT as u64),
+        };
+        let tip = self
+            .jito_target()
+            .map_or(0, |(tip_lamports, _)| tip_lamports);
+        LAMPORTS_PER_SIGNATURE * signers as u64 + (price * units).div_ceil(1_000_000) + tip
+    }
+
+    /// -- 需要通过 Jito bundle 提交时返回小费和 block engine 地址
+    ///
+    /// 模拟模式下始终返回 `None`，交易只做本地模拟。
+    pub fn jito_target(&self) -> Option<(u64, &str)> {
+        match &self.submit_mode {
+            SubmitMode::Jito {
+                tip_lamports,
+                block_engine_url,
+            } if !self.dry_run => Some((*tip_lamports, block_engine_url.as_str())),
+            _ => None,
+        }
+    }
+
+    /// -- 生成需要插入到交易开头的计算预算指令
+    ///
+    /// # 参数
+    /// * `recent_fees` - 最近的优先费（micro-lamports），仅自动模式使用
+    ///
+    /// # 返回
+    /// * `Vec<Instruction>` - 计算预算指令，未配置时为空
+    pub fn compute_budget_instructions(&self, recent_fees: &[u64]) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        if let Some(units) = self.compute_unit_limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(units));
+        }
+        let price = self.compute_unit_price.resolve(recent_fees);
+        if price > 0 {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+        }
+        instructions
+    }
+}
+
+/// -- 发送前模拟失败时的处理方式
+///
+/// 每笔交易发送前都会先模拟执行，模拟失败通常意味着交易上链后也会失败并白白消耗手续费。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreflightPolicy {
+    /// 放弃发送并返回错误
+    #[default]
+    Abort,
+    /// 输出警告后仍然发送
+    Warn,
+}
+
+/// -- 剩余代币卖出的目标代币
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DustSwapOutput {
+    /// 换成 SOL（自动解包到钱包）
+    #[default]
+    Sol,
+    /// 换成 USDC
+    Usdc,
+}
+
+impl DustSwapOutput {
+    /// -- 目标代币的 Mint 地址
+    pub fn mint(&self) -> &'static str {
+        match self {
+            DustSwapOutput::Sol => jupiter_swap::SOL_MINT,
+            DustSwapOutput::Usdc => jupiter_swap::USDC_MINT,
         }
     }
 }
+
+impl FromStr for DustSwapOutput {
+    type Err = String;
+
+    /// -- 解析目标代币参数：`sol` 或 `usdc`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "sol" => Ok(DustSwapOutput::Sol),
+            "usdc" => Ok(DustSwapOutput::Usdc),
+            other => Err(format!("无效的目标代币: {}，可选 sol 或 usdc", other)),
+        }
+    }
+}
+
+/// -- 剩余代币卖出配置
+///
+/// 销毁并关闭账户前先向 Jupiter 询价，预计换得数量不低于阈值时卖出剩余代币，
+/// 否则（包括没有可用路由）照常销毁。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DustSwapConfig {
+    /// 换成的目标代币
+    pub output: DustSwapOutput,
+    /// 最低卖出价值：预计换得的目标代币数量（最小单位，SOL 为 lamports），低于该值直接销毁
+    pub min_output_amount: u64,
+    /// 滑点（基点）
+    pub slippage_bps: u16,
+    /// Jupiter v6 API 地址
+    pub api_url: String,
+}
+
+impl Default for DustSwapConfig {
+    fn default() -> Self {
+        Self {
+            output: DustSwapOutput::Sol,
+            min_output_amount: 100_000,
+            slippage_bps: 100,
+            api_url: jupiter_swap::DEFAULT_API_URL.to_string(),
+        }
+    }
+}
+
+/// -- 交易提交方式
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SubmitMode {
+    /// 通过 RPC 节点逐笔发送
+    #[default]
+    Rpc,
+    /// 打包为 Jito bundle 发送到 block engine，最后一笔交易附带小费
+    Jito {
+        tip_lamports: u64,
+        block_engine_url: String,
+    },
+}
+
+impl SubmitMode {
+    /// -- 使用默认主网 block engine 的 Jito 提交方式
+    pub fn jito(tip_lamports: u64) -> Self {
+        SubmitMode::Jito {
+            tip_lamports,
+            block_engine_url: crate::jito::DEFAULT_BLOCK_ENGINE_URL.to_string(),
+        }
+    }
+}
+
+/// -- 交易确认方式
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ConfirmationStrategy {
+    /// 通过 RPC 发送并轮询签名状态（`send_and_confirm_transaction`）
+    #[default]
+    Rpc,
+    /// 通过 RPC 发送，在 websocket 上订阅签名状态等待确认
+    WebSocket {
+        /// websocket 地址，None 表示读取 HELIUS_WS_RPC_URL 环境变量，未设置时根据 RPC 地址推导
+        ws_url: Option<String>,
+        /// 等待确认的超时时间
+        timeout: Duration,
+        /// 检查新区块哈希的间隔，出现新区块哈希时重新广播交易；None 表示不重新广播
+        rebroadcast_interval: Option<Duration>,
+    },
+}
+
+impl ConfirmationStrategy {
+    /// -- 使用默认超时和重新广播间隔的 websocket 确认方式
+    pub fn websocket() -> Self {
+        ConfirmationStrategy::WebSocket {
+            ws_url: None,
+            timeout: crate::confirmation::DEFAULT_CONFIRMATION_TIMEOUT,
+            rebroadcast_interval: Some(crate::confirmation::DEFAULT_REBROADCAST_INTERVAL),
+        }
+    }
+}
+
+/// -- 默认的自动优先费上限（micro-lamports）
+pub const DEFAULT_MAX_AUTO_PRICE: u64 = 1_000_000;
+
+/// -- 计算单元价格（优先费）设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComputeUnitPrice {
+    /// 不设置优先费
+    #[default]
+    None,
+    /// 固定价格（micro-lamports）
+    Fixed(u64),
+    /// 根据 `getRecentPrioritizationFees` 返回的最近优先费中位数自动设置，不超过上限
+    Auto { max_micro_lamports: u64 },
+}
+
+impl ComputeUnitPrice {
+    /// -- 自动模式是否需要查询最近的优先费
+    pub fn is_auto(&self) -> bool {
+        matches!(self, ComputeUnitPrice::Auto { .. })
+    }
+
+    /// -- 计算实际使用的价格（micro-lamports）
+    ///
+    /// # 参数
+    /// * `recent_fees` - 最近的优先费，仅自动模式使用
+    pub fn resolve(&self, recent_fees: &[u64]) -> u64 {
+        match *self {
+            ComputeUnitPrice::None => 0,
+            ComputeUnitPrice::Fixed(price) => price,
+            ComputeUnitPrice::Auto { max_micro_lamports } => {
+                let mut fees: Vec<u64> =
+                    recent_fees.iter().copied().filter(|fee| *fee > 0).collect();
+                if fees.is_empty() {
+                    return 0;
+                }
+                fees.sort_unstable();
+                fees[fees.len() / 2].min(max_micro_lamports)
+            }
+        }
+    }
+}
+
+impl FromStr for ComputeUnitPrice {
+    type Err = String;
+
+    /// -- 解析优先费参数：`auto`、`auto:<上限>`、`none` 或固定价格
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("none") || s == "0" {
+            return Ok(ComputeUnitPrice::None);
+        }
+        if s.eq_ignore_ascii_case("auto") {
+            return Ok(ComputeUnitPrice::Auto {
+                max_micro_lamports: DEFAULT_MAX_AUTO_PRICE,
+            });
+        }
+        if let Some(max) = s.strip_prefix("auto:") {
+            let max_micro_lamports = max
+                .parse()
+                .map_err(|_| format!("无效的优先费上限: {}", max))?;
+            return Ok(ComputeUnitPrice::Auto { max_micro_lamports });
+        }
+
+        s.parse()
+            .map(ComputeUnitPrice::Fixed)
+            .map_err(|_| format!("无效的优先费: {}，可选 auto、auto:<上限>、none 或数字", s))
+    }
+}
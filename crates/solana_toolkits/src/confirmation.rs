@@ -0,0 +1,191 @@
+use crate::{
+    config::{ConfirmationStrategy, TokenAccountConfig},
+    TokenAccountError, TokenAccountResult,
+};
+use solana_client::{
+    pubsub_client::{PubsubClient, SignatureSubscription},
+    rpc_client::SerializableTransaction,
+    rpc_config::RpcSignatureSubscribeConfig,
+    rpc_response::{ProcessedSignatureResult, RpcSignatureResult},
+};
+use solana_sdk::signature::Signature;
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+use utils::RpcApi;
+
+/// -- 默认的 websocket 确认超时时间
+pub const DEFAULT_CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// -- 默认的新区块哈希检查间隔
+pub const DEFAULT_REBROADCAST_INTERVAL: Duration = Duration::from_secs(2);
+
+/// -- 根据 RPC 地址推导 websocket 地址
+///
+/// `http` 换成 `ws`，`https` 换成 `wss`；本地节点的默认 RPC 端口 8899 换成 websocket 端口 8900。
+///
+/// # 参数
+/// * `rpc_url` - HTTP RPC 地址
+pub fn websocket_url(rpc_url: &str) -> String {
+    let url = if let Some(rest) = rpc_url.strip_prefix("https://") {
+        format!("wss://{}", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        format!("ws://{}", rest)
+    } else {
+        rpc_url.to_string()
+    };
+    url.replacen(":8899", ":8900", 1)
+}
+
+/// -- 按配置的确认方式发送交易并等待确认
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `transaction` - 已签名的交易（legacy 或 v0）
+/// * `config` - 管理器配置（确认方式、RPC 地址）
+///
+/// # 返回
+/// * `TokenAccountResult<Signature>` - 已确认的交易签名
+pub(crate) fn send_and_confirm<R: RpcApi>(
+    connection: &R,
+    transaction: &impl SerializableTransaction,
+    config: &TokenAccountConfig,
+) -> TokenAccountResult<Signature> {
+    match &config.confirmation {
+        ConfirmationStrategy::Rpc => connection
+            .send_and_confirm_transaction(transaction)
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string())),
+        ConfirmationStrategy::WebSocket {
+            ws_url,
+            timeout,
+            rebroadcast_interval,
+        } => {
+            let ws_url = ws_url
+                .clone()
+                .unwrap_or_else(|| default_websocket_url(config));
+            confirm_via_websocket(
+                connection,
+                transaction,
+                &ws_url,
+                *timeout,
+                *rebroadcast_interval,
+            )
+        }
+    }
+}
+
+/// -- 未配置 websocket 地址时的默认值
+///
+/// 依次使用 HELIUS_WS_RPC_URL 环境变量、`rpc_urls` 中的第一个地址、配置的集群推导。
+fn default_websocket_url(config: &TokenAccountConfig) -> String {
+    env::var("HELIUS_WS_RPC_URL").unwrap_or_else(|_| {
+        let rpc_url = config
+            .rpc_urls
+            .first()
+            .cloned()
+            .unwrap_or_else(|| config.cluster().url().to_string());
+        websocket_url(&rpc_url)
+    })
+}
+
+/// -- 通过 RPC 发送交易，在 websocket 上订阅签名状态等待确认
+///
+/// 先订阅再发送，避免交易在订阅建立前确认而错过通知。等待期间按间隔检查最新区块哈希，
+/// 出现新区块哈希时重新广播同一笔交易（签名不变，不会重复执行），超时后返回错误。
+fn confirm_via_websocket<R: RpcApi>(
+    connection: &R,
+    transaction: &impl SerializableTransaction,
+    ws_url: &str,
+    timeout: Duration,
+    rebroadcast_interval: Option<Duration>,
+) -> TokenAccountResult<Signature> {
+    let signature = *transaction.get_signature();
+    let subscription = PubsubClient::signature_subscribe(
+        ws_url,
+        &signature,
+        Some(RpcSignatureSubscribeConfig {
+            commitment: Some(connection.commitment()),
+            enable_received_notification: Some(false),
+        }),
+    )
+    .map_err(|e| TokenAccountError::TransactionError(format!("订阅交易签名失败: {}", e)))?;
+
+    let result = wait_for_confirmation(
+        connection,
+        transaction,
+        &subscription,
+        timeout,
+        rebroadcast_interval,
+    );
+
+    let (mut client, _) = subscription;
+    if let Err(e) = client.shutdown() {
+        warn!("关闭签名订阅失败: {:?}", e);
+    }
+    result
+}
+
+/// -- 发送交易并等待签名订阅的确认通知
+fn wait_for_confirmation<R: RpcApi>(
+    connection: &R,
+    transaction: &impl SerializableTransaction,
+    subscription: &SignatureSubscription,
+    timeout: Duration,
+    rebroadcast_interval: Option<Duration>,
+) -> TokenAccountResult<Signature> {
+    let signature = *transaction.get_signature();
+    let (_, receiver) = subscription;
+    connection
+        .send_transaction(transaction)
+        .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+    info!("交易已发送，等待 websocket 确认: {}", signature);
+
+    let deadline = Instant::now() + timeout;
+    let poll_interval = rebroadcast_interval.unwrap_or(timeout);
+    let mut last_blockhash = connection.get_latest_blockhash().ok();
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(TokenAccountError::TransactionError(format!(
+                "等待交易确认超时: {}",
+                signature
+            )));
+        }
+
+        match receiver.recv_timeout(remaining.min(poll_interval)) {
+            Ok(response) => match response.value {
+                RpcSignatureResult::ProcessedSignature(ProcessedSignatureResult { err: None }) => {
+                    return Ok(signature)
+                }
+                RpcSignatureResult::ProcessedSignature(ProcessedSignatureResult {
+                    err: Some(err),
+                }) => {
+                    return Err(TokenAccountError::TransactionError(format!(
+                        "交易执行失败: {}",
+                        err
+                    )))
+                }
+                RpcSignatureResult::ReceivedSignature(_) => {}
+            },
+            Err(e) if e.is_timeout() => {
+                if rebroadcast_interval.is_none() {
+                    continue;
+                }
+                let blockhash = connection.get_latest_blockhash().ok();
+                if blockhash.is_some() && blockhash != last_blockhash {
+                    last_blockhash = blockhash;
+                    if let Err(e) = connection.send_transaction(transaction) {
+                        warn!("重新广播交易失败: {}", e);
+                    }
+                }
+            }
+            Err(_) => {
+                return Err(TokenAccountError::TransactionError(String::from(
+                    "websocket 连接已断开",
+                )))
+            }
+        }
+    }
+}
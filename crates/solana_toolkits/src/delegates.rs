@@ -0,0 +1,187 @@
+use crate::account_info::{DelegatedAccountInfo, RevokeOutcome};
+use crate::metadata::fetch_token_infos;
+use crate::operations::{
+    payer_and_signers, submit_with_fresh_blockhash, with_compute_budget, Submission,
+    TOKEN_PROGRAM_IDS,
+};
+use crate::TokenAccountManager;
+use solana_account_decoder::UiAccountData;
+use solana_client::rpc_request::TokenAccountsFilter;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signer::Signer, transaction::Transaction,
+};
+use spl_token_2022::instruction::revoke;
+use std::str::FromStr;
+use tracing::{error, info, warn};
+use utils::{address_book::address_label, RpcApi, TokenAccountError, TokenAccountResult};
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 列出存在授权代理的代币账户
+    ///
+    /// 授权代理可以在不经过钱包签名的情况下转出代币，长期遗留的授权是常见的资产风险。
+    /// 会同时扫描 SPL Token 和 Token-2022 程序下的账户。
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Vec<DelegatedAccountInfo>>` - 存在授权代理的账户列表
+    pub async fn get_delegated_accounts(&self) -> TokenAccountResult<Vec<DelegatedAccountInfo>> {
+        let mut delegated = Vec::new();
+        for program_id in TOKEN_PROGRAM_IDS {
+            let accounts = self.connection.get_token_accounts_by_owner(
+                &self.wallet.pubkey(),
+                TokenAccountsFilter::ProgramId(program_id),
+            )?;
+
+            for account in accounts {
+                let UiAccountData::Json(parsed_data) = &account.account.data else {
+                    continue;
+                };
+                let info = &parsed_data.parsed["info"];
+                let (Some(mint), Some(delegate)) =
+                    (info["mint"].as_str(), info["delegate"].as_str())
+                else {
+                    continue;
+                };
+                let amount = |value: &serde_json::Value| {
+                    value["amount"]
+                        .as_str()
+                        .and_then(|amount| amount.parse().ok())
+                        .unwrap_or(0)
+                };
+
+                delegated.push(DelegatedAccountInfo {
+                    address: account.pubkey.clone(),
+                    mint: mint.to_string(),
+                    symbol: "unknown".to_string(),
+                    balance: amount(&info["tokenAmount"]),
+                    delegate: delegate.to_string(),
+                    delegated_amount: amount(&info["delegatedAmount"]),
+                    program_id: account.account.owner.clone(),
+                });
+            }
+        }
+
+        // -- 补充代币符号
+        let mints: Vec<String> = delegated
+            .iter()
+            .map(|account| account.mint.clone())
+            .collect();
+        let token_infos = fetch_token_infos(
+            &self.connection,
+            &self.metadata_cache,
+            &mints,
+            self.config.metadata_concurrency,
+            self.config.metadata_requests_per_second,
+        );
+        for account in &mut delegated {
+            if let Some(Some(metadata)) = token_infos.get(&account.mint) {
+                account.symbol = metadata.symbol.clone();
+            }
+        }
+
+        info!("存在授权代理的账户数: {}", delegated.len());
+        for account in &delegated {
+            info!(
+                "账户: {}, Symbol: {}, 代理: {}, 授权数量: {}",
+                account.address,
+                account.symbol,
+                address_label(&account.delegate),
+                account.delegated_amount
+            );
+        }
+
+        Ok(delegated)
+    }
+
+    /// -- 批量撤销授权代理
+    ///
+    /// 每 `batch_size` 个账户的撤销指令合并为一笔交易。
+    ///
+    /// # 参数
+    /// * `accounts` - 要撤销授权的账户列表（通常来自 [`Self::get_delegated_accounts`]）
+    /// * `batch_size` - 每笔交易包含的账户数量
+    ///
+    /// # 返回
+    /// * `Vec<RevokeOutcome>` - 每个账户的撤销结果
+    pub async fn revoke_delegates(
+        &self,
+        accounts: &[DelegatedAccountInfo],
+        batch_size: usize,
+    ) -> Vec<RevokeOutcome> {
+        let mut outcomes = Vec::with_capacity(accounts.len());
+
+        for chunk in accounts.chunks(batch_size.max(1)) {
+            let result = chunk
+                .iter()
+                .map(|account| {
+                    revoke_instruction(&self.wallet.pubkey(), &account.address, &account.program_id)
+                })
+                .collect::<TokenAccountResult<Vec<_>>>()
+                .and_then(|instructions| self.submit_revoke(instructions));
+
+            match &result {
+                Ok(submission) => info!(
+                    "已撤销 {} 个账户的授权代理，交易签名: {}",
+                    chunk.len(),
+                    submission.signature.as_deref().unwrap_or("-")
+                ),
+                Err(e) => error!("撤销授权失败: {}", e),
+            }
+
+            outcomes.extend(chunk.iter().map(|account| {
+                RevokeOutcome {
+                    account_address: account.address.clone(),
+                    delegate: account.delegate.clone(),
+                    success: result.is_ok(),
+                    signature: result
+                        .as_ref()
+                        .ok()
+                        .and_then(|submission| submission.signature.clone()),
+                    error: result.as_ref().err().map(|e| e.to_string()),
+                }
+            }));
+        }
+
+        let failed = outcomes.iter().filter(|outcome| !outcome.success).count();
+        if failed > 0 {
+            warn!("{} 个账户撤销授权失败", failed);
+        }
+        outcomes
+    }
+
+    /// -- 撤销单个账户的授权代理
+    pub(crate) fn revoke_delegate(
+        &self,
+        account_pubkey: &Pubkey,
+        program_id: &Pubkey,
+    ) -> TokenAccountResult<Submission> {
+        let payer = self.wallet.pubkey();
+        let instruction = revoke(program_id, account_pubkey, &payer, &[])?;
+        self.submit_revoke(vec![instruction])
+    }
+
+    /// -- 签名并提交撤销交易
+    fn submit_revoke(&self, instructions: Vec<Instruction>) -> TokenAccountResult<Submission> {
+        let instructions = with_compute_budget(&self.connection, &self.config, instructions);
+        let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+        submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+            Ok(Transaction::new_signed_with_payer(
+                &instructions,
+                Some(&payer),
+                &signers,
+                blockhash,
+            ))
+        })
+    }
+}
+
+/// -- 生成撤销授权指令
+fn revoke_instruction(
+    owner: &Pubkey,
+    address: &str,
+    program_id: &str,
+) -> TokenAccountResult<Instruction> {
+    let parse = |value: &str| {
+        Pubkey::from_str(value).map_err(|e| TokenAccountError::AccountParseError(e.to_string()))
+    };
+    Ok(revoke(&parse(program_id)?, &parse(address)?, owner, &[])?)
+}
@@ -0,0 +1,135 @@
+use crate::account_info::{
+    BurnAndCloseResult, FailureKind, SimulationReport, SwapSummary, TokenAccountDetails,
+};
+use crate::config::DustSwapConfig;
+use crate::operations::submit_transaction;
+use crate::TokenAccountManager;
+use jupiter_swap::{JupiterClient, JupiterError, QuoteRequest, SwapOptions};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signer::Signer};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use std::str::FromStr;
+use tracing::info;
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 设置剩余代币卖出配置
+    ///
+    /// # 参数
+    /// * `dust_swap` - 卖出配置，`None` 表示剩余代币直接销毁
+    pub fn set_dust_swap(&mut self, dust_swap: Option<DustSwapConfig>) {
+        self.config.dust_swap = dust_swap;
+    }
+
+    /// -- 尝试卖出剩余代币并将结果写入销毁并关闭结果
+    ///
+    /// 卖出成功后关闭账户（模拟模式下只模拟交换交易）。
+    ///
+    /// # 返回
+    /// * `bool` - 是否已处理该账户；返回 `false` 时调用方应继续销毁
+    pub(crate) async fn sell_into_result(
+        &self,
+        account_pubkey: &Pubkey,
+        details: &TokenAccountDetails,
+        result: &mut BurnAndCloseResult,
+    ) -> bool {
+        let Some(dust_swap) = &self.config.dust_swap else {
+            return false;
+        };
+
+        match self.sell_dust(account_pubkey, details, dust_swap).await {
+            Ok(Some((swap, simulation))) => {
+                result.swap = Some(swap);
+                result.simulation = Some(simulation);
+                if self.config.dry_run {
+                    // -- 模拟模式：卖出交易未执行，余额不为 0，无法继续模拟关闭
+                    result.success = true;
+                    result.rent_recovered = details.rent_lamports as f64 / LAMPORTS_PER_SOL as f64;
+                } else {
+                    self.close_into_result(account_pubkey, result).await;
+                }
+                true
+            }
+            Ok(None) => false,
+            Err(e) => {
+                result.error = Some(format!("卖出剩余代币失败: {}", e));
+                result.failure_kind = Some(FailureKind::from(&e));
+                true
+            }
+        }
+    }
+
+    /// -- 询价并卖出账户中的全部代币
+    ///
+    /// 以下情况返回 `None`，由调用方照常销毁：
+    /// - 账户不是钱包的关联代币账户（Jupiter 只从 ATA 扣款）
+    /// - Jupiter 没有可用路由
+    /// - 预计换得数量低于最低卖出价值
+    async fn sell_dust(
+        &self,
+        account_pubkey: &Pubkey,
+        details: &TokenAccountDetails,
+        dust_swap: &DustSwapConfig,
+    ) -> TokenAccountResult<Option<(SwapSummary, SimulationReport)>> {
+        let parse = |value: &str| {
+            Pubkey::from_str(value).map_err(|e| TokenAccountError::AccountParseError(e.to_string()))
+        };
+        let mint = parse(&details.mint)?;
+        let program_id = parse(&details.program_id)?;
+        let ata =
+            get_associated_token_address_with_program_id(&self.wallet.pubkey(), &mint, &program_id);
+        if ata != *account_pubkey {
+            info!("账户 {} 不是关联代币账户，跳过卖出", account_pubkey);
+            return Ok(None);
+        }
+
+        let client = JupiterClient::new(&dust_swap.api_url);
+        let request = QuoteRequest::new(&details.mint, dust_swap.output.mint(), details.balance)
+            .slippage_bps(dust_swap.slippage_bps);
+        let quote = match client.best_quote(&request).await {
+            Ok(quote) => quote,
+            Err(JupiterError::NoRoute | JupiterError::ApiError(_)) => {
+                info!("代币 {} 没有可用的卖出路由，改为销毁", details.mint);
+                return Ok(None);
+            }
+            Err(e) => return Err(TokenAccountError::TransactionError(e.to_string())),
+        };
+        if quote.out_amount() < dust_swap.min_output_amount {
+            info!(
+                "代币 {} 预计换得 {}，低于最低卖出价值 {}，改为销毁",
+                details.mint,
+                quote.out_amount(),
+                dust_swap.min_output_amount
+            );
+            return Ok(None);
+        }
+
+        let transaction = client
+            .build_swap_transaction(
+                &self.connection,
+                &self.wallet,
+                &quote,
+                &SwapOptions::default(),
+            )
+            .await
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+        let submission = submit_transaction(&self.connection, &transaction, &self.config)?;
+        info!(
+            "卖出 {} 个代币 {}，预计换得 {}，路由: {}",
+            details.balance,
+            details.mint,
+            quote.out_amount(),
+            quote.route_labels()
+        );
+
+        Ok(Some((
+            SwapSummary {
+                signature: submission.signature,
+                input_amount: details.balance,
+                output_mint: dust_swap.output.mint().to_string(),
+                output_amount: quote.out_amount(),
+                route: quote.route_labels(),
+            },
+            submission.simulation,
+        )))
+    }
+}
@@ -0,0 +1,123 @@
+use crate::account_info::{TokenAccountInfo, TokenAccountsResult, ZeroValueTokenInfo};
+use std::{
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::Path,
+};
+use utils::TokenAccountResult;
+
+/// -- CSV 表头
+const CSV_HEADER: &str =
+    "category,address,mint,symbol,balance,decimals,is_nft,rent_lamports,rent_sol,program_id,is_associated";
+
+impl TokenAccountsResult {
+    /// -- 导出扫描结果为 CSV
+    ///
+    /// 每个账户一行，`category` 列为账户分类：`closeable`、`zero_value`、`whitelisted`、`nft`、
+    /// `frozen`、`no_authority`、`wrapped_sol` 或 `suspected_spam`。余额为 0、冻结和关闭权限属于其他地址的账户的
+    /// `decimals`、`is_nft` 列为空。`is_associated` 列标记账户是否为钱包的关联代币账户（ATA）。
+    ///
+    /// # 参数
+    /// * `path` - 导出文件路径
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> TokenAccountResult<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// -- 将扫描结果以 CSV 写入任意输出
+    pub fn write_csv<W: Write>(&self, writer: &mut W) -> TokenAccountResult<()> {
+        writeln!(writer, "{}", CSV_HEADER)?;
+
+        for account in &self.accounts {
+            write_account_row(writer, "closeable", account)?;
+        }
+        for account in &self.zero_value_accounts_list {
+            write_token_row(writer, "zero_value", account)?;
+        }
+        for account in &self.whitelisted_accounts_list {
+            write_token_row(writer, "whitelisted", account)?;
+        }
+        for account in &self.nft_accounts_list {
+            write_token_row(writer, "nft", account)?;
+        }
+        for account in &self.frozen_accounts_list {
+            write_account_row(writer, "frozen", account)?;
+        }
+        for account in &self.no_authority_accounts_list {
+            write_account_row(writer, "no_authority", account)?;
+        }
+        for account in &self.wrapped_sol_accounts_list {
+            write_token_row(writer, "wrapped_sol", account)?;
+        }
+        for spam in &self.suspected_spam_accounts_list {
+            write_token_row(writer, "suspected_spam", &spam.account)?;
+        }
+
+        Ok(())
+    }
+
+    /// -- 导出扫描结果为 JSON，字段与结构体一致
+    ///
+    /// # 参数
+    /// * `path` - 导出文件路径
+    pub fn export_json(&self, path: impl AsRef<Path>) -> TokenAccountResult<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// -- 写入没有余额信息的账户
+fn write_account_row<W: Write>(
+    writer: &mut W,
+    category: &str,
+    account: &TokenAccountInfo,
+) -> TokenAccountResult<()> {
+    writeln!(
+        writer,
+        "{},{},{},{},0,,,{},{},{},{}",
+        category,
+        account.address,
+        account.mint,
+        escape(&account.symbol),
+        account.rent_lamports,
+        account.rent_sol,
+        account.program_id,
+        account.is_associated
+    )?;
+    Ok(())
+}
+
+/// -- 写入有余额的代币账户
+fn write_token_row<W: Write>(
+    writer: &mut W,
+    category: &str,
+    account: &ZeroValueTokenInfo,
+) -> TokenAccountResult<()> {
+    writeln!(
+        writer,
+        "{},{},{},{},{},{},{},{},{},{},{}",
+        category,
+        account.address,
+        account.mint,
+        escape(&account.symbol),
+        account.balance,
+        account.decimals,
+        account.is_nft,
+        account.rent_lamports,
+        account.rent_sol,
+        account.program_id,
+        account.is_associated
+    )?;
+    Ok(())
+}
+
+/// -- 转义 CSV 字段，代币符号来自链上元数据，可能包含逗号或引号
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,384 @@
+use crate::multisend::{resolve_address, MultisendConfig, MultisendRow};
+use crate::TokenAccountManager;
+use solana_sdk::{
+    native_token::{lamports_to_sol, sol_to_lamports},
+    pubkey::Pubkey,
+    signature::Signature,
+    signer::Signer,
+};
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+use tracing::{error, info, warn};
+use utils::{
+    address_book::{address_book, address_label},
+    RpcApi, TokenAccountError, TokenAccountResult,
+};
+
+/// -- 预留给资金钱包支付交易费的 SOL
+const FEE_RESERVE_SOL: f64 = 0.01;
+
+/// -- 轮询确认状态的间隔
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// -- 资金来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundingSource {
+    /// 通过 RPC 节点请求空投（devnet、testnet、本地验证器）
+    Airdrop,
+    /// 从管理器钱包转账（主网）
+    Transfer,
+}
+
+impl FundingSource {
+    /// -- 根据 RPC 地址推断资金来源
+    ///
+    /// devnet、testnet 和本地地址使用空投，其余一律视为主网使用转账。
+    pub fn detect(rpc_url: &str) -> Self {
+        let url = rpc_url.to_ascii_lowercase();
+        if ["devnet", "testnet", "localhost", "127.0.0.1"]
+            .iter()
+            .any(|keyword| url.contains(keyword))
+        {
+            Self::Airdrop
+        } else {
+            Self::Transfer
+        }
+    }
+}
+
+/// -- 注资配置
+#[derive(Debug, Clone)]
+pub struct FaucetConfig {
+    /// 资金来源
+    pub source: FundingSource,
+    /// 单次空投的最大 SOL 数量，超出部分拆分为多次空投
+    pub max_airdrop_sol: f64,
+    /// 两次空投请求之间的最小间隔，避免触发节点限流
+    pub request_interval: Duration,
+    /// 单次请求的最大重试次数
+    pub max_retries: u32,
+    /// 首次重试的等待时间，之后每次翻倍
+    pub retry_delay: Duration,
+    /// 等待空投确认的超时时间
+    pub confirm_timeout: Duration,
+}
+
+impl Default for FaucetConfig {
+    fn default() -> Self {
+        Self {
+            source: FundingSource::Airdrop,
+            max_airdrop_sol: 1.0,
+            request_interval: Duration::from_millis(1500),
+            max_retries: 5,
+            retry_delay: Duration::from_secs(2),
+            confirm_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// -- 注资目标
+///
+/// 对应列表中的一行：`pubkey[,target_sol]`，未填写目标时使用默认值；地址也可以填写地址簿中的名称
+#[derive(Debug, Clone)]
+pub struct FundingTarget {
+    pub pubkey: Pubkey,  // -- 钱包地址
+    pub target_sol: f64, // -- 目标余额（SOL）
+}
+
+/// -- 单个钱包的注资结果
+#[derive(Debug, Clone)]
+pub struct FundingResult {
+    pub pubkey: String,          // -- 钱包地址
+    pub name: Option<String>,    // -- 地址簿中的钱包名称
+    pub balance_before: f64,     // -- 注资前余额（SOL）
+    pub target_sol: f64,         // -- 目标余额（SOL）
+    pub funded_sol: f64,         // -- 实际注入的 SOL
+    pub success: bool,           // -- 是否达到目标余额
+    pub signatures: Vec<String>, // -- 空投或转账的交易签名
+    pub error: Option<String>,   // -- 失败时的错误信息
+}
+
+/// -- 注资报告
+#[derive(Debug, Default)]
+pub struct FundingReport {
+    pub total: usize,                // -- 目标钱包数量
+    pub funded: usize,               // -- 成功注资数量
+    pub skipped: usize,              // -- 余额已达标而跳过的数量
+    pub failed: usize,               // -- 失败数量
+    pub sol_sent: f64,               // -- 注入的 SOL 总量
+    pub results: Vec<FundingResult>, // -- 每个钱包的结果
+}
+
+/// -- 解析注资列表
+///
+/// 每行一个地址，可选以逗号分隔的目标余额；忽略空行、`#` 开头的注释行和 `pubkey` 表头。
+///
+/// # 参数
+/// * `content` - 列表文本内容
+/// * `default_target_sol` - 未填写目标余额时使用的默认值
+///
+/// # 返回
+/// * `TokenAccountResult<Vec<FundingTarget>>` - 解析后的注资目标
+pub fn parse_funding_list(
+    content: &str,
+    default_target_sol: f64,
+) -> TokenAccountResult<Vec<FundingTarget>> {
+    let mut targets = Vec::new();
+
+    for (line_no, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line_no == 0 && line.to_ascii_lowercase().starts_with("pubkey") {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let pubkey = resolve_address(fields[0]).map_err(|e| {
+            TokenAccountError::AccountParseError(format!("第 {} 行地址无效: {}", line_no + 1, e))
+        })?;
+        let target_sol = match fields.get(1).filter(|t| !t.is_empty()) {
+            Some(t) => t.parse().map_err(|_| {
+                TokenAccountError::AccountParseError(format!("第 {} 行目标余额无效", line_no + 1))
+            })?,
+            None => default_target_sol,
+        };
+        if target_sol <= 0.0 {
+            return Err(TokenAccountError::AccountParseError(format!(
+                "第 {} 行目标余额必须大于 0",
+                line_no + 1
+            )));
+        }
+
+        targets.push(FundingTarget { pubkey, target_sol });
+    }
+
+    Ok(targets)
+}
+
+/// -- 从文件读取注资列表
+pub fn read_funding_list<P: AsRef<Path>>(
+    path: P,
+    default_target_sol: f64,
+) -> TokenAccountResult<Vec<FundingTarget>> {
+    parse_funding_list(&fs::read_to_string(path)?, default_target_sol)
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 为一组钱包注资到目标余额
+    ///
+    /// 先查询每个钱包的当前余额，只补足差额，已达标的钱包直接跳过。
+    /// 空投模式按 `max_airdrop_sol` 拆分请求，请求之间保持固定间隔，
+    /// 失败时指数退避重试；转账模式复用批量转账从管理器钱包打包发送。
+    ///
+    /// # 参数
+    /// * `targets` - 注资目标
+    /// * `faucet_config` - 注资配置
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<FundingReport>` - 每个钱包的注资结果报告
+    pub async fn fund_wallets(
+        &self,
+        targets: &[FundingTarget],
+        faucet_config: &FaucetConfig,
+    ) -> TokenAccountResult<FundingReport> {
+        let mut report = FundingReport {
+            total: targets.len(),
+            ..Default::default()
+        };
+        if targets.is_empty() {
+            warn!("没有需要注资的钱包");
+            return Ok(report);
+        }
+
+        // -- 计算每个钱包的差额
+        let mut pending = Vec::new();
+        for target in targets {
+            let lamports = self.connection.get_balance(&target.pubkey)?;
+            let missing = sol_to_lamports(target.target_sol).saturating_sub(lamports);
+            let balance = lamports_to_sol(lamports);
+            let result = FundingResult {
+                pubkey: target.pubkey.to_string(),
+                name: address_book().name(&target.pubkey).map(str::to_string),
+                balance_before: balance,
+                target_sol: target.target_sol,
+                funded_sol: 0.0,
+                success: true,
+                signatures: Vec::new(),
+                error: None,
+            };
+
+            if missing == 0 {
+                info!(
+                    "{} 余额 {:.6} SOL 已达标，跳过",
+                    address_label(&result.pubkey),
+                    balance
+                );
+                report.skipped += 1;
+                report.results.push(result);
+            } else {
+                pending.push((target.pubkey, missing, result));
+            }
+        }
+
+        match faucet_config.source {
+            FundingSource::Airdrop => self.fund_by_airdrop(&mut pending, faucet_config).await,
+            FundingSource::Transfer => self.fund_by_transfer(&mut pending).await?,
+        }
+
+        for (_, _, result) in pending {
+            if result.success {
+                report.funded += 1;
+            } else {
+                report.failed += 1;
+            }
+            report.sol_sent += result.funded_sol;
+            report.results.push(result);
+        }
+
+        info!("\n{}", "=".repeat(50));
+        info!("钱包注资完成");
+        info!("{}", "=".repeat(50));
+        info!(
+            "注资: {}, 跳过: {}, 失败: {}",
+            report.funded, report.skipped, report.failed
+        );
+        info!("注入 SOL: {:.6}", report.sol_sent);
+
+        Ok(report)
+    }
+
+    /// -- 逐个钱包请求空投，按单次上限拆分
+    async fn fund_by_airdrop(
+        &self,
+        pending: &mut [(Pubkey, u64, FundingResult)],
+        faucet_config: &FaucetConfig,
+    ) {
+        let max_lamports = sol_to_lamports(faucet_config.max_airdrop_sol).max(1);
+        let mut first_request = true;
+
+        for (pubkey, missing, result) in pending.iter_mut() {
+            let mut remaining = *missing;
+            while remaining > 0 {
+                if !first_request {
+                    tokio::time::sleep(faucet_config.request_interval).await;
+                }
+                first_request = false;
+
+                let amount = remaining.min(max_lamports);
+                match self.airdrop_with_retry(pubkey, amount, faucet_config).await {
+                    Ok(signature) => {
+                        info!(
+                            "{} 空投 {:.6} SOL 成功: {}",
+                            address_label(&result.pubkey),
+                            lamports_to_sol(amount),
+                            signature
+                        );
+                        result.signatures.push(signature.to_string());
+                        result.funded_sol += lamports_to_sol(amount);
+                        remaining -= amount;
+                    }
+                    Err(e) => {
+                        error!("{} 空投失败: {}", address_label(&result.pubkey), e);
+                        result.success = false;
+                        result.error = Some(e.to_string());
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// -- 请求一次空投并等待确认，失败时指数退避重试
+    async fn airdrop_with_retry(
+        &self,
+        pubkey: &Pubkey,
+        lamports: u64,
+        faucet_config: &FaucetConfig,
+    ) -> TokenAccountResult<Signature> {
+        let mut retries = 0;
+        let mut delay = faucet_config.retry_delay;
+        loop {
+            let result = match self.connection.request_airdrop(pubkey, lamports) {
+                Ok(signature) => self.wait_for_confirmation(&signature, faucet_config).await,
+                Err(e) => Err(TokenAccountError::TransactionError(e.to_string())),
+            };
+
+            match result {
+                Ok(signature) => return Ok(signature),
+                Err(e) if retries < faucet_config.max_retries => {
+                    retries += 1;
+                    warn!("重试第 {} 次（{:?} 后）: {}", retries, delay, e);
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// -- 轮询交易确认状态直到超时
+    async fn wait_for_confirmation(
+        &self,
+        signature: &Signature,
+        faucet_config: &FaucetConfig,
+    ) -> TokenAccountResult<Signature> {
+        let started = Instant::now();
+        loop {
+            if self.connection.confirm_transaction(signature)? {
+                return Ok(*signature);
+            }
+            if started.elapsed() >= faucet_config.confirm_timeout {
+                return Err(TokenAccountError::TransactionError(format!(
+                    "空投确认超时: {}",
+                    signature
+                )));
+            }
+            tokio::time::sleep(CONFIRM_POLL_INTERVAL).await;
+        }
+    }
+
+    /// -- 从管理器钱包批量转账补足差额
+    async fn fund_by_transfer(
+        &self,
+        pending: &mut [(Pubkey, u64, FundingResult)],
+    ) -> TokenAccountResult<()> {
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let needed = lamports_to_sol(pending.iter().map(|(_, missing, _)| missing).sum());
+        let available = lamports_to_sol(self.connection.get_balance(&self.wallet.pubkey())?);
+        if available < needed + FEE_RESERVE_SOL {
+            return Err(TokenAccountError::TransactionError(format!(
+                "资金钱包余额不足: 需要 {:.6} SOL（另需预留 {} SOL 手续费），当前 {:.6} SOL",
+                needed, FEE_RESERVE_SOL, available
+            )));
+        }
+
+        let rows: Vec<MultisendRow> = pending
+            .iter()
+            .map(|(pubkey, missing, _)| MultisendRow {
+                recipient: *pubkey,
+                amount: lamports_to_sol(*missing),
+                mint: None,
+            })
+            .collect();
+        let transfers = self.multisend(&rows, &MultisendConfig::default()).await?;
+
+        for ((_, missing, result), transfer) in pending.iter_mut().zip(transfers.results) {
+            result.success = transfer.success;
+            result.error = transfer.error;
+            if transfer.success {
+                result.funded_sol = lamports_to_sol(*missing);
+            }
+            result.signatures.extend(transfer.signature);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use crate::account_info::ScanItem;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use std::{
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tracing::warn;
+use utils::RpcApi;
+
+pub use regex::Regex;
+
+/// -- 账户扫描过滤条件
+///
+/// 设置到 [`TokenAccountConfig::scan_filter`](crate::config::TokenAccountConfig::scan_filter) 后，
+/// `get_closeable_accounts` 和 `scan_closeable_accounts` 只返回满足全部条件的账户，未设置的条件不参与过滤。
+/// 过滤在分类之后进行，白名单、黑名单和 NFT 保护规则照常生效。
+///
+/// # 示例
+/// ```no_run
+/// use solana_toolkits::{config::TokenAccountConfig, filter::{Regex, ScanFilter}};
+/// use std::time::Duration;
+///
+/// // -- 只处理 90 天前创建、符号像空投垃圾币的关联代币账户
+/// let filter = ScanFilter {
+///     symbol_pattern: Some(Regex::new("(?i)claim|airdrop|reward").unwrap()),
+///     only_associated: true,
+///     ..ScanFilter::default()
+/// }
+/// .older_than(Duration::from_secs(90 * 24 * 3600));
+/// let config = TokenAccountConfig {
+///     scan_filter: filter,
+///     ..TokenAccountConfig::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScanFilter {
+    /// 只保留这些 Mint 的账户，为空表示不限制
+    pub include_mints: Vec<String>,
+    /// 排除这些 Mint 的账户
+    pub exclude_mints: Vec<String>,
+    /// 最低租金（lamports），租金更低的账户被排除
+    pub min_rent_lamports: Option<u64>,
+    /// 只保留在该时间（Unix 时间戳，秒）之前创建的账户
+    ///
+    /// 创建时间取账户最早一笔交易的区块时间，每个候选账户需要一次 `getSignaturesForAddress` 查询。
+    /// 交易超过 1000 笔的账户以返回的最早一笔为准，实际创建时间只会更早。查询失败的账户被排除。
+    pub created_before: Option<i64>,
+    /// 代币符号需要匹配的正则表达式，没有元数据的代币符号为 `unknown`
+    pub symbol_pattern: Option<Regex>,
+    /// 只保留钱包的关联代币账户（ATA）
+    pub only_associated: bool,
+    /// 只保留非关联代币账户（辅助账户），钱包的 ATA 即使余额为 0 也不处理
+    pub only_auxiliary: bool,
+}
+
+impl ScanFilter {
+    /// -- 只保留至少在 `age` 之前创建的账户
+    ///
+    /// # 参数
+    /// * `age` - 账户的最小存在时间
+    pub fn older_than(mut self, age: Duration) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        self.created_before = Some(now.saturating_sub(age).as_secs() as i64);
+        self
+    }
+
+    /// -- 是否未设置任何过滤条件
+    pub fn is_empty(&self) -> bool {
+        self.include_mints.is_empty()
+            && self.exclude_mints.is_empty()
+            && self.min_rent_lamports.is_none()
+            && self.created_before.is_none()
+            && self.symbol_pattern.is_none()
+            && !self.only_associated
+            && !self.only_auxiliary
+    }
+
+    /// -- 检查 Mint 是否满足包含和排除列表
+    ///
+    /// 扫描时先用该方法跳过被排除的 Mint，不为它们查询元数据。
+    pub fn allows_mint(&self, mint: &str) -> bool {
+        (self.include_mints.is_empty() || self.include_mints.iter().any(|m| m == mint))
+            && !self.exclude_mints.iter().any(|m| m == mint)
+    }
+
+    /// -- 检查分类后的账户是否满足全部过滤条件
+    ///
+    /// 先检查本地条件，全部满足后才查询创建时间。
+    ///
+    /// # 参数
+    /// * `connection` - RPC 客户端，查询账户创建时间
+    /// * `owner` - 钱包地址，用于判断关联代币账户
+    /// * `item` - 账户分类结果
+    pub fn matches(&self, connection: &impl RpcApi, owner: &Pubkey, item: &ScanItem) -> bool {
+        if !self.allows_mint(item.mint()) {
+            return false;
+        }
+        if self
+            .min_rent_lamports
+            .is_some_and(|min| item.rent_lamports() < min)
+        {
+            return false;
+        }
+        if self
+            .symbol_pattern
+            .as_ref()
+            .is_some_and(|pattern| !pattern.is_match(item.symbol()))
+        {
+            return false;
+        }
+        if self.only_associated || self.only_auxiliary {
+            let associated = is_associated_account(owner, item);
+            if (self.only_associated && !associated) || (self.only_auxiliary && associated) {
+                return false;
+            }
+        }
+        match self.created_before {
+            Some(cutoff) => created_before(connection, item.address(), cutoff),
+            None => true,
+        }
+    }
+}
+
+/// -- 账户地址是否为钱包在该 Mint 和代币程序下的关联代币账户
+fn is_associated_account(owner: &Pubkey, item: &ScanItem) -> bool {
+    is_associated_address(owner, item.address(), item.mint(), item.program_id())
+}
+
+/// -- 根据钱包、Mint 和代币程序推导关联代币账户地址，判断是否与账户地址一致
+///
+/// # 参数
+/// * `owner` - 钱包地址
+/// * `address` - 代币账户地址
+/// * `mint` - 代币 Mint 地址
+/// * `program_id` - 代币程序 ID
+pub(crate) fn is_associated_address(
+    owner: &Pubkey,
+    address: &str,
+    mint: &str,
+    program_id: &str,
+) -> bool {
+    let (Ok(mint), Ok(program_id)) = (Pubkey::from_str(mint), Pubkey::from_str(program_id)) else {
+        return false;
+    };
+    get_associated_token_address_with_program_id(owner, &mint, &program_id).to_string() == address
+}
+
+/// -- 账户最早一笔交易的区块时间是否早于 `cutoff`
+fn created_before(connection: &impl RpcApi, address: &str, cutoff: i64) -> bool {
+    let Ok(pubkey) = Pubkey::from_str(address) else {
+        return false;
+    };
+    match connection.get_signatures_for_address(&pubkey) {
+        Ok(signatures) => signatures
+            .last()
+            .and_then(|oldest| oldest.block_time)
+            .is_some_and(|block_time| block_time < cutoff),
+        Err(e) => {
+            warn!("查询账户 {} 的创建时间失败: {}", address, e);
+            false
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use crate::account_info::BatchCloseReport;
+use crate::TokenAccountManager;
+use event_store::{EventStore, StoreError, StoredEvent};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signer::Signer};
+use std::path::Path;
+use time::{Date, Month};
+use tracing::{info, warn};
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 清理操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunOperation {
+    /// 关闭余额为 0 的账户
+    Close,
+    /// 销毁并关闭零值代币账户
+    Burn,
+    /// 转出剩余代币并关闭账户
+    Sweep,
+    /// 广播离线签名的关闭交易
+    Broadcast,
+}
+
+/// -- 单次清理运行的记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub wallet: String,               // -- 钱包地址
+    pub operation: RunOperation,      // -- 操作类型
+    pub accounts_closed: usize,       // -- 成功处理的账户数量
+    pub accounts_failed: usize,       // -- 失败的账户数量
+    pub signatures: Vec<String>,      // -- 发送的全部交易签名
+    pub rent_recovered_lamports: u64, // -- 回收的租金（lamports）
+    pub fees_lamports: u64,           // -- 交易费用（lamports）
+}
+
+impl RunRecord {
+    /// -- 根据批量处理报告生成记录
+    pub fn from_report(wallet: &str, operation: RunOperation, report: &BatchCloseReport) -> Self {
+        Self {
+            wallet: wallet.to_string(),
+            operation,
+            accounts_closed: report.succeeded,
+            accounts_failed: report.failed,
+            signatures: report.signatures.clone(),
+            rent_recovered_lamports: report.rent_recovered_lamports,
+            fees_lamports: report.fees_lamports,
+        }
+    }
+}
+
+/// -- 一段时间内的运行汇总
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RunSummary {
+    pub runs: usize,                  // -- 运行次数
+    pub accounts_closed: usize,       // -- 成功处理的账户总数
+    pub accounts_failed: usize,       // -- 失败的账户总数
+    pub transactions: usize,          // -- 发送的交易总数
+    pub rent_recovered_lamports: u64, // -- 回收的租金总量（lamports）
+    pub rent_recovered_sol: f64,      // -- 回收的租金总量（SOL）
+    pub fees_lamports: u64,           // -- 交易费用总量（lamports）
+    pub fees_sol: f64,                // -- 交易费用总量（SOL）
+}
+
+impl RunSummary {
+    /// -- 汇总多条运行记录
+    pub fn from_records<'a>(records: impl IntoIterator<Item = &'a RunRecord>) -> Self {
+        let mut summary = Self::default();
+        for record in records {
+            summary.runs += 1;
+            summary.accounts_closed += record.accounts_closed;
+            summary.accounts_failed += record.accounts_failed;
+            summary.transactions += record.signatures.len();
+            summary.rent_recovered_lamports += record.rent_recovered_lamports;
+            summary.fees_lamports += record.fees_lamports;
+        }
+        summary.rent_recovered_sol =
+            summary.rent_recovered_lamports as f64 / LAMPORTS_PER_SOL as f64;
+        summary.fees_sol = summary.fees_lamports as f64 / LAMPORTS_PER_SOL as f64;
+        summary
+    }
+}
+
+/// -- 本地清理运行历史
+///
+/// 每次批量关闭、销毁、转出或广播完成后追加一条 [`RunRecord`]，以 JSONL 格式保存在
+/// [`EventStore`] 中，时间戳和哈希链由事件存储维护。可以按时间范围查询和汇总，
+/// 例如统计本月回收了多少租金。
+pub struct RunHistory {
+    store: EventStore<RunRecord>, // -- 底层事件存储
+}
+
+impl RunHistory {
+    /// -- 打开（或创建）运行历史
+    ///
+    /// # 参数
+    /// * `path` - 历史文件路径，父目录不存在时会自动创建
+    pub fn open(path: impl AsRef<Path>) -> TokenAccountResult<Self> {
+        Ok(Self {
+            store: EventStore::open(path).map_err(store_error)?,
+        })
+    }
+
+    /// -- 历史文件路径
+    pub fn path(&self) -> &Path {
+        self.store.path()
+    }
+
+    /// -- 追加一条运行记录
+    pub fn record(&self, record: RunRecord) -> TokenAccountResult<StoredEvent<RunRecord>> {
+        self.store.append(record).map_err(store_error)
+    }
+
+    /// -- 按写入顺序返回全部运行记录
+    pub fn runs(&self) -> TokenAccountResult<Vec<StoredEvent<RunRecord>>> {
+        self.store.replay().map_err(store_error)
+    }
+
+    /// -- 返回时间戳在 `[from, to)` 范围内的运行记录
+    ///
+    /// # 参数
+    /// * `from` - 起始时间（Unix 秒，包含）
+    /// * `to` - 结束时间（Unix 秒，不包含）
+    pub fn runs_between(
+        &self,
+        from: u64,
+        to: u64,
+    ) -> TokenAccountResult<Vec<StoredEvent<RunRecord>>> {
+        Ok(self
+            .runs()?
+            .into_iter()
+            .filter(|run| (from..to).contains(&run.timestamp))
+            .collect())
+    }
+
+    /// -- 汇总时间戳在 `[from, to)` 范围内的运行记录
+    pub fn summary_between(&self, from: u64, to: u64) -> TokenAccountResult<RunSummary> {
+        let runs = self.runs_between(from, to)?;
+        Ok(RunSummary::from_records(runs.iter().map(|run| &run.event)))
+    }
+
+    /// -- 汇总指定月份（UTC）的运行记录
+    ///
+    /// # 参数
+    /// * `year` - 年份
+    /// * `month` - 月份（1-12）
+    pub fn monthly_summary(&self, year: i32, month: u8) -> TokenAccountResult<RunSummary> {
+        let (from, to) = month_range(year, month)?;
+        self.summary_between(from, to)
+    }
+}
+
+/// -- 指定月份（UTC）的起止时间（Unix 秒），结束时间不包含
+pub fn month_range(year: i32, month: u8) -> TokenAccountResult<(u64, u64)> {
+    let invalid = || TokenAccountError::Other(format!("无效的月份: {}-{}", year, month));
+    let month = Month::try_from(month).map_err(|_| invalid())?;
+    let start = Date::from_calendar_date(year, month, 1).map_err(|_| invalid())?;
+    let (next_year, next_month) = match month {
+        Month::December => (year + 1, Month::January),
+        _ => (year, month.next()),
+    };
+    let end = Date::from_calendar_date(next_year, next_month, 1).map_err(|_| invalid())?;
+    let timestamp = |date: Date| date.midnight().assume_utc().unix_timestamp().max(0) as u64;
+    Ok((timestamp(start), timestamp(end)))
+}
+
+/// -- 将事件存储错误转换为工具包错误
+fn store_error(error: StoreError) -> TokenAccountError {
+    TokenAccountError::Other(format!("运行历史存储错误: {}", error))
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 将批量处理结果追加到运行历史
+    ///
+    /// 未配置 `history_path`、模拟执行或没有处理任何账户时不记录；写入失败只输出警告，不影响处理结果。
+    pub(crate) fn record_history(&self, operation: RunOperation, report: &BatchCloseReport) {
+        let Some(path) = &self.config.history_path else {
+            return;
+        };
+        if report.simulated || report.outcomes.is_empty() {
+            return;
+        }
+
+        let record = RunRecord::from_report(&self.wallet.pubkey().to_string(), operation, report);
+        match RunHistory::open(path).and_then(|history| history.record(record)) {
+            Ok(stored) => info!("已写入运行历史 #{}: {}", stored.sequence, path.display()),
+            Err(e) => warn!("{}", e),
+        }
+    }
+}
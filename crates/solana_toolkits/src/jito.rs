@@ -0,0 +1,428 @@
+use crate::account_info::{AccountOutcome, TokenAccountInfo, ZeroValueTokenInfo};
+use crate::operations::{
+    burn_and_close_instructions, close_instructions, is_native_mint, payer_and_signers, preflight,
+    with_compute_budget, BurnTarget,
+};
+use crate::TokenAccountManager;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Value};
+use solana_client::client_error::ClientErrorKind;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signature, signer::Signer,
+    system_instruction, transaction::Transaction,
+};
+use spl_token_2022::instruction::close_account;
+use std::{
+    str::FromStr,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tracing::{error, info, warn};
+use utils::{audit, RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 默认的主网 block engine 地址
+pub const DEFAULT_BLOCK_ENGINE_URL: &str = "https://mainnet.block-engine.jito.wtf";
+
+/// -- 单个 bundle 最多包含的交易数量
+pub const MAX_BUNDLE_TRANSACTIONS: usize = 5;
+
+/// -- Jito 小费账户，随机选择其中一个以减少写锁竞争
+const JITO_TIP_ACCOUNTS: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+/// -- 等待 bundle 上链的超时时间
+const BUNDLE_CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// -- 轮询 bundle 确认状态的间隔
+const CONFIRM_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// -- 生成向随机 Jito 小费账户转账的指令
+///
+/// # 参数
+/// * `payer` - 支付小费的钱包
+/// * `tip_lamports` - 小费金额（lamports）
+pub fn tip_instruction(payer: &Pubkey, tip_lamports: u64) -> Instruction {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos() as usize)
+        .unwrap_or_default();
+    let tip_account = Pubkey::from_str(JITO_TIP_ACCOUNTS[nanos % JITO_TIP_ACCOUNTS.len()])
+        .expect("Jito 小费账户地址有效");
+    system_instruction::transfer(payer, &tip_account, tip_lamports)
+}
+
+/// -- Jito block engine 客户端
+pub struct JitoClient {
+    http: reqwest::Client,
+    block_engine_url: String,
+}
+
+impl JitoClient {
+    /// -- 使用指定 block engine 地址创建客户端
+    pub fn new(block_engine_url: &str) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            block_engine_url: block_engine_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// -- 发送 bundle
+    ///
+    /// bundle 中的交易按顺序原子执行，要么全部上链，要么全部丢弃。
+    /// bundle 不经过 `RpcApi` 发送，提交后在这里把每笔交易写入审计日志。
+    ///
+    /// # 参数
+    /// * `transactions` - 已签名的交易，最多 [`MAX_BUNDLE_TRANSACTIONS`] 笔
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<String>` - block engine 返回的 bundle ID
+    pub async fn send_bundle(&self, transactions: &[Transaction]) -> TokenAccountResult<String> {
+        if transactions.is_empty() || transactions.len() > MAX_BUNDLE_TRANSACTIONS {
+            return Err(TokenAccountError::TransactionError(format!(
+                "bundle 交易数量必须在 1 到 {} 之间",
+                MAX_BUNDLE_TRANSACTIONS
+            )));
+        }
+
+        let encoded = transactions
+            .iter()
+            .map(|tx| {
+                bincode::serialize(tx)
+                    .map(|bytes| STANDARD.encode(bytes))
+                    .map_err(|e| TokenAccountError::TransactionError(e.to_string()))
+            })
+            .collect::<TokenAccountResult<Vec<_>>>()?;
+
+        let result = self.post_bundle(encoded).await;
+        for transaction in transactions {
+            let sent = match &result {
+                Ok(_) => Ok(transaction.signatures[0]),
+                Err(e) => Err(ClientErrorKind::Custom(e.to_string()).into()),
+            };
+            audit::record_transaction(transaction, &sent);
+        }
+        result
+    }
+
+    /// -- 向 block engine 提交已编码的交易，返回 bundle ID
+    async fn post_bundle(&self, encoded: Vec<String>) -> TokenAccountResult<String> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "sendBundle",
+            "params": [encoded, { "encoding": "base64" }],
+        });
+
+        let response: Value = self
+            .http
+            .post(format!("{}/api/v1/bundles", self.block_engine_url))
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| TokenAccountError::TransactionError(format!("发送 bundle 失败: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| TokenAccountError::TransactionError(format!("解析响应失败: {}", e)))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(TokenAccountError::TransactionError(format!(
+                "block engine 拒绝 bundle: {}",
+                error
+            )));
+        }
+        response["result"]
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| TokenAccountError::TransactionError("响应中缺少 bundle ID".to_string()))
+    }
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 以 Jito bundle 批量关闭账户
+    ///
+    /// 每 `batch_size` 个账户合并为一笔关闭交易，再按 bundle 提交。
+    ///
+    /// # 参数
+    /// * `accounts` - 要关闭的账户列表
+    /// * `batch_size` - 每笔交易包含的账户数量
+    /// * `tip_lamports` - 每个 bundle 的小费（lamports）
+    /// * `block_engine_url` - block engine 地址
+    ///
+    /// # 返回
+    /// * `Vec<AccountOutcome>` - 每个账户的处理结果
+    pub(crate) async fn batch_close_via_jito(
+        &self,
+        accounts: &[TokenAccountInfo],
+        batch_size: usize,
+        tip_lamports: u64,
+        block_engine_url: &str,
+    ) -> Vec<AccountOutcome> {
+        let owner = self.wallet.pubkey();
+        let mut outcomes = Vec::new();
+        let mut groups = Vec::new();
+
+        for chunk in accounts.chunks(batch_size.max(1)) {
+            match close_instructions(&owner, chunk) {
+                Ok(instructions) => groups.push((
+                    instructions,
+                    chunk
+                        .iter()
+                        .map(|account| AccountOutcome {
+                            account_address: account.address.clone(),
+                            mint: account.mint.clone(),
+                            symbol: account.symbol.clone(),
+                            success: true,
+                            signature: None,
+                            burn_signature: None,
+                            burned_amount: 0,
+                            swept_amount: 0,
+                            swap: None,
+                            unwrapped_lamports: 0,
+                            rent_lamports: account.rent_lamports,
+                            error: None,
+                            failure_kind: None,
+                            simulation: None,
+                            already_closed: false,
+                        })
+                        .collect(),
+                )),
+                Err(e) => outcomes.extend(chunk.iter().map(|account| {
+                    AccountOutcome::failed(&account.address, &account.mint, &account.symbol, &e)
+                })),
+            }
+        }
+
+        outcomes.extend(
+            self.submit_in_bundles(groups, tip_lamports, block_engine_url)
+                .await,
+        );
+        outcomes
+    }
+
+    /// -- 以 Jito bundle 批量销毁并关闭零值代币账户
+    ///
+    /// 每个账户生成一笔“销毁 + 关闭”交易，销毁数量使用扫描时记录的余额。
+    /// wSOL 账户只生成关闭指令，包装的 SOL 直接返还钱包。
+    ///
+    /// # 参数
+    /// * `accounts` - 要处理的零值代币账户列表
+    /// * `tip_lamports` - 每个 bundle 的小费（lamports）
+    /// * `block_engine_url` - block engine 地址
+    ///
+    /// # 返回
+    /// * `Vec<AccountOutcome>` - 每个账户的处理结果
+    pub(crate) async fn batch_burn_and_close_via_jito(
+        &self,
+        accounts: &[ZeroValueTokenInfo],
+        tip_lamports: u64,
+        block_engine_url: &str,
+    ) -> Vec<AccountOutcome> {
+        let owner = self.wallet.pubkey();
+        let mut outcomes = Vec::new();
+        let mut groups = Vec::new();
+
+        for account in accounts {
+            let parse = |value: &str| {
+                Pubkey::from_str(value)
+                    .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))
+            };
+            let native = is_native_mint(&account.mint);
+            let instructions = parse(&account.address).and_then(|pubkey| {
+                if native {
+                    // -- wSOL 账户只关闭（解包），不销毁
+                    let program_id = parse(&account.program_id)?;
+                    return Ok(vec![close_account(
+                        &program_id,
+                        &pubkey,
+                        &owner,
+                        &owner,
+                        &[&owner],
+                    )?]);
+                }
+                let target = BurnTarget::resolve(
+                    &self.connection,
+                    &account.address,
+                    &account.mint,
+                    &account.program_id,
+                    account.balance,
+                    account.expected_decimals(),
+                )?;
+                burn_and_close_instructions(&owner, &target)
+            });
+
+            match instructions {
+                Ok(instructions) => groups.push((
+                    instructions,
+                    vec![AccountOutcome {
+                        account_address: account.address.clone(),
+                        mint: account.mint.clone(),
+                        symbol: account.symbol.clone(),
+                        success: true,
+                        signature: None,
+                        burn_signature: None,
+                        burned_amount: if native { 0 } else { account.balance },
+                        swept_amount: 0,
+                        swap: None,
+                        unwrapped_lamports: if native { account.balance } else { 0 },
+                        rent_lamports: account.rent_lamports,
+                        error: None,
+                        failure_kind: None,
+                        simulation: None,
+                        already_closed: false,
+                    }],
+                )),
+                Err(e) => outcomes.push(AccountOutcome::failed(
+                    &account.address,
+                    &account.mint,
+                    &account.symbol,
+                    &e,
+                )),
+            }
+        }
+
+        let mut submitted = self
+            .submit_in_bundles(groups, tip_lamports, block_engine_url)
+            .await;
+        // -- 销毁与关闭在同一笔交易中完成
+        for outcome in &mut submitted {
+            outcome.burn_signature = outcome.signature.clone();
+        }
+        outcomes.extend(submitted);
+        outcomes
+    }
+
+    /// -- 以 Jito bundle 提交多笔交易
+    ///
+    /// 每个分组对应一笔交易及其包含的账户结果，每 [`MAX_BUNDLE_TRANSACTIONS`] 笔交易组成一个 bundle，
+    /// 小费附加在 bundle 的最后一笔交易中。bundle 上链后写入交易签名，
+    /// 重试耗尽仍失败时把该 bundle 内的账户标记为失败。
+    ///
+    /// # 参数
+    /// * `groups` - (交易指令, 该交易处理的账户结果) 列表，结果预先标记为成功
+    /// * `tip_lamports` - 每个 bundle 的小费（lamports）
+    /// * `block_engine_url` - block engine 地址
+    ///
+    /// # 返回
+    /// * `Vec<AccountOutcome>` - 所有账户的处理结果
+    pub(crate) async fn submit_in_bundles(
+        &self,
+        groups: Vec<(Vec<Instruction>, Vec<AccountOutcome>)>,
+        tip_lamports: u64,
+        block_engine_url: &str,
+    ) -> Vec<AccountOutcome> {
+        let client = JitoClient::new(block_engine_url);
+        let mut outcomes = Vec::new();
+
+        for (i, bundle) in groups.chunks(MAX_BUNDLE_TRANSACTIONS).enumerate() {
+            info!("\n提交第 {} 个 bundle, 共 {} 笔交易", i + 1, bundle.len());
+
+            let started = Instant::now();
+            let mut retries = 0;
+            let result = loop {
+                let e = match self
+                    .send_bundle_and_confirm(&client, bundle, tip_lamports)
+                    .await
+                {
+                    Ok(signatures) => break Ok(signatures),
+                    Err(e) => e,
+                };
+                match self.config.retry_policy.next_delay(retries, started, &e) {
+                    Some(delay) => {
+                        retries += 1;
+                        warn!("bundle 重试第 {} 次（{:?} 后）: {}", retries, delay, e);
+                        tokio::time::sleep(delay).await;
+                    }
+                    None => break Err(e),
+                }
+            };
+
+            match result {
+                Ok(signatures) => {
+                    for ((_, group), signature) in bundle.iter().zip(signatures) {
+                        info!("bundle 交易已上链: {}", signature);
+                        outcomes.extend(group.iter().cloned().map(|mut outcome| {
+                            outcome.signature = Some(signature.to_string());
+                            outcome
+                        }));
+                    }
+                }
+                Err(e) => {
+                    error!("第 {} 个 bundle 提交失败: {}", i + 1, e);
+                    for (_, group) in bundle {
+                        outcomes.extend(group.iter().map(|outcome| {
+                            AccountOutcome::failed(
+                                &outcome.account_address,
+                                &outcome.mint,
+                                &outcome.symbol,
+                                &e,
+                            )
+                        }));
+                    }
+                }
+            }
+        }
+
+        outcomes
+    }
+
+    /// -- 构建、签名并发送一个 bundle，等待最后一笔交易确认
+    async fn send_bundle_and_confirm(
+        &self,
+        client: &JitoClient,
+        bundle: &[(Vec<Instruction>, Vec<AccountOutcome>)],
+        tip_lamports: u64,
+    ) -> TokenAccountResult<Vec<Signature>> {
+        let blockhash = self.connection.get_latest_blockhash()?;
+        let transactions: Vec<Transaction> = {
+            // -- 设置了独立的手续费支付者时，由它支付交易费用和小费
+            let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+            bundle
+                .iter()
+                .enumerate()
+                .map(|(i, (instructions, _))| {
+                    let mut instructions =
+                        with_compute_budget(&self.connection, &self.config, instructions.clone());
+                    if i + 1 == bundle.len() {
+                        instructions.push(tip_instruction(&payer, tip_lamports));
+                    }
+                    Transaction::new_signed_with_payer(
+                        &instructions,
+                        Some(&payer),
+                        &signers,
+                        blockhash,
+                    )
+                })
+                .collect()
+        };
+        let signatures: Vec<Signature> = transactions.iter().map(|tx| tx.signatures[0]).collect();
+        for transaction in &transactions {
+            preflight(&self.connection, transaction, self.config.preflight)?;
+        }
+
+        let bundle_id = client.send_bundle(&transactions).await?;
+        info!("bundle 已提交: {}", bundle_id);
+
+        // -- bundle 原子执行，最后一笔交易确认即表示全部上链
+        let last = signatures[signatures.len() - 1];
+        let started = Instant::now();
+        loop {
+            if self.connection.confirm_transaction(&last)? {
+                return Ok(signatures);
+            }
+            if started.elapsed() >= BUNDLE_CONFIRM_TIMEOUT {
+                return Err(TokenAccountError::TransactionError(format!(
+                    "bundle {} 确认超时",
+                    bundle_id
+                )));
+            }
+            tokio::time::sleep(CONFIRM_POLL_INTERVAL).await;
+        }
+    }
+}
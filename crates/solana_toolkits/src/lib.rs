@@ -1,22 +1,145 @@
 use account_info::*;
+use blacklist::TokenBlacklist;
+use checkpoint::{BatchCheckpoint, CheckpointWriter};
 use config::*;
-use operations::{create_batch_close_transaction, execute_close_account};
+use history::RunOperation;
+use metadata::TokenMetadataCache;
+use notify::Notifier;
+use observer::{notify_outcome, BatchObserver, NoopObserver};
+use operations::{
+    execute_close_account, is_token_program, send_batch_close_transaction, BurnTarget, Submission,
+};
+use retry::is_account_missing;
 use solana_client::rpc_client::RpcClient;
-use solana_client::rpc_request::TokenAccountsFilter;
 use solana_sdk::{
-    native_token::LAMPORTS_PER_SOL, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
-    signer::Signer,
+    account::Account as SolanaAccount, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey,
+    signature::Keypair, signer::Signer,
+};
+use spl_token_2022::{
+    extension::{transfer_fee::TransferFeeAmount, BaseStateWithExtensions, StateWithExtensions},
+    state::Account,
 };
-use spl_token::state::Account;
 use std::future::Future;
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use std::sync::Arc;
-use std::{error::Error, fs::read_to_string, str::FromStr, thread, time::Duration};
+use std::sync::{Arc, Mutex};
+use std::{collections::HashMap, env, fs, path::Path, str::FromStr, time::Instant};
 use tracing::{error, info, warn};
-use utils::{fetch_token_info, format_metadata, init_rpc_client};
-use utils::{TokenAccountError, TokenAccountResult};
+use utils::{
+    address_book::address_label,
+    init_cluster_rpc_client,
+    keystore::{
+        is_encrypted_keypair_file, load_encrypted_keypair, Keystore, KEYSTORE_PASSPHRASE_ENV,
+    },
+    wallet::{load_keypair, WALLET_PRIVATE_KEY_ENV},
+};
+use utils::{
+    FailoverRpc, KeystoreError, KeystoreResult, RpcApi, TokenAccountError, TokenAccountResult,
+};
 use whitelist::TokenWhitelist;
 
+/// -- 单次 `getMultipleAccounts` 请求最多查询的账户数量
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// -- 根据配置创建白名单：配置了 `whitelist_path` 时从文件加载，否则使用默认白名单
+fn load_whitelist(config: &TokenAccountConfig) -> TokenAccountResult<TokenWhitelist> {
+    match &config.whitelist_path {
+        Some(path) => {
+            let whitelist = TokenWhitelist::from_file(path)?;
+            info!("已加载白名单文件: {}", path.display());
+            Ok(whitelist)
+        }
+        None => Ok(TokenWhitelist::new(Some(true))),
+    }
+}
+
+/// -- 加载钱包密钥对
+///
+/// 钱包文件是加密钱包文件时使用 `KEYSTORE_PASSPHRASE` 中的口令解密，未设置时返回错误；
+/// 其他格式（以及设置了 `WALLET_PRIVATE_KEY` 的情况）交给 [`load_keypair`] 处理。
+fn load_wallet(path: &str) -> TokenAccountResult<Keypair> {
+    let private_key_set = env::var(WALLET_PRIVATE_KEY_ENV).is_ok_and(|v| !v.trim().is_empty());
+    if !private_key_set && is_encrypted_keypair_file(path) {
+        return Ok(load_encrypted_keypair(path, || {
+            Err(KeystoreError::MissingPassphrase(
+                KEYSTORE_PASSPHRASE_ENV.to_string(),
+            ))
+        })?);
+    }
+    load_keypair(path)
+}
+
+/// -- 为未处理的账户生成失败结果
+pub(crate) fn failed_outcomes(
+    accounts: &[ZeroValueTokenInfo],
+    error: &TokenAccountError,
+) -> Vec<AccountOutcome> {
+    accounts
+        .iter()
+        .map(|account| {
+            AccountOutcome::failed(&account.address, &account.mint, &account.symbol, error)
+        })
+        .collect()
+}
+
+/// -- 关闭账户的交易计划：每笔交易的业务指令数量，用于估算交易费用
+///
+/// 有暂扣转账手续费的账户在关闭前多一条提取指令。
+///
+/// # 参数
+/// * `accounts` - 要关闭的账户列表
+/// * `batch_size` - 每笔批量交易包含的账户数量
+/// * `batched` - 是否合并为批量交易，否则每个账户一笔交易
+fn close_plan(accounts: &[TokenAccountInfo], batch_size: usize, batched: bool) -> Vec<usize> {
+    let instructions = |accounts: &[TokenAccountInfo]| -> usize {
+        accounts
+            .iter()
+            .map(|account| 1 + (account.withheld_amount > 0) as usize)
+            .sum()
+    };
+    let chunk_size = if batched { batch_size } else { 1 };
+    accounts.chunks(chunk_size).map(instructions).collect()
+}
+
+/// -- 解析代币账户数据
+///
+/// 同时支持 SPL Token 和 Token-2022 账户，Token-2022 账户只读取暂扣的转账手续费扩展。
+fn parse_account_details(
+    account_pubkey: &Pubkey,
+    account_info: &SolanaAccount,
+) -> TokenAccountResult<TokenAccountDetails> {
+    if !is_token_program(&account_info.owner) {
+        return Err(TokenAccountError::AccountParseError(format!(
+            "账户不属于代币程序: {}",
+            account_info.owner
+        )));
+    }
+
+    let state = StateWithExtensions::<Account>::unpack(&account_info.data)
+        .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+    let withheld_amount = state
+        .get_extension::<TransferFeeAmount>()
+        .map_or(0, |fee| u64::from(fee.withheld_amount));
+    let token_account = state.base;
+    // -- wSOL 账户的 lamports 包含包装的 SOL，租金只取免租金储备部分
+    let rent_lamports =
+        Option::<u64>::from(token_account.is_native).unwrap_or(account_info.lamports);
+
+    Ok(TokenAccountDetails {
+        pubkey: account_pubkey.to_string(),
+        balance: token_account.amount,
+        rent_lamports,
+        rent_sol: rent_lamports as f64 / LAMPORTS_PER_SOL as f64,
+        mint: token_account.mint.to_string(),
+        owner: token_account.owner.to_string(),
+        program_id: account_info.owner.to_string(),
+        is_frozen: token_account.is_frozen(),
+        is_native: token_account.is_native(),
+        delegate: Option::<Pubkey>::from(token_account.delegate).map(|d| d.to_string()),
+        withheld_amount,
+        close_authority: Option::<Pubkey>::from(token_account.close_authority)
+            .map(|authority| authority.to_string()),
+    })
+}
+
 /// -- Solana 代币账户管理工具
 ///
 /// 该模块提供了一系列用于管理 Solana 代币账户的工具，包括：
@@ -25,30 +148,105 @@ use whitelist::TokenWhitelist;
 /// - 批量关闭账户
 /// - 白名单管理
 /// - 资源回收
+/// - 批量转账
+/// - 创建代币
+/// - 测试钱包注资
+/// - Jito bundle 提交
+/// - 地址查找表与 v0 交易
+/// - 代币元数据缓存
+/// - 剩余代币归集
+/// - 剩余代币通过 Jupiter 卖出
+/// - 按 USD 价值限制销毁
+/// - 授权代理审计与撤销
+/// - 多钱包批量清理
+/// - 批量操作进度回调
+/// - 批量关闭检查点与恢复
+/// - 指数退避重试与错误分类
+/// - 多 RPC 节点故障切换
+/// - websocket 交易确认
+/// - 构建器注入已有的密钥对和 RPC 客户端
+/// - 硬件钱包与远程签名
+/// - 离线签名：导出未签名交易、离线签名、从文件广播
+/// - Squads 多签提案
+/// - 流式分页扫描
+/// - 扫描过滤：Mint、租金、创建时间、符号、关联代币账户
+/// - 扫描结果导出为 CSV 或 JSON
+/// - Prometheus 指标与 `/metrics` 接口
+/// - 批量处理完成通知：Webhook、Telegram、Discord
+/// - 按 cron 计划定时扫描清理
+pub mod account_creation;
 pub mod account_info;
+pub mod blacklist;
+pub mod builder;
+pub mod checkpoint;
+pub mod cleanup_daemon;
 pub mod config;
+pub mod confirmation;
+pub mod delegates;
+pub mod dust_swap;
+pub mod export;
+pub mod faucet;
+pub mod filter;
+pub mod history;
+pub mod jito;
+pub mod lookup_table;
+pub mod metadata;
+pub mod metrics;
+pub mod multi_wallet;
+pub mod multisend;
+pub mod notify;
+pub mod observer;
+pub mod offline;
+pub mod open_orders;
 mod operations;
+mod parallel;
+pub mod retry;
+pub mod run_report;
+pub mod scan;
+pub mod spam;
+pub mod squads;
+pub mod stake;
+pub mod sweep;
+pub mod token_creation;
+pub mod transfer;
+pub mod valuation;
 pub mod whitelist;
 
 /// -- 代币账户管理器
 ///
 /// 提供了一系列方法来管理 Solana 代币账户，包括查询、关闭和批量操作等功能。
 /// 支持白名单管理，可以保护特定代币账户不被误关闭。
-pub struct TokenAccountManager {
+///
+/// RPC 客户端默认为 `RpcClient`，也可以使用任意实现了 `RpcApi` 的类型（如单元测试中的 `MockRpc`）。
+/// 钱包默认为本地 `Keypair`，也可以使用任意实现了 `Signer` 的类型（如 Ledger 硬件钱包或远程签名服务），
+/// 关闭、销毁等交易都由该签名者授权。
+pub struct TokenAccountManager<R: RpcApi = RpcClient, S: Signer = Keypair> {
     /// RPC 客户端连接
-    pub connection: RpcClient,
-    /// 钱包密钥对
-    pub wallet: Keypair,
+    pub connection: R,
+    /// 钱包签名者，默认为本地密钥对
+    pub wallet: S,
     /// 代币白名单
     whitelist: TokenWhitelist,
+    /// 代币黑名单，优先于白名单
+    blacklist: TokenBlacklist,
     /// 管理器配置
     config: TokenAccountConfig,
+    /// 代币元数据缓存
+    metadata_cache: TokenMetadataCache,
+    /// 批量操作进度观察者
+    observer: Arc<dyn BatchObserver>,
+    /// 批量处理完成后的通知目标
+    notifiers: Vec<Arc<dyn Notifier>>,
+    /// 独立的手续费支付者，None 表示由钱包支付
+    fee_payer: Option<Arc<dyn Signer + Send + Sync>>,
 }
 
 impl TokenAccountManager {
     /// -- 创建新的代币账户管理器实例
     ///
     /// 使用默认配置创建一个新的代币账户管理器。
+    /// 密钥文件支持 `solana-keygen` 的 JSON 数组、base58 私钥和助记词，
+    /// 设置了 `WALLET_PRIVATE_KEY` 环境变量时优先使用环境变量。
     ///
     /// # 参数
     /// * `wallet_key_path` - 钱包密钥文件路径
@@ -81,30 +279,57 @@ impl TokenAccountManager {
         wallet_key_path: &str,
         config: TokenAccountConfig,
     ) -> TokenAccountResult<Self> {
-        let connection = init_rpc_client(config.commitment)?;
+        let connection = init_cluster_rpc_client(&config.cluster(), config.commitment)?;
 
-        let key_str = read_to_string(wallet_key_path)?;
-        let key_value: serde_json::Value = serde_json::from_str(&key_str)?;
-        let private_key = key_value
-            .as_str()
-            .ok_or(TokenAccountError::InvalidKeyFormat)?;
+        let wallet = load_wallet(wallet_key_path)?;
+        let whitelist = load_whitelist(&config)?;
 
-        let wallet = Keypair::from_base58_string(private_key);
-        let whitelist = TokenWhitelist::new(Some(true));
+        Ok(Self::from_parts(connection, wallet, config, whitelist))
+    }
 
-        Ok(Self {
-            connection,
-            wallet,
-            whitelist,
-            config,
-        })
+    /// -- 使用加密密钥库中的钱包创建代币账户管理器实例
+    ///
+    /// # 参数
+    /// * `keystore` - 已解锁的密钥库
+    /// * `name` - 钱包在密钥库中的名称
+    /// * `config` - 自定义配置参数
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Self>` - 成功返回管理器实例，失败返回错误
+    pub fn from_keystore(
+        keystore: &Keystore,
+        name: &str,
+        config: TokenAccountConfig,
+    ) -> TokenAccountResult<Self> {
+        let connection = init_cluster_rpc_client(&config.cluster(), config.commitment)?;
+        let wallet = keystore.keypair(name)?;
+        let whitelist = load_whitelist(&config)?;
+
+        Ok(Self::from_parts(connection, wallet, config, whitelist))
     }
 
-    /// -- 获取当前配置
+    /// -- 使用加密钱包文件创建代币账户管理器实例
     ///
-    /// 返回管理器当前使用的配置参数
-    pub fn get_config(&self) -> &TokenAccountConfig {
-        &self.config
+    /// 加密钱包文件由 `utils::keystore::encrypt_keypair_file` 生成，磁盘上不保存明文私钥。
+    /// 设置了 `KEYSTORE_PASSPHRASE` 时直接使用其中的口令，否则调用 `prompt` 获取口令。
+    ///
+    /// # 参数
+    /// * `path` - 加密钱包文件路径
+    /// * `prompt` - 交互输入口令的回调
+    /// * `config` - 自定义配置参数
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Self>` - 成功返回管理器实例，口令错误时返回 `KeystoreError::WrongPassphrase`
+    pub fn from_encrypted_wallet(
+        path: impl AsRef<Path>,
+        prompt: impl FnOnce() -> KeystoreResult<String>,
+        config: TokenAccountConfig,
+    ) -> TokenAccountResult<Self> {
+        let connection = init_cluster_rpc_client(&config.cluster(), config.commitment)?;
+        let wallet = load_encrypted_keypair(path, prompt)?;
+        let whitelist = load_whitelist(&config)?;
+
+        Ok(Self::from_parts(connection, wallet, config, whitelist))
     }
 
     /// -- 更新配置
@@ -117,13 +342,173 @@ impl TokenAccountManager {
     /// # 返回
     /// * `TokenAccountResult<()>` - 成功返回 Ok(()), 失败返回错误
     pub fn update_config(&mut self, config: TokenAccountConfig) -> TokenAccountResult<()> {
-        // 如果 commitment 发生变化，需要更新 RPC 客户端
-        if self.config.commitment != config.commitment {
-            self.connection = init_rpc_client(config.commitment)?;
+        // 如果 commitment 或集群发生变化，需要更新 RPC 客户端
+        if self.config.commitment != config.commitment || self.config.cluster != config.cluster {
+            self.connection = init_cluster_rpc_client(&config.cluster(), config.commitment)?;
+        }
+        // 缓存有效期或文件路径发生变化时重建元数据缓存
+        if self.config.metadata_cache_ttl != config.metadata_cache_ttl
+            || self.config.metadata_cache_path != config.metadata_cache_path
+        {
+            self.metadata_cache = TokenMetadataCache::new(
+                config.metadata_cache_ttl,
+                config.metadata_cache_path.clone(),
+            );
+        }
+        // 白名单文件路径发生变化时重新加载白名单
+        if self.config.whitelist_path != config.whitelist_path {
+            self.whitelist = load_whitelist(&config)?;
         }
         self.config = config;
         Ok(())
     }
+}
+
+impl TokenAccountManager<FailoverRpc> {
+    /// -- 使用多个 RPC 节点创建代币账户管理器实例
+    ///
+    /// 节点地址取自配置中的 `rpc_urls`，为空时使用 `cluster`，都未设置时读取 `RPC_URLS`（逗号分隔）或 `RPC_URL` 环境变量。
+    /// 遇到限流或连接错误时切换到下一个健康节点，失败的节点每隔 `rpc_probe_interval` 重新探测，
+    /// 适合在公共 RPC 上长时间运行的批量清理。
+    ///
+    /// # 参数
+    /// * `wallet_key_path` - 钱包密钥文件路径
+    /// * `config` - 自定义配置参数
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Self>` - 成功返回管理器实例，失败返回错误
+    pub fn with_rpc_pool(
+        wallet_key_path: &str,
+        config: TokenAccountConfig,
+    ) -> TokenAccountResult<Self> {
+        let connection = match (&config.rpc_urls[..], &config.cluster) {
+            ([], Some(cluster)) => {
+                FailoverRpc::new(&[cluster.url().to_string()], config.commitment)
+            }
+            ([], None) => FailoverRpc::from_env(config.commitment),
+            (urls, _) => FailoverRpc::new(urls, config.commitment),
+        }
+        .with_probe_interval(config.rpc_probe_interval);
+
+        let wallet = load_wallet(wallet_key_path)?;
+        let whitelist = load_whitelist(&config)?;
+
+        Ok(Self::from_parts(connection, wallet, config, whitelist))
+    }
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 使用指定的 RPC 客户端创建代币账户管理器实例
+    ///
+    /// 适用于自定义 RPC 实现，例如在单元测试中注入 `MockRpc`。
+    ///
+    /// # 参数
+    /// * `connection` - 实现了 `RpcApi` 的 RPC 客户端
+    /// * `wallet` - 钱包签名者
+    /// * `config` - 配置参数
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Self>` - 成功返回管理器实例，配置了 `whitelist_path` 但加载失败时返回错误
+    pub fn with_rpc(
+        connection: R,
+        wallet: S,
+        config: TokenAccountConfig,
+    ) -> TokenAccountResult<Self> {
+        let whitelist = load_whitelist(&config)?;
+        Ok(Self::from_parts(connection, wallet, config, whitelist))
+    }
+
+    /// -- 由各部分组装管理器
+    fn from_parts(
+        connection: R,
+        wallet: S,
+        config: TokenAccountConfig,
+        whitelist: TokenWhitelist,
+    ) -> Self {
+        Self {
+            connection,
+            wallet,
+            whitelist,
+            blacklist: TokenBlacklist::new(),
+            metadata_cache: TokenMetadataCache::new(
+                config.metadata_cache_ttl,
+                config.metadata_cache_path.clone(),
+            ),
+            observer: Arc::new(NoopObserver),
+            notifiers: Vec::new(),
+            fee_payer: None,
+            config,
+        }
+    }
+
+    /// -- 获取当前配置
+    ///
+    /// 返回管理器当前使用的配置参数
+    pub fn get_config(&self) -> &TokenAccountConfig {
+        &self.config
+    }
+
+    /// -- 获取代币元数据缓存
+    ///
+    /// 扫描账户时查询到的元数据会写入该缓存，可用于按 Mint 查询代币符号或清空缓存。
+    pub fn metadata_cache(&self) -> &TokenMetadataCache {
+        &self.metadata_cache
+    }
+
+    /// -- 设置批量操作进度观察者
+    ///
+    /// 批量关闭、销毁和转出时会调用观察者的回调，默认不处理任何事件。
+    /// 使用 `Arc` 传入，调用方可以保留一份引用读取观察者收集的状态。
+    ///
+    /// # 参数
+    /// * `observer` - 实现了 [`BatchObserver`] 的观察者
+    pub fn set_observer(&mut self, observer: Arc<dyn BatchObserver>) {
+        self.observer = observer;
+    }
+
+    /// -- 添加批量处理完成后的通知目标
+    ///
+    /// 批量关闭、销毁、转出和广播完成后按配置的 `notify_policy` 发送报告，可以添加多个。
+    ///
+    /// # 参数
+    /// * `notifier` - 实现了 [`Notifier`] 的通知目标，如 [`notify::TelegramNotifier`]
+    pub fn add_notifier(&mut self, notifier: Arc<dyn Notifier>) {
+        self.notifiers.push(notifier);
+    }
+
+    /// -- 设置独立的手续费支付者
+    ///
+    /// 关闭、销毁交易的费用由该签名者支付，钱包仍作为账户所有者签名，租金退回钱包。
+    /// 适用于清理 SOL 余额为 0 的钱包；`min_wallet_sol_reserve` 检查的是手续费支付者的余额。
+    ///
+    /// # 参数
+    /// * `fee_payer` - 支付交易费用的签名者，可以在多个管理器之间共享
+    pub fn set_fee_payer(&mut self, fee_payer: Arc<dyn Signer + Send + Sync>) {
+        self.fee_payer = Some(fee_payer);
+    }
+
+    /// -- 支付交易费用的地址：设置了独立的手续费支付者时为它的地址，否则为钱包地址
+    pub fn fee_payer_pubkey(&self) -> Pubkey {
+        match &self.fee_payer {
+            Some(fee_payer) => fee_payer.pubkey(),
+            None => self.wallet.pubkey(),
+        }
+    }
+
+    /// -- 独立的手续费支付者
+    pub(crate) fn fee_payer(&self) -> Option<&(dyn Signer + Send + Sync)> {
+        self.fee_payer.as_deref()
+    }
+
+    /// -- 钱包与独立手续费支付者的 SOL 余额之和，用于根据余额变化计算交易费用
+    pub(crate) fn tracked_balance(&self) -> TokenAccountResult<u64> {
+        let mut balance = self.connection.get_balance(&self.wallet.pubkey())?;
+        let fee_payer = self.fee_payer_pubkey();
+        if fee_payer != self.wallet.pubkey() {
+            balance += self.connection.get_balance(&fee_payer)?;
+        }
+        Ok(balance)
+    }
 
     /// -- 设置是否合并默认白名单
     ///
@@ -167,8 +552,20 @@ impl TokenAccountManager {
         self.whitelist.add_mints(mints);
     }
 
+    /// -- 将当前白名单保存到 TOML 或 JSON 文件
+    ///
+    /// 保存后的文件可以通过配置中的 `whitelist_path` 自动加载。
+    ///
+    /// # 参数
+    /// * `path` - 白名单文件路径，扩展名为 `.toml` 时保存为 TOML，否则保存为 JSON
+    pub fn save_whitelist(&self, path: impl AsRef<Path>) -> TokenAccountResult<()> {
+        self.whitelist.save_to_file(path)
+    }
+
     /// -- 检查代币是否在白名单中
     ///
+    /// 黑名单优先：Mint 在黑名单中时，即使符号在白名单中也返回 false。
+    ///
     /// # 参数
     /// * `symbol` - 代币符号
     /// * `mint` - 代币的 Mint 地址
@@ -176,7 +573,36 @@ impl TokenAccountManager {
     /// # 返回
     /// * `bool` - true 表示在白名单中，false 表示不在
     pub fn is_token_whitelisted(&self, symbol: &str, mint: &str) -> bool {
-        self.whitelist.is_whitelisted(symbol, mint)
+        !self.blacklist.is_blacklisted(mint) && self.whitelist.is_whitelisted(symbol, mint)
+    }
+
+    /// -- 添加 Mint 地址到黑名单
+    ///
+    /// 黑名单中的代币总是被销毁并关闭，不受白名单和 NFT 保护。
+    ///
+    /// # 参数
+    /// * `mint` - 代币的 Mint 地址
+    pub fn add_mint_to_blacklist(&mut self, mint: &str) {
+        self.blacklist.add_mint(mint);
+    }
+
+    /// -- 批量添加 Mint 地址到黑名单
+    ///
+    /// # 参数
+    /// * `mints` - Mint 地址列表
+    pub fn add_mints_to_blacklist(&mut self, mints: &[&str]) {
+        self.blacklist.add_mints(mints);
+    }
+
+    /// -- 检查代币是否在黑名单中
+    ///
+    /// # 参数
+    /// * `mint` - 代币的 Mint 地址
+    ///
+    /// # 返回
+    /// * `bool` - true 表示在黑名单中，false 表示不在
+    pub fn is_token_blacklisted(&self, mint: &str) -> bool {
+        self.blacklist.is_blacklisted(mint)
     }
 
     /// -- 获取指定账户的详细信息
@@ -188,26 +614,78 @@ impl TokenAccountManager {
     ///
     /// # 返回
     /// * `TokenAccountResult<TokenAccountDetails>` - 成功返回账户详情，失败返回错误
+    ///
+    /// 同时支持 SPL Token 和 Token-2022 账户，Token-2022 账户的扩展数据会被忽略。
     pub async fn get_account_details(
         &self,
         account_pubkey: &Pubkey,
     ) -> TokenAccountResult<TokenAccountDetails> {
-        let account_info = self
-            .connection
-            .get_account(account_pubkey)
-            .map_err(TokenAccountError::from)?;
+        let account_info = self.connection.get_account(account_pubkey).map_err(|e| {
+            let error = TokenAccountError::from(e);
+            if is_account_missing(&error) {
+                TokenAccountError::AccountNotFound(account_pubkey.to_string())
+            } else {
+                error
+            }
+        })?;
 
-        let token_account = Account::unpack(&account_info.data)
-            .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+        parse_account_details(account_pubkey, &account_info)
+    }
 
-        Ok(TokenAccountDetails {
-            pubkey: account_pubkey.to_string(),
-            balance: token_account.amount,
-            rent_lamports: account_info.lamports,
-            rent_sol: account_info.lamports as f64 / LAMPORTS_PER_SOL as f64,
-            mint: token_account.mint.to_string(),
-            owner: token_account.owner.to_string(),
-        })
+    /// -- 批量获取多个账户的详细信息
+    ///
+    /// 按每次最多 [`MAX_MULTIPLE_ACCOUNTS`] 个账户调用 `getMultipleAccounts`，
+    /// 比逐个调用 [`get_account_details`](Self::get_account_details) 少得多的 RPC 请求。
+    /// 账户不存在或不是代币账户时只有对应位置返回错误，不影响其他账户。
+    ///
+    /// # 参数
+    /// * `account_pubkeys` - 账户公钥列表
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Vec<TokenAccountResult<TokenAccountDetails>>>` - 与输入顺序一致的账户详情，RPC 请求失败时返回错误
+    pub async fn get_accounts_details(
+        &self,
+        account_pubkeys: &[Pubkey],
+    ) -> TokenAccountResult<Vec<TokenAccountResult<TokenAccountDetails>>> {
+        let mut details = Vec::with_capacity(account_pubkeys.len());
+        for chunk in account_pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.connection.get_multiple_accounts(chunk)?;
+            for (pubkey, account) in chunk.iter().zip(accounts) {
+                details.push(match account {
+                    Some(account) => parse_account_details(pubkey, &account),
+                    None => Err(TokenAccountError::AccountNotFound(pubkey.to_string())),
+                });
+            }
+        }
+        Ok(details)
+    }
+
+    /// -- 查询一组账户中已被关闭（链上不存在）的账户，返回 `AlreadyClosed` 结果
+    ///
+    /// 地址无效的账户不会出现在结果中，仍按失败处理。
+    fn already_closed_outcomes(
+        &self,
+        accounts: &[TokenAccountInfo],
+    ) -> TokenAccountResult<Vec<AccountOutcome>> {
+        let mut closed = Vec::new();
+        for chunk in accounts.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let valid: Vec<(&TokenAccountInfo, Pubkey)> = chunk
+                .iter()
+                .filter_map(|account| {
+                    let pubkey = Pubkey::from_str(&account.address).ok()?;
+                    Some((account, pubkey))
+                })
+                .collect();
+            let pubkeys: Vec<Pubkey> = valid.iter().map(|(_, pubkey)| *pubkey).collect();
+            let existing = self.connection.get_multiple_accounts(&pubkeys)?;
+            for ((account, _), existing) in valid.into_iter().zip(existing) {
+                if existing.is_none() {
+                    info!("账户已被关闭，跳过: {}", account.address);
+                    closed.push(AccountOutcome::already_closed(account));
+                }
+            }
+        }
+        Ok(closed)
     }
 
     /// -- 关闭单个代币账户的内部实现
@@ -218,22 +696,66 @@ impl TokenAccountManager {
     /// * `account_pubkey` - 要关闭的账户公钥
     ///
     /// # 返回
-    /// * `TokenAccountResult<(String, u64)>` - 成功返回 (交易签名, 租金金额)，失败返回错误
+    /// * `TokenAccountResult<(Submission, u64)>` - 成功返回 (提交结果, 租金金额)，模拟模式下签名为 None
     async fn close_account_internal(
         &self,
         account_pubkey: &Pubkey,
-    ) -> TokenAccountResult<(String, u64)> {
+    ) -> TokenAccountResult<(Submission, u64)> {
         let details = self.get_account_details(account_pubkey).await?;
+        self.close_with_details(account_pubkey, details).await
+    }
 
-        if details.balance != 0 {
+    /// -- 使用已查询的账户详情关闭账户
+    async fn close_with_details(
+        &self,
+        account_pubkey: &Pubkey,
+        details: TokenAccountDetails,
+    ) -> TokenAccountResult<(Submission, u64)> {
+        details.ensure_not_frozen()?;
+        details.ensure_closable_by(&self.wallet.pubkey())?;
+
+        // -- wSOL 账户关闭时余额会随租金一起返还，无需先清空
+        if details.balance != 0 && !details.is_native {
             return Err(TokenAccountError::NonZeroBalance(details.balance));
         }
+        let program_id = Pubkey::from_str(&details.program_id)
+            .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+
+        // -- 关闭前撤销授权代理
+        if self.config.revoke_delegates {
+            if let Some(delegate) = &details.delegate {
+                let submission = self.revoke_delegate(account_pubkey, &program_id)?;
+                info!(
+                    "已撤销授权代理 {}，交易签名: {}",
+                    delegate,
+                    submission.signature.as_deref().unwrap_or("-")
+                );
+            }
+        }
+
+        // -- Token-2022 账户中有暂扣的转账手续费时，关闭前先提取到 Mint
+        let harvest_mint = if details.withheld_amount > 0 {
+            info!(
+                "账户 {} 暂扣了 {} 转账手续费，关闭前提取到 Mint",
+                account_pubkey, details.withheld_amount
+            );
+            Some(
+                Pubkey::from_str(&details.mint)
+                    .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?,
+            )
+        } else {
+            None
+        };
 
         execute_close_account(
             &self.connection,
+            self.fee_payer(),
             &self.wallet,
             account_pubkey,
             details.rent_lamports,
+            &program_id,
+            harvest_mint.as_ref(),
+            &self.config,
         )
         .await
     }
@@ -241,6 +763,7 @@ impl TokenAccountManager {
     /// -- 关闭单个代币账户
     ///
     /// 对外暴露的账户关闭接口，提供友好的结果格式。
+    /// 模拟模式下只模拟执行交易，`rent_recovered` 为预计回收的租金。
     ///
     /// # 参数
     /// * `account_pubkey` - 要关闭的账户公钥
@@ -249,12 +772,14 @@ impl TokenAccountManager {
     /// * `ClosureResult` - 包含操作结果的详细信息
     pub async fn close_account(&self, account_pubkey: &Pubkey) -> ClosureResult {
         match self.close_account_internal(account_pubkey).await {
-            Ok((signature, rent)) => ClosureResult {
+            Ok((submission, rent)) => ClosureResult {
                 success: true,
-                signature: Some(signature),
+                signature: submission.signature,
                 error: None,
                 account_address: account_pubkey.to_string(),
                 rent_recovered: rent as f64 / LAMPORTS_PER_SOL as f64,
+                simulated: self.config.dry_run,
+                simulation: Some(submission.simulation),
             },
             Err(e) => ClosureResult {
                 success: false,
@@ -262,56 +787,176 @@ impl TokenAccountManager {
                 error: Some(e.to_string()),
                 account_address: account_pubkey.to_string(),
                 rent_recovered: 0.0,
+                simulated: self.config.dry_run,
+                simulation: None,
             },
         }
     }
 
+    /// -- 估算一组交易的总费用（lamports）
+    ///
+    /// 按 [`TokenAccountConfig::estimated_fee_lamports`] 估算每笔交易的最高费用，
+    /// 设置了独立的手续费支付者时每笔交易按两个签名计算。
+    ///
+    /// # 参数
+    /// * `transactions` - 每笔交易的业务指令数量
+    ///
+    /// # 返回
+    /// * `u64` - 预计的总费用（lamports）
+    pub(crate) fn estimated_fees(&self, transactions: &[usize]) -> u64 {
+        let signers = if self.fee_payer_pubkey() == self.wallet.pubkey() {
+            1
+        } else {
+            2
+        };
+        transactions
+            .iter()
+            .map(|&instructions| self.config.estimated_fee_lamports(signers, instructions))
+            .sum()
+    }
+
+    /// -- 销毁并关闭账户的交易计划：每笔交易的业务指令数量，用于估算交易费用
+    ///
+    /// Jito bundle 模式每个账户一笔销毁、提取手续费并关闭的交易；
+    /// 否则每个账户先发送销毁交易，再发送提取手续费并关闭的交易。按最多的指令数量估算。
+    fn burn_plan(&self, accounts: &[ZeroValueTokenInfo]) -> Vec<usize> {
+        if self.config.jito_target().is_some() {
+            vec![3; accounts.len()]
+        } else {
+            [1, 2].repeat(accounts.len())
+        }
+    }
+
+    /// -- 估算执行计划中各笔交易的总费用，超过 `max_fee_sol` 时拒绝执行
+    ///
+    /// 按 [`estimated_fees`](Self::estimated_fees) 估算，估算结果写入日志。
+    /// 模拟模式下同样检查，便于提前确认计划是否会被拒绝。
+    ///
+    /// # 参数
+    /// * `transactions` - 计划发送的每笔交易的业务指令数量
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<u64>` - 预计的总费用（lamports）
+    pub(crate) fn check_fee_budget(&self, transactions: &[usize]) -> TokenAccountResult<u64> {
+        let estimated = self.estimated_fees(transactions);
+        info!(
+            "执行计划: {} 笔交易，预计交易费用最多 {} SOL",
+            transactions.len(),
+            estimated as f64 / LAMPORTS_PER_SOL as f64
+        );
+        if let Some(max_fee_sol) = self.config.max_fee_sol {
+            let budget = (max_fee_sol * LAMPORTS_PER_SOL as f64).round() as u64;
+            if estimated > budget {
+                return Err(TokenAccountError::FeeBudgetExceeded { estimated, budget });
+            }
+        }
+        Ok(estimated)
+    }
+
+    /// -- 检查钱包余额支付 `transactions` 的预计费用后是否仍不低于 `min_wallet_sol_reserve`
+    ///
+    /// 设置了独立的手续费支付者时检查手续费支付者的余额。模拟模式或未配置保留余额时不检查。
+    ///
+    /// # 参数
+    /// * `transactions` - 即将发送的每笔交易的业务指令数量
+    pub(crate) fn check_wallet_reserve(&self, transactions: &[usize]) -> TokenAccountResult<()> {
+        let Some(reserve_sol) = self.config.min_wallet_sol_reserve else {
+            return Ok(());
+        };
+        if self.config.dry_run {
+            return Ok(());
+        }
+
+        let balance = self.connection.get_balance(&self.fee_payer_pubkey())?;
+        let fees = self.estimated_fees(transactions);
+        let reserve = (reserve_sol * LAMPORTS_PER_SOL as f64).round() as u64;
+        if balance.saturating_sub(fees) < reserve {
+            return Err(TokenAccountError::InsufficientReserve {
+                balance,
+                fees,
+                reserve,
+            });
+        }
+        Ok(())
+    }
+
     /// -- 通用批量处理函数
     ///
-    /// 提供带重试机制的批量处理功能。
+    /// 提供带重试机制的批量处理功能，按配置的 [`RetryPolicy`](retry::RetryPolicy) 退避重试，
+    /// 不可重试的错误立即放弃。某一批次失败后继续处理下一批次。
+    /// 每批开始前检查钱包保留余额，检查失败时停止处理，剩余项目作为一个失败批次返回。
     ///
     /// # 参数
     /// * `items` - 要处理的项目列表
     /// * `batch_size` - 每批处理的数量
+    /// * `plan` - 每批的交易计划（每笔交易的业务指令数量），用于估算交易费用
     /// * `process_fn` - 处理函数
     ///
     /// # 返回
-    /// * `TokenAccountResult<()>` - 处理结果
-    async fn process_batch_with_retry<'a, T, F, Fut>(
+    /// * `Vec<(&[T], TokenAccountError)>` - 重试后仍然失败的批次及最后一次的错误
+    async fn process_batch_with_retry<'a, T, P, F, Fut>(
         &'a self,
         items: &'a [T],
         batch_size: usize,
+        plan: P,
         process_fn: F,
-    ) -> TokenAccountResult<()>
+    ) -> Vec<(&'a [T], TokenAccountError)>
     where
+        P: Fn(&[T]) -> Vec<usize>,
         F: Fn(&'a [T]) -> Fut + Send + 'a,
         Fut: Future<Output = TokenAccountResult<()>> + Send + 'a,
         T: Sync + 'a,
     {
+        let mut failed_chunks = Vec::new();
+        let total_batches = items.len().div_ceil(batch_size);
         for (i, chunk) in items.chunks(batch_size).enumerate() {
+            if let Err(e) = self.check_wallet_reserve(&plan(chunk)) {
+                error!("{}，停止处理剩余账户", e);
+                failed_chunks.push((&items[i * batch_size..], e));
+                break;
+            }
+
             info!("\n处理第 {} 批, 共 {} 个项目", i + 1, chunk.len());
+            self.observer
+                .on_batch_start(i + 1, total_batches, chunk.len());
 
+            let started = Instant::now();
             let mut retries = 0;
             loop {
-                match process_fn(chunk).await {
+                let e = match process_fn(chunk).await {
                     Ok(_) => break,
-                    Err(e) if retries < self.config.max_retries => {
+                    Err(e) => e,
+                };
+                match self.config.retry_policy.next_delay(retries, started, &e) {
+                    Some(delay) => {
                         retries += 1;
-                        warn!("重试第 {} 次: {}", retries, e);
-                        tokio::time::sleep(self.config.retry_delay).await;
+                        warn!("重试第 {} 次（{:?} 后）: {}", retries, delay, e);
+                        self.observer.on_retry(i + 1, retries, &e);
+                        tokio::time::sleep(delay).await;
+                    }
+                    None => {
+                        error!("第 {} 批处理失败: {}", i + 1, e);
+                        failed_chunks.push((chunk, e));
+                        break;
                     }
-                    Err(e) => return Err(e),
                 }
             }
-            tokio::time::sleep(self.config.batch_delay).await;
+            if !self.config.dry_run {
+                tokio::time::sleep(self.config.batch_delay).await;
+            }
         }
-        Ok(())
+        failed_chunks
     }
 
     /// -- 批量关闭账户
     ///
     /// 批量关闭多个代币账户，支持单独交易和批量交易两种模式。
-    /// 使用原子计数器和并发处理来提高效率。
+    /// 模拟模式下只模拟执行交易，报告中的租金为预计回收的租金。
+    /// 配置为 [`SubmitMode::Jito`] 时，关闭交易以 bundle 形式提交到 Jito block engine。
+    /// 配置的 `max_concurrent_transactions` 大于 1 时，批量交易模式并发提交各批次的交易。
+    /// 处理进度通过 [`set_observer`](Self::set_observer) 设置的观察者回调。
+    /// 配置了 `max_fee_sol` 时，执行前估算的总交易费用超过上限会返回
+    /// [`TokenAccountError::FeeBudgetExceeded`]，不发送任何交易。
     ///
     /// # 参数
     /// * `accounts` - 要关闭的账户列表
@@ -319,173 +964,349 @@ impl TokenAccountManager {
     /// * `use_batch_tx` - 是否使用批量交易（true: 合并交易，false: 单独交易）
     ///
     /// # 返回
-    /// * `TokenAccountResult<()>` - 处理结果
+    /// * `TokenAccountResult<BatchCloseReport>` - 每个账户的处理结果和统计信息
     pub async fn batch_close_accounts(
         &self,
         accounts: &[TokenAccountInfo],
         batch_size: usize,
         use_batch_tx: bool,
-    ) -> TokenAccountResult<()> {
+    ) -> TokenAccountResult<BatchCloseReport> {
+        let mut report = BatchCloseReport {
+            simulated: self.config.dry_run,
+            ..BatchCloseReport::default()
+        };
+
         // -- 检查账户列表是否为空
         if accounts.is_empty() {
             warn!("没有找到可关闭的账户");
-            return Ok(());
+            return Ok(report);
         }
 
-        // -- 获取操作前的钱包余额，用于后续计算实际回收的租金
-        let balance_before = self.connection.get_balance(&self.wallet.pubkey())?;
-        let balance_before_sol = balance_before as f64 / LAMPORTS_PER_SOL as f64;
+        // -- 合并交易和 Jito bundle 模式每批一笔交易，单独交易模式每个账户一笔
+        let batched = use_batch_tx || self.config.jito_target().is_some();
+        report.estimated_fees_lamports =
+            self.check_fee_budget(&close_plan(accounts, batch_size, batched))?;
+
+        // -- 获取操作前的钱包余额，用于后续计算交易费用
+        let balance_before = self.tracked_balance()?;
+
+        // -- 配置了检查点文件时记录每个账户的处理状态（模拟模式不记录）
+        let checkpoint = match &self.config.checkpoint_path {
+            Some(path) if !self.config.dry_run => Some(CheckpointWriter::start(
+                path,
+                &self.wallet.pubkey().to_string(),
+                accounts,
+            )?),
+            _ => None,
+        };
+        let record = |outcome: &AccountOutcome| {
+            notify_outcome(self.observer.as_ref(), outcome);
+            if let Some(checkpoint) = &checkpoint {
+                checkpoint.record(outcome);
+            }
+        };
 
-        // -- 创建原子计数器，用于跨线程安全地统计处理结果
-        // Arc (Atomic Reference Counting) 提供了线程安全的引用计数
-        // AtomicUsize 和 AtomicU64 提供了原子操作的整数类型
-        let success_count = Arc::new(AtomicUsize::new(0)); // 成功处理的账户数量
-        let fail_count = Arc::new(AtomicUsize::new(0)); // 失败的账户数量
-        let total_rent_recovered = Arc::new(AtomicU64::new(0)); // 总回收的租金（lamports）
+        // -- 各批次的处理结果，处理函数在异步闭包中执行，因此使用 Mutex 收集
+        let outcomes = Mutex::new(Vec::new());
+
+        // -- 记录重试后仍然失败、或因钱包余额低于保留余额未处理的批次
+        let record_failed = |failed_chunks: Vec<(&[TokenAccountInfo], TokenAccountError)>| {
+            let mut outcomes = outcomes.lock().unwrap();
+            for (chunk, e) in failed_chunks {
+                // -- 恢复或重试时批次中的账户可能已被关闭，这些账户标记为 AlreadyClosed，不计为失败
+                let mut closed = if is_account_missing(&e) {
+                    self.already_closed_outcomes(chunk).unwrap_or_default()
+                } else {
+                    Vec::new()
+                };
+                for account in chunk {
+                    let outcome = match closed
+                        .iter()
+                        .position(|outcome| outcome.account_address == account.address)
+                    {
+                        Some(index) => closed.swap_remove(index),
+                        None => AccountOutcome::failed(
+                            &account.address,
+                            &account.mint,
+                            &account.symbol,
+                            &e,
+                        ),
+                    };
+                    record(&outcome);
+                    outcomes.push(outcome);
+                }
+            }
+        };
 
-        if use_batch_tx {
+        if let Some((tip_lamports, block_engine_url)) = self.config.jito_target() {
+            // ====== Jito bundle 模式 ======
+            // 关闭交易打包为 bundle 发送，模拟模式下仍走普通模拟流程
+            match self.check_wallet_reserve(&close_plan(accounts, batch_size, true)) {
+                Ok(()) => {
+                    let jito_outcomes = self
+                        .batch_close_via_jito(accounts, batch_size, tip_lamports, block_engine_url)
+                        .await;
+                    jito_outcomes.iter().for_each(&record);
+                    outcomes.lock().unwrap().extend(jito_outcomes);
+                }
+                Err(e) => {
+                    error!("{}，停止处理剩余账户", e);
+                    record_failed(vec![(accounts, e)]);
+                }
+            }
+        } else if use_batch_tx && self.config.max_concurrent_transactions > 1 {
+            // ====== 并发批量交易模式 ======
+            // 多笔批量关闭交易同时在途，区块哈希过期的交易自动重新签名
+            match self.check_wallet_reserve(&close_plan(accounts, batch_size, true)) {
+                Ok(()) => {
+                    let (succeeded, failed_chunks) =
+                        self.close_batches_concurrently(accounts, batch_size).await;
+                    let mut closed = outcomes.lock().unwrap();
+                    for (chunk, submission) in succeeded {
+                        for account in chunk {
+                            info!("成功关闭账户: {}", account.address);
+                            info!("代币 Symbol: {}", account.symbol);
+                            let outcome = AccountOutcome::closed(
+                                account,
+                                submission.signature.clone(),
+                                submission.simulation.clone(),
+                            );
+                            record(&outcome);
+                            closed.push(outcome);
+                        }
+                    }
+                    drop(closed);
+                    record_failed(failed_chunks);
+                }
+                Err(e) => {
+                    error!("{}，停止处理剩余账户", e);
+                    record_failed(vec![(accounts, e)]);
+                }
+            }
+        } else if use_batch_tx {
             // ====== 批量交易模式 ======
             // 将多个账户的关闭操作合并到一个交易中执行
-
-            // -- 克隆原子计数器的引用，以便在异步闭包中使用
-            let success_count_clone = Arc::clone(&success_count);
-            let fail_count_clone = Arc::clone(&fail_count);
-            let total_rent_recovered_clone = Arc::clone(&total_rent_recovered);
-
-            // -- 使用批处理重试机制处理账户
-            self.process_batch_with_retry(accounts, batch_size, move |chunk| {
-                // 为每个异步闭包克隆计数器引用
-                let success_count = Arc::clone(&success_count_clone);
-                let fail_count = Arc::clone(&fail_count_clone);
-                let total_rent_recovered = Arc::clone(&total_rent_recovered_clone);
-
-                async move {
-                    // -- 创建批量关闭交易
-                    let (transaction, chunk_rent) =
-                        create_batch_close_transaction(&self.connection, &self.wallet, chunk)
-                            .await?;
-
-                    // -- 计算本批次的租金总额（转换为 lamports）
-                    let rent_lamports = (chunk_rent * LAMPORTS_PER_SOL as f64) as u64;
-                    // 原子操作：添加到总租金计数器
-                    total_rent_recovered.fetch_add(rent_lamports, Ordering::SeqCst);
-
-                    // -- 发送并确认交易
-                    match self.connection.send_and_confirm_transaction(&transaction) {
-                        Ok(signature) => {
-                            // 原子操作：增加成功计数
-                            success_count.fetch_add(chunk.len(), Ordering::SeqCst);
-                            info!("批量关闭成功，交易签名: {}", signature);
-
-                            // -- 打印每个账户的详细信息
-                            for account in chunk {
-                                // 获取代币元数据信息
-                                if let Ok((metadata, _)) =
-                                    fetch_token_info(&self.connection, &account.mint)
-                                        .map_err(|e| TokenAccountError::Other(e.to_string()))
-                                {
-                                    let symbol =
-                                        metadata.symbol.trim_matches(char::from(0)).to_string();
-                                    info!("代币地址: {}, Symbol: {}", account.mint, symbol);
-                                }
-                                info!("成功关闭账户: {}", account.address);
-                                info!("代币 Symbol: {}", account.symbol);
-                            }
-                            Ok(())
+            let plan = |chunk: &[TokenAccountInfo]| close_plan(chunk, batch_size, true);
+            let failed_chunks = self
+                .process_batch_with_retry(accounts, batch_size, plan, |chunk| {
+                    let outcomes = &outcomes;
+                    let record = &record;
+                    async move {
+                        // -- 先模拟再发送并确认批量关闭交易（模拟模式下只模拟执行），区块哈希过期时自动重新签名
+                        let submission = send_batch_close_transaction(
+                            &self.connection,
+                            self.fee_payer(),
+                            &self.wallet,
+                            chunk,
+                            &self.config,
+                        )
+                        .await?;
+                        let signature = submission.signature;
+                        match &signature {
+                            Some(signature) => info!("批量关闭成功，交易签名: {}", signature),
+                            None => info!("[模拟] 批量关闭可以成功执行"),
                         }
-                        Err(e) => {
-                            // 原子操作：增加失败计数
-                            fail_count.fetch_add(chunk.len(), Ordering::SeqCst);
-                            Err(TokenAccountError::TransactionError(e.to_string()))
+
+                        let mut outcomes = outcomes.lock().unwrap();
+                        for account in chunk {
+                            info!("成功关闭账户: {}", account.address);
+                            info!("代币 Symbol: {}", account.symbol);
+                            let outcome = AccountOutcome::closed(
+                                account,
+                                signature.clone(),
+                                submission.simulation.clone(),
+                            );
+                            record(&outcome);
+                            outcomes.push(outcome);
                         }
+                        Ok(())
                     }
-                }
-            })
-            .await?;
+                })
+                .await;
+
+            record_failed(failed_chunks);
         } else {
             // ====== 单独交易模式 ======
-            // 为每个账户创建单独的关闭交易
-
-            // -- 克隆原子计数器的引用
-            let success_count_clone = Arc::clone(&success_count);
-            let fail_count_clone = Arc::clone(&fail_count);
-            let total_rent_recovered_clone = Arc::clone(&total_rent_recovered);
-
-            // -- 使用批处理重试机制处理账户
-            self.process_batch_with_retry(accounts, batch_size, move |chunk| {
-                let success_count = Arc::clone(&success_count_clone);
-                let fail_count = Arc::clone(&fail_count_clone);
-                let total_rent_recovered = Arc::clone(&total_rent_recovered_clone);
-
-                async move {
-                    // -- 逐个处理每个账户
-                    for account in chunk {
-                        // -- 解析账户公钥
-                        let pubkey = Pubkey::from_str(&account.address)
-                            .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
-
-                        // -- 关闭账户并处理结果
-                        match self.close_account_internal(&pubkey).await {
-                            Ok((signature, rent_lamports)) => {
-                                // 原子操作：更新成功计数和租金总额
-                                success_count.fetch_add(1, Ordering::SeqCst);
-                                total_rent_recovered.fetch_add(rent_lamports, Ordering::SeqCst);
-
-                                // -- 获取并打印代币信息
-                                if let Ok((metadata, _)) =
-                                    fetch_token_info(&self.connection, &account.mint)
-                                        .map_err(|e| TokenAccountError::Other(e.to_string()))
-                                {
-                                    let symbol =
-                                        metadata.symbol.trim_matches(char::from(0)).to_string();
-                                    info!("代币地址: {}, Symbol: {}", account.mint, symbol);
+            // 为每个账户创建单独的关闭交易，单个账户失败不影响其他账户
+            let plan = |chunk: &[TokenAccountInfo]| close_plan(chunk, batch_size, false);
+            let failed_chunks = self
+                .process_batch_with_retry(accounts, batch_size, plan, |chunk| {
+                    let outcomes = &outcomes;
+                    let record = &record;
+                    async move {
+                        // -- 每批通过一次 getMultipleAccounts 查询全部账户详情
+                        let pubkeys: Vec<Pubkey> = chunk
+                            .iter()
+                            .filter_map(|account| Pubkey::from_str(&account.address).ok())
+                            .collect();
+                        let mut details: HashMap<Pubkey, TokenAccountResult<TokenAccountDetails>> =
+                            pubkeys
+                                .iter()
+                                .copied()
+                                .zip(self.get_accounts_details(&pubkeys).await?)
+                                .collect();
+
+                        for account in chunk {
+                            let result = match Pubkey::from_str(&account.address) {
+                                Ok(pubkey) => match details.remove(&pubkey) {
+                                    Some(Ok(details)) => {
+                                        self.close_with_details(&pubkey, details).await
+                                    }
+                                    Some(Err(e)) => Err(e),
+                                    // -- 重复的地址已被处理过，重新查询
+                                    None => self.close_account_internal(&pubkey).await,
+                                },
+                                Err(e) => Err(TokenAccountError::AccountParseError(e.to_string())),
+                            };
+
+                            let outcome = match result {
+                                Ok((submission, rent_lamports)) => {
+                                    let signature = submission.signature;
+                                    info!("成功关闭账户: {}", account.address);
+                                    info!("代币 Symbol: {}", account.symbol);
+                                    match &signature {
+                                        Some(signature) => info!("交易签名: {}", signature),
+                                        None => info!("[模拟] 未发送交易"),
+                                    }
+                                    info!(
+                                        "回收租金: {} SOL",
+                                        rent_lamports as f64 / LAMPORTS_PER_SOL as f64
+                                    );
+
+                                    AccountOutcome {
+                                        account_address: account.address.clone(),
+                                        mint: account.mint.clone(),
+                                        symbol: account.symbol.clone(),
+                                        success: true,
+                                        signature,
+                                        burn_signature: None,
+                                        burned_amount: 0,
+                                        swept_amount: 0,
+                                        swap: None,
+                                        unwrapped_lamports: 0,
+                                        rent_lamports,
+                                        error: None,
+                                        failure_kind: None,
+                                        simulation: Some(submission.simulation),
+                                        already_closed: false,
+                                    }
                                 }
-
-                                // -- 打印成功信息
-                                info!("成功关闭账户: {}", account.address);
-                                info!("代币 Symbol: {}", account.symbol);
-                                info!("交易签名: {}", signature);
-                                info!(
-                                    "回收租金: {} SOL",
-                                    rent_lamports as f64 / LAMPORTS_PER_SOL as f64
-                                );
-                            }
-                            Err(e) => {
-                                // 原子操作：增加失败计数
-                                fail_count.fetch_add(1, Ordering::SeqCst);
-                                error!("关闭失败: {}", account.address);
-                                error!("错误信息: {}", e);
-                            }
+                                Err(TokenAccountError::AccountNotFound(_)) => {
+                                    info!("账户已被关闭，跳过: {}", account.address);
+                                    AccountOutcome::already_closed(account)
+                                }
+                                Err(e) => {
+                                    error!("关闭失败: {}", account.address);
+                                    error!("错误信息: {}", e);
+                                    AccountOutcome::failed(
+                                        &account.address,
+                                        &account.mint,
+                                        &account.symbol,
+                                        &e,
+                                    )
+                                }
+                            };
+                            record(&outcome);
+                            outcomes.lock().unwrap().push(outcome);
                         }
+                        Ok(())
                     }
-                    Ok(())
-                }
-            })
-            .await?;
+                })
+                .await;
+            record_failed(failed_chunks);
         }
 
         // ====== 统计最终结果 ======
-        // -- 获取操作后的钱包余额
-        let balance_after = self.connection.get_balance(&self.wallet.pubkey())?;
-        let balance_after_sol = balance_after as f64 / LAMPORTS_PER_SOL as f64;
+        report.outcomes = outcomes.into_inner().unwrap();
+        let balance_after = if self.config.dry_run {
+            balance_before
+        } else {
+            self.tracked_balance()?
+        };
+        report.finalize(balance_before, balance_after);
+        self.log_batch_report(&report);
+        if let Some(checkpoint) = &checkpoint {
+            checkpoint.finish();
+        }
+        self.observer.on_complete(&report);
+        self.record_history(RunOperation::Close, &report);
+        self.save_run_report(RunOperation::Close, &report);
+        self.send_notifications(&report).await;
 
-        // -- 计算实际回收的租金和 GAS 消耗
-        let actual_recovered = balance_after_sol - balance_before_sol;
-        let total_rent_recovered_sol =
-            total_rent_recovered.load(Ordering::SeqCst) as f64 / LAMPORTS_PER_SOL as f64;
-        let gas_consumed = actual_recovered - total_rent_recovered_sol;
+        Ok(report)
+    }
 
-        // -- 打印统计信息
-        info!("\n====== 处理完成 ======");
-        info!("执行前钱包余额: {} SOL", balance_before_sol);
-        info!("执行后钱包余额: {} SOL", balance_after_sol);
-        info!("实际增加余额: {} SOL", actual_recovered);
-        info!("成功关闭: {} 个账户", success_count.load(Ordering::SeqCst));
-        info!("失败数量: {} 个账户", fail_count.load(Ordering::SeqCst));
-        info!("预计回收租金: {} SOL", total_rent_recovered_sol);
-        info!("GAS 消耗: {} SOL", gas_consumed);
+    /// -- 从检查点恢复中断的批量关闭
+    ///
+    /// 读取配置中 `checkpoint_path` 指定的检查点文件，跳过已确认关闭的账户，
+    /// 只重新处理未处理和处理失败的账户，处理过程中继续更新同一个检查点文件。
+    ///
+    /// # 参数
+    /// * `batch_size` - 每批处理的账户数量
+    /// * `use_batch_tx` - 是否使用批量交易（true: 合并交易，false: 单独交易）
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<BatchCloseReport>` - 本次恢复处理的账户结果和统计信息
+    pub async fn resume_from_checkpoint(
+        &self,
+        batch_size: usize,
+        use_batch_tx: bool,
+    ) -> TokenAccountResult<BatchCloseReport> {
+        let path = self
+            .config
+            .checkpoint_path
+            .as_ref()
+            .ok_or_else(|| TokenAccountError::Other("未配置检查点文件路径".to_string()))?;
+        let checkpoint = BatchCheckpoint::load(path)?;
+        let wallet = self.wallet.pubkey().to_string();
+        if checkpoint.wallet != wallet {
+            return Err(TokenAccountError::Other(format!(
+                "检查点属于钱包 {}，与当前钱包 {} 不一致",
+                checkpoint.wallet, wallet
+            )));
+        }
 
-        Ok(())
+        let accounts = checkpoint.pending_accounts();
+        info!(
+            "从检查点恢复: 已关闭 {} 个账户，剩余 {} 个账户",
+            checkpoint.closed(),
+            accounts.len()
+        );
+        if accounts.is_empty() {
+            // -- 上次运行在删除检查点之前中断，所有账户都已关闭
+            let _ = fs::remove_file(path);
+        }
+        self.batch_close_accounts(&accounts, batch_size, use_batch_tx)
+            .await
+    }
+
+    /// -- 输出批量处理统计信息
+    fn log_batch_report(&self, report: &BatchCloseReport) {
+        let to_sol = |lamports: u64| lamports as f64 / LAMPORTS_PER_SOL as f64;
+
+        if report.simulated {
+            info!("\n====== 模拟完成（未发送任何交易） ======");
+        } else {
+            info!("\n====== 处理完成 ======");
+        }
+        info!("钱包: {}", address_label(&self.wallet.pubkey().to_string()));
+        info!("执行前钱包余额: {} SOL", to_sol(report.balance_before));
+        if !report.simulated {
+            info!("执行后钱包余额: {} SOL", to_sol(report.balance_after));
+            info!("交易数量: {}", report.signatures.len());
+        }
+        info!("成功处理: {} 个账户", report.succeeded);
+        info!("失败数量: {} 个账户", report.failed);
+        if report.already_closed > 0 {
+            info!("已被关闭（跳过）: {} 个账户", report.already_closed);
+        }
+        info!("预计回收租金: {} SOL", report.rent_recovered_sol);
+        if report.unwrapped_lamports > 0 {
+            info!("解包 wSOL: {} SOL", report.unwrapped_sol);
+        }
+        if !report.simulated {
+            info!("GAS 消耗: {} SOL", to_sol(report.fees_lamports));
+        }
     }
 
     /// -- 获取可关闭的代币账户列表
@@ -494,102 +1315,76 @@ impl TokenAccountManager {
     /// - 余额为 0 的账户
     /// - 不在白名单中的零值代币账户
     ///
+    /// 被冻结的账户无法关闭或销毁，单独列在 `frozen_accounts_list` 中，不计入可回收租金。
+    /// 关闭权限属于其他地址的账户钱包无法关闭，单独列在 `no_authority_accounts_list` 中，同样不计入可回收租金。
+    /// 有余额的 wSOL 账户单独列在 `wrapped_sol_accounts_list` 中，关闭即可解包，不会被当作零值代币销毁。
+    /// 配置了 `spam_heuristics` 时，Mint 保留冻结权限、可修改元数据指向钓鱼域名或没有流动性的代币
+    /// 单独列在 `suspected_spam_accounts_list` 中，黑名单和白名单代币不参与识别。
+    ///
+    /// 会同时扫描 SPL Token 和 Token-2022 程序下的账户。代币元数据按
+    /// `metadata_concurrency` 并发查询，并受 `metadata_requests_per_second` 限速。
+    ///
+    /// 配置了 `scan_filter` 时，各列表只包含满足过滤条件的账户，`total_accounts` 仍为钱包的全部代币账户数。
+    ///
     /// # 返回
     /// * `TokenAccountResult<TokenAccountsResult>` - 包含可关闭账户列表和统计信息
     pub async fn get_closeable_accounts(&self) -> TokenAccountResult<TokenAccountsResult> {
-        let accounts = self.connection.get_token_accounts_by_owner(
-            &self.wallet.pubkey(),
-            TokenAccountsFilter::ProgramId(spl_token::id()),
-        )?;
+        let accounts = self.fetch_token_accounts()?;
+        let total_accounts = accounts.len();
 
         let mut closeable_accounts = Vec::new();
         let mut zero_value_accounts = Vec::new();
+        let mut whitelisted_accounts = Vec::new();
+        let mut nft_accounts = Vec::new();
+        let mut frozen_accounts = Vec::new();
+        let mut no_authority_accounts = Vec::new();
+        let mut wrapped_sol_accounts = Vec::new();
+        let mut suspected_spam_accounts = Vec::new();
         let mut total_rent_lamports = 0;
         let mut total_rent_sol = 0.0;
 
-        for account in &accounts {
-            if let solana_account_decoder::UiAccountData::Json(parsed_data) = &account.account.data
-            {
-                if let Some(info) = parsed_data.parsed.get("info") {
-                    if let Some(mint) = info.get("mint") {
-                        let mint_str = mint.to_string();
-                        let clean_mint = mint_str.trim_matches('"');
-
-                        // -- 获取代币信息
-                        let token_info = match fetch_token_info(&self.connection, clean_mint) {
-                            Ok(token_info) => {
-                                info!("代币元数据: {}", format_metadata(&token_info.0));
-                                Some(token_info)
-                            }
-                            Err(e) => {
-                                warn!("获取代币信息失败: {}, 继续处理下一个账户", e);
-                                None
-                            }
-                        };
-
-                        if let Some(token_amount) = info.get("tokenAmount") {
-                            let amount = token_amount
-                                .get("amount")
-                                .and_then(|v| v.as_str())
-                                .and_then(|s| s.parse::<u64>().ok())
-                                .unwrap_or(0);
-
-                            let rent_lamports = account.account.lamports;
-                            let rent_sol = rent_lamports as f64 / LAMPORTS_PER_SOL as f64;
-                            let mint = clean_mint.to_string();
-                            let symbol = token_info
-                                .as_ref()
-                                .map(|(metadata, _)| {
-                                    metadata.symbol.trim_matches(char::from(0)).to_string()
-                                })
-                                .unwrap_or_else(|| "unknown".to_string());
-
-                            total_rent_lamports += rent_lamports;
-                            total_rent_sol += rent_sol;
-
-                            if amount == 0 {
-                                // -- 余额为 0 的账户
-                                closeable_accounts.push(TokenAccountInfo {
-                                    address: account.pubkey.to_string(),
-                                    mint: mint.clone(),
-                                    rent_lamports,
-                                    rent_sol,
-                                    symbol: symbol.clone(),
-                                });
-                            } else {
-                                // -- 检查是否为零值代币，且不在白名单中
-                                if let Some((metadata, _)) = token_info {
-                                    let symbol =
-                                        metadata.symbol.trim_matches(char::from(0)).to_string();
-                                    if !self.is_token_whitelisted(&symbol, &mint) {
-                                        zero_value_accounts.push(ZeroValueTokenInfo {
-                                            address: account.pubkey.to_string(),
-                                            mint,
-                                            balance: amount,
-                                            rent_lamports,
-                                            rent_sol,
-                                            symbol,
-                                        });
-                                    } else {
-                                        info!(
-                                            "跳过白名单代币 - Symbol: {}, Mint: {}",
-                                            symbol, mint
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+        for item in self.classify_accounts(&accounts) {
+            // -- 冻结和关闭权限属于其他地址的账户无法关闭，不计入可回收租金
+            if !matches!(item, ScanItem::Frozen(_) | ScanItem::NoAuthority(_)) {
+                total_rent_lamports += item.rent_lamports();
+                total_rent_sol += item.rent_lamports() as f64 / LAMPORTS_PER_SOL as f64;
             }
+            match item {
+                ScanItem::Closeable(account) => closeable_accounts.push(account),
+                ScanItem::ZeroValue(account) => zero_value_accounts.push(account),
+                ScanItem::Whitelisted(account) => whitelisted_accounts.push(account),
+                ScanItem::Nft(account) => nft_accounts.push(account),
+                ScanItem::Frozen(account) => frozen_accounts.push(account),
+                ScanItem::NoAuthority(account) => no_authority_accounts.push(account),
+                ScanItem::WrappedSol(account) => wrapped_sol_accounts.push(account),
+                ScanItem::SuspectedSpam(spam) => suspected_spam_accounts.push(spam),
+                ScanItem::Unknown(_) => {}
+            }
+        }
+
+        // -- 查询不到价格的零值代币没有市场流动性，同样归为疑似垃圾代币
+        if self
+            .config
+            .spam_heuristics
+            .as_ref()
+            .is_some_and(|heuristics| heuristics.check_liquidity)
+        {
+            self.flag_illiquid_accounts(&mut zero_value_accounts, &mut suspected_spam_accounts)
+                .await;
         }
 
         let result = TokenAccountsResult {
-            total_accounts: accounts.len(),
+            total_accounts,
             closable_accounts: closeable_accounts.len(),
             zero_value_accounts: zero_value_accounts.len(),
             accounts: closeable_accounts,
             zero_value_accounts_list: zero_value_accounts,
+            whitelisted_accounts_list: whitelisted_accounts,
+            nft_accounts_list: nft_accounts,
+            frozen_accounts_list: frozen_accounts,
+            no_authority_accounts_list: no_authority_accounts,
+            wrapped_sol_accounts_list: wrapped_sol_accounts,
+            suspected_spam_accounts_list: suspected_spam_accounts,
             total_rent_lamports,
             total_rent_sol,
         };
@@ -598,9 +1393,28 @@ impl TokenAccountManager {
         info!("{}", "=".repeat(50));
         info!("账户统计");
         info!("{}", "=".repeat(50));
+        info!("钱包: {}", address_label(&self.wallet.pubkey().to_string()));
         info!("总账户数: {}", result.total_accounts);
         info!("可关闭账户数（余额为 0）: {}", result.closable_accounts);
         info!("零值代币账户数: {}", result.zero_value_accounts);
+        info!("NFT 账户数（不销毁）: {}", result.nft_accounts_list.len());
+        info!(
+            "冻结账户数（无法关闭）: {}",
+            result.frozen_accounts_list.len()
+        );
+        info!(
+            "关闭权限不属于钱包的账户数（无法关闭）: {}",
+            result.no_authority_accounts_list.len()
+        );
+        info!(
+            "wSOL 账户数（关闭即解包）: {}",
+            result.wrapped_sol_accounts_list.len()
+        );
+        info!(
+            "疑似垃圾代币账户数: {}",
+            result.suspected_spam_accounts_list.len()
+        );
+        info!("非 ATA 辅助账户数: {}", result.auxiliary_accounts().len());
         info!("总可回收租金: {} SOL", result.total_rent_sol);
 
         // -- 打印详细信息
@@ -612,9 +1426,10 @@ impl TokenAccountManager {
             for (index, account) in result.accounts.iter().enumerate() {
                 info!("[账户 {}]", index + 1);
                 info!("地址: {}", account.address);
-                info!("Mint: {}", account.mint);
+                info!("Mint: {}", address_label(&account.mint));
                 info!("租金: {} SOL", account.rent_sol);
                 info!("Symbol: {}", account.symbol);
+                info!("程序: {}", address_label(&account.program_id));
             }
         }
 
@@ -626,14 +1441,16 @@ impl TokenAccountManager {
             for (index, account) in result.zero_value_accounts_list.iter().enumerate() {
                 info!("[账户 {}]", index + 1);
                 info!("地址: {}", account.address);
-                info!("Mint: {}", account.mint);
+                info!("Mint: {}", address_label(&account.mint));
                 info!("余额: {}", account.balance);
                 info!("租金: {} SOL", account.rent_sol);
                 info!("Symbol: {}", account.symbol);
+                info!("程序: {}", address_label(&account.program_id));
             }
         }
 
         info!("{}", "=".repeat(50));
+        self.observer.on_scan_complete(&result);
 
         Ok(result)
     }
@@ -642,6 +1459,7 @@ impl TokenAccountManager {
     ///
     /// 销毁账户中的代币并关闭账户，回收租金。
     /// 如果账户余额为 0，则直接关闭账户。
+    /// wSOL 账户不销毁，直接关闭以将包装的 SOL 返还钱包（记录在 `unwrapped_lamports`）。
     ///
     /// # 参数
     /// * `account_pubkey` - 要操作的账户公钥
@@ -654,6 +1472,16 @@ impl TokenAccountManager {
     ///   - 回收的租金数量
     ///   - 错误信息（如果有）
     pub async fn burn_and_close_account(&self, account_pubkey: &Pubkey) -> BurnAndCloseResult {
+        self.burn_and_close_account_checked(account_pubkey, None)
+            .await
+    }
+
+    /// -- 销毁代币并回收账户，`expected_decimals` 与 Mint 的链上精度不一致时不销毁
+    pub(crate) async fn burn_and_close_account_checked(
+        &self,
+        account_pubkey: &Pubkey,
+        expected_decimals: Option<u8>,
+    ) -> BurnAndCloseResult {
         let mut result = BurnAndCloseResult {
             success: false,
             burn_signature: None,
@@ -662,56 +1490,136 @@ impl TokenAccountManager {
             account_address: account_pubkey.to_string(),
             burned_amount: 0,
             rent_recovered: 0.0,
+            simulated: self.config.dry_run,
+            failure_kind: None,
+            simulation: None,
+            swap: None,
+            unwrapped_lamports: 0,
         };
 
         // -- 获取账户详情
         match self.get_account_details(account_pubkey).await {
             Ok(details) => {
-                if details.balance == 0 {
+                let checked = details
+                    .ensure_not_frozen()
+                    .and_then(|_| details.ensure_closable_by(&self.wallet.pubkey()));
+                if let Err(e) = checked {
+                    // -- 冻结或关闭权限不属于钱包的账户无法关闭，直接返回错误
+                    result.error = Some(e.to_string());
+                    result.failure_kind = Some(FailureKind::from(&e));
+                } else if details.balance == 0 {
                     // -- 如果余额为 0，直接关闭账户
-                    let close_result = self.close_account(account_pubkey).await;
-                    result.success = close_result.success;
-                    result.close_signature = close_result.signature;
-                    result.error = close_result.error;
-                    result.rent_recovered = close_result.rent_recovered;
+                    self.close_into_result(account_pubkey, &mut result).await;
+                } else if details.is_native {
+                    // -- wSOL 账户：关闭即解包，包装的 SOL 返还钱包而不是销毁
+                    self.close_into_result(account_pubkey, &mut result).await;
+                    if result.success {
+                        result.unwrapped_lamports = details.balance;
+                        info!(
+                            "已解包 {} SOL: {}",
+                            details.balance as f64 / LAMPORTS_PER_SOL as f64,
+                            account_pubkey
+                        );
+                    }
+                } else if self
+                    .sell_into_result(account_pubkey, &details, &mut result)
+                    .await
+                {
+                    // -- 已通过 Jupiter 卖出剩余代币（或卖出失败），不再销毁
                 } else {
-                    // -- 1. 销毁代币
-                    let mint_pubkey = Pubkey::from_str(&details.mint).unwrap();
-
-                    match operations::burn_tokens(
+                    let target = BurnTarget::resolve(
                         &self.connection,
-                        &self.wallet,
-                        account_pubkey,
-                        &mint_pubkey,
+                        &details.pubkey,
+                        &details.mint,
+                        &details.program_id,
                         details.balance,
-                    )
-                    .await
-                    {
-                        Ok(signature) => {
-                            result.burn_signature = Some(signature);
-                            result.burned_amount = details.balance;
-
-                            // -- 2. 关闭账户
-                            let close_result = self.close_account(account_pubkey).await;
-                            result.success = close_result.success;
-                            result.close_signature = close_result.signature;
-                            result.rent_recovered = close_result.rent_recovered;
-                        }
+                        expected_decimals,
+                    );
+                    match target {
                         Err(e) => {
-                            result.error = Some(format!("销毁代币失败: {}", e));
-                            return result;
+                            result.error = Some(format!("校验代币精度失败: {}", e));
+                            result.failure_kind = Some(FailureKind::from(&e));
+                        }
+                        Ok(target) if self.config.dry_run => {
+                            // -- 模拟模式：销毁和关闭放在同一笔交易中模拟
+                            match operations::simulate_burn_and_close(
+                                &self.connection,
+                                self.fee_payer(),
+                                &self.wallet,
+                                &target,
+                                &self.config,
+                            )
+                            .await
+                            {
+                                Ok(simulation) => {
+                                    result.success = true;
+                                    result.simulation = Some(simulation);
+                                    result.burned_amount = details.balance;
+                                    result.rent_recovered =
+                                        details.rent_lamports as f64 / LAMPORTS_PER_SOL as f64;
+                                }
+                                Err(e) => {
+                                    result.error = Some(format!("模拟销毁并关闭失败: {}", e));
+                                    result.failure_kind = Some(FailureKind::from(&e));
+                                }
+                            }
+                        }
+                        Ok(target) => {
+                            // -- 1. 销毁代币
+                            match operations::burn_tokens(
+                                &self.connection,
+                                self.fee_payer(),
+                                &self.wallet,
+                                &target,
+                                &self.config,
+                            )
+                            .await
+                            {
+                                Ok(submission) => {
+                                    result.burn_signature = submission.signature;
+                                    result.simulation = Some(submission.simulation);
+                                    result.burned_amount = details.balance;
+
+                                    // -- 2. 关闭账户
+                                    self.close_into_result(account_pubkey, &mut result).await;
+                                }
+                                Err(e) => {
+                                    result.error = Some(format!("销毁代币失败: {}", e));
+                                    result.failure_kind = Some(FailureKind::from(&e));
+                                }
+                            }
                         }
                     }
                 }
             }
             Err(e) => {
                 result.error = Some(format!("获取账户详情失败: {}", e));
+                result.failure_kind = Some(FailureKind::from(&e));
             }
         }
 
         result
     }
 
+    /// -- 关闭账户并将结果写入销毁并关闭结果
+    async fn close_into_result(&self, account_pubkey: &Pubkey, result: &mut BurnAndCloseResult) {
+        match self.close_account_internal(account_pubkey).await {
+            Ok((submission, rent)) => {
+                result.success = true;
+                result.close_signature = submission.signature;
+                result.rent_recovered = rent as f64 / LAMPORTS_PER_SOL as f64;
+                result
+                    .simulation
+                    .get_or_insert_with(SimulationReport::default)
+                    .merge(submission.simulation);
+            }
+            Err(e) => {
+                result.error = Some(format!("关闭账户失败: {}", e));
+                result.failure_kind = Some(FailureKind::from(&e));
+            }
+        }
+    }
+
     /// -- 批量销毁并关闭零值代币账户
     ///
     /// 批量处理零值代币账户，包括：
@@ -723,84 +1631,176 @@ impl TokenAccountManager {
     /// * `batch_size` - 每批处理的账户数量
     ///
     /// # 返回
-    /// * `Result<(), Box<dyn Error>>` - 处理结果
+    /// * `TokenAccountResult<BatchCloseReport>` - 每个账户的处理结果和统计信息；超过费用预算或价格查询失败时返回错误
     ///
     /// # 说明
     /// - 每个账户都会单独处理，确保操作的安全性
-    /// - 会自动跳过白名单中的代币账户
-    /// - 处理过程中会记录详细的操作日志
-    /// - 最后会输出统计信息，包括：
-    ///   - 成功处理的账户数量
-    ///   - 失败的账户数量
-    ///   - 回收的总租金
-    ///   - GAS 消耗
+    /// - 会自动跳过白名单中的代币账户，未开启 `burn_nfts` 时跳过 NFT 账户
+    /// - 配置了 `max_usd_value_to_burn` 时，跳过 USD 价值超过上限的账户，价格查询失败时不销毁任何账户
+    /// - 处理过程中会记录详细的操作日志，并调用设置的 [`BatchObserver`] 回调
+    /// - 报告中包含成功和失败的账户、回收的总租金以及 GAS 消耗
+    /// - 配置为 [`SubmitMode::Jito`] 时，交易以 bundle 形式提交到 Jito block engine
     pub async fn batch_burn_and_close_zero_value_accounts(
         &self,
         accounts: &[ZeroValueTokenInfo],
         batch_size: usize,
-    ) -> Result<(), Box<dyn Error>> {
+    ) -> TokenAccountResult<BatchCloseReport> {
+        let mut report = BatchCloseReport {
+            simulated: self.config.dry_run,
+            ..BatchCloseReport::default()
+        };
+
         if accounts.is_empty() {
             warn!("没有找到可关闭的零值代币账户");
-            return Ok(());
+            return Ok(report);
         }
 
-        let balance_before = self
-            .connection
-            .get_balance(&self.wallet.pubkey())
-            .unwrap_or(0);
-        let balance_before_sol = balance_before as f64 / LAMPORTS_PER_SOL as f64;
-
-        let mut success_count = 0;
-        let mut fail_count = 0;
-        let mut total_rent_recovered = 0.0;
+        // -- NFT 保护：未开启 `burn_nfts` 时跳过 NFT 账户（黑名单代币除外）
+        let accounts: Vec<ZeroValueTokenInfo> = accounts
+            .iter()
+            .filter(|account| {
+                let skip = account.is_nft
+                    && !self.config.burn_nfts
+                    && !self.is_token_blacklisted(&account.mint);
+                if skip {
+                    warn!("跳过 NFT 账户: {} ({})", account.address, account.symbol);
+                }
+                !skip
+            })
+            .cloned()
+            .collect();
 
-        for (i, chunk) in accounts.chunks(batch_size).enumerate() {
-            info!("\n处理第 {} 批, 共 {} 个账户", i + 1, chunk.len());
+        // -- 价值检查：超过 USD 上限的账户无论是否在白名单中都不销毁
+        let accounts = &self.exclude_valuable_accounts(&accounts).await?;
 
-            for account in chunk {
-                let pubkey = Pubkey::from_str(&account.address).unwrap();
-                let result = self.burn_and_close_account(&pubkey).await;
+        report.estimated_fees_lamports = self.check_fee_budget(&self.burn_plan(accounts))?;
 
-                if result.success {
-                    success_count += 1;
-                    total_rent_recovered += result.rent_recovered;
+        let balance_before = self.tracked_balance()?;
 
-                    info!("成功处理账户: {}", result.account_address);
-                    info!("代币 Symbol: {}", account.symbol);
-                    info!("销毁数量: {}", result.burned_amount);
-                    info!("销毁交易: {}", result.burn_signature.unwrap());
-                    info!("关闭交易: {}", result.close_signature.unwrap());
-                    info!("回收租金: {} SOL", result.rent_recovered);
-                } else {
-                    fail_count += 1;
-                    error!("处理失败: {}", result.account_address);
-                    error!("错误信息: {}", result.error.unwrap());
+        if let Some((tip_lamports, block_engine_url)) = self.config.jito_target() {
+            // -- Jito bundle 模式：每个账户一笔销毁并关闭交易，打包为 bundle 发送
+            if self.config.dust_swap.is_some() {
+                warn!("Jito bundle 模式不支持卖出剩余代币，全部直接销毁");
+            }
+            report.outcomes = match self.check_wallet_reserve(&self.burn_plan(accounts)) {
+                Ok(()) => {
+                    self.batch_burn_and_close_via_jito(accounts, tip_lamports, block_engine_url)
+                        .await
                 }
+                Err(e) => {
+                    error!("{}，停止处理剩余账户", e);
+                    failed_outcomes(accounts, &e)
+                }
+            };
+            for outcome in &report.outcomes {
+                notify_outcome(self.observer.as_ref(), outcome);
             }
+        } else {
+            let total_batches = accounts.len().div_ceil(batch_size);
+            for (i, chunk) in accounts.chunks(batch_size).enumerate() {
+                if let Err(e) = self.check_wallet_reserve(&self.burn_plan(chunk)) {
+                    error!("{}，停止处理剩余账户", e);
+                    for outcome in failed_outcomes(&accounts[i * batch_size..], &e) {
+                        notify_outcome(self.observer.as_ref(), &outcome);
+                        report.outcomes.push(outcome);
+                    }
+                    break;
+                }
 
-            // -- 批次间延时
-            if i * batch_size < accounts.len() {
-                thread::sleep(Duration::from_millis(2000));
-            }
-        }
+                info!("\n处理第 {} 批, 共 {} 个账户", i + 1, chunk.len());
+                self.observer
+                    .on_batch_start(i + 1, total_batches, chunk.len());
 
-        let balance_after = self
-            .connection
-            .get_balance(&self.wallet.pubkey())
-            .unwrap_or(0);
-        let balance_after_sol = balance_after as f64 / LAMPORTS_PER_SOL as f64;
-        let actual_recovered = balance_after_sol - balance_before_sol;
-        let gas_consumed = actual_recovered - total_rent_recovered;
+                for account in chunk {
+                    let pubkey = match Pubkey::from_str(&account.address) {
+                        Ok(pubkey) => pubkey,
+                        Err(e) => {
+                            let e = TokenAccountError::AccountParseError(e.to_string());
+                            error!("处理失败: {}", account.address);
+                            error!("错误信息: {}", e);
+                            let outcome = AccountOutcome::failed(
+                                &account.address,
+                                &account.mint,
+                                &account.symbol,
+                                &e,
+                            );
+                            notify_outcome(self.observer.as_ref(), &outcome);
+                            report.outcomes.push(outcome);
+                            continue;
+                        }
+                    };
+                    let result = self
+                        .burn_and_close_account_checked(&pubkey, account.expected_decimals())
+                        .await;
+
+                    if result.success {
+                        info!("成功处理账户: {}", result.account_address);
+                        info!("代币 Symbol: {}", account.symbol);
+                        match &result.swap {
+                            Some(swap) => info!(
+                                "卖出数量: {}, 预计换得: {}",
+                                swap.input_amount, swap.output_amount
+                            ),
+                            None => info!("销毁数量: {}", result.burned_amount),
+                        }
+                        info!(
+                            "销毁交易: {}",
+                            result.burn_signature.as_deref().unwrap_or("-")
+                        );
+                        info!(
+                            "关闭交易: {}",
+                            result.close_signature.as_deref().unwrap_or("-")
+                        );
+                        info!("回收租金: {} SOL", result.rent_recovered);
+                    } else {
+                        error!("处理失败: {}", result.account_address);
+                        error!("错误信息: {}", result.error.as_deref().unwrap_or("-"));
+                    }
 
-        info!("\n====== 处理完成 ======");
-        info!("执行前钱包余额: {} SOL", balance_before_sol);
-        info!("执行后钱包余额: {} SOL", balance_after_sol);
-        info!("实际增加余额: {} SOL", actual_recovered);
-        info!("成功处理: {} 个账户", success_count);
-        info!("失败数量: {} 个账户", fail_count);
-        info!("预计回收租金: {} SOL", total_rent_recovered);
-        info!("GAS 消耗: {} SOL", gas_consumed);
+                    let outcome = AccountOutcome {
+                        account_address: result.account_address,
+                        mint: account.mint.clone(),
+                        symbol: account.symbol.clone(),
+                        success: result.success,
+                        signature: result.close_signature,
+                        burn_signature: result.burn_signature,
+                        burned_amount: result.burned_amount,
+                        swept_amount: 0,
+                        swap: result.swap,
+                        unwrapped_lamports: result.unwrapped_lamports,
+                        rent_lamports: if result.success {
+                            (result.rent_recovered * LAMPORTS_PER_SOL as f64).round() as u64
+                        } else {
+                            0
+                        },
+                        error: result.error,
+                        failure_kind: result.failure_kind,
+                        simulation: result.simulation,
+                        already_closed: false,
+                    };
+                    notify_outcome(self.observer.as_ref(), &outcome);
+                    report.outcomes.push(outcome);
+                }
 
-        Ok(())
+                // -- 批次间延时，最后一批之后不再等待
+                if i + 1 < total_batches && !self.config.dry_run {
+                    tokio::time::sleep(self.config.batch_delay).await;
+                }
+            }
+        }
+
+        let balance_after = if self.config.dry_run {
+            balance_before
+        } else {
+            self.tracked_balance()?
+        };
+        report.finalize(balance_before, balance_after);
+        self.log_batch_report(&report);
+        self.observer.on_complete(&report);
+        self.record_history(RunOperation::Burn, &report);
+        self.save_run_report(RunOperation::Burn, &report);
+        self.send_notifications(&report).await;
+
+        Ok(report)
     }
 }
@@ -0,0 +1,164 @@
+use crate::account_info::TokenAccountInfo;
+use crate::operations::fetch_lookup_table;
+use crate::TokenAccountManager;
+use solana_sdk::{
+    address_lookup_table::instruction::{create_lookup_table, extend_lookup_table},
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::Transaction,
+};
+use std::{
+    str::FromStr,
+    time::{Duration, Instant},
+};
+use tracing::info;
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 单条扩展指令写入的地址数量，保证扩展交易不超过大小上限
+const EXTEND_CHUNK_SIZE: usize = 20;
+
+/// -- 等待新地址生效的超时时间
+const ACTIVATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// -- 轮询 slot 的间隔
+const SLOT_POLL_INTERVAL: Duration = Duration::from_millis(400);
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 设置批量关闭使用的地址查找表
+    ///
+    /// # 参数
+    /// * `table` - 查找表地址，`None` 表示使用 legacy 交易
+    pub fn set_lookup_table(&mut self, table: Option<Pubkey>) {
+        self.config.lookup_table = table;
+    }
+
+    /// -- 创建地址查找表，管理器钱包作为管理权限并支付租金
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Pubkey>` - 新查找表的地址
+    pub async fn create_lookup_table(&self) -> TokenAccountResult<Pubkey> {
+        let payer = self.wallet.pubkey();
+        let recent_slot = self.connection.get_slot()?;
+        let (instruction, table) = create_lookup_table(payer, payer, recent_slot);
+
+        let signature = self.send_lookup_table_instruction(instruction)?;
+        info!("已创建地址查找表: {}", table);
+        info!("交易签名: {}", signature);
+
+        Ok(table)
+    }
+
+    /// -- 向地址查找表追加地址，已存在的地址会被跳过
+    ///
+    /// 追加完成后等待进入下一个 slot，新地址生效后才能被交易引用。
+    ///
+    /// # 参数
+    /// * `table` - 查找表地址
+    /// * `addresses` - 要追加的地址
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<usize>` - 实际追加的地址数量
+    pub async fn extend_lookup_table(
+        &self,
+        table: &Pubkey,
+        addresses: &[Pubkey],
+    ) -> TokenAccountResult<usize> {
+        // -- 刚创建的查找表可能尚未被当前节点看到，此时视为空表
+        let existing = fetch_lookup_table(&self.connection, table)
+            .map(|lookup_table| lookup_table.addresses)
+            .unwrap_or_default();
+
+        let mut missing: Vec<Pubkey> = Vec::new();
+        for address in addresses {
+            if !existing.contains(address) && !missing.contains(address) {
+                missing.push(*address);
+            }
+        }
+        if missing.is_empty() {
+            return Ok(0);
+        }
+
+        let payer = self.wallet.pubkey();
+        for chunk in missing.chunks(EXTEND_CHUNK_SIZE) {
+            let instruction = extend_lookup_table(*table, payer, Some(payer), chunk.to_vec());
+            let signature = self.send_lookup_table_instruction(instruction)?;
+            info!(
+                "查找表已追加 {} 个地址，交易签名: {}",
+                chunk.len(),
+                signature
+            );
+        }
+
+        self.wait_for_next_slot().await?;
+        Ok(missing.len())
+    }
+
+    /// -- 准备批量关闭使用的地址查找表
+    ///
+    /// 未指定查找表时新建一个，然后把待关闭的账户地址写入查找表。
+    ///
+    /// # 参数
+    /// * `table` - 已有的查找表地址，`None` 表示新建
+    /// * `accounts` - 待关闭的账户列表
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Pubkey>` - 可直接传给 [`Self::set_lookup_table`] 的查找表地址
+    pub async fn prepare_lookup_table(
+        &self,
+        table: Option<Pubkey>,
+        accounts: &[TokenAccountInfo],
+    ) -> TokenAccountResult<Pubkey> {
+        let table = match table {
+            Some(table) => table,
+            None => self.create_lookup_table().await?,
+        };
+
+        let addresses = accounts
+            .iter()
+            .map(|account| {
+                Pubkey::from_str(&account.address)
+                    .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))
+            })
+            .collect::<TokenAccountResult<Vec<_>>>()?;
+        self.extend_lookup_table(&table, &addresses).await?;
+
+        Ok(table)
+    }
+
+    /// -- 发送单条查找表指令
+    fn send_lookup_table_instruction(
+        &self,
+        instruction: Instruction,
+    ) -> TokenAccountResult<String> {
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.wallet.pubkey()),
+            &[&self.wallet],
+            self.connection.get_latest_blockhash()?,
+        );
+        let signature = self
+            .connection
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+
+        Ok(signature.to_string())
+    }
+
+    /// -- 等待 slot 前进，使刚追加的地址生效
+    async fn wait_for_next_slot(&self) -> TokenAccountResult<()> {
+        let start_slot = self.connection.get_slot()?;
+        let started = Instant::now();
+
+        while self.connection.get_slot()? <= start_slot {
+            if started.elapsed() >= ACTIVATION_TIMEOUT {
+                return Err(TokenAccountError::TransactionError(
+                    "等待查找表地址生效超时".to_string(),
+                ));
+            }
+            tokio::time::sleep(SLOT_POLL_INTERVAL).await;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use crate::retry::is_account_missing;
+use mpl_token_metadata::accounts::MasterEdition;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use spl_token_2022::state::Mint;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tracing::{info, warn};
+use utils::{fetch_token_info, format_metadata, RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 缓存的代币元数据
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    pub mint: String,    // -- 代币的 Mint 地址
+    pub symbol: String,  // -- 代币符号（已去除末尾的空字符）
+    pub name: String,    // -- 代币名称（已去除末尾的空字符）
+    pub decimals: u8,    // -- 小数位数
+    pub fetched_at: u64, // -- 查询时间（Unix 秒）
+    #[serde(default)]
+    pub is_nft: bool, // -- 是否为 NFT
+    #[serde(default)]
+    pub uri: String, // -- 元数据 URI（已去除末尾的空字符）
+    #[serde(default)]
+    pub is_mutable: bool, // -- 元数据是否仍可修改
+    #[serde(default)]
+    pub freeze_authority: Option<String>, // -- Mint 的冻结权限地址（已放弃时为 None）
+}
+
+/// -- 代币元数据缓存
+///
+/// 以 Mint 地址为键缓存元数据，超过 TTL 的条目视为过期。
+/// 指定文件路径时，启动时从文件加载，每次写入新条目后保存到文件，供下次运行复用。
+/// 只缓存查询成功的结果，查询失败的 Mint 下次仍会重新查询。
+pub struct TokenMetadataCache {
+    ttl: Duration,                                  // -- 条目有效期
+    path: Option<PathBuf>,                          // -- 磁盘缓存文件路径
+    entries: Mutex<HashMap<String, TokenMetadata>>, // -- 以 Mint 地址为键的缓存条目
+}
+
+impl TokenMetadataCache {
+    /// -- 创建缓存，指定路径且文件存在时从文件加载
+    ///
+    /// # 参数
+    /// * `ttl` - 条目有效期
+    /// * `path` - 磁盘缓存文件路径，None 表示只使用内存缓存
+    pub fn new(ttl: Duration, path: Option<PathBuf>) -> Self {
+        let entries = path
+            .as_deref()
+            .and_then(|path| match load_entries(path) {
+                Ok(entries) => Some(entries),
+                Err(e) => {
+                    warn!("读取代币元数据缓存失败: {}", e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            ttl,
+            path,
+            entries: Mutex::new(entries),
+        }
+    }
+
+    /// -- 获取未过期的缓存条目
+    pub fn get(&self, mint: &str) -> Option<TokenMetadata> {
+        let entries = self.entries.lock().unwrap();
+        let entry = entries.get(mint)?;
+        (unix_now().saturating_sub(entry.fetched_at) < self.ttl.as_secs()).then(|| entry.clone())
+    }
+
+    /// -- 写入缓存条目，配置了磁盘缓存时同步保存到文件
+    pub fn insert_all(&self, items: impl IntoIterator<Item = TokenMetadata>) {
+        let mut entries = self.entries.lock().unwrap();
+        for item in items {
+            entries.insert(item.mint.clone(), item);
+        }
+
+        if let Some(path) = &self.path {
+            if let Err(e) = save_entries(path, &entries) {
+                warn!("保存代币元数据缓存失败: {}", e);
+            }
+        }
+    }
+
+    /// -- 清空缓存（包括磁盘缓存文件）
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+        if let Some(path) = &self.path {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    /// -- 缓存条目数量（包括已过期的条目）
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    /// -- 缓存是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// -- 当前 Unix 时间（秒）
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+/// -- 判断 Mint 是否为 NFT
+///
+/// 小数位数为 0 且供应量为 1，或存在 Metaplex master edition 账户时视为 NFT。
+/// 只有 master edition 账户不存在时才视为非 NFT，限流、超时等其他查询错误直接返回，
+/// 避免把 NFT 误判为可销毁的代币。
+fn is_nft<R: RpcApi>(connection: &R, mint: &str, mint_account: &Mint) -> TokenAccountResult<bool> {
+    if mint_account.decimals != 0 {
+        return Ok(false);
+    }
+    if mint_account.supply == 1 {
+        return Ok(true);
+    }
+
+    let mint =
+        Pubkey::from_str(mint).map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+    let (master_edition, _) = MasterEdition::find_pda(&mint);
+    match connection.get_account(&master_edition) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let e = TokenAccountError::from(e);
+            if is_account_missing(&e) {
+                Ok(false)
+            } else {
+                Err(e)
+            }
+        }
+    }
+}
+
+/// -- 查询单个 Mint 的元数据，NFT 判断失败时整体视为查询失败
+fn fetch_metadata<R: RpcApi>(connection: &R, mint: &str) -> anyhow::Result<TokenMetadata> {
+    let (metadata, mint_account) = fetch_token_info(connection, mint)?;
+    info!("代币元数据: {}", format_metadata(&metadata));
+    Ok(TokenMetadata {
+        mint: mint.to_string(),
+        symbol: metadata.symbol.trim_matches(char::from(0)).to_string(),
+        name: metadata.name.trim_matches(char::from(0)).to_string(),
+        decimals: mint_account.decimals,
+        fetched_at: unix_now(),
+        is_nft: is_nft(connection, mint, &mint_account)?,
+        uri: metadata.uri.trim_matches(char::from(0)).to_string(),
+        is_mutable: metadata.is_mutable,
+        freeze_authority: Option::<Pubkey>::from(mint_account.freeze_authority)
+            .map(|authority| authority.to_string()),
+    })
+}
+
+/// -- 从文件加载缓存条目，文件不存在时返回空表
+fn load_entries(path: &Path) -> std::io::Result<HashMap<String, TokenMetadata>> {
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// -- 将缓存条目写入文件
+fn save_entries(path: &Path, entries: &HashMap<String, TokenMetadata>) -> std::io::Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(entries)?)
+}
+
+/// -- 简单的请求速率限制器
+///
+/// 按固定间隔为每次请求分配发送时间，多个线程共享同一个限制器。
+struct RateLimiter {
+    interval: Duration,        // -- 两次请求之间的最小间隔
+    next_slot: Mutex<Instant>, // -- 下一次请求可以发出的时间
+}
+
+impl RateLimiter {
+    /// -- 创建限制器，`per_second` 为每秒最多允许的请求数
+    fn new(per_second: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / per_second.max(1),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// -- 等待直到可以发出下一次请求
+    fn acquire(&self) {
+        let wait = {
+            let mut next_slot = self.next_slot.lock().unwrap();
+            let now = Instant::now();
+            let slot = (*next_slot).max(now);
+            *next_slot = slot + self.interval;
+            slot - now
+        };
+        if !wait.is_zero() {
+            thread::sleep(wait);
+        }
+    }
+}
+
+/// -- 并发查询多个代币的元数据
+///
+/// 优先使用缓存中未过期的条目，其余 Mint 查询后写入缓存。RPC 调用是阻塞的，
+/// 因此使用固定数量的工作线程并发查询，每个 Mint 只查询一次，查询失败的 Mint 记为 None。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `cache` - 代币元数据缓存
+/// * `mints` - 要查询的 Mint 地址（可重复）
+/// * `concurrency` - 最大并发查询数
+/// * `requests_per_second` - 每秒最多发起的元数据查询数，None 表示不限制
+///
+/// # 返回
+/// * `HashMap<String, Option<TokenMetadata>>` - 以 Mint 地址为键的查询结果
+pub(crate) fn fetch_token_infos<R: RpcApi>(
+    connection: &R,
+    cache: &TokenMetadataCache,
+    mints: &[String],
+    concurrency: usize,
+    requests_per_second: Option<u32>,
+) -> HashMap<String, Option<TokenMetadata>> {
+    let mut results = HashMap::new();
+    let mut pending: Vec<&str> = Vec::new();
+    for mint in mints {
+        if results.contains_key(mint) || pending.contains(&mint.as_str()) {
+            continue;
+        }
+        match cache.get(mint) {
+            Some(cached) => {
+                results.insert(mint.clone(), Some(cached));
+            }
+            None => pending.push(mint),
+        }
+    }
+    if pending.is_empty() {
+        return results;
+    }
+    info!(
+        "代币元数据缓存命中 {} 个，需要查询 {} 个",
+        results.len(),
+        pending.len()
+    );
+
+    let limiter = requests_per_second.map(RateLimiter::new);
+    let queue = Mutex::new(pending.into_iter());
+    let fetched = Mutex::new(HashMap::new());
+
+    thread::scope(|scope| {
+        for _ in 0..concurrency.max(1) {
+            scope.spawn(|| loop {
+                let Some(mint) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                if let Some(limiter) = &limiter {
+                    limiter.acquire();
+                }
+
+                let token_info = match fetch_metadata(connection, mint) {
+                    Ok(metadata) => Some(metadata),
+                    Err(e) => {
+                        warn!("获取代币信息失败: {}, 继续处理下一个账户", e);
+                        None
+                    }
+                };
+                fetched.lock().unwrap().insert(mint.to_string(), token_info);
+            });
+        }
+    });
+
+    let fetched = fetched.into_inner().unwrap();
+    cache.insert_all(fetched.values().flatten().cloned());
+    results.extend(fetched);
+    results
+}
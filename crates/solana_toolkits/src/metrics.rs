@@ -0,0 +1,187 @@
+use crate::{
+    account_info::{AccountOutcome, FailureKind, TokenAccountsResult},
+    observer::BatchObserver,
+};
+use std::{
+    fmt::Write as _,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, ToSocketAddrs},
+};
+use tracing::{info, warn};
+use utils::{TokenAccountError, TokenAccountResult};
+
+/// -- Prometheus 文本格式的 Content-Type
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// -- 清理操作的 Prometheus 指标
+///
+/// 实现了 [`BatchObserver`]，通过 `manager.set_observer(metrics.clone())` 挂到管理器上即可累计计数，
+/// 多个管理器可以共用同一个实例。模拟模式下的成功结果同样计入关闭、销毁和回收租金。
+///
+/// # 示例
+/// ```no_run
+/// use solana_toolkits::{metrics::{serve_metrics, CleanupMetrics}, TokenAccountManager};
+/// use std::sync::Arc;
+///
+/// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+///     let metrics = Arc::new(CleanupMetrics::default());
+///     tokio::spawn(serve_metrics("0.0.0.0:9184", metrics.clone()));
+///
+///     let mut manager = TokenAccountManager::new("wallet.json")?;
+///     manager.set_observer(metrics.clone());
+///     let result = manager.get_closeable_accounts().await?;
+///     manager.batch_close_accounts(&result.accounts, 5, true).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct CleanupMetrics {
+    accounts_scanned: AtomicU64,        // -- 扫描的代币账户数
+    accounts_closed: AtomicU64,         // -- 关闭成功的账户数
+    accounts_burned: AtomicU64,         // -- 销毁代币后关闭的账户数
+    accounts_failed: AtomicU64,         // -- 处理失败的账户数
+    rent_recovered_lamports: AtomicU64, // -- 回收的租金（lamports）
+    rpc_errors: AtomicU64,              // -- RPC 请求失败次数
+    retries: AtomicU64,                 // -- 批次重试次数
+}
+
+impl CleanupMetrics {
+    /// -- 以 Prometheus 文本格式输出全部指标
+    pub fn render(&self) -> String {
+        let metrics = [
+            (
+                "solana_cleanup_accounts_scanned_total",
+                "扫描的代币账户数",
+                &self.accounts_scanned,
+            ),
+            (
+                "solana_cleanup_accounts_closed_total",
+                "关闭成功的账户数",
+                &self.accounts_closed,
+            ),
+            (
+                "solana_cleanup_accounts_burned_total",
+                "销毁代币后关闭的账户数",
+                &self.accounts_burned,
+            ),
+            (
+                "solana_cleanup_accounts_failed_total",
+                "处理失败的账户数",
+                &self.accounts_failed,
+            ),
+            (
+                "solana_cleanup_rent_recovered_lamports_total",
+                "回收的租金（lamports）",
+                &self.rent_recovered_lamports,
+            ),
+            (
+                "solana_cleanup_rpc_errors_total",
+                "RPC 请求失败次数",
+                &self.rpc_errors,
+            ),
+            (
+                "solana_cleanup_retries_total",
+                "批次重试次数",
+                &self.retries,
+            ),
+        ];
+
+        let mut output = String::new();
+        for (name, help, value) in metrics {
+            let _ = writeln!(output, "# HELP {} {}", name, help);
+            let _ = writeln!(output, "# TYPE {} counter", name);
+            let _ = writeln!(output, "{} {}", name, value.load(Ordering::Relaxed));
+        }
+        output
+    }
+}
+
+impl BatchObserver for CleanupMetrics {
+    fn on_scan_complete(&self, result: &TokenAccountsResult) {
+        self.accounts_scanned
+            .fetch_add(result.total_accounts as u64, Ordering::Relaxed);
+    }
+
+    fn on_account_closed(&self, outcome: &AccountOutcome) {
+        self.accounts_closed.fetch_add(1, Ordering::Relaxed);
+        if outcome.burn_signature.is_some() || outcome.burned_amount > 0 {
+            self.accounts_burned.fetch_add(1, Ordering::Relaxed);
+        }
+        self.rent_recovered_lamports
+            .fetch_add(outcome.rent_lamports, Ordering::Relaxed);
+    }
+
+    fn on_account_failed(&self, outcome: &AccountOutcome) {
+        self.accounts_failed.fetch_add(1, Ordering::Relaxed);
+        if outcome.failure_kind == Some(FailureKind::Rpc) {
+            self.rpc_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn on_retry(&self, _batch: usize, _attempt: u32, error: &TokenAccountError) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+        if matches!(error, TokenAccountError::RpcError(_)) {
+            self.rpc_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// -- 在指定地址提供 `/metrics` HTTP 接口，供 Prometheus 抓取
+///
+/// 只处理 `GET /metrics`，其余路径返回 404。该函数持续运行，通常放到单独的任务中。
+///
+/// # 参数
+/// * `addr` - 监听地址，例如 `0.0.0.0:9184`
+/// * `metrics` - 指标实例
+///
+/// # 返回
+/// * `TokenAccountResult<()>` - 只有监听失败时返回错误
+pub async fn serve_metrics(
+    addr: impl ToSocketAddrs,
+    metrics: Arc<CleanupMetrics>,
+) -> TokenAccountResult<()> {
+    let listener = TcpListener::bind(addr).await?;
+    info!("指标接口已启动: http://{}/metrics", listener.local_addr()?);
+
+    loop {
+        let (mut stream, peer) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(e) => {
+                warn!("接受指标请求失败: {}", e);
+                continue;
+            }
+        };
+        let metrics = metrics.clone();
+        tokio::spawn(async move {
+            let mut buffer = [0; 1024];
+            let read = match stream.read(&mut buffer).await {
+                Ok(read) => read,
+                Err(e) => {
+                    warn!("读取 {} 的指标请求失败: {}", peer, e);
+                    return;
+                }
+            };
+            let request = String::from_utf8_lossy(&buffer[..read]);
+            let response = if request.starts_with("GET /metrics ") {
+                let body = metrics.render();
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\n\r\n{}",
+                    CONTENT_TYPE,
+                    body.len(),
+                    body
+                )
+            } else {
+                String::from("HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")
+            };
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                warn!("返回指标给 {} 失败: {}", peer, e);
+            }
+        });
+    }
+}
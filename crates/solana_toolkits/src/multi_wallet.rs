@@ -0,0 +1,565 @@
+use crate::account_info::{BatchCloseReport, ZeroValueTokenInfo};
+use crate::config::TokenAccountConfig;
+use crate::observer::BatchObserver;
+use crate::operations::{submit_with_fresh_blockhash, with_compute_budget};
+use crate::TokenAccountManager;
+use futures::stream::{self, StreamExt};
+use serde::Serialize;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signer::Signer, system_instruction,
+    transaction::Transaction,
+};
+use std::{
+    fs,
+    future::Future,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tracing::{error, info, warn};
+use utils::{
+    address_book::address_label, init_cluster_rpc_client, wallet::parse_keypair, RpcApi,
+    TokenAccountError, TokenAccountResult,
+};
+
+/// -- 多钱包批量操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WalletOperation {
+    /// 只扫描账户，不发送交易
+    Scan,
+    /// 关闭余额为 0 的账户
+    Close,
+    /// 销毁并关闭零值代币账户（wSOL 账户只解包）
+    Burn,
+}
+
+/// -- 单个钱包的处理结果
+#[derive(Debug, Clone, Serialize)]
+pub struct WalletReport {
+    pub label: String,                    // -- 钱包标识（密钥文件路径或钱包地址）
+    pub wallet: String,                   // -- 钱包地址
+    pub closable_accounts: usize,         // -- 余额为 0 的账户数量
+    pub zero_value_accounts: usize,       // -- 零值代币账户数量
+    pub total_rent_sol: f64,              // -- 扫描到的可回收租金（SOL）
+    pub report: Option<BatchCloseReport>, // -- 关闭或销毁的批量结果（仅扫描时为 None）
+    pub error: Option<String>,            // -- 扫描或处理失败时的错误信息
+}
+
+impl WalletReport {
+    /// -- 是否处理成功（扫描成功且没有失败的账户）
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+            && self
+                .report
+                .as_ref()
+                .is_none_or(BatchCloseReport::is_success)
+    }
+}
+
+/// -- 多钱包汇总报告
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MultiWalletReport {
+    pub wallets: Vec<WalletReport>, // -- 每个钱包的处理结果，顺序与加载顺序一致
+    pub succeeded: usize,           // -- 处理成功的钱包数量
+    pub failed: usize,              // -- 处理失败的钱包数量
+    pub accounts_closed: usize,     // -- 成功关闭的账户总数
+    pub rent_recovered_lamports: u64, // -- 回收的租金总量（以 lamports 为单位）
+    pub rent_recovered_sol: f64,    // -- 回收的租金总量（以 SOL 为单位）
+    pub fees_lamports: u64,         // -- 交易费用总量（lamports）
+}
+
+impl MultiWalletReport {
+    /// -- 根据每个钱包的结果汇总统计信息
+    fn from_wallets(wallets: Vec<WalletReport>) -> Self {
+        let reports = || wallets.iter().filter_map(|wallet| wallet.report.as_ref());
+        let succeeded = wallets.iter().filter(|wallet| wallet.is_success()).count();
+        let rent_recovered_lamports = reports().map(|r| r.rent_recovered_lamports).sum();
+
+        Self {
+            succeeded,
+            failed: wallets.len() - succeeded,
+            accounts_closed: reports().map(|r| r.succeeded).sum(),
+            rent_recovered_lamports,
+            rent_recovered_sol: rent_recovered_lamports as f64 / LAMPORTS_PER_SOL as f64,
+            fees_lamports: reports().map(|r| r.fees_lamports).sum(),
+            wallets,
+        }
+    }
+}
+
+/// -- 单个钱包的 SOL 归集结果
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsolidationOutcome {
+    pub label: String,             // -- 钱包标识（密钥文件路径或钱包地址）
+    pub wallet: String,            // -- 钱包地址
+    pub balance_lamports: u64,     // -- 归集前余额（lamports）
+    pub transferred_lamports: u64, // -- 转入主钱包的数量（lamports），跳过或失败时为 0
+    pub signature: Option<String>, // -- 转账交易签名（模拟模式或跳过时为 None）
+    pub error: Option<String>,     // -- 失败时的错误信息
+}
+
+/// -- SOL 归集汇总报告
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConsolidationReport {
+    pub master: String,                      // -- 接收 SOL 的主钱包地址
+    pub outcomes: Vec<ConsolidationOutcome>, // -- 每个钱包的归集结果，顺序与加载顺序一致
+    pub succeeded: usize,                    // -- 成功转出的钱包数量
+    pub skipped: usize,                      // -- 余额不足或为主钱包本身而跳过的钱包数量
+    pub failed: usize,                       // -- 失败的钱包数量
+    pub transferred_lamports: u64,           // -- 转入主钱包的总量（lamports）
+    pub transferred_sol: f64,                // -- 转入主钱包的总量（SOL）
+    pub simulated: bool,                     // -- 是否为模拟执行（未发送交易）
+}
+
+impl ConsolidationReport {
+    /// -- 根据每个钱包的结果汇总统计信息
+    fn from_outcomes(
+        master: &Pubkey,
+        outcomes: Vec<ConsolidationOutcome>,
+        simulated: bool,
+    ) -> Self {
+        let failed = outcomes.iter().filter(|o| o.error.is_some()).count();
+        let succeeded = outcomes
+            .iter()
+            .filter(|o| o.error.is_none() && o.transferred_lamports > 0)
+            .count();
+        let transferred_lamports = outcomes.iter().map(|o| o.transferred_lamports).sum();
+
+        Self {
+            master: master.to_string(),
+            succeeded,
+            skipped: outcomes.len() - succeeded - failed,
+            failed,
+            transferred_lamports,
+            transferred_sol: transferred_lamports as f64 / LAMPORTS_PER_SOL as f64,
+            simulated,
+            outcomes,
+        }
+    }
+}
+
+/// -- 多钱包管理器
+///
+/// 为每个钱包创建一个 [`TokenAccountManager`]，在所有钱包上执行扫描、关闭或销毁流程，
+/// 可以逐个执行，也可以限制并发数量并行执行。
+pub struct MultiWalletManager<R: RpcApi = RpcClient> {
+    wallets: Vec<(String, TokenAccountManager<R>)>, // -- (钱包标识, 管理器)
+    concurrency: usize,                             // -- 同时处理的钱包数量，1 表示逐个执行
+}
+
+impl MultiWalletManager {
+    /// -- 从目录加载全部密钥文件
+    ///
+    /// 读取目录下所有 `.json` 文件（按文件名排序），每个文件对应一个钱包。
+    /// 与单钱包不同，这里不会读取 `WALLET_PRIVATE_KEY` 环境变量。
+    ///
+    /// # 参数
+    /// * `dir` - 密钥文件目录
+    /// * `config` - 每个钱包共用的配置参数
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Self>` - 成功返回管理器实例，目录中没有密钥文件时返回错误
+    pub fn from_dir(dir: impl AsRef<Path>, config: TokenAccountConfig) -> TokenAccountResult<Self> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir.as_ref())?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+        if paths.is_empty() {
+            return Err(TokenAccountError::Other(format!(
+                "目录中没有密钥文件: {}",
+                dir.as_ref().display()
+            )));
+        }
+
+        let mut wallets = Vec::with_capacity(paths.len());
+        for path in paths {
+            let keypair = parse_keypair(&fs::read_to_string(&path)?).map_err(|e| {
+                TokenAccountError::Other(format!("加载密钥文件 {} 失败: {}", path.display(), e))
+            })?;
+            let connection = init_cluster_rpc_client(&config.cluster(), config.commitment)?;
+            wallets.push((
+                path.display().to_string(),
+                TokenAccountManager::with_rpc(connection, keypair, config.clone())?,
+            ));
+        }
+        info!("已加载 {} 个钱包", wallets.len());
+
+        Ok(Self {
+            wallets,
+            concurrency: 1,
+        })
+    }
+}
+
+impl<R: RpcApi> MultiWalletManager<R> {
+    /// -- 使用已创建的管理器构建多钱包管理器
+    ///
+    /// 钱包标识使用钱包地址，适用于自定义 RPC 实现（如单元测试中的 `MockRpc`）。
+    ///
+    /// # 参数
+    /// * `managers` - 每个钱包的管理器
+    pub fn with_managers(managers: Vec<TokenAccountManager<R>>) -> Self {
+        Self {
+            wallets: managers
+                .into_iter()
+                .map(|manager| (manager.wallet.pubkey().to_string(), manager))
+                .collect(),
+            concurrency: 1,
+        }
+    }
+
+    /// -- 设置同时处理的钱包数量
+    ///
+    /// # 参数
+    /// * `concurrency` - 并发数量，1 表示逐个执行
+    pub fn set_concurrency(&mut self, concurrency: usize) {
+        self.concurrency = concurrency.max(1);
+    }
+
+    /// -- 为所有钱包设置同一个批量操作进度观察者
+    ///
+    /// 并行处理时多个钱包的事件会交错回调。
+    ///
+    /// # 参数
+    /// * `observer` - 实现了 [`BatchObserver`] 的观察者
+    pub fn set_observer(&mut self, observer: Arc<dyn BatchObserver>) {
+        for (_, manager) in &mut self.wallets {
+            manager.set_observer(observer.clone());
+        }
+    }
+
+    /// -- 为所有钱包设置同一个手续费支付者
+    ///
+    /// 由一个有余额的钱包支付全部交易费用，可以清理 SOL 余额为 0 的钱包。
+    ///
+    /// # 参数
+    /// * `fee_payer` - 支付交易费用的签名者
+    pub fn set_fee_payer(&mut self, fee_payer: Arc<dyn Signer + Send + Sync>) {
+        for (_, manager) in &mut self.wallets {
+            manager.set_fee_payer(fee_payer.clone());
+        }
+    }
+
+    /// -- 将代币符号加入所有钱包的白名单
+    ///
+    /// # 参数
+    /// * `symbols` - 代币符号列表
+    pub fn add_symbols_to_whitelist(&mut self, symbols: &[&str]) {
+        for (_, manager) in &mut self.wallets {
+            manager.add_symbols_to_whitelist(symbols);
+        }
+    }
+
+    /// -- 将代币 Mint 地址加入所有钱包的黑名单
+    ///
+    /// # 参数
+    /// * `mints` - 代币 Mint 地址列表
+    pub fn add_mints_to_blacklist(&mut self, mints: &[&str]) {
+        for (_, manager) in &mut self.wallets {
+            manager.add_mints_to_blacklist(mints);
+        }
+    }
+
+    /// -- 钱包数量
+    pub fn len(&self) -> usize {
+        self.wallets.len()
+    }
+
+    /// -- 是否没有任何钱包
+    pub fn is_empty(&self) -> bool {
+        self.wallets.is_empty()
+    }
+
+    /// -- 所有钱包的管理器
+    pub fn managers(&self) -> impl Iterator<Item = &TokenAccountManager<R>> {
+        self.wallets.iter().map(|(_, manager)| manager)
+    }
+
+    /// -- 在所有钱包上执行操作
+    ///
+    /// 并发数量大于 1 时，最多同时处理该数量的钱包，结果按加载顺序排列。
+    /// 单个钱包失败不会中断其余钱包。
+    ///
+    /// # 参数
+    /// * `operation` - 要执行的操作
+    /// * `batch_size` - 每批处理的账户数量
+    ///
+    /// # 返回
+    /// * `MultiWalletReport` - 每个钱包的结果和汇总统计
+    pub async fn run(&self, operation: WalletOperation, batch_size: usize) -> MultiWalletReport {
+        info!(
+            "开始处理 {} 个钱包，操作: {:?}，并发数: {}",
+            self.wallets.len(),
+            operation,
+            self.concurrency
+        );
+
+        let wallets = self
+            .map_wallets(|label, manager| run_wallet(label, manager, operation, batch_size))
+            .await;
+
+        let report = MultiWalletReport::from_wallets(wallets);
+        log_multi_wallet_report(&report);
+        report
+    }
+
+    /// -- 将所有钱包的 SOL 归集到主钱包
+    ///
+    /// 每个钱包保留租金豁免最低余额（配置了更高的 `min_wallet_sol_reserve` 时保留该值）和一笔交易的预计费用，
+    /// 其余 SOL 转入主钱包，适合在多个机器人钱包关闭账户回收租金后集中资金。
+    /// 余额不足的钱包和主钱包本身会被跳过，单个钱包失败不会中断其余钱包。
+    ///
+    /// # 参数
+    /// * `master` - 接收 SOL 的主钱包地址
+    ///
+    /// # 返回
+    /// * `ConsolidationReport` - 每个钱包的归集结果和汇总统计
+    pub async fn consolidate_sol(&self, master: &Pubkey) -> ConsolidationReport {
+        info!(
+            "开始将 {} 个钱包的 SOL 归集到 {}，并发数: {}",
+            self.wallets.len(),
+            address_label(&master.to_string()),
+            self.concurrency
+        );
+
+        let outcomes = self
+            .map_wallets(|label, manager| async move { consolidate_wallet(label, manager, master) })
+            .await;
+        let simulated = self.managers().any(|manager| manager.config.dry_run);
+        let report = ConsolidationReport::from_outcomes(master, outcomes, simulated);
+        log_consolidation_report(&report);
+        report
+    }
+
+    /// -- 在所有钱包上执行 `f`，按加载顺序返回结果
+    ///
+    /// 最多同时处理 `concurrency` 个钱包，在当前任务中并发执行，不会阻塞异步运行时。
+    async fn map_wallets<'a, T, F, Fut>(&'a self, f: F) -> Vec<T>
+    where
+        F: Fn(&'a str, &'a TokenAccountManager<R>) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        stream::iter(&self.wallets)
+            .map(|(label, manager)| f(label, manager))
+            .buffered(self.concurrency.max(1))
+            .collect()
+            .await
+    }
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 将钱包中超出保留余额和交易费用的 SOL 转入目标地址
+    ///
+    /// 余额不足时不发送交易，转出数量为 0。
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<(u64, u64, Option<String>)>` - (转出前余额, 转出数量, 交易签名)，模拟模式下签名为 None
+    pub(crate) fn transfer_sol_remainder(
+        &self,
+        destination: &Pubkey,
+    ) -> TokenAccountResult<(u64, u64, Option<String>)> {
+        let wallet = self.wallet.pubkey();
+        let balance = self.connection.get_balance(&wallet)?;
+        let rent_exempt = self.connection.get_minimum_balance_for_rent_exemption(0)?;
+        let reserve = self
+            .config
+            .min_wallet_sol_reserve
+            .map_or(0, |sol| (sol * LAMPORTS_PER_SOL as f64).round() as u64)
+            .max(rent_exempt);
+        // -- 钱包单独签名，交易中只有一条转账指令
+        let amount = balance.saturating_sub(reserve + self.config.estimated_fee_lamports(1, 1));
+        if amount == 0 {
+            info!(
+                "{} 余额 {} lamports 不足以保留 {} lamports 并支付手续费，跳过",
+                address_label(&wallet.to_string()),
+                balance,
+                reserve
+            );
+            return Ok((balance, 0, None));
+        }
+
+        let instructions = with_compute_budget(
+            &self.connection,
+            &self.config,
+            vec![system_instruction::transfer(&wallet, destination, amount)],
+        );
+        let submission =
+            submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+                Ok(Transaction::new_signed_with_payer(
+                    &instructions,
+                    Some(&wallet),
+                    &[&self.wallet],
+                    blockhash,
+                ))
+            })?;
+        info!(
+            "{} 转出 {} SOL 到 {}",
+            address_label(&wallet.to_string()),
+            amount as f64 / LAMPORTS_PER_SOL as f64,
+            address_label(&destination.to_string())
+        );
+
+        Ok((balance, amount, submission.signature))
+    }
+}
+
+/// -- 在单个钱包上归集 SOL
+fn consolidate_wallet<R: RpcApi>(
+    label: &str,
+    manager: &TokenAccountManager<R>,
+    master: &Pubkey,
+) -> ConsolidationOutcome {
+    let mut outcome = ConsolidationOutcome {
+        label: label.to_string(),
+        wallet: manager.wallet.pubkey().to_string(),
+        balance_lamports: 0,
+        transferred_lamports: 0,
+        signature: None,
+        error: None,
+    };
+    if manager.wallet.pubkey() == *master {
+        info!("{} 为主钱包，跳过", label);
+        return outcome;
+    }
+
+    match manager.transfer_sol_remainder(master) {
+        Ok((balance, amount, signature)) => {
+            outcome.balance_lamports = balance;
+            outcome.transferred_lamports = amount;
+            outcome.signature = signature;
+        }
+        Err(e) => {
+            error!("钱包 {} 归集 SOL 失败: {}", label, e);
+            outcome.error = Some(e.to_string());
+        }
+    }
+    outcome
+}
+
+/// -- 在单个钱包上执行操作
+async fn run_wallet<R: RpcApi>(
+    label: &str,
+    manager: &TokenAccountManager<R>,
+    operation: WalletOperation,
+    batch_size: usize,
+) -> WalletReport {
+    let mut report = WalletReport {
+        label: label.to_string(),
+        wallet: manager.wallet.pubkey().to_string(),
+        closable_accounts: 0,
+        zero_value_accounts: 0,
+        total_rent_sol: 0.0,
+        report: None,
+        error: None,
+    };
+
+    let scan = match manager.get_closeable_accounts().await {
+        Ok(scan) => scan,
+        Err(e) => {
+            error!("钱包 {} 扫描失败: {}", label, e);
+            report.error = Some(format!("扫描失败: {}", e));
+            return report;
+        }
+    };
+    report.closable_accounts = scan.closable_accounts;
+    report.zero_value_accounts = scan.zero_value_accounts;
+    report.total_rent_sol = scan.total_rent_sol;
+
+    let result = match operation {
+        WalletOperation::Scan => return report,
+        WalletOperation::Close => manager
+            .batch_close_accounts(&scan.accounts, batch_size, false)
+            .await
+            .map_err(|e| e.to_string()),
+        WalletOperation::Burn => {
+            // -- wSOL 账户一并处理：关闭即解包，不会销毁
+            let accounts: Vec<ZeroValueTokenInfo> = scan
+                .zero_value_accounts_list
+                .iter()
+                .chain(&scan.wrapped_sol_accounts_list)
+                .cloned()
+                .collect();
+            manager
+                .batch_burn_and_close_zero_value_accounts(&accounts, batch_size)
+                .await
+                .map_err(|e| e.to_string())
+        }
+    };
+
+    match result {
+        Ok(batch) => report.report = Some(batch),
+        Err(e) => {
+            error!("钱包 {} 处理失败: {}", label, e);
+            report.error = Some(e);
+        }
+    }
+    report
+}
+
+/// -- 打印多钱包汇总报告
+fn log_multi_wallet_report(report: &MultiWalletReport) {
+    info!("\n====== 多钱包处理完成 ======");
+    for wallet in &report.wallets {
+        match (&wallet.error, &wallet.report) {
+            (Some(error), _) => warn!(
+                "{} ({}): {}",
+                wallet.label,
+                address_label(&wallet.wallet),
+                error
+            ),
+            (None, Some(batch)) => info!(
+                "{} ({}): 成功 {} 个, 失败 {} 个, 回收租金 {} SOL",
+                wallet.label,
+                address_label(&wallet.wallet),
+                batch.succeeded,
+                batch.failed,
+                batch.rent_recovered_sol
+            ),
+            (None, None) => info!(
+                "{} ({}): 可关闭 {} 个, 零值 {} 个, 可回收租金 {} SOL",
+                wallet.label,
+                address_label(&wallet.wallet),
+                wallet.closable_accounts,
+                wallet.zero_value_accounts,
+                wallet.total_rent_sol
+            ),
+        }
+    }
+    info!(
+        "钱包: 成功 {} 个, 失败 {} 个",
+        report.succeeded, report.failed
+    );
+    info!("关闭账户: {} 个", report.accounts_closed);
+    info!("回收租金: {} SOL", report.rent_recovered_sol);
+}
+
+/// -- 打印 SOL 归集汇总报告
+fn log_consolidation_report(report: &ConsolidationReport) {
+    info!("\n====== SOL 归集完成 ======");
+    for outcome in &report.outcomes {
+        match &outcome.error {
+            Some(error) => warn!(
+                "{} ({}): {}",
+                outcome.label,
+                address_label(&outcome.wallet),
+                error
+            ),
+            None => info!(
+                "{} ({}): 转出 {} SOL",
+                outcome.label,
+                address_label(&outcome.wallet),
+                outcome.transferred_lamports as f64 / LAMPORTS_PER_SOL as f64
+            ),
+        }
+    }
+    info!(
+        "钱包: 成功 {} 个, 跳过 {} 个, 失败 {} 个",
+        report.succeeded, report.skipped, report.failed
+    );
+    info!(
+        "归集 SOL: {} 到 {}",
+        report.transferred_sol,
+        address_label(&report.master)
+    );
+}
@@ -0,0 +1,504 @@
+use crate::{TokenAccountManager, MAX_MULTIPLE_ACCOUNTS};
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction,
+    native_token::sol_to_lamports, packet::PACKET_DATA_SIZE, pubkey::Pubkey, signer::Signer,
+    system_instruction, transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::Write,
+    path::Path,
+};
+use tracing::{error, info, warn};
+use utils::{address_book::address_book, RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 批量转账配置
+#[derive(Debug, Clone)]
+pub struct MultisendConfig {
+    /// 每笔交易的计算单元价格（micro-lamports），0 表示不设置
+    pub compute_unit_price: u64,
+    /// 每条 SOL 转账预估的计算单元
+    pub sol_transfer_units: u32,
+    /// 每条代币转账预估的计算单元（不含创建 ATA）
+    pub token_transfer_units: u32,
+    /// 创建 ATA 预估的计算单元
+    pub create_ata_units: u32,
+    /// 单笔交易最大指令组数量，避免触发其他运行时限制
+    pub max_transfers_per_tx: usize,
+}
+
+impl Default for MultisendConfig {
+    fn default() -> Self {
+        Self {
+            compute_unit_price: 0,
+            sol_transfer_units: 450,
+            token_transfer_units: 6_500,
+            create_ata_units: 25_000,
+            max_transfers_per_tx: 20,
+        }
+    }
+}
+
+/// -- 批量转账条目
+///
+/// 对应 CSV 中的一行：`recipient,amount[,mint]`，未填写 mint 表示转账 SOL；
+/// 收款人和 mint 也可以填写地址簿中的名称
+#[derive(Debug, Clone)]
+pub struct MultisendRow {
+    pub recipient: Pubkey,    // -- 收款地址
+    pub amount: f64,          // -- 转账数量（UI 数量，SOL 或代币）
+    pub mint: Option<Pubkey>, // -- 代币 Mint 地址，None 表示 SOL
+}
+
+/// -- 单个收款人的转账结果
+#[derive(Debug, Clone)]
+pub struct MultisendResult {
+    pub recipient: String,         // -- 收款地址
+    pub name: Option<String>,      // -- 地址簿中的收款人名称
+    pub mint: Option<String>,      // -- 代币 Mint 地址
+    pub amount: f64,               // -- 转账数量（UI 数量）
+    pub ata_created: bool,         // -- 是否为收款人创建了 ATA
+    pub success: bool,             // -- 操作是否成功
+    pub signature: Option<String>, // -- 成功时的交易签名
+    pub error: Option<String>,     // -- 失败时的错误信息
+}
+
+/// -- 批量转账报告
+#[derive(Debug, Default)]
+pub struct MultisendReport {
+    pub total: usize,                  // -- 总条目数量
+    pub succeeded: usize,              // -- 成功数量
+    pub failed: usize,                 // -- 失败数量
+    pub transactions: usize,           // -- 发送的交易数量
+    pub atas_created: usize,           // -- 创建的 ATA 数量
+    pub sol_sent: f64,                 // -- 转出的 SOL 数量
+    pub results: Vec<MultisendResult>, // -- 每个收款人的结果
+}
+
+impl MultisendReport {
+    /// -- 将报告写出为 CSV 文件
+    ///
+    /// # 参数
+    /// * `path` - 输出文件路径
+    pub fn write_csv<P: AsRef<Path>>(&self, path: P) -> TokenAccountResult<()> {
+        let mut file = fs::File::create(path)?;
+        writeln!(
+            file,
+            "recipient,name,mint,amount,ata_created,success,signature,error"
+        )?;
+        for r in &self.results {
+            writeln!(
+                file,
+                "{},{},{},{},{},{},{},{}",
+                r.recipient,
+                r.name.as_deref().unwrap_or("").replace(',', ";"),
+                r.mint.as_deref().unwrap_or("SOL"),
+                r.amount,
+                r.ata_created,
+                r.success,
+                r.signature.as_deref().unwrap_or(""),
+                r.error.as_deref().unwrap_or("").replace(',', ";"),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// -- 解析批量转账 CSV 内容
+///
+/// 支持可选的表头行（以 `recipient` 开头），忽略空行和 `#` 开头的注释行。
+///
+/// # 参数
+/// * `content` - CSV 文本内容
+///
+/// # 返回
+/// * `TokenAccountResult<Vec<MultisendRow>>` - 解析后的转账条目
+pub fn parse_multisend_csv(content: &str) -> TokenAccountResult<Vec<MultisendRow>> {
+    let mut rows = Vec::new();
+
+    for (line_no, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line_no == 0 && line.to_ascii_lowercase().starts_with("recipient") {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        if fields.len() < 2 {
+            return Err(TokenAccountError::AccountParseError(format!(
+                "第 {} 行格式错误: {}",
+                line_no + 1,
+                line
+            )));
+        }
+
+        let recipient = resolve_address(fields[0]).map_err(|e| {
+            TokenAccountError::AccountParseError(format!("第 {} 行地址无效: {}", line_no + 1, e))
+        })?;
+        let amount: f64 = fields[1].parse().map_err(|_| {
+            TokenAccountError::AccountParseError(format!("第 {} 行数量无效", line_no + 1))
+        })?;
+        if amount <= 0.0 {
+            return Err(TokenAccountError::AccountParseError(format!(
+                "第 {} 行数量必须大于 0",
+                line_no + 1
+            )));
+        }
+        let mint = match fields.get(2).filter(|m| !m.is_empty()) {
+            Some(m) => Some(resolve_address(m).map_err(|e| {
+                TokenAccountError::AccountParseError(format!(
+                    "第 {} 行 Mint 无效: {}",
+                    line_no + 1,
+                    e
+                ))
+            })?),
+            None => None,
+        };
+
+        rows.push(MultisendRow {
+            recipient,
+            amount,
+            mint,
+        });
+    }
+
+    Ok(rows)
+}
+
+/// -- 将地址或地址簿中的名称解析为地址
+pub(crate) fn resolve_address(field: &str) -> Result<Pubkey, String> {
+    address_book()
+        .resolve(field)
+        .ok_or_else(|| format!("{} 既不是有效地址，也不在地址簿中", field))
+}
+
+/// -- 从文件读取批量转账 CSV
+pub fn read_multisend_csv<P: AsRef<Path>>(path: P) -> TokenAccountResult<Vec<MultisendRow>> {
+    parse_multisend_csv(&fs::read_to_string(path)?)
+}
+
+/// -- Mint 信息（所属程序和精度）
+#[derive(Debug, Clone, Copy)]
+struct MintInfo {
+    program_id: Pubkey,
+    decimals: u8,
+}
+
+/// -- 单个收款人的指令组
+struct TransferGroup {
+    row_index: usize,
+    instructions: Vec<Instruction>,
+    compute_units: u32,
+    ata_created: bool,
+}
+
+/// -- 计算交易序列化后的大小
+fn transaction_size(instructions: &[Instruction], payer: &Pubkey) -> usize {
+    let tx = Transaction::new_with_payer(instructions, Some(payer));
+    bincode::serialized_size(&tx)
+        .map(|s| s as usize)
+        .unwrap_or(usize::MAX)
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 批量转账 SOL 和 SPL 代币
+    ///
+    /// 与批量关闭账户相反的流程：为缺失的收款人创建 ATA，
+    /// 按交易大小上限尽可能多地打包转账指令，并附加计算预算指令。
+    ///
+    /// # 参数
+    /// * `rows` - 转账条目
+    /// * `multisend_config` - 批量转账配置
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<MultisendReport>` - 每个收款人的转账结果报告
+    pub async fn multisend(
+        &self,
+        rows: &[MultisendRow],
+        multisend_config: &MultisendConfig,
+    ) -> TokenAccountResult<MultisendReport> {
+        let mut report = MultisendReport {
+            total: rows.len(),
+            ..Default::default()
+        };
+        if rows.is_empty() {
+            warn!("没有需要转账的条目");
+            return Ok(report);
+        }
+
+        let payer = self.wallet.pubkey();
+        let mints = self.load_mint_infos(rows)?;
+        let missing_atas = self.find_missing_atas(rows, &mints)?;
+
+        // -- 为每个收款人构建指令组
+        let mut groups = Vec::with_capacity(rows.len());
+        let mut created = HashSet::new();
+        for (row_index, row) in rows.iter().enumerate() {
+            let mut instructions = Vec::new();
+            let mut ata_created = false;
+            let compute_units;
+
+            match row.mint {
+                None => {
+                    instructions.push(system_instruction::transfer(
+                        &payer,
+                        &row.recipient,
+                        sol_to_lamports(row.amount),
+                    ));
+                    compute_units = multisend_config.sol_transfer_units;
+                }
+                Some(mint) => {
+                    let info = mints[&mint];
+                    let source = get_associated_token_address_with_program_id(
+                        &payer,
+                        &mint,
+                        &info.program_id,
+                    );
+                    let destination = get_associated_token_address_with_program_id(
+                        &row.recipient,
+                        &mint,
+                        &info.program_id,
+                    );
+
+                    let mut units = multisend_config.token_transfer_units;
+                    if missing_atas.contains(&destination) && created.insert(destination) {
+                        instructions.push(create_associated_token_account_idempotent(
+                            &payer,
+                            &row.recipient,
+                            &mint,
+                            &info.program_id,
+                        ));
+                        units += multisend_config.create_ata_units;
+                        ata_created = true;
+                    }
+
+                    let amount = (row.amount * 10f64.powi(info.decimals as i32)).round() as u64;
+                    instructions.push(spl_token_2022::instruction::transfer_checked(
+                        &info.program_id,
+                        &source,
+                        &mint,
+                        &destination,
+                        &payer,
+                        &[],
+                        amount,
+                        info.decimals,
+                    )?);
+                    compute_units = units;
+                }
+            }
+
+            groups.push(TransferGroup {
+                row_index,
+                instructions,
+                compute_units,
+                ata_created,
+            });
+        }
+
+        // -- 按交易大小打包
+        let batches = pack_groups(groups, &payer, multisend_config);
+        info!(
+            "共 {} 个转账条目，打包为 {} 笔交易",
+            rows.len(),
+            batches.len()
+        );
+
+        for (i, batch) in batches.iter().enumerate() {
+            let instructions = build_instructions(batch, multisend_config);
+            info!("\n发送第 {} 笔交易, 包含 {} 个转账", i + 1, batch.len());
+
+            let result = self.send_instructions_with_retry(&instructions).await;
+            report.transactions += 1;
+
+            for group in batch {
+                let row = &rows[group.row_index];
+                let (success, signature, error) = match &result {
+                    Ok(sig) => (true, Some(sig.clone()), None),
+                    Err(e) => (false, None, Some(e.to_string())),
+                };
+
+                if success {
+                    report.succeeded += 1;
+                    if group.ata_created {
+                        report.atas_created += 1;
+                    }
+                    if row.mint.is_none() {
+                        report.sol_sent += row.amount;
+                    }
+                } else {
+                    report.failed += 1;
+                }
+
+                report.results.push(MultisendResult {
+                    recipient: row.recipient.to_string(),
+                    name: address_book().name(&row.recipient).map(str::to_string),
+                    mint: row.mint.map(|m| m.to_string()),
+                    amount: row.amount,
+                    ata_created: group.ata_created && success,
+                    success,
+                    signature,
+                    error,
+                });
+            }
+
+            if i < batches.len() - 1 {
+                tokio::time::sleep(self.config.batch_delay).await;
+            }
+        }
+
+        info!("\n{}", "=".repeat(50));
+        info!("批量转账完成");
+        info!("{}", "=".repeat(50));
+        info!("成功: {}, 失败: {}", report.succeeded, report.failed);
+        info!("交易数量: {}", report.transactions);
+        info!("创建 ATA: {}", report.atas_created);
+        info!("转出 SOL: {:.6}", report.sol_sent);
+
+        Ok(report)
+    }
+
+    /// -- 查询所有涉及的 Mint 信息
+    fn load_mint_infos(
+        &self,
+        rows: &[MultisendRow],
+    ) -> TokenAccountResult<HashMap<Pubkey, MintInfo>> {
+        let mut mint_keys: Vec<Pubkey> = rows.iter().filter_map(|r| r.mint).collect();
+        mint_keys.sort();
+        mint_keys.dedup();
+
+        let mut mints = HashMap::new();
+        for chunk in mint_keys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.connection.get_multiple_accounts(chunk)?;
+            for (mint, account) in chunk.iter().zip(accounts) {
+                let account = account.ok_or_else(|| {
+                    TokenAccountError::AccountParseError(format!("Mint 不存在: {}", mint))
+                })?;
+                let state = StateWithExtensions::<Mint>::unpack(&account.data)
+                    .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+                mints.insert(
+                    *mint,
+                    MintInfo {
+                        program_id: account.owner,
+                        decimals: state.base.decimals,
+                    },
+                );
+            }
+        }
+
+        Ok(mints)
+    }
+
+    /// -- 查找收款人缺失的 ATA
+    fn find_missing_atas(
+        &self,
+        rows: &[MultisendRow],
+        mints: &HashMap<Pubkey, MintInfo>,
+    ) -> TokenAccountResult<HashSet<Pubkey>> {
+        let mut atas: Vec<Pubkey> = rows
+            .iter()
+            .filter_map(|r| {
+                r.mint.map(|mint| {
+                    get_associated_token_address_with_program_id(
+                        &r.recipient,
+                        &mint,
+                        &mints[&mint].program_id,
+                    )
+                })
+            })
+            .collect();
+        atas.sort();
+        atas.dedup();
+
+        let mut missing = HashSet::new();
+        for chunk in atas.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let accounts = self.connection.get_multiple_accounts(chunk)?;
+            for (ata, account) in chunk.iter().zip(accounts) {
+                if account.is_none() {
+                    missing.insert(*ata);
+                }
+            }
+        }
+
+        Ok(missing)
+    }
+
+    /// -- 签名并发送指令，失败时按配置重试
+    async fn send_instructions_with_retry(
+        &self,
+        instructions: &[Instruction],
+    ) -> TokenAccountResult<String> {
+        let mut retries = 0;
+        loop {
+            let blockhash = self.connection.get_latest_blockhash()?;
+            let transaction = Transaction::new_signed_with_payer(
+                instructions,
+                Some(&self.wallet.pubkey()),
+                &[&self.wallet],
+                blockhash,
+            );
+
+            match self.connection.send_and_confirm_transaction(&transaction) {
+                Ok(signature) => return Ok(signature.to_string()),
+                Err(e) if retries < self.config.max_retries => {
+                    retries += 1;
+                    warn!("重试第 {} 次: {}", retries, e);
+                    tokio::time::sleep(self.config.retry_delay).await;
+                }
+                Err(e) => {
+                    error!("交易失败: {}", e);
+                    return Err(TokenAccountError::TransactionError(e.to_string()));
+                }
+            }
+        }
+    }
+}
+
+/// -- 生成带计算预算的交易指令
+fn build_instructions(batch: &[TransferGroup], config: &MultisendConfig) -> Vec<Instruction> {
+    let units: u32 = batch.iter().map(|g| g.compute_units).sum();
+    let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_limit(units)];
+    if config.compute_unit_price > 0 {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            config.compute_unit_price,
+        ));
+    }
+    instructions.extend(batch.iter().flat_map(|g| g.instructions.iter().cloned()));
+    instructions
+}
+
+/// -- 将指令组贪心打包为多笔交易
+///
+/// 每笔交易在加入计算预算指令后仍需满足单个数据包大小限制。
+fn pack_groups(
+    groups: Vec<TransferGroup>,
+    payer: &Pubkey,
+    config: &MultisendConfig,
+) -> Vec<Vec<TransferGroup>> {
+    let mut batches: Vec<Vec<TransferGroup>> = Vec::new();
+    let mut current: Vec<TransferGroup> = Vec::new();
+
+    for group in groups {
+        current.push(group);
+        let fits = current.len() <= config.max_transfers_per_tx
+            && transaction_size(&build_instructions(&current, config), payer) <= PACKET_DATA_SIZE;
+
+        if !fits && current.len() > 1 {
+            let last = current.pop().expect("current 至少包含一个元素");
+            batches.push(std::mem::take(&mut current));
+            current.push(last);
+        }
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    batches
+}
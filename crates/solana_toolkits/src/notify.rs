@@ -0,0 +1,276 @@
+use crate::{account_info::BatchCloseReport, TokenAccountManager};
+use futures::future::BoxFuture;
+use serde_json::json;
+use solana_sdk::signer::Signer;
+use std::fmt::Write as _;
+use tracing::warn;
+use utils::{
+    locale::{current_locale, Locale},
+    RpcApi, TokenAccountError, TokenAccountResult,
+};
+
+/// -- Telegram Bot API 默认地址
+pub const DEFAULT_TELEGRAM_API_URL: &str = "https://api.telegram.org";
+
+/// -- Discord 单条消息的最大字符数
+const DISCORD_MESSAGE_LIMIT: usize = 2000;
+
+/// -- 摘要中最多列出的失败账户数
+const MAX_LISTED_FAILURES: usize = 10;
+
+/// -- 发送通知的时机
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotifyPolicy {
+    /// 每次批量处理完成都发送
+    #[default]
+    Always,
+    /// 只在有账户处理失败时发送
+    OnFailure,
+}
+
+impl NotifyPolicy {
+    /// -- 该报告是否需要发送通知
+    pub fn should_notify(&self, report: &BatchCloseReport) -> bool {
+        match self {
+            NotifyPolicy::Always => true,
+            NotifyPolicy::OnFailure => report.failed > 0,
+        }
+    }
+}
+
+/// -- 批量处理结果通知
+///
+/// 通过 `manager.add_notifier(Arc::new(notifier))` 挂到管理器上，批量关闭、销毁、转出和广播完成后，
+/// 按配置的 `notify_policy` 发送包含统计摘要的报告。发送失败只记录日志，不影响批量处理的结果。
+pub trait Notifier: Send + Sync {
+    /// -- 发送批量处理报告
+    ///
+    /// # 参数
+    /// * `wallet` - 钱包地址
+    /// * `report` - 批量处理报告
+    fn notify<'a>(
+        &'a self,
+        wallet: &'a str,
+        report: &'a BatchCloseReport,
+    ) -> BoxFuture<'a, TokenAccountResult<()>>;
+}
+
+/// -- 生成报告的文本摘要，使用当前界面语言（见 `utils::locale::set_locale`）
+///
+/// # 参数
+/// * `wallet` - 钱包地址
+/// * `report` - 批量处理报告
+pub fn report_summary(wallet: &str, report: &BatchCloseReport) -> String {
+    let locale = current_locale();
+    let mut summary = match locale {
+        Locale::Zh => format!(
+            "{}批量处理完成\n钱包: {}\n成功: {} 个账户\n失败: {} 个账户\n回收租金: {} SOL",
+            if report.simulated { "[模拟] " } else { "" },
+            wallet,
+            report.succeeded,
+            report.failed,
+            report.rent_recovered_sol
+        ),
+        Locale::En => format!(
+            "{}Batch completed\nWallet: {}\nSucceeded: {} accounts\nFailed: {} accounts\nRent recovered: {} SOL",
+            if report.simulated { "[Simulated] " } else { "" },
+            wallet,
+            report.succeeded,
+            report.failed,
+            report.rent_recovered_sol
+        ),
+    };
+    for failure in report.failures().take(MAX_LISTED_FAILURES) {
+        let _ = write!(
+            summary,
+            "\n- {} ({}): {}",
+            failure.account_address,
+            failure.symbol,
+            failure
+                .error
+                .as_deref()
+                .unwrap_or(locale.text("未知错误", "Unknown error"))
+        );
+    }
+    if report.failed > MAX_LISTED_FAILURES {
+        let remaining = report.failed - MAX_LISTED_FAILURES;
+        let _ = match locale {
+            Locale::Zh => write!(summary, "\n... 另有 {} 个失败账户", remaining),
+            Locale::En => write!(summary, "\n... and {} more failed accounts", remaining),
+        };
+    }
+    summary
+}
+
+/// -- 发送 JSON 请求并检查响应状态
+async fn post_json(
+    http: &reqwest::Client,
+    url: &str,
+    body: &serde_json::Value,
+) -> TokenAccountResult<()> {
+    http.post(url)
+        .json(body)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| TokenAccountError::Other(format!("发送通知失败: {}", e)))?;
+    Ok(())
+}
+
+/// -- 通用 Webhook 通知
+///
+/// 向指定地址 POST `{"text": 摘要, "wallet": 钱包地址, "report": 完整报告}`，
+/// 兼容 Slack 等只读取 `text` 字段的服务。
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl WebhookNotifier {
+    /// -- 创建 Webhook 通知
+    ///
+    /// # 参数
+    /// * `url` - Webhook 地址
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn notify<'a>(
+        &'a self,
+        wallet: &'a str,
+        report: &'a BatchCloseReport,
+    ) -> BoxFuture<'a, TokenAccountResult<()>> {
+        Box::pin(async move {
+            let body = json!({
+                "text": report_summary(wallet, report),
+                "wallet": wallet,
+                "report": report,
+            });
+            post_json(&self.http, &self.url, &body).await
+        })
+    }
+}
+
+/// -- Telegram 机器人通知
+#[derive(Debug, Clone)]
+pub struct TelegramNotifier {
+    http: reqwest::Client,
+    api_url: String,
+    bot_token: String,
+    chat_id: String,
+}
+
+impl TelegramNotifier {
+    /// -- 创建 Telegram 通知
+    ///
+    /// # 参数
+    /// * `bot_token` - 机器人 token
+    /// * `chat_id` - 接收消息的会话 ID
+    pub fn new(bot_token: impl Into<String>, chat_id: impl Into<String>) -> Self {
+        Self::with_api_url(DEFAULT_TELEGRAM_API_URL, bot_token, chat_id)
+    }
+
+    /// -- 使用自定义 Bot API 地址（如自建 Bot API 服务）创建 Telegram 通知
+    ///
+    /// # 参数
+    /// * `api_url` - Bot API 地址
+    /// * `bot_token` - 机器人 token
+    /// * `chat_id` - 接收消息的会话 ID
+    pub fn with_api_url(
+        api_url: impl Into<String>,
+        bot_token: impl Into<String>,
+        chat_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            api_url: api_url.into(),
+            bot_token: bot_token.into(),
+            chat_id: chat_id.into(),
+        }
+    }
+}
+
+impl Notifier for TelegramNotifier {
+    fn notify<'a>(
+        &'a self,
+        wallet: &'a str,
+        report: &'a BatchCloseReport,
+    ) -> BoxFuture<'a, TokenAccountResult<()>> {
+        Box::pin(async move {
+            let url = format!(
+                "{}/bot{}/sendMessage",
+                self.api_url.trim_end_matches('/'),
+                self.bot_token
+            );
+            let body = json!({
+                "chat_id": self.chat_id,
+                "text": report_summary(wallet, report),
+            });
+            post_json(&self.http, &url, &body).await
+        })
+    }
+}
+
+/// -- Discord Webhook 通知
+///
+/// 摘要超过 Discord 单条消息长度限制时截断。
+#[derive(Debug, Clone)]
+pub struct DiscordNotifier {
+    http: reqwest::Client,
+    webhook_url: String,
+}
+
+impl DiscordNotifier {
+    /// -- 创建 Discord 通知
+    ///
+    /// # 参数
+    /// * `webhook_url` - 频道的 Webhook 地址
+    pub fn new(webhook_url: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            webhook_url: webhook_url.into(),
+        }
+    }
+}
+
+impl Notifier for DiscordNotifier {
+    fn notify<'a>(
+        &'a self,
+        wallet: &'a str,
+        report: &'a BatchCloseReport,
+    ) -> BoxFuture<'a, TokenAccountResult<()>> {
+        Box::pin(async move {
+            let content: String = report_summary(wallet, report)
+                .chars()
+                .take(DISCORD_MESSAGE_LIMIT)
+                .collect();
+            post_json(
+                &self.http,
+                &self.webhook_url,
+                &json!({ "content": content }),
+            )
+            .await
+        })
+    }
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 按 `notify_policy` 向全部通知目标发送报告
+    pub(crate) async fn send_notifications(&self, report: &BatchCloseReport) {
+        if self.notifiers.is_empty() || !self.config.notify_policy.should_notify(report) {
+            return;
+        }
+        let wallet = self.wallet.pubkey().to_string();
+        for notifier in &self.notifiers {
+            if let Err(e) = notifier.notify(&wallet, report).await {
+                warn!("{}", e);
+            }
+        }
+    }
+}
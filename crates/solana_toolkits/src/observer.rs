@@ -0,0 +1,65 @@
+use crate::account_info::{AccountOutcome, BatchCloseReport, TokenAccountsResult};
+use utils::TokenAccountError;
+
+/// -- 批量操作进度观察者
+///
+/// 批量关闭、销毁和转出流程在关键节点调用观察者，GUI 和机器人可以据此渲染进度条或推送结果，
+/// 不需要解析日志输出。所有方法都有空的默认实现，只需实现关心的事件。
+///
+/// 观察者可能在多个线程中被调用（如多钱包并行处理），因此要求 `Send + Sync`。
+pub trait BatchObserver: Send + Sync {
+    /// -- 账户扫描完成（`get_closeable_accounts`）
+    ///
+    /// # 参数
+    /// * `result` - 扫描结果
+    fn on_scan_complete(&self, _result: &TokenAccountsResult) {}
+
+    /// -- 开始处理一批账户
+    ///
+    /// # 参数
+    /// * `batch` - 批次序号（从 1 开始）
+    /// * `total_batches` - 批次总数
+    /// * `accounts` - 本批账户数量
+    fn on_batch_start(&self, _batch: usize, _total_batches: usize, _accounts: usize) {}
+
+    /// -- 账户处理成功（模拟模式下表示模拟成功）
+    ///
+    /// # 参数
+    /// * `outcome` - 账户处理结果
+    fn on_account_closed(&self, _outcome: &AccountOutcome) {}
+
+    /// -- 账户处理失败
+    ///
+    /// # 参数
+    /// * `outcome` - 账户处理结果，`error` 中包含失败原因
+    fn on_account_failed(&self, _outcome: &AccountOutcome) {}
+
+    /// -- 批次处理失败，即将重试
+    ///
+    /// # 参数
+    /// * `batch` - 批次序号（从 1 开始）
+    /// * `attempt` - 第几次重试（从 1 开始）
+    /// * `error` - 上一次失败的错误
+    fn on_retry(&self, _batch: usize, _attempt: u32, _error: &TokenAccountError) {}
+
+    /// -- 全部批次处理完成
+    ///
+    /// # 参数
+    /// * `report` - 最终的批量处理报告
+    fn on_complete(&self, _report: &BatchCloseReport) {}
+}
+
+/// -- 不处理任何事件的观察者，管理器的默认值
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopObserver;
+
+impl BatchObserver for NoopObserver {}
+
+/// -- 根据处理结果通知观察者
+pub(crate) fn notify_outcome(observer: &dyn BatchObserver, outcome: &AccountOutcome) {
+    if outcome.success {
+        observer.on_account_closed(outcome);
+    } else {
+        observer.on_account_failed(outcome);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    account_info::{AccountOutcome, BatchCloseReport, TokenAccountInfo},
+    history::RunOperation,
+    observer::notify_outcome,
+    operations::{create_batch_close_message, submit_transaction},
+    TokenAccountManager,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{message::VersionedMessage, signer::Signer, transaction::VersionedTransaction};
+use std::{fs, path::Path};
+use tracing::{error, info};
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 离线交易文件中的单笔交易
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineTransaction {
+    pub accounts: Vec<TokenAccountInfo>, // -- 交易关闭的账户
+    pub message: String,                 // -- base64 编码的未签名消息
+    pub transaction: Option<String>,     // -- base64 编码的已签名交易，签名前为 None
+}
+
+/// -- 离线签名交易文件
+///
+/// 在线机器导出未签名的批量关闭消息，离线机器签名后写回文件，再由在线机器广播，
+/// 私钥始终不接触运行扫描的机器。
+///
+/// 消息使用导出时的最新区块哈希，区块哈希大约 60 秒后过期，需要在此之前完成签名和广播。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfflineTransactionFile {
+    pub wallet: String,                        // -- 需要签名的钱包地址（手续费支付者）
+    pub transactions: Vec<OfflineTransaction>, // -- 按批次排列的交易
+}
+
+impl OfflineTransactionFile {
+    /// -- 从文件加载
+    ///
+    /// # 参数
+    /// * `path` - 交易文件路径
+    pub fn load(path: impl AsRef<Path>) -> TokenAccountResult<Self> {
+        let content = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// -- 保存到文件
+    ///
+    /// # 参数
+    /// * `path` - 交易文件路径
+    pub fn save(&self, path: impl AsRef<Path>) -> TokenAccountResult<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// -- 已签名的交易数量
+    pub fn signed(&self) -> usize {
+        self.transactions
+            .iter()
+            .filter(|transaction| transaction.transaction.is_some())
+            .count()
+    }
+}
+
+/// -- 离线签名交易文件中的全部消息
+///
+/// 不需要网络连接，签名者地址必须与文件中的钱包地址一致。
+///
+/// # 参数
+/// * `input` - 未签名的交易文件
+/// * `output` - 写入已签名交易的文件，可以与 `input` 相同
+/// * `signer` - 钱包签名者
+///
+/// # 返回
+/// * `TokenAccountResult<usize>` - 签名的交易数量
+pub fn sign_offline(
+    input: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+    signer: &impl Signer,
+) -> TokenAccountResult<usize> {
+    let mut file = OfflineTransactionFile::load(input)?;
+    if file.wallet != signer.pubkey().to_string() {
+        return Err(TokenAccountError::Other(format!(
+            "签名者 {} 与交易文件中的钱包 {} 不一致",
+            signer.pubkey(),
+            file.wallet
+        )));
+    }
+
+    for transaction in &mut file.transactions {
+        let bytes = STANDARD
+            .decode(&transaction.message)
+            .map_err(|e| TokenAccountError::TransactionError(format!("无效的消息: {}", e)))?;
+        let message: VersionedMessage = bincode::deserialize(&bytes)
+            .map_err(|e| TokenAccountError::TransactionError(format!("无效的消息: {}", e)))?;
+        let signed = VersionedTransaction::try_new(message, &[signer])
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+        let bytes = bincode::serialize(&signed)
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+        transaction.transaction = Some(STANDARD.encode(bytes));
+    }
+    file.save(output)?;
+    info!("已签名 {} 笔交易", file.transactions.len());
+
+    Ok(file.transactions.len())
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 导出未签名的批量关闭交易
+    ///
+    /// 只使用钱包地址，在线机器可以用 `NullSigner` 作为钱包创建管理器，不需要私钥。
+    ///
+    /// # 参数
+    /// * `accounts` - 要关闭的账户列表
+    /// * `batch_size` - 每笔交易关闭的账户数量
+    /// * `path` - 写入的交易文件路径
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<usize>` - 导出的交易数量
+    pub fn export_unsigned_close_transactions(
+        &self,
+        accounts: &[TokenAccountInfo],
+        batch_size: usize,
+        path: impl AsRef<Path>,
+    ) -> TokenAccountResult<usize> {
+        let owner = self.wallet.pubkey();
+        let mut file = OfflineTransactionFile {
+            wallet: owner.to_string(),
+            transactions: Vec::new(),
+        };
+        for chunk in accounts.chunks(batch_size.max(1)) {
+            let message =
+                create_batch_close_message(&self.connection, &owner, &owner, chunk, &self.config)?;
+            let bytes = bincode::serialize(&message)
+                .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+            file.transactions.push(OfflineTransaction {
+                accounts: chunk.to_vec(),
+                message: STANDARD.encode(bytes),
+                transaction: None,
+            });
+        }
+        file.save(&path)?;
+        info!(
+            "已导出 {} 笔未签名交易到 {}",
+            file.transactions.len(),
+            path.as_ref().display()
+        );
+
+        Ok(file.transactions.len())
+    }
+
+    /// -- 广播离线签名的交易
+    ///
+    /// 按顺序发送文件中已签名的交易，未签名或发送失败的交易中的账户记为失败。
+    /// 模拟模式下只模拟执行。
+    ///
+    /// # 参数
+    /// * `path` - 已签名的交易文件路径
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<BatchCloseReport>` - 每个账户的处理结果和统计信息
+    pub async fn broadcast_from_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> TokenAccountResult<BatchCloseReport> {
+        let file = OfflineTransactionFile::load(path)?;
+        let mut report = BatchCloseReport {
+            simulated: self.config.dry_run,
+            ..BatchCloseReport::default()
+        };
+        let balance_before = self.connection.get_balance(&self.wallet.pubkey())?;
+
+        let total_batches = file.transactions.len();
+        for (index, transaction) in file.transactions.iter().enumerate() {
+            self.observer
+                .on_batch_start(index + 1, total_batches, transaction.accounts.len());
+            let result = decode_transaction(transaction)
+                .and_then(|signed| submit_transaction(&self.connection, &signed, &self.config));
+            match &result {
+                Ok(submission) => info!(
+                    "第 {} 笔交易广播成功，交易签名: {}",
+                    index + 1,
+                    submission.signature.as_deref().unwrap_or("-")
+                ),
+                Err(e) => error!("第 {} 笔交易广播失败: {}", index + 1, e),
+            }
+
+            for account in &transaction.accounts {
+                let outcome = match &result {
+                    Ok(submission) => AccountOutcome {
+                        account_address: account.address.clone(),
+                        mint: account.mint.clone(),
+                        symbol: account.symbol.clone(),
+                        success: true,
+                        signature: submission.signature.clone(),
+                        burn_signature: None,
+                        burned_amount: 0,
+                        swept_amount: 0,
+                        swap: None,
+                        unwrapped_lamports: 0,
+                        rent_lamports: account.rent_lamports,
+                        error: None,
+                        failure_kind: None,
+                        simulation: Some(submission.simulation.clone()),
+                        already_closed: false,
+                    },
+                    Err(e) => {
+                        AccountOutcome::failed(&account.address, &account.mint, &account.symbol, e)
+                    }
+                };
+                notify_outcome(self.observer.as_ref(), &outcome);
+                report.outcomes.push(outcome);
+            }
+        }
+
+        let balance_after = if self.config.dry_run {
+            balance_before
+        } else {
+            self.connection.get_balance(&self.wallet.pubkey())?
+        };
+        report.finalize(balance_before, balance_after);
+        self.log_batch_report(&report);
+        self.observer.on_complete(&report);
+        self.record_history(RunOperation::Broadcast, &report);
+        self.save_run_report(RunOperation::Broadcast, &report);
+        self.send_notifications(&report).await;
+
+        Ok(report)
+    }
+}
+
+/// -- 解码文件中已签名的交易
+fn decode_transaction(
+    transaction: &OfflineTransaction,
+) -> TokenAccountResult<VersionedTransaction> {
+    let encoded = transaction
+        .transaction
+        .as_ref()
+        .ok_or_else(|| TokenAccountError::TransactionError(String::from("交易尚未签名")))?;
+    let bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| TokenAccountError::TransactionError(format!("无效的交易: {}", e)))?;
+    bincode::deserialize(&bytes)
+        .map_err(|e| TokenAccountError::TransactionError(format!("无效的交易: {}", e)))
+}
@@ -0,0 +1,269 @@
+use crate::operations::{payer_and_signers, submit_with_fresh_blockhash, with_compute_budget};
+use crate::TokenAccountManager;
+use serde::Serialize;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::Transaction,
+};
+use tracing::{info, warn};
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- OpenBook（Serum v3 分叉）DEX 程序 ID
+pub const OPENBOOK_PROGRAM_ID: Pubkey = pubkey!("srmqPvymJeFKQ4zGQed1GFppgkRHB9kaELCbyksJtPX");
+
+/// -- Serum DEX v3 程序 ID
+pub const SERUM_DEX_V3_PROGRAM_ID: Pubkey = pubkey!("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin");
+
+/// -- 扫描的 DEX 程序，两者的 open orders 账户布局相同
+pub const OPEN_ORDERS_PROGRAM_IDS: [Pubkey; 2] = [OPENBOOK_PROGRAM_ID, SERUM_DEX_V3_PROGRAM_ID];
+
+/// -- open orders 账户大小
+///
+/// 布局: "serum"(5) + account_flags(8) + market(32) + owner(32) + 4 个 u64 余额(32)
+/// + free_slot_bits(16) + is_bid_bits(16) + orders(16*128) + client_order_ids(8*128)
+/// + referrer_rebates_accrued(8) + "padding"(7)
+pub const OPEN_ORDERS_ACCOUNT_SIZE: usize = 3228;
+
+/// -- 各字段的偏移量
+const ACCOUNT_FLAGS_OFFSET: usize = 5;
+const MARKET_OFFSET: usize = 13;
+const OWNER_OFFSET: usize = 45;
+const NATIVE_COIN_TOTAL_OFFSET: usize = 85;
+const NATIVE_PC_TOTAL_OFFSET: usize = 101;
+const FREE_SLOT_BITS_OFFSET: usize = 109;
+const REFERRER_REBATES_OFFSET: usize = 3213;
+
+/// -- account_flags 中的 Initialized、OpenOrders 和 Closed 标志位
+const FLAG_INITIALIZED: u64 = 1;
+const FLAG_OPEN_ORDERS: u64 = 1 << 2;
+const FLAG_CLOSED: u64 = 1 << 8;
+
+/// -- `MarketInstruction::CloseOpenOrders` 的序号
+const CLOSE_OPEN_ORDERS_TAG: u32 = 14;
+
+/// -- 钱包的 open orders 账户信息
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenOrdersInfo {
+    pub address: String,       // -- open orders 账户地址
+    pub program_id: String,    // -- 所属的 DEX 程序 ID
+    pub market: String,        // -- 对应的市场地址
+    pub lamports: u64,         // -- 账户租金（lamports）
+    pub base_total: u64,       // -- 基础代币总额（含挂单冻结部分）
+    pub quote_total: u64,      // -- 计价代币总额（含挂单冻结部分）
+    pub open_orders: u32,      // -- 未成交的挂单数量
+    pub referrer_rebates: u64, // -- 未结算的推荐返佣
+    pub closeable: bool,       // -- 是否可以直接关闭（无余额、无挂单）
+}
+
+/// -- open orders 账户关闭结果
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenOrdersCloseResult {
+    pub address: String,           // -- 被关闭的 open orders 账户地址
+    pub market: String,            // -- 对应的市场地址
+    pub rent_lamports: u64,        // -- 回收的租金（lamports）
+    pub signature: Option<String>, // -- 交易签名，模拟模式下为 None
+    pub simulated: bool,           // -- 是否为模拟执行（未发送交易）
+}
+
+/// -- 解析 open orders 账户
+///
+/// # 参数
+/// * `address` - open orders 账户地址
+/// * `account` - 账户数据，所有者必须是 [`OPEN_ORDERS_PROGRAM_IDS`] 之一
+pub fn parse_open_orders(
+    address: &Pubkey,
+    account: &Account,
+) -> TokenAccountResult<OpenOrdersInfo> {
+    let invalid = |reason: &str| {
+        TokenAccountError::AccountParseError(format!(
+            "{} 不是 open orders 账户: {}",
+            address, reason
+        ))
+    };
+    if !OPEN_ORDERS_PROGRAM_IDS.contains(&account.owner) {
+        return Err(invalid("所有者不是 OpenBook 或 Serum 程序"));
+    }
+    let data = &account.data;
+    if data.len() != OPEN_ORDERS_ACCOUNT_SIZE {
+        return Err(invalid("账户大小不匹配"));
+    }
+
+    let flags = read_u64(data, ACCOUNT_FLAGS_OFFSET);
+    if flags & (FLAG_INITIALIZED | FLAG_OPEN_ORDERS) != FLAG_INITIALIZED | FLAG_OPEN_ORDERS
+        || flags & FLAG_CLOSED != 0
+    {
+        return Err(invalid("账户标志无效"));
+    }
+
+    let base_total = read_u64(data, NATIVE_COIN_TOTAL_OFFSET);
+    let quote_total = read_u64(data, NATIVE_PC_TOTAL_OFFSET);
+    let free_slot_bits = u128::from_le_bytes(
+        data[FREE_SLOT_BITS_OFFSET..FREE_SLOT_BITS_OFFSET + 16]
+            .try_into()
+            .unwrap(),
+    );
+    let open_orders = free_slot_bits.count_zeros();
+    let referrer_rebates = read_u64(data, REFERRER_REBATES_OFFSET);
+
+    Ok(OpenOrdersInfo {
+        address: address.to_string(),
+        program_id: account.owner.to_string(),
+        market: read_pubkey(data, MARKET_OFFSET).to_string(),
+        lamports: account.lamports,
+        base_total,
+        quote_total,
+        open_orders,
+        referrer_rebates,
+        closeable: base_total == 0 && quote_total == 0 && open_orders == 0,
+    })
+}
+
+/// -- 构建关闭 open orders 账户的指令，租金返还到 `destination`
+///
+/// # 参数
+/// * `program_id` - DEX 程序 ID
+/// * `open_orders` - open orders 账户地址
+/// * `owner` - open orders 账户所有者（需要签名）
+/// * `destination` - 接收租金的地址
+/// * `market` - 对应的市场地址
+pub fn close_open_orders_instruction(
+    program_id: &Pubkey,
+    open_orders: &Pubkey,
+    owner: &Pubkey,
+    destination: &Pubkey,
+    market: &Pubkey,
+) -> Instruction {
+    // -- 指令数据: 版本号(1) + 指令序号(u32 LE)
+    let mut data = vec![0u8];
+    data.extend_from_slice(&CLOSE_OPEN_ORDERS_TAG.to_le_bytes());
+    Instruction::new_with_bytes(
+        *program_id,
+        &data,
+        vec![
+            AccountMeta::new(*open_orders, false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*market, false),
+        ],
+    )
+}
+
+/// -- 读取小端序 u64
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+/// -- 读取公钥
+fn read_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap())
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 扫描钱包在 OpenBook 和 Serum DEX v3 下的 open orders 账户
+    ///
+    /// 每个 open orders 账户约占用 0.023 SOL 租金。没有余额和挂单的账户 `closeable` 为 true，
+    /// 可以通过 [`close_open_orders`](Self::close_open_orders) 关闭；仍有余额的账户需要先在 DEX 中结算。
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Vec<OpenOrdersInfo>>` - 钱包的全部 open orders 账户
+    pub async fn get_open_orders_accounts(&self) -> TokenAccountResult<Vec<OpenOrdersInfo>> {
+        let owner = self.wallet.pubkey();
+        let mut result = Vec::new();
+        for program_id in OPEN_ORDERS_PROGRAM_IDS {
+            let config = RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::DataSize(OPEN_ORDERS_ACCOUNT_SIZE as u64),
+                    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(OWNER_OFFSET, owner.as_ref())),
+                ]),
+                account_config: RpcAccountInfoConfig::default(),
+                ..RpcProgramAccountsConfig::default()
+            };
+            let accounts = self
+                .connection
+                .get_program_accounts_with_config(&program_id, config)?;
+            for (pubkey, account) in accounts {
+                match parse_open_orders(&pubkey, &account) {
+                    Ok(info) => result.push(info),
+                    Err(e) => warn!("{}", e),
+                }
+            }
+        }
+
+        let closeable: Vec<&OpenOrdersInfo> = result.iter().filter(|info| info.closeable).collect();
+        info!(
+            "open orders 账户: {} 个，可关闭 {} 个，可回收 {} SOL",
+            result.len(),
+            closeable.len(),
+            closeable.iter().map(|info| info.lamports).sum::<u64>() as f64
+                / LAMPORTS_PER_SOL as f64
+        );
+        Ok(result)
+    }
+
+    /// -- 关闭 open orders 账户，租金返还钱包
+    ///
+    /// 发送前重新读取账户，仍有余额或挂单时返回错误，不发送交易。
+    ///
+    /// # 参数
+    /// * `address` - open orders 账户地址
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<OpenOrdersCloseResult>` - 成功返回回收的租金和交易签名
+    pub async fn close_open_orders(
+        &self,
+        address: &Pubkey,
+    ) -> TokenAccountResult<OpenOrdersCloseResult> {
+        let owner = self.wallet.pubkey();
+        let account = self.connection.get_account(address)?;
+        let info = parse_open_orders(address, &account)?;
+        if read_pubkey(&account.data, OWNER_OFFSET) != owner {
+            return Err(TokenAccountError::Other(format!(
+                "open orders 账户 {} 不属于当前钱包",
+                address
+            )));
+        }
+        if !info.closeable {
+            return Err(TokenAccountError::Other(format!(
+                "open orders 账户 {} 仍有余额或挂单，请先在 DEX 中结算",
+                address
+            )));
+        }
+
+        let market = read_pubkey(&account.data, MARKET_OFFSET);
+        let instruction =
+            close_open_orders_instruction(&account.owner, address, &owner, &owner, &market);
+        let instructions = with_compute_budget(&self.connection, &self.config, vec![instruction]);
+        let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+        let submission =
+            submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+                Ok(Transaction::new_signed_with_payer(
+                    &instructions,
+                    Some(&payer),
+                    &signers,
+                    blockhash,
+                ))
+            })?;
+
+        info!(
+            "已关闭 open orders 账户: {}，回收 {} SOL",
+            address,
+            info.lamports as f64 / LAMPORTS_PER_SOL as f64
+        );
+        Ok(OpenOrdersCloseResult {
+            address: info.address,
+            market: info.market,
+            rent_lamports: info.lamports,
+            signature: submission.signature,
+            simulated: self.config.dry_run,
+        })
+    }
+}
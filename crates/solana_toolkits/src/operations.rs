@@ -1,7 +1,248 @@
-use crate::{TokenAccountError, TokenAccountResult};
-use solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
-use spl_token::instruction::close_account;
-use std::str::FromStr;
+use crate::{
+    account_info::SimulationReport,
+    config::{PreflightPolicy, TokenAccountConfig},
+    confirmation,
+    retry::is_blockhash_expired,
+    TokenAccountError, TokenAccountResult,
+};
+use solana_client::rpc_client::SerializableTransaction;
+use solana_sdk::{
+    address_lookup_table::{state::AddressLookupTable, AddressLookupTableAccount},
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, Message, VersionedMessage},
+    pubkey,
+    pubkey::Pubkey,
+    signer::Signer,
+    transaction::{Transaction, VersionedTransaction},
+};
+use spl_token_2022::{
+    extension::{
+        transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeConfig},
+        BaseStateWithExtensions, StateWithExtensions,
+    },
+    instruction::{burn_checked, close_account},
+    state::Mint,
+};
+use std::{str::FromStr, time::Instant};
+use tracing::{info, warn};
+use utils::RpcApi;
+
+/// -- 支持扫描和关闭的代币程序（SPL Token 与 Token-2022）
+pub const TOKEN_PROGRAM_IDS: [Pubkey; 2] = [spl_token::ID, spl_token_2022::ID];
+
+/// -- 判断程序 ID 是否为支持的代币程序
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    TOKEN_PROGRAM_IDS.contains(program_id)
+}
+
+/// -- Memo 程序 v2
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// -- 原生 SOL 的 Mint（SPL Token 与 Token-2022 各有一个）
+pub const NATIVE_MINTS: [Pubkey; 2] = [spl_token::native_mint::ID, spl_token_2022::native_mint::ID];
+
+/// -- 判断 Mint 是否为原生 SOL（wSOL 账户）
+pub fn is_native_mint(mint: &str) -> bool {
+    NATIVE_MINTS.iter().any(|native| native.to_string() == mint)
+}
+
+/// -- 创建 Memo 程序备注指令
+///
+/// 不要求额外签名者，备注内容以 UTF-8 原样写入交易日志。
+pub fn memo_instruction(memo: &str) -> Instruction {
+    Instruction::new_with_bytes(MEMO_PROGRAM_ID, memo.as_bytes(), Vec::new())
+}
+
+/// -- 在指令前插入计算预算指令，配置了 `memo` 时在末尾追加备注指令
+///
+/// 自动优先费模式下，使用交易中可写账户最近的优先费估算价格，查询失败时不设置优先费。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `config` - 管理器配置
+/// * `instructions` - 业务指令
+///
+/// # 返回
+/// * `Vec<Instruction>` - 插入计算预算指令和备注指令后的指令列表
+pub fn with_compute_budget<R: RpcApi>(
+    connection: &R,
+    config: &TokenAccountConfig,
+    instructions: Vec<Instruction>,
+) -> Vec<Instruction> {
+    let mut recent_fees = Vec::new();
+    if config.compute_unit_price.is_auto() {
+        let mut writable: Vec<Pubkey> = Vec::new();
+        for meta in instructions.iter().flat_map(|ix| &ix.accounts) {
+            if meta.is_writable && !writable.contains(&meta.pubkey) {
+                writable.push(meta.pubkey);
+            }
+        }
+        match connection.get_recent_prioritization_fees(&writable) {
+            Ok(fees) => recent_fees = fees.iter().map(|fee| fee.prioritization_fee).collect(),
+            Err(e) => warn!("查询最近优先费失败，不设置优先费: {}", e),
+        }
+    }
+
+    let mut budgeted = config.compute_budget_instructions(&recent_fees);
+    budgeted.extend(instructions);
+    if let Some(memo) = &config.memo {
+        budgeted.push(memo_instruction(memo));
+    }
+    budgeted
+}
+
+/// -- 交易的手续费支付者及签名者列表
+///
+/// 设置了独立的手续费支付者时由它支付交易费用，钱包仍作为账户所有者签名；
+/// 未设置或与钱包相同时由钱包支付并只签名一次。
+///
+/// # 参数
+/// * `fee_payer` - 独立的手续费支付者
+/// * `wallet` - 钱包签名者（账户所有者）
+///
+/// # 返回
+/// * `(Pubkey, Vec<&dyn Signer>)` - (手续费支付者地址, 交易签名者列表)
+pub fn payer_and_signers<'a>(
+    fee_payer: Option<&'a (dyn Signer + Send + Sync)>,
+    wallet: &'a impl Signer,
+) -> (Pubkey, Vec<&'a dyn Signer>) {
+    let owner = wallet.pubkey();
+    match fee_payer {
+        Some(payer) if payer.pubkey() != owner => {
+            (payer.pubkey(), vec![payer as &dyn Signer, wallet])
+        }
+        _ => (owner, vec![wallet as &dyn Signer]),
+    }
+}
+
+/// -- 交易提交结果
+pub struct Submission {
+    pub signature: Option<String>,    // -- 交易签名，模拟模式下为 None
+    pub simulation: SimulationReport, // -- 发送前的模拟结果
+}
+
+/// -- 模拟执行交易
+///
+/// 模拟失败时按 `policy` 返回错误或输出警告。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `transaction` - 已签名的交易（legacy 或 v0）
+/// * `policy` - 模拟失败时的处理方式
+///
+/// # 返回
+/// * `TokenAccountResult<SimulationReport>` - 模拟的日志和计算单元消耗
+pub fn preflight<R: RpcApi>(
+    connection: &R,
+    transaction: &impl SerializableTransaction,
+    policy: PreflightPolicy,
+) -> TokenAccountResult<SimulationReport> {
+    let simulation = connection.simulate_transaction(transaction)?;
+    let report = SimulationReport {
+        units_consumed: simulation.units_consumed,
+        logs: simulation.logs.unwrap_or_default(),
+    };
+
+    if let Some(err) = simulation.err {
+        let message = format!("模拟执行失败: {}\n{}", err, report.logs.join("\n"));
+        match policy {
+            PreflightPolicy::Abort => return Err(TokenAccountError::TransactionError(message)),
+            PreflightPolicy::Warn => warn!("{}\n按配置继续发送交易", message),
+        }
+    }
+    info!(
+        "模拟执行消耗计算单元: {}",
+        report.units_consumed.unwrap_or(0)
+    );
+
+    Ok(report)
+}
+
+/// -- 发送交易，发送前先模拟执行
+///
+/// 模拟模式下只模拟执行，模拟失败始终返回错误；否则模拟失败时按配置放弃或继续发送。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `transaction` - 已签名的交易（legacy 或 v0）
+/// * `config` - 管理器配置（模拟模式、模拟失败处理方式、确认方式）
+///
+/// # 返回
+/// * `TokenAccountResult<Submission>` - 交易签名（模拟模式下为 None）和模拟结果
+pub fn submit_transaction<R: RpcApi>(
+    connection: &R,
+    transaction: &impl SerializableTransaction,
+    config: &TokenAccountConfig,
+) -> TokenAccountResult<Submission> {
+    let policy = if config.dry_run {
+        PreflightPolicy::Abort
+    } else {
+        config.preflight
+    };
+    let simulation = preflight(connection, transaction, policy)?;
+
+    if config.dry_run {
+        info!("[模拟] 交易可以成功执行");
+        return Ok(Submission {
+            signature: None,
+            simulation,
+        });
+    }
+
+    let signature = confirmation::send_and_confirm(connection, transaction, config)?;
+
+    Ok(Submission {
+        signature: Some(signature.to_string()),
+        simulation,
+    })
+}
+
+/// -- 签名并发送交易，区块哈希过期时用新的区块哈希重新签名后再次发送
+///
+/// `sign` 使用传入的最新区块哈希构建并签名交易。节点返回区块哈希过期错误时，
+/// 重新查询区块哈希、用相同的指令重新签名并立即发送，不需要退避等待；
+/// 重新签名的次数和总时长受 `retry_policy` 限制，超过后返回最后一次的错误。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `config` - 管理器配置（模拟模式、模拟失败处理方式、确认方式、重试策略）
+/// * `sign` - 根据区块哈希构建并签名交易
+///
+/// # 返回
+/// * `TokenAccountResult<Submission>` - 交易签名（模拟模式下为 None）和模拟结果
+pub fn submit_with_fresh_blockhash<R, T, F>(
+    connection: &R,
+    config: &TokenAccountConfig,
+    sign: F,
+) -> TokenAccountResult<Submission>
+where
+    R: RpcApi,
+    T: SerializableTransaction,
+    F: Fn(Hash) -> TokenAccountResult<T>,
+{
+    let started = Instant::now();
+    let mut resigns = 0;
+    loop {
+        let transaction = sign(connection.get_latest_blockhash()?)?;
+        match submit_transaction(connection, &transaction, config) {
+            Err(e)
+                if is_blockhash_expired(&e)
+                    && config
+                        .retry_policy
+                        .next_delay(resigns, started, &e)
+                        .is_some() =>
+            {
+                resigns += 1;
+                warn!(
+                    "区块哈希已过期，使用新的区块哈希重新签名（第 {} 次）: {}",
+                    resigns, e
+                );
+            }
+            result => return result,
+        }
+    }
+}
 
 /// -- 执行账户关闭操作
 ///
@@ -9,125 +250,418 @@ use std::str::FromStr;
 ///
 /// # 参数
 /// * `connection` - RPC 客户端连接
-/// * `wallet` - 钱包密钥对
+/// * `fee_payer` - 独立的手续费支付者，None 表示由钱包支付
+/// * `wallet` - 钱包签名者
 /// * `account_pubkey` - 要关闭的账户公钥
 /// * `rent_lamports` - 账户当前的租金金额
+/// * `program_id` - 账户所属的代币程序 ID
+/// * `harvest_mint` - 账户中有暂扣的转账手续费时传入 Mint 地址，关闭前先提取到 Mint
+/// * `config` - 管理器配置（计算预算、模拟模式）
 ///
 /// # 返回
-/// * `TokenAccountResult<(String, u64)>` - 成功返回 (交易签名, 租金金额)，失败返回错误
-pub async fn execute_close_account(
-    connection: &solana_client::rpc_client::RpcClient,
-    wallet: &solana_sdk::signature::Keypair,
+/// * `TokenAccountResult<(Submission, u64)>` - 成功返回 (提交结果, 租金金额)，模拟模式下签名为 None
+#[allow(clippy::too_many_arguments)]
+pub async fn execute_close_account<R: RpcApi>(
+    connection: &R,
+    fee_payer: Option<&(dyn Signer + Send + Sync)>,
+    wallet: &impl Signer,
     account_pubkey: &Pubkey,
     rent_lamports: u64,
-) -> TokenAccountResult<(String, u64)> {
-    let instruction = close_account(
-        &spl_token::id(),
+    program_id: &Pubkey,
+    harvest_mint: Option<&Pubkey>,
+    config: &TokenAccountConfig,
+) -> TokenAccountResult<(Submission, u64)> {
+    let mut instructions = Vec::new();
+    if let Some(mint) = harvest_mint {
+        instructions.push(harvest_withheld_instruction(
+            program_id,
+            mint,
+            account_pubkey,
+        )?);
+    }
+    instructions.push(close_account(
+        program_id,
         account_pubkey,
         &wallet.pubkey(),
         &wallet.pubkey(),
         &[&wallet.pubkey()],
-    )?;
+    )?);
 
-    let transaction = Transaction::new_signed_with_payer(
-        &[instruction],
-        Some(&wallet.pubkey()),
-        &[wallet],
-        connection
-            .get_latest_blockhash()
-            .map_err(TokenAccountError::from)?,
-    );
+    let instructions = with_compute_budget(connection, config, instructions);
+    let (payer, signers) = payer_and_signers(fee_payer, wallet);
+    let submission = submit_with_fresh_blockhash(connection, config, |blockhash| {
+        Ok(Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&payer),
+            &signers,
+            blockhash,
+        ))
+    })?;
 
-    let signature = connection
-        .send_and_confirm_transaction(&transaction)
-        .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
-
-    Ok((signature.to_string(), rent_lamports))
+    Ok((submission, rent_lamports))
 }
 
-/// -- 创建批量关闭交易
+/// -- 生成关闭多个账户的指令，租金退回钱包
 ///
-/// 为多个账户创建一个批量关闭交易。
+/// 扫描时记录到暂扣转账手续费的 Token-2022 账户，在关闭前先提取手续费到 Mint。
 ///
 /// # 参数
-/// * `connection` - RPC 客户端连接
-/// * `wallet` - 钱包密钥对
+/// * `wallet` - 钱包地址（账户所有者）
 /// * `accounts` - 要关闭的账户列表
-///
-/// # 返回
-/// * `TokenAccountResult<(Transaction, f64)>` - 成功返回 (交易对象, 预计回收租金)
-pub async fn create_batch_close_transaction(
-    connection: &solana_client::rpc_client::RpcClient,
-    wallet: &solana_sdk::signature::Keypair,
+pub fn close_instructions(
+    wallet: &Pubkey,
     accounts: &[crate::account_info::TokenAccountInfo],
-) -> TokenAccountResult<(Transaction, f64)> {
-    let mut instructions = Vec::new();
-    let mut total_rent_recovered = 0.0;
-
+) -> TokenAccountResult<Vec<Instruction>> {
+    let mut instructions = Vec::with_capacity(accounts.len());
     for account in accounts {
         let pubkey = Pubkey::from_str(&account.address)
             .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
-        let instruction = close_account(
-            &spl_token::id(),
+        let program_id = Pubkey::from_str(&account.program_id)
+            .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+        if account.withheld_amount > 0 {
+            let mint = Pubkey::from_str(&account.mint)
+                .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+            instructions.push(harvest_withheld_instruction(&program_id, &mint, &pubkey)?);
+        }
+        instructions.push(close_account(
+            &program_id,
             &pubkey,
-            &wallet.pubkey(),
-            &wallet.pubkey(),
-            &[&wallet.pubkey()],
-        )?;
-        instructions.push(instruction);
-        total_rent_recovered += account.rent_sol;
+            wallet,
+            wallet,
+            &[wallet],
+        )?);
     }
+    Ok(instructions)
+}
 
-    let transaction = Transaction::new_signed_with_payer(
-        &instructions,
-        Some(&wallet.pubkey()),
+/// -- 销毁代币的目标账户
+#[derive(Debug, Clone)]
+pub struct BurnTarget {
+    pub account: Pubkey,    // -- 要销毁代币的账户
+    pub mint: Pubkey,       // -- 代币的 Mint 地址
+    pub program_id: Pubkey, // -- 账户所属的代币程序 ID
+    pub amount: u64,        // -- 要销毁的代币数量
+    pub decimals: u8,       // -- Mint 的链上精度
+    pub transfer_fee: bool, // -- Mint 是否启用转账手续费扩展，关闭前需要提取暂扣的手续费
+}
+
+impl BurnTarget {
+    /// -- 解析地址并读取 Mint 的链上精度
+    ///
+    /// 地址无效时返回 [`TokenAccountError::AccountParseError`]；
+    /// `expected_decimals` 与链上精度不一致时返回 [`TokenAccountError::DecimalsMismatch`]，不发送必然失败的交易。
+    ///
+    /// # 参数
+    /// * `connection` - RPC 客户端连接
+    /// * `account` - 账户地址
+    /// * `mint` - Mint 地址
+    /// * `program_id` - 代币程序 ID
+    /// * `amount` - 要销毁的代币数量
+    /// * `expected_decimals` - 扫描时记录的精度，None 表示直接使用链上精度
+    pub fn resolve<R: RpcApi>(
+        connection: &R,
+        account: &str,
+        mint: &str,
+        program_id: &str,
+        amount: u64,
+        expected_decimals: Option<u8>,
+    ) -> TokenAccountResult<Self> {
+        let parse = |value: &str| {
+            Pubkey::from_str(value).map_err(|e| TokenAccountError::AccountParseError(e.to_string()))
+        };
+        let (account, mint, program_id) = (parse(account)?, parse(mint)?, parse(program_id)?);
+
+        let (decimals, transfer_fee) = read_mint(connection, &mint, &program_id)?;
+        if let Some(expected) = expected_decimals.filter(|expected| *expected != decimals) {
+            return Err(TokenAccountError::DecimalsMismatch {
+                mint: mint.to_string(),
+                expected,
+                actual: decimals,
+            });
+        }
+
+        Ok(Self {
+            account,
+            mint,
+            program_id,
+            amount,
+            decimals,
+            transfer_fee,
+        })
+    }
+}
+
+/// -- 读取 Mint 的链上精度，以及是否启用了转账手续费扩展
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `mint` - Mint 地址
+/// * `program_id` - Mint 应属于的代币程序 ID
+///
+/// # 返回
+/// * `TokenAccountResult<(u8, bool)>` - (精度, 是否启用转账手续费)
+pub fn read_mint<R: RpcApi>(
+    connection: &R,
+    mint: &Pubkey,
+    program_id: &Pubkey,
+) -> TokenAccountResult<(u8, bool)> {
+    let account = connection.get_account(mint)?;
+    if account.owner != *program_id {
+        return Err(TokenAccountError::AccountParseError(format!(
+            "Mint {} 不属于代币程序 {}",
+            mint, program_id
+        )));
+    }
+    let state = StateWithExtensions::<Mint>::unpack(&account.data)
+        .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+    let transfer_fee = state.get_extension::<TransferFeeConfig>().is_ok();
+    Ok((state.base.decimals, transfer_fee))
+}
+
+/// -- 生成将账户中暂扣的转账手续费提取到 Mint 的指令
+///
+/// Token-2022 转账手续费扩展的账户中仍有暂扣手续费时无法关闭。提取到 Mint 不需要任何签名，
+/// 之后由 Mint 的手续费提取权限持有者通过 `withdraw_withheld_tokens_from_mint` 领取。
+///
+/// # 参数
+/// * `program_id` - 代币程序 ID
+/// * `mint` - Mint 地址
+/// * `account` - 暂扣手续费的账户
+pub fn harvest_withheld_instruction(
+    program_id: &Pubkey,
+    mint: &Pubkey,
+    account: &Pubkey,
+) -> TokenAccountResult<Instruction> {
+    Ok(harvest_withheld_tokens_to_mint(
+        program_id,
+        mint,
+        &[account],
+    )?)
+}
+
+/// -- 生成销毁代币并关闭账户的指令
+///
+/// 使用 `burn_checked`，链上会再次校验精度。Mint 启用了转账手续费扩展时，
+/// 在关闭前提取账户中暂扣的手续费。
+///
+/// # 参数
+/// * `wallet` - 钱包地址（账户所有者）
+/// * `target` - 销毁目标
+pub fn burn_and_close_instructions(
+    wallet: &Pubkey,
+    target: &BurnTarget,
+) -> TokenAccountResult<Vec<Instruction>> {
+    let mut instructions = vec![burn_checked(
+        &target.program_id,
+        &target.account,
+        &target.mint,
+        wallet,
         &[wallet],
-        connection.get_latest_blockhash()?,
-    );
+        target.amount,
+        target.decimals,
+    )?];
+    if target.transfer_fee {
+        instructions.push(harvest_withheld_instruction(
+            &target.program_id,
+            &target.mint,
+            &target.account,
+        )?);
+    }
+    instructions.push(close_account(
+        &target.program_id,
+        &target.account,
+        wallet,
+        wallet,
+        &[wallet],
+    )?);
+    Ok(instructions)
+}
+
+/// -- 读取地址查找表
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `table` - 查找表地址
+///
+/// # 返回
+/// * `TokenAccountResult<AddressLookupTableAccount>` - 可用于编译 v0 消息的查找表
+pub fn fetch_lookup_table<R: RpcApi>(
+    connection: &R,
+    table: &Pubkey,
+) -> TokenAccountResult<AddressLookupTableAccount> {
+    let data = connection.get_account_data(table)?;
+    let lookup_table = AddressLookupTable::deserialize(&data).map_err(|e| {
+        TokenAccountError::AccountParseError(format!("无效的地址查找表 {}: {}", table, e))
+    })?;
 
-    Ok((transaction, total_rent_recovered))
+    Ok(AddressLookupTableAccount {
+        key: *table,
+        addresses: lookup_table.addresses.to_vec(),
+    })
+}
+
+/// -- 创建批量关闭消息（未签名）
+///
+/// 配置了地址查找表时生成 v0 消息，查找表中的账户只占用 1 字节索引，单笔交易可以容纳更多关闭指令；
+/// 否则生成 legacy 消息。只需要钱包地址，可以在不持有私钥的机器上生成，再交给签名者离线签名。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `payer` - 手续费支付者地址
+/// * `owner` - 钱包地址（账户所有者）
+/// * `accounts` - 要关闭的账户列表
+/// * `config` - 管理器配置（计算预算、地址查找表）
+///
+/// # 返回
+/// * `TokenAccountResult<VersionedMessage>` - 使用最新区块哈希编译的消息
+pub fn create_batch_close_message<R: RpcApi>(
+    connection: &R,
+    payer: &Pubkey,
+    owner: &Pubkey,
+    accounts: &[crate::account_info::TokenAccountInfo],
+    config: &TokenAccountConfig,
+) -> TokenAccountResult<VersionedMessage> {
+    let blockhash = connection.get_latest_blockhash()?;
+    batch_close_message_with_blockhash(connection, payer, owner, accounts, config, &blockhash)
+}
+
+/// -- 使用指定的区块哈希创建批量关闭消息
+///
+/// 并发提交时多笔交易共用缓存的区块哈希，不必每笔交易都查询一次。
+pub(crate) fn batch_close_message_with_blockhash<R: RpcApi>(
+    connection: &R,
+    payer: &Pubkey,
+    owner: &Pubkey,
+    accounts: &[crate::account_info::TokenAccountInfo],
+    config: &TokenAccountConfig,
+    blockhash: &Hash,
+) -> TokenAccountResult<VersionedMessage> {
+    let instructions = close_instructions(owner, accounts)?;
+    let instructions = with_compute_budget(connection, config, instructions);
+
+    match &config.lookup_table {
+        Some(table) => {
+            let lookup_table = fetch_lookup_table(connection, table)?;
+            let message =
+                v0::Message::try_compile(payer, &instructions, &[lookup_table], *blockhash)
+                    .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+            Ok(VersionedMessage::V0(message))
+        }
+        None => Ok(VersionedMessage::Legacy(Message::new_with_blockhash(
+            &instructions,
+            Some(payer),
+            blockhash,
+        ))),
+    }
+}
+
+/// -- 签名并发送批量关闭交易
+///
+/// 为多个账户创建一个批量关闭交易，消息格式见 [`create_batch_close_message`]。
+/// 区块哈希过期时用新的区块哈希重新构建并签名，见 [`submit_with_fresh_blockhash`]。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `fee_payer` - 独立的手续费支付者，None 表示由钱包支付
+/// * `wallet` - 钱包签名者
+/// * `accounts` - 要关闭的账户列表
+/// * `config` - 管理器配置（计算预算、地址查找表、模拟模式）
+///
+/// # 返回
+/// * `TokenAccountResult<Submission>` - 交易签名（模拟模式下为 None）和模拟结果
+pub async fn send_batch_close_transaction<R: RpcApi>(
+    connection: &R,
+    fee_payer: Option<&(dyn Signer + Send + Sync)>,
+    wallet: &impl Signer,
+    accounts: &[crate::account_info::TokenAccountInfo],
+    config: &TokenAccountConfig,
+) -> TokenAccountResult<Submission> {
+    let (payer, signers) = payer_and_signers(fee_payer, wallet);
+    submit_with_fresh_blockhash(connection, config, |blockhash| {
+        let message = batch_close_message_with_blockhash(
+            connection,
+            &payer,
+            &wallet.pubkey(),
+            accounts,
+            config,
+            &blockhash,
+        )?;
+        VersionedTransaction::try_new(message, &signers)
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string()))
+    })
 }
 
 /// -- 销毁代币
 ///
-/// 销毁指定账户中的代币。
+/// 使用 `burn_checked` 销毁指定账户中的代币。
 ///
 /// # 参数
 /// * `connection` - RPC 客户端连接
-/// * `wallet` - 钱包密钥对
-/// * `account_pubkey` - 要销毁代币的账户公钥
-/// * `mint_pubkey` - 代币的 Mint 地址
-/// * `amount` - 要销毁的代币数量
+/// * `fee_payer` - 独立的手续费支付者，None 表示由钱包支付
+/// * `wallet` - 钱包签名者
+/// * `target` - 销毁目标
+/// * `config` - 管理器配置（计算预算、模拟失败处理方式）
 ///
 /// # 返回
-/// * `TokenAccountResult<String>` - 成功返回交易签名，失败返回错误
-pub async fn burn_tokens(
-    connection: &solana_client::rpc_client::RpcClient,
-    wallet: &solana_sdk::signature::Keypair,
-    account_pubkey: &Pubkey,
-    mint_pubkey: &Pubkey,
-    amount: u64,
-) -> TokenAccountResult<String> {
-    let burn_instruction = spl_token::instruction::burn(
-        &spl_token::id(),
-        account_pubkey,
-        mint_pubkey,
+/// * `TokenAccountResult<Submission>` - 成功返回交易签名和模拟结果，失败返回错误
+pub async fn burn_tokens<R: RpcApi>(
+    connection: &R,
+    fee_payer: Option<&(dyn Signer + Send + Sync)>,
+    wallet: &impl Signer,
+    target: &BurnTarget,
+    config: &TokenAccountConfig,
+) -> TokenAccountResult<Submission> {
+    let burn_instruction = burn_checked(
+        &target.program_id,
+        &target.account,
+        &target.mint,
         &wallet.pubkey(),
         &[&wallet.pubkey()],
-        amount,
+        target.amount,
+        target.decimals,
     )?;
 
-    let recent_blockhash = connection.get_latest_blockhash()?;
-    let burn_tx = Transaction::new_signed_with_payer(
-        &[burn_instruction],
-        Some(&wallet.pubkey()),
-        &[wallet],
-        recent_blockhash,
-    );
+    let instructions = with_compute_budget(connection, config, vec![burn_instruction]);
+    let (payer, signers) = payer_and_signers(fee_payer, wallet);
+    submit_with_fresh_blockhash(connection, config, |blockhash| {
+        Ok(Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&payer),
+            &signers,
+            blockhash,
+        ))
+    })
+}
 
-    let signature = connection
-        .send_and_confirm_transaction(&burn_tx)
-        .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+/// -- 模拟销毁代币并关闭账户
+///
+/// 模拟模式下销毁和关闭无法分两笔交易依次模拟（关闭前余额仍不为 0），
+/// 因此将两条指令放入同一笔交易中模拟执行。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `fee_payer` - 独立的手续费支付者，None 表示由钱包支付
+/// * `wallet` - 钱包签名者
+/// * `target` - 销毁目标
+/// * `config` - 管理器配置（计算预算）
+///
+/// # 返回
+/// * `TokenAccountResult<SimulationReport>` - 模拟成功返回日志和计算单元消耗，失败返回错误
+pub async fn simulate_burn_and_close<R: RpcApi>(
+    connection: &R,
+    fee_payer: Option<&(dyn Signer + Send + Sync)>,
+    wallet: &impl Signer,
+    target: &BurnTarget,
+    config: &TokenAccountConfig,
+) -> TokenAccountResult<SimulationReport> {
+    let instructions = burn_and_close_instructions(&wallet.pubkey(), target)?;
 
-    Ok(signature.to_string())
+    let (payer, signers) = payer_and_signers(fee_payer, wallet);
+    let transaction = Transaction::new_signed_with_payer(
+        &with_compute_budget(connection, config, instructions),
+        Some(&payer),
+        &signers,
+        connection.get_latest_blockhash()?,
+    );
+    preflight(connection, &transaction, PreflightPolicy::Abort)
 }
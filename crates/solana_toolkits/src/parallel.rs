@@ -0,0 +1,272 @@
+use crate::{
+    account_info::TokenAccountInfo,
+    operations::{
+        batch_close_message_with_blockhash, payer_and_signers, submit_transaction, Submission,
+    },
+    retry::is_blockhash_expired,
+    TokenAccountManager,
+};
+use futures::stream::{FuturesUnordered, StreamExt};
+use solana_sdk::{hash::Hash, signer::Signer, transaction::VersionedTransaction};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+use tracing::{error, info, warn};
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 区块哈希的有效期
+///
+/// 区块哈希约 150 个区块（60 到 90 秒）后失效，这里取保守值，超过后不再发送，重新签名。
+pub const BLOCKHASH_MAX_AGE: Duration = Duration::from_secs(60);
+
+/// -- 区块哈希缓存的刷新间隔，签名新交易时缓存超过该时间就查询新的区块哈希
+const BLOCKHASH_REFRESH_INTERVAL: Duration = Duration::from_secs(20);
+
+/// -- 单个批次因区块哈希过期重新签名的最大次数
+const MAX_RESIGNS: u32 = 5;
+
+/// -- 并发提交的结果：成功的批次及提交结果、失败的批次及最后一次的错误
+type ConcurrentOutcome<'a> = (
+    Vec<(&'a [TokenAccountInfo], Submission)>,
+    Vec<(&'a [TokenAccountInfo], TokenAccountError)>,
+);
+
+/// -- 带查询时间的区块哈希缓存
+#[derive(Default)]
+struct BlockhashCache {
+    current: Option<(Hash, Instant)>,
+}
+
+impl BlockhashCache {
+    /// -- 返回缓存的区块哈希及其查询时间，超过刷新间隔时重新查询
+    fn get(&mut self, connection: &impl RpcApi) -> TokenAccountResult<(Hash, Instant)> {
+        match self.current {
+            Some((hash, fetched_at)) if fetched_at.elapsed() < BLOCKHASH_REFRESH_INTERVAL => {
+                Ok((hash, fetched_at))
+            }
+            _ => {
+                let current = (connection.get_latest_blockhash()?, Instant::now());
+                self.current = Some(current);
+                Ok(current)
+            }
+        }
+    }
+
+    /// -- 丢弃缓存，下次签名时查询新的区块哈希
+    fn invalidate(&mut self) {
+        self.current = None;
+    }
+}
+
+/// -- 等待签名的批次
+struct PendingBatch {
+    index: usize,      // -- 批次序号
+    retries: u32,      // -- 已重试次数（不含重新签名）
+    resigns: u32,      // -- 因区块哈希过期重新签名的次数
+    started: Instant,  // -- 第一次签名的时间，用于计算重试总时长
+    ready_at: Instant, // -- 最早可以签名的时间（重试退避）
+}
+
+/// -- 已签名、等待发送的批次
+struct SignedBatch {
+    batch: PendingBatch,
+    transaction: VersionedTransaction,
+    blockhash_fetched_at: Instant, // -- 交易所用区块哈希的查询时间
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 并发提交批量关闭交易
+    ///
+    /// 按批次构建并签名交易，最多 `max_concurrent_transactions` 笔交易同时发送并等待确认。
+    /// 签名使用缓存的区块哈希；发送前发现区块哈希已超过 [`BLOCKHASH_MAX_AGE`]，
+    /// 或节点返回区块哈希过期错误时，批次回到队列用新的区块哈希重新构建并签名，
+    /// 不计入重试次数。其他错误按 `retry_policy` 退避重试，退避期间不阻塞异步运行时。
+    ///
+    /// # 参数
+    /// * `accounts` - 要关闭的账户列表
+    /// * `batch_size` - 每笔交易关闭的账户数量
+    ///
+    /// # 返回
+    /// * `(成功的批次, 失败的批次)` - 成功的批次附带提交结果，失败的批次附带最后一次的错误
+    pub(crate) async fn close_batches_concurrently<'a>(
+        &self,
+        accounts: &'a [TokenAccountInfo],
+        batch_size: usize,
+    ) -> ConcurrentOutcome<'a> {
+        let chunks: Vec<&[TokenAccountInfo]> = accounts.chunks(batch_size).collect();
+        let concurrency = self
+            .config
+            .max_concurrent_transactions
+            .clamp(1, chunks.len().max(1));
+        info!(
+            "并发提交 {} 笔批量关闭交易，并发数: {}",
+            chunks.len(),
+            concurrency
+        );
+
+        let now = Instant::now();
+        let mut pending: VecDeque<PendingBatch> = (0..chunks.len())
+            .map(|index| PendingBatch {
+                index,
+                retries: 0,
+                resigns: 0,
+                started: now,
+                ready_at: now,
+            })
+            .collect();
+        let mut blockhash = BlockhashCache::default();
+        let mut succeeded = Vec::new();
+        let mut failed = Vec::new();
+
+        let connection = &self.connection;
+        let config = &self.config;
+        let mut in_flight = FuturesUnordered::new();
+        loop {
+            // -- 补足在途交易：按队列顺序签名已到重试时间的批次
+            while in_flight.len() < concurrency {
+                let now = Instant::now();
+                let Some(position) = pending.iter().position(|batch| batch.ready_at <= now) else {
+                    break;
+                };
+                let Some(batch) = pending.remove(position) else {
+                    break;
+                };
+                let chunk = chunks[batch.index];
+                if batch.retries == 0 && batch.resigns == 0 {
+                    self.observer
+                        .on_batch_start(batch.index + 1, chunks.len(), chunk.len());
+                }
+                match self.sign_close_batch(chunk, &mut blockhash) {
+                    Ok((transaction, blockhash_fetched_at)) => {
+                        let job = SignedBatch {
+                            batch,
+                            transaction,
+                            blockhash_fetched_at,
+                        };
+                        in_flight.push(async move {
+                            let result = if job.blockhash_fetched_at.elapsed() >= BLOCKHASH_MAX_AGE
+                            {
+                                Err(TokenAccountError::TransactionError(
+                                    "blockhash expired before sending".to_string(),
+                                ))
+                            } else {
+                                submit_transaction(connection, &job.transaction, config)
+                            };
+                            (job.batch, result)
+                        });
+                    }
+                    Err(e) => {
+                        error!("第 {} 批交易签名失败: {}", batch.index + 1, e);
+                        failed.push((chunk, e));
+                    }
+                }
+            }
+
+            let next_ready = pending.iter().map(|batch| batch.ready_at).min();
+            if in_flight.is_empty() {
+                match next_ready {
+                    Some(ready_at) => {
+                        tokio::time::sleep(ready_at.saturating_duration_since(Instant::now()))
+                            .await;
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+
+            // -- 等待任一交易完成；有批次在退避时，到时间后回到循环开头签名
+            let done = match next_ready {
+                Some(ready_at) => {
+                    let wait = ready_at.saturating_duration_since(Instant::now());
+                    match tokio::time::timeout(wait, in_flight.next()).await {
+                        Ok(done) => done,
+                        Err(_) => continue,
+                    }
+                }
+                None => in_flight.next().await,
+            };
+            let Some((batch, result)) = done else {
+                break;
+            };
+
+            let chunk = chunks[batch.index];
+            match result {
+                Ok(submission) => {
+                    match &submission.signature {
+                        Some(signature) => info!("批量关闭成功，交易签名: {}", signature),
+                        None => info!("[模拟] 批量关闭可以成功执行"),
+                    }
+                    succeeded.push((chunk, submission));
+                }
+                Err(e) if is_blockhash_expired(&e) && batch.resigns < MAX_RESIGNS => {
+                    warn!(
+                        "第 {} 批交易的区块哈希已过期，重新签名: {}",
+                        batch.index + 1,
+                        e
+                    );
+                    blockhash.invalidate();
+                    pending.push_front(PendingBatch {
+                        resigns: batch.resigns + 1,
+                        ready_at: Instant::now(),
+                        ..batch
+                    });
+                }
+                Err(e) => {
+                    match self
+                        .config
+                        .retry_policy
+                        .next_delay(batch.retries, batch.started, &e)
+                    {
+                        Some(delay) => {
+                            warn!(
+                                "第 {} 批重试第 {} 次（{:?} 后）: {}",
+                                batch.index + 1,
+                                batch.retries + 1,
+                                delay,
+                                e
+                            );
+                            self.observer
+                                .on_retry(batch.index + 1, batch.retries + 1, &e);
+                            pending.push_back(PendingBatch {
+                                retries: batch.retries + 1,
+                                ready_at: Instant::now() + delay,
+                                ..batch
+                            });
+                        }
+                        None => {
+                            error!("第 {} 批处理失败: {}", batch.index + 1, e);
+                            failed.push((chunk, e));
+                        }
+                    }
+                }
+            }
+        }
+
+        (succeeded, failed)
+    }
+
+    /// -- 使用缓存的区块哈希构建并签名一笔批量关闭交易
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<(VersionedTransaction, Instant)>` - 已签名的交易及所用区块哈希的查询时间
+    fn sign_close_batch(
+        &self,
+        chunk: &[TokenAccountInfo],
+        blockhash: &mut BlockhashCache,
+    ) -> TokenAccountResult<(VersionedTransaction, Instant)> {
+        let (hash, fetched_at) = blockhash.get(&self.connection)?;
+        let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+        let message = batch_close_message_with_blockhash(
+            &self.connection,
+            &payer,
+            &self.wallet.pubkey(),
+            chunk,
+            &self.config,
+            &hash,
+        )?;
+        let transaction = VersionedTransaction::try_new(message, &signers)
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+        Ok((transaction, fetched_at))
+    }
+}
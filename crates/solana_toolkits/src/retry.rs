@@ -0,0 +1,191 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use utils::TokenAccountError;
+
+/// -- 区块哈希过期的错误信息（忽略大小写），重新签名后可以成功
+const BLOCKHASH_EXPIRED_PATTERNS: &[&str] = &[
+    "blockhash not found",
+    "block height exceeded",
+    "blockhash expired",
+];
+
+/// -- 账户不存在时的错误信息（忽略大小写）
+///
+/// 关闭不存在的代币账户时，代币程序读取到空数据，返回 `InvalidAccountData`。
+const ACCOUNT_MISSING_PATTERNS: &[&str] = &[
+    "accountnotfound",
+    "account not found",
+    "invalid account data",
+];
+
+/// -- 可以重试的错误信息（忽略大小写），与区块哈希过期的错误信息一起优先于不可重试的错误信息匹配
+const RETRYABLE_PATTERNS: &[&str] = &[
+    "too many requests",
+    "rate limit",
+    "node is behind",
+    "node is unhealthy",
+    "timed out",
+    "timeout",
+    "connection reset",
+];
+
+/// -- 重试也无法成功的错误信息（忽略大小写）
+const FATAL_PATTERNS: &[&str] = &[
+    "insufficient funds",
+    "insufficient lamports",
+    "invalid account owner",
+    "invalidaccountowner",
+    "owner does not match",
+    "incorrect program id",
+    "incorrectprogramid",
+    "invalid account data",
+    "non-native account can only be closed if its balance is zero",
+    "custom program error",
+];
+
+/// -- 重试策略
+///
+/// 失败后等待 `initial_delay`，之后每次重试等待时间乘以 `multiplier`，不超过 `max_delay`；
+/// 每次等待时间叠加 `jitter` 比例的随机抖动，避免多个任务同时重试。
+/// 只有可重试的错误才会重试（见 [`is_retryable`]），账户状态错误、余额不足等立即放弃。
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,              // -- 最大重试次数
+    pub initial_delay: Duration,       // -- 第一次重试前的等待时间
+    pub max_delay: Duration,           // -- 单次等待时间上限
+    pub multiplier: f64,               // -- 每次重试后等待时间的倍数，1.0 表示固定间隔
+    pub jitter: f64,                   // -- 随机抖动比例（0.0 ~ 1.0）
+    pub max_elapsed: Option<Duration>, // -- 从第一次尝试起的最长重试时间，None 表示不限制
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay: Duration::from_millis(1000),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: 0.1,
+            max_elapsed: Some(Duration::from_secs(60)),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// -- 固定间隔、不带抖动的重试策略
+    ///
+    /// # 参数
+    /// * `max_retries` - 最大重试次数
+    /// * `delay` - 每次重试前的等待时间
+    pub fn fixed(max_retries: u32, delay: Duration) -> Self {
+        Self {
+            max_retries,
+            initial_delay: delay,
+            max_delay: delay,
+            multiplier: 1.0,
+            jitter: 0.0,
+            max_elapsed: None,
+        }
+    }
+
+    /// -- 第 `retry` 次重试（从 1 开始）前的等待时间
+    pub fn delay(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1) as i32;
+        let base = self.initial_delay.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        let base = base.min(self.max_delay.as_secs_f64());
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        // -- 在 [1 - jitter, 1 + jitter) 范围内随机缩放
+        let factor = 1.0 - jitter + 2.0 * jitter * random_unit();
+        Duration::from_secs_f64((base * factor).max(0.0))
+    }
+
+    /// -- 判断失败后是否继续重试，需要重试时返回等待时间
+    ///
+    /// # 参数
+    /// * `retries` - 已经重试的次数
+    /// * `started` - 第一次尝试的开始时间
+    /// * `error` - 本次失败的错误
+    ///
+    /// # 返回
+    /// * `Option<Duration>` - 下一次重试前的等待时间，None 表示放弃
+    pub fn next_delay(
+        &self,
+        retries: u32,
+        started: Instant,
+        error: &TokenAccountError,
+    ) -> Option<Duration> {
+        if retries >= self.max_retries || !is_retryable(error) {
+            return None;
+        }
+        let delay = self.delay(retries + 1);
+        match self.max_elapsed {
+            Some(max_elapsed) if started.elapsed() + delay > max_elapsed => None,
+            _ => Some(delay),
+        }
+    }
+}
+
+/// -- 判断错误是否值得重试
+///
+/// 区块哈希过期、限流、节点落后和网络超时等临时错误可以重试；
+/// 账户状态错误（余额不为 0、已冻结、所有者或关闭权限不匹配）、余额不足和数据解析错误重试也不会成功。
+/// 无法识别的错误按可重试处理。
+pub fn is_retryable(error: &TokenAccountError) -> bool {
+    match error {
+        TokenAccountError::NonZeroBalance(_)
+        | TokenAccountError::AccountFrozen(_)
+        | TokenAccountError::NotCloseAuthority { .. }
+        | TokenAccountError::AccountParseError(_)
+        | TokenAccountError::AccountNotFound(_)
+        | TokenAccountError::InvalidKeyFormat
+        | TokenAccountError::DecimalsMismatch { .. }
+        | TokenAccountError::InsufficientReserve { .. }
+        | TokenAccountError::FeeBudgetExceeded { .. }
+        | TokenAccountError::JsonError(_)
+        | TokenAccountError::SplTokenError(_)
+        | TokenAccountError::ProgramError(_) => false,
+        _ => {
+            let message = error.to_string().to_lowercase();
+            is_blockhash_expired(error)
+                || RETRYABLE_PATTERNS
+                    .iter()
+                    .any(|pattern| message.contains(pattern))
+                || !FATAL_PATTERNS
+                    .iter()
+                    .any(|pattern| message.contains(pattern))
+        }
+    }
+}
+
+/// -- 判断错误是否因交易的区块哈希过期
+///
+/// 这类交易需要用新的区块哈希重新构建并签名，原样重新发送不会成功。
+pub fn is_blockhash_expired(error: &TokenAccountError) -> bool {
+    let message = error.to_string().to_lowercase();
+    BLOCKHASH_EXPIRED_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+/// -- 判断错误是否可能因账户不存在（已被关闭）导致
+///
+/// 为 true 时需要再查询账户确认，`InvalidAccountData` 也可能由其他原因引起。
+pub fn is_account_missing(error: &TokenAccountError) -> bool {
+    if matches!(error, TokenAccountError::AccountNotFound(_)) {
+        return true;
+    }
+    let message = error.to_string().to_lowercase();
+    ACCOUNT_MISSING_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+/// -- [0, 1) 范围内的伪随机数，只用于重试抖动
+fn random_unit() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos())
+        .unwrap_or(0);
+    // -- 打乱低位后取高位，避免连续调用得到相近的值
+    let mixed = (nanos as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    (mixed >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,430 @@
+use crate::{account_info::BatchCloseReport, history::RunOperation, TokenAccountManager};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signer::Signer};
+use std::{fmt::Write as _, fs, path::Path};
+use tracing::{info, warn};
+use utils::{
+    cluster::Cluster,
+    locale::{current_locale, Locale},
+    RpcApi, TokenAccountResult,
+};
+
+/// -- Solana Explorer 地址
+const EXPLORER_URL: &str = "https://explorer.solana.com";
+
+/// -- 本地节点在 Explorer 中的自定义集群参数（URL 编码后的 http://127.0.0.1:8899）
+const LOCALNET_EXPLORER_QUERY: &str = "?cluster=custom&customUrl=http%3A%2F%2F127.0.0.1%3A8899";
+
+/// -- 可读报告格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Markdown，适合粘贴到 issue、聊天或 Git 仓库中存档
+    Markdown,
+    /// 单文件 HTML，可直接在浏览器中打开
+    Html,
+}
+
+impl ReportFormat {
+    /// -- 根据文件扩展名选择格式：`.html` / `.htm` 为 HTML，其他为 Markdown
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("html") || ext.eq_ignore_ascii_case("htm") => {
+                ReportFormat::Html
+            }
+            _ => ReportFormat::Markdown,
+        }
+    }
+}
+
+/// -- 交易在 Solana Explorer 中的链接
+pub fn explorer_tx_url(signature: &str, cluster: &Cluster) -> String {
+    format!(
+        "{}/tx/{}{}",
+        EXPLORER_URL,
+        signature,
+        explorer_query(cluster)
+    )
+}
+
+/// -- 账户在 Solana Explorer 中的链接
+pub fn explorer_account_url(address: &str, cluster: &Cluster) -> String {
+    format!(
+        "{}/address/{}{}",
+        EXPLORER_URL,
+        address,
+        explorer_query(cluster)
+    )
+}
+
+/// -- 集群对应的 Explorer 查询参数
+///
+/// 自定义 RPC 地址通常是带 API key 的私有 mainnet 节点，为避免泄露到共享的报告中按 mainnet 生成链接。
+fn explorer_query(cluster: &Cluster) -> &'static str {
+    match cluster {
+        Cluster::Mainnet | Cluster::Custom(_) => "",
+        Cluster::Devnet => "?cluster=devnet",
+        Cluster::Testnet => "?cluster=testnet",
+        Cluster::Localnet => LOCALNET_EXPLORER_QUERY,
+    }
+}
+
+/// -- 报告标题
+fn title(operation: RunOperation, report: &BatchCloseReport, locale: Locale) -> String {
+    let name = match operation {
+        RunOperation::Close => locale.text("批量关闭报告", "Batch close report"),
+        RunOperation::Burn => locale.text("批量销毁报告", "Batch burn report"),
+        RunOperation::Sweep => locale.text("批量转出报告", "Batch sweep report"),
+        RunOperation::Broadcast => locale.text("离线交易广播报告", "Offline broadcast report"),
+    };
+    if report.simulated {
+        format!("{} {}", locale.text("[模拟]", "[Simulated]"), name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// -- lamports 转换为 SOL
+fn sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64
+}
+
+/// -- 表格单元格
+enum Cell {
+    Text(String),
+    Link { text: String, url: String },
+}
+
+/// -- 报告中的一个表格
+struct Table {
+    title: &'static str,
+    headers: &'static [&'static str],
+    rows: Vec<Vec<Cell>>,
+}
+
+/// -- 与输出格式无关的报告内容
+struct ReportContent {
+    title: String,
+    summary: Vec<(&'static str, Cell)>,
+    tables: Vec<Table>,
+    locale: Locale,
+}
+
+impl ReportContent {
+    fn new(
+        wallet: &str,
+        operation: RunOperation,
+        report: &BatchCloseReport,
+        cluster: &Cluster,
+        locale: Locale,
+    ) -> Self {
+        let text = |zh, en| locale.text(zh, en);
+        let accounts = |count: usize| match locale {
+            Locale::Zh => format!("{} 个账户", count),
+            Locale::En => format!("{} account{}", count, if count == 1 { "" } else { "s" }),
+        };
+        let tx_link = |signature: &Option<String>| match signature {
+            Some(signature) => Cell::Link {
+                text: signature.clone(),
+                url: explorer_tx_url(signature, cluster),
+            },
+            None => Cell::Text("-".to_string()),
+        };
+        let account_link = |address: &str| Cell::Link {
+            text: address.to_string(),
+            url: explorer_account_url(address, cluster),
+        };
+
+        let mut summary = vec![
+            (text("钱包", "Wallet"), account_link(wallet)),
+            (text("集群", "Cluster"), Cell::Text(cluster.to_string())),
+            (
+                text("成功", "Succeeded"),
+                Cell::Text(accounts(report.succeeded)),
+            ),
+            (text("失败", "Failed"), Cell::Text(accounts(report.failed))),
+        ];
+        if report.already_closed > 0 {
+            summary.push((
+                text("已被关闭（跳过）", "Already closed (skipped)"),
+                Cell::Text(accounts(report.already_closed)),
+            ));
+        }
+        summary.push((
+            text("回收租金", "Rent recovered"),
+            Cell::Text(format!("{} SOL", report.rent_recovered_sol)),
+        ));
+        if report.unwrapped_lamports > 0 {
+            summary.push((
+                text("解包 wSOL", "Unwrapped wSOL"),
+                Cell::Text(format!("{} SOL", report.unwrapped_sol)),
+            ));
+        }
+        if !report.simulated {
+            summary.push((
+                text("交易费用", "Fees"),
+                Cell::Text(format!("{} SOL", sol(report.fees_lamports))),
+            ));
+            summary.push((
+                text("钱包余额", "Wallet balance"),
+                Cell::Text(format!(
+                    "{} SOL → {} SOL",
+                    sol(report.balance_before),
+                    sol(report.balance_after)
+                )),
+            ));
+        }
+
+        let processed = report
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.success && !outcome.already_closed)
+            .map(|outcome| {
+                vec![
+                    account_link(&outcome.account_address),
+                    Cell::Text(outcome.symbol.clone()),
+                    Cell::Text(sol(outcome.rent_lamports).to_string()),
+                    tx_link(&outcome.signature),
+                ]
+            })
+            .collect();
+        let failures = report
+            .failures()
+            .map(|outcome| {
+                vec![
+                    account_link(&outcome.account_address),
+                    Cell::Text(outcome.symbol.clone()),
+                    Cell::Text(
+                        outcome
+                            .failure_kind
+                            .map_or("-", |kind| kind.label(locale))
+                            .to_string(),
+                    ),
+                    Cell::Text(
+                        outcome
+                            .error
+                            .clone()
+                            .unwrap_or_else(|| text("未知错误", "Unknown error").to_string()),
+                    ),
+                ]
+            })
+            .collect();
+        let already_closed = report
+            .outcomes
+            .iter()
+            .filter(|outcome| outcome.already_closed)
+            .map(|outcome| {
+                vec![
+                    account_link(&outcome.account_address),
+                    Cell::Text(outcome.symbol.clone()),
+                ]
+            })
+            .collect();
+        let transactions = report
+            .signatures
+            .iter()
+            .map(|signature| vec![tx_link(&Some(signature.clone()))])
+            .collect();
+
+        Self {
+            title: title(operation, report, locale),
+            summary,
+            tables: vec![
+                Table {
+                    title: text("已处理账户", "Processed accounts"),
+                    headers: match locale {
+                        Locale::Zh => &["账户", "代币", "回收租金 (SOL)", "交易"],
+                        Locale::En => &["Account", "Token", "Rent recovered (SOL)", "Transaction"],
+                    },
+                    rows: processed,
+                },
+                Table {
+                    title: text("失败账户", "Failed accounts"),
+                    headers: match locale {
+                        Locale::Zh => &["账户", "代币", "原因", "错误信息"],
+                        Locale::En => &["Account", "Token", "Reason", "Error"],
+                    },
+                    rows: failures,
+                },
+                Table {
+                    title: text("已被关闭的账户", "Already closed accounts"),
+                    headers: match locale {
+                        Locale::Zh => &["账户", "代币"],
+                        Locale::En => &["Account", "Token"],
+                    },
+                    rows: already_closed,
+                },
+                Table {
+                    title: text("交易", "Transactions"),
+                    headers: match locale {
+                        Locale::Zh => &["签名"],
+                        Locale::En => &["Signature"],
+                    },
+                    rows: transactions,
+                },
+            ],
+            locale,
+        }
+    }
+}
+
+/// -- 将批量处理报告渲染为 Markdown
+///
+/// 包含统计摘要，以及已处理账户、失败账户、已被关闭账户和交易签名的表格，
+/// 账户和交易均链接到 Solana Explorer；没有内容的表格不输出。
+///
+/// # 参数
+/// * `wallet` - 钱包地址
+/// * `operation` - 操作类型
+/// * `report` - 批量处理报告
+/// * `cluster` - 报告所属的集群，决定 Explorer 链接
+/// * `locale` - 报告语言
+pub fn render_markdown(
+    wallet: &str,
+    operation: RunOperation,
+    report: &BatchCloseReport,
+    cluster: &Cluster,
+    locale: Locale,
+) -> String {
+    let content = ReportContent::new(wallet, operation, report, cluster, locale);
+    let cell = |cell: &Cell| match cell {
+        Cell::Text(text) => escape_markdown(text),
+        Cell::Link { text, url } => format!("[{}]({})", escape_markdown(text), url),
+    };
+
+    let mut output = format!("# {}\n\n", content.title);
+    for (label, value) in &content.summary {
+        let _ = writeln!(output, "- {}: {}", label, cell(value));
+    }
+    for table in content.tables.iter().filter(|table| !table.rows.is_empty()) {
+        let _ = write!(
+            output,
+            "\n## {}\n\n| {} |\n|{}\n",
+            table.title,
+            table.headers.join(" | "),
+            " --- |".repeat(table.headers.len())
+        );
+        for row in &table.rows {
+            let cells: Vec<String> = row.iter().map(cell).collect();
+            let _ = writeln!(output, "| {} |", cells.join(" | "));
+        }
+    }
+    output
+}
+
+/// -- 将批量处理报告渲染为单文件 HTML，内容与 [`render_markdown`] 相同
+///
+/// # 参数
+/// * `wallet` - 钱包地址
+/// * `operation` - 操作类型
+/// * `report` - 批量处理报告
+/// * `cluster` - 报告所属的集群，决定 Explorer 链接
+/// * `locale` - 报告语言
+pub fn render_html(
+    wallet: &str,
+    operation: RunOperation,
+    report: &BatchCloseReport,
+    cluster: &Cluster,
+    locale: Locale,
+) -> String {
+    let content = ReportContent::new(wallet, operation, report, cluster, locale);
+    let cell = |cell: &Cell| match cell {
+        Cell::Text(text) => escape_html(text),
+        Cell::Link { text, url } => {
+            format!("<a href=\"{}\">{}</a>", escape_html(url), escape_html(text))
+        }
+    };
+
+    let title = escape_html(&content.title);
+    let mut output = format!(
+        "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         <style>body{{font-family:sans-serif;margin:2em}}table{{border-collapse:collapse}}\
+         th,td{{border:1px solid #ccc;padding:4px 8px;text-align:left}}</style>\n\
+         </head>\n<body>\n<h1>{}</h1>\n<ul>\n",
+        content.locale, title, title
+    );
+    for (label, value) in &content.summary {
+        let _ = writeln!(output, "<li>{}: {}</li>", label, cell(value));
+    }
+    output.push_str("</ul>\n");
+    for table in content.tables.iter().filter(|table| !table.rows.is_empty()) {
+        let _ = writeln!(output, "<h2>{}</h2>\n<table>\n<tr>", table.title);
+        for header in table.headers {
+            let _ = write!(output, "<th>{}</th>", header);
+        }
+        output.push_str("</tr>\n");
+        for row in &table.rows {
+            output.push_str("<tr>");
+            for value in row {
+                let _ = write!(output, "<td>{}</td>", cell(value));
+            }
+            output.push_str("</tr>\n");
+        }
+        output.push_str("</table>\n");
+    }
+    output.push_str("</body>\n</html>\n");
+    output
+}
+
+/// -- 将批量处理报告渲染为可读报告并写入文件，格式由扩展名决定（见 [`ReportFormat::from_path`]）
+///
+/// # 参数
+/// * `path` - 报告文件路径，已存在时覆盖
+/// * `wallet` - 钱包地址
+/// * `operation` - 操作类型
+/// * `report` - 批量处理报告
+/// * `cluster` - 报告所属的集群，决定 Explorer 链接
+/// * `locale` - 报告语言
+pub fn write_run_report(
+    path: impl AsRef<Path>,
+    wallet: &str,
+    operation: RunOperation,
+    report: &BatchCloseReport,
+    cluster: &Cluster,
+    locale: Locale,
+) -> TokenAccountResult<()> {
+    let path = path.as_ref();
+    let rendered = match ReportFormat::from_path(path) {
+        ReportFormat::Markdown => render_markdown(wallet, operation, report, cluster, locale),
+        ReportFormat::Html => render_html(wallet, operation, report, cluster, locale),
+    };
+    fs::write(path, rendered)?;
+    Ok(())
+}
+
+/// -- 转义 Markdown 表格中的特殊字符，代币符号来自链上元数据，可能包含表格分隔符或 HTML
+fn escape_markdown(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace(['[', ']'], "")
+        .replace('\n', " ")
+}
+
+/// -- 转义 HTML 特殊字符
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 将批量处理结果写入配置中 `run_report_path` 指定的可读报告
+    ///
+    /// 未配置时不生成；模拟执行同样生成，标题带 `[模拟]` 前缀。报告使用当前界面语言，
+    /// 写入失败只输出警告，不影响处理结果。
+    pub(crate) fn save_run_report(&self, operation: RunOperation, report: &BatchCloseReport) {
+        let Some(path) = &self.config.run_report_path else {
+            return;
+        };
+
+        let wallet = self.wallet.pubkey().to_string();
+        let cluster = self.config.cluster();
+        match write_run_report(path, &wallet, operation, report, &cluster, current_locale()) {
+            Ok(()) => info!("已生成运行报告: {}", path.display()),
+            Err(e) => warn!("生成运行报告 {} 失败: {}", path.display(), e),
+        }
+    }
+}
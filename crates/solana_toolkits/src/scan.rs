@@ -0,0 +1,350 @@
+use crate::{
+    account_info::{ScanItem, TokenAccountInfo, ZeroValueTokenInfo},
+    filter::is_associated_address,
+    metadata::{fetch_token_infos, TokenMetadata},
+    operations::{is_native_mint, TOKEN_PROGRAM_IDS},
+    spam::SuspectedSpamInfo,
+    TokenAccountManager,
+};
+use futures::stream::{self, Stream, StreamExt};
+use solana_account_decoder::UiAccountData;
+use solana_client::{rpc_request::TokenAccountsFilter, rpc_response::RpcKeyedAccount};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signer::Signer};
+use std::collections::{HashMap, VecDeque};
+use tracing::{info, warn};
+use utils::{RpcApi, TokenAccountResult};
+
+/// -- 流式扫描默认每页处理的账户数量
+pub const DEFAULT_SCAN_PAGE_SIZE: usize = 100;
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 流式扫描钱包的代币账户
+    ///
+    /// 与 [`get_closeable_accounts`](Self::get_closeable_accounts) 的分类规则和 `scan_filter` 过滤条件相同，
+    /// 但按页查询元数据并逐个返回分类结果，不需要等待全部账户处理完，也不会一次性为全部 Mint 发起元数据查询。
+    /// 每页的元数据查询并发数和速率仍受 `metadata_concurrency`、`metadata_requests_per_second` 限制。
+    ///
+    /// # 参数
+    /// * `page_size` - 每页处理的账户数量
+    ///
+    /// # 返回
+    /// * `impl Stream<Item = TokenAccountResult<ScanItem>>` - 账户分类结果，查询账户列表失败时返回一个错误后结束
+    ///
+    /// # 示例
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use solana_toolkits::{account_info::ScanItem, TokenAccountManager};
+    ///
+    /// async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let manager = TokenAccountManager::new("wallet.json")?;
+    ///     let mut items = Box::pin(manager.scan_closeable_accounts(100));
+    ///     while let Some(item) = items.next().await {
+    ///         if let ScanItem::Closeable(account) = item? {
+    ///             println!("可关闭: {}", account.address);
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn scan_closeable_accounts(
+        &self,
+        page_size: usize,
+    ) -> impl Stream<Item = TokenAccountResult<ScanItem>> + '_ {
+        let page_size = page_size.max(1);
+        stream::unfold(
+            None,
+            move |pending: Option<VecDeque<RpcKeyedAccount>>| async move {
+                let mut pending = match pending {
+                    Some(pending) => pending,
+                    None => match self.fetch_token_accounts() {
+                        Ok(accounts) => {
+                            info!(
+                                "共 {} 个代币账户，每页处理 {} 个",
+                                accounts.len(),
+                                page_size
+                            );
+                            VecDeque::from(accounts)
+                        }
+                        Err(e) => return Some((vec![Err(e)], Some(VecDeque::new()))),
+                    },
+                };
+                if pending.is_empty() {
+                    return None;
+                }
+
+                let count = page_size.min(pending.len());
+                let page: Vec<RpcKeyedAccount> = pending.drain(..count).collect();
+                let items = self.classify_accounts(&page).into_iter().map(Ok).collect();
+                Some((items, Some(pending)))
+            },
+        )
+        .flat_map(stream::iter)
+    }
+
+    /// -- 查询钱包在 SPL Token 和 Token-2022 下的全部代币账户
+    pub(crate) fn fetch_token_accounts(&self) -> TokenAccountResult<Vec<RpcKeyedAccount>> {
+        let mut accounts = Vec::new();
+        for program_id in TOKEN_PROGRAM_IDS {
+            let program_accounts = self.connection.get_token_accounts_by_owner(
+                &self.wallet.pubkey(),
+                TokenAccountsFilter::ProgramId(program_id),
+            )?;
+            accounts.extend(program_accounts);
+        }
+        Ok(accounts)
+    }
+
+    /// -- 并发查询一组账户的代币元数据并逐个分类
+    ///
+    /// 只返回满足 `scan_filter` 的账户。
+    pub(crate) fn classify_accounts(&self, accounts: &[RpcKeyedAccount]) -> Vec<ScanItem> {
+        let filter = &self.config.scan_filter;
+        // -- 先收集所有 Mint，并发查询元数据
+        let mints: Vec<String> = accounts
+            .iter()
+            .filter_map(|account| match &account.account.data {
+                UiAccountData::Json(parsed_data) => parsed_data
+                    .parsed
+                    .get("info")
+                    .and_then(|info| info.get("mint"))
+                    .and_then(|mint| mint.as_str())
+                    .map(str::to_string),
+                _ => None,
+            })
+            .filter(|mint| filter.allows_mint(mint))
+            .collect();
+        let token_infos = fetch_token_infos(
+            &self.connection,
+            &self.metadata_cache,
+            &mints,
+            self.config.metadata_concurrency,
+            self.config.metadata_requests_per_second,
+        );
+
+        let owner = self.wallet.pubkey();
+        let items: Vec<ScanItem> = accounts
+            .iter()
+            .filter_map(|account| self.classify_account(account, &token_infos))
+            .filter(|item| filter.matches(&self.connection, &owner, item))
+            .collect();
+        if !filter.is_empty() {
+            info!("扫描过滤条件排除了 {} 个账户", accounts.len() - items.len());
+        }
+        items
+    }
+
+    /// -- 根据余额、元数据、黑白名单和垃圾代币规则对单个账户分类，无法解析的账户返回 None
+    fn classify_account(
+        &self,
+        account: &RpcKeyedAccount,
+        token_infos: &HashMap<String, Option<TokenMetadata>>,
+    ) -> Option<ScanItem> {
+        let UiAccountData::Json(parsed_data) = &account.account.data else {
+            return None;
+        };
+        let info = parsed_data.parsed.get("info")?;
+        let mint_str = info.get("mint")?.to_string();
+        let clean_mint = mint_str.trim_matches('"');
+
+        // -- 获取代币信息
+        let token_info = token_infos.get(clean_mint).and_then(Option::as_ref);
+
+        let token_amount = info.get("tokenAmount")?;
+        let amount = token_amount
+            .get("amount")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<u64>().ok())
+            .unwrap_or(0);
+        // -- jsonParsed 账户自带 mint 的小数位数，元数据缺失时以此为准
+        let parsed_decimals = token_amount
+            .get("decimals")
+            .and_then(|v| v.as_u64())
+            .and_then(|d| u8::try_from(d).ok());
+        let withheld_amount = withheld_amount(info);
+
+        // -- wSOL 账户的 lamports 包含包装的 SOL，租金需扣除余额
+        let native = is_native_mint(clean_mint);
+        let rent_lamports = if native {
+            account.account.lamports.saturating_sub(amount)
+        } else {
+            account.account.lamports
+        };
+        let program_id = account.account.owner.clone();
+        let is_associated = is_associated_address(
+            &self.wallet.pubkey(),
+            &account.pubkey,
+            clean_mint,
+            &program_id,
+        );
+        let rent_sol = rent_lamports as f64 / LAMPORTS_PER_SOL as f64;
+        let mint = clean_mint.to_string();
+        let symbol = token_info
+            .map(|metadata| metadata.symbol.clone())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let frozen = info.get("state").and_then(|state| state.as_str()) == Some("frozen");
+        if frozen {
+            // -- 冻结账户无法关闭或销毁，单独归类且不计入可回收租金
+            warn!("跳过冻结账户: {} ({})", account.pubkey, symbol);
+            return Some(ScanItem::Frozen(TokenAccountInfo {
+                address: account.pubkey.to_string(),
+                mint,
+                rent_lamports,
+                rent_sol,
+                symbol,
+                program_id,
+                is_associated,
+                withheld_amount,
+            }));
+        }
+
+        let close_authority = info
+            .get("closeAuthority")
+            .and_then(|authority| authority.as_str());
+        if close_authority.is_some_and(|authority| authority != self.wallet.pubkey().to_string()) {
+            // -- 关闭权限属于其他地址的账户钱包无法关闭，单独归类且不计入可回收租金
+            warn!(
+                "跳过关闭权限不属于钱包的账户: {} ({})",
+                account.pubkey, symbol
+            );
+            return Some(ScanItem::NoAuthority(TokenAccountInfo {
+                address: account.pubkey.to_string(),
+                mint,
+                rent_lamports,
+                rent_sol,
+                symbol,
+                program_id,
+                is_associated,
+                withheld_amount,
+            }));
+        }
+
+        if amount == 0 {
+            // -- 余额为 0 的账户
+            return Some(ScanItem::Closeable(TokenAccountInfo {
+                address: account.pubkey.to_string(),
+                mint,
+                rent_lamports,
+                rent_sol,
+                symbol,
+                program_id,
+                is_associated,
+                withheld_amount,
+            }));
+        }
+
+        if native {
+            // -- 有余额的 wSOL 账户关闭即可解包，不作为零值代币销毁
+            return Some(ScanItem::WrappedSol(ZeroValueTokenInfo {
+                address: account.pubkey.to_string(),
+                mint,
+                balance: amount,
+                decimals: spl_token::native_mint::DECIMALS,
+                is_nft: false,
+                rent_lamports,
+                rent_sol,
+                symbol,
+                program_id,
+                is_associated,
+                decimals_verified: true,
+            }));
+        }
+
+        let blacklisted = self.is_token_blacklisted(&mint);
+        let Some(metadata) = token_info else {
+            if !blacklisted {
+                return Some(ScanItem::Unknown(TokenAccountInfo {
+                    address: account.pubkey.to_string(),
+                    mint,
+                    rent_lamports,
+                    rent_sol,
+                    symbol,
+                    program_id,
+                    is_associated,
+                    withheld_amount,
+                }));
+            }
+            // -- 黑名单代币即使查询不到元数据也会销毁，小数位数取自解析后的代币账户，
+            // -- 缺失时记为未确认，销毁时改用链上精度
+            warn!("黑名单代币（无元数据） - Mint: {}", mint);
+            return Some(ScanItem::ZeroValue(ZeroValueTokenInfo {
+                address: account.pubkey.to_string(),
+                mint,
+                balance: amount,
+                decimals: parsed_decimals.unwrap_or(0),
+                is_nft: false,
+                rent_lamports,
+                rent_sol,
+                symbol,
+                program_id,
+                is_associated,
+                decimals_verified: parsed_decimals.is_some(),
+            }));
+        };
+
+        // -- 检查是否为零值代币，NFT 和白名单代币单独归类，黑名单代币不受保护
+        let whitelisted = self.is_token_whitelisted(&symbol, &mint);
+        let zero_value = ZeroValueTokenInfo {
+            address: account.pubkey.to_string(),
+            mint,
+            balance: amount,
+            decimals: metadata.decimals,
+            is_nft: metadata.is_nft,
+            rent_lamports,
+            rent_sol,
+            symbol,
+            program_id,
+            is_associated,
+            decimals_verified: true,
+        };
+
+        let signals = match &self.config.spam_heuristics {
+            Some(heuristics) if !blacklisted && !whitelisted => heuristics.signals(metadata),
+            _ => Vec::new(),
+        };
+
+        if blacklisted {
+            warn!(
+                "黑名单代币 - Symbol: {}, Mint: {}",
+                zero_value.symbol, zero_value.mint
+            );
+            Some(ScanItem::ZeroValue(zero_value))
+        } else if !signals.is_empty() {
+            warn!(
+                "疑似垃圾代币 - Symbol: {}, Mint: {}, 依据: {:?}",
+                zero_value.symbol, zero_value.mint, signals
+            );
+            Some(ScanItem::SuspectedSpam(SuspectedSpamInfo {
+                account: zero_value,
+                signals,
+            }))
+        } else if metadata.is_nft && !self.config.burn_nfts {
+            info!(
+                "跳过 NFT - Symbol: {}, Mint: {}",
+                zero_value.symbol, zero_value.mint
+            );
+            Some(ScanItem::Nft(zero_value))
+        } else if whitelisted {
+            info!(
+                "跳过白名单代币 - Symbol: {}, Mint: {}",
+                zero_value.symbol, zero_value.mint
+            );
+            Some(ScanItem::Whitelisted(zero_value))
+        } else {
+            Some(ScanItem::ZeroValue(zero_value))
+        }
+    }
+}
+
+/// -- 读取 jsonParsed 账户中 Token-2022 暂扣的转账手续费，没有转账手续费扩展时为 0
+fn withheld_amount(info: &serde_json::Value) -> u64 {
+    info.get("extensions")
+        .and_then(|extensions| extensions.as_array())
+        .into_iter()
+        .flatten()
+        .find(|extension| {
+            extension.get("extension").and_then(|name| name.as_str()) == Some("transferFeeAmount")
+        })
+        .and_then(|extension| extension.get("state")?.get("withheldAmount")?.as_u64())
+        .unwrap_or(0)
+}
@@ -0,0 +1,159 @@
+use crate::{account_info::ZeroValueTokenInfo, metadata::TokenMetadata, TokenAccountManager};
+use serde::{Deserialize, Serialize};
+use solana_sdk::signer::Signer;
+use tracing::warn;
+use utils::RpcApi;
+
+/// -- 默认的钓鱼域名关键词
+///
+/// 空投诈骗代币的元数据 URI 通常指向包含这些词的域名，引导用户连接钱包"领取"奖励。
+pub const DEFAULT_PHISHING_KEYWORDS: &[&str] = &[
+    "claim",
+    "airdrop",
+    "reward",
+    "giveaway",
+    "bonus",
+    "free-mint",
+    "drop-",
+];
+
+/// -- 可疑代币的判定依据
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpamSignal {
+    /// Mint 仍保留冻结权限，发行方可以随时冻结持有者的账户
+    FreezeAuthority(String),
+    /// 元数据可修改且 URI 指向已知的钓鱼域名
+    PhishingUri(String),
+    /// 查询不到价格，代币没有市场流动性
+    NoLiquidity,
+}
+
+/// -- 疑似垃圾或蜜罐代币的账户
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspectedSpamInfo {
+    #[serde(flatten)]
+    pub account: ZeroValueTokenInfo, // -- 账户信息
+    pub signals: Vec<SpamSignal>, // -- 命中的判定依据
+}
+
+/// -- 垃圾代币识别规则
+///
+/// 白名单代币不会被标记，黑名单代币仍按零值代币处理。
+/// 流动性检查需要查询价格，只在 `get_closeable_accounts` 中进行，流式扫描只使用冻结权限和 URI 规则。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpamHeuristics {
+    pub flag_freeze_authority: bool,    // -- Mint 保留冻结权限时标记
+    pub phishing_domains: Vec<String>,  // -- 已知钓鱼域名（包括其子域名）
+    pub phishing_keywords: Vec<String>, // -- 域名中出现即视为钓鱼的关键词
+    pub check_liquidity: bool,          // -- 查询不到价格的零值代币标记为无流动性
+}
+
+impl Default for SpamHeuristics {
+    fn default() -> Self {
+        Self {
+            flag_freeze_authority: true,
+            phishing_domains: Vec::new(),
+            phishing_keywords: DEFAULT_PHISHING_KEYWORDS
+                .iter()
+                .map(|keyword| keyword.to_string())
+                .collect(),
+            check_liquidity: true,
+        }
+    }
+}
+
+impl SpamHeuristics {
+    /// -- 根据代币元数据判断是否可疑，返回命中的判定依据
+    ///
+    /// 不可修改的元数据 URI 无法再被替换为钓鱼链接，因此只检查可修改的元数据。
+    pub fn signals(&self, metadata: &TokenMetadata) -> Vec<SpamSignal> {
+        let mut signals = Vec::new();
+        if self.flag_freeze_authority {
+            if let Some(authority) = &metadata.freeze_authority {
+                signals.push(SpamSignal::FreezeAuthority(authority.clone()));
+            }
+        }
+        if metadata.is_mutable && self.is_phishing_uri(&metadata.uri) {
+            signals.push(SpamSignal::PhishingUri(metadata.uri.clone()));
+        }
+        signals
+    }
+
+    /// -- URI 的域名是否为已知钓鱼域名或包含钓鱼关键词
+    pub fn is_phishing_uri(&self, uri: &str) -> bool {
+        let Some(host) = uri_host(uri) else {
+            return false;
+        };
+        self.phishing_domains.iter().any(|domain| {
+            let domain = domain.trim().to_ascii_lowercase();
+            !domain.is_empty() && (host == domain || host.ends_with(&format!(".{}", domain)))
+        }) || self.phishing_keywords.iter().any(|keyword| {
+            let keyword = keyword.trim().to_ascii_lowercase();
+            !keyword.is_empty() && host.contains(&keyword)
+        })
+    }
+}
+
+/// -- 提取 URI 中的域名（小写，不含端口），没有域名时返回 None
+fn uri_host(uri: &str) -> Option<String> {
+    let (_, rest) = uri.trim().split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host = authority.rsplit('@').next()?.split(':').next()?;
+    (!host.is_empty()).then(|| host.to_ascii_lowercase())
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 将查询不到价格的零值代币移入疑似垃圾代币列表
+    ///
+    /// 黑名单代币仍留在零值代币列表中，已被标记的账户追加 `NoLiquidity` 依据。
+    /// 价格查询失败时无法判断流动性，保持原有分类。
+    pub(crate) async fn flag_illiquid_accounts(
+        &self,
+        zero_value_accounts: &mut Vec<ZeroValueTokenInfo>,
+        suspected_spam_accounts: &mut Vec<SuspectedSpamInfo>,
+    ) {
+        let candidates: Vec<ZeroValueTokenInfo> = zero_value_accounts
+            .iter()
+            .filter(|account| !self.is_token_blacklisted(&account.mint))
+            .cloned()
+            .chain(
+                suspected_spam_accounts
+                    .iter()
+                    .map(|spam| spam.account.clone()),
+            )
+            .collect();
+        if candidates.is_empty() {
+            return;
+        }
+
+        let values = match self.usd_values(&candidates).await {
+            Ok(values) => values,
+            Err(e) => {
+                warn!("{}，跳过流动性检查", e);
+                return;
+            }
+        };
+
+        for spam in suspected_spam_accounts.iter_mut() {
+            if !values.contains_key(&spam.account.address) {
+                spam.signals.push(SpamSignal::NoLiquidity);
+            }
+        }
+
+        let (illiquid, liquid): (Vec<_>, Vec<_>) =
+            zero_value_accounts.drain(..).partition(|account| {
+                !values.contains_key(&account.address) && !self.is_token_blacklisted(&account.mint)
+            });
+        *zero_value_accounts = liquid;
+        for account in illiquid {
+            warn!(
+                "疑似垃圾代币（无流动性） - Symbol: {}, Mint: {}",
+                account.symbol, account.mint
+            );
+            suspected_spam_accounts.push(SuspectedSpamInfo {
+                account,
+                signals: vec![SpamSignal::NoLiquidity],
+            });
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use crate::{
+    account_info::{TokenAccountInfo, ZeroValueTokenInfo},
+    operations::{
+        burn_and_close_instructions, close_instructions, submit_with_fresh_blockhash,
+        with_compute_budget, BurnTarget,
+    },
+    TokenAccountManager,
+};
+use serde::Serialize;
+use solana_sdk::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey,
+    pubkey::Pubkey,
+    signer::Signer,
+    system_program,
+    transaction::Transaction,
+};
+use tracing::info;
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- Squads v4 多签程序 ID
+pub const SQUADS_PROGRAM_ID: Pubkey = pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
+
+/// -- 多签账户中 `transaction_index` 字段的偏移量
+/// （discriminator 8 + create_key 32 + config_authority 32 + threshold 2 + time_lock 4）
+const MULTISIG_TRANSACTION_INDEX_OFFSET: usize = 78;
+
+/// -- 已创建的多签提案
+#[derive(Debug, Clone, Serialize)]
+pub struct SquadsProposal {
+    pub transaction_index: u64,    // -- 多签交易序号
+    pub transaction: String,       // -- 金库交易账户地址
+    pub proposal: String,          // -- 提案账户地址
+    pub accounts: Vec<String>,     // -- 提案中关闭的代币账户
+    pub rent_lamports: u64,        // -- 提案执行后回收的租金（lamports）
+    pub signature: Option<String>, // -- 创建提案的交易签名（模拟模式下为 None）
+}
+
+/// -- 多签金库地址
+///
+/// # 参数
+/// * `multisig` - 多签账户地址
+/// * `vault_index` - 金库序号，默认金库为 0
+pub fn vault_address(multisig: &Pubkey, vault_index: u8) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"multisig", multisig.as_ref(), b"vault", &[vault_index]],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// -- 金库交易账户地址
+pub fn transaction_address(multisig: &Pubkey, transaction_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"multisig",
+            multisig.as_ref(),
+            b"transaction",
+            &transaction_index.to_le_bytes(),
+        ],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// -- 提案账户地址
+pub fn proposal_address(multisig: &Pubkey, transaction_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"multisig",
+            multisig.as_ref(),
+            b"transaction",
+            &transaction_index.to_le_bytes(),
+            b"proposal",
+        ],
+        &SQUADS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// -- 将金库需要执行的指令编码为 Squads 的 `TransactionMessage`
+///
+/// Squads 使用紧凑格式：数组长度为 u8，指令数据长度为 u16，不使用地址查找表。
+///
+/// # 参数
+/// * `vault` - 金库地址，作为交易的签名者和手续费支付者
+/// * `instructions` - 金库执行的指令
+pub fn vault_transaction_message(vault: &Pubkey, instructions: &[Instruction]) -> Vec<u8> {
+    let message = Message::new(instructions, Some(vault));
+    let header = message.header;
+    let num_signers = header.num_required_signatures;
+    let num_writable_signers = num_signers - header.num_readonly_signed_accounts;
+    let num_writable_non_signers =
+        (message.account_keys.len() as u8 - num_signers) - header.num_readonly_unsigned_accounts;
+
+    let mut data = vec![num_signers, num_writable_signers, num_writable_non_signers];
+    data.push(message.account_keys.len() as u8);
+    for key in &message.account_keys {
+        data.extend_from_slice(key.as_ref());
+    }
+    data.push(message.instructions.len() as u8);
+    for instruction in &message.instructions {
+        data.push(instruction.program_id_index);
+        data.push(instruction.accounts.len() as u8);
+        data.extend_from_slice(&instruction.accounts);
+        data.extend_from_slice(&(instruction.data.len() as u16).to_le_bytes());
+        data.extend_from_slice(&instruction.data);
+    }
+    // -- address_table_lookups
+    data.push(0);
+    data
+}
+
+/// -- Anchor 指令标识：`sha256("global:<name>")` 的前 8 字节
+fn discriminator(name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// -- 创建金库交易的指令
+///
+/// # 参数
+/// * `multisig` - 多签账户地址
+/// * `creator` - 发起人（需要是具有发起权限的多签成员），同时支付账户租金
+/// * `transaction_index` - 新交易的序号（多签当前序号 + 1）
+/// * `vault_index` - 金库序号
+/// * `transaction_message` - [`vault_transaction_message`] 编码的金库消息
+/// * `memo` - 备注
+pub fn vault_transaction_create_instruction(
+    multisig: &Pubkey,
+    creator: &Pubkey,
+    transaction_index: u64,
+    vault_index: u8,
+    transaction_message: Vec<u8>,
+    memo: Option<&str>,
+) -> Instruction {
+    let mut data = discriminator("vault_transaction_create").to_vec();
+    data.push(vault_index);
+    // -- ephemeral_signers
+    data.push(0);
+    data.extend_from_slice(&(transaction_message.len() as u32).to_le_bytes());
+    data.extend_from_slice(&transaction_message);
+    match memo {
+        Some(memo) => {
+            data.push(1);
+            data.extend_from_slice(&(memo.len() as u32).to_le_bytes());
+            data.extend_from_slice(memo.as_bytes());
+        }
+        None => data.push(0),
+    }
+
+    Instruction::new_with_bytes(
+        SQUADS_PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(transaction_address(multisig, transaction_index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    )
+}
+
+/// -- 为金库交易创建提案的指令
+///
+/// # 参数
+/// * `multisig` - 多签账户地址
+/// * `creator` - 发起人，同时支付账户租金
+/// * `transaction_index` - 金库交易的序号
+pub fn proposal_create_instruction(
+    multisig: &Pubkey,
+    creator: &Pubkey,
+    transaction_index: u64,
+) -> Instruction {
+    let mut data = discriminator("proposal_create").to_vec();
+    data.extend_from_slice(&transaction_index.to_le_bytes());
+    // -- draft: false，创建后立即进入投票
+    data.push(0);
+
+    Instruction::new_with_bytes(
+        SQUADS_PROGRAM_ID,
+        &data,
+        vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(proposal_address(multisig, transaction_index), false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+    )
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 为多签金库中的代币账户创建关闭提案
+    ///
+    /// 关闭指令不直接发送，而是包装为 Squads v4 金库交易和提案，由多签成员在 Squads 中投票批准后执行，
+    /// 租金退回金库。管理器钱包作为提案发起人，需要是具有发起权限的多签成员。
+    /// 金库中的账户可以用钱包为 `NullSigner::new(&vault_address(..))` 的管理器扫描。
+    ///
+    /// # 参数
+    /// * `multisig` - 多签账户地址
+    /// * `vault_index` - 金库序号，默认金库为 0
+    /// * `accounts` - 金库中要关闭的账户列表
+    /// * `batch_size` - 每个提案关闭的账户数量
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Vec<SquadsProposal>>` - 创建的提案，按序号排列
+    pub fn propose_close_accounts(
+        &self,
+        multisig: &Pubkey,
+        vault_index: u8,
+        accounts: &[TokenAccountInfo],
+        batch_size: usize,
+    ) -> TokenAccountResult<Vec<SquadsProposal>> {
+        let vault = vault_address(multisig, vault_index);
+        let batches = accounts
+            .chunks(batch_size.max(1))
+            .map(|chunk| {
+                Ok(ProposalBatch {
+                    instructions: close_instructions(&vault, chunk)?,
+                    accounts: chunk
+                        .iter()
+                        .map(|account| account.address.clone())
+                        .collect(),
+                    rent_lamports: chunk.iter().map(|account| account.rent_lamports).sum(),
+                })
+            })
+            .collect::<TokenAccountResult<Vec<_>>>()?;
+
+        self.create_proposals(multisig, vault_index, batches, "关闭代币账户")
+    }
+
+    /// -- 为多签金库中的零值代币账户创建销毁并关闭提案
+    ///
+    /// 与 [`propose_close_accounts`](Self::propose_close_accounts) 相同，每个账户先销毁剩余代币再关闭。
+    ///
+    /// # 参数
+    /// * `multisig` - 多签账户地址
+    /// * `vault_index` - 金库序号，默认金库为 0
+    /// * `accounts` - 金库中要销毁并关闭的零值账户列表
+    /// * `batch_size` - 每个提案处理的账户数量
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Vec<SquadsProposal>>` - 创建的提案，按序号排列
+    pub fn propose_burn_and_close(
+        &self,
+        multisig: &Pubkey,
+        vault_index: u8,
+        accounts: &[ZeroValueTokenInfo],
+        batch_size: usize,
+    ) -> TokenAccountResult<Vec<SquadsProposal>> {
+        let vault = vault_address(multisig, vault_index);
+        let batches = accounts
+            .chunks(batch_size.max(1))
+            .map(|chunk| {
+                let mut instructions = Vec::new();
+                for account in chunk {
+                    let target = BurnTarget::resolve(
+                        &self.connection,
+                        &account.address,
+                        &account.mint,
+                        &account.program_id,
+                        account.balance,
+                        account.expected_decimals(),
+                    )?;
+                    instructions.extend(burn_and_close_instructions(&vault, &target)?);
+                }
+                Ok(ProposalBatch {
+                    instructions,
+                    accounts: chunk
+                        .iter()
+                        .map(|account| account.address.clone())
+                        .collect(),
+                    rent_lamports: chunk.iter().map(|account| account.rent_lamports).sum(),
+                })
+            })
+            .collect::<TokenAccountResult<Vec<_>>>()?;
+
+        self.create_proposals(multisig, vault_index, batches, "销毁并关闭代币账户")
+    }
+
+    /// -- 读取多签当前的交易序号，为每批指令依次创建金库交易和提案
+    fn create_proposals(
+        &self,
+        multisig: &Pubkey,
+        vault_index: u8,
+        batches: Vec<ProposalBatch>,
+        memo: &str,
+    ) -> TokenAccountResult<Vec<SquadsProposal>> {
+        let vault = vault_address(multisig, vault_index);
+        let data = self.connection.get_account_data(multisig)?;
+        let mut transaction_index = data
+            .get(MULTISIG_TRANSACTION_INDEX_OFFSET..MULTISIG_TRANSACTION_INDEX_OFFSET + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or_else(|| {
+                TokenAccountError::AccountParseError(format!("无效的多签账户: {}", multisig))
+            })?;
+        info!("多签: {}，金库: {}", multisig, vault);
+
+        let creator = self.wallet.pubkey();
+        let mut proposals = Vec::new();
+        for batch in batches {
+            transaction_index += 1;
+            let instructions = vec![
+                vault_transaction_create_instruction(
+                    multisig,
+                    &creator,
+                    transaction_index,
+                    vault_index,
+                    vault_transaction_message(&vault, &batch.instructions),
+                    Some(memo),
+                ),
+                proposal_create_instruction(multisig, &creator, transaction_index),
+            ];
+            let instructions = with_compute_budget(&self.connection, &self.config, instructions);
+            let submission =
+                submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+                    Ok(Transaction::new_signed_with_payer(
+                        &instructions,
+                        Some(&creator),
+                        &[&self.wallet],
+                        blockhash,
+                    ))
+                })?;
+            info!(
+                "已创建多签提案 #{}（{} 个账户），交易签名: {}",
+                transaction_index,
+                batch.accounts.len(),
+                submission.signature.as_deref().unwrap_or("-")
+            );
+
+            proposals.push(SquadsProposal {
+                transaction_index,
+                transaction: transaction_address(multisig, transaction_index).to_string(),
+                proposal: proposal_address(multisig, transaction_index).to_string(),
+                accounts: batch.accounts,
+                rent_lamports: batch.rent_lamports,
+                signature: submission.signature,
+            });
+        }
+
+        Ok(proposals)
+    }
+}
+
+/// -- 一个提案中金库要执行的指令
+struct ProposalBatch {
+    instructions: Vec<Instruction>, // -- 金库执行的指令
+    accounts: Vec<String>,          // -- 涉及的代币账户
+    rent_lamports: u64,             // -- 回收的租金（lamports）
+}
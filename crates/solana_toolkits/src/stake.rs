@@ -0,0 +1,273 @@
+use crate::operations::{payer_and_signers, submit_with_fresh_blockhash, with_compute_budget};
+use crate::TokenAccountManager;
+use serde::Serialize;
+use solana_client::{
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    account::Account,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signer::Signer,
+    stake::{self, instruction as stake_instruction},
+    transaction::Transaction,
+};
+use stake_toolkits::StakeError;
+use tracing::{info, warn};
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+pub use stake_toolkits::account_info::{StakeAccountInfo, StakeStatus};
+
+/// -- 质押账户中 withdrawer 字段的偏移量
+///
+/// StakeStateV2 布局: enum tag(4) + rent_exempt_reserve(8) + staker(32) + withdrawer(32)
+const STAKE_WITHDRAWER_OFFSET: usize = 4 + 8 + 32;
+
+/// -- 质押账户扫描结果
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StakeScanResult {
+    pub accounts: Vec<StakeAccountInfo>, // -- 以钱包为 withdrawer 的全部质押账户
+    pub inactive_accounts: usize,        // -- 已取消激活或未委托的账户数量
+    pub deactivating_accounts: usize,    // -- 取消激活中的账户数量
+    pub withdrawable_lamports: u64,      // -- 当前可提取的 lamports
+    pub withdrawable_sol: f64,           // -- 当前可提取的 SOL
+    pub pending_lamports: u64,           // -- 取消激活中、之后可提取的 lamports
+}
+
+/// -- 钱包可回收的 SOL 汇总
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoverableSol {
+    pub token_rent_lamports: u64,  // -- 关闭代币账户可回收的租金
+    pub open_orders_lamports: u64, // -- 关闭空的 open orders 账户可回收的租金
+    pub stake_lamports: u64,       // -- 质押账户当前可提取的 lamports
+    pub pending_lamports: u64,     // -- 取消激活中、之后可提取的 lamports
+    pub total_lamports: u64,       // -- 当前可回收的总量（租金 + 可提取质押）
+    pub total_sol: f64,            // -- 当前可回收的总量（SOL）
+}
+
+/// -- 质押操作结果
+#[derive(Debug, Clone, Serialize)]
+pub struct StakeOperationResult {
+    pub stake_account: String,     // -- 质押账户地址
+    pub lamports: u64,             // -- 提取的 lamports，取消激活时为委托数量
+    pub signature: Option<String>, // -- 交易签名，模拟模式下为 None
+    pub simulated: bool,           // -- 是否为模拟执行（未发送交易）
+}
+
+/// -- 解析质押账户，解析逻辑与状态计算复用 `stake_toolkits`
+fn parse_stake_account(
+    pubkey: &Pubkey,
+    account: &Account,
+    current_epoch: u64,
+) -> TokenAccountResult<StakeAccountInfo> {
+    stake_toolkits::parse_stake_account(pubkey, account, current_epoch).map_err(|e| match e {
+        StakeError::AccountParseError(msg) => TokenAccountError::AccountParseError(msg),
+        StakeError::InvalidState(msg) => {
+            TokenAccountError::AccountParseError(format!("{}: {}", msg, pubkey))
+        }
+        e => TokenAccountError::AccountParseError(e.to_string()),
+    })
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 扫描以钱包为 withdrawer 的质押账户
+    ///
+    /// 已取消激活（`Inactive`）和未委托（`Initialized`）的账户可以直接提取全部余额，
+    /// 取消激活中的账户在当前 epoch 结束后可提取，计入 `pending_lamports`。
+    /// 无法解析的账户只输出警告，不影响其他账户。
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<StakeScanResult>` - 质押账户列表和可提取数量统计
+    pub async fn scan_stake_accounts(&self) -> TokenAccountResult<StakeScanResult> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                STAKE_WITHDRAWER_OFFSET,
+                self.wallet.pubkey().as_ref(),
+            ))]),
+            account_config: RpcAccountInfoConfig::default(),
+            ..RpcProgramAccountsConfig::default()
+        };
+        let accounts = self
+            .connection
+            .get_program_accounts_with_config(&stake::program::id(), config)?;
+        let current_epoch = self.connection.get_epoch_info()?.epoch;
+
+        let mut result = StakeScanResult::default();
+        for (pubkey, account) in accounts {
+            let info = match parse_stake_account(&pubkey, &account, current_epoch) {
+                Ok(info) => info,
+                Err(e) => {
+                    warn!("解析质押账户失败: {}, {}", pubkey, e);
+                    continue;
+                }
+            };
+            match info.status {
+                StakeStatus::Initialized | StakeStatus::Inactive => result.inactive_accounts += 1,
+                StakeStatus::Deactivating => {
+                    result.deactivating_accounts += 1;
+                    result.pending_lamports += info.lamports - info.withdrawable_lamports();
+                }
+                StakeStatus::Activating | StakeStatus::Active => {}
+            }
+            result.withdrawable_lamports += info.withdrawable_lamports();
+            result.accounts.push(info);
+        }
+        result.withdrawable_sol = result.withdrawable_lamports as f64 / LAMPORTS_PER_SOL as f64;
+
+        info!(
+            "质押账户: {} 个，可提取 {} SOL（已取消激活 {} 个，取消激活中 {} 个）",
+            result.accounts.len(),
+            result.withdrawable_sol,
+            result.inactive_accounts,
+            result.deactivating_accounts
+        );
+        Ok(result)
+    }
+
+    /// -- 汇总钱包当前可回收的全部 SOL
+    ///
+    /// 包括关闭代币账户可回收的租金（同 [`get_closeable_accounts`](Self::get_closeable_accounts)）、
+    /// 可关闭的 open orders 账户租金和质押账户当前可提取的余额。
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<RecoverableSol>` - 各来源的可回收数量
+    pub async fn get_recoverable_sol(&self) -> TokenAccountResult<RecoverableSol> {
+        let token_rent_lamports = self.get_closeable_accounts().await?.total_rent_lamports;
+        let open_orders_lamports = self
+            .get_open_orders_accounts()
+            .await?
+            .iter()
+            .filter(|info| info.closeable)
+            .map(|info| info.lamports)
+            .sum::<u64>();
+        let stake = self.scan_stake_accounts().await?;
+        let total_lamports =
+            token_rent_lamports + open_orders_lamports + stake.withdrawable_lamports;
+
+        Ok(RecoverableSol {
+            token_rent_lamports,
+            open_orders_lamports,
+            stake_lamports: stake.withdrawable_lamports,
+            pending_lamports: stake.pending_lamports,
+            total_lamports,
+            total_sol: total_lamports as f64 / LAMPORTS_PER_SOL as f64,
+        })
+    }
+
+    /// -- 取消激活质押账户，当前 epoch 结束后即可提取
+    ///
+    /// 钱包必须是该账户的 staker，账户必须处于激活中或已激活状态。
+    ///
+    /// # 参数
+    /// * `stake_account` - 质押账户地址
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<StakeOperationResult>` - 成功返回交易签名
+    pub async fn deactivate_stake(
+        &self,
+        stake_account: &Pubkey,
+    ) -> TokenAccountResult<StakeOperationResult> {
+        let owner = self.wallet.pubkey();
+        let info = self.load_stake_account(stake_account)?;
+        if info.staker != owner.to_string() {
+            return Err(TokenAccountError::Other(format!(
+                "钱包不是质押账户 {} 的 staker: {}",
+                stake_account, info.staker
+            )));
+        }
+        if !matches!(info.status, StakeStatus::Activating | StakeStatus::Active) {
+            return Err(TokenAccountError::Other(format!(
+                "质押账户 {} 当前状态为 {:?}，无需取消激活",
+                stake_account, info.status
+            )));
+        }
+
+        let instruction = stake_instruction::deactivate_stake(stake_account, &owner);
+        let signature = self.submit_stake_instruction(instruction)?;
+        info!("已取消激活质押账户: {}", stake_account);
+
+        Ok(StakeOperationResult {
+            stake_account: stake_account.to_string(),
+            lamports: info.delegated_stake,
+            signature,
+            simulated: self.config.dry_run,
+        })
+    }
+
+    /// -- 从质押账户提取 SOL 到钱包
+    ///
+    /// 钱包必须是该账户的 withdrawer。提取全部余额后账户被关闭，租金一并返还。
+    ///
+    /// # 参数
+    /// * `stake_account` - 质押账户地址
+    /// * `lamports` - 提取数量，`None` 表示提取当前全部可提取余额
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<StakeOperationResult>` - 成功返回提取数量和交易签名
+    pub async fn withdraw_stake(
+        &self,
+        stake_account: &Pubkey,
+        lamports: Option<u64>,
+    ) -> TokenAccountResult<StakeOperationResult> {
+        let owner = self.wallet.pubkey();
+        let info = self.load_stake_account(stake_account)?;
+        if info.withdrawer != owner.to_string() {
+            return Err(TokenAccountError::Other(format!(
+                "钱包不是质押账户 {} 的 withdrawer: {}",
+                stake_account, info.withdrawer
+            )));
+        }
+
+        let withdrawable_lamports = info.withdrawable_lamports();
+        let amount = lamports.unwrap_or(withdrawable_lamports);
+        if amount == 0 || amount > withdrawable_lamports {
+            return Err(TokenAccountError::Other(format!(
+                "质押账户 {} 可提取 {} lamports，请求提取 {} lamports",
+                stake_account, withdrawable_lamports, amount
+            )));
+        }
+
+        let instruction = stake_instruction::withdraw(stake_account, &owner, &owner, amount, None);
+        let signature = self.submit_stake_instruction(instruction)?;
+        info!(
+            "已从质押账户 {} 提取 {} SOL",
+            stake_account,
+            amount as f64 / LAMPORTS_PER_SOL as f64
+        );
+
+        Ok(StakeOperationResult {
+            stake_account: stake_account.to_string(),
+            lamports: amount,
+            signature,
+            simulated: self.config.dry_run,
+        })
+    }
+
+    /// -- 查询并解析单个质押账户
+    fn load_stake_account(&self, stake_account: &Pubkey) -> TokenAccountResult<StakeAccountInfo> {
+        let account = self.connection.get_account(stake_account)?;
+        let current_epoch = self.connection.get_epoch_info()?.epoch;
+        parse_stake_account(stake_account, &account, current_epoch)
+    }
+
+    /// -- 签名并提交质押指令，模拟模式下只模拟执行
+    fn submit_stake_instruction(
+        &self,
+        instruction: Instruction,
+    ) -> TokenAccountResult<Option<String>> {
+        let instructions = with_compute_budget(&self.connection, &self.config, vec![instruction]);
+        let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+        let submission =
+            submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+                Ok(Transaction::new_signed_with_payer(
+                    &instructions,
+                    Some(&payer),
+                    &signers,
+                    blockhash,
+                ))
+            })?;
+        Ok(submission.signature)
+    }
+}
@@ -0,0 +1,273 @@
+use crate::account_info::{
+    AccountOutcome, BatchCloseReport, FailureKind, SweepAndCloseResult, ZeroValueTokenInfo,
+};
+use crate::history::RunOperation;
+use crate::observer::notify_outcome;
+use crate::operations::{
+    payer_and_signers, submit_with_fresh_blockhash, with_compute_budget, Submission,
+};
+use crate::{failed_outcomes, TokenAccountManager};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signer::Signer, transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token_2022::{
+    extension::StateWithExtensions,
+    instruction::{close_account, transfer_checked},
+    state::Mint,
+};
+use std::str::FromStr;
+use tracing::{error, info, warn};
+use utils::{address_book::address_label, RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 单个账户转出并关闭的执行结果
+struct SweepOutcome {
+    submission: Submission, // -- 交易提交结果
+    destination: Pubkey,    // -- 接收代币的目标 ATA
+    swept_amount: u64,      // -- 转出的代币数量
+    rent_lamports: u64,     // -- 回收的租金
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 转出剩余代币并关闭账户
+    ///
+    /// 将账户中的全部代币转入目标钱包的 ATA（不存在时自动创建），然后关闭账户回收租金。
+    /// 创建 ATA、转账和关闭在同一笔交易中完成。
+    ///
+    /// # 参数
+    /// * `account_pubkey` - 要操作的账户公钥
+    /// * `destination` - 接收代币的目标钱包地址
+    ///
+    /// # 返回
+    /// * `SweepAndCloseResult` - 包含操作结果的详细信息
+    pub async fn sweep_and_close_account(
+        &self,
+        account_pubkey: &Pubkey,
+        destination: &Pubkey,
+    ) -> SweepAndCloseResult {
+        match self
+            .sweep_and_close_internal(account_pubkey, destination)
+            .await
+        {
+            Ok(outcome) => SweepAndCloseResult {
+                success: true,
+                signature: outcome.submission.signature,
+                error: None,
+                account_address: account_pubkey.to_string(),
+                destination_account: Some(outcome.destination.to_string()),
+                swept_amount: outcome.swept_amount,
+                rent_recovered: outcome.rent_lamports as f64 / LAMPORTS_PER_SOL as f64,
+                simulated: self.config.dry_run,
+                failure_kind: None,
+                simulation: Some(outcome.submission.simulation),
+            },
+            Err(e) => SweepAndCloseResult {
+                success: false,
+                signature: None,
+                error: Some(e.to_string()),
+                account_address: account_pubkey.to_string(),
+                destination_account: None,
+                swept_amount: 0,
+                rent_recovered: 0.0,
+                simulated: self.config.dry_run,
+                failure_kind: Some(FailureKind::from(&e)),
+                simulation: None,
+            },
+        }
+    }
+
+    /// -- 批量转出剩余代币并关闭账户
+    ///
+    /// 与批量销毁相对：剩余代币归集到目标钱包而不是销毁。
+    ///
+    /// # 参数
+    /// * `accounts` - 要处理的代币账户列表（通常为扫描得到的零值代币账户）
+    /// * `destination` - 接收代币的目标钱包地址
+    /// * `batch_size` - 每批处理的账户数量
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<BatchCloseReport>` - 每个账户的处理结果和统计信息
+    pub async fn batch_sweep_and_close(
+        &self,
+        accounts: &[ZeroValueTokenInfo],
+        destination: &Pubkey,
+        batch_size: usize,
+    ) -> TokenAccountResult<BatchCloseReport> {
+        let mut report = BatchCloseReport {
+            simulated: self.config.dry_run,
+            ..BatchCloseReport::default()
+        };
+
+        if accounts.is_empty() {
+            warn!("没有找到需要转出的代币账户");
+            return Ok(report);
+        }
+
+        info!("代币转入钱包: {}", address_label(&destination.to_string()));
+        // -- 每个账户一笔交易：最多创建目标关联账户、转出代币并关闭三条指令
+        report.estimated_fees_lamports = self.check_fee_budget(&vec![3; accounts.len()])?;
+        let balance_before = self.tracked_balance()?;
+
+        let batch_count = accounts.len().div_ceil(batch_size.max(1));
+        for (i, chunk) in accounts.chunks(batch_size.max(1)).enumerate() {
+            if let Err(e) = self.check_wallet_reserve(&vec![3; chunk.len()]) {
+                error!("{}，停止处理剩余账户", e);
+                for outcome in failed_outcomes(&accounts[i * batch_size.max(1)..], &e) {
+                    notify_outcome(self.observer.as_ref(), &outcome);
+                    report.outcomes.push(outcome);
+                }
+                break;
+            }
+
+            info!("\n处理第 {} 批, 共 {} 个账户", i + 1, chunk.len());
+            self.observer
+                .on_batch_start(i + 1, batch_count, chunk.len());
+
+            for account in chunk {
+                let result = match Pubkey::from_str(&account.address) {
+                    Ok(pubkey) => self.sweep_and_close_internal(&pubkey, destination).await,
+                    Err(e) => Err(TokenAccountError::AccountParseError(e.to_string())),
+                };
+
+                let outcome = match result {
+                    Ok(outcome) => {
+                        info!("成功转出并关闭账户: {}", account.address);
+                        info!("代币 Symbol: {}", account.symbol);
+                        info!("转出数量: {}", outcome.swept_amount);
+                        match &outcome.submission.signature {
+                            Some(signature) => info!("交易签名: {}", signature),
+                            None => info!("[模拟] 未发送交易"),
+                        }
+
+                        AccountOutcome {
+                            account_address: account.address.clone(),
+                            mint: account.mint.clone(),
+                            symbol: account.symbol.clone(),
+                            success: true,
+                            signature: outcome.submission.signature,
+                            burn_signature: None,
+                            burned_amount: 0,
+                            swept_amount: outcome.swept_amount,
+                            swap: None,
+                            unwrapped_lamports: 0,
+                            rent_lamports: outcome.rent_lamports,
+                            error: None,
+                            failure_kind: None,
+                            simulation: Some(outcome.submission.simulation),
+                            already_closed: false,
+                        }
+                    }
+                    Err(e) => {
+                        error!("处理失败: {}", account.address);
+                        error!("错误信息: {}", e);
+                        AccountOutcome::failed(&account.address, &account.mint, &account.symbol, &e)
+                    }
+                };
+                notify_outcome(self.observer.as_ref(), &outcome);
+                report.outcomes.push(outcome);
+            }
+
+            // -- 批次间延时
+            if i + 1 < batch_count && !self.config.dry_run {
+                tokio::time::sleep(self.config.batch_delay).await;
+            }
+        }
+
+        let balance_after = if self.config.dry_run {
+            balance_before
+        } else {
+            self.tracked_balance()?
+        };
+        report.finalize(balance_before, balance_after);
+        self.log_batch_report(&report);
+        self.observer.on_complete(&report);
+        self.record_history(RunOperation::Sweep, &report);
+        self.save_run_report(RunOperation::Sweep, &report);
+        self.send_notifications(&report).await;
+
+        Ok(report)
+    }
+
+    /// -- 构建并提交转出并关闭交易
+    async fn sweep_and_close_internal(
+        &self,
+        account_pubkey: &Pubkey,
+        destination: &Pubkey,
+    ) -> TokenAccountResult<SweepOutcome> {
+        let details = self.get_account_details(account_pubkey).await?;
+        details.ensure_not_frozen()?;
+        details.ensure_closable_by(&self.wallet.pubkey())?;
+        let parse = |value: &str| {
+            Pubkey::from_str(value).map_err(|e| TokenAccountError::AccountParseError(e.to_string()))
+        };
+        let mint = parse(&details.mint)?;
+        let program_id = parse(&details.program_id)?;
+        let owner = self.wallet.pubkey();
+        // -- 设置了独立的手续费支付者时，由它支付交易费用和目标 ATA 的租金
+        let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+
+        let destination_ata =
+            get_associated_token_address_with_program_id(destination, &mint, &program_id);
+        if destination_ata == *account_pubkey {
+            return Err(TokenAccountError::AccountParseError(
+                "目标 ATA 与待关闭账户相同".to_string(),
+            ));
+        }
+
+        let mut instructions = Vec::new();
+        if details.balance > 0 {
+            instructions.push(create_associated_token_account_idempotent(
+                &payer,
+                destination,
+                &mint,
+                &program_id,
+            ));
+            instructions.push(transfer_checked(
+                &program_id,
+                account_pubkey,
+                &mint,
+                &destination_ata,
+                &owner,
+                &[],
+                details.balance,
+                self.mint_decimals(&mint)?,
+            )?);
+        }
+        instructions.push(close_account(
+            &program_id,
+            account_pubkey,
+            &owner,
+            &owner,
+            &[],
+        )?);
+
+        let instructions = with_compute_budget(&self.connection, &self.config, instructions);
+        let submission =
+            submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+                Ok(Transaction::new_signed_with_payer(
+                    &instructions,
+                    Some(&payer),
+                    &signers,
+                    blockhash,
+                ))
+            })?;
+
+        Ok(SweepOutcome {
+            submission,
+            destination: destination_ata,
+            swept_amount: details.balance,
+            rent_lamports: details.rent_lamports,
+        })
+    }
+
+    /// -- 查询 Mint 的小数位数
+    fn mint_decimals(&self, mint: &Pubkey) -> TokenAccountResult<u8> {
+        let data = self.connection.get_account_data(mint)?;
+        let state = StateWithExtensions::<Mint>::unpack(&data)
+            .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?;
+        Ok(state.base.decimals)
+    }
+}
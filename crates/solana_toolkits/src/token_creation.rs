@@ -0,0 +1,211 @@
+use crate::TokenAccountManager;
+use mpl_token_metadata::{
+    accounts::Metadata, instructions::CreateMetadataAccountV3Builder, types::DataV2,
+};
+use solana_sdk::{
+    program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer, system_instruction,
+    transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token_2022::{
+    instruction::{initialize_mint2, mint_to_checked, set_authority, AuthorityType},
+    state::Mint,
+};
+use tracing::info;
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 代币程序类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TokenProgram {
+    /// 标准 SPL Token 程序
+    #[default]
+    Spl,
+    /// Token-2022 程序
+    Token2022,
+}
+
+impl TokenProgram {
+    /// -- 获取程序 ID
+    pub fn id(&self) -> Pubkey {
+        match self {
+            TokenProgram::Spl => spl_token::id(),
+            TokenProgram::Token2022 => spl_token_2022::id(),
+        }
+    }
+}
+
+/// -- 创建代币参数
+#[derive(Debug, Clone)]
+pub struct CreateTokenParams {
+    pub name: String,                  // -- 代币名称
+    pub symbol: String,                // -- 代币符号
+    pub uri: String,                   // -- 元数据 URI
+    pub decimals: u8,                  // -- 代币精度
+    pub initial_supply: u64,           // -- 初始供应量（最小单位）
+    pub token_program: TokenProgram,   // -- 使用的代币程序
+    pub revoke_mint_authority: bool,   // -- 铸造后是否撤销铸币权限
+    pub revoke_freeze_authority: bool, // -- 是否撤销冻结权限
+}
+
+impl Default for CreateTokenParams {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            symbol: String::new(),
+            uri: String::new(),
+            decimals: 9,
+            initial_supply: 0,
+            token_program: TokenProgram::Spl,
+            revoke_mint_authority: false,
+            revoke_freeze_authority: false,
+        }
+    }
+}
+
+/// -- 代币创建结果
+#[derive(Debug, Clone)]
+pub struct CreatedToken {
+    pub mint: String,          // -- Mint 地址
+    pub metadata: String,      // -- Metaplex 元数据账户地址
+    pub token_account: String, // -- 接收初始供应量的 ATA 地址
+    pub token_program: String, // -- 代币程序 ID
+    pub signature: String,     // -- 交易签名
+    pub initial_supply: u64,   // -- 初始供应量（最小单位）
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 创建新的代币
+    ///
+    /// 在一笔交易中创建 Mint、写入 Metaplex 元数据、为钱包创建 ATA 并铸造初始供应量，
+    /// 可选撤销铸币和冻结权限。主要用于在 devnet 上为监控和工具测试准备代币。
+    ///
+    /// # 参数
+    /// * `params` - 创建代币参数
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<CreatedToken>` - 成功返回所有新建地址，失败返回错误
+    pub async fn create_token(
+        &self,
+        params: &CreateTokenParams,
+    ) -> TokenAccountResult<CreatedToken> {
+        let payer = self.wallet.pubkey();
+        let mint = Keypair::new();
+        let mint_pubkey = mint.pubkey();
+        let program_id = params.token_program.id();
+
+        let rent = self
+            .connection
+            .get_minimum_balance_for_rent_exemption(Mint::LEN)?;
+
+        let (metadata, _) = Metadata::find_pda(&mint_pubkey);
+        let token_account =
+            get_associated_token_address_with_program_id(&payer, &mint_pubkey, &program_id);
+
+        let mut instructions = vec![
+            system_instruction::create_account(
+                &payer,
+                &mint_pubkey,
+                rent,
+                Mint::LEN as u64,
+                &program_id,
+            ),
+            initialize_mint2(
+                &program_id,
+                &mint_pubkey,
+                &payer,
+                Some(&payer),
+                params.decimals,
+            )?,
+            CreateMetadataAccountV3Builder::new()
+                .metadata(metadata)
+                .mint(mint_pubkey)
+                .mint_authority(payer)
+                .payer(payer)
+                .update_authority(payer, true)
+                .data(DataV2 {
+                    name: params.name.clone(),
+                    symbol: params.symbol.clone(),
+                    uri: params.uri.clone(),
+                    seller_fee_basis_points: 0,
+                    creators: None,
+                    collection: None,
+                    uses: None,
+                })
+                .is_mutable(true)
+                .instruction(),
+        ];
+
+        if params.initial_supply > 0 {
+            instructions.push(create_associated_token_account_idempotent(
+                &payer,
+                &payer,
+                &mint_pubkey,
+                &program_id,
+            ));
+            instructions.push(mint_to_checked(
+                &program_id,
+                &mint_pubkey,
+                &token_account,
+                &payer,
+                &[],
+                params.initial_supply,
+                params.decimals,
+            )?);
+        }
+
+        if params.revoke_mint_authority {
+            instructions.push(set_authority(
+                &program_id,
+                &mint_pubkey,
+                None,
+                AuthorityType::MintTokens,
+                &payer,
+                &[],
+            )?);
+        }
+
+        if params.revoke_freeze_authority {
+            instructions.push(set_authority(
+                &program_id,
+                &mint_pubkey,
+                None,
+                AuthorityType::FreezeAccount,
+                &payer,
+                &[],
+            )?);
+        }
+
+        let transaction = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&payer),
+            &[&self.wallet as &dyn Signer, &mint],
+            self.connection.get_latest_blockhash()?,
+        );
+
+        let signature = self
+            .connection
+            .send_and_confirm_transaction(&transaction)
+            .map_err(|e| TokenAccountError::TransactionError(e.to_string()))?;
+
+        info!("代币创建成功: {} ({})", params.symbol, mint_pubkey);
+        info!("元数据账户: {}", metadata);
+        if params.initial_supply > 0 {
+            info!(
+                "初始供应量 {} 已铸造到: {}",
+                params.initial_supply, token_account
+            );
+        }
+
+        Ok(CreatedToken {
+            mint: mint_pubkey.to_string(),
+            metadata: metadata.to_string(),
+            token_account: token_account.to_string(),
+            token_program: program_id.to_string(),
+            signature: signature.to_string(),
+            initial_supply: params.initial_supply,
+        })
+    }
+}
@@ -0,0 +1,152 @@
+use crate::operations::{
+    is_token_program, payer_and_signers, submit_with_fresh_blockhash, with_compute_budget,
+};
+use crate::{parse_account_details, TokenAccountManager};
+use serde::Serialize;
+use solana_sdk::{pubkey::Pubkey, signer::Signer, transaction::Transaction};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    instruction::create_associated_token_account_idempotent,
+};
+use spl_token::error::TokenError;
+use spl_token_2022::{extension::StateWithExtensions, instruction::transfer_checked, state::Mint};
+use tracing::info;
+use utils::{address_book::address_label, RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 代币转账结果
+#[derive(Debug, Clone, Serialize)]
+pub struct TokenTransferResult {
+    pub mint: String,                // -- 代币的 Mint 地址
+    pub source: String,              // -- 转出的代币账户（钱包的 ATA）
+    pub destination: String,         // -- 收款钱包地址
+    pub destination_account: String, // -- 收款钱包的 ATA 地址
+    pub amount: u64,                 // -- 转账数量（最小单位）
+    pub decimals: u8,                // -- Mint 的链上精度
+    pub ata_created: bool,           // -- 是否为收款钱包创建了 ATA
+    pub signature: Option<String>,   // -- 交易签名，模拟模式下为 None
+    pub simulated: bool,             // -- 是否为模拟执行（未发送交易）
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 从钱包的 ATA 向另一个钱包转账代币
+    ///
+    /// 根据收款钱包推导其 ATA，不存在时在同一笔交易中创建（租金由手续费支付者支付），
+    /// 使用 `transfer_checked` 转账，链上会再次校验精度。代币程序按 Mint 的所有者自动选择。
+    /// 转出账户余额不足时返回 [`TokenError::InsufficientFunds`]，不发送交易。
+    ///
+    /// # 参数
+    /// * `mint` - 代币的 Mint 地址
+    /// * `destination` - 收款钱包地址（不是代币账户地址）
+    /// * `amount` - 转账数量（最小单位）
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<TokenTransferResult>` - 成功返回转账结果，失败返回错误
+    pub async fn transfer_tokens(
+        &self,
+        mint: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> TokenAccountResult<TokenTransferResult> {
+        if amount == 0 {
+            return Err(TokenAccountError::Other(String::from("转账数量必须大于 0")));
+        }
+
+        let mint_account = self.connection.get_account(mint)?;
+        let program_id = mint_account.owner;
+        if !is_token_program(&program_id) {
+            return Err(TokenAccountError::AccountParseError(format!(
+                "{} 不是代币 Mint，所有者为 {}",
+                mint, program_id
+            )));
+        }
+        let decimals = StateWithExtensions::<Mint>::unpack(&mint_account.data)
+            .map_err(|e| TokenAccountError::AccountParseError(e.to_string()))?
+            .base
+            .decimals;
+
+        let owner = self.wallet.pubkey();
+        let source = get_associated_token_address_with_program_id(&owner, mint, &program_id);
+        let destination_account =
+            get_associated_token_address_with_program_id(destination, mint, &program_id);
+        if source == destination_account {
+            return Err(TokenAccountError::AccountParseError(
+                "收款 ATA 与转出账户相同".to_string(),
+            ));
+        }
+
+        // -- 一次查询转出账户和收款 ATA
+        let accounts = self
+            .connection
+            .get_multiple_accounts(&[source, destination_account])?;
+        let source_details = match &accounts[0] {
+            Some(account) => parse_account_details(&source, account)?,
+            None => {
+                return Err(TokenAccountError::AccountParseError(format!(
+                    "账户不存在: {}",
+                    source
+                )))
+            }
+        };
+        source_details.ensure_not_frozen()?;
+        if source_details.balance < amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+        let ata_created = !matches!(accounts.get(1), Some(Some(_)));
+
+        let mut instructions = Vec::new();
+        if ata_created {
+            instructions.push(create_associated_token_account_idempotent(
+                &self.fee_payer_pubkey(),
+                destination,
+                mint,
+                &program_id,
+            ));
+        }
+        instructions.push(transfer_checked(
+            &program_id,
+            &source,
+            mint,
+            &destination_account,
+            &owner,
+            &[],
+            amount,
+            decimals,
+        )?);
+
+        let instructions = with_compute_budget(&self.connection, &self.config, instructions);
+        let (payer, signers) = payer_and_signers(self.fee_payer(), &self.wallet);
+        let submission =
+            submit_with_fresh_blockhash(&self.connection, &self.config, |blockhash| {
+                Ok(Transaction::new_signed_with_payer(
+                    &instructions,
+                    Some(&payer),
+                    &signers,
+                    blockhash,
+                ))
+            })?;
+
+        info!(
+            "已转账 {} (Mint: {}) 到 {}{}",
+            amount,
+            mint,
+            address_label(&destination.to_string()),
+            if ata_created {
+                "，并创建了 ATA"
+            } else {
+                ""
+            }
+        );
+
+        Ok(TokenTransferResult {
+            mint: mint.to_string(),
+            source: source.to_string(),
+            destination: destination.to_string(),
+            destination_account: destination_account.to_string(),
+            amount,
+            decimals,
+            ata_created,
+            signature: submission.signature,
+            simulated: self.config.dry_run,
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use crate::account_info::ZeroValueTokenInfo;
+use crate::operations::is_native_mint;
+use crate::TokenAccountManager;
+use jupiter_swap::price::{JupiterPriceClient, PriceSource};
+use solana_sdk::signer::Signer;
+use std::collections::HashMap;
+use tracing::{info, warn};
+use utils::{RpcApi, TokenAccountError, TokenAccountResult};
+
+/// -- 根据余额、小数位数和单价计算 USD 价值
+///
+/// # 参数
+/// * `balance` - 代币余额（最小单位）
+/// * `decimals` - 代币小数位数
+/// * `price` - 代币 USD 单价
+pub fn usd_value(balance: u64, decimals: u8, price: f64) -> f64 {
+    balance as f64 / 10f64.powi(decimals as i32) * price
+}
+
+impl<R: RpcApi, S: Signer> TokenAccountManager<R, S> {
+    /// -- 查询零值代币账户的 USD 价值
+    ///
+    /// 查询不到价格的代币不会出现在结果中，通常说明该代币已没有市场。
+    ///
+    /// # 参数
+    /// * `accounts` - 零值代币账户列表
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<HashMap<String, f64>>` - 以账户地址为键的 USD 价值
+    pub async fn usd_values(
+        &self,
+        accounts: &[ZeroValueTokenInfo],
+    ) -> TokenAccountResult<HashMap<String, f64>> {
+        let mut mints: Vec<String> = accounts.iter().map(|a| a.mint.clone()).collect();
+        mints.sort();
+        mints.dedup();
+
+        let prices = JupiterPriceClient::new(&self.config.price_api_url)
+            .get_prices(&mints)
+            .await
+            .map_err(|e| TokenAccountError::Other(format!("查询代币价格失败: {}", e)))?;
+
+        Ok(accounts
+            .iter()
+            .filter_map(|account| {
+                prices.get(&account.mint).map(|price| {
+                    (
+                        account.address.clone(),
+                        usd_value(account.balance, account.decimals, *price),
+                    )
+                })
+            })
+            .collect())
+    }
+
+    /// -- 排除价值超过销毁上限的账户
+    ///
+    /// 未配置 `max_usd_value_to_burn` 时原样返回。价格查询失败时返回错误而不是放行，
+    /// 避免在无法确认价值的情况下销毁代币。wSOL 账户只会解包，不受上限限制。
+    pub(crate) async fn exclude_valuable_accounts(
+        &self,
+        accounts: &[ZeroValueTokenInfo],
+    ) -> TokenAccountResult<Vec<ZeroValueTokenInfo>> {
+        let Some(max_usd_value) = self.config.max_usd_value_to_burn else {
+            return Ok(accounts.to_vec());
+        };
+
+        let values = self.usd_values(accounts).await?;
+        let mut burnable = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            match values.get(&account.address) {
+                // -- wSOL 账户关闭即解包，不会损失价值
+                Some(_) if is_native_mint(&account.mint) => burnable.push(account.clone()),
+                Some(value) if *value > max_usd_value => {
+                    warn!(
+                        "跳过价值 ${:.2} 的代币账户（上限 ${:.2}） - Symbol: {}, 地址: {}",
+                        value, max_usd_value, account.symbol, account.address
+                    );
+                }
+                _ => burnable.push(account.clone()),
+            }
+        }
+
+        info!(
+            "价值检查: {} 个账户可销毁, {} 个账户超过上限",
+            burnable.len(),
+            accounts.len() - burnable.len()
+        );
+        Ok(burnable)
+    }
+}
@@ -1,13 +1,66 @@
-use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+use utils::{TokenAccountError, TokenAccountResult};
 
 /// -- 代币白名单管理器
 /// 用于管理不应该被关闭的代币账户的白名单
 #[derive(Debug, Default)]
 pub struct TokenWhitelist {
-    symbols: HashSet<String>, // -- 代币符号白名单
-    mints: HashSet<String>,   // -- 代币 Mint 地址白名单
-    user_added: bool,         // -- 是否已添加用户自定义白名单
-    merge_default: bool,      // -- 是否合并默认白名单
+    symbols: HashSet<String>,          // -- 代币符号白名单
+    mints: HashSet<String>,            // -- 代币 Mint 地址白名单
+    comments: HashMap<String, String>, // -- 从文件加载的备注，以符号或 Mint 地址为键
+    user_added: bool,                  // -- 是否已添加用户自定义白名单
+    merge_default: bool,               // -- 是否合并默认白名单
+}
+
+/// -- 白名单文件中的一项：纯字符串，或带备注的对象
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum WhitelistEntry {
+    Plain(String),
+    Annotated {
+        value: String,           // -- 代币符号或 Mint 地址
+        comment: Option<String>, // -- 备注
+    },
+}
+
+impl WhitelistEntry {
+    fn into_parts(self) -> (String, Option<String>) {
+        match self {
+            WhitelistEntry::Plain(value) => (value, None),
+            WhitelistEntry::Annotated { value, comment } => (value, comment),
+        }
+    }
+}
+
+/// -- 白名单文件格式
+///
+/// ```toml
+/// merge_default = true
+/// symbols = ["JUP", { value = "RAY", comment = "长期持有" }]
+/// mints = ["JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN"]
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+struct WhitelistFile {
+    #[serde(default = "default_merge_default")]
+    merge_default: bool, // -- 是否合并默认白名单，未填写时为 true
+    #[serde(default)]
+    symbols: Vec<WhitelistEntry>, // -- 代币符号
+    #[serde(default)]
+    mints: Vec<WhitelistEntry>, // -- 代币 Mint 地址
+}
+
+fn default_merge_default() -> bool {
+    true
+}
+
+/// -- 根据扩展名判断是否为 TOML 文件，其余一律按 JSON 处理
+fn is_toml(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "toml")
 }
 
 impl TokenWhitelist {
@@ -23,6 +76,7 @@ impl TokenWhitelist {
         Self {
             symbols: HashSet::new(),
             mints: HashSet::new(),
+            comments: HashMap::new(),
             user_added: false,
             merge_default: merge_default.unwrap_or(false), // -- 设置是否合并, 默认为 false
         }
@@ -76,4 +130,82 @@ impl TokenWhitelist {
     pub fn set_merge_default(&mut self, merge_default: bool) {
         self.merge_default = merge_default;
     }
+
+    /// -- 从 TOML 或 JSON 文件加载白名单
+    ///
+    /// 扩展名为 `.toml` 时按 TOML 解析，否则按 JSON 解析。每一项可以是字符串，
+    /// 也可以是带 `comment` 备注的对象，TOML 文件中还可以使用 `#` 注释。
+    ///
+    /// # 参数
+    /// * `path` - 白名单文件路径
+    ///
+    /// # 返回
+    /// * `TokenAccountResult<Self>` - 成功返回白名单，文件不存在或格式错误时返回错误
+    pub fn from_file(path: impl AsRef<Path>) -> TokenAccountResult<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)?;
+        let file: WhitelistFile = if is_toml(path) {
+            toml::from_str(&content).map_err(|e| {
+                TokenAccountError::Other(format!("解析白名单文件 {:?} 失败: {}", path, e))
+            })?
+        } else {
+            serde_json::from_str(&content)?
+        };
+
+        let mut whitelist = Self::new(Some(file.merge_default));
+        for (value, comment) in file.symbols.into_iter().map(WhitelistEntry::into_parts) {
+            let symbol = value.to_uppercase();
+            if let Some(comment) = comment {
+                whitelist.comments.insert(symbol.clone(), comment);
+            }
+            whitelist.symbols.insert(symbol);
+        }
+        for (mint, comment) in file.mints.into_iter().map(WhitelistEntry::into_parts) {
+            if let Some(comment) = comment {
+                whitelist.comments.insert(mint.clone(), comment);
+            }
+            whitelist.mints.insert(mint);
+        }
+        whitelist.user_added = !whitelist.symbols.is_empty() || !whitelist.mints.is_empty();
+
+        Ok(whitelist)
+    }
+
+    /// -- 将白名单保存到 TOML 或 JSON 文件
+    ///
+    /// 格式规则与 [`Self::from_file`] 相同，条目按字母顺序写入，已有备注会一并保存。
+    ///
+    /// # 参数
+    /// * `path` - 白名单文件路径
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> TokenAccountResult<()> {
+        let path = path.as_ref();
+        let entries = |values: &HashSet<String>| {
+            let mut values: Vec<&String> = values.iter().collect();
+            values.sort();
+            values
+                .into_iter()
+                .map(|value| match self.comments.get(value) {
+                    Some(comment) => WhitelistEntry::Annotated {
+                        value: value.clone(),
+                        comment: Some(comment.clone()),
+                    },
+                    None => WhitelistEntry::Plain(value.clone()),
+                })
+                .collect()
+        };
+        let file = WhitelistFile {
+            merge_default: self.merge_default,
+            symbols: entries(&self.symbols),
+            mints: entries(&self.mints),
+        };
+
+        let content = if is_toml(path) {
+            toml::to_string_pretty(&file)
+                .map_err(|e| TokenAccountError::Other(format!("序列化白名单失败: {}", e)))?
+        } else {
+            serde_json::to_string_pretty(&file)?
+        };
+        fs::write(path, content)?;
+        Ok(())
+    }
 }
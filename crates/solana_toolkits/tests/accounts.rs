@@ -0,0 +1,218 @@
+mod common;
+
+use common::{manager, token_account, TOKEN_ACCOUNT_RENT};
+use solana_sdk::{
+    account::Account, native_token::LAMPORTS_PER_SOL, program_pack::Pack, pubkey::Pubkey,
+    signature::Keypair, signer::Signer,
+};
+use solana_toolkits::{
+    account_info::DelegatedAccountInfo,
+    config::TokenAccountConfig,
+    open_orders::{close_open_orders_instruction, OPENBOOK_PROGRAM_ID, OPEN_ORDERS_ACCOUNT_SIZE},
+    stake::StakeStatus,
+    TokenAccountManager,
+};
+use spl_token::state::Account as TokenAccount;
+use utils::MockRpc;
+
+#[tokio::test]
+async fn get_delegated_accounts_lists_active_delegations() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let delegated = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &delegated,
+        &Pubkey::new_unique(),
+        1_000,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager
+        .connection
+        .delegate_token_account(&owner, &delegated, &delegate, 400);
+    manager.connection.add_token_account(
+        &owner,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+
+    let accounts = manager.get_delegated_accounts().await.unwrap();
+
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].address, delegated.to_string());
+    assert_eq!(accounts[0].delegate, delegate.to_string());
+    assert_eq!(accounts[0].delegated_amount, 400);
+    assert_eq!(accounts[0].balance, 1_000);
+}
+
+#[tokio::test]
+async fn revoke_delegates_batches_instructions_per_transaction() {
+    let manager = manager(MockRpc::new());
+    let accounts: Vec<DelegatedAccountInfo> = (0..3)
+        .map(|_| DelegatedAccountInfo {
+            address: Pubkey::new_unique().to_string(),
+            mint: Pubkey::new_unique().to_string(),
+            symbol: "unknown".to_string(),
+            balance: 0,
+            delegate: Pubkey::new_unique().to_string(),
+            delegated_amount: 1,
+            program_id: spl_token::id().to_string(),
+        })
+        .collect();
+
+    let outcomes = manager.revoke_delegates(&accounts, 2).await;
+
+    assert!(outcomes.iter().all(|outcome| outcome.success));
+    assert_eq!(manager.connection.sent_transactions().len(), 2);
+}
+
+#[tokio::test]
+async fn close_account_revokes_delegate_first_when_enabled() {
+    let config = TokenAccountConfig {
+        revoke_delegates: true,
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let address = Pubkey::new_unique();
+    let mut account = token_account(&manager.wallet.pubkey(), &Pubkey::new_unique(), 0);
+    let mut state = TokenAccount::unpack(&account.data).unwrap();
+    state.delegate = Some(Pubkey::new_unique()).into();
+    TokenAccount::pack(state, &mut account.data).unwrap();
+    manager.connection.add_account(address, account);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 2);
+}
+
+fn stake_account(withdrawer: &Pubkey, stake: u64, deactivation_epoch: u64) -> Account {
+    use solana_sdk::stake::{
+        stake_flags::StakeFlags,
+        state::{Authorized, Delegation, Lockup, Meta, Stake, StakeStateV2},
+    };
+
+    let reserve = 2_282_880;
+    let mut delegation = Delegation::new(&Pubkey::new_unique(), stake, 2);
+    delegation.deactivation_epoch = deactivation_epoch;
+    let state = StakeStateV2::Stake(
+        Meta {
+            rent_exempt_reserve: reserve,
+            authorized: Authorized::auto(withdrawer),
+            lockup: Lockup::default(),
+        },
+        Stake {
+            delegation,
+            credits_observed: 0,
+        },
+        StakeFlags::empty(),
+    );
+    Account {
+        lamports: stake + reserve,
+        data: bincode::serialize(&state).unwrap(),
+        owner: solana_sdk::stake::program::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn scans_and_withdraws_inactive_stake() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let inactive = Pubkey::new_unique();
+    let active = Pubkey::new_unique();
+    manager.connection.set_epoch(10);
+    manager
+        .connection
+        .add_account(inactive, stake_account(&owner, LAMPORTS_PER_SOL, 5));
+    manager.connection.add_account(
+        active,
+        stake_account(&owner, 3 * LAMPORTS_PER_SOL, u64::MAX),
+    );
+    manager.connection.add_account(
+        Pubkey::new_unique(),
+        stake_account(&Pubkey::new_unique(), LAMPORTS_PER_SOL, 5),
+    );
+
+    let scan = manager.scan_stake_accounts().await.unwrap();
+    assert_eq!(scan.accounts.len(), 2);
+    assert_eq!(scan.inactive_accounts, 1);
+    assert_eq!(scan.withdrawable_lamports, LAMPORTS_PER_SOL + 2_282_880);
+    let status = |address: &Pubkey| {
+        scan.accounts
+            .iter()
+            .find(|account| account.address == address.to_string())
+            .map(|account| account.status)
+    };
+    assert_eq!(status(&inactive), Some(StakeStatus::Inactive));
+    assert_eq!(status(&active), Some(StakeStatus::Active));
+    let recoverable = manager.get_recoverable_sol().await.unwrap();
+    assert_eq!(recoverable.stake_lamports, scan.withdrawable_lamports);
+
+    let withdrawn = manager.withdraw_stake(&inactive, None).await.unwrap();
+    assert_eq!(withdrawn.lamports, LAMPORTS_PER_SOL + 2_282_880);
+    assert!(manager.withdraw_stake(&active, None).await.is_err());
+    assert!(manager.deactivate_stake(&inactive).await.is_err());
+
+    let deactivated = manager.deactivate_stake(&active).await.unwrap();
+    assert_eq!(deactivated.lamports, 3 * LAMPORTS_PER_SOL);
+    assert_eq!(manager.connection.sent_transactions().len(), 2);
+}
+
+fn open_orders_account(owner: &Pubkey, market: &Pubkey, base_total: u64) -> Account {
+    let mut data = vec![0u8; OPEN_ORDERS_ACCOUNT_SIZE];
+    data[..5].copy_from_slice(b"serum");
+    data[5..13].copy_from_slice(&5u64.to_le_bytes());
+    data[13..45].copy_from_slice(market.as_ref());
+    data[45..77].copy_from_slice(owner.as_ref());
+    data[85..93].copy_from_slice(&base_total.to_le_bytes());
+    data[109..125].copy_from_slice(&u128::MAX.to_le_bytes());
+    data[OPEN_ORDERS_ACCOUNT_SIZE - 7..].copy_from_slice(b"padding");
+    Account {
+        lamports: 23_357_760,
+        data,
+        owner: OPENBOOK_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn closes_empty_open_orders_accounts() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let market = Pubkey::new_unique();
+    let empty = Pubkey::new_unique();
+    let funded = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(empty, open_orders_account(&owner, &market, 0));
+    manager
+        .connection
+        .add_account(funded, open_orders_account(&owner, &market, 1_000));
+    manager.connection.add_account(
+        Pubkey::new_unique(),
+        open_orders_account(&Pubkey::new_unique(), &market, 0),
+    );
+
+    let accounts = manager.get_open_orders_accounts().await.unwrap();
+    assert_eq!(accounts.len(), 2);
+    let closeable: Vec<_> = accounts.iter().filter(|info| info.closeable).collect();
+    assert_eq!(closeable.len(), 1);
+    assert_eq!(closeable[0].address, empty.to_string());
+    assert_eq!(closeable[0].market, market.to_string());
+
+    let closed = manager.close_open_orders(&empty).await.unwrap();
+    assert_eq!(closed.rent_lamports, 23_357_760);
+    assert!(manager.close_open_orders(&funded).await.is_err());
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+
+    let instruction =
+        close_open_orders_instruction(&OPENBOOK_PROGRAM_ID, &empty, &owner, &owner, &market);
+    assert_eq!(instruction.data, vec![0, 14, 0, 0, 0]);
+    assert!(instruction.accounts[1].is_signer);
+}
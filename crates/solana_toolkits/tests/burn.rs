@@ -0,0 +1,257 @@
+mod common;
+
+use common::{manager, mint_account, token_account, TOKEN_ACCOUNT_RENT};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
+    signer::Signer,
+};
+use solana_toolkits::{
+    account_info::{FailureKind, ZeroValueTokenInfo},
+    config::{DustSwapConfig, TokenAccountConfig},
+    valuation::usd_value,
+    TokenAccountManager,
+};
+use spl_token::state::Account as TokenAccount;
+use utils::MockRpc;
+
+#[tokio::test]
+async fn batch_burn_skips_nft_accounts() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let accounts = [ZeroValueTokenInfo {
+        address: Pubkey::new_unique().to_string(),
+        mint: Pubkey::new_unique().to_string(),
+        balance: 1,
+        decimals: 0,
+        is_nft: true,
+        rent_lamports: TOKEN_ACCOUNT_RENT,
+        rent_sol: TOKEN_ACCOUNT_RENT as f64 / 1e9,
+        symbol: "APE".to_string(),
+        program_id: spl_token::id().to_string(),
+        is_associated: false,
+        decimals_verified: true,
+    }];
+
+    let report = manager
+        .batch_burn_and_close_zero_value_accounts(&accounts, 5)
+        .await
+        .unwrap();
+
+    assert!(report.outcomes.is_empty());
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn dry_run_burn_and_close_burns_non_ata_accounts_when_dust_swap_enabled() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        dust_swap: Some(DustSwapConfig::default()),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager.connection.add_account(mint, mint_account(6));
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &mint, 500));
+
+    let result = manager.burn_and_close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(result.swap.is_none());
+    assert_eq!(result.burned_amount, 500);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn batch_burn_refuses_to_burn_when_prices_are_unavailable() {
+    let config = TokenAccountConfig {
+        max_usd_value_to_burn: Some(1.0),
+        price_api_url: "http://127.0.0.1:9".to_string(),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &mint, 10));
+    let accounts = [ZeroValueTokenInfo {
+        address: address.to_string(),
+        mint: mint.to_string(),
+        balance: 10,
+        decimals: 6,
+        is_nft: false,
+        rent_lamports: TOKEN_ACCOUNT_RENT,
+        rent_sol: TOKEN_ACCOUNT_RENT as f64 / 1e9,
+        symbol: "DUST".to_string(),
+        program_id: spl_token::id().to_string(),
+        is_associated: false,
+        decimals_verified: true,
+    }];
+
+    let result = manager
+        .batch_burn_and_close_zero_value_accounts(&accounts, 5)
+        .await;
+
+    assert!(result.is_err());
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn batch_burn_reports_invalid_address_instead_of_panicking() {
+    let manager = manager(MockRpc::new());
+    let accounts = [ZeroValueTokenInfo {
+        address: "not-a-pubkey".to_string(),
+        mint: Pubkey::new_unique().to_string(),
+        balance: 10,
+        decimals: 6,
+        is_nft: false,
+        rent_lamports: TOKEN_ACCOUNT_RENT,
+        rent_sol: TOKEN_ACCOUNT_RENT as f64 / 1e9,
+        symbol: "DUST".to_string(),
+        program_id: spl_token::id().to_string(),
+        is_associated: false,
+        decimals_verified: true,
+    }];
+
+    let report = manager
+        .batch_burn_and_close_zero_value_accounts(&accounts, 5)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (0, 1));
+    let failure = report.failures().next().unwrap();
+    assert_eq!(failure.account_address, "not-a-pubkey");
+    assert_eq!(failure.failure_kind, Some(FailureKind::InvalidAccount));
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[test]
+fn usd_value_accounts_for_decimals() {
+    assert_eq!(usd_value(2_500_000, 6, 0.5), 1.25);
+    assert_eq!(usd_value(0, 9, 150.0), 0.0);
+}
+
+#[tokio::test]
+async fn burn_and_close_unwraps_wrapped_sol_instead_of_burning() {
+    let manager = manager(MockRpc::new());
+    let address = Pubkey::new_unique();
+    let mut account = token_account(
+        &manager.wallet.pubkey(),
+        &spl_token::native_mint::id(),
+        5_000_000,
+    );
+    let mut state = TokenAccount::unpack(&account.data).unwrap();
+    state.is_native = Some(TOKEN_ACCOUNT_RENT).into();
+    TokenAccount::pack(state, &mut account.data).unwrap();
+    account.lamports += 5_000_000;
+    manager.connection.add_account(address, account);
+
+    let result = manager.burn_and_close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(result.burn_signature.is_none());
+    assert_eq!(result.burned_amount, 0);
+    assert_eq!(result.unwrapped_lamports, 5_000_000);
+    assert_eq!(
+        result.rent_recovered,
+        TOKEN_ACCOUNT_RENT as f64 / LAMPORTS_PER_SOL as f64
+    );
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn batch_burn_processes_blacklisted_nft_accounts() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        ..TokenAccountConfig::default()
+    };
+    let mut manager =
+        TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let mint = Pubkey::new_unique();
+    manager.add_mints_to_blacklist(&[&mint.to_string()]);
+    let accounts = [ZeroValueTokenInfo {
+        address: Pubkey::new_unique().to_string(),
+        mint: mint.to_string(),
+        balance: 1,
+        decimals: 0,
+        is_nft: true,
+        rent_lamports: TOKEN_ACCOUNT_RENT,
+        rent_sol: TOKEN_ACCOUNT_RENT as f64 / 1e9,
+        symbol: "SCAM".to_string(),
+        program_id: spl_token::id().to_string(),
+        is_associated: false,
+        decimals_verified: true,
+    }];
+
+    let report = manager
+        .batch_burn_and_close_zero_value_accounts(&accounts, 5)
+        .await
+        .unwrap();
+
+    assert_eq!(report.outcomes.len(), 1);
+}
+
+#[tokio::test]
+async fn batch_burn_rejects_accounts_whose_mint_decimals_changed() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager.connection.add_account(mint, mint_account(6));
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &mint, 500));
+    let accounts = [ZeroValueTokenInfo {
+        address: address.to_string(),
+        mint: mint.to_string(),
+        balance: 500,
+        decimals: 9,
+        is_nft: false,
+        rent_lamports: TOKEN_ACCOUNT_RENT,
+        rent_sol: TOKEN_ACCOUNT_RENT as f64 / 1e9,
+        symbol: "DUST".to_string(),
+        program_id: spl_token::id().to_string(),
+        is_associated: false,
+        decimals_verified: true,
+    }];
+
+    let report = manager
+        .batch_burn_and_close_zero_value_accounts(&accounts, 5)
+        .await
+        .unwrap();
+
+    assert_eq!(report.failed, 1);
+    let failure = &report.outcomes[0];
+    assert_eq!(failure.failure_kind, Some(FailureKind::InvalidAccount));
+    assert!(failure.error.as_deref().unwrap().contains("代币精度不匹配"));
+    assert!(manager.connection.simulated_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn burn_and_close_reports_account_with_foreign_close_authority() {
+    let manager = manager(MockRpc::new());
+    let address = Pubkey::new_unique();
+    let mut account = token_account(&manager.wallet.pubkey(), &Pubkey::new_unique(), 0);
+    let mut state = TokenAccount::unpack(&account.data).unwrap();
+    state.close_authority = Some(Pubkey::new_unique()).into();
+    TokenAccount::pack(state, &mut account.data).unwrap();
+    manager.connection.add_account(address, account);
+
+    let result = manager.burn_and_close_account(&address).await;
+
+    assert!(!result.success);
+    assert_eq!(result.failure_kind, Some(FailureKind::CloseAuthority));
+    assert!(manager.connection.sent_transactions().is_empty());
+}
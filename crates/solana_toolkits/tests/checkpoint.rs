@@ -0,0 +1,133 @@
+mod common;
+
+use common::{
+    account_info, manager, token_account, wallet_with_empty_accounts, TOKEN_ACCOUNT_RENT,
+};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_toolkits::{
+    checkpoint::{BatchCheckpoint, CheckpointStatus},
+    cleanup_daemon::{CleanupDaemon, CleanupDaemonConfig, CleanupState},
+    config::TokenAccountConfig,
+    retry::RetryPolicy,
+    TokenAccountManager,
+};
+use std::time::Duration;
+use utils::MockRpc;
+
+fn manager_with_checkpoint(path: &std::path::Path) -> TokenAccountManager<MockRpc> {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        checkpoint_path: Some(path.to_path_buf()),
+        ..TokenAccountConfig::default()
+    };
+    TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap()
+}
+
+#[tokio::test]
+async fn resume_from_checkpoint_skips_closed_accounts() {
+    let path = std::env::temp_dir().join(format!("checkpoint-{}.json", Pubkey::new_unique()));
+    let manager = manager_with_checkpoint(&path);
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    let funded = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(empty, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager
+        .connection
+        .add_account(funded, token_account(&owner, &Pubkey::new_unique(), 42));
+
+    manager
+        .batch_close_accounts(&[account_info(&empty), account_info(&funded)], 5, false)
+        .await
+        .unwrap();
+    let checkpoint = BatchCheckpoint::load(&path).unwrap();
+    let resumed = manager.resume_from_checkpoint(5, false).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(checkpoint.closed(), 1);
+    assert_eq!(checkpoint.entries[1].status, CheckpointStatus::Failed);
+    assert_eq!(resumed.outcomes.len(), 1);
+    assert_eq!(resumed.outcomes[0].account_address, funded.to_string());
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn checkpoint_is_removed_after_all_accounts_close() {
+    let path = std::env::temp_dir().join(format!("checkpoint-{}.json", Pubkey::new_unique()));
+    let manager = manager_with_checkpoint(&path);
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(empty, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let report = manager
+        .batch_close_accounts(&[account_info(&empty)], 5, false)
+        .await
+        .unwrap();
+
+    assert_eq!(report.succeeded, 1);
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn cleanup_daemon_closes_accounts_and_persists_state() {
+    let path = std::env::temp_dir().join(format!("cleanup-state-{}.json", Pubkey::new_unique()));
+    let config = CleanupDaemonConfig {
+        state_path: Some(path.clone()),
+        ..CleanupDaemonConfig::default()
+    };
+    let mut daemon = CleanupDaemon::new(wallet_with_empty_accounts(2), config).unwrap();
+
+    let summary = daemon.run_once().await.unwrap();
+
+    assert_eq!(summary.scanned, 2);
+    assert_eq!(summary.closed.succeeded, 2);
+    assert!(summary.burned.is_none());
+    let state = CleanupState::load(&path).unwrap();
+    assert_eq!(state.runs, 1);
+    assert!(state.last_run.is_some());
+    assert_eq!(state.rent_recovered_lamports, 2 * TOKEN_ACCOUNT_RENT);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn cleanup_daemon_skips_accounts_after_repeated_failures() {
+    let manager = wallet_with_empty_accounts(1);
+    manager.connection.fail_next_sends(4);
+    let config = CleanupDaemonConfig {
+        max_failures: 1,
+        ..CleanupDaemonConfig::default()
+    };
+    let mut daemon = CleanupDaemon::new(manager, config).unwrap();
+
+    let first = daemon.run_once().await.unwrap();
+    assert_eq!(first.closed.failed, 1);
+    assert_eq!(daemon.state().failures.len(), 1);
+
+    let second = daemon.run_once().await.unwrap();
+    assert_eq!(second.skipped, 1);
+    assert!(second.closed.outcomes.is_empty());
+    assert!(daemon.manager().connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn cleanup_daemon_stops_after_shutdown_request() {
+    let mut daemon = CleanupDaemon::new(
+        wallet_with_empty_accounts(1),
+        CleanupDaemonConfig::default(),
+    )
+    .unwrap();
+    let handle = daemon.shutdown_handle();
+    handle.shutdown();
+
+    tokio::time::timeout(Duration::from_secs(5), daemon.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(handle.is_shutdown());
+    assert_eq!(daemon.state().runs, 0);
+}
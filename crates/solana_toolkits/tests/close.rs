@@ -0,0 +1,393 @@
+mod common;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use common::{account_info, manager, token_account, TOKEN_ACCOUNT_RENT};
+use solana_sdk::{
+    message::VersionedMessage,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::{null_signer::NullSigner, Signer},
+};
+use solana_toolkits::{
+    account_info::{FailureKind, TokenAccountInfo},
+    config::{PreflightPolicy, SubmitMode, TokenAccountConfig},
+    offline::OfflineTransactionFile,
+    retry::{is_account_missing, RetryPolicy},
+    TokenAccountManager,
+};
+use spl_token::state::{Account as TokenAccount, AccountState};
+use std::{str::FromStr, time::Duration};
+use utils::{MockRpc, TokenAccountError};
+
+#[tokio::test]
+async fn close_account_sends_transaction_for_empty_account() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+    assert_eq!(
+        result.signature,
+        Some(manager.connection.sent_transactions()[0].to_string())
+    );
+}
+
+#[tokio::test]
+async fn close_account_is_signed_by_external_signer() {
+    let owner = Pubkey::new_unique();
+    let manager = TokenAccountManager::builder()
+        .rpc_client(MockRpc::new())
+        .signer(NullSigner::new(&owner))
+        .batch_delay(Duration::ZERO)
+        .build()
+        .unwrap();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.wallet.pubkey(), owner);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn close_account_rejects_non_zero_balance() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 42));
+
+    let result = manager.close_account(&address).await;
+
+    assert!(!result.success);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn close_account_reports_send_failure() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager.connection.fail_next_sends(1);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(!result.success);
+    assert!(result.signature.is_none());
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn dry_run_close_simulates_without_sending() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert!(result.simulated);
+    assert!(result.signature.is_none());
+    assert_eq!(manager.connection.simulated_transactions().len(), 1);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn dry_run_close_reports_simulation_failure() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager.connection.fail_next_simulations(1);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(!result.success);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn close_simulates_before_sending_and_reports_compute_units() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    let simulation = result.simulation.unwrap();
+    assert_eq!(simulation.units_consumed, Some(0));
+    assert!(!simulation.logs.is_empty());
+    assert_eq!(manager.connection.simulated_transactions().len(), 1);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn close_aborts_when_preflight_simulation_fails() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager.connection.fail_next_simulations(1);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(!result.success);
+    assert!(result.error.unwrap().contains("模拟执行失败"));
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn close_sends_despite_failed_simulation_with_warn_policy() {
+    let config = TokenAccountConfig {
+        preflight: PreflightPolicy::Warn,
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager.connection.fail_next_simulations(1);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn dry_run_batch_close_ignores_jito_submit_mode() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        submit_mode: SubmitMode::jito(10_000),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let report = manager
+        .batch_close_accounts(&[account_info(&address)], 5, true)
+        .await
+        .unwrap();
+
+    assert!(report.is_success());
+    assert!(report.simulated);
+    assert_eq!(manager.connection.simulated_transactions().len(), 1);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn close_account_rejects_frozen_account_without_sending() {
+    let manager = manager(MockRpc::new());
+    let address = Pubkey::new_unique();
+    let mut account = token_account(&manager.wallet.pubkey(), &Pubkey::new_unique(), 0);
+    let mut state = TokenAccount::unpack(&account.data).unwrap();
+    state.state = AccountState::Frozen;
+    TokenAccount::pack(state, &mut account.data).unwrap();
+    manager.connection.add_account(address, account);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(!result.success);
+    assert!(manager.connection.sent_transactions().is_empty());
+    assert!(manager.connection.simulated_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn batch_close_report_records_per_account_outcomes() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    let funded = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(empty, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager
+        .connection
+        .add_account(funded, token_account(&owner, &Pubkey::new_unique(), 42));
+
+    let report = manager
+        .batch_close_accounts(&[account_info(&empty), account_info(&funded)], 5, false)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (1, 1));
+    assert_eq!(report.signatures.len(), 1);
+    assert_eq!(report.rent_recovered_lamports, TOKEN_ACCOUNT_RENT);
+    let failure = report.failures().next().unwrap();
+    assert_eq!(failure.account_address, funded.to_string());
+    assert_eq!(failure.failure_kind, Some(FailureKind::NonZeroBalance));
+}
+
+#[tokio::test]
+async fn batch_close_skips_already_closed_accounts() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    let closed = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(empty, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let report = manager
+        .batch_close_accounts(&[account_info(&empty), account_info(&closed)], 5, false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        (report.succeeded, report.failed, report.already_closed),
+        (1, 0, 1)
+    );
+    assert_eq!(report.failures().count(), 0);
+    assert_eq!(report.rent_recovered_lamports, TOKEN_ACCOUNT_RENT);
+    let outcome = report
+        .outcomes
+        .iter()
+        .find(|outcome| outcome.account_address == closed.to_string())
+        .unwrap();
+    assert!(outcome.already_closed);
+    assert_eq!(outcome.signature, None);
+    assert!(is_account_missing(&TokenAccountError::AccountNotFound(
+        closed.to_string()
+    )));
+}
+
+#[tokio::test]
+async fn batch_close_with_lookup_table_sends_versioned_transaction() {
+    let mut manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let addresses: Vec<Pubkey> = (0..30).map(|_| Pubkey::new_unique()).collect();
+    let table = Pubkey::new_unique();
+    manager
+        .connection
+        .add_lookup_table(table, &owner, &addresses);
+    manager.set_lookup_table(Some(table));
+    let accounts: Vec<TokenAccountInfo> = addresses.iter().map(account_info).collect();
+
+    let report = manager
+        .batch_close_accounts(&accounts, accounts.len(), true)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (30, 0));
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn batch_close_with_missing_lookup_table_fails_accounts() {
+    let mut manager = manager(MockRpc::new());
+    manager.set_lookup_table(Some(Pubkey::new_unique()));
+
+    let report = manager
+        .batch_close_accounts(&[account_info(&Pubkey::new_unique())], 5, true)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (0, 1));
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn extend_lookup_table_skips_existing_addresses() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let existing = Pubkey::new_unique();
+    let table = Pubkey::new_unique();
+    manager
+        .connection
+        .add_lookup_table(table, &owner, &[existing]);
+
+    let added = manager
+        .extend_lookup_table(&table, &[existing, Pubkey::new_unique()])
+        .await
+        .unwrap();
+
+    assert_eq!(added, 1);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[test]
+fn close_transactions_carry_configured_memo() {
+    let config = TokenAccountConfig {
+        memo: Some("rent-recovery bot v1".to_string()),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let accounts = vec![account_info(&Pubkey::new_unique())];
+    let path = std::env::temp_dir().join(format!("memo-{}.json", Pubkey::new_unique()));
+    manager
+        .export_unsigned_close_transactions(&accounts, 5, &path)
+        .unwrap();
+
+    let file = OfflineTransactionFile::load(&path).unwrap();
+    let bytes = STANDARD.decode(&file.transactions[0].message).unwrap();
+    let message: VersionedMessage = bincode::deserialize(&bytes).unwrap();
+    let memo_program = Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr").unwrap();
+    let keys = message.static_account_keys();
+    let memo = message
+        .instructions()
+        .iter()
+        .find(|ix| keys[ix.program_id_index as usize] == memo_program)
+        .unwrap();
+    assert_eq!(memo.data, b"rent-recovery bot v1");
+    let _ = std::fs::remove_file(path);
+}
+
+#[tokio::test]
+async fn close_account_succeeds_when_wallet_is_close_authority() {
+    let manager = manager(MockRpc::new());
+    let address = Pubkey::new_unique();
+    let mut account = token_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 0);
+    let mut state = TokenAccount::unpack(&account.data).unwrap();
+    state.close_authority = Some(manager.wallet.pubkey()).into();
+    TokenAccount::pack(state, &mut account.data).unwrap();
+    manager.connection.add_account(address, account);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
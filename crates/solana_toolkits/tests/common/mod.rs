@@ -0,0 +1,133 @@
+//! 集成测试共用的夹具：MockRpc 管理器以及代币账户、Mint 和元数据账户数据
+#![allow(dead_code)]
+
+use solana_sdk::{
+    account::Account, program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+use solana_toolkits::{
+    account_info::TokenAccountInfo, config::TokenAccountConfig, metadata::TokenMetadata,
+    retry::RetryPolicy, TokenAccountManager,
+};
+use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use utils::MockRpc;
+
+pub const TOKEN_ACCOUNT_RENT: u64 = 2_039_280;
+
+pub fn manager(rpc: MockRpc) -> TokenAccountManager<MockRpc> {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        ..TokenAccountConfig::default()
+    };
+    TokenAccountManager::with_rpc(rpc, Keypair::new(), config).unwrap()
+}
+
+pub fn token_account(owner: &Pubkey, mint: &Pubkey, amount: u64) -> Account {
+    let state = TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        state: AccountState::Initialized,
+        ..TokenAccount::default()
+    };
+    let mut data = vec![0; TokenAccount::LEN];
+    TokenAccount::pack(state, &mut data).unwrap();
+
+    Account {
+        lamports: TOKEN_ACCOUNT_RENT,
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn mint_account(decimals: u8) -> Account {
+    let state = Mint {
+        decimals,
+        is_initialized: true,
+        ..Mint::default()
+    };
+    let mut data = vec![0; Mint::LEN];
+    Mint::pack(state, &mut data).unwrap();
+
+    Account {
+        lamports: 1_461_600,
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+pub fn account_info(address: &Pubkey) -> TokenAccountInfo {
+    TokenAccountInfo {
+        address: address.to_string(),
+        mint: Pubkey::new_unique().to_string(),
+        rent_lamports: TOKEN_ACCOUNT_RENT,
+        rent_sol: TOKEN_ACCOUNT_RENT as f64 / 1e9,
+        symbol: "unknown".to_string(),
+        program_id: spl_token::id().to_string(),
+        is_associated: false,
+        withheld_amount: 0,
+    }
+}
+
+pub fn cached_metadata(mint: &Pubkey, symbol: &str, fetched_at: u64) -> TokenMetadata {
+    TokenMetadata {
+        mint: mint.to_string(),
+        symbol: symbol.to_string(),
+        name: symbol.to_string(),
+        decimals: 6,
+        fetched_at,
+        is_nft: false,
+        uri: String::new(),
+        is_mutable: false,
+        freeze_authority: None,
+    }
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+pub fn wallet_with_empty_accounts(empty_accounts: usize) -> TokenAccountManager<MockRpc> {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    for _ in 0..empty_accounts {
+        let address = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        manager
+            .connection
+            .add_token_account(&owner, &address, &mint, 0, TOKEN_ACCOUNT_RENT);
+        manager
+            .connection
+            .add_account(address, token_account(&owner, &mint, 0));
+    }
+    manager
+}
+
+/// Metaplex 元数据账户的 Borsh 编码，只填写名称、符号和 URI
+pub fn metadata_account(mint: &Pubkey, symbol: &str) -> Account {
+    let mut data = vec![4]; // Key::MetadataV1
+    data.extend_from_slice(Pubkey::new_unique().as_ref());
+    data.extend_from_slice(mint.as_ref());
+    for value in [symbol, symbol, ""] {
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value.as_bytes());
+    }
+    data.extend_from_slice(&0u16.to_le_bytes()); // seller_fee_basis_points
+    data.extend_from_slice(&[0, 0, 1, 0, 0, 0, 0, 0, 0]); // creators 之后的字段全部为空
+
+    Account {
+        lamports: 5_616_720,
+        data,
+        owner: mpl_token_metadata::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::manager;
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signer::Signer};
+use solana_toolkits::faucet::{parse_funding_list, FaucetConfig, FundingSource, FundingTarget};
+use std::time::Duration;
+use utils::{MockRpc, RpcApi};
+
+fn faucet_config(source: FundingSource) -> FaucetConfig {
+    FaucetConfig {
+        source,
+        request_interval: Duration::ZERO,
+        retry_delay: Duration::ZERO,
+        ..FaucetConfig::default()
+    }
+}
+
+#[test]
+fn parse_funding_list_uses_default_target() {
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let content = format!("pubkey,target_sol\n# bots\n{}\n{},2.5\n", first, second);
+
+    let targets = parse_funding_list(&content, 0.5).unwrap();
+
+    assert_eq!(targets.len(), 2);
+    assert_eq!(targets[0].pubkey, first);
+    assert_eq!(targets[0].target_sol, 0.5);
+    assert_eq!(targets[1].target_sol, 2.5);
+}
+
+#[tokio::test]
+async fn airdrop_tops_up_in_chunks_and_skips_funded_wallets() {
+    let rpc = MockRpc::new();
+    let empty = Pubkey::new_unique();
+    let funded = Pubkey::new_unique();
+    rpc.set_balance(empty, LAMPORTS_PER_SOL / 2);
+    rpc.set_balance(funded, 3 * LAMPORTS_PER_SOL);
+    let manager = manager(rpc);
+
+    let targets = [
+        FundingTarget {
+            pubkey: empty,
+            target_sol: 2.0,
+        },
+        FundingTarget {
+            pubkey: funded,
+            target_sol: 2.0,
+        },
+    ];
+    let report = manager
+        .fund_wallets(&targets, &faucet_config(FundingSource::Airdrop))
+        .await
+        .unwrap();
+
+    assert_eq!((report.funded, report.skipped, report.failed), (1, 1, 0));
+    assert_eq!(manager.connection.airdrops().len(), 2);
+    assert_eq!(
+        manager.connection.get_balance(&empty).unwrap(),
+        2 * LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn airdrop_retries_after_failure() {
+    let rpc = MockRpc::new();
+    let wallet = Pubkey::new_unique();
+    rpc.fail_next_airdrops(2);
+    let manager = manager(rpc);
+
+    let targets = [FundingTarget {
+        pubkey: wallet,
+        target_sol: 1.0,
+    }];
+    let report = manager
+        .fund_wallets(&targets, &faucet_config(FundingSource::Airdrop))
+        .await
+        .unwrap();
+
+    assert_eq!(report.funded, 1);
+    assert_eq!(report.results[0].signatures.len(), 1);
+}
+
+#[tokio::test]
+async fn transfer_requires_sufficient_funder_balance() {
+    let manager = manager(MockRpc::new());
+    manager
+        .connection
+        .set_balance(manager.wallet.pubkey(), LAMPORTS_PER_SOL);
+
+    let targets = [FundingTarget {
+        pubkey: Pubkey::new_unique(),
+        target_sol: 5.0,
+    }];
+    let result = manager
+        .fund_wallets(&targets, &faucet_config(FundingSource::Transfer))
+        .await;
+
+    assert!(result.is_err());
+    assert!(manager.connection.sent_transactions().is_empty());
+}
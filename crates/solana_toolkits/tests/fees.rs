@@ -0,0 +1,206 @@
+mod common;
+
+use common::{account_info, manager, token_account};
+use solana_sdk::{
+    native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::Keypair, signer::Signer,
+};
+use solana_toolkits::{
+    account_info::FailureKind,
+    config::{ComputeUnitPrice, TokenAccountConfig},
+    TokenAccountManager,
+};
+use std::time::Duration;
+use utils::{MockRpc, TokenAccountError};
+
+#[test]
+fn auto_priority_fee_uses_capped_median_of_recent_fees() {
+    let auto: ComputeUnitPrice = "auto:8000".parse().unwrap();
+
+    assert_eq!(auto.resolve(&[0, 1_000, 5_000, 20_000]), 5_000);
+    assert_eq!(auto.resolve(&[10_000, 20_000]), 8_000);
+    assert_eq!(auto.resolve(&[0, 0]), 0);
+    assert_eq!("1500".parse(), Ok(ComputeUnitPrice::Fixed(1_500)));
+}
+
+#[tokio::test]
+async fn close_account_with_compute_budget_succeeds() {
+    let config = TokenAccountConfig {
+        compute_unit_limit: Some(10_000),
+        compute_unit_price: ComputeUnitPrice::Auto {
+            max_micro_lamports: 50_000,
+        },
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager
+        .connection
+        .set_prioritization_fees(vec![0, 1_000, 3_000]);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn batch_close_stops_when_fees_would_breach_wallet_reserve() {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        compute_unit_limit: Some(100_000),
+        compute_unit_price: ComputeUnitPrice::Fixed(10_000),
+        min_wallet_sol_reserve: Some(1.0),
+        ..TokenAccountConfig::default()
+    };
+    // -- 签名费 5000 + 优先费 100_000 * 10_000 / 1_000_000
+    assert_eq!(config.estimated_fee_lamports(1, 1), 6_000);
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    manager
+        .connection
+        .set_balance(manager.wallet.pubkey(), LAMPORTS_PER_SOL + 10_000);
+    let accounts: Vec<_> = (0..4)
+        .map(|_| account_info(&Pubkey::new_unique()))
+        .collect();
+
+    let report = manager
+        .batch_close_accounts(&accounts, 2, false)
+        .await
+        .unwrap();
+
+    assert_eq!(report.failed, 4);
+    assert!(report
+        .failures()
+        .all(|failure| failure.failure_kind == Some(FailureKind::LowBalance)));
+    assert!(manager.connection.sent_transactions().is_empty());
+
+    let report = manager
+        .batch_close_accounts(&accounts, 2, true)
+        .await
+        .unwrap();
+    assert_eq!(report.succeeded, 4);
+}
+
+#[tokio::test]
+async fn fee_payer_covers_fees_for_wallet_without_sol() {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        min_wallet_sol_reserve: Some(0.01),
+        ..TokenAccountConfig::default()
+    };
+    let fee_payer = Keypair::new();
+    let fee_payer_pubkey = fee_payer.pubkey();
+    let manager = TokenAccountManager::builder()
+        .rpc_client(MockRpc::new())
+        .keypair(Keypair::new())
+        .config(config)
+        .fee_payer(fee_payer)
+        .build()
+        .unwrap();
+    let owner = manager.wallet.pubkey();
+    manager.connection.set_balance(owner, 0);
+    manager
+        .connection
+        .set_balance(fee_payer_pubkey, LAMPORTS_PER_SOL);
+    let accounts: Vec<_> = (0..3)
+        .map(|_| {
+            let address = Pubkey::new_unique();
+            manager
+                .connection
+                .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+            account_info(&address)
+        })
+        .collect();
+
+    let individual = manager
+        .batch_close_accounts(&accounts[..2], 5, false)
+        .await
+        .unwrap();
+    let batched = manager
+        .batch_close_accounts(&accounts[2..], 5, true)
+        .await
+        .unwrap();
+
+    assert_eq!(manager.fee_payer_pubkey(), fee_payer_pubkey);
+    assert_eq!(individual.succeeded, 2);
+    assert_eq!(batched.succeeded, 1);
+    assert_eq!(individual.balance_before, LAMPORTS_PER_SOL);
+    assert_eq!(manager.connection.sent_transactions().len(), 3);
+}
+
+#[tokio::test]
+async fn batch_close_refuses_plans_whose_fees_exceed_budget() {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        compute_unit_limit: Some(100_000),
+        compute_unit_price: ComputeUnitPrice::Fixed(10_000),
+        max_fee_sol: Some(0.00002),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let accounts: Vec<_> = (0..4)
+        .map(|_| account_info(&Pubkey::new_unique()))
+        .collect();
+
+    let error = manager
+        .batch_close_accounts(&accounts, 2, false)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        TokenAccountError::FeeBudgetExceeded {
+            estimated: 24_000,
+            budget: 20_000
+        }
+    ));
+    assert!(manager.connection.sent_transactions().is_empty());
+
+    let report = manager
+        .batch_close_accounts(&accounts, 2, true)
+        .await
+        .unwrap();
+    assert_eq!(report.estimated_fees_lamports, 12_000);
+    assert_eq!(report.succeeded, 4);
+}
+
+#[tokio::test]
+async fn fee_estimate_counts_fee_payer_signature_and_default_units_per_instruction() {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        compute_unit_price: ComputeUnitPrice::Fixed(10_000),
+        max_fee_sol: Some(0.000013),
+        ..TokenAccountConfig::default()
+    };
+    // -- 未设置计算单元上限：每条指令 200k，单笔交易最多 1.4M
+    assert_eq!(config.estimated_fee_lamports(1, 1), 7_000);
+    assert_eq!(config.estimated_fee_lamports(2, 3), 16_000);
+    assert_eq!(config.estimated_fee_lamports(1, 10), 19_000);
+
+    let manager = TokenAccountManager::builder()
+        .rpc_client(MockRpc::new())
+        .keypair(Keypair::new())
+        .config(config)
+        .fee_payer(Keypair::new())
+        .build()
+        .unwrap();
+    let accounts: Vec<_> = (0..2)
+        .map(|_| account_info(&Pubkey::new_unique()))
+        .collect();
+
+    // -- 一笔批量交易：两个签名 10_000 + 两条关闭指令 400_000 * 10_000 / 1_000_000
+    let error = manager
+        .batch_close_accounts(&accounts, 2, true)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        TokenAccountError::FeeBudgetExceeded {
+            estimated: 14_000,
+            budget: 13_000
+        }
+    ));
+    assert!(manager.connection.sent_transactions().is_empty());
+}
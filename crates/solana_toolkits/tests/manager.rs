@@ -0,0 +1,227 @@
+mod common;
+
+use common::{manager, mint_account, token_account};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_toolkits::{
+    config::{ConfirmationStrategy, TokenAccountConfig},
+    confirmation::websocket_url,
+    retry::RetryPolicy,
+    whitelist::TokenWhitelist,
+    TokenAccountManager, MAX_MULTIPLE_ACCOUNTS,
+};
+use std::time::Duration;
+use utils::{
+    cluster::Cluster,
+    keystore::{encrypt_keypair_file, is_encrypted_keypair_file, load_encrypted_keypair},
+    FailoverRpc, KeystoreError, MockRpc, RpcApi,
+};
+
+#[test]
+fn builder_injects_rpc_client_keypair_and_settings() {
+    let wallet = Keypair::new();
+    let owner = wallet.pubkey();
+    let mut whitelist = TokenWhitelist::new(Some(false));
+    whitelist.add_symbol("FOO");
+    let manager = TokenAccountManager::builder()
+        .rpc_client(MockRpc::new())
+        .keypair(wallet)
+        .retry_policy(RetryPolicy::fixed(5, Duration::ZERO))
+        .dry_run(true)
+        .whitelist(whitelist)
+        .build()
+        .unwrap();
+
+    assert_eq!(manager.wallet.pubkey(), owner);
+    assert_eq!(
+        manager.get_config().retry_policy,
+        RetryPolicy::fixed(5, Duration::ZERO)
+    );
+    assert!(manager.get_config().dry_run);
+    assert!(manager.is_token_whitelisted("FOO", "unknown"));
+    assert!(!manager.is_token_whitelisted("USDC", "unknown"));
+}
+
+#[test]
+fn cluster_parses_names_and_custom_urls() {
+    assert_eq!("devnet".parse::<Cluster>(), Ok(Cluster::Devnet));
+    assert_eq!("mainnet-beta".parse::<Cluster>(), Ok(Cluster::Mainnet));
+    assert_eq!(
+        "https://rpc.example.com".parse::<Cluster>(),
+        Ok(Cluster::Custom("https://rpc.example.com".to_string()))
+    );
+    assert!("moonnet".parse::<Cluster>().is_err());
+    assert_eq!(Cluster::Devnet.url(), "https://api.devnet.solana.com");
+
+    let config = TokenAccountConfig {
+        cluster: Some(Cluster::Testnet),
+        ..TokenAccountConfig::default()
+    };
+    assert_eq!(config.cluster(), Cluster::Testnet);
+}
+
+#[test]
+fn builder_requires_wallet() {
+    let result = TokenAccountManager::builder()
+        .rpc_client(MockRpc::new())
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn websocket_url_is_derived_from_rpc_url() {
+    assert_eq!(
+        websocket_url("https://api.mainnet-beta.solana.com"),
+        "wss://api.mainnet-beta.solana.com"
+    );
+    assert_eq!(
+        websocket_url("http://127.0.0.1:8899"),
+        "ws://127.0.0.1:8900"
+    );
+}
+
+#[tokio::test]
+async fn websocket_confirmation_does_not_send_when_subscription_fails() {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        confirmation: ConfirmationStrategy::WebSocket {
+            ws_url: Some(String::from("ws://127.0.0.1:1")),
+            timeout: Duration::from_secs(1),
+            rebroadcast_interval: None,
+        },
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    let result = manager.close_account(&address).await;
+
+    assert!(!result.success);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[test]
+fn encrypted_wallet_file_round_trips_with_passphrase() {
+    let path = std::env::temp_dir().join(format!("wallet-{}.json", Pubkey::new_unique()));
+    let keypair = Keypair::new();
+    encrypt_keypair_file(&path, &keypair, "correct horse").unwrap();
+
+    assert!(is_encrypted_keypair_file(&path));
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains(&keypair.to_base58_string()));
+    let loaded = load_encrypted_keypair(&path, || Ok("correct horse".to_string())).unwrap();
+    assert_eq!(loaded.pubkey(), keypair.pubkey());
+    assert!(matches!(
+        load_encrypted_keypair(&path, || Ok("wrong".to_string())),
+        Err(KeystoreError::WrongPassphrase)
+    ));
+    assert!(matches!(
+        encrypt_keypair_file(&path, &keypair, "again"),
+        Err(KeystoreError::AlreadyExists(_))
+    ));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn get_accounts_details_fetches_in_chunks_and_keeps_order() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mut pubkeys: Vec<Pubkey> = (0..MAX_MULTIPLE_ACCOUNTS + 20)
+        .map(|amount| {
+            let address = Pubkey::new_unique();
+            manager.connection.add_account(
+                address,
+                token_account(&owner, &Pubkey::new_unique(), amount as u64),
+            );
+            address
+        })
+        .collect();
+    let missing = Pubkey::new_unique();
+    pubkeys.insert(MAX_MULTIPLE_ACCOUNTS, missing);
+
+    let details = manager.get_accounts_details(&pubkeys).await.unwrap();
+
+    assert_eq!(details.len(), pubkeys.len());
+    assert!(details[MAX_MULTIPLE_ACCOUNTS].is_err());
+    for (index, (pubkey, detail)) in pubkeys.iter().zip(&details).enumerate() {
+        if *pubkey == missing {
+            continue;
+        }
+        let detail = detail.as_ref().unwrap();
+        assert_eq!(detail.pubkey, pubkey.to_string());
+        let expected = if index > MAX_MULTIPLE_ACCOUNTS {
+            index - 1
+        } else {
+            index
+        };
+        assert_eq!(detail.balance, expected as u64);
+    }
+}
+
+fn failover_pool(probe_interval: Duration) -> FailoverRpc<MockRpc> {
+    FailoverRpc::from_clients(vec![
+        ("primary".to_string(), MockRpc::new()),
+        ("backup".to_string(), MockRpc::new()),
+    ])
+    .with_probe_interval(probe_interval)
+}
+
+#[tokio::test]
+async fn close_account_fails_over_to_backup_rpc_when_rate_limited() {
+    let rpc = failover_pool(Duration::from_secs(3600));
+    let wallet = Keypair::new();
+    let address = Pubkey::new_unique();
+    let account = token_account(&wallet.pubkey(), &Pubkey::new_unique(), 0);
+    for client in rpc.clients() {
+        client.add_account(address, account.clone());
+    }
+    rpc.clients().next().unwrap().set_rate_limited(true);
+    let manager =
+        TokenAccountManager::with_rpc(rpc, wallet, TokenAccountConfig::default()).unwrap();
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.active_url(), "backup");
+    let sent: Vec<usize> = manager
+        .connection
+        .clients()
+        .map(|client| client.sent_transactions().len())
+        .collect();
+    assert_eq!(sent, vec![0, 1]);
+}
+
+#[test]
+fn failover_rpc_reprobes_failed_endpoint_after_interval() {
+    let owner = Pubkey::new_unique();
+    let cooling = failover_pool(Duration::from_secs(3600));
+    let reprobing = failover_pool(Duration::ZERO);
+    for rpc in [&cooling, &reprobing] {
+        rpc.clients().next().unwrap().set_rate_limited(true);
+        rpc.get_balance(&owner).unwrap();
+        assert_eq!(rpc.active_url(), "backup");
+        rpc.clients().next().unwrap().set_rate_limited(false);
+        rpc.get_balance(&owner).unwrap();
+    }
+
+    assert_eq!(cooling.active_url(), "backup");
+    assert_eq!(reprobing.active_url(), "primary");
+}
+
+#[test]
+fn failover_rpc_does_not_switch_on_request_errors() {
+    let rpc = failover_pool(Duration::from_secs(3600));
+    let address = Pubkey::new_unique();
+    rpc.clients()
+        .nth(1)
+        .unwrap()
+        .add_account(address, mint_account(6));
+
+    assert!(rpc.get_account(&address).is_err());
+    assert_eq!(rpc.active_url(), "primary");
+}
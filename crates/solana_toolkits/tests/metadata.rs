@@ -0,0 +1,155 @@
+mod common;
+
+use common::{
+    cached_metadata, manager, metadata_account, mint_account, unix_now, TOKEN_ACCOUNT_RENT,
+};
+use mpl_token_metadata::accounts::{MasterEdition, Metadata};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_toolkits::{
+    config::TokenAccountConfig,
+    metadata::{TokenMetadata, TokenMetadataCache},
+    TokenAccountManager,
+};
+use std::time::Duration;
+use utils::MockRpc;
+
+#[tokio::test]
+async fn get_closeable_accounts_fetches_metadata_concurrently() {
+    let config = TokenAccountConfig {
+        metadata_concurrency: 4,
+        metadata_requests_per_second: Some(1_000),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let shared_mint = Pubkey::new_unique();
+    for i in 0..20 {
+        let mint = if i % 2 == 0 {
+            shared_mint
+        } else {
+            Pubkey::new_unique()
+        };
+        manager.connection.add_token_account(
+            &owner,
+            &Pubkey::new_unique(),
+            &mint,
+            0,
+            TOKEN_ACCOUNT_RENT,
+        );
+    }
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.total_accounts, 20);
+    assert_eq!(result.closable_accounts, 20);
+    assert!(result
+        .accounts
+        .iter()
+        .all(|account| account.symbol == "unknown"));
+}
+
+#[tokio::test]
+async fn get_closeable_accounts_uses_cached_metadata() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &Pubkey::new_unique(),
+        &mint,
+        1_000,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager
+        .metadata_cache()
+        .insert_all([cached_metadata(&mint, "DUST", unix_now())]);
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.zero_value_accounts, 1);
+    assert_eq!(result.zero_value_accounts_list[0].symbol, "DUST");
+}
+
+#[tokio::test]
+async fn get_closeable_accounts_classifies_nfts_separately() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &Pubkey::new_unique(),
+        &mint,
+        1,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager.metadata_cache().insert_all([TokenMetadata {
+        decimals: 0,
+        is_nft: true,
+        ..cached_metadata(&mint, "APE", unix_now())
+    }]);
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.zero_value_accounts, 0);
+    assert_eq!(result.nft_accounts_list.len(), 1);
+    assert!(result.nft_accounts_list[0].is_nft);
+}
+
+#[test]
+fn metadata_cache_expires_entries_after_ttl() {
+    let cache = TokenMetadataCache::new(Duration::from_secs(60), None);
+    let fresh = Pubkey::new_unique();
+    let stale = Pubkey::new_unique();
+    cache.insert_all([
+        cached_metadata(&fresh, "NEW", unix_now()),
+        cached_metadata(&stale, "OLD", unix_now() - 120),
+    ]);
+
+    assert_eq!(cache.get(&fresh.to_string()).unwrap().symbol, "NEW");
+    assert!(cache.get(&stale.to_string()).is_none());
+}
+
+#[test]
+fn metadata_cache_persists_to_disk() {
+    let path = std::env::temp_dir().join(format!("metadata-cache-{}.json", Pubkey::new_unique()));
+    let mint = Pubkey::new_unique();
+    TokenMetadataCache::new(Duration::from_secs(60), Some(path.clone()))
+        .insert_all([cached_metadata(&mint, "DISK", unix_now())]);
+
+    let reloaded = TokenMetadataCache::new(Duration::from_secs(60), Some(path.clone()));
+
+    assert_eq!(reloaded.get(&mint.to_string()).unwrap().symbol, "DISK");
+    reloaded.clear();
+    assert!(!path.exists());
+}
+
+#[tokio::test]
+async fn nft_check_failure_leaves_mint_unclassified() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let missing = Pubkey::new_unique();
+    let failing = Pubkey::new_unique();
+    for mint in [missing, failing] {
+        manager.connection.add_account(mint, mint_account(0));
+        manager
+            .connection
+            .add_account(Metadata::find_pda(&mint).0, metadata_account(&mint, "DUST"));
+        manager.connection.add_token_account(
+            &owner,
+            &Pubkey::new_unique(),
+            &mint,
+            5,
+            TOKEN_ACCOUNT_RENT,
+        );
+    }
+    manager
+        .connection
+        .fail_account(MasterEdition::find_pda(&failing).0, "429 Too Many Requests");
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.zero_value_accounts_list.len(), 1);
+    assert_eq!(result.zero_value_accounts_list[0].mint, missing.to_string());
+    assert!(result.nft_accounts_list.is_empty());
+    assert!(manager.metadata_cache().get(&failing.to_string()).is_none());
+}
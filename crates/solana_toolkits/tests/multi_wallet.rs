@@ -0,0 +1,80 @@
+mod common;
+
+use common::{manager, wallet_with_empty_accounts, TOKEN_ACCOUNT_RENT};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, signer::Signer};
+use solana_toolkits::multi_wallet::{MultiWalletManager, WalletOperation};
+use utils::{MockRpc, RpcApi};
+
+#[tokio::test]
+async fn multi_wallet_scan_reports_each_wallet_in_order() {
+    let multi = MultiWalletManager::with_managers(vec![
+        wallet_with_empty_accounts(2),
+        wallet_with_empty_accounts(0),
+    ]);
+
+    let report = multi.run(WalletOperation::Scan, 5).await;
+
+    assert_eq!(report.wallets.len(), 2);
+    assert_eq!(report.wallets[0].closable_accounts, 2);
+    assert_eq!(report.wallets[1].closable_accounts, 0);
+    assert!(report.wallets.iter().all(|wallet| wallet.report.is_none()));
+    assert_eq!((report.succeeded, report.failed), (2, 0));
+    assert!(multi
+        .managers()
+        .all(|manager| manager.connection.sent_transactions().is_empty()));
+}
+
+#[tokio::test]
+async fn multi_wallet_parallel_close_aggregates_reports() {
+    let mut multi = MultiWalletManager::with_managers(vec![
+        wallet_with_empty_accounts(1),
+        wallet_with_empty_accounts(2),
+        wallet_with_empty_accounts(3),
+    ]);
+    multi.set_concurrency(2);
+    let wallets: Vec<String> = multi
+        .managers()
+        .map(|manager| manager.wallet.pubkey().to_string())
+        .collect();
+
+    let report = multi.run(WalletOperation::Close, 5).await;
+
+    let order: Vec<String> = report.wallets.iter().map(|w| w.wallet.clone()).collect();
+    assert_eq!(order, wallets);
+    assert_eq!((report.succeeded, report.failed), (3, 0));
+    assert_eq!(report.accounts_closed, 6);
+    assert_eq!(report.rent_recovered_lamports, TOKEN_ACCOUNT_RENT * 6);
+}
+
+#[tokio::test]
+async fn multi_wallet_consolidates_sol_into_master() {
+    let rich = manager(MockRpc::new());
+    let poor = manager(MockRpc::new());
+    let master = manager(MockRpc::new());
+    let rent_exempt = rich
+        .connection
+        .get_minimum_balance_for_rent_exemption(0)
+        .unwrap();
+    rich.connection
+        .set_balance(rich.wallet.pubkey(), LAMPORTS_PER_SOL);
+    poor.connection
+        .set_balance(poor.wallet.pubkey(), rent_exempt);
+    master
+        .connection
+        .set_balance(master.wallet.pubkey(), LAMPORTS_PER_SOL);
+    let master_pubkey = master.wallet.pubkey();
+    let multi = MultiWalletManager::with_managers(vec![rich, poor, master]);
+
+    let report = multi.consolidate_sol(&master_pubkey).await;
+
+    let expected = LAMPORTS_PER_SOL - rent_exempt - 5000;
+    assert_eq!((report.succeeded, report.skipped, report.failed), (1, 2, 0));
+    assert_eq!(report.outcomes[0].transferred_lamports, expected);
+    assert!(report.outcomes[0].signature.is_some());
+    assert_eq!(report.transferred_lamports, expected);
+    let sent: Vec<usize> = multi
+        .managers()
+        .map(|manager| manager.connection.sent_transactions().len())
+        .collect();
+    assert_eq!(sent, vec![1, 0, 0]);
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use common::{account_info, manager, TOKEN_ACCOUNT_RENT};
+use solana_sdk::{
+    account::Account,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::{null_signer::NullSigner, Signer},
+};
+use solana_toolkits::{
+    account_info::TokenAccountInfo,
+    config::TokenAccountConfig,
+    offline::{sign_offline, OfflineTransactionFile},
+    retry::RetryPolicy,
+    squads::{proposal_address, vault_address, vault_transaction_message, SQUADS_PROGRAM_ID},
+    TokenAccountManager,
+};
+use std::time::Duration;
+use utils::MockRpc;
+
+fn offline_manager(owner: &Pubkey) -> TokenAccountManager<MockRpc, NullSigner> {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        ..TokenAccountConfig::default()
+    };
+    TokenAccountManager::with_rpc(MockRpc::new(), NullSigner::new(owner), config).unwrap()
+}
+
+#[tokio::test]
+async fn offline_signed_transactions_are_broadcast_from_file() {
+    let keypair = Keypair::new();
+    let manager = offline_manager(&keypair.pubkey());
+    let accounts: Vec<TokenAccountInfo> = (0..3)
+        .map(|_| account_info(&Pubkey::new_unique()))
+        .collect();
+    let unsigned = std::env::temp_dir().join(format!("unsigned-{}.json", Pubkey::new_unique()));
+    let signed = std::env::temp_dir().join(format!("signed-{}.json", Pubkey::new_unique()));
+
+    assert_eq!(
+        manager
+            .export_unsigned_close_transactions(&accounts, 2, &unsigned)
+            .unwrap(),
+        2
+    );
+    assert_eq!(OfflineTransactionFile::load(&unsigned).unwrap().signed(), 0);
+    assert_eq!(sign_offline(&unsigned, &signed, &keypair).unwrap(), 2);
+    let report = manager.broadcast_from_file(&signed).await.unwrap();
+
+    assert_eq!(report.succeeded, 3);
+    assert_eq!(report.signatures.len(), 2);
+    assert_eq!(manager.connection.sent_transactions().len(), 2);
+    assert_eq!(
+        report.outcomes[0].signature,
+        Some(manager.connection.sent_transactions()[0].to_string())
+    );
+    let _ = std::fs::remove_file(unsigned);
+    let _ = std::fs::remove_file(signed);
+}
+
+#[tokio::test]
+async fn offline_signing_rejects_other_wallet_and_unsigned_broadcast_fails() {
+    let manager = offline_manager(&Pubkey::new_unique());
+    let accounts = vec![account_info(&Pubkey::new_unique())];
+    let path = std::env::temp_dir().join(format!("unsigned-{}.json", Pubkey::new_unique()));
+    manager
+        .export_unsigned_close_transactions(&accounts, 5, &path)
+        .unwrap();
+
+    assert!(sign_offline(&path, &path, &Keypair::new()).is_err());
+    let report = manager.broadcast_from_file(&path).await.unwrap();
+
+    assert_eq!(report.failed, 1);
+    assert!(manager.connection.sent_transactions().is_empty());
+    let _ = std::fs::remove_file(path);
+}
+
+fn multisig_account(transaction_index: u64) -> Account {
+    let mut data = vec![0u8; 128];
+    data[78..86].copy_from_slice(&transaction_index.to_le_bytes());
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner: SQUADS_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[test]
+fn squads_proposals_use_next_transaction_indexes() {
+    let manager = manager(MockRpc::new());
+    let multisig = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(multisig, multisig_account(4));
+    let accounts: Vec<TokenAccountInfo> = (0..3)
+        .map(|_| account_info(&Pubkey::new_unique()))
+        .collect();
+
+    let proposals = manager
+        .propose_close_accounts(&multisig, 0, &accounts, 2)
+        .unwrap();
+
+    assert_eq!(proposals.len(), 2);
+    assert_eq!(proposals[0].transaction_index, 5);
+    assert_eq!(proposals[1].transaction_index, 6);
+    assert_eq!(
+        proposals[1].proposal,
+        proposal_address(&multisig, 6).to_string()
+    );
+    assert_eq!(proposals[0].accounts.len(), 2);
+    assert_eq!(proposals[0].rent_lamports, 2 * TOKEN_ACCOUNT_RENT);
+    assert_eq!(manager.connection.sent_transactions().len(), 2);
+}
+
+#[test]
+fn squads_proposal_requires_multisig_account() {
+    let manager = manager(MockRpc::new());
+    let accounts = vec![account_info(&Pubkey::new_unique())];
+
+    let result = manager.propose_close_accounts(&Pubkey::new_unique(), 0, &accounts, 5);
+
+    assert!(result.is_err());
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[test]
+fn squads_vault_message_uses_vault_as_signer() {
+    let vault = vault_address(&Pubkey::new_unique(), 0);
+    let account = Pubkey::new_unique();
+    let instruction =
+        spl_token::instruction::close_account(&spl_token::id(), &account, &vault, &vault, &[])
+            .unwrap();
+
+    let message = vault_transaction_message(&vault, &[instruction]);
+
+    // -- 1 个签名者（可写），1 个可写非签名者，3 个账户
+    assert_eq!(&message[..4], &[1, 1, 1, 3]);
+    assert_eq!(&message[4..36], vault.as_ref());
+}
@@ -0,0 +1,446 @@
+mod common;
+
+use common::{
+    account_info, manager, token_account, wallet_with_empty_accounts, TOKEN_ACCOUNT_RENT,
+};
+use futures::future::BoxFuture;
+use solana_sdk::{account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_toolkits::{
+    account_info::{AccountOutcome, BatchCloseReport, FailureKind},
+    config::TokenAccountConfig,
+    history::{month_range, RunHistory, RunOperation},
+    metrics::{serve_metrics, CleanupMetrics},
+    notify::{report_summary, Notifier, NotifyPolicy, TelegramNotifier},
+    observer::BatchObserver,
+    retry::RetryPolicy,
+    run_report::{
+        explorer_account_url, explorer_tx_url, render_html, render_markdown, ReportFormat,
+    },
+    TokenAccountManager,
+};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use utils::{cluster::Cluster, locale::Locale, MockRpc, TokenAccountError};
+
+#[tokio::test]
+async fn batch_close_writes_markdown_and_html_run_reports() {
+    let path = std::env::temp_dir().join(format!("report-{}.md", Pubkey::new_unique()));
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        cluster: Some(Cluster::Devnet),
+        run_report_path: Some(path.clone()),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    let funded = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(empty, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager
+        .connection
+        .add_account(funded, token_account(&owner, &Pubkey::new_unique(), 42));
+
+    let mut accounts = vec![account_info(&empty), account_info(&funded)];
+    accounts[1].symbol = "<b>|x".to_string();
+    let report = manager
+        .batch_close_accounts(&accounts, 5, false)
+        .await
+        .unwrap();
+
+    let markdown = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(markdown.starts_with("# 批量关闭报告"));
+    assert!(markdown.contains(&format!(
+        "[{}]({})",
+        empty,
+        explorer_account_url(&empty.to_string(), &Cluster::Devnet)
+    )));
+    assert!(markdown.contains(&explorer_tx_url(&report.signatures[0], &Cluster::Devnet)));
+    assert!(markdown.contains("?cluster=devnet"));
+    assert!(markdown.contains("## 失败账户"));
+    assert!(markdown.contains("&lt;b&gt;\\|x | 余额不为 0 | 账户余额不为 0: 42"));
+
+    let wallet = owner.to_string();
+    let html = render_html(
+        &wallet,
+        RunOperation::Close,
+        &report,
+        &Cluster::Devnet,
+        Locale::Zh,
+    );
+    assert!(html.contains("&lt;b&gt;|x"));
+    assert!(!html.contains("<b>|x"));
+    assert_eq!(ReportFormat::from_path("run.HTML"), ReportFormat::Html);
+    assert_eq!(ReportFormat::from_path("run.md"), ReportFormat::Markdown);
+
+    let english = render_markdown(
+        &wallet,
+        RunOperation::Close,
+        &report,
+        &Cluster::Devnet,
+        Locale::En,
+    );
+    assert!(english.starts_with("# Batch close report"));
+    assert!(english.contains("- Succeeded: 1 account\n"));
+    assert!(english.contains("## Failed accounts"));
+    assert!(english.contains("| Non-zero balance |"));
+}
+
+#[test]
+fn errors_and_failure_kinds_render_in_english() {
+    assert_eq!("en_US.UTF-8".parse::<Locale>(), Ok(Locale::En));
+    assert_eq!("zh-CN".parse::<Locale>(), Ok(Locale::Zh));
+    assert!("fr".parse::<Locale>().is_err());
+
+    let error = TokenAccountError::NonZeroBalance(42);
+    assert_eq!(error.localized(Locale::Zh), error.to_string());
+    assert_eq!(
+        error.localized(Locale::En),
+        "Account balance is not zero: 42"
+    );
+    assert_eq!(
+        TokenAccountError::FeeBudgetExceeded {
+            estimated: 10,
+            budget: 5
+        }
+        .localized(Locale::En),
+        "Estimated fees of 10 lamports exceed the budget of 5 lamports, aborted"
+    );
+    assert_eq!(FailureKind::Frozen.label(Locale::En), "Account frozen");
+    assert_eq!(FailureKind::Frozen.label(Locale::Zh), "账户已冻结");
+}
+
+#[derive(Default)]
+struct RecordingObserver {
+    events: Mutex<Vec<String>>,
+}
+
+impl RecordingObserver {
+    fn events(&self) -> Vec<String> {
+        self.events.lock().unwrap().clone()
+    }
+
+    fn record(&self, event: String) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl BatchObserver for RecordingObserver {
+    fn on_batch_start(&self, batch: usize, total_batches: usize, accounts: usize) {
+        self.record(format!("batch {}/{} ({})", batch, total_batches, accounts));
+    }
+
+    fn on_account_closed(&self, outcome: &AccountOutcome) {
+        self.record(format!("closed {}", outcome.account_address));
+    }
+
+    fn on_account_failed(&self, outcome: &AccountOutcome) {
+        self.record(format!("failed {}", outcome.account_address));
+    }
+
+    fn on_retry(&self, batch: usize, attempt: u32, _error: &TokenAccountError) {
+        self.record(format!("retry {} #{}", batch, attempt));
+    }
+
+    fn on_complete(&self, report: &BatchCloseReport) {
+        self.record(format!("complete {}/{}", report.succeeded, report.failed));
+    }
+}
+
+#[tokio::test]
+async fn batch_close_reports_progress_to_observer() {
+    let mut manager = wallet_with_empty_accounts(3);
+    let observer = Arc::new(RecordingObserver::default());
+    manager.set_observer(observer.clone());
+    let accounts = manager.get_closeable_accounts().await.unwrap().accounts;
+
+    manager
+        .batch_close_accounts(&accounts, 2, false)
+        .await
+        .unwrap();
+
+    let closed = |i: usize| format!("closed {}", accounts[i].address);
+    assert_eq!(
+        observer.events(),
+        vec![
+            "batch 1/2 (2)".to_string(),
+            closed(0),
+            closed(1),
+            "batch 2/2 (1)".to_string(),
+            closed(2),
+            "complete 3/0".to_string(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn batch_close_reports_retries_and_failures_to_observer() {
+    let mut manager = manager(MockRpc::new());
+    let observer = Arc::new(RecordingObserver::default());
+    manager.set_observer(observer.clone());
+    let accounts = [
+        account_info(&Pubkey::new_unique()),
+        account_info(&Pubkey::new_unique()),
+    ];
+    manager.connection.fail_next_sends(4);
+
+    manager
+        .batch_close_accounts(&accounts, 5, true)
+        .await
+        .unwrap();
+
+    let events = observer.events();
+    assert_eq!(events[0], "batch 1/1 (2)");
+    assert_eq!(&events[1..4], ["retry 1 #1", "retry 1 #2", "retry 1 #3"]);
+    assert_eq!(events[4], format!("failed {}", accounts[0].address));
+    assert_eq!(events[5], format!("failed {}", accounts[1].address));
+    assert_eq!(events[6], "complete 0/2");
+}
+
+#[tokio::test]
+async fn cleanup_metrics_count_scanned_closed_and_rent() {
+    let mut manager = wallet_with_empty_accounts(3);
+    let metrics = Arc::new(CleanupMetrics::default());
+    manager.set_observer(metrics.clone());
+    let accounts = manager.get_closeable_accounts().await.unwrap().accounts;
+
+    manager
+        .batch_close_accounts(&accounts, 2, false)
+        .await
+        .unwrap();
+
+    let output = metrics.render();
+    assert!(output.contains("# TYPE solana_cleanup_accounts_closed_total counter"));
+    assert!(output.contains("solana_cleanup_accounts_scanned_total 3\n"));
+    assert!(output.contains("solana_cleanup_accounts_closed_total 3\n"));
+    assert!(output.contains("solana_cleanup_accounts_burned_total 0\n"));
+    assert!(output.contains(&format!(
+        "solana_cleanup_rent_recovered_lamports_total {}\n",
+        3 * TOKEN_ACCOUNT_RENT
+    )));
+}
+
+#[tokio::test]
+async fn cleanup_metrics_count_retries_and_failures() {
+    let mut manager = manager(MockRpc::new());
+    let metrics = Arc::new(CleanupMetrics::default());
+    manager.set_observer(metrics.clone());
+    let accounts = [
+        account_info(&Pubkey::new_unique()),
+        account_info(&Pubkey::new_unique()),
+    ];
+    manager.connection.fail_next_sends(4);
+
+    manager
+        .batch_close_accounts(&accounts, 5, true)
+        .await
+        .unwrap();
+
+    let output = metrics.render();
+    assert!(output.contains("solana_cleanup_retries_total 3\n"));
+    assert!(output.contains("solana_cleanup_accounts_failed_total 2\n"));
+    assert!(output.contains("solana_cleanup_accounts_closed_total 0\n"));
+}
+
+#[tokio::test]
+async fn metrics_endpoint_serves_prometheus_text() {
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    tokio::spawn(serve_metrics(addr, Arc::new(CleanupMetrics::default())));
+
+    let request = |path: &'static str| async move {
+        let mut stream = loop {
+            match tokio::net::TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+        stream
+            .write_all(format!("GET {} HTTP/1.1\r\n\r\n", path).as_bytes())
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    };
+
+    let response = request("/metrics").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK"));
+    assert!(response.contains("solana_cleanup_accounts_scanned_total 0"));
+    assert!(request("/").await.starts_with("HTTP/1.1 404"));
+}
+
+#[derive(Default)]
+struct RecordingNotifier {
+    reports: Mutex<Vec<(String, usize, usize)>>,
+}
+
+impl Notifier for RecordingNotifier {
+    fn notify<'a>(
+        &'a self,
+        wallet: &'a str,
+        report: &'a BatchCloseReport,
+    ) -> BoxFuture<'a, utils::TokenAccountResult<()>> {
+        self.reports
+            .lock()
+            .unwrap()
+            .push((wallet.to_string(), report.succeeded, report.failed));
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[tokio::test]
+async fn batch_close_sends_report_to_notifiers() {
+    let mut manager = wallet_with_empty_accounts(2);
+    let notifier = Arc::new(RecordingNotifier::default());
+    manager.add_notifier(notifier.clone());
+    let accounts = manager.get_closeable_accounts().await.unwrap().accounts;
+
+    manager
+        .batch_close_accounts(&accounts, 5, true)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *notifier.reports.lock().unwrap(),
+        vec![(manager.wallet.pubkey().to_string(), 2, 0)]
+    );
+}
+
+#[tokio::test]
+async fn failure_only_policy_skips_successful_reports() {
+    let rpc = MockRpc::new();
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(1, Duration::ZERO),
+        notify_policy: NotifyPolicy::OnFailure,
+        ..TokenAccountConfig::default()
+    };
+    let mut manager = TokenAccountManager::with_rpc(rpc, Keypair::new(), config).unwrap();
+    let notifier = Arc::new(RecordingNotifier::default());
+    manager.add_notifier(notifier.clone());
+    let accounts = [account_info(&Pubkey::new_unique())];
+
+    let report = manager
+        .batch_close_accounts(&accounts, 5, true)
+        .await
+        .unwrap();
+    assert_eq!(report.succeeded, 1);
+    assert!(notifier.reports.lock().unwrap().is_empty());
+
+    manager.connection.fail_next_sends(2);
+    manager
+        .batch_close_accounts(&accounts, 5, true)
+        .await
+        .unwrap();
+    let reports = notifier.reports.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!((reports[0].1, reports[0].2), (0, 1));
+}
+
+#[test]
+fn report_summary_lists_failed_accounts() {
+    let account = account_info(&Pubkey::new_unique());
+    let mut report = BatchCloseReport::default();
+    report.outcomes.push(AccountOutcome::failed(
+        &account.address,
+        &account.mint,
+        "DUST",
+        &TokenAccountError::NonZeroBalance(5),
+    ));
+    report.finalize(0, 0);
+
+    let summary = report_summary("wallet", &report);
+
+    assert!(summary.contains("钱包: wallet"));
+    assert!(summary.contains("失败: 1 个账户"));
+    assert!(summary.contains(&format!("- {} (DUST): 账户余额不为 0: 5", account.address)));
+}
+
+#[tokio::test]
+async fn telegram_notifier_posts_summary_to_bot_api() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let api_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buffer = [0; 4096];
+        loop {
+            let read = stream.read(&mut buffer).await.unwrap();
+            request.extend_from_slice(&buffer[..read]);
+            let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") else {
+                continue;
+            };
+            let headers = String::from_utf8_lossy(&request[..end]).to_ascii_lowercase();
+            let length: usize = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length: "))
+                .and_then(|value| value.trim().parse().ok())
+                .unwrap_or(0);
+            if request.len() >= end + 4 + length {
+                break;
+            }
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}")
+            .await
+            .unwrap();
+        String::from_utf8(request).unwrap()
+    });
+
+    let notifier = TelegramNotifier::with_api_url(api_url, "TOKEN", "42");
+    notifier
+        .notify("wallet", &BatchCloseReport::default())
+        .await
+        .unwrap();
+
+    let request = server.await.unwrap();
+    assert!(request.starts_with("POST /botTOKEN/sendMessage "));
+    assert!(request.contains("\"chat_id\":\"42\""));
+    assert!(request.contains("钱包: wallet"));
+}
+
+#[tokio::test]
+async fn batch_close_appends_run_to_history() {
+    let path = std::env::temp_dir().join(format!("history-{}.jsonl", Pubkey::new_unique()));
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        history_path: Some(path.clone()),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(empty, token_account(&owner, &Pubkey::new_unique(), 0));
+
+    manager
+        .batch_close_accounts(&[account_info(&empty)], 5, false)
+        .await
+        .unwrap();
+    let history = RunHistory::open(&path).unwrap();
+    let runs = history.runs().unwrap();
+    let summary = history.summary_between(0, u64::MAX).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].event.operation, RunOperation::Close);
+    assert_eq!(runs[0].event.wallet, owner.to_string());
+    assert_eq!(runs[0].event.accounts_closed, 1);
+    assert_eq!(runs[0].event.signatures.len(), 1);
+    assert_eq!(summary.rent_recovered_lamports, TOKEN_ACCOUNT_RENT);
+    assert_eq!(
+        month_range(2024, 12).unwrap(),
+        (1_733_011_200, 1_735_689_600)
+    );
+    assert!(month_range(2024, 13).is_err());
+}
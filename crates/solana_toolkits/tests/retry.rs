@@ -0,0 +1,219 @@
+mod common;
+
+use common::{account_info, manager, token_account};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_toolkits::{
+    account_info::FailureKind,
+    config::TokenAccountConfig,
+    retry::{is_blockhash_expired, is_retryable, RetryPolicy},
+    TokenAccountManager,
+};
+use std::time::Duration;
+use utils::{MockRpc, TokenAccountError};
+
+#[tokio::test]
+async fn close_account_resigns_after_blockhash_expires() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager.connection.expire_next_sends(2);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn close_account_gives_up_when_blockhash_keeps_expiring() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager.connection.expire_next_sends(4);
+
+    let result = manager.close_account(&address).await;
+
+    assert!(!result.success);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn batch_close_resigns_after_blockhash_expires() {
+    let manager = manager(MockRpc::new());
+    let accounts = [
+        account_info(&Pubkey::new_unique()),
+        account_info(&Pubkey::new_unique()),
+    ];
+    manager.connection.expire_next_sends(3);
+
+    let report = manager
+        .batch_close_accounts(&accounts, 5, true)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (2, 0));
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn batch_close_report_records_failed_batch_after_retries() {
+    let manager = manager(MockRpc::new());
+    let accounts = [
+        account_info(&Pubkey::new_unique()),
+        account_info(&Pubkey::new_unique()),
+    ];
+    manager.connection.fail_next_sends(4);
+
+    let report = manager
+        .batch_close_accounts(&accounts, 5, true)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (0, 2));
+    assert!(report.signatures.is_empty());
+    assert!(report
+        .failures()
+        .all(|failure| failure.failure_kind == Some(FailureKind::Transaction)));
+}
+
+fn concurrent_manager(rpc: MockRpc) -> TokenAccountManager<MockRpc> {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        retry_policy: RetryPolicy::fixed(3, Duration::ZERO),
+        max_concurrent_transactions: 4,
+        ..TokenAccountConfig::default()
+    };
+    TokenAccountManager::with_rpc(rpc, Keypair::new(), config).unwrap()
+}
+
+#[tokio::test]
+async fn concurrent_batch_close_sends_every_batch() {
+    let manager = concurrent_manager(MockRpc::new());
+    let accounts: Vec<_> = (0..10)
+        .map(|_| account_info(&Pubkey::new_unique()))
+        .collect();
+
+    let report = manager
+        .batch_close_accounts(&accounts, 2, true)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (10, 0));
+    assert_eq!(report.signatures.len(), 5);
+    assert_eq!(manager.connection.sent_transactions().len(), 5);
+}
+
+#[tokio::test]
+async fn concurrent_batch_close_resigns_expired_transactions() {
+    let manager = concurrent_manager(MockRpc::new());
+    let accounts: Vec<_> = (0..4)
+        .map(|_| account_info(&Pubkey::new_unique()))
+        .collect();
+    manager.connection.expire_next_sends(3);
+
+    let report = manager
+        .batch_close_accounts(&accounts, 1, true)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (4, 0));
+    assert_eq!(manager.connection.sent_transactions().len(), 4);
+}
+
+#[tokio::test]
+async fn concurrent_batch_close_reports_failed_batches_after_retries() {
+    let manager = concurrent_manager(MockRpc::new());
+    let accounts = [
+        account_info(&Pubkey::new_unique()),
+        account_info(&Pubkey::new_unique()),
+    ];
+    manager.connection.fail_next_sends(8);
+
+    let report = manager
+        .batch_close_accounts(&accounts, 1, true)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (0, 2));
+    assert!(report
+        .failures()
+        .all(|failure| failure.failure_kind == Some(FailureKind::Transaction)));
+}
+
+#[test]
+fn blockhash_expiry_is_retryable() {
+    let error = TokenAccountError::TransactionError("Blockhash not found".to_string());
+    assert!(is_retryable(&error));
+    assert!(is_blockhash_expired(&error));
+    assert!(!is_blockhash_expired(&TokenAccountError::TransactionError(
+        "rate limit".to_string()
+    )));
+}
+
+#[test]
+fn retry_policy_backs_off_exponentially_up_to_max_delay() {
+    let policy = RetryPolicy {
+        initial_delay: Duration::from_millis(100),
+        max_delay: Duration::from_millis(500),
+        multiplier: 2.0,
+        jitter: 0.0,
+        ..RetryPolicy::default()
+    };
+
+    let delays: Vec<u128> = (1..=5)
+        .map(|retry| policy.delay(retry).as_millis())
+        .collect();
+
+    assert_eq!(delays, vec![100, 200, 400, 500, 500]);
+}
+
+#[test]
+fn retry_policy_gives_up_on_fatal_errors_and_elapsed_time() {
+    let policy = RetryPolicy {
+        max_elapsed: Some(Duration::from_millis(50)),
+        ..RetryPolicy::fixed(3, Duration::from_millis(10))
+    };
+    let transient = TokenAccountError::TransactionError("Blockhash not found".to_string());
+    let fatal = TokenAccountError::TransactionError(
+        "Error processing Instruction 0: insufficient funds for instruction".to_string(),
+    );
+    let started = std::time::Instant::now();
+
+    assert!(is_retryable(&transient));
+    assert!(!is_retryable(&fatal));
+    assert!(!is_retryable(&TokenAccountError::NonZeroBalance(1)));
+    assert_eq!(
+        policy.next_delay(0, started, &transient),
+        Some(Duration::from_millis(10))
+    );
+    assert_eq!(policy.next_delay(0, started, &fatal), None);
+    assert_eq!(policy.next_delay(3, started, &transient), None);
+    let expired = started - Duration::from_millis(100);
+    assert_eq!(policy.next_delay(0, expired, &transient), None);
+}
+
+#[tokio::test]
+async fn batch_close_does_not_retry_failed_simulations() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &Pubkey::new_unique(), 0));
+    manager.connection.fail_next_simulations(1);
+
+    let report = manager
+        .batch_close_accounts(&[account_info(&address)], 5, true)
+        .await
+        .unwrap();
+
+    assert_eq!(report.failed, 1);
+    assert_eq!(manager.connection.simulated_transactions().len(), 1);
+    assert!(manager.connection.sent_transactions().is_empty());
+}
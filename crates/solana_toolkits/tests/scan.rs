@@ -0,0 +1,455 @@
+mod common;
+
+use common::{manager, unix_now, TOKEN_ACCOUNT_RENT};
+use futures::StreamExt;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_toolkits::{
+    account_info::{ScanItem, TokenAccountsResult},
+    config::TokenAccountConfig,
+    filter::{Regex, ScanFilter},
+    metadata::TokenMetadata,
+    spam::{SpamHeuristics, SpamSignal},
+    TokenAccountManager,
+};
+use spl_associated_token_account::get_associated_token_address;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use utils::MockRpc;
+
+#[tokio::test]
+async fn get_closeable_accounts_flags_suspected_spam() {
+    let config = TokenAccountConfig {
+        spam_heuristics: Some(SpamHeuristics {
+            check_liquidity: false,
+            ..SpamHeuristics::default()
+        }),
+        ..TokenAccountConfig::default()
+    };
+    let mut manager =
+        TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    manager.add_symbols_to_whitelist(&["SAFE"]);
+    let owner = manager.wallet.pubkey();
+    let freezable = Pubkey::new_unique();
+    let phishing = Pubkey::new_unique();
+    let locked = Pubkey::new_unique();
+    let whitelisted = Pubkey::new_unique();
+    for mint in [&freezable, &phishing, &locked, &whitelisted] {
+        manager.connection.add_token_account(
+            &owner,
+            &Pubkey::new_unique(),
+            mint,
+            1_000,
+            TOKEN_ACCOUNT_RENT,
+        );
+    }
+    let authority = Pubkey::new_unique().to_string();
+    manager.metadata_cache().insert_all([
+        TokenMetadata {
+            freeze_authority: Some(authority.clone()),
+            ..cached_metadata(&freezable, "FRZ", unix_now())
+        },
+        TokenMetadata {
+            uri: "https://claim-solana-airdrop.xyz/meta.json".to_string(),
+            is_mutable: true,
+            ..cached_metadata(&phishing, "GIFT", unix_now())
+        },
+        TokenMetadata {
+            uri: "https://claim-solana-airdrop.xyz/meta.json".to_string(),
+            is_mutable: false,
+            ..cached_metadata(&locked, "LOCK", unix_now())
+        },
+        TokenMetadata {
+            freeze_authority: Some(authority.clone()),
+            ..cached_metadata(&whitelisted, "SAFE", unix_now())
+        },
+    ]);
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    let spam = &result.suspected_spam_accounts_list;
+    assert_eq!(spam.len(), 2);
+    let signals_for = |mint: &Pubkey| {
+        spam.iter()
+            .find(|spam| spam.account.mint == mint.to_string())
+            .map(|spam| spam.signals.clone())
+    };
+    assert_eq!(
+        signals_for(&freezable),
+        Some(vec![SpamSignal::FreezeAuthority(authority)])
+    );
+    assert!(matches!(
+        signals_for(&phishing).as_deref(),
+        Some([SpamSignal::PhishingUri(_)])
+    ));
+    assert_eq!(result.zero_value_accounts_list.len(), 1);
+    assert_eq!(result.zero_value_accounts_list[0].symbol, "LOCK");
+    assert_eq!(result.whitelisted_accounts_list.len(), 1);
+    assert_eq!(result.suspected_spam_tokens().len(), 2);
+}
+
+#[test]
+fn spam_heuristics_match_phishing_domains() {
+    let heuristics = SpamHeuristics {
+        phishing_domains: vec!["scam.example".to_string()],
+        ..SpamHeuristics::default()
+    };
+
+    assert!(heuristics.is_phishing_uri("https://app.scam.example/token.json"));
+    assert!(heuristics.is_phishing_uri("https://SOL-Rewards.io:8443/x"));
+    assert!(!heuristics.is_phishing_uri("https://notscam.example/token.json"));
+    assert!(!heuristics.is_phishing_uri("https://arweave.net/abc"));
+    assert!(!heuristics.is_phishing_uri(""));
+}
+
+#[tokio::test]
+async fn get_closeable_accounts_lists_frozen_accounts_separately() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let frozen = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &frozen,
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager.connection.freeze_token_account(&owner, &frozen);
+    manager.connection.add_token_account(
+        &owner,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.closable_accounts, 1);
+    assert_eq!(result.frozen_accounts_list.len(), 1);
+    assert_eq!(result.frozen_accounts_list[0].address, frozen.to_string());
+    assert_eq!(result.total_rent_lamports, TOKEN_ACCOUNT_RENT);
+}
+
+#[tokio::test]
+async fn get_closeable_accounts_counts_empty_accounts() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    let funded = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &empty,
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager.connection.add_token_account(
+        &owner,
+        &funded,
+        &Pubkey::new_unique(),
+        1_000,
+        TOKEN_ACCOUNT_RENT,
+    );
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.total_accounts, 2);
+    assert_eq!(result.closable_accounts, 1);
+    assert_eq!(result.accounts[0].address, empty.to_string());
+    assert_eq!(result.total_rent_lamports, TOKEN_ACCOUNT_RENT * 2);
+}
+
+#[tokio::test]
+async fn scan_closeable_accounts_streams_items_page_by_page() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    for amount in [0, 0, 0, 1_000, 0] {
+        manager.connection.add_token_account(
+            &owner,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            amount,
+            TOKEN_ACCOUNT_RENT,
+        );
+    }
+
+    let items: Vec<ScanItem> = manager
+        .scan_closeable_accounts(2)
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 5);
+    let closeable = items
+        .iter()
+        .filter(|item| matches!(item, ScanItem::Closeable(_)))
+        .count();
+    assert_eq!(closeable, 4);
+    assert_eq!(
+        closeable,
+        manager
+            .get_closeable_accounts()
+            .await
+            .unwrap()
+            .closable_accounts
+    );
+}
+
+#[tokio::test]
+async fn scan_closeable_accounts_yields_error_when_listing_fails() {
+    let manager = manager(MockRpc::new());
+    manager.connection.set_rate_limited(true);
+
+    let items: Vec<_> = manager.scan_closeable_accounts(10).collect().await;
+
+    assert_eq!(items.len(), 1);
+    assert!(items[0].is_err());
+}
+
+fn filtered_manager(
+    rpc: MockRpc,
+    wallet: Keypair,
+    scan_filter: ScanFilter,
+) -> TokenAccountManager<MockRpc> {
+    let config = TokenAccountConfig {
+        batch_delay: Duration::ZERO,
+        scan_filter,
+        ..TokenAccountConfig::default()
+    };
+    TokenAccountManager::with_rpc(rpc, wallet, config).unwrap()
+}
+
+#[tokio::test]
+async fn scan_filter_applies_mint_rent_and_associated_conditions() {
+    let rpc = MockRpc::new();
+    let wallet = Keypair::new();
+    let owner = wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let excluded_mint = Pubkey::new_unique();
+    let associated = get_associated_token_address(&owner, &mint);
+    rpc.add_token_account(&owner, &associated, &mint, 0, TOKEN_ACCOUNT_RENT);
+    rpc.add_token_account(&owner, &Pubkey::new_unique(), &mint, 0, TOKEN_ACCOUNT_RENT);
+    rpc.add_token_account(
+        &owner,
+        &get_associated_token_address(&owner, &excluded_mint),
+        &excluded_mint,
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    let low_rent_mint = Pubkey::new_unique();
+    rpc.add_token_account(
+        &owner,
+        &get_associated_token_address(&owner, &low_rent_mint),
+        &low_rent_mint,
+        0,
+        1_000,
+    );
+    let manager = filtered_manager(
+        rpc,
+        wallet,
+        ScanFilter {
+            exclude_mints: vec![excluded_mint.to_string()],
+            min_rent_lamports: Some(TOKEN_ACCOUNT_RENT),
+            only_associated: true,
+            ..ScanFilter::default()
+        },
+    );
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.total_accounts, 4);
+    assert_eq!(result.closable_accounts, 1);
+    assert_eq!(result.accounts[0].address, associated.to_string());
+    assert_eq!(result.total_rent_lamports, TOKEN_ACCOUNT_RENT);
+}
+
+#[tokio::test]
+async fn scan_filter_keeps_accounts_created_before_cutoff() {
+    let rpc = MockRpc::new();
+    let wallet = Keypair::new();
+    let owner = wallet.pubkey();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    let old = Pubkey::new_unique();
+    let recent = Pubkey::new_unique();
+    let unknown = Pubkey::new_unique();
+    for address in [old, recent, unknown] {
+        rpc.add_token_account(
+            &owner,
+            &address,
+            &Pubkey::new_unique(),
+            0,
+            TOKEN_ACCOUNT_RENT,
+        );
+    }
+    rpc.add_address_signature(old, now - 200 * 24 * 3600);
+    rpc.add_address_signature(old, now - 3600);
+    rpc.add_address_signature(recent, now - 10 * 24 * 3600);
+    let manager = filtered_manager(
+        rpc,
+        wallet,
+        ScanFilter::default().older_than(Duration::from_secs(90 * 24 * 3600)),
+    );
+
+    let items: Vec<ScanItem> = manager
+        .scan_closeable_accounts(10)
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].address(), old.to_string());
+}
+
+#[tokio::test]
+async fn scan_filter_matches_symbol_pattern() {
+    let rpc = MockRpc::new();
+    let wallet = Keypair::new();
+    rpc.add_token_account(
+        &wallet.pubkey(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    let manager = filtered_manager(
+        rpc,
+        wallet,
+        ScanFilter {
+            symbol_pattern: Some(Regex::new("(?i)airdrop").unwrap()),
+            ..ScanFilter::default()
+        },
+    );
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.total_accounts, 1);
+    assert_eq!(result.closable_accounts, 0);
+}
+
+#[tokio::test]
+async fn scan_result_exports_csv_and_json() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let empty = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &empty,
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    let mut result = manager.get_closeable_accounts().await.unwrap();
+    result.accounts[0].symbol = "A,\"B\"".to_string();
+
+    let mut csv = Vec::new();
+    result.write_csv(&mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("category,address,mint,symbol"));
+    assert!(lines[1].starts_with(&format!("closeable,{},", empty)));
+    assert!(lines[1].contains(",\"A,\"\"B\"\"\",0,,,"));
+
+    let path = std::env::temp_dir().join(format!("scan-{}.json", Pubkey::new_unique()));
+    result.export_json(&path).unwrap();
+    let loaded: TokenAccountsResult =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.closable_accounts, 1);
+    assert_eq!(loaded.accounts[0].address, empty.to_string());
+    assert_eq!(loaded.total_rent_lamports, TOKEN_ACCOUNT_RENT);
+}
+
+#[tokio::test]
+async fn get_closeable_accounts_lists_wrapped_sol_separately() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let wrapped = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &wrapped,
+        &spl_token::native_mint::id(),
+        5_000_000,
+        TOKEN_ACCOUNT_RENT + 5_000_000,
+    );
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert!(result.zero_value_accounts_list.is_empty());
+    assert_eq!(result.wrapped_sol_accounts_list.len(), 1);
+    assert_eq!(result.wrapped_sol_accounts_list[0].balance, 5_000_000);
+    assert_eq!(
+        result.wrapped_sol_accounts_list[0].rent_lamports,
+        TOKEN_ACCOUNT_RENT
+    );
+    assert_eq!(result.total_rent_lamports, TOKEN_ACCOUNT_RENT);
+}
+
+#[tokio::test]
+async fn get_closeable_accounts_lists_foreign_close_authority_separately() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let foreign = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &foreign,
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager
+        .connection
+        .set_close_authority(&owner, &foreign, &Pubkey::new_unique());
+    let own = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &own,
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager.connection.set_close_authority(&owner, &own, &owner);
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.closable_accounts, 1);
+    assert_eq!(result.accounts[0].address, own.to_string());
+    assert_eq!(result.no_authority_accounts_list.len(), 1);
+    assert_eq!(
+        result.no_authority_accounts_list[0].address,
+        foreign.to_string()
+    );
+    assert_eq!(result.total_rent_lamports, TOKEN_ACCOUNT_RENT);
+}
+
+#[tokio::test]
+async fn scan_flags_auxiliary_accounts_and_keeps_atas_when_only_auxiliary() {
+    let rpc = MockRpc::new();
+    let wallet = Keypair::new();
+    let owner = wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let associated = get_associated_token_address(&owner, &mint);
+    rpc.add_token_account(&owner, &associated, &mint, 0, TOKEN_ACCOUNT_RENT);
+    let auxiliary = Pubkey::new_unique();
+    rpc.add_token_account(&owner, &auxiliary, &mint, 0, TOKEN_ACCOUNT_RENT);
+
+    let manager = filtered_manager(rpc, wallet, ScanFilter::default());
+    let result = manager.get_closeable_accounts().await.unwrap();
+    assert_eq!(result.closable_accounts, 2);
+    assert_eq!(result.auxiliary_accounts(), vec![auxiliary.to_string()]);
+
+    let manager = filtered_manager(
+        manager.connection,
+        manager.wallet,
+        ScanFilter {
+            only_auxiliary: true,
+            ..ScanFilter::default()
+        },
+    );
+    let result = manager.get_closeable_accounts().await.unwrap();
+    assert_eq!(result.closable_accounts, 1);
+    assert_eq!(result.accounts[0].address, auxiliary.to_string());
+    assert!(!result.accounts[0].is_associated);
+}
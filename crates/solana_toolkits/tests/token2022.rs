@@ -0,0 +1,225 @@
+mod common;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use common::{manager, metadata_account, TOKEN_ACCOUNT_RENT};
+use mpl_token_metadata::accounts::Metadata;
+use solana_sdk::{account::Account, message::VersionedMessage, pubkey::Pubkey, signer::Signer};
+use solana_toolkits::offline::OfflineTransactionFile;
+use spl_token::state::AccountState;
+use spl_token_2022::{
+    extension::{
+        mint_close_authority::MintCloseAuthority,
+        transfer_fee::{instruction::harvest_withheld_tokens_to_mint, TransferFeeAmount},
+        ExtensionType, StateWithExtensionsMut,
+    },
+    state::{Account as Token2022Account, Mint as Token2022Mint},
+};
+use utils::MockRpc;
+
+#[tokio::test]
+async fn get_closeable_accounts_includes_token_2022_accounts() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let legacy = Pubkey::new_unique();
+    let token_2022 = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &legacy,
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager.connection.add_token_account_with_program(
+        &owner,
+        &token_2022,
+        &Pubkey::new_unique(),
+        0,
+        TOKEN_ACCOUNT_RENT,
+        &spl_token_2022::id(),
+    );
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.total_accounts, 2);
+    assert_eq!(result.closable_accounts, 2);
+    let programs: Vec<&str> = result
+        .accounts
+        .iter()
+        .map(|account| account.program_id.as_str())
+        .collect();
+    assert!(programs.contains(&spl_token::id().to_string().as_str()));
+    assert!(programs.contains(&spl_token_2022::id().to_string().as_str()));
+}
+
+#[tokio::test]
+async fn close_account_supports_token_2022_accounts() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    let account = Account {
+        owner: spl_token_2022::id(),
+        ..token_account(&owner, &Pubkey::new_unique(), 0)
+    };
+    manager.connection.add_account(address, account);
+
+    let details = manager.get_account_details(&address).await.unwrap();
+    let result = manager.close_account(&address).await;
+
+    assert_eq!(details.program_id, spl_token_2022::id().to_string());
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+fn token_2022_account_with_withheld_fee(owner: &Pubkey, mint: &Pubkey, withheld: u64) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<Token2022Account>(&[
+        ExtensionType::TransferFeeAmount,
+    ])
+    .unwrap();
+    let mut data = vec![0; len];
+    let mut state =
+        StateWithExtensionsMut::<Token2022Account>::unpack_uninitialized(&mut data).unwrap();
+    state.base = Token2022Account {
+        mint: *mint,
+        owner: *owner,
+        state: spl_token_2022::state::AccountState::Initialized,
+        ..Token2022Account::default()
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    state
+        .init_extension::<TransferFeeAmount>(true)
+        .unwrap()
+        .withheld_amount = withheld.into();
+
+    Account {
+        lamports: TOKEN_ACCOUNT_RENT,
+        data,
+        owner: spl_token_2022::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn token_2022_mint_with_close_authority(decimals: u8) -> Account {
+    let len = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+        ExtensionType::MintCloseAuthority,
+    ])
+    .unwrap();
+    let mut data = vec![0; len];
+    let mut state =
+        StateWithExtensionsMut::<Token2022Mint>::unpack_uninitialized(&mut data).unwrap();
+    state.base = Token2022Mint {
+        decimals,
+        is_initialized: true,
+        ..Token2022Mint::default()
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    state.init_extension::<MintCloseAuthority>(true).unwrap();
+
+    Account {
+        lamports: 1_461_600,
+        data,
+        owner: spl_token_2022::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn metadata_lookup_supports_token_2022_mints_with_extensions() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(mint, token_2022_mint_with_close_authority(9));
+    manager
+        .connection
+        .add_account(Metadata::find_pda(&mint).0, metadata_account(&mint, "EXT"));
+    manager.connection.add_token_account_with_program(
+        &owner,
+        &Pubkey::new_unique(),
+        &mint,
+        1_000,
+        TOKEN_ACCOUNT_RENT,
+        &spl_token_2022::id(),
+    );
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.zero_value_accounts_list.len(), 1);
+    assert_eq!(result.zero_value_accounts_list[0].symbol, "EXT");
+    assert_eq!(result.zero_value_accounts_list[0].decimals, 9);
+    let cached = manager.metadata_cache().get(&mint.to_string()).unwrap();
+    assert_eq!(cached.decimals, 9);
+}
+
+#[tokio::test]
+async fn close_account_harvests_withheld_transfer_fees() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let address = Pubkey::new_unique();
+    manager.connection.add_account(
+        address,
+        token_2022_account_with_withheld_fee(&owner, &Pubkey::new_unique(), 7),
+    );
+
+    let details = manager.get_account_details(&address).await.unwrap();
+    assert_eq!(details.withheld_amount, 7);
+    assert_eq!(details.program_id, spl_token_2022::id().to_string());
+
+    let result = manager.close_account(&address).await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn batch_close_harvests_withheld_transfer_fees_recorded_by_scan() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager.connection.add_token_account_with_program(
+        &owner,
+        &address,
+        &mint,
+        0,
+        TOKEN_ACCOUNT_RENT,
+        &spl_token_2022::id(),
+    );
+    manager.connection.set_withheld_amount(&owner, &address, 7);
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+    assert_eq!(result.accounts.len(), 1);
+    assert_eq!(result.accounts[0].withheld_amount, 7);
+
+    let path = std::env::temp_dir().join(format!("harvest-{}.json", Pubkey::new_unique()));
+    manager
+        .export_unsigned_close_transactions(&result.accounts, 5, &path)
+        .unwrap();
+
+    let file = OfflineTransactionFile::load(&path).unwrap();
+    let bytes = STANDARD.decode(&file.transactions[0].message).unwrap();
+    let message: VersionedMessage = bincode::deserialize(&bytes).unwrap();
+    let keys = message.static_account_keys();
+    let token_instructions: Vec<_> = message
+        .instructions()
+        .iter()
+        .filter(|ix| keys[ix.program_id_index as usize] == spl_token_2022::id())
+        .map(|ix| ix.data.clone())
+        .collect();
+    let harvest =
+        harvest_withheld_tokens_to_mint(&spl_token_2022::id(), &mint, &[&address]).unwrap();
+    let close = spl_token_2022::instruction::close_account(
+        &spl_token_2022::id(),
+        &address,
+        &owner,
+        &owner,
+        &[&owner],
+    )
+    .unwrap();
+    assert_eq!(token_instructions, vec![harvest.data, close.data]);
+    let _ = std::fs::remove_file(path);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::{manager, mint_account, token_account, TOKEN_ACCOUNT_RENT};
+use solana_sdk::{native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signer::Signer};
+use solana_toolkits::account_info::ZeroValueTokenInfo;
+use spl_associated_token_account::get_associated_token_address;
+use utils::{MockRpc, TokenAccountError};
+
+#[tokio::test]
+async fn sweep_and_close_transfers_balance_in_one_transaction() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager.connection.add_account(mint, mint_account(6));
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &mint, 1_500));
+
+    let result = manager
+        .sweep_and_close_account(&address, &Pubkey::new_unique())
+        .await;
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.swept_amount, 1_500);
+    assert!(result.destination_account.is_some());
+    assert_eq!(manager.connection.sent_transactions().len(), 1);
+}
+
+#[tokio::test]
+async fn batch_sweep_and_close_records_missing_mint_as_failure() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &mint, 10));
+    let accounts = [ZeroValueTokenInfo {
+        address: address.to_string(),
+        mint: mint.to_string(),
+        balance: 10,
+        decimals: 6,
+        is_nft: false,
+        rent_lamports: TOKEN_ACCOUNT_RENT,
+        rent_sol: TOKEN_ACCOUNT_RENT as f64 / 1e9,
+        symbol: "DUST".to_string(),
+        program_id: spl_token::id().to_string(),
+        is_associated: false,
+        decimals_verified: true,
+    }];
+
+    let report = manager
+        .batch_sweep_and_close(&accounts, &Pubkey::new_unique(), 5)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (0, 1));
+    assert!(manager.connection.sent_transactions().is_empty());
+}
+
+#[tokio::test]
+async fn creates_associated_token_accounts_and_wraps_sol() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    manager.connection.add_account(mint, mint_account(6));
+
+    let created = manager.create_ata_idempotent(&mint).await.unwrap();
+    assert_eq!(
+        created.address,
+        get_associated_token_address(&owner, &mint).to_string()
+    );
+    assert_eq!(created.program_id, spl_token::id().to_string());
+    assert!(created.signature.is_some());
+
+    let wrapped = manager.wrap_sol(LAMPORTS_PER_SOL / 2).await.unwrap();
+    assert_eq!(
+        wrapped.address,
+        get_associated_token_address(&owner, &spl_token::native_mint::id()).to_string()
+    );
+    assert_eq!(wrapped.wrapped_lamports, LAMPORTS_PER_SOL / 2);
+    assert!(manager.wrap_sol(0).await.is_err());
+    assert!(manager.create_ata(&owner).await.is_err());
+    assert_eq!(manager.connection.sent_transactions().len(), 2);
+}
+
+#[tokio::test]
+async fn transfer_tokens_creates_missing_destination_ata() {
+    let manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    manager.connection.add_account(mint, mint_account(6));
+    manager.connection.add_account(
+        get_associated_token_address(&owner, &mint),
+        token_account(&owner, &mint, 1_000),
+    );
+
+    let first = manager
+        .transfer_tokens(&mint, &recipient, 400)
+        .await
+        .unwrap();
+    let destination = get_associated_token_address(&recipient, &mint);
+    assert_eq!(first.destination_account, destination.to_string());
+    assert_eq!(first.decimals, 6);
+    assert!(first.ata_created);
+
+    manager
+        .connection
+        .add_account(destination, token_account(&recipient, &mint, 400));
+    let second = manager
+        .transfer_tokens(&mint, &recipient, 600)
+        .await
+        .unwrap();
+    assert!(!second.ata_created);
+
+    let insufficient = manager.transfer_tokens(&mint, &recipient, 1_001).await;
+    assert!(matches!(
+        insufficient,
+        Err(TokenAccountError::SplTokenError(
+            spl_token::error::TokenError::InsufficientFunds
+        ))
+    ));
+    assert_eq!(manager.connection.sent_transactions().len(), 2);
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use common::{cached_metadata, manager, mint_account, token_account, unix_now, TOKEN_ACCOUNT_RENT};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+use solana_toolkits::{config::TokenAccountConfig, whitelist::TokenWhitelist, TokenAccountManager};
+use utils::MockRpc;
+
+#[test]
+fn whitelist_round_trips_between_toml_and_json_files() {
+    let toml_path = std::env::temp_dir().join(format!("whitelist-{}.toml", Pubkey::new_unique()));
+    let json_path = toml_path.with_extension("json");
+    let mint = Pubkey::new_unique().to_string();
+    std::fs::write(
+        &toml_path,
+        format!(
+            "# 长期持有的代币\nmerge_default = false\nsymbols = [\"jup\", {{ value = \"RAY\", comment = \"流动性挖矿\" }}]\nmints = [\"{}\"]\n",
+            mint
+        ),
+    )
+    .unwrap();
+
+    let whitelist = TokenWhitelist::from_file(&toml_path).unwrap();
+    whitelist.save_to_file(&json_path).unwrap();
+    let reloaded = TokenWhitelist::from_file(&json_path).unwrap();
+    let saved = std::fs::read_to_string(&json_path).unwrap();
+    std::fs::remove_file(&toml_path).unwrap();
+    std::fs::remove_file(&json_path).unwrap();
+
+    assert!(reloaded.is_whitelisted("JUP", "unknown"));
+    assert!(reloaded.is_whitelisted("ray", "unknown"));
+    assert!(reloaded.is_whitelisted("DUST", &mint));
+    assert!(!reloaded.is_whitelisted("USDC", "unknown"));
+    assert!(saved.contains("流动性挖矿"));
+}
+
+#[tokio::test]
+async fn manager_loads_whitelist_from_configured_path() {
+    let path = std::env::temp_dir().join(format!("whitelist-{}.json", Pubkey::new_unique()));
+    std::fs::write(&path, r#"{ "symbols": ["DUST"] }"#).unwrap();
+    let config = TokenAccountConfig {
+        whitelist_path: Some(path.clone()),
+        ..TokenAccountConfig::default()
+    };
+    let manager = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let mint = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &manager.wallet.pubkey(),
+        &Pubkey::new_unique(),
+        &mint,
+        1_000,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager
+        .metadata_cache()
+        .insert_all([cached_metadata(&mint, "DUST", unix_now())]);
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert!(result.zero_value_accounts_list.is_empty());
+    assert_eq!(result.whitelisted_accounts_list.len(), 1);
+}
+
+#[test]
+fn manager_rejects_unreadable_whitelist_file() {
+    let path = std::env::temp_dir().join(format!("whitelist-{}.json", Pubkey::new_unique()));
+    std::fs::write(&path, "not json").unwrap();
+    let config = TokenAccountConfig {
+        whitelist_path: Some(path.clone()),
+        ..TokenAccountConfig::default()
+    };
+
+    let result = TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn blacklisted_mint_overrides_whitelisted_symbol() {
+    let mut manager = manager(MockRpc::new());
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    manager.connection.add_token_account(
+        &owner,
+        &Pubkey::new_unique(),
+        &mint,
+        1_000,
+        TOKEN_ACCOUNT_RENT,
+    );
+    manager
+        .metadata_cache()
+        .insert_all([cached_metadata(&mint, "USDC", unix_now())]);
+    manager.add_mint_to_blacklist(&mint.to_string());
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert!(manager.is_token_blacklisted(&mint.to_string()));
+    assert!(!manager.is_token_whitelisted("USDC", &mint.to_string()));
+    assert!(result.whitelisted_accounts_list.is_empty());
+    assert_eq!(result.zero_value_accounts_list.len(), 1);
+    assert_eq!(result.zero_value_accounts_list[0].symbol, "USDC");
+}
+
+#[tokio::test]
+async fn blacklisted_mint_without_metadata_uses_parsed_decimals() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        ..TokenAccountConfig::default()
+    };
+    let mut manager =
+        TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager.connection.add_account(mint, mint_account(6));
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &mint, 1_000));
+    manager
+        .connection
+        .add_token_account(&owner, &address, &mint, 1_000, TOKEN_ACCOUNT_RENT);
+    manager
+        .connection
+        .set_token_account_decimals(&owner, &address, 6);
+    manager.add_mint_to_blacklist(&mint.to_string());
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.zero_value_accounts_list.len(), 1);
+    assert_eq!(result.zero_value_accounts_list[0].symbol, "unknown");
+    assert_eq!(result.zero_value_accounts_list[0].decimals, 6);
+
+    let report = manager
+        .batch_burn_and_close_zero_value_accounts(&result.zero_value_accounts_list, 5)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (1, 0));
+}
+
+#[tokio::test]
+async fn blacklisted_mint_without_decimals_burns_with_on_chain_decimals() {
+    let config = TokenAccountConfig {
+        dry_run: true,
+        ..TokenAccountConfig::default()
+    };
+    let mut manager =
+        TokenAccountManager::with_rpc(MockRpc::new(), Keypair::new(), config).unwrap();
+    let owner = manager.wallet.pubkey();
+    let mint = Pubkey::new_unique();
+    let address = Pubkey::new_unique();
+    manager.connection.add_account(mint, mint_account(9));
+    manager
+        .connection
+        .add_account(address, token_account(&owner, &mint, 1_000));
+    manager
+        .connection
+        .add_token_account(&owner, &address, &mint, 1_000, TOKEN_ACCOUNT_RENT);
+    manager.add_mint_to_blacklist(&mint.to_string());
+
+    let result = manager.get_closeable_accounts().await.unwrap();
+
+    assert_eq!(result.zero_value_accounts_list.len(), 1);
+    assert!(!result.zero_value_accounts_list[0].decimals_verified);
+    assert_eq!(result.zero_value_accounts_list[0].expected_decimals(), None);
+
+    let report = manager
+        .batch_burn_and_close_zero_value_accounts(&result.zero_value_accounts_list, 5)
+        .await
+        .unwrap();
+
+    assert_eq!((report.succeeded, report.failed), (1, 0));
+}
@@ -0,0 +1,15 @@
+"""扫描并关闭零余额代币账户的示例"""
+import os
+
+from solana_toolkits_py import TokenAccountManager
+
+manager = TokenAccountManager(os.environ["WALLET_PATH"])
+manager.add_symbols_to_whitelist(["JUP", "RAY"])
+
+result = manager.scan()
+print(f"可关闭账户: {result.closable_accounts}, 可回收租金: {result.total_rent_sol:.6f} SOL")
+
+for account in result.accounts:
+    print(account.symbol, account.address, account.rent_sol)
+
+manager.batch_close(batch_size=5)
@@ -0,0 +1,175 @@
+//! # solana_toolkits 的 Python 绑定
+//!
+//! 基于 pyo3 将 `TokenAccountManager` 的扫描、关闭、销毁功能和结果类型导出到 Python，
+//! 避免在 Python 脚本中重复实现关闭逻辑。
+//!
+//! 构建:
+//! ```bash
+//! cd crates/solana_toolkits_py && maturin develop --release
+//! ```
+use pyo3::{exceptions::PyRuntimeError, prelude::*};
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use solana_toolkits::TokenAccountManager;
+use std::str::FromStr;
+use tokio::runtime::Runtime;
+
+mod report;
+
+use report::*;
+
+/// -- 将错误转换为 Python 异常
+fn to_py_err<E: std::fmt::Display>(e: E) -> PyErr {
+    PyRuntimeError::new_err(e.to_string())
+}
+
+/// -- 代币账户管理器（Python）
+///
+/// 内部持有 tokio 运行时，所有方法以同步方式调用并在执行期间释放 GIL
+#[pyclass(name = "TokenAccountManager")]
+pub struct PyTokenAccountManager {
+    inner: TokenAccountManager,
+    runtime: Runtime,
+}
+
+#[pymethods]
+impl PyTokenAccountManager {
+    /// -- 创建代币账户管理器
+    ///
+    /// # 参数
+    /// * `wallet_key_path` - 钱包密钥文件路径
+    #[new]
+    fn new(wallet_key_path: &str) -> PyResult<Self> {
+        let _ = utils::load_env();
+        let inner = TokenAccountManager::new(wallet_key_path).map_err(to_py_err)?;
+        let runtime = Runtime::new().map_err(to_py_err)?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// -- 钱包地址
+    #[getter]
+    fn wallet(&self) -> String {
+        self.inner.wallet.pubkey().to_string()
+    }
+
+    /// -- 批量添加代币符号到白名单
+    fn add_symbols_to_whitelist(&mut self, symbols: Vec<String>) {
+        let symbols: Vec<&str> = symbols.iter().map(String::as_str).collect();
+        self.inner.add_symbols_to_whitelist(&symbols);
+    }
+
+    /// -- 批量添加 Mint 地址到白名单
+    fn add_mints_to_whitelist(&mut self, mints: Vec<String>) {
+        let mints: Vec<&str> = mints.iter().map(String::as_str).collect();
+        self.inner.add_mints_to_whitelist(&mints);
+    }
+
+    /// -- 批量添加 Mint 地址到黑名单
+    fn add_mints_to_blacklist(&mut self, mints: Vec<String>) {
+        let mints: Vec<&str> = mints.iter().map(String::as_str).collect();
+        self.inner.add_mints_to_blacklist(&mints);
+    }
+
+    /// -- 扫描可关闭账户和零值代币账户
+    fn scan(&self, py: Python<'_>) -> PyResult<PyTokenAccountsResult> {
+        py.allow_threads(|| {
+            self.runtime
+                .block_on(self.inner.get_closeable_accounts())
+                .map(|result| PyTokenAccountsResult::from(&result))
+                .map_err(to_py_err)
+        })
+    }
+
+    /// -- 关闭单个零余额账户
+    ///
+    /// # 参数
+    /// * `address` - 代币账户地址
+    fn close_account(&self, py: Python<'_>, address: &str) -> PyResult<PyClosureResult> {
+        let pubkey = Pubkey::from_str(address).map_err(to_py_err)?;
+        Ok(py.allow_threads(|| {
+            self.runtime
+                .block_on(self.inner.close_account(&pubkey))
+                .into()
+        }))
+    }
+
+    /// -- 扫描并批量关闭所有零余额账户
+    ///
+    /// # 参数
+    /// * `batch_size` - 每批处理的账户数量
+    /// * `use_batch_tx` - 是否将一批账户合并为一笔交易
+    #[pyo3(signature = (batch_size = 5, use_batch_tx = false))]
+    fn batch_close(
+        &self,
+        py: Python<'_>,
+        batch_size: usize,
+        use_batch_tx: bool,
+    ) -> PyResult<PyBatchCloseReport> {
+        py.allow_threads(|| {
+            self.runtime.block_on(async {
+                let accounts = self.inner.get_closeable_accounts().await?;
+                self.inner
+                    .batch_close_accounts(&accounts.accounts, batch_size, use_batch_tx)
+                    .await
+            })
+        })
+        .map(Into::into)
+        .map_err(to_py_err)
+    }
+
+    /// -- 销毁单个账户中的代币并关闭账户
+    ///
+    /// # 参数
+    /// * `address` - 代币账户地址
+    fn burn_and_close(&self, py: Python<'_>, address: &str) -> PyResult<PyBurnAndCloseResult> {
+        let pubkey = Pubkey::from_str(address).map_err(to_py_err)?;
+        Ok(py.allow_threads(|| {
+            self.runtime
+                .block_on(self.inner.burn_and_close_account(&pubkey))
+                .into()
+        }))
+    }
+
+    /// -- 扫描并批量销毁、关闭所有零值代币账户
+    ///
+    /// # 参数
+    /// * `batch_size` - 每批处理的账户数量
+    #[pyo3(signature = (batch_size = 5))]
+    fn batch_burn_and_close(
+        &self,
+        py: Python<'_>,
+        batch_size: usize,
+    ) -> PyResult<PyBatchCloseReport> {
+        py.allow_threads(|| {
+            self.runtime.block_on(async {
+                let accounts = self
+                    .inner
+                    .get_closeable_accounts()
+                    .await
+                    .map_err(|e| e.to_string())?;
+                self.inner
+                    .batch_burn_and_close_zero_value_accounts(
+                        &accounts.zero_value_accounts_list,
+                        batch_size,
+                    )
+                    .await
+                    .map_err(|e| e.to_string())
+            })
+        })
+        .map(Into::into)
+        .map_err(to_py_err)
+    }
+}
+
+/// -- Python 模块定义
+#[pymodule]
+fn solana_toolkits_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyTokenAccountManager>()?;
+    m.add_class::<PyTokenAccountInfo>()?;
+    m.add_class::<PyZeroValueTokenInfo>()?;
+    m.add_class::<PyTokenAccountsResult>()?;
+    m.add_class::<PyClosureResult>()?;
+    m.add_class::<PyBurnAndCloseResult>()?;
+    m.add_class::<PyAccountOutcome>()?;
+    m.add_class::<PyBatchCloseReport>()?;
+    Ok(())
+}
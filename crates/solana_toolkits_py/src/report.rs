@@ -0,0 +1,298 @@
+use pyo3::prelude::*;
+use solana_toolkits::account_info::{
+    AccountOutcome, BatchCloseReport, BurnAndCloseResult, ClosureResult, TokenAccountInfo,
+    TokenAccountsResult, ZeroValueTokenInfo,
+};
+
+/// -- 可关闭代币账户信息
+#[pyclass(name = "TokenAccountInfo", get_all)]
+#[derive(Debug, Clone)]
+pub struct PyTokenAccountInfo {
+    pub address: String,     // -- 账户地址
+    pub mint: String,        // -- 代币的 Mint 地址
+    pub rent_lamports: u64,  // -- 租金（以 lamports 为单位）
+    pub rent_sol: f64,       // -- 租金（以 SOL 为单位）
+    pub symbol: String,      // -- 代币符号
+    pub program_id: String,  // -- 所属代币程序 ID
+    pub is_associated: bool, // -- 是否为钱包的关联代币账户（ATA）
+}
+
+impl From<&TokenAccountInfo> for PyTokenAccountInfo {
+    fn from(info: &TokenAccountInfo) -> Self {
+        Self {
+            address: info.address.clone(),
+            mint: info.mint.clone(),
+            rent_lamports: info.rent_lamports,
+            rent_sol: info.rent_sol,
+            symbol: info.symbol.clone(),
+            program_id: info.program_id.clone(),
+            is_associated: info.is_associated,
+        }
+    }
+}
+
+/// -- 零值代币账户信息
+#[pyclass(name = "ZeroValueTokenInfo", get_all)]
+#[derive(Debug, Clone)]
+pub struct PyZeroValueTokenInfo {
+    pub address: String,     // -- 账户地址
+    pub mint: String,        // -- 代币的 Mint 地址
+    pub balance: u64,        // -- 代币余额
+    pub decimals: u8,        // -- 代币小数位数
+    pub is_nft: bool,        // -- 是否为 NFT
+    pub rent_lamports: u64,  // -- 租金（以 lamports 为单位）
+    pub rent_sol: f64,       // -- 租金（以 SOL 为单位）
+    pub symbol: String,      // -- 代币符号
+    pub program_id: String,  // -- 所属代币程序 ID
+    pub is_associated: bool, // -- 是否为钱包的关联代币账户（ATA）
+}
+
+impl From<&ZeroValueTokenInfo> for PyZeroValueTokenInfo {
+    fn from(info: &ZeroValueTokenInfo) -> Self {
+        Self {
+            address: info.address.clone(),
+            mint: info.mint.clone(),
+            balance: info.balance,
+            decimals: info.decimals,
+            is_nft: info.is_nft,
+            rent_lamports: info.rent_lamports,
+            rent_sol: info.rent_sol,
+            symbol: info.symbol.clone(),
+            program_id: info.program_id.clone(),
+            is_associated: info.is_associated,
+        }
+    }
+}
+
+/// -- 代币账户扫描结果
+#[pyclass(name = "TokenAccountsResult", get_all)]
+#[derive(Debug, Clone)]
+pub struct PyTokenAccountsResult {
+    pub total_accounts: usize,                                // -- 总账户数量
+    pub closable_accounts: usize,                             // -- 可关闭的账户数量
+    pub zero_value_accounts: usize,                           // -- 零值代币账户数量
+    pub accounts: Vec<PyTokenAccountInfo>,                    // -- 可关闭账户列表
+    pub zero_value_accounts_list: Vec<PyZeroValueTokenInfo>,  // -- 零值代币账户列表
+    pub nft_accounts_list: Vec<PyZeroValueTokenInfo>,         // -- 持有 NFT 的账户列表
+    pub frozen_accounts_list: Vec<PyTokenAccountInfo>,        // -- 被冻结的账户列表
+    pub no_authority_accounts_list: Vec<PyTokenAccountInfo>,  // -- 关闭权限属于其他地址的账户
+    pub wrapped_sol_accounts_list: Vec<PyZeroValueTokenInfo>, // -- 有余额的 wSOL 账户列表
+    pub suspected_spam_accounts_list: Vec<PyZeroValueTokenInfo>, // -- 疑似垃圾代币账户列表
+    pub total_rent_lamports: u64,                             // -- 总租金（lamports）
+    pub total_rent_sol: f64,                                  // -- 总租金（SOL）
+}
+
+impl From<&TokenAccountsResult> for PyTokenAccountsResult {
+    fn from(result: &TokenAccountsResult) -> Self {
+        Self {
+            total_accounts: result.total_accounts,
+            closable_accounts: result.closable_accounts,
+            zero_value_accounts: result.zero_value_accounts,
+            accounts: result.accounts.iter().map(Into::into).collect(),
+            zero_value_accounts_list: result
+                .zero_value_accounts_list
+                .iter()
+                .map(Into::into)
+                .collect(),
+            nft_accounts_list: result.nft_accounts_list.iter().map(Into::into).collect(),
+            frozen_accounts_list: result.frozen_accounts_list.iter().map(Into::into).collect(),
+            no_authority_accounts_list: result
+                .no_authority_accounts_list
+                .iter()
+                .map(Into::into)
+                .collect(),
+            wrapped_sol_accounts_list: result
+                .wrapped_sol_accounts_list
+                .iter()
+                .map(Into::into)
+                .collect(),
+            suspected_spam_accounts_list: result
+                .suspected_spam_accounts_list
+                .iter()
+                .map(|spam| (&spam.account).into())
+                .collect(),
+            total_rent_lamports: result.total_rent_lamports,
+            total_rent_sol: result.total_rent_sol,
+        }
+    }
+}
+
+/// -- 账户关闭结果
+#[pyclass(name = "ClosureResult", get_all)]
+#[derive(Debug, Clone)]
+pub struct PyClosureResult {
+    pub success: bool,               // -- 操作是否成功
+    pub signature: Option<String>,   // -- 成功时的交易签名
+    pub error: Option<String>,       // -- 失败时的错误信息
+    pub account_address: String,     // -- 被关闭的账户地址
+    pub rent_recovered: f64,         // -- 回收的租金数量（以 SOL 为单位）
+    pub simulated: bool,             // -- 是否为模拟执行（未发送交易）
+    pub units_consumed: Option<u64>, // -- 发送前模拟消耗的计算单元
+    pub logs: Vec<String>,           // -- 发送前模拟的程序日志
+}
+
+impl From<ClosureResult> for PyClosureResult {
+    fn from(result: ClosureResult) -> Self {
+        Self {
+            success: result.success,
+            signature: result.signature,
+            error: result.error,
+            account_address: result.account_address,
+            rent_recovered: result.rent_recovered,
+            simulated: result.simulated,
+            units_consumed: result
+                .simulation
+                .as_ref()
+                .and_then(|sim| sim.units_consumed),
+            logs: result.simulation.map(|sim| sim.logs).unwrap_or_default(),
+        }
+    }
+}
+
+/// -- 销毁并关闭账户结果
+#[pyclass(name = "BurnAndCloseResult", get_all)]
+#[derive(Debug, Clone)]
+pub struct PyBurnAndCloseResult {
+    pub success: bool,                   // -- 操作是否成功
+    pub burn_signature: Option<String>,  // -- 销毁代币的交易签名
+    pub close_signature: Option<String>, // -- 关闭账户的交易签名
+    pub error: Option<String>,           // -- 失败时的错误信息
+    pub account_address: String,         // -- 被操作的账户地址
+    pub burned_amount: u64,              // -- 销毁的代币数量
+    pub swap_signature: Option<String>,  // -- 卖出剩余代币的交易签名
+    pub sold_amount: u64,                // -- 通过 Jupiter 卖出的代币数量
+    pub unwrapped_lamports: u64,         // -- 解包 wSOL 返还的 SOL（lamports）
+    pub rent_recovered: f64,             // -- 回收的租金数量（以 SOL 为单位）
+    pub simulated: bool,                 // -- 是否为模拟执行（未发送交易）
+    pub failure_kind: Option<String>,    // -- 失败原因分类
+    pub units_consumed: Option<u64>,     // -- 发送前模拟消耗的计算单元（销毁与关闭合计）
+    pub logs: Vec<String>,               // -- 发送前模拟的程序日志
+}
+
+impl From<BurnAndCloseResult> for PyBurnAndCloseResult {
+    fn from(result: BurnAndCloseResult) -> Self {
+        Self {
+            success: result.success,
+            burn_signature: result.burn_signature,
+            close_signature: result.close_signature,
+            error: result.error,
+            account_address: result.account_address,
+            burned_amount: result.burned_amount,
+            swap_signature: result.swap.as_ref().and_then(|swap| swap.signature.clone()),
+            sold_amount: result.swap.as_ref().map_or(0, |swap| swap.input_amount),
+            unwrapped_lamports: result.unwrapped_lamports,
+            rent_recovered: result.rent_recovered,
+            simulated: result.simulated,
+            failure_kind: result.failure_kind.map(|kind| format!("{:?}", kind)),
+            units_consumed: result
+                .simulation
+                .as_ref()
+                .and_then(|sim| sim.units_consumed),
+            logs: result.simulation.map(|sim| sim.logs).unwrap_or_default(),
+        }
+    }
+}
+
+/// -- 批量操作中单个账户的处理结果
+#[pyclass(name = "AccountOutcome", get_all)]
+#[derive(Debug, Clone)]
+pub struct PyAccountOutcome {
+    pub account_address: String,        // -- 账户地址
+    pub mint: String,                   // -- 代币的 Mint 地址
+    pub symbol: String,                 // -- 代币符号
+    pub success: bool,                  // -- 操作是否成功
+    pub signature: Option<String>,      // -- 关闭账户的交易签名
+    pub burn_signature: Option<String>, // -- 销毁代币的交易签名
+    pub burned_amount: u64,             // -- 销毁的代币数量
+    pub swept_amount: u64,              // -- 转出到目标钱包的代币数量
+    pub sold_amount: u64,               // -- 通过 Jupiter 卖出的代币数量
+    pub unwrapped_lamports: u64,        // -- 解包 wSOL 返还的 SOL（lamports）
+    pub rent_lamports: u64,             // -- 回收的租金（lamports）
+    pub error: Option<String>,          // -- 失败时的错误信息
+    pub failure_kind: Option<String>,   // -- 失败原因分类
+    pub units_consumed: Option<u64>,    // -- 发送前模拟消耗的计算单元
+    pub already_closed: bool,           // -- 处理前账户已被关闭，未发送交易
+}
+
+impl From<&AccountOutcome> for PyAccountOutcome {
+    fn from(outcome: &AccountOutcome) -> Self {
+        Self {
+            account_address: outcome.account_address.clone(),
+            mint: outcome.mint.clone(),
+            symbol: outcome.symbol.clone(),
+            success: outcome.success,
+            signature: outcome.signature.clone(),
+            burn_signature: outcome.burn_signature.clone(),
+            burned_amount: outcome.burned_amount,
+            swept_amount: outcome.swept_amount,
+            sold_amount: outcome.swap.as_ref().map_or(0, |swap| swap.input_amount),
+            unwrapped_lamports: outcome.unwrapped_lamports,
+            rent_lamports: outcome.rent_lamports,
+            error: outcome.error.clone(),
+            failure_kind: outcome.failure_kind.map(|kind| format!("{:?}", kind)),
+            units_consumed: outcome
+                .simulation
+                .as_ref()
+                .and_then(|sim| sim.units_consumed),
+            already_closed: outcome.already_closed,
+        }
+    }
+}
+
+/// -- 批量关闭结果
+#[pyclass(name = "BatchCloseReport", get_all)]
+#[derive(Debug, Clone)]
+pub struct PyBatchCloseReport {
+    pub outcomes: Vec<PyAccountOutcome>, // -- 每个账户的处理结果
+    pub signatures: Vec<String>,         // -- 发送的全部交易签名
+    pub succeeded: usize,                // -- 成功处理的账户数量
+    pub failed: usize,                   // -- 失败的账户数量
+    pub already_closed: usize,           // -- 处理前已被关闭的账户数量
+    pub rent_recovered_lamports: u64,    // -- 回收的租金（lamports）
+    pub rent_recovered_sol: f64,         // -- 回收的租金（SOL）
+    pub unwrapped_sol: f64,              // -- 解包 wSOL 返还的 SOL
+    pub fees_lamports: u64,              // -- 交易费用（lamports）
+    pub estimated_fees_lamports: u64,    // -- 执行前预计的交易费用上限（lamports）
+    pub simulated: bool,                 // -- 是否为模拟执行
+}
+
+impl From<BatchCloseReport> for PyBatchCloseReport {
+    fn from(report: BatchCloseReport) -> Self {
+        Self {
+            outcomes: report.outcomes.iter().map(Into::into).collect(),
+            signatures: report.signatures,
+            succeeded: report.succeeded,
+            failed: report.failed,
+            already_closed: report.already_closed,
+            rent_recovered_lamports: report.rent_recovered_lamports,
+            rent_recovered_sol: report.rent_recovered_sol,
+            unwrapped_sol: report.unwrapped_sol,
+            fees_lamports: report.fees_lamports,
+            estimated_fees_lamports: report.estimated_fees_lamports,
+            simulated: report.simulated,
+        }
+    }
+}
+
+macro_rules! impl_repr {
+    ($($ty:ty),*) => {
+        $(
+            #[pymethods]
+            impl $ty {
+                fn __repr__(&self) -> String {
+                    format!("{:?}", self)
+                }
+            }
+        )*
+    };
+}
+
+impl_repr!(
+    PyTokenAccountInfo,
+    PyZeroValueTokenInfo,
+    PyTokenAccountsResult,
+    PyClosureResult,
+    PyBurnAndCloseResult,
+    PyAccountOutcome,
+    PyBatchCloseReport
+);
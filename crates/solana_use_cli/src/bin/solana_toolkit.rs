@@ -0,0 +1,205 @@
+use anyhow::{anyhow, Result};
+use clap::{Args, Parser, Subcommand};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_toolkits::{
+    account_info::BatchCloseReport, config::TokenAccountConfig, TokenAccountManager,
+};
+use std::path::PathBuf;
+use tracing::info;
+use utils::{
+    address_book::address_book,
+    cluster::Cluster,
+    init_tracing, load_env,
+    locale::{set_locale, Locale},
+};
+
+/// -- 代币账户租金回收工具
+///
+/// 只包含扫描、关闭、销毁、转出和可回收统计几个常用操作，无需编写代码即可回收租金；
+/// 更多功能（多钱包、检查点、通知等）见 `solana-use accounts`。
+#[derive(Debug, Parser)]
+#[command(
+    name = "solana-toolkit",
+    version,
+    about = "Solana 代币账户租金回收工具"
+)]
+struct Cli {
+    #[command(flatten)]
+    global: GlobalArgs,
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// -- 全部子命令共享的参数
+#[derive(Debug, Args)]
+struct GlobalArgs {
+    /// 钱包密钥文件路径（支持加密钱包文件）
+    #[arg(long, short, env = "WALLET_PATH", global = true)]
+    wallet: Option<String>,
+    /// 连接的集群：mainnet、devnet、testnet、localnet 或 RPC 地址，未设置时读取 RPC_URL
+    #[arg(long, env = "SOLANA_CLUSTER", global = true)]
+    rpc: Option<Cluster>,
+    /// 白名单文件（TOML 或 JSON），白名单中的代币不会被销毁或转出
+    #[arg(long, env = "WHITELIST_PATH", global = true)]
+    whitelist_file: Option<PathBuf>,
+    /// 只模拟执行，不发送交易
+    #[arg(long, global = true)]
+    dry_run: bool,
+    /// 每批处理的账户数量
+    #[arg(long, default_value_t = 5, global = true)]
+    batch_size: usize,
+    /// 批量处理完成后写入的可读报告（.md 或 .html）
+    #[arg(long, global = true)]
+    report: Option<PathBuf>,
+    /// 结果、报告和错误信息的语言：zh 或 en
+    #[arg(long, env = "SOLANA_USE_LOCALE", default_value = "zh", global = true)]
+    locale: Locale,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// 扫描可关闭的代币账户
+    Scan {
+        /// 导出扫描结果的文件路径，扩展名为 .csv 时导出 CSV，否则导出 JSON
+        #[arg(long)]
+        export: Option<PathBuf>,
+    },
+    /// 关闭余额为 0 的代币账户
+    Close {
+        /// 将一批账户合并到一个交易中
+        #[arg(long)]
+        batch_tx: bool,
+    },
+    /// 销毁零值代币并关闭账户
+    BurnClose {
+        /// 额外加入白名单的代币符号
+        #[arg(long, value_delimiter = ',')]
+        whitelist: Vec<String>,
+    },
+    /// 将零值代币账户中的剩余代币转入目标钱包并关闭账户
+    Sweep {
+        /// 接收代币的钱包地址或地址簿名称
+        #[arg(long)]
+        to: String,
+        /// 额外加入白名单的代币符号
+        #[arg(long, value_delimiter = ',')]
+        whitelist: Vec<String>,
+    },
+    /// 统计钱包当前可回收的 SOL（代币账户租金、open orders 账户租金和可提取质押）
+    Report {
+        /// 以 JSON 格式输出
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // -- 加载 .env 失败时继续使用进程环境变量
+    let _ = load_env();
+    init_tracing();
+
+    let cli = Cli::parse();
+    set_locale(cli.global.locale);
+    let mut manager = load_manager(&cli.global)?;
+    let batch_size = cli.global.batch_size;
+
+    let report = match cli.command {
+        Command::Scan { export } => {
+            let result = manager.get_closeable_accounts().await?;
+            if let Some(path) = export {
+                if path.extension().is_some_and(|ext| ext == "csv") {
+                    result.export_csv(&path)?;
+                } else {
+                    result.export_json(&path)?;
+                }
+                info!("扫描结果已导出到 {}", path.display());
+            }
+            return Ok(());
+        }
+        Command::Close { batch_tx } => {
+            let result = manager.get_closeable_accounts().await?;
+            manager
+                .batch_close_accounts(&result.accounts, batch_size, batch_tx)
+                .await?
+        }
+        Command::BurnClose { whitelist } => {
+            add_whitelist(&mut manager, &whitelist);
+            let result = manager.get_closeable_accounts().await?;
+            // -- wSOL 账户一并处理：关闭即解包，不会销毁
+            let accounts: Vec<_> = result
+                .zero_value_accounts_list
+                .iter()
+                .chain(&result.wrapped_sol_accounts_list)
+                .cloned()
+                .collect();
+            manager
+                .batch_burn_and_close_zero_value_accounts(&accounts, batch_size)
+                .await?
+        }
+        Command::Sweep { to, whitelist } => {
+            let destination = address_book()
+                .resolve(&to)
+                .ok_or_else(|| anyhow!("{} 既不是有效地址，也不在地址簿中", to))?;
+            add_whitelist(&mut manager, &whitelist);
+            let result = manager.get_closeable_accounts().await?;
+            manager
+                .batch_sweep_and_close(&result.zero_value_accounts_list, &destination, batch_size)
+                .await?
+        }
+        Command::Report { json } => {
+            let recoverable = manager.get_recoverable_sol().await?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&recoverable)?);
+            } else {
+                let sol = |lamports: u64| lamports as f64 / LAMPORTS_PER_SOL as f64;
+                info!("代币账户租金: {} SOL", sol(recoverable.token_rent_lamports));
+                info!(
+                    "open orders 账户租金: {} SOL",
+                    sol(recoverable.open_orders_lamports)
+                );
+                info!("可提取质押: {} SOL", sol(recoverable.stake_lamports));
+                info!(
+                    "取消激活中的质押: {} SOL",
+                    sol(recoverable.pending_lamports)
+                );
+                info!("当前可回收合计: {} SOL", recoverable.total_sol);
+            }
+            return Ok(());
+        }
+    };
+
+    ensure_succeeded(&report)
+}
+
+/// -- 根据全局参数创建管理器
+fn load_manager(args: &GlobalArgs) -> Result<TokenAccountManager> {
+    let wallet = args
+        .wallet
+        .as_deref()
+        .ok_or_else(|| anyhow!("请通过 --wallet 或 WALLET_PATH 指定钱包密钥文件"))?;
+    let config = TokenAccountConfig {
+        dry_run: args.dry_run,
+        cluster: args.rpc.clone(),
+        whitelist_path: args.whitelist_file.clone(),
+        run_report_path: args.report.clone(),
+        ..TokenAccountConfig::default()
+    };
+    Ok(TokenAccountManager::with_config(wallet, config)?)
+}
+
+/// -- 额外加入白名单的代币符号
+fn add_whitelist(manager: &mut TokenAccountManager, whitelist: &[String]) {
+    let symbols: Vec<&str> = whitelist.iter().map(String::as_str).collect();
+    if !symbols.is_empty() {
+        manager.add_symbols_to_whitelist(&symbols);
+    }
+}
+
+/// -- 有账户处理失败时以非零状态退出，便于在脚本或定时任务中检测
+fn ensure_succeeded(report: &BatchCloseReport) -> Result<()> {
+    if report.failed > 0 {
+        return Err(anyhow!("{} 个账户处理失败", report.failed));
+    }
+    Ok(())
+}
@@ -0,0 +1,645 @@
+use anyhow::{anyhow, Result};
+use raydium_monitor::{
+    inspector,
+    services::{subscribe_to_logs, RaydiumMonitor},
+    sink::NdjsonFileSink,
+    swap_analyzer::analyze_swap_info,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair},
+    signer::Signer,
+};
+use solana_toolkits::{
+    config::{DustSwapConfig, PreflightPolicy, SubmitMode, TokenAccountConfig},
+    faucet::{read_funding_list, FaucetConfig, FundingSource},
+    filter::ScanFilter,
+    history::RunHistory,
+    multi_wallet::{MultiWalletManager, WalletOperation},
+    notify::{DiscordNotifier, NotifyPolicy, TelegramNotifier, WebhookNotifier},
+    spam::SpamHeuristics,
+    TokenAccountManager,
+};
+use std::{
+    env::{self, current_dir},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
+use tracing::info;
+use utils::{
+    address_book::address_book,
+    fetch_token_info, format_metadata, init_rpc_client,
+    keystore::{
+        encrypt_keypair_file, is_encrypted_keypair_file, Keystore, KEYSTORE_PASSPHRASE_ENV,
+    },
+    wallet::parse_keypair,
+};
+
+use crate::{FilterArgs, SellArgs, TxArgs, WalletArgs};
+
+/// -- 根据钱包参数创建管理器：指定密钥名称时从加密密钥库加载，否则读取密钥文件
+fn load_manager(wallet: &WalletArgs) -> Result<TokenAccountManager> {
+    load_manager_with_config(wallet, TokenAccountConfig::default())
+}
+
+/// -- 使用自定义配置创建管理器
+fn load_manager_with_config(
+    wallet: &WalletArgs,
+    config: TokenAccountConfig,
+) -> Result<TokenAccountManager> {
+    let config = TokenAccountConfig {
+        cluster: wallet.cluster.clone().or(config.cluster),
+        ..config
+    };
+    match (&wallet.key, &wallet.wallet) {
+        (Some(name), _) => {
+            let keystore = unlock_keystore(&wallet.keystore)?;
+            Ok(TokenAccountManager::from_keystore(&keystore, name, config)?)
+        }
+        (None, Some(path)) if is_encrypted_keypair_file(path) => {
+            Ok(TokenAccountManager::from_encrypted_wallet(
+                path,
+                || Ok(rpassword::prompt_password("钱包口令: ")?),
+                config,
+            )?)
+        }
+        (None, Some(path)) => Ok(TokenAccountManager::with_config(path, config)?),
+        (None, None) => Err(anyhow!("请指定 --wallet 或 --key")),
+    }
+}
+
+/// -- 读取 `--fee-payer` 指定的手续费支付者密钥文件
+fn load_fee_payer(tx: &TxArgs) -> Result<Option<Arc<dyn Signer + Send + Sync>>> {
+    let Some(path) = &tx.fee_payer else {
+        return Ok(None);
+    };
+    let keypair = parse_keypair(&fs::read_to_string(path)?)
+        .map_err(|e| anyhow!("加载手续费支付者密钥文件 {} 失败: {}", path.display(), e))?;
+    info!("交易费用由 {} 支付", keypair.pubkey());
+    Ok(Some(Arc::new(keypair)))
+}
+
+/// -- 使用交易参数创建管理器，并添加配置的手续费支付者和通知目标
+fn load_tx_manager(
+    wallet: &WalletArgs,
+    config: TokenAccountConfig,
+    tx: &TxArgs,
+) -> Result<TokenAccountManager> {
+    let mut manager = load_manager_with_config(wallet, config)?;
+    if let Some(fee_payer) = load_fee_payer(tx)? {
+        manager.set_fee_payer(fee_payer);
+    }
+    let notify = &tx.notify;
+    if let Some(url) = &notify.notify_webhook {
+        manager.add_notifier(Arc::new(WebhookNotifier::new(url)));
+    }
+    if let (Some(token), Some(chat_id)) = (&notify.telegram_bot_token, &notify.telegram_chat_id) {
+        manager.add_notifier(Arc::new(TelegramNotifier::new(token, chat_id)));
+    }
+    if let Some(url) = &notify.discord_webhook {
+        manager.add_notifier(Arc::new(DiscordNotifier::new(url)));
+    }
+    Ok(manager)
+}
+
+/// -- 根据交易参数生成管理器配置
+fn tx_config(tx: &TxArgs) -> TokenAccountConfig {
+    TokenAccountConfig {
+        dry_run: tx.dry_run,
+        revoke_delegates: tx.revoke_delegates,
+        whitelist_path: tx.whitelist_file.clone(),
+        checkpoint_path: tx.checkpoint.clone(),
+        history_path: tx.history.clone(),
+        run_report_path: tx.report.clone(),
+        min_wallet_sol_reserve: tx.min_sol_reserve,
+        max_fee_sol: tx.max_fee_sol,
+        memo: tx.memo.clone(),
+        max_concurrent_transactions: tx.max_concurrent_txs,
+        compute_unit_limit: tx.compute_unit_limit,
+        compute_unit_price: tx.priority_fee,
+        preflight: if tx.ignore_simulation_errors {
+            PreflightPolicy::Warn
+        } else {
+            PreflightPolicy::Abort
+        },
+        submit_mode: match tx.jito_tip {
+            Some(tip_lamports) => SubmitMode::Jito {
+                tip_lamports,
+                block_engine_url: tx.block_engine_url.clone(),
+            },
+            None => SubmitMode::Rpc,
+        },
+        scan_filter: scan_filter(&tx.filter),
+        spam_heuristics: tx.filter.detect_spam.then(SpamHeuristics::default),
+        notify_policy: if tx.notify.notify_on_failure {
+            NotifyPolicy::OnFailure
+        } else {
+            NotifyPolicy::Always
+        },
+        ..TokenAccountConfig::default()
+    }
+}
+
+/// -- 根据过滤参数生成扫描过滤条件
+fn scan_filter(filter: &FilterArgs) -> ScanFilter {
+    let scan_filter = ScanFilter {
+        include_mints: filter.include_mint.clone(),
+        exclude_mints: filter.exclude_mint.clone(),
+        min_rent_lamports: filter.min_rent,
+        symbol_pattern: filter.symbol_pattern.clone(),
+        only_associated: filter.only_ata,
+        only_auxiliary: filter.only_auxiliary,
+        ..ScanFilter::default()
+    };
+    match filter.older_than_days {
+        Some(days) => scan_filter.older_than(Duration::from_secs(days * 24 * 3600)),
+        None => scan_filter,
+    }
+}
+
+/// -- 根据卖出和交易参数生成销毁流程的管理器配置
+fn burn_config(sell: &SellArgs, tx: &TxArgs) -> TokenAccountConfig {
+    TokenAccountConfig {
+        dust_swap: sell
+            .sell_min_output
+            .map(|min_output_amount| DustSwapConfig {
+                output: sell.sell_to,
+                min_output_amount,
+                slippage_bps: sell.sell_slippage_bps,
+                ..DustSwapConfig::default()
+            }),
+        max_usd_value_to_burn: Some(sell.max_usd_value),
+        burn_nfts: sell.burn_nfts,
+        ..tx_config(tx)
+    }
+}
+
+/// -- 解锁密钥库，优先使用 KEYSTORE_PASSPHRASE，未设置时提示输入
+fn unlock_keystore(path: &Path) -> Result<Keystore> {
+    if env::var(KEYSTORE_PASSPHRASE_ENV).is_ok() {
+        return Ok(Keystore::unlock_from_env(path)?);
+    }
+
+    let passphrase = rpassword::prompt_password("密钥库口令: ")?;
+    Ok(Keystore::unlock(path, &passphrase)?)
+}
+
+/// -- 扫描可关闭账户
+pub async fn accounts_scan(
+    wallet: &WalletArgs,
+    filter: &FilterArgs,
+    export: Option<&Path>,
+) -> Result<()> {
+    let config = TokenAccountConfig {
+        scan_filter: scan_filter(filter),
+        spam_heuristics: filter.detect_spam.then(SpamHeuristics::default),
+        ..TokenAccountConfig::default()
+    };
+    let manager = load_manager_with_config(wallet, config)?;
+    let result = manager.get_closeable_accounts().await?;
+    if let Some(path) = export {
+        if path.extension().is_some_and(|ext| ext == "csv") {
+            result.export_csv(path)?;
+        } else {
+            result.export_json(path)?;
+        }
+        info!("扫描结果已导出到 {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// -- 关闭余额为 0 的账户
+///
+/// 指定或新建地址查找表时，先把待关闭账户写入查找表，再以 v0 交易批量关闭。
+pub async fn accounts_close(
+    wallet: &WalletArgs,
+    batch_size: usize,
+    batch_tx: bool,
+    lookup_table: Option<&str>,
+    create_lookup_table: bool,
+    resume: bool,
+    tx: &TxArgs,
+) -> Result<()> {
+    let mut manager = load_tx_manager(wallet, tx_config(tx), tx)?;
+    if resume {
+        // -- 从检查点恢复，不重新扫描账户，只使用已有的查找表
+        manager.set_lookup_table(lookup_table.map(Pubkey::from_str).transpose()?);
+        let report = manager.resume_from_checkpoint(batch_size, batch_tx).await?;
+        if report.failed > 0 {
+            return Err(anyhow!("{} 个账户关闭失败", report.failed));
+        }
+        return Ok(());
+    }
+
+    let result = manager.get_closeable_accounts().await?;
+
+    let table = lookup_table.map(Pubkey::from_str).transpose()?;
+    if tx.dry_run {
+        // -- 模拟模式不发送查找表交易，只使用已有查找表中的地址
+        manager.set_lookup_table(table);
+    } else if (table.is_some() || create_lookup_table) && !result.accounts.is_empty() {
+        let table = manager
+            .prepare_lookup_table(table, &result.accounts)
+            .await?;
+        info!("使用地址查找表: {}", table);
+        manager.set_lookup_table(Some(table));
+    }
+
+    let report = manager
+        .batch_close_accounts(&result.accounts, batch_size, batch_tx)
+        .await?;
+    if report.failed > 0 {
+        return Err(anyhow!("{} 个账户关闭失败", report.failed));
+    }
+
+    Ok(())
+}
+
+/// -- 销毁并关闭零值代币账户
+pub async fn accounts_burn(
+    wallet: &WalletArgs,
+    batch_size: usize,
+    whitelist: &[String],
+    sell: &SellArgs,
+    tx: &TxArgs,
+) -> Result<()> {
+    let mut manager = load_tx_manager(wallet, burn_config(sell, tx), tx)?;
+    let symbols: Vec<&str> = whitelist.iter().map(String::as_str).collect();
+    if !symbols.is_empty() {
+        manager.add_symbols_to_whitelist(&symbols);
+    }
+    let mints: Vec<&str> = sell.blacklist.iter().map(String::as_str).collect();
+    if !mints.is_empty() {
+        manager.add_mints_to_blacklist(&mints);
+    }
+
+    let result = manager.get_closeable_accounts().await?;
+    // -- wSOL 账户一并处理：关闭即解包，不会销毁
+    let mut accounts: Vec<_> = result
+        .zero_value_accounts_list
+        .iter()
+        .chain(&result.wrapped_sol_accounts_list)
+        .cloned()
+        .collect();
+    if sell.burn_spam {
+        // -- 疑似垃圾代币加入黑名单，NFT 形式的垃圾代币同样会被销毁
+        let spam = result.suspected_spam_tokens();
+        let mints: Vec<&str> = spam.iter().map(|account| account.mint.as_str()).collect();
+        manager.add_mints_to_blacklist(&mints);
+        accounts.extend(spam);
+    }
+    let report = manager
+        .batch_burn_and_close_zero_value_accounts(&accounts, batch_size)
+        .await?;
+    if report.failed > 0 {
+        return Err(anyhow!("{} 个账户处理失败", report.failed));
+    }
+
+    Ok(())
+}
+
+/// -- 将零值代币账户中的剩余代币转入目标钱包并关闭账户
+pub async fn accounts_sweep(
+    wallet: &WalletArgs,
+    to: &str,
+    batch_size: usize,
+    whitelist: &[String],
+    tx: &TxArgs,
+) -> Result<()> {
+    let destination = address_book()
+        .resolve(to)
+        .ok_or_else(|| anyhow!("{} 既不是有效地址，也不在地址簿中", to))?;
+
+    let mut manager = load_tx_manager(wallet, tx_config(tx), tx)?;
+    let symbols: Vec<&str> = whitelist.iter().map(String::as_str).collect();
+    if !symbols.is_empty() {
+        manager.add_symbols_to_whitelist(&symbols);
+    }
+
+    let result = manager.get_closeable_accounts().await?;
+    let report = manager
+        .batch_sweep_and_close(&result.zero_value_accounts_list, &destination, batch_size)
+        .await?;
+    if report.failed > 0 {
+        return Err(anyhow!("{} 个账户处理失败", report.failed));
+    }
+
+    Ok(())
+}
+
+/// -- 对目录中的全部钱包执行扫描、关闭或销毁
+pub async fn accounts_multi(
+    dir: &Path,
+    operation: WalletOperation,
+    concurrency: usize,
+    batch_size: usize,
+    whitelist: &[String],
+    sell: &SellArgs,
+    tx: &TxArgs,
+) -> Result<()> {
+    let config = match operation {
+        WalletOperation::Burn => burn_config(sell, tx),
+        WalletOperation::Scan | WalletOperation::Close => tx_config(tx),
+    };
+    let mut manager = MultiWalletManager::from_dir(dir, config)?;
+    manager.set_concurrency(concurrency);
+    if let Some(fee_payer) = load_fee_payer(tx)? {
+        manager.set_fee_payer(fee_payer);
+    }
+    let symbols: Vec<&str> = whitelist.iter().map(String::as_str).collect();
+    if !symbols.is_empty() {
+        manager.add_symbols_to_whitelist(&symbols);
+    }
+    let mints: Vec<&str> = sell.blacklist.iter().map(String::as_str).collect();
+    if !mints.is_empty() {
+        manager.add_mints_to_blacklist(&mints);
+    }
+
+    let report = manager.run(operation, batch_size).await;
+    if report.failed > 0 {
+        return Err(anyhow!("{} 个钱包处理失败", report.failed));
+    }
+
+    Ok(())
+}
+
+/// -- 将目录中全部钱包的 SOL 归集到主钱包
+pub async fn accounts_consolidate(
+    dir: &Path,
+    to: &str,
+    concurrency: usize,
+    tx: &TxArgs,
+) -> Result<()> {
+    let master = address_book()
+        .resolve(to)
+        .ok_or_else(|| anyhow!("{} 既不是有效地址，也不在地址簿中", to))?;
+
+    let mut manager = MultiWalletManager::from_dir(dir, tx_config(tx))?;
+    manager.set_concurrency(concurrency);
+    let report = manager.consolidate_sol(&master).await;
+    if report.failed > 0 {
+        return Err(anyhow!("{} 个钱包归集失败", report.failed));
+    }
+
+    Ok(())
+}
+
+/// -- 列出存在授权代理的代币账户，指定 `revoke` 时全部撤销
+pub async fn accounts_delegates(
+    wallet: &WalletArgs,
+    revoke: bool,
+    batch_size: usize,
+    tx: &TxArgs,
+) -> Result<()> {
+    let manager = load_manager_with_config(wallet, tx_config(tx))?;
+    let delegated = manager.get_delegated_accounts().await?;
+    if !revoke || delegated.is_empty() {
+        return Ok(());
+    }
+
+    let outcomes = manager.revoke_delegates(&delegated, batch_size).await;
+    let failed = outcomes.iter().filter(|outcome| !outcome.success).count();
+    if failed > 0 {
+        return Err(anyhow!("{} 个账户撤销授权失败", failed));
+    }
+
+    Ok(())
+}
+
+/// -- 为列表中的钱包注资到目标余额
+pub async fn fund(
+    wallet: &WalletArgs,
+    list: &Path,
+    default_target: f64,
+    source: Option<FundingSource>,
+    max_airdrop_sol: f64,
+) -> Result<()> {
+    let manager = load_manager(wallet)?;
+    let targets = read_funding_list(list, default_target)?;
+    let source =
+        source.unwrap_or_else(|| FundingSource::detect(manager.get_config().cluster().url()));
+    info!("使用 {:?} 为 {} 个钱包注资", source, targets.len());
+
+    let faucet_config = FaucetConfig {
+        source,
+        max_airdrop_sol,
+        ..FaucetConfig::default()
+    };
+    let report = manager.fund_wallets(&targets, &faucet_config).await?;
+    if report.failed > 0 {
+        return Err(anyhow!("{} 个钱包注资失败", report.failed));
+    }
+
+    Ok(())
+}
+
+/// -- 汇总运行历史，指定 `month`（YYYY-MM）时只统计该月份
+pub fn accounts_history(path: &Path, month: Option<&str>, json: bool) -> Result<()> {
+    let history = RunHistory::open(path)?;
+    let summary = match month {
+        Some(month) => {
+            let (year, month) = month
+                .split_once('-')
+                .and_then(|(year, month)| Some((year.parse().ok()?, month.parse().ok()?)))
+                .ok_or_else(|| anyhow!("无效的月份 {}，格式应为 YYYY-MM", month))?;
+            history.monthly_summary(year, month)?
+        }
+        None => history.summary_between(0, u64::MAX)?,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+    } else {
+        println!("运行次数: {}", summary.runs);
+        println!(
+            "处理账户: {} 成功 / {} 失败",
+            summary.accounts_closed, summary.accounts_failed
+        );
+        println!("交易数量: {}", summary.transactions);
+        println!("回收租金: {:.9} SOL", summary.rent_recovered_sol);
+        println!("交易费用: {:.9} SOL", summary.fees_sol);
+    }
+
+    Ok(())
+}
+
+/// -- 订阅 Raydium 新流动性池
+///
+/// 只订阅 AMM v4 新流动性池时输出可读的流动性池详情，否则每个事件输出一行 JSON；
+/// 指定输出文件时事件同时追加写入该文件。
+///
+/// # 参数
+/// * `ws_url` - WebSocket RPC URL
+/// * `clmm` - 是否同时订阅 CLMM 程序
+/// * `whirlpool` - 是否同时订阅 Orca Whirlpool 程序
+/// * `phoenix` - 是否同时订阅 Phoenix 订单簿程序
+/// * `jupiter` - 是否同时订阅 Jupiter v6 程序
+/// * `dca` - 是否同时订阅 Jupiter DCA 程序
+/// * `all` - 是否推送全部事件
+/// * `output` - NDJSON 事件输出文件
+#[allow(clippy::too_many_arguments)]
+pub async fn monitor_watch(
+    ws_url: &str,
+    clmm: bool,
+    whirlpool: bool,
+    phoenix: bool,
+    jupiter: bool,
+    dca: bool,
+    all: bool,
+    output: Option<&Path>,
+) -> Result<()> {
+    if !clmm && !whirlpool && !dca && !all && output.is_none() {
+        return subscribe_to_logs(ws_url).await;
+    }
+
+    let mut monitor = RaydiumMonitor::new(ws_url)?
+        .with_clmm(clmm)
+        .with_whirlpool(whirlpool)
+        .with_phoenix(phoenix)
+        .with_jupiter(jupiter)
+        .with_dca(dca)
+        .new_pools_only(!all);
+    if let Some(path) = output {
+        monitor = monitor.with_sink(NdjsonFileSink::open(path)?);
+    }
+    let (mut events, handle) = monitor.spawn();
+    while let Some(event) = events.recv().await {
+        println!("{}", serde_json::to_string(&event)?);
+    }
+    handle.await?
+}
+
+/// -- 分析 swap 交易
+pub async fn monitor_analyze(signature: String) -> Result<()> {
+    analyze_swap_info(signature).await
+}
+
+/// -- 检查交易并输出可读报告
+pub async fn inspect(signature: &str, json: bool) -> Result<()> {
+    let report = inspector::inspect(signature).await?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("{}", report);
+    }
+
+    Ok(())
+}
+
+/// -- 查询代币信息
+pub fn token_info(mint: &str) -> Result<()> {
+    let rpc_client = init_rpc_client(CommitmentConfig::confirmed())?;
+    let (metadata, mint) = fetch_token_info(&rpc_client, mint)?;
+
+    info!("代币元数据: {}", format_metadata(&metadata));
+    info!("代币精度: {}", mint.decimals);
+    info!("代币供应量: {}", mint.supply);
+
+    Ok(())
+}
+
+/// -- 批量生成密钥对，输出与 solana-keygen 兼容的 JSON 数组文件
+pub fn keys_generate(count: u16, out_dir: Option<PathBuf>, prefix: &str) -> Result<()> {
+    let out_dir = match out_dir {
+        Some(dir) => dir,
+        None => current_dir()?,
+    };
+    fs::create_dir_all(&out_dir)?;
+
+    for index in 0..count {
+        let keypair = Keypair::new();
+        let path = out_dir.join(format!("{prefix}_{index}.json"));
+        let json = keypair_json(&keypair.to_bytes());
+        fs::write(&path, json)?;
+
+        info!("Keypair {}: {}", index + 1, keypair.pubkey());
+        info!("密钥已保存到 {}", path.display());
+    }
+
+    info!("共生成 {} 个密钥对", count);
+
+    Ok(())
+}
+
+/// -- 将密钥文件导入加密密钥库
+pub fn keys_import(keystore_path: &Path, name: &str, keypair_path: &Path) -> Result<()> {
+    let keypair = read_keypair_file(keypair_path).or_else(|_| -> Result<Keypair> {
+        // -- 兼容工作空间使用的 JSON 字符串（base58 私钥）格式
+        let value: serde_json::Value = serde_json::from_str(&fs::read_to_string(keypair_path)?)?;
+        let private_key = value
+            .as_str()
+            .ok_or_else(|| anyhow!("无法识别的密钥格式"))?;
+        Ok(Keypair::from_base58_string(private_key))
+    })?;
+
+    let mut keystore = if keystore_path.exists() {
+        unlock_keystore(keystore_path)?
+    } else {
+        let passphrase = new_passphrase("设置密钥库口令: ")?;
+        info!("创建密钥库: {}", keystore_path.display());
+        Keystore::create(keystore_path, &passphrase)?
+    };
+
+    let pubkey = keystore.insert(name, &keypair)?;
+    info!("已导入 {}: {}", name, pubkey);
+
+    Ok(())
+}
+
+/// -- 将明文钱包文件加密为单独的加密钱包文件
+pub fn keys_encrypt(keypair_path: &Path, output: &Path) -> Result<()> {
+    let keypair = parse_keypair(&fs::read_to_string(keypair_path)?)?;
+    let passphrase = new_passphrase("设置钱包口令: ")?;
+    encrypt_keypair_file(output, &keypair, &passphrase)?;
+    info!(
+        "已加密 {} 到 {}，确认可以解密后请删除明文文件 {}",
+        keypair.pubkey(),
+        output.display(),
+        keypair_path.display()
+    );
+
+    Ok(())
+}
+
+/// -- 获取新口令：优先使用 KEYSTORE_PASSPHRASE，未设置时提示输入两次
+fn new_passphrase(prompt: &str) -> Result<String> {
+    if let Ok(passphrase) = env::var(KEYSTORE_PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+
+    let passphrase = rpassword::prompt_password(prompt)?;
+    let confirm = rpassword::prompt_password("再次输入口令: ")?;
+    if passphrase != confirm {
+        return Err(anyhow!("两次输入的口令不一致"));
+    }
+    Ok(passphrase)
+}
+
+/// -- 列出加密密钥库中的密钥（只读取公钥，仍需口令校验）
+pub fn keys_list(keystore_path: &Path) -> Result<()> {
+    let keystore = unlock_keystore(keystore_path)?;
+    let keys = keystore.list();
+
+    for (name, pubkey) in &keys {
+        info!("{}: {}", name, pubkey);
+    }
+    info!("共 {} 个密钥", keys.len());
+
+    Ok(())
+}
+
+fn keypair_json(bytes: &[u8]) -> String {
+    format!(
+        "[{}]",
+        bytes
+            .iter()
+            .map(|b| b.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    )
+}
@@ -0,0 +1,616 @@
+use anyhow::Result;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use solana_toolkits::{
+    config::{ComputeUnitPrice, DustSwapOutput},
+    faucet::FundingSource,
+    filter::Regex,
+    jito,
+    multi_wallet::WalletOperation,
+};
+use std::path::PathBuf;
+use utils::{
+    cluster::Cluster,
+    init_tracing, load_env,
+    locale::{set_locale, Locale},
+    TokenAccountError,
+};
+
+mod commands;
+
+/// -- Solana-use 命令行工具
+///
+/// 将工作空间中的各个库封装为子命令，所有命令共享 `.env` 中的配置（RPC_URL、WALLET_PATH 等）
+#[derive(Debug, Parser)]
+#[command(name = "solana-use", version, about = "Solana 工具集命令行")]
+struct Cli {
+    /// 结果、报告和错误信息的语言：zh 或 en（日志始终为中文）
+    #[arg(long, global = true, env = "SOLANA_USE_LOCALE", default_value = "zh")]
+    locale: Locale,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// 代币账户管理
+    #[command(subcommand)]
+    Accounts(AccountsCommand),
+    /// Raydium 监控
+    #[command(subcommand)]
+    Monitor(MonitorCommand),
+    /// 代币信息查询
+    #[command(subcommand)]
+    Token(TokenCommand),
+    /// 密钥管理
+    #[command(subcommand)]
+    Keys(KeysCommand),
+    /// 检查任意交易：程序、指令、余额变化和手续费
+    Inspect {
+        /// 交易签名
+        signature: String,
+        /// 以 JSON 格式输出
+        #[arg(long)]
+        json: bool,
+    },
+    /// 为测试钱包注资（devnet 空投或从资金钱包转账）
+    Fund {
+        /// 钱包列表文件，每行 `pubkey[,target_sol]`
+        list: PathBuf,
+        /// 未填写目标余额时的默认目标（SOL）
+        #[arg(long, default_value_t = 1.0)]
+        target: f64,
+        /// 资金来源，默认根据 --cluster 或 RPC_URL 推断
+        #[arg(long, value_enum)]
+        source: Option<FundSource>,
+        /// 单次空投的最大 SOL 数量
+        #[arg(long, default_value_t = 1.0)]
+        max_airdrop: f64,
+        #[command(flatten)]
+        wallet: WalletArgs,
+    },
+}
+
+/// -- 注资来源
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum FundSource {
+    /// 请求空投
+    Airdrop,
+    /// 从资金钱包转账
+    Transfer,
+}
+
+impl From<FundSource> for FundingSource {
+    fn from(source: FundSource) -> Self {
+        match source {
+            FundSource::Airdrop => FundingSource::Airdrop,
+            FundSource::Transfer => FundingSource::Transfer,
+        }
+    }
+}
+
+/// -- 钱包参数，默认读取 WALLET_PATH（支持加密钱包文件）；指定 --key 时改为从加密密钥库加载
+#[derive(Debug, Args)]
+struct WalletArgs {
+    /// 钱包密钥文件路径，加密钱包文件使用 KEYSTORE_PASSPHRASE 或交互输入的口令解密
+    #[arg(long, env = "WALLET_PATH", required_unless_present = "key")]
+    wallet: Option<String>,
+    /// 密钥库中的钱包名称
+    #[arg(long, env = "KEYSTORE_KEY")]
+    key: Option<String>,
+    /// 加密密钥库路径
+    #[arg(long, env = "KEYSTORE_PATH", default_value = "keystore.json")]
+    keystore: PathBuf,
+    /// 连接的集群：mainnet、devnet、testnet、localnet 或 RPC 地址，未设置时读取 RPC_URL
+    #[arg(long, env = "SOLANA_CLUSTER")]
+    cluster: Option<Cluster>,
+}
+
+/// -- 关闭、销毁交易参数
+#[derive(Debug, Args)]
+struct TxArgs {
+    /// 只模拟执行，不发送交易
+    #[arg(long)]
+    dry_run: bool,
+    /// 优先费（micro-lamports/CU）：数字、auto、auto:<上限> 或 none
+    #[arg(long, default_value = "none")]
+    priority_fee: ComputeUnitPrice,
+    /// 每笔交易的计算单元上限
+    #[arg(long)]
+    compute_unit_limit: Option<u32>,
+    /// 发送前模拟失败时仍然发送交易（默认放弃发送）
+    #[arg(long)]
+    ignore_simulation_errors: bool,
+    /// 通过 Jito bundle 提交，并为每个 bundle 支付指定小费（lamports）
+    #[arg(long)]
+    jito_tip: Option<u64>,
+    /// Jito block engine 地址
+    #[arg(long, default_value = jito::DEFAULT_BLOCK_ENGINE_URL, requires = "jito_tip")]
+    block_engine_url: String,
+    /// 关闭账户前撤销仍然存在的授权代理
+    #[arg(long)]
+    revoke_delegates: bool,
+    /// 白名单文件（TOML 或 JSON）
+    #[arg(long, env = "WHITELIST_PATH")]
+    whitelist_file: Option<PathBuf>,
+    /// 批量关闭检查点文件，记录每个账户的处理状态，中断后可以通过 --resume 恢复
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+    /// 运行历史文件，每次批量处理完成后追加回收的租金、手续费和交易签名
+    #[arg(long, env = "SOLANA_USE_HISTORY")]
+    history: Option<PathBuf>,
+    /// 可读运行报告文件（.md 或 .html），批量处理完成后写入成功、失败账户和交易链接
+    #[arg(long)]
+    report: Option<PathBuf>,
+    /// 支付交易费用的密钥文件，钱包只作为账户所有者签名，适用于清理 SOL 余额为 0 的钱包
+    #[arg(long, env = "FEE_PAYER_PATH")]
+    fee_payer: Option<PathBuf>,
+    /// 钱包需要保留的最低 SOL 余额，每批处理前检查，余额不足时停止处理
+    #[arg(long, env = "MIN_WALLET_SOL_RESERVE")]
+    min_sol_reserve: Option<f64>,
+    /// 单次批量处理预计交易费用的上限（SOL），超过时拒绝执行
+    #[arg(long, env = "MAX_FEE_SOL")]
+    max_fee_sol: Option<f64>,
+    /// 通过 Memo 程序附加到每笔交易的备注，例如 "rent-recovery bot v1"
+    #[arg(long)]
+    memo: Option<String>,
+    /// 批量交易模式下同时在途的交易数，大于 1 时并发提交
+    #[arg(long, default_value_t = 1)]
+    max_concurrent_txs: usize,
+    #[command(flatten)]
+    filter: FilterArgs,
+    #[command(flatten)]
+    notify: NotifyArgs,
+}
+
+/// -- 批量处理完成通知参数
+#[derive(Debug, Args)]
+struct NotifyArgs {
+    /// 批量处理完成后通知的 Webhook 地址
+    #[arg(long, env = "NOTIFY_WEBHOOK_URL")]
+    notify_webhook: Option<String>,
+    /// Telegram 机器人 token（需要同时设置 --telegram-chat-id）
+    #[arg(long, env = "TELEGRAM_BOT_TOKEN")]
+    telegram_bot_token: Option<String>,
+    /// 接收通知的 Telegram 会话 ID
+    #[arg(long, env = "TELEGRAM_CHAT_ID")]
+    telegram_chat_id: Option<String>,
+    /// 接收通知的 Discord 频道 Webhook 地址
+    #[arg(long, env = "DISCORD_WEBHOOK_URL")]
+    discord_webhook: Option<String>,
+    /// 只在有账户处理失败时发送通知
+    #[arg(long)]
+    notify_on_failure: bool,
+}
+
+/// -- 账户扫描过滤参数
+#[derive(Debug, Args)]
+struct FilterArgs {
+    /// 只处理这些 Mint 的账户
+    #[arg(long, value_delimiter = ',')]
+    include_mint: Vec<String>,
+    /// 跳过这些 Mint 的账户
+    #[arg(long, value_delimiter = ',')]
+    exclude_mint: Vec<String>,
+    /// 只处理租金不低于该值（lamports）的账户
+    #[arg(long)]
+    min_rent: Option<u64>,
+    /// 只处理创建时间超过指定天数的账户（每个账户需要额外查询一次交易记录）
+    #[arg(long)]
+    older_than_days: Option<u64>,
+    /// 代币符号需要匹配的正则表达式，例如 "(?i)claim|airdrop"
+    #[arg(long)]
+    symbol_pattern: Option<Regex>,
+    /// 只处理关联代币账户（ATA）
+    #[arg(long, conflicts_with = "only_auxiliary")]
+    only_ata: bool,
+    /// 只处理非 ATA 的辅助代币账户，保留钱包的 ATA
+    #[arg(long)]
+    only_auxiliary: bool,
+    /// 识别疑似垃圾或蜜罐代币（Mint 保留冻结权限、元数据指向钓鱼域名或没有流动性），单独归类且默认不销毁
+    #[arg(long)]
+    detect_spam: bool,
+}
+
+/// -- 剩余代币卖出和销毁保护参数
+#[derive(Debug, Args)]
+struct SellArgs {
+    /// 销毁前先通过 Jupiter 卖出预计换得数量不低于该值的剩余代币（目标代币最小单位）
+    #[arg(long)]
+    sell_min_output: Option<u64>,
+    /// 卖出换成的代币：sol 或 usdc
+    #[arg(long, default_value = "sol", requires = "sell_min_output")]
+    sell_to: DustSwapOutput,
+    /// 卖出滑点（基点）
+    #[arg(long, default_value_t = 100, requires = "sell_min_output")]
+    sell_slippage_bps: u16,
+    /// 允许销毁的最高 USD 价值，价值更高的账户无论是否在白名单中都不会被销毁
+    #[arg(long, default_value_t = 1.0)]
+    max_usd_value: f64,
+    /// 允许销毁 NFT（默认跳过）
+    #[arg(long)]
+    burn_nfts: bool,
+    /// 强制销毁的代币 Mint 地址，优先于白名单和 NFT 保护
+    #[arg(long, value_delimiter = ',')]
+    blacklist: Vec<String>,
+    /// 一并销毁识别出的疑似垃圾代币（包括 NFT）
+    #[arg(long, requires = "detect_spam")]
+    burn_spam: bool,
+}
+
+/// -- 多钱包操作
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum MultiOperation {
+    /// 只扫描
+    Scan,
+    /// 关闭余额为 0 的账户
+    Close,
+    /// 销毁并关闭零值代币账户
+    Burn,
+}
+
+impl From<MultiOperation> for WalletOperation {
+    fn from(operation: MultiOperation) -> Self {
+        match operation {
+            MultiOperation::Scan => WalletOperation::Scan,
+            MultiOperation::Close => WalletOperation::Close,
+            MultiOperation::Burn => WalletOperation::Burn,
+        }
+    }
+}
+
+#[derive(Debug, Subcommand)]
+enum AccountsCommand {
+    /// 扫描可关闭的代币账户
+    Scan {
+        #[command(flatten)]
+        wallet: WalletArgs,
+        #[command(flatten)]
+        filter: FilterArgs,
+        /// 导出扫描结果的文件路径，扩展名为 .csv 时导出 CSV，否则导出 JSON
+        #[arg(long)]
+        export: Option<PathBuf>,
+    },
+    /// 关闭余额为 0 的代币账户
+    Close {
+        #[command(flatten)]
+        wallet: WalletArgs,
+        /// 每批处理的账户数量
+        #[arg(long, default_value_t = 5)]
+        batch_size: usize,
+        /// 是否将一批账户合并到一个交易中
+        #[arg(long)]
+        batch_tx: bool,
+        /// 批量交易使用的地址查找表（生成 v0 交易，待关闭账户会写入该表）
+        #[arg(long, requires = "batch_tx")]
+        lookup_table: Option<String>,
+        /// 新建地址查找表用于批量交易
+        #[arg(long, requires = "batch_tx", conflicts_with = "lookup_table")]
+        create_lookup_table: bool,
+        /// 从检查点恢复中断的批量关闭，跳过已关闭的账户（需要 --checkpoint）
+        #[arg(long, requires = "checkpoint", conflicts_with = "create_lookup_table")]
+        resume: bool,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// 销毁并关闭零值代币账户
+    Burn {
+        #[command(flatten)]
+        wallet: WalletArgs,
+        /// 每批处理的账户数量
+        #[arg(long, default_value_t = 10)]
+        batch_size: usize,
+        /// 额外加入白名单的代币符号
+        #[arg(long, value_delimiter = ',')]
+        whitelist: Vec<String>,
+        #[command(flatten)]
+        sell: SellArgs,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// 将零值代币账户中的剩余代币转入目标钱包并关闭账户
+    Sweep {
+        #[command(flatten)]
+        wallet: WalletArgs,
+        /// 接收代币的钱包地址或地址簿名称
+        #[arg(long)]
+        to: String,
+        /// 每批处理的账户数量
+        #[arg(long, default_value_t = 10)]
+        batch_size: usize,
+        /// 额外加入白名单的代币符号
+        #[arg(long, value_delimiter = ',')]
+        whitelist: Vec<String>,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// 对目录中的全部钱包执行扫描、关闭或销毁，并输出每个钱包的汇总
+    Multi {
+        /// 密钥文件目录（读取其中全部 .json 文件）
+        dir: PathBuf,
+        /// 要执行的操作
+        #[arg(long, value_enum, default_value = "scan")]
+        op: MultiOperation,
+        /// 同时处理的钱包数量，1 表示逐个执行
+        #[arg(long, default_value_t = 1)]
+        concurrency: usize,
+        /// 每批处理的账户数量
+        #[arg(long, default_value_t = 5)]
+        batch_size: usize,
+        /// 额外加入白名单的代币符号
+        #[arg(long, value_delimiter = ',')]
+        whitelist: Vec<String>,
+        #[command(flatten)]
+        sell: SellArgs,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// 将目录中全部钱包的 SOL（保留租金豁免最低余额和手续费）归集到主钱包
+    Consolidate {
+        /// 密钥文件目录（读取其中全部 .json 文件）
+        dir: PathBuf,
+        /// 接收 SOL 的主钱包地址或地址簿名称
+        #[arg(long)]
+        to: String,
+        /// 同时处理的钱包数量，1 表示逐个执行
+        #[arg(long, default_value_t = 1)]
+        concurrency: usize,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// 列出存在授权代理的代币账户，可选择全部撤销
+    Delegates {
+        #[command(flatten)]
+        wallet: WalletArgs,
+        /// 撤销列出的全部授权代理
+        #[arg(long)]
+        revoke: bool,
+        /// 每笔撤销交易包含的账户数量
+        #[arg(long, default_value_t = 10)]
+        batch_size: usize,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// 汇总运行历史中的清理记录，例如本月回收了多少租金
+    History {
+        /// 运行历史文件
+        #[arg(env = "SOLANA_USE_HISTORY")]
+        path: PathBuf,
+        /// 只统计指定月份（UTC），格式 YYYY-MM
+        #[arg(long)]
+        month: Option<String>,
+        /// 以 JSON 格式输出
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum MonitorCommand {
+    /// 订阅 Raydium 新流动性池
+    Watch {
+        /// WebSocket RPC URL
+        #[arg(long, env = "HELIUS_WS_RPC_URL")]
+        ws_url: String,
+        /// 同时订阅 Raydium CLMM（集中流动性）程序
+        #[arg(long)]
+        clmm: bool,
+        /// 同时订阅 Orca Whirlpool 程序
+        #[arg(long)]
+        whirlpool: bool,
+        /// 同时订阅 Phoenix 订单簿成交，需要同时指定 --all
+        #[arg(long, requires = "all")]
+        phoenix: bool,
+        /// 同时订阅 Jupiter v6 路由兑换（含每一跳的 DEX 和数量），需要同时指定 --all
+        #[arg(long, requires = "all")]
+        jupiter: bool,
+        /// 同时订阅 Jupiter DCA 定投订单，未指定 --all 时只输出买入新代币的定投
+        #[arg(long)]
+        dca: bool,
+        /// 推送全部事件（含兑换、流动性变动、开仓、平仓、订单簿成交、路由兑换和其他交易日志），默认只推送新建流动性池
+        #[arg(long)]
+        all: bool,
+        /// 同时将事件以 NDJSON 格式（每行一个 JSON）追加写入指定文件
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// 分析指定交易的 swap 信息
+    Analyze {
+        /// 交易签名
+        signature: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum TokenCommand {
+    /// 查询代币元数据和精度
+    Info {
+        /// 代币 Mint 地址
+        mint: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum KeysCommand {
+    /// 批量生成密钥对
+    Generate {
+        /// 生成数量
+        #[arg(default_value_t = 1)]
+        count: u16,
+        /// 输出目录，默认当前目录
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+        /// 文件名前缀
+        #[arg(long, default_value = "keypair")]
+        prefix: String,
+    },
+    /// 将密钥文件导入加密密钥库，密钥库不存在时自动创建
+    Import {
+        /// 密钥库中的名称
+        name: String,
+        /// 密钥文件（solana-keygen JSON 数组或 base58 字符串）
+        keypair: PathBuf,
+        /// 加密密钥库路径
+        #[arg(long, env = "KEYSTORE_PATH", default_value = "keystore.json")]
+        keystore: PathBuf,
+    },
+    /// 将明文钱包文件加密为单独的加密钱包文件，可直接作为 --wallet 使用
+    Encrypt {
+        /// 明文密钥文件（JSON 数组、base58 私钥或助记词）
+        keypair: PathBuf,
+        /// 加密钱包文件输出路径
+        output: PathBuf,
+    },
+    /// 列出加密密钥库中的密钥
+    List {
+        /// 加密密钥库路径
+        #[arg(long, env = "KEYSTORE_PATH", default_value = "keystore.json")]
+        keystore: PathBuf,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // -- 加载 .env 失败时继续使用进程环境变量
+    let _ = load_env();
+    init_tracing();
+
+    let cli = Cli::parse();
+    set_locale(cli.locale);
+
+    let result = match cli.command {
+        Command::Accounts(cmd) => match cmd {
+            AccountsCommand::Scan {
+                wallet,
+                filter,
+                export,
+            } => commands::accounts_scan(&wallet, &filter, export.as_deref()).await,
+            AccountsCommand::Close {
+                wallet,
+                batch_size,
+                batch_tx,
+                lookup_table,
+                create_lookup_table,
+                resume,
+                tx,
+            } => {
+                commands::accounts_close(
+                    &wallet,
+                    batch_size,
+                    batch_tx,
+                    lookup_table.as_deref(),
+                    create_lookup_table,
+                    resume,
+                    &tx,
+                )
+                .await
+            }
+            AccountsCommand::Burn {
+                wallet,
+                batch_size,
+                whitelist,
+                sell,
+                tx,
+            } => commands::accounts_burn(&wallet, batch_size, &whitelist, &sell, &tx).await,
+            AccountsCommand::Sweep {
+                wallet,
+                to,
+                batch_size,
+                whitelist,
+                tx,
+            } => commands::accounts_sweep(&wallet, &to, batch_size, &whitelist, &tx).await,
+            AccountsCommand::Multi {
+                dir,
+                op,
+                concurrency,
+                batch_size,
+                whitelist,
+                sell,
+                tx,
+            } => {
+                commands::accounts_multi(
+                    &dir,
+                    op.into(),
+                    concurrency,
+                    batch_size,
+                    &whitelist,
+                    &sell,
+                    &tx,
+                )
+                .await
+            }
+            AccountsCommand::Consolidate {
+                dir,
+                to,
+                concurrency,
+                tx,
+            } => commands::accounts_consolidate(&dir, &to, concurrency, &tx).await,
+            AccountsCommand::Delegates {
+                wallet,
+                revoke,
+                batch_size,
+                tx,
+            } => commands::accounts_delegates(&wallet, revoke, batch_size, &tx).await,
+            AccountsCommand::History { path, month, json } => {
+                commands::accounts_history(&path, month.as_deref(), json)
+            }
+        },
+        Command::Monitor(cmd) => match cmd {
+            MonitorCommand::Watch {
+                ws_url,
+                clmm,
+                whirlpool,
+                phoenix,
+                jupiter,
+                dca,
+                all,
+                output,
+            } => {
+                commands::monitor_watch(
+                    &ws_url,
+                    clmm,
+                    whirlpool,
+                    phoenix,
+                    jupiter,
+                    dca,
+                    all,
+                    output.as_deref(),
+                )
+                .await
+            }
+            MonitorCommand::Analyze { signature } => commands::monitor_analyze(signature).await,
+        },
+        Command::Token(TokenCommand::Info { mint }) => commands::token_info(&mint),
+        Command::Keys(cmd) => match cmd {
+            KeysCommand::Generate {
+                count,
+                out_dir,
+                prefix,
+            } => commands::keys_generate(count, out_dir, &prefix),
+            KeysCommand::Import {
+                name,
+                keypair,
+                keystore,
+            } => commands::keys_import(&keystore, &name, &keypair),
+            KeysCommand::Encrypt { keypair, output } => commands::keys_encrypt(&keypair, &output),
+            KeysCommand::List { keystore } => commands::keys_list(&keystore),
+        },
+        Command::Inspect { signature, json } => commands::inspect(&signature, json).await,
+        Command::Fund {
+            list,
+            target,
+            source,
+            max_airdrop,
+            wallet,
+        } => commands::fund(&wallet, &list, target, source.map(Into::into), max_airdrop).await,
+    };
+
+    result.map_err(|e| localize_error(e, cli.locale))
+}
+
+/// -- 按界面语言重新生成工具包错误的信息，其他错误保持不变
+fn localize_error(error: anyhow::Error, locale: Locale) -> anyhow::Error {
+    match error.downcast_ref::<TokenAccountError>() {
+        Some(e) if locale != Locale::Zh => anyhow::anyhow!(e.localized(locale)),
+        _ => error,
+    }
+}
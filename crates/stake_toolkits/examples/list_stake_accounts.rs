@@ -0,0 +1,20 @@
+use anyhow::Result;
+use stake_toolkits::StakeAccountManager;
+use std::error::Error;
+use utils::{init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    // -- 初始化质押账户管理器
+    let wallet_path = std::env::var("WALLET_PATH")?;
+    let manager = StakeAccountManager::new(&wallet_path)?;
+
+    // -- 列出质押账户
+    manager.get_stake_accounts().await?;
+
+    Ok(())
+}
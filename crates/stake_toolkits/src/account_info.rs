@@ -0,0 +1,52 @@
+use serde::Serialize;
+
+/// -- 质押账户激活状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeStatus {
+    Initialized,  // -- 已初始化但未委托，可直接提取
+    Activating,   // -- 激活中
+    Active,       // -- 已激活
+    Deactivating, // -- 取消激活中，当前 epoch 结束后可提取
+    Inactive,     // -- 已取消激活，可直接提取
+}
+
+/// -- 质押账户信息结构体
+#[derive(Debug, Clone, Serialize)]
+pub struct StakeAccountInfo {
+    pub address: String,                 // -- 质押账户地址
+    pub lamports: u64,                   // -- 账户总 lamports
+    pub sol: f64,                        // -- 账户总 SOL
+    pub rent_exempt_reserve: u64,        // -- 租金豁免最低余额
+    pub delegated_stake: u64,            // -- 委托的 lamports
+    pub voter: Option<String>,           // -- 委托的验证者投票账户
+    pub activation_epoch: Option<u64>,   // -- 激活 epoch
+    pub deactivation_epoch: Option<u64>, // -- 取消激活 epoch
+    pub staker: String,                  // -- staker 权限
+    pub withdrawer: String,              // -- withdrawer 权限
+    pub status: StakeStatus,             // -- 激活状态
+}
+
+impl StakeAccountInfo {
+    /// -- 当前可提取的 lamports
+    pub fn withdrawable_lamports(&self) -> u64 {
+        match self.status {
+            StakeStatus::Initialized | StakeStatus::Inactive => self.lamports,
+            _ => self
+                .lamports
+                .saturating_sub(self.delegated_stake)
+                .saturating_sub(self.rent_exempt_reserve),
+        }
+    }
+}
+
+/// -- 质押操作结果结构体
+/// 记录单个质押操作的结果
+#[derive(Debug)]
+pub struct StakeOperationResult {
+    pub success: bool,             // -- 操作是否成功
+    pub signature: Option<String>, // -- 成功时的交易签名
+    pub error: Option<String>,     // -- 失败时的错误信息
+    pub stake_account: String,     // -- 相关的质押账户地址
+    pub lamports: u64,             // -- 涉及的 lamports 数量
+}
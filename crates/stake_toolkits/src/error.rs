@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+/// -- 质押账户管理错误类型
+#[derive(Debug, Error)]
+pub enum StakeError {
+    /// RPC 客户端错误
+    #[error("RPC 错误: {0}")]
+    RpcError(#[from] solana_client::client_error::ClientError),
+
+    /// 密钥格式无效
+    #[error("无效的密钥格式")]
+    InvalidKeyFormat,
+
+    /// 账户数据解析错误
+    #[error("账户解析错误: {0}")]
+    AccountParseError(String),
+
+    /// 质押账户状态不允许该操作
+    #[error("质押状态错误: {0}")]
+    InvalidState(String),
+
+    /// 余额不足
+    #[error("余额不足: 需要 {needed} lamports, 可用 {available} lamports")]
+    InsufficientFunds { needed: u64, available: u64 },
+
+    /// 交易执行错误
+    #[error("交易错误: {0}")]
+    TransactionError(String),
+
+    /// IO 操作错误
+    #[error("IO 错误: {0}")]
+    IoError(#[from] std::io::Error),
+
+    /// JSON 解析错误
+    #[error("JSON 解析错误: {0}")]
+    JsonError(#[from] serde_json::Error),
+}
+
+/// -- 自定义 Result 类型
+pub type StakeResult<T> = Result<T, StakeError>;
@@ -0,0 +1,393 @@
+use account_info::*;
+use config::*;
+use operations::send_with_retry;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_sdk::{
+    account::Account,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    stake::{
+        self, instruction as stake_instruction,
+        state::{Authorized, Lockup, StakeStateV2},
+    },
+};
+use std::fs::read_to_string;
+use tracing::{error, info, warn};
+use utils::init_rpc_client;
+
+/// -- Solana 质押账户管理工具
+///
+/// 与 `solana_toolkits` 对应，提供质押账户的管理功能：
+/// - 查询钱包的质押账户
+/// - 创建并委托质押
+/// - 取消激活、提取
+/// - 合并、拆分质押账户
+pub mod account_info;
+pub mod config;
+mod error;
+mod operations;
+
+pub use error::*;
+
+/// -- 质押账户中 withdrawer 字段的偏移量
+///
+/// StakeStateV2 布局: enum tag(4) + rent_exempt_reserve(8) + staker(32) + withdrawer(32)
+const STAKE_WITHDRAWER_OFFSET: usize = 4 + 8 + 32;
+
+/// -- 质押账户管理器
+///
+/// 钱包同时作为 staker、withdrawer 和手续费支付者
+pub struct StakeAccountManager {
+    /// RPC 客户端连接
+    pub connection: RpcClient,
+    /// 钱包密钥对
+    pub wallet: Keypair,
+    /// 管理器配置
+    config: StakeConfig,
+}
+
+impl StakeAccountManager {
+    /// -- 创建新的质押账户管理器实例
+    ///
+    /// # 参数
+    /// * `wallet_key_path` - 钱包密钥文件路径
+    pub fn new(wallet_key_path: &str) -> StakeResult<Self> {
+        Self::with_config(wallet_key_path, StakeConfig::default())
+    }
+
+    /// -- 使用自定义配置创建质押账户管理器实例
+    ///
+    /// # 参数
+    /// * `wallet_key_path` - 钱包密钥文件路径
+    /// * `config` - 自定义配置参数
+    pub fn with_config(wallet_key_path: &str, config: StakeConfig) -> StakeResult<Self> {
+        let connection = init_rpc_client(config.commitment)?;
+
+        let key_str = read_to_string(wallet_key_path)?;
+        let key_value: serde_json::Value = serde_json::from_str(&key_str)?;
+        let private_key = key_value.as_str().ok_or(StakeError::InvalidKeyFormat)?;
+
+        let wallet = Keypair::from_base58_string(private_key);
+
+        Ok(Self {
+            connection,
+            wallet,
+            config,
+        })
+    }
+
+    /// -- 获取当前配置
+    pub fn get_config(&self) -> &StakeConfig {
+        &self.config
+    }
+
+    /// -- 获取钱包的所有质押账户
+    ///
+    /// 查询 withdrawer 为当前钱包的质押账户，并根据当前 epoch 计算激活状态。
+    pub async fn get_stake_accounts(&self) -> StakeResult<Vec<StakeAccountInfo>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                STAKE_WITHDRAWER_OFFSET,
+                self.wallet.pubkey().as_ref(),
+            ))]),
+            account_config: RpcAccountInfoConfig::default(),
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let accounts = self
+            .connection
+            .get_program_accounts_with_config(&stake::program::id(), config)?;
+        let current_epoch = self.connection.get_epoch_info()?.epoch;
+
+        let mut result = Vec::new();
+        for (pubkey, account) in accounts {
+            match parse_stake_account(&pubkey, &account, current_epoch) {
+                Ok(info) => result.push(info),
+                Err(e) => warn!("解析质押账户失败: {}, {}", pubkey, e),
+            }
+        }
+
+        info!("{}", "=".repeat(50));
+        info!("质押账户统计");
+        info!("{}", "=".repeat(50));
+        for (index, account) in result.iter().enumerate() {
+            info!("[账户 {}]", index + 1);
+            info!("地址: {}", account.address);
+            info!("余额: {} SOL", account.sol);
+            info!("状态: {:?}", account.status);
+            if let Some(voter) = &account.voter {
+                info!("验证者: {}", voter);
+            }
+        }
+        info!("{}", "=".repeat(50));
+
+        Ok(result)
+    }
+
+    /// -- 获取单个质押账户信息
+    pub async fn get_stake_account(&self, stake_pubkey: &Pubkey) -> StakeResult<StakeAccountInfo> {
+        let account = self.connection.get_account(stake_pubkey)?;
+        let current_epoch = self.connection.get_epoch_info()?.epoch;
+        parse_stake_account(stake_pubkey, &account, current_epoch)
+    }
+
+    /// -- 创建质押账户并委托给验证者
+    ///
+    /// # 参数
+    /// * `vote_pubkey` - 验证者投票账户
+    /// * `lamports` - 质押数量（包含租金豁免余额）
+    pub async fn create_and_delegate(
+        &self,
+        vote_pubkey: &Pubkey,
+        lamports: u64,
+    ) -> StakeOperationResult {
+        let stake_account = Keypair::new();
+        let stake_pubkey = stake_account.pubkey();
+        let authorized = Authorized::auto(&self.wallet.pubkey());
+
+        let instructions = stake_instruction::create_account_and_delegate_stake(
+            &self.wallet.pubkey(),
+            &stake_pubkey,
+            vote_pubkey,
+            &authorized,
+            &Lockup::default(),
+            lamports,
+        );
+
+        let result = send_with_retry(
+            &self.connection,
+            &self.wallet,
+            &instructions,
+            &[&stake_account],
+            self.config.max_retries,
+            self.config.retry_delay,
+        )
+        .await;
+
+        to_result(result, &stake_pubkey, lamports)
+    }
+
+    /// -- 取消激活质押账户
+    pub async fn deactivate(&self, stake_pubkey: &Pubkey) -> StakeOperationResult {
+        let instruction = stake_instruction::deactivate_stake(stake_pubkey, &self.wallet.pubkey());
+
+        let result = send_with_retry(
+            &self.connection,
+            &self.wallet,
+            &[instruction],
+            &[],
+            self.config.max_retries,
+            self.config.retry_delay,
+        )
+        .await;
+
+        to_result(result, stake_pubkey, 0)
+    }
+
+    /// -- 从质押账户提取 SOL 到钱包
+    ///
+    /// # 参数
+    /// * `stake_pubkey` - 质押账户
+    /// * `lamports` - 提取数量，`None` 表示提取全部可提取余额
+    pub async fn withdraw(
+        &self,
+        stake_pubkey: &Pubkey,
+        lamports: Option<u64>,
+    ) -> StakeOperationResult {
+        let info = match self.get_stake_account(stake_pubkey).await {
+            Ok(info) => info,
+            Err(e) => return to_result(Err(e), stake_pubkey, 0),
+        };
+
+        let available = info.withdrawable_lamports();
+        let amount = lamports.unwrap_or(available);
+        if amount == 0 || amount > available {
+            return to_result(
+                Err(StakeError::InsufficientFunds {
+                    needed: amount,
+                    available,
+                }),
+                stake_pubkey,
+                0,
+            );
+        }
+
+        let instruction = stake_instruction::withdraw(
+            stake_pubkey,
+            &self.wallet.pubkey(),
+            &self.wallet.pubkey(),
+            amount,
+            None,
+        );
+
+        let result = send_with_retry(
+            &self.connection,
+            &self.wallet,
+            &[instruction],
+            &[],
+            self.config.max_retries,
+            self.config.retry_delay,
+        )
+        .await;
+
+        to_result(result, stake_pubkey, amount)
+    }
+
+    /// -- 合并质押账户
+    ///
+    /// 将 `source` 合并到 `destination`，两者需要处于兼容的激活状态。
+    pub async fn merge(&self, destination: &Pubkey, source: &Pubkey) -> StakeOperationResult {
+        let instructions = stake_instruction::merge(destination, source, &self.wallet.pubkey());
+
+        let result = send_with_retry(
+            &self.connection,
+            &self.wallet,
+            &instructions,
+            &[],
+            self.config.max_retries,
+            self.config.retry_delay,
+        )
+        .await;
+
+        to_result(result, destination, 0)
+    }
+
+    /// -- 拆分质押账户
+    ///
+    /// 从 `stake_pubkey` 拆分出 `lamports` 到新建的质押账户，返回结果中的地址为新账户。
+    pub async fn split(&self, stake_pubkey: &Pubkey, lamports: u64) -> StakeOperationResult {
+        let split_account = Keypair::new();
+        let split_pubkey = split_account.pubkey();
+
+        let instructions =
+            stake_instruction::split(stake_pubkey, &self.wallet.pubkey(), lamports, &split_pubkey);
+
+        let result = send_with_retry(
+            &self.connection,
+            &self.wallet,
+            &instructions,
+            &[&split_account],
+            self.config.max_retries,
+            self.config.retry_delay,
+        )
+        .await;
+
+        to_result(result, &split_pubkey, lamports)
+    }
+
+    /// -- 批量提取所有已取消激活的质押账户
+    pub async fn withdraw_all_inactive(&self) -> StakeResult<Vec<StakeOperationResult>> {
+        let accounts = self.get_stake_accounts().await?;
+        let mut results = Vec::new();
+
+        for account in accounts
+            .iter()
+            .filter(|a| matches!(a.status, StakeStatus::Inactive | StakeStatus::Initialized))
+        {
+            let pubkey = account
+                .address
+                .parse::<Pubkey>()
+                .map_err(|e| StakeError::AccountParseError(e.to_string()))?;
+            let result = self.withdraw(&pubkey, None).await;
+
+            if result.success {
+                info!(
+                    "成功提取: {} SOL",
+                    result.lamports as f64 / LAMPORTS_PER_SOL as f64
+                );
+            } else if let Some(e) = &result.error {
+                error!("提取失败: {}, {}", result.stake_account, e);
+            }
+            results.push(result);
+
+            tokio::time::sleep(self.config.batch_delay).await;
+        }
+
+        Ok(results)
+    }
+}
+
+/// -- 解析质押账户数据，并根据当前 epoch 计算激活状态
+///
+/// # 参数
+/// * `pubkey` - 质押账户地址
+/// * `account` - 质押账户
+/// * `current_epoch` - 当前 epoch
+pub fn parse_stake_account(
+    pubkey: &Pubkey,
+    account: &Account,
+    current_epoch: u64,
+) -> StakeResult<StakeAccountInfo> {
+    if account.owner != stake::program::id() {
+        return Err(StakeError::AccountParseError(format!(
+            "{} 不是质押账户，所有者为 {}",
+            pubkey, account.owner
+        )));
+    }
+    let state: StakeStateV2 = bincode::deserialize(&account.data)
+        .map_err(|e| StakeError::AccountParseError(e.to_string()))?;
+
+    let meta = state
+        .meta()
+        .ok_or_else(|| StakeError::InvalidState("未初始化的质押账户".to_string()))?;
+    let delegation = state.delegation();
+
+    let status = match &delegation {
+        None => StakeStatus::Initialized,
+        Some(d) if d.deactivation_epoch == u64::MAX => {
+            if d.activation_epoch >= current_epoch {
+                StakeStatus::Activating
+            } else {
+                StakeStatus::Active
+            }
+        }
+        Some(d) if d.deactivation_epoch >= current_epoch => StakeStatus::Deactivating,
+        Some(_) => StakeStatus::Inactive,
+    };
+
+    Ok(StakeAccountInfo {
+        address: pubkey.to_string(),
+        lamports: account.lamports,
+        sol: account.lamports as f64 / LAMPORTS_PER_SOL as f64,
+        rent_exempt_reserve: meta.rent_exempt_reserve,
+        delegated_stake: delegation.map(|d| d.stake).unwrap_or(0),
+        voter: delegation.map(|d| d.voter_pubkey.to_string()),
+        activation_epoch: delegation.map(|d| d.activation_epoch),
+        deactivation_epoch: delegation
+            .map(|d| d.deactivation_epoch)
+            .filter(|e| *e != u64::MAX),
+        staker: meta.authorized.staker.to_string(),
+        withdrawer: meta.authorized.withdrawer.to_string(),
+        status,
+    })
+}
+
+/// -- 将操作结果转换为友好的结果格式
+fn to_result(
+    result: StakeResult<String>,
+    stake_pubkey: &Pubkey,
+    lamports: u64,
+) -> StakeOperationResult {
+    match result {
+        Ok(signature) => StakeOperationResult {
+            success: true,
+            signature: Some(signature),
+            error: None,
+            stake_account: stake_pubkey.to_string(),
+            lamports,
+        },
+        Err(e) => StakeOperationResult {
+            success: false,
+            signature: None,
+            error: Some(e.to_string()),
+            stake_account: stake_pubkey.to_string(),
+            lamports: 0,
+        },
+    }
+}
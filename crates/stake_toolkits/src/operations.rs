@@ -0,0 +1,55 @@
+use crate::{StakeError, StakeResult};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transaction::Transaction,
+};
+use std::time::Duration;
+use tracing::warn;
+use utils::RpcApi;
+
+/// -- 签名并发送交易，失败时按配置重试
+///
+/// 每次重试都会重新获取最新区块哈希并重新签名。
+///
+/// # 参数
+/// * `connection` - RPC 客户端连接
+/// * `payer` - 手续费支付者
+/// * `instructions` - 交易指令
+/// * `extra_signers` - 额外签名者（如新建的质押账户）
+/// * `max_retries` - 最大重试次数
+/// * `retry_delay` - 重试间隔
+///
+/// # 返回
+/// * `StakeResult<String>` - 成功返回交易签名
+pub async fn send_with_retry(
+    connection: &RpcClient,
+    payer: &Keypair,
+    instructions: &[Instruction],
+    extra_signers: &[&Keypair],
+    max_retries: u32,
+    retry_delay: Duration,
+) -> StakeResult<String> {
+    let mut signers: Vec<&Keypair> = vec![payer];
+    signers.extend_from_slice(extra_signers);
+
+    let mut retries = 0;
+    loop {
+        let blockhash = connection.get_latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &signers,
+            blockhash,
+        );
+
+        match RpcApi::send_and_confirm_transaction(connection, &transaction) {
+            Ok(signature) => return Ok(signature.to_string()),
+            Err(e) if retries < max_retries => {
+                retries += 1;
+                warn!("重试第 {} 次: {}", retries, e);
+                tokio::time::sleep(retry_delay).await;
+            }
+            Err(e) => return Err(StakeError::TransactionError(e.to_string())),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * solana_use C ABI
+ *
+ * Raydium 指令解码与可关闭账户扫描，结果均为 UTF-8 JSON 字符串。
+ *
+ * 约定：
+ *   - 成功返回 SU_OK，并通过 out_json 输出字符串，使用 su_string_free 释放
+ *   - 失败时 *out_json 为 NULL，可通过 su_last_error 获取当前线程的错误信息
+ *
+ * 示例：
+ *   char *json = NULL;
+ *   if (su_decode_raydium_ix("...", &json) == SU_OK) {
+ *       puts(json);
+ *       su_string_free(json);
+ *   } else {
+ *       fprintf(stderr, "%s\n", su_last_error());
+ *   }
+ */
+#ifndef SOLANA_USE_H
+#define SOLANA_USE_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define SU_ABI_VERSION 1
+
+typedef enum SuStatus {
+    SU_OK = 0,               /* 成功 */
+    SU_INVALID_ARGUMENT = 1, /* 参数为空或不是有效的 UTF-8 */
+    SU_DECODE_ERROR = 2,     /* 指令数据解码失败 */
+    SU_SCAN_ERROR = 3,       /* 账户扫描失败 */
+    SU_PANIC = 4,            /* 内部错误 */
+} SuStatus;
+
+/* ABI 版本，与 SU_ABI_VERSION 不一致时说明头文件和库不匹配 */
+uint32_t su_abi_version(void);
+
+/* 根据指令标识自动解码全部 AMM v4 指令，输出 {"type": "initialize2" | "swapBaseIn" | "swapBaseOut" | "deposit" | ..., ...} */
+SuStatus su_decode_raydium_ix(const char *data_base58, char **out_json);
+
+/* 解码 swapBaseIn 指令数据 */
+SuStatus su_decode_swap_ix(const char *data_base58, char **out_json);
+
+/* 解码 initialize2 指令数据 */
+SuStatus su_decode_initialize_ix(const char *data_base58, char **out_json);
+
+/* 扫描钱包的可关闭账户和零值代币账户（阻塞，RPC 地址读取 RPC_URL 环境变量） */
+SuStatus su_scan_closeable_accounts(const char *wallet_path, char **out_json);
+
+/* 当前线程最近一次的错误信息，没有错误时为 NULL；由库持有，不要释放 */
+const char *su_last_error(void);
+
+/* 释放本库返回的字符串 */
+void su_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SOLANA_USE_H */
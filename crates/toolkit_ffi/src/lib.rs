@@ -0,0 +1,235 @@
+//! # C ABI 绑定
+//!
+//! 以稳定的 C ABI 导出 Raydium 指令解码和可关闭账户扫描，结果统一为 JSON 字符串，
+//! C++ / C# 等交易系统可以直接链接动态库或静态库使用，无需重复实现解码和扫描逻辑。
+//!
+//! 约定：
+//! - 所有函数返回 [`SuStatus`]，成功时通过 `out_json` 输出 UTF-8 JSON 字符串，
+//!   调用方必须使用 [`su_string_free`] 释放
+//! - 失败时可调用 [`su_last_error`] 获取当前线程最近一次的错误信息
+//! - 函数内部的 panic 会被捕获并转换为 `SU_PANIC`，不会跨越 FFI 边界
+//!
+//! 头文件位于 `include/solana_use.h`。构建:
+//! ```bash
+//! cargo build -p toolkit_ffi --release
+//! ```
+use anyhow::Result;
+use raydium_monitor::decoder::{decode_ix_data, decode_raydium_ix};
+use raydium_monitor::model::{RaydiumInstruction, SwapIxData};
+use solana_toolkits::TokenAccountManager;
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr, CString},
+    panic::{catch_unwind, UnwindSafe},
+    ptr,
+    sync::OnceLock,
+};
+use tokio::runtime::Runtime;
+
+/// -- ABI 版本，导出函数的签名或语义发生不兼容变化时递增
+pub const SU_ABI_VERSION: u32 = 1;
+
+/// -- 扫描使用的 tokio 运行时，首次扫描时创建
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+thread_local! {
+    /// -- 当前线程最近一次的错误信息
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// -- 调用结果状态码
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuStatus {
+    /// 成功
+    Ok = 0,
+    /// 参数为空或不是有效的 UTF-8
+    InvalidArgument = 1,
+    /// 指令数据解码失败
+    DecodeError = 2,
+    /// 账户扫描失败
+    ScanError = 3,
+    /// 内部 panic
+    Panic = 4,
+}
+
+/// -- 返回 ABI 版本
+#[no_mangle]
+pub extern "C" fn su_abi_version() -> u32 {
+    SU_ABI_VERSION
+}
+
+/// -- 根据指令标识自动解码 Raydium 指令数据
+///
+/// # 参数
+/// * `data` - base58 编码的指令数据
+/// * `out_json` - 输出 `{"type": "initialize2" | "swapBaseIn" | "swapBaseOut" | "deposit" | ..., ...}`
+///
+/// # Safety
+/// `data` 必须是以 NUL 结尾的有效字符串，`out_json` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn su_decode_raydium_ix(
+    data: *const c_char,
+    out_json: *mut *mut c_char,
+) -> SuStatus {
+    ffi_call(out_json, SuStatus::DecodeError, || {
+        let decoded = decode_raydium_ix(read_str(data)?)?;
+        Ok(serde_json::to_string(&decoded)?)
+    })
+}
+
+/// -- 解码 swapBaseIn 指令数据
+///
+/// # Safety
+/// 同 [`su_decode_raydium_ix`]
+#[no_mangle]
+pub unsafe extern "C" fn su_decode_swap_ix(
+    data: *const c_char,
+    out_json: *mut *mut c_char,
+) -> SuStatus {
+    ffi_call(out_json, SuStatus::DecodeError, || {
+        let ix: SwapIxData = decode_ix_data(read_str(data)?)?;
+        Ok(serde_json::to_string(&ix)?)
+    })
+}
+
+/// -- 解码 initialize2 指令数据
+///
+/// # Safety
+/// 同 [`su_decode_raydium_ix`]
+#[no_mangle]
+pub unsafe extern "C" fn su_decode_initialize_ix(
+    data: *const c_char,
+    out_json: *mut *mut c_char,
+) -> SuStatus {
+    ffi_call(out_json, SuStatus::DecodeError, || {
+        let ix: RaydiumInstruction = decode_ix_data(read_str(data)?)?;
+        Ok(serde_json::to_string(&ix)?)
+    })
+}
+
+/// -- 扫描钱包的可关闭账户和零值代币账户
+///
+/// RPC 地址读取 `RPC_URL` 环境变量（会先尝试加载工作空间的 .env）。
+/// 调用会阻塞到扫描完成。
+///
+/// # 参数
+/// * `wallet_path` - 钱包密钥文件路径
+/// * `out_json` - 输出扫描结果（`TokenAccountsResult` 的 JSON）
+///
+/// # Safety
+/// `wallet_path` 必须是以 NUL 结尾的有效字符串，`out_json` 必须是可写的指针
+#[no_mangle]
+pub unsafe extern "C" fn su_scan_closeable_accounts(
+    wallet_path: *const c_char,
+    out_json: *mut *mut c_char,
+) -> SuStatus {
+    ffi_call(out_json, SuStatus::ScanError, || {
+        let wallet_path = read_str(wallet_path)?;
+        let _ = utils::load_env();
+
+        let runtime = match RUNTIME.get() {
+            Some(runtime) => runtime,
+            None => {
+                let runtime = Runtime::new()?;
+                RUNTIME.get_or_init(|| runtime)
+            }
+        };
+
+        let manager = TokenAccountManager::new(wallet_path)?;
+        let result = runtime.block_on(manager.get_closeable_accounts())?;
+        Ok(serde_json::to_string(&result)?)
+    })
+}
+
+/// -- 当前线程最近一次的错误信息
+///
+/// 没有错误时返回空指针。返回的字符串由库持有，在当前线程下一次调用前有效，不要释放。
+#[no_mangle]
+pub extern "C" fn su_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// -- 释放由本库返回的字符串
+///
+/// # Safety
+/// `s` 必须是本库返回的字符串或空指针，且只能释放一次
+#[no_mangle]
+pub unsafe extern "C" fn su_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// -- 执行导出函数：捕获 panic，成功时写出 JSON，失败时记录错误信息
+unsafe fn ffi_call<F>(out_json: *mut *mut c_char, error_status: SuStatus, f: F) -> SuStatus
+where
+    F: FnOnce() -> Result<String> + UnwindSafe,
+{
+    if out_json.is_null() {
+        set_last_error("out_json 不能为空");
+        return SuStatus::InvalidArgument;
+    }
+    *out_json = ptr::null_mut();
+
+    match catch_unwind(f) {
+        Ok(Ok(json)) => match CString::new(json) {
+            Ok(json) => {
+                clear_last_error();
+                *out_json = json.into_raw();
+                SuStatus::Ok
+            }
+            Err(e) => {
+                set_last_error(e.to_string());
+                error_status
+            }
+        },
+        Ok(Err(e)) => {
+            set_last_error(format!("{:#}", e));
+            if e.is::<InvalidArgument>() {
+                SuStatus::InvalidArgument
+            } else {
+                error_status
+            }
+        }
+        Err(_) => {
+            set_last_error("内部错误（panic）");
+            SuStatus::Panic
+        }
+    }
+}
+
+/// -- 参数错误
+#[derive(Debug)]
+struct InvalidArgument(&'static str);
+
+impl std::fmt::Display for InvalidArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for InvalidArgument {}
+
+/// -- 读取 C 字符串参数
+unsafe fn read_str<'a>(s: *const c_char) -> Result<&'a str> {
+    if s.is_null() {
+        return Err(anyhow::Error::new(InvalidArgument("参数不能为空")));
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| anyhow::Error::new(InvalidArgument("参数不是有效的 UTF-8")))
+}
+
+fn set_last_error(message: impl Into<String>) {
+    let message = CString::new(message.into().replace('\0', " ")).ok();
+    LAST_ERROR.with(|last| *last.borrow_mut() = message);
+}
+
+fn clear_last_error() {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+}
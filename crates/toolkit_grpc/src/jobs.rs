@@ -0,0 +1,178 @@
+use crate::pb::{CleanupJob, JobStatus};
+use solana_toolkits::TokenAccountManager;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tracing::{error, info};
+
+/// -- 已结束任务的保留时长，超过后不再能查询
+const FINISHED_JOB_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// -- 最多保留的已结束任务数量，超过时淘汰最早结束的任务
+const MAX_FINISHED_JOBS: usize = 100;
+
+/// -- 清理任务参数
+#[derive(Debug, Clone, Copy)]
+pub struct CleanupParams {
+    pub batch_size: usize,     // -- 每批处理的账户数量
+    pub use_batch_tx: bool,    // -- 是否合并为批量交易
+    pub burn_zero_value: bool, // -- 是否销毁零值代币账户
+}
+
+/// -- 登记表中的任务记录
+struct JobEntry {
+    job: CleanupJob,              // -- 任务状态
+    wallet_key_path: String,      // -- 执行任务的钱包密钥文件路径
+    finished_at: Option<Instant>, // -- 任务结束时间，未结束时为 None
+}
+
+/// -- 清理任务登记表
+///
+/// 任务在后台线程执行，状态保存在内存中，服务重启后丢失。
+/// 同一钱包同时只能有一个等待或执行中的任务；已结束的任务保留 [`FINISHED_JOB_TTL`]，
+/// 最多保留 [`MAX_FINISHED_JOBS`] 个
+#[derive(Clone, Default)]
+pub struct JobRegistry {
+    jobs: Arc<Mutex<HashMap<String, JobEntry>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl JobRegistry {
+    /// -- 查询任务状态
+    pub fn get(&self, job_id: &str) -> Option<CleanupJob> {
+        let mut jobs = self.jobs.lock().unwrap();
+        evict_finished(&mut jobs);
+        jobs.get(job_id).map(|entry| entry.job.clone())
+    }
+
+    /// -- 提交清理任务
+    ///
+    /// # 参数
+    /// * `wallet_key_path` - 钱包密钥文件路径
+    /// * `params` - 清理参数
+    ///
+    /// # 返回
+    /// * `Result<CleanupJob, CleanupJob>` - 处于等待状态的新任务；该钱包已有未结束的任务时返回该任务
+    pub fn submit(
+        &self,
+        wallet_key_path: String,
+        params: CleanupParams,
+    ) -> Result<CleanupJob, CleanupJob> {
+        let mut jobs = self.jobs.lock().unwrap();
+        evict_finished(&mut jobs);
+        if let Some(active) = jobs
+            .values()
+            .find(|entry| entry.finished_at.is_none() && entry.wallet_key_path == wallet_key_path)
+        {
+            return Err(active.job.clone());
+        }
+
+        let job_id = format!("job-{}", self.next_id.fetch_add(1, Ordering::Relaxed) + 1);
+        let job = CleanupJob {
+            job_id: job_id.clone(),
+            status: JobStatus::Pending as i32,
+            accounts_processed: 0,
+            error: String::new(),
+        };
+        let entry = JobEntry {
+            job: job.clone(),
+            wallet_key_path: wallet_key_path.clone(),
+            finished_at: None,
+        };
+        jobs.insert(job_id.clone(), entry);
+        drop(jobs);
+
+        let registry = self.clone();
+        let handle = tokio::runtime::Handle::current();
+        tokio::task::spawn_blocking(move || {
+            registry.update(&job_id, |job| job.status = JobStatus::Running as i32);
+            info!("开始执行清理任务: {}", job_id);
+
+            match handle.block_on(run_cleanup(&wallet_key_path, params)) {
+                Ok(processed) => {
+                    info!("清理任务完成: {}, 处理账户 {} 个", job_id, processed);
+                    registry.finish(&job_id, |job| {
+                        job.status = JobStatus::Succeeded as i32;
+                        job.accounts_processed = processed as u32;
+                    });
+                }
+                Err(e) => {
+                    error!("清理任务失败: {}, {}", job_id, e);
+                    registry.finish(&job_id, |job| {
+                        job.status = JobStatus::Failed as i32;
+                        job.error = e;
+                    });
+                }
+            }
+        });
+
+        Ok(job)
+    }
+
+    fn update(&self, job_id: &str, f: impl FnOnce(&mut CleanupJob)) {
+        if let Some(entry) = self.jobs.lock().unwrap().get_mut(job_id) {
+            f(&mut entry.job);
+        }
+    }
+
+    /// -- 更新任务的最终状态并记录结束时间
+    fn finish(&self, job_id: &str, f: impl FnOnce(&mut CleanupJob)) {
+        if let Some(entry) = self.jobs.lock().unwrap().get_mut(job_id) {
+            f(&mut entry.job);
+            entry.finished_at = Some(Instant::now());
+        }
+    }
+}
+
+/// -- 淘汰超过保留时长的已结束任务，数量仍超过上限时淘汰最早结束的任务
+fn evict_finished(jobs: &mut HashMap<String, JobEntry>) {
+    jobs.retain(|_, entry| {
+        entry
+            .finished_at
+            .is_none_or(|finished_at| finished_at.elapsed() < FINISHED_JOB_TTL)
+    });
+
+    let mut finished: Vec<_> = jobs
+        .iter()
+        .filter_map(|(job_id, entry)| Some((entry.finished_at?, job_id.clone())))
+        .collect();
+    if finished.len() > MAX_FINISHED_JOBS {
+        finished.sort();
+        for (_, job_id) in &finished[..finished.len() - MAX_FINISHED_JOBS] {
+            jobs.remove(job_id);
+        }
+    }
+}
+
+/// -- 执行一次清理，返回处理的账户数量
+async fn run_cleanup(wallet_key_path: &str, params: CleanupParams) -> Result<usize, String> {
+    let manager = TokenAccountManager::new(wallet_key_path).map_err(|e| e.to_string())?;
+    let accounts = manager
+        .get_closeable_accounts()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let report = manager
+        .batch_close_accounts(&accounts.accounts, params.batch_size, params.use_batch_tx)
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut processed = report.succeeded;
+
+    if params.burn_zero_value {
+        let report = manager
+            .batch_burn_and_close_zero_value_accounts(
+                &accounts.zero_value_accounts_list,
+                params.batch_size,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        processed += report.succeeded;
+    }
+
+    Ok(processed)
+}
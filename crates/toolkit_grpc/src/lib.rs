@@ -0,0 +1,167 @@
+//! # 工具包 gRPC 服务
+//!
+//! 基于 tonic 将代币账户扫描、后台清理任务和 Raydium 监控事件流以 gRPC 接口暴露，
+//! 供基础设施中的其他服务通过类型化的协议调用。接口定义见 `proto/toolkit.proto`。
+use jobs::{CleanupParams, JobRegistry};
+use pb::toolkit_server::Toolkit;
+use pb::{
+    CleanupJob, CleanupJobRequest, GetCleanupJobRequest, MonitorEvent, MonitorRequest, ScanRequest,
+    ScanResponse, TokenAccount,
+};
+use solana_toolkits::TokenAccountManager;
+use std::pin::Pin;
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, Stream};
+use tonic::{Request, Response, Status};
+use tracing::{info, instrument};
+
+pub mod jobs;
+pub mod monitor;
+
+/// -- 由 `proto/toolkit.proto` 生成的类型
+pub mod pb {
+    tonic::include_proto!("solana_use.toolkit.v1");
+}
+
+pub use pb::toolkit_server::ToolkitServer;
+
+/// -- 默认批处理大小
+const DEFAULT_BATCH_SIZE: usize = 5;
+
+/// -- 监控事件通道容量
+const EVENT_CHANNEL_SIZE: usize = 128;
+
+/// -- 服务配置
+#[derive(Debug, Clone)]
+pub struct ServiceConfig {
+    /// 钱包密钥文件路径
+    pub wallet_key_path: String,
+    /// 监控使用的 WebSocket 地址
+    pub ws_url: String,
+}
+
+/// -- gRPC 服务实现
+pub struct ToolkitService {
+    config: ServiceConfig,
+    jobs: JobRegistry,
+}
+
+impl ToolkitService {
+    /// -- 创建服务实例
+    pub fn new(config: ServiceConfig) -> Self {
+        Self {
+            config,
+            jobs: JobRegistry::default(),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Toolkit for ToolkitService {
+    #[instrument(skip_all)]
+    async fn scan_closeable_accounts(
+        &self,
+        _request: Request<ScanRequest>,
+    ) -> Result<Response<ScanResponse>, Status> {
+        let wallet_key_path = self.config.wallet_key_path.clone();
+
+        // -- 扫描过程使用阻塞 RPC，放到阻塞线程执行
+        let handle = tokio::runtime::Handle::current();
+        let result = tokio::task::spawn_blocking(move || {
+            let manager = TokenAccountManager::new(&wallet_key_path)?;
+            handle.block_on(manager.get_closeable_accounts())
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?
+        .map_err(|e| Status::internal(e.to_string()))?;
+
+        let response = ScanResponse {
+            total_accounts: result.total_accounts as u32,
+            closeable_accounts: result
+                .accounts
+                .iter()
+                .map(|a| TokenAccount {
+                    address: a.address.clone(),
+                    mint: a.mint.clone(),
+                    symbol: a.symbol.clone(),
+                    balance: 0,
+                    rent_lamports: a.rent_lamports,
+                })
+                .collect(),
+            zero_value_accounts: result
+                .zero_value_accounts_list
+                .iter()
+                .map(|a| TokenAccount {
+                    address: a.address.clone(),
+                    mint: a.mint.clone(),
+                    symbol: a.symbol.clone(),
+                    balance: a.balance,
+                    rent_lamports: a.rent_lamports,
+                })
+                .collect(),
+            total_rent_lamports: result.total_rent_lamports,
+        };
+
+        Ok(Response::new(response))
+    }
+
+    #[instrument(skip_all)]
+    async fn submit_cleanup_job(
+        &self,
+        request: Request<CleanupJobRequest>,
+    ) -> Result<Response<CleanupJob>, Status> {
+        let request = request.into_inner();
+        let params = CleanupParams {
+            batch_size: match request.batch_size {
+                0 => DEFAULT_BATCH_SIZE,
+                n => n as usize,
+            },
+            use_batch_tx: request.use_batch_tx,
+            burn_zero_value: request.burn_zero_value,
+        };
+
+        // -- 同一钱包已有等待或执行中的任务时拒绝提交，避免两个任务同时处理同一批账户
+        let job = self
+            .jobs
+            .submit(self.config.wallet_key_path.clone(), params)
+            .map_err(|active| {
+                Status::already_exists(format!("钱包已有未结束的清理任务: {}", active.job_id))
+            })?;
+        info!("已提交清理任务: {}", job.job_id);
+
+        Ok(Response::new(job))
+    }
+
+    async fn get_cleanup_job(
+        &self,
+        request: Request<GetCleanupJobRequest>,
+    ) -> Result<Response<CleanupJob>, Status> {
+        let job_id = request.into_inner().job_id;
+        self.jobs
+            .get(&job_id)
+            .map(Response::new)
+            .ok_or_else(|| Status::not_found(format!("任务不存在: {}", job_id)))
+    }
+
+    type StreamMonitorEventsStream =
+        Pin<Box<dyn Stream<Item = Result<MonitorEvent, Status>> + Send + 'static>>;
+
+    #[instrument(skip_all)]
+    async fn stream_monitor_events(
+        &self,
+        request: Request<MonitorRequest>,
+    ) -> Result<Response<Self::StreamMonitorEventsStream>, Status> {
+        let request = request.into_inner();
+        let decoders = monitor::monitor_decoders(&request.program_ids)?;
+
+        let (sender, receiver) = mpsc::channel(EVENT_CHANNEL_SIZE);
+        monitor::forward_monitor_events(
+            self.config.ws_url.clone(),
+            decoders,
+            request.new_pools_only,
+            sender,
+        );
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(receiver))))
+    }
+}
@@ -0,0 +1,27 @@
+use anyhow::Result;
+use toolkit_grpc::{ServiceConfig, ToolkitServer, ToolkitService};
+use tracing::info;
+use utils::{init_tracing, load_env};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    let addr = std::env::var("GRPC_ADDR")
+        .unwrap_or_else(|_| "127.0.0.1:50051".to_string())
+        .parse()?;
+    let config = ServiceConfig {
+        wallet_key_path: std::env::var("WALLET_PATH")?,
+        ws_url: std::env::var("HELIUS_WS_RPC_URL")?,
+    };
+
+    info!("gRPC 服务启动: {}", addr);
+    tonic::transport::Server::builder()
+        .add_service(ToolkitServer::new(ToolkitService::new(config)))
+        .serve(addr)
+        .await?;
+
+    Ok(())
+}
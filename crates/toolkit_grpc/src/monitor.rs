@@ -0,0 +1,133 @@
+use crate::pb::{MonitorEvent, NewPool};
+use futures::future::BoxFuture;
+use raydium_monitor::{
+    model,
+    services::{DecoderRegistry, RaydiumMonitor},
+    sink::EventSink,
+};
+use tokio::sync::mpsc;
+use tonic::Status;
+use tracing::{error, info};
+
+/// -- Raydium AMM v4 程序 ID
+pub const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+/// -- 将监控事件转换为 gRPC 消息并发送到客户端的事件输出端
+struct GrpcSink {
+    sender: mpsc::Sender<Result<MonitorEvent, Status>>, // -- 客户端事件流发送端
+}
+
+impl EventSink for GrpcSink {
+    fn handle<'a>(&'a self, event: &'a model::MonitorEvent) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            self.sender
+                .send(Ok(to_proto(event)?))
+                .await
+                .map_err(|_| anyhow::anyhow!("客户端已断开"))
+        })
+    }
+
+    fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+/// -- 根据请求的程序 ID 选择解码器
+///
+/// AMM v4 始终订阅；其他程序 ID 需要有对应的内置解码器（CLMM、Whirlpool、Phoenix、
+/// Jupiter v6、Jupiter DCA），否则返回 `InvalidArgument`。
+///
+/// # 参数
+/// * `program_ids` - 请求订阅的程序 ID
+pub fn monitor_decoders(program_ids: &[String]) -> Result<DecoderRegistry, Status> {
+    let builtin = DecoderRegistry::builtin();
+    let mut decoders = DecoderRegistry::new();
+    for program_id in program_ids.iter().filter(|id| *id != RAYDIUM_AMM_V4) {
+        let decoder = builtin
+            .get(program_id)
+            .ok_or_else(|| Status::invalid_argument(format!("不支持的程序 ID: {}", program_id)))?;
+        decoders.register(decoder.clone());
+    }
+    Ok(decoders)
+}
+
+/// -- 订阅程序日志并将监控事件推送到通道
+///
+/// 在后台任务中运行 `RaydiumMonitor`，客户端断开（通道关闭）或订阅出错时退出。
+///
+/// # 参数
+/// * `ws_url` - WebSocket 地址
+/// * `decoders` - 除 AMM v4 外同时订阅的程序的解码器
+/// * `new_pools_only` - 是否只推送新建流动性池事件
+/// * `sender` - 事件发送端
+pub fn forward_monitor_events(
+    ws_url: String,
+    decoders: DecoderRegistry,
+    new_pools_only: bool,
+    sender: mpsc::Sender<Result<MonitorEvent, Status>>,
+) {
+    tokio::spawn(async move {
+        let monitor = match RaydiumMonitor::new(ws_url) {
+            Ok(monitor) => monitor,
+            Err(e) => {
+                let _ = sender.send(Err(Status::unavailable(e.to_string()))).await;
+                return;
+            }
+        };
+        info!("监控事件流已建立");
+
+        let sink = GrpcSink {
+            sender: sender.clone(),
+        };
+        let result = monitor
+            .with_decoders(decoders)
+            .new_pools_only(new_pools_only)
+            .with_sink(sink)
+            .run_sinks()
+            .await;
+
+        match result {
+            Ok(()) => info!("客户端已断开，停止推送监控事件"),
+            Err(e) => {
+                error!("日志订阅已中断: {:?}", e);
+                let _ = sender.send(Err(Status::aborted(e.to_string()))).await;
+            }
+        }
+    });
+}
+
+/// -- 将监控事件转换为 gRPC 消息
+///
+/// 完整事件以 JSON 保存在 `event_json` 中，新建流动性池额外填充 `new_pool`。
+fn to_proto(event: &model::MonitorEvent) -> anyhow::Result<MonitorEvent> {
+    let value = serde_json::to_value(event)?;
+    let field = |name: &str| value.get(name).and_then(|v| v.as_str()).unwrap_or_default();
+
+    let (logs, new_pool) = match event {
+        model::MonitorEvent::Logs { logs, .. } => (logs.clone(), None),
+        model::MonitorEvent::PoolCreated(pool) => (
+            Vec::new(),
+            Some(NewPool {
+                lp_account: pool.lp_account.clone(),
+                token_a: pool.token_a.mint.clone(),
+                token_b: pool.token_b.mint.clone(),
+                init_coin_amount: pool.token_a.raw_amount,
+                init_pc_amount: pool.token_b.raw_amount,
+                open_time: pool.open_time,
+            }),
+        ),
+        _ => (Vec::new(), None),
+    };
+
+    Ok(MonitorEvent {
+        signature: field("signature").to_string(),
+        slot: value
+            .get("slot")
+            .and_then(|v| v.as_u64())
+            .unwrap_or_default(),
+        logs,
+        new_pool,
+        event_type: field("type").to_string(),
+        event_json: value.to_string(),
+    })
+}
@@ -0,0 +1,289 @@
+use crate::monitor::MonitorEvent;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    widgets::TableState,
+};
+use solana_toolkits::account_info::TokenAccountsResult;
+use std::collections::{BTreeSet, VecDeque};
+
+/// -- 事件面板最多保留的事件数量
+const MAX_EVENTS: usize = 200;
+
+/// -- 账户分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountKind {
+    /// 余额为 0，可直接关闭
+    Closeable,
+    /// 有余额的零值代币，需要先销毁再关闭
+    ZeroValue,
+    /// 白名单保护，不可操作
+    Whitelisted,
+    /// NFT，不可操作
+    Nft,
+    /// 有余额的 wSOL，关闭即解包
+    WrappedSol,
+}
+
+impl AccountKind {
+    /// -- 分类名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            AccountKind::Closeable => "可关闭",
+            AccountKind::ZeroValue => "零值",
+            AccountKind::Whitelisted => "白名单",
+            AccountKind::Nft => "NFT",
+            AccountKind::WrappedSol => "wSOL",
+        }
+    }
+}
+
+/// -- 账户表格中的一行
+#[derive(Debug, Clone)]
+pub struct AccountRow {
+    pub address: String,   // -- 账户地址
+    pub symbol: String,    // -- 代币符号
+    pub balance: u64,      // -- 代币余额
+    pub rent_sol: f64,     // -- 租金（SOL）
+    pub kind: AccountKind, // -- 账户分类
+}
+
+impl AccountRow {
+    /// -- 将扫描结果展开为表格行，依次为可关闭、零值、白名单、NFT 和 wSOL 账户
+    pub fn from_scan(result: &TokenAccountsResult) -> Vec<Self> {
+        let closeable = result.accounts.iter().map(|account| AccountRow {
+            address: account.address.clone(),
+            symbol: account.symbol.clone(),
+            balance: 0,
+            rent_sol: account.rent_sol,
+            kind: AccountKind::Closeable,
+        });
+        let zero_value = result
+            .zero_value_accounts_list
+            .iter()
+            .map(|account| AccountRow {
+                address: account.address.clone(),
+                symbol: account.symbol.clone(),
+                balance: account.balance,
+                rent_sol: account.rent_sol,
+                kind: AccountKind::ZeroValue,
+            });
+        let whitelisted = result
+            .whitelisted_accounts_list
+            .iter()
+            .map(|account| AccountRow {
+                address: account.address.clone(),
+                symbol: account.symbol.clone(),
+                balance: account.balance,
+                rent_sol: account.rent_sol,
+                kind: AccountKind::Whitelisted,
+            });
+        let nfts = result.nft_accounts_list.iter().map(|account| AccountRow {
+            address: account.address.clone(),
+            symbol: account.symbol.clone(),
+            balance: account.balance,
+            rent_sol: account.rent_sol,
+            kind: AccountKind::Nft,
+        });
+        let wrapped_sol = result
+            .wrapped_sol_accounts_list
+            .iter()
+            .map(|account| AccountRow {
+                address: account.address.clone(),
+                symbol: account.symbol.clone(),
+                balance: account.balance,
+                rent_sol: account.rent_sol,
+                kind: AccountKind::WrappedSol,
+            });
+
+        closeable
+            .chain(zero_value)
+            .chain(whitelisted)
+            .chain(nfts)
+            .chain(wrapped_sol)
+            .collect()
+    }
+}
+
+/// -- 当前焦点面板
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Accounts,
+    Events,
+}
+
+/// -- 按键处理后需要执行的操作
+#[derive(Debug)]
+pub enum Action {
+    None,
+    Quit,
+    Rescan,
+    Close(Vec<AccountRow>),
+}
+
+/// -- 界面状态
+pub struct App {
+    pub wallet: String,                 // -- 钱包地址
+    pub accounts: Vec<AccountRow>,      // -- 账户列表
+    pub table: TableState,              // -- 账户表格选中状态
+    pub selected: BTreeSet<usize>,      // -- 已勾选的账户下标
+    pub events: VecDeque<MonitorEvent>, // -- 监控事件，最新的在前
+    pub event_offset: usize,            // -- 事件面板滚动偏移
+    pub focus: Focus,                   // -- 当前焦点面板
+    pub status: String,                 // -- 状态栏信息
+    pub busy: bool,                     // -- 是否有扫描或关闭任务在进行
+    pub confirming: bool,               // -- 是否等待关闭确认
+    pub monitor_online: bool,           // -- 监控订阅是否在线
+}
+
+impl App {
+    /// -- 创建界面状态
+    pub fn new(wallet: String) -> Self {
+        Self {
+            wallet,
+            accounts: Vec::new(),
+            table: TableState::default(),
+            selected: BTreeSet::new(),
+            events: VecDeque::with_capacity(MAX_EVENTS),
+            event_offset: 0,
+            focus: Focus::Accounts,
+            status: "正在扫描账户...".to_string(),
+            busy: true,
+            confirming: false,
+            monitor_online: true,
+        }
+    }
+
+    /// -- 已勾选账户的租金合计（SOL）
+    pub fn selected_rent(&self) -> f64 {
+        self.selected
+            .iter()
+            .filter_map(|index| self.accounts.get(*index))
+            .map(|account| account.rent_sol)
+            .sum()
+    }
+
+    /// -- 更新扫描结果，清空已勾选的账户
+    pub fn set_accounts(&mut self, accounts: Vec<AccountRow>) {
+        self.status = format!("扫描完成，共 {} 个账户", accounts.len());
+        self.accounts = accounts;
+        self.selected.clear();
+        self.table.select((!self.accounts.is_empty()).then_some(0));
+        self.busy = false;
+    }
+
+    /// -- 记录监控事件
+    pub fn push_event(&mut self, event: MonitorEvent) {
+        if self.events.len() == MAX_EVENTS {
+            self.events.pop_back();
+        }
+        self.events.push_front(event);
+        if self.event_offset > 0 {
+            self.event_offset = (self.event_offset + 1).min(self.events.len() - 1);
+        }
+    }
+
+    /// -- 处理按键
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if self.confirming {
+            self.confirming = false;
+            return match key.code {
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    let rows: Vec<AccountRow> = self
+                        .selected
+                        .iter()
+                        .filter_map(|index| self.accounts.get(*index).cloned())
+                        .collect();
+                    self.busy = true;
+                    self.status = format!("正在关闭 {} 个账户...", rows.len());
+                    Action::Close(rows)
+                }
+                _ => {
+                    self.status = "已取消".to_string();
+                    Action::None
+                }
+            };
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Focus::Accounts => Focus::Events,
+                    Focus::Events => Focus::Accounts,
+                };
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1),
+            KeyCode::Char(' ') if self.focus == Focus::Accounts => self.toggle_current(),
+            KeyCode::Char('a') => self.select_all_closeable(),
+            KeyCode::Char('r') if !self.busy => {
+                self.busy = true;
+                self.status = "正在扫描账户...".to_string();
+                return Action::Rescan;
+            }
+            KeyCode::Char('c') if !self.busy => {
+                if self.selected.is_empty() {
+                    self.status = "请先用空格勾选要关闭的账户".to_string();
+                } else {
+                    self.confirming = true;
+                    self.status = format!(
+                        "确认关闭 {} 个账户，回收约 {:.6} SOL？(y/n)",
+                        self.selected.len(),
+                        self.selected_rent()
+                    );
+                }
+            }
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    /// -- 移动当前面板的光标
+    fn move_cursor(&mut self, delta: isize) {
+        match self.focus {
+            Focus::Accounts => {
+                if self.accounts.is_empty() {
+                    return;
+                }
+                let current = self.table.selected().unwrap_or(0) as isize;
+                let next = (current + delta).clamp(0, self.accounts.len() as isize - 1);
+                self.table.select(Some(next as usize));
+            }
+            Focus::Events => {
+                let max = self.events.len().saturating_sub(1) as isize;
+                self.event_offset = (self.event_offset as isize + delta).clamp(0, max) as usize;
+            }
+        }
+    }
+
+    /// -- 勾选或取消勾选当前账户，白名单和 NFT 账户不可勾选
+    fn toggle_current(&mut self) {
+        let Some(index) = self.table.selected() else {
+            return;
+        };
+        let Some(account) = self.accounts.get(index) else {
+            return;
+        };
+
+        if account.kind == AccountKind::Whitelisted {
+            self.status = format!("{} 受白名单保护", account.symbol);
+        } else if account.kind == AccountKind::Nft {
+            self.status = format!("{} 是 NFT，不可销毁", account.symbol);
+        } else if !self.selected.remove(&index) {
+            self.selected.insert(index);
+        }
+    }
+
+    /// -- 勾选全部余额为 0 的账户
+    fn select_all_closeable(&mut self) {
+        self.selected = self
+            .accounts
+            .iter()
+            .enumerate()
+            .filter(|(_, account)| account.kind == AccountKind::Closeable)
+            .map(|(index, _)| index)
+            .collect();
+        self.status = format!("已勾选 {} 个可关闭账户", self.selected.len());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::app::AccountRow;
+use crate::monitor::MonitorEvent;
+use ratatui::crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use std::{thread, time::Duration};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// -- 界面刷新间隔
+const TICK_RATE: Duration = Duration::from_millis(250);
+
+/// -- 界面事件
+#[derive(Debug)]
+pub enum AppEvent {
+    /// 键盘输入
+    Key(KeyEvent),
+    /// 定时刷新
+    Tick,
+    /// 账户扫描完成
+    Scanned(Result<Vec<AccountRow>, String>),
+    /// 账户关闭完成，包含成功、失败数量和回收的租金（SOL）
+    Closed {
+        succeeded: usize,
+        failed: usize,
+        rent_recovered: f64,
+    },
+    /// 新的监控事件
+    Monitor(MonitorEvent),
+    /// 监控订阅已停止
+    MonitorStopped(String),
+}
+
+/// -- 在后台线程读取终端输入，无输入时按固定间隔发送刷新事件
+pub fn spawn_input_reader(sender: UnboundedSender<AppEvent>) {
+    thread::spawn(move || loop {
+        let app_event = match event::poll(TICK_RATE) {
+            Ok(true) => match event::read() {
+                Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => AppEvent::Key(key),
+                Ok(_) => continue,
+                Err(_) => return,
+            },
+            Ok(false) => AppEvent::Tick,
+            Err(_) => return,
+        };
+
+        if sender.send(app_event).is_err() {
+            return;
+        }
+    });
+}
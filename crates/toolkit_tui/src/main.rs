@@ -0,0 +1,156 @@
+//! # 终端仪表盘
+//!
+//! 基于 ratatui 展示钱包的代币账户（可关闭 / 零值 / 白名单）和 Raydium 实时事件，
+//! 支持在界面中勾选账户并关闭。
+use anyhow::Result;
+use app::{AccountKind, AccountRow, Action, App};
+use clap::Parser;
+use event::{spawn_input_reader, AppEvent};
+use monitor::spawn_monitor;
+use solana_sdk::{pubkey::Pubkey, signer::Signer};
+use solana_toolkits::TokenAccountManager;
+use std::{str::FromStr, sync::Arc};
+use tokio::{
+    runtime::Handle,
+    sync::mpsc::{unbounded_channel, UnboundedSender},
+};
+use utils::load_env;
+
+mod app;
+mod event;
+mod monitor;
+mod ui;
+
+/// -- 终端仪表盘
+#[derive(Debug, Parser)]
+#[command(
+    name = "toolkit-tui",
+    version,
+    about = "代币账户与 Raydium 监控终端仪表盘"
+)]
+struct Cli {
+    /// 钱包密钥文件路径
+    #[arg(long, env = "WALLET_PATH")]
+    wallet: String,
+    /// 监控使用的 WebSocket 地址
+    #[arg(long, env = "HELIUS_WS_RPC_URL")]
+    ws_url: String,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // -- 日志会破坏终端界面，这里只加载环境变量，不初始化 tracing
+    load_env()?;
+    let cli = Cli::parse();
+
+    let manager = Arc::new(TokenAccountManager::new(&cli.wallet)?);
+    let mut app = App::new(manager.wallet.pubkey().to_string());
+
+    let (sender, mut receiver) = unbounded_channel();
+    spawn_input_reader(sender.clone());
+    spawn_monitor(cli.ws_url, sender.clone());
+    spawn_scan(manager.clone(), sender.clone());
+
+    let mut terminal = ratatui::init();
+    let result = loop {
+        if let Err(e) = terminal.draw(|frame| ui::draw(frame, &mut app)) {
+            break Err(e.into());
+        }
+
+        let Some(event) = receiver.recv().await else {
+            break Ok(());
+        };
+
+        match event {
+            AppEvent::Key(key) => match app.handle_key(key) {
+                Action::Quit => break Ok(()),
+                Action::Rescan => spawn_scan(manager.clone(), sender.clone()),
+                Action::Close(rows) => spawn_close(manager.clone(), rows, sender.clone()),
+                Action::None => {}
+            },
+            AppEvent::Tick => {}
+            AppEvent::Scanned(Ok(accounts)) => app.set_accounts(accounts),
+            AppEvent::Scanned(Err(e)) => {
+                app.busy = false;
+                app.status = format!("扫描失败: {}", e);
+            }
+            AppEvent::Closed {
+                succeeded,
+                failed,
+                rent_recovered,
+            } => {
+                app.status = format!(
+                    "关闭完成：成功 {}，失败 {}，回收 {:.6} SOL，正在重新扫描...",
+                    succeeded, failed, rent_recovered
+                );
+                spawn_scan(manager.clone(), sender.clone());
+            }
+            AppEvent::Monitor(event) => app.push_event(event),
+            AppEvent::MonitorStopped(reason) => {
+                app.monitor_online = false;
+                app.status = format!("监控已停止: {}", reason);
+            }
+        }
+    };
+    ratatui::restore();
+
+    result
+}
+
+/// -- 在阻塞线程中扫描账户，完成后推送结果
+fn spawn_scan(manager: Arc<TokenAccountManager>, sender: UnboundedSender<AppEvent>) {
+    let handle = Handle::current();
+    tokio::task::spawn_blocking(move || {
+        let result = handle
+            .block_on(manager.get_closeable_accounts())
+            .map(|result| AccountRow::from_scan(&result))
+            .map_err(|e| e.to_string());
+        let _ = sender.send(AppEvent::Scanned(result));
+    });
+}
+
+/// -- 在阻塞线程中逐个关闭账户：余额为 0 的直接关闭，零值代币先销毁再关闭
+fn spawn_close(
+    manager: Arc<TokenAccountManager>,
+    rows: Vec<AccountRow>,
+    sender: UnboundedSender<AppEvent>,
+) {
+    let handle = Handle::current();
+    tokio::task::spawn_blocking(move || {
+        let mut succeeded = 0;
+        let mut failed = 0;
+        let mut rent_recovered = 0.0;
+
+        for row in rows {
+            let Ok(pubkey) = Pubkey::from_str(&row.address) else {
+                failed += 1;
+                continue;
+            };
+
+            let (success, rent) = match row.kind {
+                AccountKind::Closeable => {
+                    let result = handle.block_on(manager.close_account(&pubkey));
+                    (result.success, result.rent_recovered)
+                }
+                AccountKind::ZeroValue | AccountKind::WrappedSol => {
+                    let result = handle.block_on(manager.burn_and_close_account(&pubkey));
+                    (result.success, result.rent_recovered)
+                }
+                AccountKind::Whitelisted | AccountKind::Nft => continue,
+            };
+
+            if success {
+                succeeded += 1;
+                rent_recovered += rent;
+            } else {
+                failed += 1;
+            }
+        }
+
+        let _ = sender.send(AppEvent::Closed {
+            succeeded,
+            failed,
+            rent_recovered,
+        });
+    });
+}
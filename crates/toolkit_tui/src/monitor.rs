@@ -0,0 +1,154 @@
+use crate::event::AppEvent;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_client::{
+    pubsub_client::PubsubClient,
+    rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter},
+};
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::thread;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// -- Raydium AMM v4 程序 ID
+pub const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+/// -- ray_log 日志类型：初始化流动性池
+const LOG_TYPE_INIT: u8 = 0;
+/// -- ray_log 日志类型：SwapBaseIn
+const LOG_TYPE_SWAP_BASE_IN: u8 = 3;
+/// -- ray_log 日志类型：SwapBaseOut
+const LOG_TYPE_SWAP_BASE_OUT: u8 = 4;
+
+/// -- 监控事件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorEventKind {
+    /// 新建流动性池
+    NewPool,
+    /// 交换
+    Swap,
+}
+
+/// -- 监控事件
+#[derive(Debug, Clone)]
+pub struct MonitorEvent {
+    pub kind: MonitorEventKind, // -- 事件类型
+    pub signature: String,      // -- 交易签名
+    pub slot: u64,              // -- 所在 slot
+    pub detail: String,         // -- 事件摘要
+}
+
+/// -- 在后台线程订阅 Raydium 日志，将新建流动性池和交换事件推送给界面
+///
+/// 通过解析程序输出的 `ray_log` 判断事件类型，不额外请求交易详情。
+/// 订阅失败或中断时推送 [`AppEvent::MonitorStopped`]。
+///
+/// # 参数
+/// * `ws_url` - WebSocket 地址
+/// * `sender` - 界面事件发送端
+pub fn spawn_monitor(ws_url: String, sender: UnboundedSender<AppEvent>) {
+    thread::spawn(move || {
+        let (_subscription, receiver) = match PubsubClient::logs_subscribe(
+            &ws_url,
+            RpcTransactionLogsFilter::Mentions(vec![RAYDIUM_AMM_V4.to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        ) {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                let _ = sender.send(AppEvent::MonitorStopped(e.to_string()));
+                return;
+            }
+        };
+
+        while let Ok(response) = receiver.recv() {
+            if response.value.err.is_some() {
+                continue;
+            }
+
+            let Some((kind, detail)) = parse_logs(&response.value.logs) else {
+                continue;
+            };
+
+            let event = MonitorEvent {
+                kind,
+                signature: response.value.signature,
+                slot: response.context.slot,
+                detail,
+            };
+            if sender.send(AppEvent::Monitor(event)).is_err() {
+                return;
+            }
+        }
+
+        let _ = sender.send(AppEvent::MonitorStopped("日志订阅已中断".to_string()));
+    });
+}
+
+/// -- 从交易日志中识别事件类型并生成摘要
+fn parse_logs(logs: &[String]) -> Option<(MonitorEventKind, String)> {
+    let ray_log = logs
+        .iter()
+        .find_map(|log| log.split("ray_log: ").nth(1))
+        .and_then(|data| STANDARD.decode(data.trim()).ok());
+
+    match ray_log {
+        Some(data) => parse_ray_log(&data),
+        None if logs.iter().any(|log| log.contains("initialize2")) => {
+            Some((MonitorEventKind::NewPool, "initialize2".to_string()))
+        }
+        None => None,
+    }
+}
+
+/// -- 解析 ray_log 数据
+///
+/// SwapBaseIn 布局：`log_type, amount_in, minimum_out, direction, user_source, pool_coin, pool_pc, out_amount`
+/// SwapBaseOut 布局：`log_type, max_in, amount_out, direction, user_source, pool_coin, pool_pc, deduct_in`
+fn parse_ray_log(data: &[u8]) -> Option<(MonitorEventKind, String)> {
+    let read_u64 = |offset: usize| -> Option<u64> {
+        data.get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    };
+
+    match *data.first()? {
+        LOG_TYPE_INIT => Some((MonitorEventKind::NewPool, "initialize2".to_string())),
+        LOG_TYPE_SWAP_BASE_IN => {
+            let amount_in = read_u64(1)?;
+            let direction = read_u64(17)?;
+            let out_amount = read_u64(49)?;
+            Some((
+                MonitorEventKind::Swap,
+                format!(
+                    "{} {} -> {}",
+                    direction_label(direction),
+                    amount_in,
+                    out_amount
+                ),
+            ))
+        }
+        LOG_TYPE_SWAP_BASE_OUT => {
+            let amount_out = read_u64(9)?;
+            let direction = read_u64(17)?;
+            let deduct_in = read_u64(49)?;
+            Some((
+                MonitorEventKind::Swap,
+                format!(
+                    "{} {} -> {}",
+                    direction_label(direction),
+                    deduct_in,
+                    amount_out
+                ),
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// -- 交换方向描述
+fn direction_label(direction: u64) -> &'static str {
+    match direction {
+        1 => "coin→pc",
+        2 => "pc→coin",
+        _ => "swap",
+    }
+}
@@ -0,0 +1,158 @@
+use crate::app::{AccountKind, App, Focus};
+use crate::monitor::MonitorEventKind;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, List, ListItem, Paragraph, Row, Table},
+    Frame,
+};
+
+/// -- 绘制界面：顶部概览、左侧账户表格、右侧监控事件、底部帮助
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [accounts_area, events_area] =
+        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(body);
+
+    draw_header(frame, app, header);
+    draw_accounts(frame, app, accounts_area);
+    draw_events(frame, app, events_area);
+
+    let help = "↑↓/jk 移动  Tab 切换面板  空格 勾选  a 全选可关闭  c 关闭  r 重新扫描  q 退出";
+    frame.render_widget(
+        Paragraph::new(help).style(Style::default().fg(Color::DarkGray)),
+        footer,
+    );
+}
+
+fn draw_header(frame: &mut Frame, app: &App, area: Rect) {
+    let count = |kind: AccountKind| app.accounts.iter().filter(|a| a.kind == kind).count();
+    let monitor = if app.monitor_online {
+        Span::styled("在线", Style::default().fg(Color::Green))
+    } else {
+        Span::styled("离线", Style::default().fg(Color::Red))
+    };
+
+    let summary = Line::from(vec![
+        Span::raw(format!("钱包 {}  ", app.wallet)),
+        Span::raw(format!(
+            "可关闭 {}  零值 {}  wSOL {}  白名单 {}  NFT {}  已勾选 {} ({:.6} SOL)  监控 ",
+            count(AccountKind::Closeable),
+            count(AccountKind::ZeroValue),
+            count(AccountKind::WrappedSol),
+            count(AccountKind::Whitelisted),
+            count(AccountKind::Nft),
+            app.selected.len(),
+            app.selected_rent(),
+        )),
+        monitor,
+    ]);
+    let status_style = if app.confirming {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+
+    frame.render_widget(
+        Paragraph::new(vec![
+            summary,
+            Line::styled(app.status.clone(), status_style),
+        ])
+        .block(Block::default().borders(Borders::BOTTOM)),
+        area,
+    );
+}
+
+fn draw_accounts(frame: &mut Frame, app: &mut App, area: Rect) {
+    let rows: Vec<Row> = app
+        .accounts
+        .iter()
+        .enumerate()
+        .map(|(index, account)| {
+            let mark = if app.selected.contains(&index) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let color = match account.kind {
+                AccountKind::Closeable => Color::Green,
+                AccountKind::ZeroValue => Color::Yellow,
+                AccountKind::Whitelisted => Color::DarkGray,
+                AccountKind::Nft => Color::Magenta,
+                AccountKind::WrappedSol => Color::Cyan,
+            };
+            Row::new(vec![
+                Cell::from(mark),
+                Cell::from(account.kind.label()).style(Style::default().fg(color)),
+                Cell::from(account.symbol.clone()),
+                Cell::from(account.balance.to_string()),
+                Cell::from(format!("{:.6}", account.rent_sol)),
+                Cell::from(account.address.clone()),
+            ])
+        })
+        .collect();
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(3),
+            Constraint::Length(6),
+            Constraint::Length(10),
+            Constraint::Length(14),
+            Constraint::Length(10),
+            Constraint::Min(20),
+        ],
+    )
+    .header(
+        Row::new(vec!["", "分类", "代币", "余额", "租金", "地址"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .block(panel("代币账户", app.focus == Focus::Accounts))
+    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    frame.render_stateful_widget(table, area, &mut app.table);
+}
+
+fn draw_events(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .events
+        .iter()
+        .skip(app.event_offset)
+        .map(|event| {
+            let (label, color) = match event.kind {
+                MonitorEventKind::NewPool => ("新池", Color::Magenta),
+                MonitorEventKind::Swap => ("交换", Color::Cyan),
+            };
+            let signature: String = event.signature.chars().take(16).collect();
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{} ", label), Style::default().fg(color)),
+                Span::raw(format!("{} {}… {}", event.slot, signature, event.detail)),
+            ]))
+        })
+        .collect();
+
+    frame.render_widget(
+        List::new(items).block(panel("Raydium 事件", app.focus == Focus::Events)),
+        area,
+    );
+}
+
+/// -- 带边框的面板，焦点面板高亮显示
+fn panel(title: &str, focused: bool) -> Block<'_> {
+    let style = if focused {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+    Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(style)
+}
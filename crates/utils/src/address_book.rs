@@ -0,0 +1,271 @@
+use crate::{AddressBookError, AddressBookResult};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::{collections::HashMap, env, fs, path::Path, str::FromStr, sync::OnceLock};
+use tracing::{debug, warn};
+
+/// -- 默认地址簿路径
+pub const DEFAULT_ADDRESS_BOOK_PATH: &str = "address_book.json";
+
+/// -- 全局地址簿，首次访问时从 `ADDRESS_BOOK_PATH` 加载
+static GLOBAL_ADDRESS_BOOK: OnceLock<AddressBook> = OnceLock::new();
+
+/// -- 内置的常用程序名称
+#[rustfmt::skip]
+const BUILTIN_PROGRAMS: &[(&str, &str)] = &[
+    ("system", "11111111111111111111111111111111"),
+    ("vote", "Vote111111111111111111111111111111111111111"),
+    ("stake", "Stake11111111111111111111111111111111111111"),
+    ("compute-budget", "ComputeBudget111111111111111111111111111111"),
+    ("address-lookup-table", "AddressLookupTab1e1111111111111111111111111"),
+    ("spl-token", "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+    ("spl-token-2022", "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"),
+    ("associated-token", "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+    ("token-metadata", "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"),
+    ("memo", "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+    ("jupiter", "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4"),
+    ("jupiter-dca", "DCA265Vj8a9CEuX1eb1LWRnDT7uK6q1xMipnNyatn23M"),
+    ("raydium-amm-v4", "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"),
+    ("orca-whirlpool", "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc"),
+    ("meteora-dlmm", "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo"),
+    ("phoenix", "PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY"),
+    ("drift-v2", "dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH"),
+    ("saber-stable-swap", "SSwpkEEcbUqx4vtoEByFjSkhKdCT862DNVb52nZg1UZ"),
+    ("pyth-oracle", "FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH"),
+];
+
+/// -- 地址簿条目
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AddressEntry {
+    pub name: String,
+    pub address: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// -- 地址簿
+///
+/// 维护名称与地址的双向映射，可为每个地址附加标签（如 `bot`、`pool`、`program`）。
+/// 配置文件为条目数组：
+///
+/// ```json
+/// [
+///   { "name": "bot-1", "address": "<pubkey>", "tags": ["bot"] },
+///   { "name": "SOL/USDC", "address": "<pool>", "tags": ["pool"] }
+/// ]
+/// ```
+///
+/// 名称不区分大小写且必须唯一；内置的常用程序名称始终可用，配置文件中的同名或同地址条目会覆盖它们。
+#[derive(Debug, Clone, Default)]
+pub struct AddressBook {
+    entries: HashMap<Pubkey, AddressEntry>, // -- 地址 -> 条目
+    names: HashMap<String, Pubkey>,         // -- 小写名称 -> 地址
+}
+
+impl AddressBook {
+    /// -- 创建空地址簿
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// -- 创建包含内置程序名称的地址簿
+    pub fn with_builtin() -> Self {
+        let mut book = Self::new();
+        for (name, address) in BUILTIN_PROGRAMS.iter().copied() {
+            let pubkey = Pubkey::from_str(address).expect("内置程序地址有效");
+            book.upsert(name, pubkey, &["program"]);
+        }
+        book
+    }
+
+    /// -- 从文件加载地址簿（包含内置程序名称）
+    ///
+    /// # 参数
+    /// * `path` - 配置文件路径
+    ///
+    /// # 返回
+    /// * `AddressBookResult<Self>` - 加载后的地址簿
+    pub fn load(path: impl AsRef<Path>) -> AddressBookResult<Self> {
+        let entries: Vec<AddressEntry> = serde_json::from_str(&fs::read_to_string(path)?)?;
+
+        let mut book = Self::with_builtin();
+        for entry in entries {
+            let pubkey = Pubkey::from_str(&entry.address)
+                .map_err(|_| AddressBookError::InvalidAddress(entry.address.clone()))?;
+            let tags: Vec<&str> = entry.tags.iter().map(String::as_str).collect();
+            let overrides_builtin = book.is_builtin(&entry.name)
+                || book
+                    .entry(&pubkey)
+                    .is_some_and(|existing| book.is_builtin_entry(existing));
+            if overrides_builtin {
+                book.upsert(&entry.name, pubkey, &tags);
+            } else {
+                book.insert(&entry.name, pubkey, &tags)?;
+            }
+        }
+
+        Ok(book)
+    }
+
+    /// -- 保存为配置文件（不包含内置程序名称）
+    pub fn save(&self, path: impl AsRef<Path>) -> AddressBookResult<()> {
+        let mut entries: Vec<&AddressEntry> = self
+            .entries
+            .values()
+            .filter(|entry| !self.is_builtin_entry(entry))
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        fs::write(path, serde_json::to_string_pretty(&entries)?)?;
+        Ok(())
+    }
+
+    /// -- 添加条目，名称或地址已存在时返回错误
+    ///
+    /// # 参数
+    /// * `name` - 名称
+    /// * `pubkey` - 地址
+    /// * `tags` - 标签
+    pub fn insert(&mut self, name: &str, pubkey: Pubkey, tags: &[&str]) -> AddressBookResult<()> {
+        if self.names.contains_key(&name.to_lowercase()) {
+            return Err(AddressBookError::DuplicateName(name.to_string()));
+        }
+        if let Some(existing) = self.entries.get(&pubkey) {
+            return Err(AddressBookError::DuplicateAddress(
+                pubkey.to_string(),
+                existing.name.clone(),
+            ));
+        }
+
+        self.upsert(name, pubkey, tags);
+        Ok(())
+    }
+
+    /// -- 删除条目
+    pub fn remove(&mut self, name: &str) -> Option<AddressEntry> {
+        let pubkey = self.names.remove(&name.to_lowercase())?;
+        self.entries.remove(&pubkey)
+    }
+
+    /// -- 查询地址对应的名称
+    pub fn name(&self, pubkey: &Pubkey) -> Option<&str> {
+        self.entries.get(pubkey).map(|entry| entry.name.as_str())
+    }
+
+    /// -- 查询字符串地址对应的名称
+    pub fn name_of(&self, address: &str) -> Option<&str> {
+        Pubkey::from_str(address)
+            .ok()
+            .and_then(|pubkey| self.name(&pubkey))
+    }
+
+    /// -- 查询地址对应的条目
+    pub fn entry(&self, pubkey: &Pubkey) -> Option<&AddressEntry> {
+        self.entries.get(pubkey)
+    }
+
+    /// -- 将名称或地址字符串解析为地址
+    ///
+    /// 先按 base58 地址解析，失败时按名称查找（不区分大小写）。
+    pub fn resolve(&self, name_or_address: &str) -> Option<Pubkey> {
+        Pubkey::from_str(name_or_address)
+            .ok()
+            .or_else(|| self.names.get(&name_or_address.to_lowercase()).copied())
+    }
+
+    /// -- 生成日志和报告中使用的显示文本
+    ///
+    /// 已知地址显示为 `名称 (地址)`，未知地址原样返回。
+    pub fn label(&self, address: &str) -> String {
+        match self.name_of(address) {
+            Some(name) => format!("{} ({})", name, address),
+            None => address.to_string(),
+        }
+    }
+
+    /// -- 带有指定标签的条目
+    pub fn tagged<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a AddressEntry> + 'a {
+        self.entries
+            .values()
+            .filter(move |entry| entry.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+    }
+
+    /// -- 所有条目
+    pub fn entries(&self) -> impl Iterator<Item = &AddressEntry> {
+        self.entries.values()
+    }
+
+    /// -- 条目数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// -- 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// -- 写入条目，覆盖同名或同地址的旧条目
+    fn upsert(&mut self, name: &str, pubkey: Pubkey, tags: &[&str]) {
+        if let Some(old) = self.names.remove(&name.to_lowercase()) {
+            self.entries.remove(&old);
+        }
+        if let Some(old) = self.entries.remove(&pubkey) {
+            self.names.remove(&old.name.to_lowercase());
+        }
+
+        self.names.insert(name.to_lowercase(), pubkey);
+        self.entries.insert(
+            pubkey,
+            AddressEntry {
+                name: name.to_string(),
+                address: pubkey.to_string(),
+                tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            },
+        );
+    }
+
+    /// -- 名称是否属于内置程序
+    fn is_builtin(&self, name: &str) -> bool {
+        BUILTIN_PROGRAMS
+            .iter()
+            .any(|(builtin, _)| builtin.eq_ignore_ascii_case(name))
+    }
+
+    /// -- 条目是否与内置程序完全一致
+    fn is_builtin_entry(&self, entry: &AddressEntry) -> bool {
+        BUILTIN_PROGRAMS
+            .iter()
+            .any(|(name, address)| *name == entry.name && *address == entry.address)
+    }
+}
+
+/// -- 获取全局地址簿
+///
+/// 首次调用时加载 `ADDRESS_BOOK_PATH` 指定的文件（未设置时使用 [`DEFAULT_ADDRESS_BOOK_PATH`]）。
+/// 文件不存在或加载失败时只包含内置程序名称，加载失败会记录警告。
+pub fn address_book() -> &'static AddressBook {
+    GLOBAL_ADDRESS_BOOK.get_or_init(|| {
+        let path = env::var("ADDRESS_BOOK_PATH")
+            .ok()
+            .filter(|path| !path.is_empty())
+            .unwrap_or_else(|| DEFAULT_ADDRESS_BOOK_PATH.to_string());
+        if !Path::new(&path).exists() {
+            debug!("地址簿 {} 不存在，仅使用内置名称", path);
+            return AddressBook::with_builtin();
+        }
+
+        match AddressBook::load(&path) {
+            Ok(book) => book,
+            Err(e) => {
+                warn!("无法加载地址簿 {}: {}", path, e);
+                AddressBook::with_builtin()
+            }
+        }
+    })
+}
+
+/// -- 使用全局地址簿生成显示文本
+pub fn address_label(address: &str) -> String {
+    address_book().label(address)
+}
@@ -0,0 +1,183 @@
+use crate::{AuditError, AuditResult};
+use event_store::{verify_file, EventStore, StoredEvent};
+use serde::{Deserialize, Serialize};
+use solana_client::{client_error::Result as ClientResult, rpc_client::SerializableTransaction};
+use solana_sdk::{hash::hash, signature::Signature, transaction::VersionedTransaction};
+use std::{env, path::Path, sync::OnceLock};
+use tracing::{debug, warn};
+
+/// -- 默认审计日志路径（相对于当前工作目录）
+pub const DEFAULT_AUDIT_LOG_PATH: &str = "audit.jsonl";
+
+/// -- 全局审计日志，首次使用时根据环境变量初始化
+static GLOBAL_AUDIT_LOG: OnceLock<Option<AuditLog>> = OnceLock::new();
+
+/// -- 指令摘要
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionSummary {
+    pub program_id: String,
+    pub program: Option<String>,
+    pub accounts: usize,
+    pub data_len: usize,
+}
+
+/// -- 审计日志条目
+///
+/// 作为事件写入 [`EventStore`]，序号、时间戳和哈希链由事件存储维护，
+/// 删除或修改任意历史条目都会被 [`verify_audit_log`] 发现。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub fee_payer: Option<String>,
+    pub payload_hash: String,
+    pub signature: String,
+    pub instructions: Vec<InstructionSummary>,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// -- 只追加的审计日志
+///
+/// 以 JSONL 格式记录工作空间签名并发送的每一笔交易，
+/// 写入后立即落盘，打开已有文件时从最后一条记录继续哈希链。
+pub struct AuditLog {
+    store: EventStore<AuditEntry>, // -- 底层事件存储
+}
+
+impl AuditLog {
+    /// -- 打开（或创建）审计日志
+    ///
+    /// # 参数
+    /// * `path` - 日志文件路径，父目录不存在时会自动创建
+    pub fn open(path: impl AsRef<Path>) -> AuditResult<Self> {
+        Ok(Self {
+            store: EventStore::open(path)?,
+        })
+    }
+
+    /// -- 日志文件路径
+    pub fn path(&self) -> &Path {
+        self.store.path()
+    }
+
+    /// -- 底层事件存储，可用于回放和订阅审计记录
+    pub fn store(&self) -> &EventStore<AuditEntry> {
+        &self.store
+    }
+
+    /// -- 记录一笔已发送的交易
+    ///
+    /// # 参数
+    /// * `transaction` - 已签名的交易（legacy 或 v0）
+    /// * `result` - 发送结果
+    ///
+    /// # 返回
+    /// * `AuditResult<StoredEvent<AuditEntry>>` - 写入的条目
+    pub fn record(
+        &self,
+        transaction: &impl SerializableTransaction,
+        result: &ClientResult<Signature>,
+    ) -> AuditResult<StoredEvent<AuditEntry>> {
+        let bytes = bincode::serialize(transaction)
+            .map_err(|e| AuditError::Serialization(e.to_string()))?;
+        let versioned: VersionedTransaction =
+            bincode::deserialize(&bytes).map_err(|e| AuditError::Serialization(e.to_string()))?;
+
+        let message = &versioned.message;
+        let keys = message.static_account_keys();
+        let instructions = message
+            .instructions()
+            .iter()
+            .map(|ix| {
+                let program_id = keys
+                    .get(ix.program_id_index as usize)
+                    .map(|key| key.to_string())
+                    .unwrap_or_default();
+                InstructionSummary {
+                    program: program_name(&program_id).map(str::to_string),
+                    program_id,
+                    accounts: ix.accounts.len(),
+                    data_len: ix.data.len(),
+                }
+            })
+            .collect();
+
+        let entry = self.store.append(AuditEntry {
+            fee_payer: keys.first().map(|key| key.to_string()),
+            payload_hash: hash(&message.serialize()).to_string(),
+            signature: transaction.get_signature().to_string(),
+            instructions,
+            success: result.is_ok(),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        })?;
+
+        debug!("审计日志已记录: {}", entry.event.signature);
+        Ok(entry)
+    }
+}
+
+/// -- 获取全局审计日志
+///
+/// 首次调用时打开 `AUDIT_LOG_PATH` 指定的文件（未设置时使用 [`DEFAULT_AUDIT_LOG_PATH`]），
+/// 设置 `AUDIT_LOG_PATH=off` 可禁用。打开失败时仅记录警告，不影响交易发送。
+pub fn audit_log() -> Option<&'static AuditLog> {
+    GLOBAL_AUDIT_LOG
+        .get_or_init(|| {
+            let path = env::var("AUDIT_LOG_PATH")
+                .ok()
+                .filter(|path| !path.is_empty())
+                .unwrap_or_else(|| DEFAULT_AUDIT_LOG_PATH.to_string());
+            if path.eq_ignore_ascii_case("off") {
+                return None;
+            }
+
+            match AuditLog::open(&path) {
+                Ok(log) => Some(log),
+                Err(e) => {
+                    warn!("无法打开审计日志 {}: {}", path, e);
+                    None
+                }
+            }
+        })
+        .as_ref()
+}
+
+/// -- 将交易写入全局审计日志
+///
+/// 写入失败只记录警告，调用方无需处理。
+pub fn record_transaction(
+    transaction: &impl SerializableTransaction,
+    result: &ClientResult<Signature>,
+) {
+    if let Some(log) = audit_log() {
+        if let Err(e) = log.record(transaction, result) {
+            warn!("写入审计日志失败: {}", e);
+        }
+    }
+}
+
+/// -- 校验审计日志的哈希链
+///
+/// # 参数
+/// * `path` - 日志文件路径
+///
+/// # 返回
+/// * `AuditResult<usize>` - 校验通过返回条目数量，否则返回首个异常条目的行号
+pub fn verify_audit_log(path: impl AsRef<Path>) -> AuditResult<usize> {
+    Ok(verify_file(path)?)
+}
+
+/// -- 常用程序名称
+fn program_name(program_id: &str) -> Option<&'static str> {
+    match program_id {
+        "11111111111111111111111111111111" => Some("system"),
+        "ComputeBudget111111111111111111111111111111" => Some("compute-budget"),
+        "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" => Some("spl-token"),
+        "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb" => Some("spl-token-2022"),
+        "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" => Some("associated-token"),
+        "Stake11111111111111111111111111111111111111" => Some("stake"),
+        "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s" => Some("token-metadata"),
+        "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr" => Some("memo"),
+        "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4" => Some("jupiter"),
+        _ => None,
+    }
+}
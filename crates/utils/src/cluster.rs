@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use std::{env, fmt, str::FromStr};
+use tracing::warn;
+
+/// -- Solana 集群
+///
+/// 预置集群使用官方公共 RPC 地址，`Custom` 使用自定义地址（例如私有节点或 Helius）。
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Cluster {
+    #[default]
+    Mainnet,
+    Devnet,
+    Testnet,
+    Localnet,
+    Custom(String),
+}
+
+impl Cluster {
+    /// -- 集群的 HTTP RPC 地址
+    pub fn url(&self) -> &str {
+        match self {
+            Cluster::Mainnet => "https://api.mainnet-beta.solana.com",
+            Cluster::Devnet => "https://api.devnet.solana.com",
+            Cluster::Testnet => "https://api.testnet.solana.com",
+            Cluster::Localnet => "http://127.0.0.1:8899",
+            Cluster::Custom(url) => url,
+        }
+    }
+
+    /// -- 从环境变量读取集群
+    ///
+    /// 设置了 `RPC_URL` 时按 [`FromStr`] 解析（可以是集群名称或 RPC 地址），否则使用 mainnet。
+    pub fn from_env() -> Self {
+        match env::var("RPC_URL") {
+            Ok(url) => url.parse().unwrap_or(Cluster::Custom(url)),
+            Err(_) => {
+                warn!("未设置 RPC_URL，使用默认的 mainnet URL");
+                Cluster::Mainnet
+            }
+        }
+    }
+}
+
+impl fmt::Display for Cluster {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cluster::Mainnet => write!(f, "mainnet"),
+            Cluster::Devnet => write!(f, "devnet"),
+            Cluster::Testnet => write!(f, "testnet"),
+            Cluster::Localnet => write!(f, "localnet"),
+            Cluster::Custom(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+impl FromStr for Cluster {
+    type Err = String;
+
+    /// -- 解析集群参数：`mainnet`（`mainnet-beta`）、`devnet`、`testnet`、`localnet`（`localhost`）或 http(s) 地址
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.to_ascii_lowercase().as_str() {
+            "mainnet" | "mainnet-beta" | "m" => Ok(Cluster::Mainnet),
+            "devnet" | "d" => Ok(Cluster::Devnet),
+            "testnet" | "t" => Ok(Cluster::Testnet),
+            "localnet" | "localhost" | "l" => Ok(Cluster::Localnet),
+            lower if lower.starts_with("http://") || lower.starts_with("https://") => {
+                Ok(Cluster::Custom(s.to_string()))
+            }
+            _ => Err(format!(
+                "无效的集群: {}，可选 mainnet、devnet、testnet、localnet 或 RPC 地址",
+                s
+            )),
+        }
+    }
+}
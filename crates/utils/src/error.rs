@@ -1,3 +1,4 @@
+use crate::locale::Locale;
 use std::error::Error;
 use thiserror::Error;
 
@@ -8,10 +9,12 @@ use thiserror::Error;
 /// - 账户操作错误
 /// - 数据解析错误
 /// - 交易执行错误
+/// - 钱包余额保护
 /// - IO 和系统错误
 #[derive(Debug, Error)]
 pub enum TokenAccountError {
     /// RPC 客户端错误
+    #[cfg(feature = "rpc")]
     #[error("RPC 错误: {0}")]
     RpcError(#[from] solana_client::client_error::ClientError),
 
@@ -23,14 +26,46 @@ pub enum TokenAccountError {
     #[error("账户解析错误: {0}")]
     AccountParseError(String),
 
+    /// 账户不存在（未创建或已被关闭）
+    #[error("账户不存在: {0}")]
+    AccountNotFound(String),
+
     /// 账户余额非零错误
     #[error("账户余额不为 0: {0}")]
     NonZeroBalance(u64),
 
+    /// 账户已被冻结，无法关闭或销毁
+    #[error("账户已冻结: {0}")]
+    AccountFrozen(String),
+
+    /// 账户设置了其他地址作为关闭权限，钱包无法关闭
+    #[error("钱包不是账户 {account} 的关闭权限，关闭权限为 {authority}")]
+    NotCloseAuthority { account: String, authority: String },
+
+    /// 扫描时记录的代币精度与 Mint 的链上精度不一致
+    #[error("代币精度不匹配: Mint {mint} 预期 {expected} 位，链上为 {actual} 位")]
+    DecimalsMismatch {
+        mint: String,
+        expected: u8,
+        actual: u8,
+    },
+
     /// 交易执行错误
     #[error("交易错误: {0}")]
     TransactionError(String),
 
+    /// 支付交易费用后钱包余额将低于保留余额
+    #[error("钱包余额不足: 余额 {balance} lamports，预计费用 {fees} lamports，需保留 {reserve} lamports")]
+    InsufficientReserve {
+        balance: u64,
+        fees: u64,
+        reserve: u64,
+    },
+
+    /// 批量处理的预计交易费用超过配置的上限
+    #[error("预计交易费用 {estimated} lamports 超过上限 {budget} lamports，已拒绝执行")]
+    FeeBudgetExceeded { estimated: u64, budget: u64 },
+
     /// IO 操作错误
     #[error("IO 错误: {0}")]
     IoError(#[from] std::io::Error),
@@ -47,11 +82,74 @@ pub enum TokenAccountError {
     #[error("程序错误: {0}")]
     ProgramError(#[from] solana_sdk::program_error::ProgramError),
 
+    /// 密钥库错误
+    #[cfg(feature = "keystore")]
+    #[error("密钥库错误: {0}")]
+    KeystoreError(#[from] KeystoreError),
+
     /// 其他未分类错误
     #[error("其他错误: {0}")]
     Other(String),
 }
 
+impl TokenAccountError {
+    /// -- 按界面语言生成错误信息，中文与 `Display` 输出一致
+    ///
+    /// # 参数
+    /// * `locale` - 界面语言
+    pub fn localized(&self, locale: Locale) -> String {
+        if locale == Locale::Zh {
+            return self.to_string();
+        }
+
+        match self {
+            #[cfg(feature = "rpc")]
+            TokenAccountError::RpcError(e) => format!("RPC error: {}", e),
+            TokenAccountError::InvalidKeyFormat => "Invalid key format".to_string(),
+            TokenAccountError::AccountParseError(e) => format!("Failed to parse account: {}", e),
+            TokenAccountError::AccountNotFound(account) => {
+                format!("Account not found: {}", account)
+            }
+            TokenAccountError::NonZeroBalance(balance) => {
+                format!("Account balance is not zero: {}", balance)
+            }
+            TokenAccountError::AccountFrozen(account) => format!("Account is frozen: {}", account),
+            TokenAccountError::NotCloseAuthority { account, authority } => format!(
+                "Wallet is not the close authority of {}, close authority is {}",
+                account, authority
+            ),
+            TokenAccountError::DecimalsMismatch {
+                mint,
+                expected,
+                actual,
+            } => format!(
+                "Decimals mismatch: mint {} expected {}, on-chain {}",
+                mint, expected, actual
+            ),
+            TokenAccountError::TransactionError(e) => format!("Transaction error: {}", e),
+            TokenAccountError::InsufficientReserve {
+                balance,
+                fees,
+                reserve,
+            } => format!(
+                "Insufficient wallet balance: balance {} lamports, estimated fees {} lamports, reserve {} lamports",
+                balance, fees, reserve
+            ),
+            TokenAccountError::FeeBudgetExceeded { estimated, budget } => format!(
+                "Estimated fees of {} lamports exceed the budget of {} lamports, aborted",
+                estimated, budget
+            ),
+            TokenAccountError::IoError(e) => format!("IO error: {}", e),
+            TokenAccountError::JsonError(e) => format!("JSON error: {}", e),
+            TokenAccountError::SplTokenError(e) => format!("SPL Token error: {}", e),
+            TokenAccountError::ProgramError(e) => format!("Program error: {}", e),
+            #[cfg(feature = "keystore")]
+            TokenAccountError::KeystoreError(e) => format!("Keystore error: {}", e.localized(locale)),
+            TokenAccountError::Other(e) => format!("Error: {}", e),
+        }
+    }
+}
+
 impl From<Box<dyn Error>> for TokenAccountError {
     fn from(error: Box<dyn Error>) -> Self {
         TokenAccountError::Other(error.to_string())
@@ -68,3 +166,118 @@ impl From<anyhow::Error> for TokenAccountError {
 ///
 /// 用于代币账户管理操作的统一返回类型
 pub type TokenAccountResult<T> = Result<T, TokenAccountError>;
+
+/// -- 地址簿错误类型
+#[derive(Debug, Error)]
+pub enum AddressBookError {
+    /// IO 操作错误
+    #[error("IO 错误: {0}")]
+    IoError(#[from] std::io::Error),
+
+    /// JSON 解析错误
+    #[error("JSON 解析错误: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    /// 地址格式无效
+    #[error("无效的地址: {0}")]
+    InvalidAddress(String),
+
+    /// 名称已存在
+    #[error("名称已存在: {0}")]
+    DuplicateName(String),
+
+    /// 地址已登记在其他名称下
+    #[error("地址 {0} 已登记为 {1}")]
+    DuplicateAddress(String, String),
+}
+
+/// -- 地址簿操作的返回类型
+pub type AddressBookResult<T> = Result<T, AddressBookError>;
+
+/// -- 审计日志错误类型
+#[cfg(feature = "audit")]
+#[derive(Debug, Error)]
+pub enum AuditError {
+    /// 事件存储错误（IO、JSON 或哈希链断裂）
+    #[error("审计日志存储错误: {0}")]
+    Store(#[from] event_store::StoreError),
+
+    /// 交易序列化错误
+    #[error("交易序列化错误: {0}")]
+    Serialization(String),
+}
+
+/// -- 审计日志操作的返回类型
+#[cfg(feature = "audit")]
+pub type AuditResult<T> = Result<T, AuditError>;
+
+/// -- 密钥库错误类型
+#[cfg(feature = "keystore")]
+#[derive(Debug, Error)]
+pub enum KeystoreError {
+    /// IO 操作错误
+    #[error("IO 错误: {0}")]
+    IoError(#[from] std::io::Error),
+
+    /// JSON 解析错误
+    #[error("JSON 解析错误: {0}")]
+    JsonError(#[from] serde_json::Error),
+
+    /// 口令错误
+    #[error("口令错误")]
+    WrongPassphrase,
+
+    /// 未提供口令
+    #[error("未设置口令环境变量: {0}")]
+    MissingPassphrase(String),
+
+    /// 密钥库或密钥已存在
+    #[error("已存在: {0}")]
+    AlreadyExists(String),
+
+    /// 密钥不存在
+    #[error("密钥不存在: {0}")]
+    KeyNotFound(String),
+
+    /// 不支持的密钥库版本
+    #[error("不支持的密钥库版本: {0}")]
+    UnsupportedVersion(u32),
+
+    /// 加解密错误
+    #[error("加解密错误: {0}")]
+    Crypto(String),
+
+    /// 密钥库内容损坏
+    #[error("密钥库内容损坏: {0}")]
+    Corrupted(String),
+}
+
+#[cfg(feature = "keystore")]
+impl KeystoreError {
+    /// -- 按界面语言生成错误信息，中文与 `Display` 输出一致
+    pub fn localized(&self, locale: Locale) -> String {
+        if locale == Locale::Zh {
+            return self.to_string();
+        }
+
+        match self {
+            KeystoreError::IoError(e) => format!("IO error: {}", e),
+            KeystoreError::JsonError(e) => format!("JSON error: {}", e),
+            KeystoreError::WrongPassphrase => "Wrong passphrase".to_string(),
+            KeystoreError::MissingPassphrase(var) => {
+                format!("Passphrase environment variable not set: {}", var)
+            }
+            KeystoreError::AlreadyExists(name) => format!("Already exists: {}", name),
+            KeystoreError::KeyNotFound(name) => format!("Key not found: {}", name),
+            KeystoreError::UnsupportedVersion(version) => {
+                format!("Unsupported keystore version: {}", version)
+            }
+            KeystoreError::Crypto(e) => format!("Encryption error: {}", e),
+            KeystoreError::Corrupted(e) => format!("Keystore is corrupted: {}", e),
+        }
+    }
+}
+
+/// -- 密钥库操作的返回类型
+#[cfg(feature = "keystore")]
+pub type KeystoreResult<T> = Result<T, KeystoreError>;
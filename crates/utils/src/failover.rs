@@ -0,0 +1,314 @@
+use crate::rpc::RpcApi;
+use solana_client::{
+    client_error::{ClientErrorKind, Result as ClientResult},
+    rpc_client::{RpcClient, SerializableTransaction},
+    rpc_config::{RpcProgramAccountsConfig, RpcTransactionConfig},
+    rpc_request::TokenAccountsFilter,
+    rpc_response::{
+        RpcConfirmedTransactionStatusWithSignature, RpcKeyedAccount, RpcPrioritizationFee,
+        RpcSimulateTransactionResult,
+    },
+};
+use solana_sdk::{
+    account::Account, clock::Slot, commitment_config::CommitmentConfig, epoch_info::EpochInfo,
+    hash::Hash, pubkey::Pubkey, signature::Signature,
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use std::{
+    env,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+/// -- 默认的故障节点重新探测间隔
+pub const DEFAULT_PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// -- 需要切换节点的错误信息（忽略大小写）
+const FAILOVER_PATTERNS: &[&str] = &[
+    "429",
+    "too many requests",
+    "rate limit",
+    "502",
+    "503",
+    "504",
+    "node is behind",
+    "node is unhealthy",
+    "timed out",
+    "timeout",
+    "connection",
+];
+
+/// -- 单个 RPC 节点
+struct Endpoint<C> {
+    url: String,                       // -- 节点地址，仅用于日志
+    client: C,                         // -- RPC 客户端
+    failed_at: Mutex<Option<Instant>>, // -- 最近一次失败的时间，None 表示健康
+}
+
+/// -- 多节点故障切换 RPC
+///
+/// 按配置顺序优先使用靠前的节点，遇到限流或连接错误时切换到下一个健康节点。
+/// 失败的节点在 `probe_interval` 内跳过，之后重新探测，恢复后自动切回。
+/// 交易执行失败、账户不存在等与节点无关的错误直接返回，不会切换节点。
+///
+/// 实现了 [`RpcApi`]，可以直接作为 `TokenAccountManager` 等组件的 RPC 客户端。
+pub struct FailoverRpc<C: RpcApi = RpcClient> {
+    endpoints: Vec<Endpoint<C>>, // -- 按优先级排列的节点
+    active: AtomicUsize,         // -- 最近一次成功的节点序号
+    probe_interval: Duration,    // -- 故障节点重新探测间隔
+}
+
+impl FailoverRpc {
+    /// -- 使用多个 RPC 地址创建故障切换客户端
+    ///
+    /// # 参数
+    /// * `urls` - RPC 地址列表，靠前的地址优先使用
+    /// * `commitment` - 提交级别
+    pub fn new(urls: &[String], commitment: CommitmentConfig) -> Self {
+        Self::from_clients(
+            urls.iter()
+                .map(|url| {
+                    let client = RpcClient::new_with_commitment(url.clone(), commitment);
+                    (url.clone(), client)
+                })
+                .collect(),
+        )
+    }
+
+    /// -- 从环境变量创建故障切换客户端
+    ///
+    /// 读取 `RPC_URLS`（逗号分隔），未设置时使用 `RPC_URL`，都未设置时使用默认的 mainnet 地址。
+    ///
+    /// # 参数
+    /// * `commitment` - 提交级别
+    pub fn from_env(commitment: CommitmentConfig) -> Self {
+        let urls: Vec<String> = env::var("RPC_URLS")
+            .or_else(|_| env::var("RPC_URL"))
+            .unwrap_or_else(|_| {
+                warn!("未设置 RPC_URLS 或 RPC_URL，使用默认的 mainnet URL");
+                String::from("https://api.mainnet-beta.solana.com")
+            })
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(String::from)
+            .collect();
+        Self::new(&urls, commitment)
+    }
+}
+
+impl<C: RpcApi> FailoverRpc<C> {
+    /// -- 使用已创建的客户端构建故障切换客户端
+    ///
+    /// 适用于自定义 RPC 实现（如单元测试中的 `MockRpc`）。
+    ///
+    /// # 参数
+    /// * `clients` - (节点地址, 客户端) 列表，靠前的节点优先使用
+    pub fn from_clients(clients: Vec<(String, C)>) -> Self {
+        assert!(!clients.is_empty(), "至少需要一个 RPC 节点");
+        Self {
+            endpoints: clients
+                .into_iter()
+                .map(|(url, client)| Endpoint {
+                    url,
+                    client,
+                    failed_at: Mutex::new(None),
+                })
+                .collect(),
+            active: AtomicUsize::new(0),
+            probe_interval: DEFAULT_PROBE_INTERVAL,
+        }
+    }
+
+    /// -- 设置故障节点重新探测间隔
+    ///
+    /// # 参数
+    /// * `probe_interval` - 节点失败后多久重新尝试
+    pub fn with_probe_interval(mut self, probe_interval: Duration) -> Self {
+        self.probe_interval = probe_interval;
+        self
+    }
+
+    /// -- 最近一次请求成功的节点地址
+    pub fn active_url(&self) -> &str {
+        &self.endpoints[self.active.load(Ordering::Relaxed)].url
+    }
+
+    /// -- 所有节点的客户端，按优先级排列
+    pub fn clients(&self) -> impl Iterator<Item = &C> {
+        self.endpoints.iter().map(|endpoint| &endpoint.client)
+    }
+
+    /// -- 按优先级在健康节点上执行请求，限流或连接错误时切换到下一个节点
+    ///
+    /// 所有节点都处于故障冷却期时仍然按顺序尝试，避免请求直接失败。
+    fn call<T>(&self, request: impl Fn(&C) -> ClientResult<T>) -> ClientResult<T> {
+        let now = Instant::now();
+        let cooling_down = |endpoint: &Endpoint<C>| {
+            endpoint
+                .failed_at
+                .lock()
+                .unwrap()
+                .is_some_and(|failed_at| now.duration_since(failed_at) < self.probe_interval)
+        };
+        let mut order: Vec<usize> = (0..self.endpoints.len())
+            .filter(|&index| !cooling_down(&self.endpoints[index]))
+            .collect();
+        if order.is_empty() {
+            order = (0..self.endpoints.len()).collect();
+        }
+
+        let mut last_error = None;
+        for index in order {
+            let endpoint = &self.endpoints[index];
+            match request(&endpoint.client) {
+                Ok(value) => {
+                    if endpoint.failed_at.lock().unwrap().take().is_some() {
+                        info!("RPC 节点已恢复: {}", endpoint.url);
+                    }
+                    if self.active.swap(index, Ordering::Relaxed) != index {
+                        info!("切换到 RPC 节点: {}", endpoint.url);
+                    }
+                    return Ok(value);
+                }
+                Err(e) if is_failover_error(e.kind()) => {
+                    warn!("RPC 节点 {} 不可用: {}", endpoint.url, e);
+                    *endpoint.failed_at.lock().unwrap() = Some(Instant::now());
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.expect("至少尝试了一个 RPC 节点"))
+    }
+}
+
+/// -- 判断错误是否由节点引起（限流、连接失败、节点落后），需要切换节点
+pub fn is_failover_error(kind: &ClientErrorKind) -> bool {
+    match kind {
+        ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_) => true,
+        ClientErrorKind::TransactionError(_) | ClientErrorKind::SigningError(_) => false,
+        _ => {
+            let message = kind.to_string().to_lowercase();
+            FAILOVER_PATTERNS
+                .iter()
+                .any(|pattern| message.contains(pattern))
+        }
+    }
+}
+
+impl<C: RpcApi> RpcApi for FailoverRpc<C> {
+    fn commitment(&self) -> CommitmentConfig {
+        self.endpoints[0].client.commitment()
+    }
+
+    fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Account> {
+        self.call(|client| client.get_account(pubkey))
+    }
+
+    fn get_account_data(&self, pubkey: &Pubkey) -> ClientResult<Vec<u8>> {
+        self.call(|client| client.get_account_data(pubkey))
+    }
+
+    fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        self.call(|client| client.get_multiple_accounts(pubkeys))
+    }
+
+    fn get_balance(&self, pubkey: &Pubkey) -> ClientResult<u64> {
+        self.call(|client| client.get_balance(pubkey))
+    }
+
+    fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
+        self.call(|client| client.get_minimum_balance_for_rent_exemption(data_len))
+    }
+
+    fn get_program_accounts_with_config(
+        &self,
+        program_id: &Pubkey,
+        config: RpcProgramAccountsConfig,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.call(|client| client.get_program_accounts_with_config(program_id, config.clone()))
+    }
+
+    fn get_token_accounts_by_owner(
+        &self,
+        owner: &Pubkey,
+        filter: TokenAccountsFilter,
+    ) -> ClientResult<Vec<RpcKeyedAccount>> {
+        self.call(|client| {
+            let filter = match &filter {
+                TokenAccountsFilter::Mint(mint) => TokenAccountsFilter::Mint(*mint),
+                TokenAccountsFilter::ProgramId(id) => TokenAccountsFilter::ProgramId(*id),
+            };
+            client.get_token_accounts_by_owner(owner, filter)
+        })
+    }
+
+    fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        self.call(|client| client.get_latest_blockhash())
+    }
+
+    fn get_slot(&self) -> ClientResult<Slot> {
+        self.call(|client| client.get_slot())
+    }
+
+    fn get_epoch_info(&self) -> ClientResult<EpochInfo> {
+        self.call(|client| client.get_epoch_info())
+    }
+
+    /// 同一笔已签名交易在不同节点上重复发送不会重复执行
+    fn send_and_confirm_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature> {
+        self.call(|client| client.send_and_confirm_transaction(transaction))
+    }
+
+    fn send_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature> {
+        self.call(|client| client.send_transaction(transaction))
+    }
+
+    fn simulate_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<RpcSimulateTransactionResult> {
+        self.call(|client| client.simulate_transaction(transaction))
+    }
+
+    fn get_transaction_with_config(
+        &self,
+        signature: &Signature,
+        config: RpcTransactionConfig,
+    ) -> ClientResult<EncodedConfirmedTransactionWithStatusMeta> {
+        self.call(|client| client.get_transaction_with_config(signature, config))
+    }
+
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        self.call(|client| client.request_airdrop(pubkey, lamports))
+    }
+
+    fn confirm_transaction(&self, signature: &Signature) -> ClientResult<bool> {
+        self.call(|client| client.confirm_transaction(signature))
+    }
+
+    fn get_recent_prioritization_fees(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<RpcPrioritizationFee>> {
+        self.call(|client| client.get_recent_prioritization_fees(addresses))
+    }
+
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        self.call(|client| client.get_signatures_for_address(address))
+    }
+}
@@ -0,0 +1,419 @@
+use crate::{KeystoreError, KeystoreResult};
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng, Payload},
+    Aes256Gcm, Key, Nonce,
+};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// -- 当前密钥库文件版本
+const KEYSTORE_VERSION: u32 = 1;
+
+/// -- 用于校验口令的固定明文
+const VERIFIER_PLAINTEXT: &[u8] = b"solana-use-keystore";
+
+/// -- 派生密钥长度（AES-256）
+const KEY_LEN: usize = 32;
+
+/// -- 盐长度
+const SALT_LEN: usize = 16;
+
+/// -- 读取口令的环境变量
+pub const KEYSTORE_PASSPHRASE_ENV: &str = "KEYSTORE_PASSPHRASE";
+
+/// -- 密钥派生参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KdfParams {
+    algorithm: String,
+    salt: String,
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+}
+
+impl KdfParams {
+    /// -- 使用随机盐和 argon2 默认参数生成密钥派生参数
+    fn generate() -> Self {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let defaults = Params::default();
+        Self {
+            algorithm: "argon2id".to_string(),
+            salt: STANDARD.encode(salt),
+            m_cost: defaults.m_cost(),
+            t_cost: defaults.t_cost(),
+            p_cost: defaults.p_cost(),
+        }
+    }
+}
+
+/// -- 加密后的数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Sealed {
+    nonce: String,
+    ciphertext: String,
+}
+
+/// -- 密钥库中的一个密钥
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredKey {
+    pubkey: String,
+    #[serde(flatten)]
+    sealed: Sealed,
+}
+
+/// -- 密钥库文件格式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    kdf: KdfParams,
+    verifier: Sealed,
+    keys: BTreeMap<String, StoredKey>,
+}
+
+/// -- 加密钱包文件格式，只保存一个密钥
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EncryptedKeypairFile {
+    version: u32,
+    kdf: KdfParams,
+    pubkey: String,
+    #[serde(flatten)]
+    sealed: Sealed,
+}
+
+/// -- 加密钱包文件的附加认证数据
+const ENCRYPTED_KEYPAIR_AAD: &[u8] = b"encrypted-keypair";
+
+/// -- 加密密钥库
+///
+/// 在一个 JSON 文件中保存多个命名密钥，每个私钥使用由口令派生（argon2id）的密钥以
+/// AES-256-GCM 单独加密，密钥名称作为附加认证数据。解锁后按名称取出 `Keypair`
+/// 交给各工具使用，调用方无需接触明文密钥文件。
+pub struct Keystore {
+    path: PathBuf,      // -- 密钥库文件路径
+    file: KeystoreFile, // -- 文件内容
+    cipher: Aes256Gcm,  // -- 解锁后的加密器
+}
+
+impl Keystore {
+    /// -- 创建新的空密钥库
+    ///
+    /// # 参数
+    /// * `path` - 密钥库文件路径，文件已存在时返回错误
+    /// * `passphrase` - 口令
+    pub fn create(path: impl AsRef<Path>, passphrase: &str) -> KeystoreResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        if path.exists() {
+            return Err(KeystoreError::AlreadyExists(path.display().to_string()));
+        }
+
+        let kdf = KdfParams::generate();
+        let cipher = derive_cipher(&kdf, passphrase)?;
+        let verifier = seal(&cipher, VERIFIER_PLAINTEXT, b"verifier")?;
+        let keystore = Self {
+            path,
+            file: KeystoreFile {
+                version: KEYSTORE_VERSION,
+                kdf,
+                verifier,
+                keys: BTreeMap::new(),
+            },
+            cipher,
+        };
+        keystore.save()?;
+
+        Ok(keystore)
+    }
+
+    /// -- 使用口令打开并解锁密钥库
+    ///
+    /// # 参数
+    /// * `path` - 密钥库文件路径
+    /// * `passphrase` - 口令，错误时返回 `KeystoreError::WrongPassphrase`
+    pub fn unlock(path: impl AsRef<Path>, passphrase: &str) -> KeystoreResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file: KeystoreFile = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        if file.version != KEYSTORE_VERSION {
+            return Err(KeystoreError::UnsupportedVersion(file.version));
+        }
+
+        let cipher = derive_cipher(&file.kdf, passphrase)?;
+        let verifier = open(&cipher, &file.verifier, b"verifier")
+            .map_err(|_| KeystoreError::WrongPassphrase)?;
+        if verifier != VERIFIER_PLAINTEXT {
+            return Err(KeystoreError::WrongPassphrase);
+        }
+
+        Ok(Self { path, file, cipher })
+    }
+
+    /// -- 使用环境变量 `KEYSTORE_PASSPHRASE` 中的口令解锁密钥库
+    ///
+    /// 适用于守护进程等无法交互输入口令的场景。
+    pub fn unlock_from_env(path: impl AsRef<Path>) -> KeystoreResult<Self> {
+        let passphrase = env::var(KEYSTORE_PASSPHRASE_ENV)
+            .map_err(|_| KeystoreError::MissingPassphrase(KEYSTORE_PASSPHRASE_ENV.to_string()))?;
+        Self::unlock(path, &passphrase)
+    }
+
+    /// -- 密钥库文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// -- 列出所有密钥的名称和公钥
+    pub fn list(&self) -> Vec<(String, String)> {
+        self.file
+            .keys
+            .iter()
+            .map(|(name, key)| (name.clone(), key.pubkey.clone()))
+            .collect()
+    }
+
+    /// -- 查询密钥的公钥，无需解密私钥
+    pub fn pubkey(&self, name: &str) -> KeystoreResult<Pubkey> {
+        let key = self.stored(name)?;
+        Pubkey::from_str(&key.pubkey).map_err(|e| KeystoreError::Corrupted(e.to_string()))
+    }
+
+    /// -- 添加密钥并立即写入文件
+    ///
+    /// # 参数
+    /// * `name` - 密钥名称，已存在时返回错误
+    /// * `keypair` - 要保存的密钥对
+    pub fn insert(&mut self, name: &str, keypair: &Keypair) -> KeystoreResult<Pubkey> {
+        if self.file.keys.contains_key(name) {
+            return Err(KeystoreError::AlreadyExists(name.to_string()));
+        }
+
+        let sealed = seal(&self.cipher, &keypair.to_bytes(), name.as_bytes())?;
+        self.file.keys.insert(
+            name.to_string(),
+            StoredKey {
+                pubkey: keypair.pubkey().to_string(),
+                sealed,
+            },
+        );
+        self.save()?;
+
+        Ok(keypair.pubkey())
+    }
+
+    /// -- 删除密钥并立即写入文件
+    pub fn remove(&mut self, name: &str) -> KeystoreResult<()> {
+        self.file
+            .keys
+            .remove(name)
+            .ok_or_else(|| KeystoreError::KeyNotFound(name.to_string()))?;
+        self.save()
+    }
+
+    /// -- 解密并返回指定名称的密钥对
+    ///
+    /// # 参数
+    /// * `name` - 密钥名称
+    ///
+    /// # 返回
+    /// * `KeystoreResult<Keypair>` - 可直接作为 `Signer` 使用的密钥对
+    pub fn keypair(&self, name: &str) -> KeystoreResult<Keypair> {
+        let key = self.stored(name)?;
+        let bytes = open(&self.cipher, &key.sealed, name.as_bytes())?;
+        let keypair =
+            Keypair::from_bytes(&bytes).map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+
+        if keypair.pubkey().to_string() != key.pubkey {
+            return Err(KeystoreError::Corrupted(format!("{} 的公钥不匹配", name)));
+        }
+
+        Ok(keypair)
+    }
+
+    fn stored(&self, name: &str) -> KeystoreResult<&StoredKey> {
+        self.file
+            .keys
+            .get(name)
+            .ok_or_else(|| KeystoreError::KeyNotFound(name.to_string()))
+    }
+
+    fn save(&self) -> KeystoreResult<()> {
+        write_private(&self.path, &serde_json::to_string_pretty(&self.file)?)
+    }
+}
+
+/// -- 获取口令：优先读取环境变量 `KEYSTORE_PASSPHRASE`，未设置时调用 `prompt`
+///
+/// # 参数
+/// * `prompt` - 交互输入口令的回调，例如终端密码提示
+pub fn resolve_passphrase(
+    prompt: impl FnOnce() -> KeystoreResult<String>,
+) -> KeystoreResult<String> {
+    match env::var(KEYSTORE_PASSPHRASE_ENV) {
+        Ok(passphrase) => Ok(passphrase),
+        Err(_) => prompt(),
+    }
+}
+
+/// -- 将密钥对加密保存为单独的钱包文件
+///
+/// 私钥使用由口令派生（argon2id）的密钥以 AES-256-GCM 加密，文件中只保留公钥明文，
+/// 适合替代磁盘上的明文钱包文件。文件权限设置为 0600。
+///
+/// # 参数
+/// * `path` - 加密钱包文件路径，文件已存在时返回错误
+/// * `keypair` - 要加密的密钥对
+/// * `passphrase` - 口令
+pub fn encrypt_keypair_file(
+    path: impl AsRef<Path>,
+    keypair: &Keypair,
+    passphrase: &str,
+) -> KeystoreResult<()> {
+    let path = path.as_ref();
+    if path.exists() {
+        return Err(KeystoreError::AlreadyExists(path.display().to_string()));
+    }
+
+    let kdf = KdfParams::generate();
+    let cipher = derive_cipher(&kdf, passphrase)?;
+    let file = EncryptedKeypairFile {
+        version: KEYSTORE_VERSION,
+        kdf,
+        pubkey: keypair.pubkey().to_string(),
+        sealed: seal(&cipher, &keypair.to_bytes(), ENCRYPTED_KEYPAIR_AAD)?,
+    };
+    write_private(path, &serde_json::to_string_pretty(&file)?)
+}
+
+/// -- 判断文件是否为 [`encrypt_keypair_file`] 生成的加密钱包文件
+pub fn is_encrypted_keypair_file(path: impl AsRef<Path>) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<EncryptedKeypairFile>(&contents).ok())
+        .is_some()
+}
+
+/// -- 解密加密钱包文件
+///
+/// 口令通过 [`resolve_passphrase`] 获取：设置了 `KEYSTORE_PASSPHRASE` 时直接使用，
+/// 否则调用 `prompt`。无人值守的清理任务应使用环境变量，`prompt` 直接返回错误即可。
+///
+/// # 参数
+/// * `path` - 加密钱包文件路径
+/// * `prompt` - 交互输入口令的回调
+///
+/// # 返回
+/// * `KeystoreResult<Keypair>` - 解密出的密钥对，口令错误时返回 `KeystoreError::WrongPassphrase`
+pub fn load_encrypted_keypair(
+    path: impl AsRef<Path>,
+    prompt: impl FnOnce() -> KeystoreResult<String>,
+) -> KeystoreResult<Keypair> {
+    let file: EncryptedKeypairFile = serde_json::from_str(&fs::read_to_string(path)?)?;
+    if file.version != KEYSTORE_VERSION {
+        return Err(KeystoreError::UnsupportedVersion(file.version));
+    }
+
+    let passphrase = resolve_passphrase(prompt)?;
+    let cipher = derive_cipher(&file.kdf, &passphrase)?;
+    let bytes = open(&cipher, &file.sealed, ENCRYPTED_KEYPAIR_AAD)
+        .map_err(|_| KeystoreError::WrongPassphrase)?;
+    let keypair =
+        Keypair::from_bytes(&bytes).map_err(|e| KeystoreError::Corrupted(e.to_string()))?;
+
+    if keypair.pubkey().to_string() != file.pubkey {
+        return Err(KeystoreError::Corrupted("公钥不匹配".to_string()));
+    }
+
+    Ok(keypair)
+}
+
+/// -- 先写临时文件再替换，避免写入中断损坏文件；文件权限设置为 0600
+fn write_private(path: &Path, contents: &str) -> KeystoreResult<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+
+    let temp = path.with_extension("tmp");
+    fs::write(&temp, contents)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&temp, fs::Permissions::from_mode(0o600))?;
+    }
+    fs::rename(&temp, path)?;
+
+    Ok(())
+}
+
+/// -- 由口令派生 AES-256-GCM 加密器
+fn derive_cipher(kdf: &KdfParams, passphrase: &str) -> KeystoreResult<Aes256Gcm> {
+    if kdf.algorithm != "argon2id" {
+        return Err(KeystoreError::Corrupted(format!(
+            "不支持的密钥派生算法: {}",
+            kdf.algorithm
+        )));
+    }
+
+    let salt = decode(&kdf.salt)?;
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(KEY_LEN))
+        .map_err(|e| KeystoreError::Crypto(e.to_string()))?;
+    let mut key = [0u8; KEY_LEN];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+        .map_err(|e| KeystoreError::Crypto(e.to_string()))?;
+
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&key)))
+}
+
+/// -- 加密数据
+fn seal(cipher: &Aes256Gcm, plaintext: &[u8], aad: &[u8]) -> KeystoreResult<Sealed> {
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .map_err(|e| KeystoreError::Crypto(e.to_string()))?;
+
+    Ok(Sealed {
+        nonce: STANDARD.encode(nonce),
+        ciphertext: STANDARD.encode(ciphertext),
+    })
+}
+
+/// -- 解密数据
+fn open(cipher: &Aes256Gcm, sealed: &Sealed, aad: &[u8]) -> KeystoreResult<Vec<u8>> {
+    let nonce = decode(&sealed.nonce)?;
+    let ciphertext = decode(&sealed.ciphertext)?;
+    if nonce.len() != 12 {
+        return Err(KeystoreError::Corrupted("nonce 长度无效".to_string()));
+    }
+
+    cipher
+        .decrypt(
+            Nonce::from_slice(&nonce),
+            Payload {
+                msg: &ciphertext,
+                aad,
+            },
+        )
+        .map_err(|e| KeystoreError::Crypto(e.to_string()))
+}
+
+fn decode(value: &str) -> KeystoreResult<Vec<u8>> {
+    STANDARD
+        .decode(value)
+        .map_err(|e| KeystoreError::Corrupted(e.to_string()))
+}
@@ -1,19 +1,58 @@
+//! # 工作空间公共工具
+//!
+//! 特性划分（默认全部启用）：
+//!
+//! - 无特性：`ToPubkey`、地址簿、集群选择、界面语言、钱包密钥加载、cron 表达式解析、日志初始化、环境变量加载和错误类型
+//! - `rpc`：`RpcApi`、`FailoverRpc`、`init_rpc_client` 和 `init_cluster_rpc_client`，引入 solana-client
+//! - `mock`：测试用的内存 RPC 实现 `MockRpc`，默认不启用，只在 dev-dependencies 中开启
+//! - `metadata`：代币元数据查询与格式化
+//! - `audit`：交易审计日志
+//! - `keystore`：加密密钥库
 use anyhow::Result;
+#[cfg(feature = "rpc")]
+use cluster::Cluster;
+#[cfg(feature = "metadata")]
 use mpl_token_metadata::accounts::Metadata;
+#[cfg(feature = "rpc")]
 use solana_client::rpc_client::RpcClient;
-use solana_sdk::{commitment_config::CommitmentConfig, program_pack::Pack, pubkey::Pubkey};
-use spl_token::state::Mint;
-use std::{env, fmt, path::Path, str::FromStr};
+#[cfg(feature = "rpc")]
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+#[cfg(feature = "metadata")]
+use spl_token_2022::{extension::StateWithExtensions, state::Mint};
+use std::{env, path::Path, str::FromStr};
 use time::{macros::format_description, UtcOffset};
-use tracing::{debug, info, instrument, warn};
+#[cfg(feature = "metadata")]
+use tracing::{debug, info, instrument};
 use tracing_subscriber::{
     fmt::{format::json, time::OffsetTime},
     EnvFilter,
 };
 
+pub mod address_book;
+#[cfg(feature = "audit")]
+pub mod audit;
+pub mod cluster;
 mod error;
+#[cfg(feature = "rpc")]
+mod failover;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod locale;
+#[cfg(feature = "mock")]
+mod mock;
+#[cfg(feature = "rpc")]
+mod rpc;
+pub mod schedule;
+pub mod wallet;
 
 pub use error::*;
+#[cfg(feature = "rpc")]
+pub use failover::*;
+#[cfg(feature = "mock")]
+pub use mock::*;
+#[cfg(feature = "rpc")]
+pub use rpc::*;
 
 /// 定义 ToPubkey trait，用于将不同类型转换为 Solana 的公钥（Pubkey）
 pub trait ToPubkey {
@@ -50,7 +89,7 @@ impl<T: ToPubkey + ?Sized> ToPubkey for &T {
 
 /// 初始化 RPC 客户端
 ///
-/// 该函数创建并返回一个 Solana RPC 客户端实例。
+/// 该函数创建并返回一个 Solana RPC 客户端实例，集群由 `RPC_URL` 环境变量决定（见 [`Cluster::from_env`]）。
 ///
 /// # 参数
 ///
@@ -63,17 +102,26 @@ impl<T: ToPubkey + ?Sized> ToPubkey for &T {
 /// # 错误
 ///
 /// 如果无法从环境变量获取 RPC URL 或创建客户端失败，将返回错误。
+#[cfg(feature = "rpc")]
 pub fn init_rpc_client(
     commitment_config: CommitmentConfig,
 ) -> Result<RpcClient, solana_client::client_error::ClientError> {
-    // 尝试从环境变量获取 RPC URL，如果未设置则使用默认 mainnet URL
-    let rpc_url = env::var("RPC_URL").unwrap_or_else(|_| {
-        warn!("未设置 RPC_URL，使用默认的 mainnet URL");
-        String::from("https://api.mainnet-beta.solana.com")
-    });
+    init_cluster_rpc_client(&Cluster::from_env(), commitment_config)
+}
 
-    // 使用指定的 URL 和提交配置创建 RPC 客户端
-    let rpc_client = RpcClient::new_with_commitment(rpc_url, commitment_config);
+/// 初始化指定集群的 RPC 客户端
+///
+/// # 参数
+///
+/// * `cluster` - 要连接的集群
+/// * `commitment_config` - Solana 网络的提交配置
+#[cfg(feature = "rpc")]
+pub fn init_cluster_rpc_client(
+    cluster: &Cluster,
+    commitment_config: CommitmentConfig,
+) -> Result<RpcClient, solana_client::client_error::ClientError> {
+    // 使用集群的 URL 和提交配置创建 RPC 客户端
+    let rpc_client = RpcClient::new_with_commitment(cluster.url().to_string(), commitment_config);
 
     Ok(rpc_client)
 }
@@ -122,6 +170,7 @@ pub fn load_env() -> Result<()> {
 }
 
 /// 格式化 Metadata 为易读的 JSON 字符串
+#[cfg(feature = "metadata")]
 pub fn format_metadata(metadata: &Metadata) -> String {
     let metadata_json = serde_json::json!({
         "key": format!("{:?}", metadata.key),
@@ -143,17 +192,20 @@ pub fn format_metadata(metadata: &Metadata) -> String {
 /// 获取代币信息
 ///
 /// 该函数通过给定的代币账户地址获取代币的元数据和小数位数。
+/// Mint 按 Token-2022 的布局解析，同时支持 SPL Token 和带扩展的 Token-2022 Mint。
 ///
 /// # 参数
+/// * `rpc_client` - RPC 客户端（实现 `RpcApi` 即可）
 /// * `token_account` - 代币账户或 mint 地址
-/// * `is_mint` - 是否直接是 mint 地址
 ///
 /// # 返回值
-/// * `Result<(Metadata, u8)>` - 代币元数据和小数位数
+/// * `Result<(Metadata, Mint)>` - 代币元数据和 Mint 的基础状态
+#[cfg(feature = "metadata")]
 #[instrument(skip(rpc_client, token_account))]
-pub fn fetch_token_info<T>(rpc_client: &RpcClient, token_account: T) -> Result<(Metadata, Mint)>
+pub fn fetch_token_info<R, T>(rpc_client: &R, token_account: T) -> Result<(Metadata, Mint)>
 where
-    T: ToPubkey + fmt::Debug,
+    R: RpcApi,
+    T: ToPubkey + std::fmt::Debug,
 {
     let token_pubkey = token_account.to_pubkey()?;
     info!("正在获取代币信息，账户: {}", token_pubkey);
@@ -166,7 +218,7 @@ where
 
     debug!("正在获取代币小数位数");
     let data = rpc_client.get_account_data(&token_pubkey)?;
-    let mint = Mint::unpack(&data)?;
+    let mint = StateWithExtensions::<Mint>::unpack(&data)?.base;
 
     Ok((metadata, mint))
 }
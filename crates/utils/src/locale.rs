@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fmt,
+    str::FromStr,
+    sync::atomic::{AtomicU8, Ordering},
+};
+use tracing::warn;
+
+/// -- 选择界面语言的环境变量
+pub const LOCALE_ENV: &str = "SOLANA_USE_LOCALE";
+
+/// -- 当前界面语言，默认中文
+static CURRENT_LOCALE: AtomicU8 = AtomicU8::new(Locale::Zh as u8);
+
+/// -- 界面语言
+///
+/// 影响批量处理结果中的错误信息、失败原因、可读报告和通知摘要；日志始终使用中文。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    /// 中文
+    #[default]
+    Zh,
+    /// 英文
+    En,
+}
+
+impl Locale {
+    /// -- 从环境变量 `SOLANA_USE_LOCALE` 读取界面语言，未设置或无法识别时使用中文
+    pub fn from_env() -> Self {
+        match env::var(LOCALE_ENV) {
+            Ok(value) => value.parse().unwrap_or_else(|e| {
+                warn!("{}", e);
+                Locale::Zh
+            }),
+            Err(_) => Locale::Zh,
+        }
+    }
+
+    /// -- 按语言选择文本
+    ///
+    /// # 参数
+    /// * `zh` - 中文文本
+    /// * `en` - 英文文本
+    pub fn text<'a>(self, zh: &'a str, en: &'a str) -> &'a str {
+        match self {
+            Locale::Zh => zh,
+            Locale::En => en,
+        }
+    }
+}
+
+/// -- 设置当前界面语言，对之后生成的结果和报告生效
+pub fn set_locale(locale: Locale) {
+    CURRENT_LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+/// -- 当前界面语言
+pub fn current_locale() -> Locale {
+    match CURRENT_LOCALE.load(Ordering::Relaxed) {
+        value if value == Locale::En as u8 => Locale::En,
+        _ => Locale::Zh,
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Locale::Zh => write!(f, "zh"),
+            Locale::En => write!(f, "en"),
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// -- 解析语言参数：`zh`（`zh-cn`、`cn`）或 `en`（`en-us`），忽略大小写和 `.UTF-8` 等编码后缀
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_ascii_lowercase().replace('_', "-");
+        let language = lower.split('.').next().unwrap_or_default();
+        match language {
+            "zh" | "zh-cn" | "zh-hans" | "cn" => Ok(Locale::Zh),
+            "en" | "en-us" | "en-gb" => Ok(Locale::En),
+            _ => Err(format!("无效的语言: {}，可选 zh 或 en", s.trim())),
+        }
+    }
+}
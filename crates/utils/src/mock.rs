@@ -0,0 +1,616 @@
+use crate::RpcApi;
+use serde_json::json;
+use solana_account_decoder::{parse_account_data::ParsedAccount, UiAccount, UiAccountData};
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind, Result as ClientResult},
+    rpc_client::SerializableTransaction,
+    rpc_config::{RpcProgramAccountsConfig, RpcTransactionConfig},
+    rpc_request::TokenAccountsFilter,
+    rpc_response::{
+        RpcConfirmedTransactionStatusWithSignature, RpcKeyedAccount, RpcPrioritizationFee,
+        RpcSimulateTransactionResult,
+    },
+};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    address_lookup_table::{
+        self,
+        state::{AddressLookupTable, LookupTableMeta},
+    },
+    clock::{Epoch, Slot},
+    commitment_config::CommitmentConfig,
+    epoch_info::EpochInfo,
+    hash::Hash,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::Signature,
+    system_program,
+    transaction::TransactionError,
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+use std::{borrow::Cow, collections::HashMap, sync::Mutex};
+
+/// -- 每字节租金（与主网一致，用于模拟租金豁免计算）
+const MOCK_LAMPORTS_PER_BYTE: u64 = 6960;
+
+/// -- 账户元数据存储开销（与运行时一致）
+const ACCOUNT_STORAGE_OVERHEAD: u64 = 128;
+
+#[derive(Default)]
+struct MockState {
+    accounts: HashMap<Pubkey, Account>,
+    account_errors: HashMap<Pubkey, String>,
+    token_accounts: HashMap<Pubkey, Vec<RpcKeyedAccount>>,
+    transactions: HashMap<Signature, EncodedConfirmedTransactionWithStatusMeta>,
+    address_signatures: HashMap<Pubkey, Vec<RpcConfirmedTransactionStatusWithSignature>>,
+    sent: Vec<Signature>,
+    simulated: Vec<Signature>,
+    airdrops: Vec<(Pubkey, u64)>,
+    prioritization_fees: Vec<u64>,
+    fail_sends: usize,
+    expire_sends: usize,
+    fail_airdrops: usize,
+    fail_simulations: usize,
+    rate_limited: bool,
+    slot: Slot,
+    epoch: Epoch,
+}
+
+/// -- 内存中的 RPC 模拟实现
+///
+/// 账户、代币账户和交易由测试预先写入；发送的交易只记录签名，不执行指令。
+/// 空投立即计入余额。可通过 [`MockRpc::fail_next_sends`]、[`MockRpc::expire_next_sends`]、
+/// [`MockRpc::fail_next_airdrops`] 和 [`MockRpc::fail_next_simulations`] 模拟发送失败、区块哈希过期、
+/// 空投或模拟执行失败以验证重试逻辑，
+/// 通过 [`MockRpc::set_rate_limited`] 模拟节点限流以验证节点切换逻辑。
+#[derive(Default)]
+pub struct MockRpc {
+    state: Mutex<MockState>,
+}
+
+impl MockRpc {
+    /// -- 创建空的模拟 RPC
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// -- 写入账户
+    pub fn add_account(&self, pubkey: Pubkey, account: Account) {
+        self.state.lock().unwrap().accounts.insert(pubkey, account);
+    }
+
+    /// -- 使查询指定账户时返回错误，模拟单个账户的查询失败（如超时）
+    pub fn fail_account(&self, pubkey: Pubkey, message: &str) {
+        self.state
+            .lock()
+            .unwrap()
+            .account_errors
+            .insert(pubkey, message.to_string());
+    }
+
+    /// -- 设置 SOL 余额（创建或覆盖系统账户）
+    pub fn set_balance(&self, pubkey: Pubkey, lamports: u64) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .accounts
+            .entry(pubkey)
+            .or_insert_with(|| Account::new(0, 0, &system_program::id()))
+            .lamports = lamports;
+    }
+
+    /// -- 为钱包添加一个 jsonParsed 格式的代币账户
+    ///
+    /// # 参数
+    /// * `owner` - 钱包地址
+    /// * `address` - 代币账户地址
+    /// * `mint` - 代币 Mint 地址
+    /// * `amount` - 代币余额（最小单位）
+    /// * `lamports` - 账户租金
+    pub fn add_token_account(
+        &self,
+        owner: &Pubkey,
+        address: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+        lamports: u64,
+    ) {
+        self.add_token_account_with_program(
+            owner,
+            address,
+            mint,
+            amount,
+            lamports,
+            &spl_token::id(),
+        );
+    }
+
+    /// -- 为钱包添加一个属于指定代币程序（如 Token-2022）的 jsonParsed 格式代币账户
+    pub fn add_token_account_with_program(
+        &self,
+        owner: &Pubkey,
+        address: &Pubkey,
+        mint: &Pubkey,
+        amount: u64,
+        lamports: u64,
+        program_id: &Pubkey,
+    ) {
+        let program = if *program_id == spl_token::id() {
+            "spl-token"
+        } else {
+            "spl-token-2022"
+        };
+        let parsed = ParsedAccount {
+            program: program.to_string(),
+            parsed: json!({
+                "type": "account",
+                "info": {
+                    "mint": mint.to_string(),
+                    "owner": owner.to_string(),
+                    "state": "initialized",
+                    "tokenAmount": {
+                        "amount": amount.to_string(),
+                    },
+                },
+            }),
+            space: 165,
+        };
+        let account = RpcKeyedAccount {
+            pubkey: address.to_string(),
+            account: UiAccount {
+                lamports,
+                data: UiAccountData::Json(parsed),
+                owner: program_id.to_string(),
+                executable: false,
+                rent_epoch: 0,
+                space: Some(165),
+            },
+        };
+
+        self.state
+            .lock()
+            .unwrap()
+            .token_accounts
+            .entry(*owner)
+            .or_default()
+            .push(account);
+    }
+
+    /// -- 将已添加的 jsonParsed 代币账户标记为冻结
+    ///
+    /// # 参数
+    /// * `owner` - 钱包地址
+    /// * `address` - 代币账户地址
+    pub fn freeze_token_account(&self, owner: &Pubkey, address: &Pubkey) {
+        self.update_token_account_info(owner, address, |info| {
+            info["state"] = json!("frozen");
+        });
+    }
+
+    /// -- 为已添加的 jsonParsed 代币账户设置授权代理
+    ///
+    /// # 参数
+    /// * `owner` - 钱包地址
+    /// * `address` - 代币账户地址
+    /// * `delegate` - 代理地址
+    /// * `amount` - 授权数量（最小单位）
+    pub fn delegate_token_account(
+        &self,
+        owner: &Pubkey,
+        address: &Pubkey,
+        delegate: &Pubkey,
+        amount: u64,
+    ) {
+        self.update_token_account_info(owner, address, |info| {
+            info["delegate"] = json!(delegate.to_string());
+            info["delegatedAmount"] = json!({ "amount": amount.to_string() });
+        });
+    }
+
+    /// -- 为已添加的 jsonParsed 代币账户设置关闭权限
+    ///
+    /// # 参数
+    /// * `owner` - 钱包地址
+    /// * `address` - 代币账户地址
+    /// * `authority` - 关闭权限地址
+    pub fn set_close_authority(&self, owner: &Pubkey, address: &Pubkey, authority: &Pubkey) {
+        self.update_token_account_info(owner, address, |info| {
+            info["closeAuthority"] = json!(authority.to_string());
+        });
+    }
+
+    /// -- 为已添加的 jsonParsed 代币账户设置 `tokenAmount.decimals`
+    ///
+    /// # 参数
+    /// * `owner` - 钱包地址
+    /// * `address` - 代币账户地址
+    /// * `decimals` - 代币小数位数
+    pub fn set_token_account_decimals(&self, owner: &Pubkey, address: &Pubkey, decimals: u8) {
+        self.update_token_account_info(owner, address, |info| {
+            info["tokenAmount"]["decimals"] = json!(decimals);
+        });
+    }
+
+    /// -- 为已添加的 jsonParsed Token-2022 代币账户设置暂扣的转账手续费
+    ///
+    /// # 参数
+    /// * `owner` - 钱包地址
+    /// * `address` - 代币账户地址
+    /// * `withheld_amount` - 暂扣的手续费数量（最小单位）
+    pub fn set_withheld_amount(&self, owner: &Pubkey, address: &Pubkey, withheld_amount: u64) {
+        self.update_token_account_info(owner, address, |info| {
+            info["extensions"] = json!([{
+                "extension": "transferFeeAmount",
+                "state": { "withheldAmount": withheld_amount },
+            }]);
+        });
+    }
+
+    /// -- 修改已添加的 jsonParsed 代币账户的 `info` 字段
+    fn update_token_account_info(
+        &self,
+        owner: &Pubkey,
+        address: &Pubkey,
+        mut update: impl FnMut(&mut serde_json::Value),
+    ) {
+        let mut state = self.state.lock().unwrap();
+        let accounts = state.token_accounts.entry(*owner).or_default();
+        for account in accounts
+            .iter_mut()
+            .filter(|account| account.pubkey == address.to_string())
+        {
+            if let UiAccountData::Json(parsed) = &mut account.account.data {
+                update(&mut parsed.parsed["info"]);
+            }
+        }
+    }
+
+    /// -- 写入地址查找表账户
+    ///
+    /// # 参数
+    /// * `address` - 查找表地址
+    /// * `authority` - 查找表管理权限
+    /// * `addresses` - 查找表中的地址
+    pub fn add_lookup_table(&self, address: Pubkey, authority: &Pubkey, addresses: &[Pubkey]) {
+        let table = AddressLookupTable {
+            meta: LookupTableMeta {
+                authority: Some(*authority),
+                ..LookupTableMeta::default()
+            },
+            addresses: Cow::Borrowed(addresses),
+        };
+        let data = table.serialize_for_tests().expect("查找表序列化失败");
+        let mut account = Account::new(0, data.len(), &address_lookup_table::program::id());
+        account.data = data;
+        self.add_account(address, account);
+    }
+
+    /// -- 写入交易详情
+    pub fn add_transaction(
+        &self,
+        signature: Signature,
+        transaction: EncodedConfirmedTransactionWithStatusMeta,
+    ) {
+        self.state
+            .lock()
+            .unwrap()
+            .transactions
+            .insert(signature, transaction);
+    }
+
+    /// -- 为地址写入一条指定区块时间（Unix 时间戳，秒）的交易签名
+    ///
+    /// 按时间顺序写入，查询时最后写入的签名排在最前面。
+    pub fn add_address_signature(&self, address: Pubkey, block_time: i64) -> Signature {
+        let signature = Signature::new_unique();
+        let mut state = self.state.lock().unwrap();
+        let slot = state.slot;
+        state.address_signatures.entry(address).or_default().insert(
+            0,
+            RpcConfirmedTransactionStatusWithSignature {
+                signature: signature.to_string(),
+                slot,
+                err: None,
+                memo: None,
+                block_time: Some(block_time),
+                confirmation_status: None,
+            },
+        );
+        signature
+    }
+
+    /// -- 使接下来的 `count` 次发送失败
+    pub fn fail_next_sends(&self, count: usize) {
+        self.state.lock().unwrap().fail_sends = count;
+    }
+
+    /// -- 使接下来的 `count` 次发送返回区块哈希过期错误
+    pub fn expire_next_sends(&self, count: usize) {
+        self.state.lock().unwrap().expire_sends = count;
+    }
+
+    /// -- 已成功发送的交易签名
+    pub fn sent_transactions(&self) -> Vec<Signature> {
+        self.state.lock().unwrap().sent.clone()
+    }
+
+    /// -- 使接下来的 `count` 次模拟执行返回程序错误
+    pub fn fail_next_simulations(&self, count: usize) {
+        self.state.lock().unwrap().fail_simulations = count;
+    }
+
+    /// -- 已模拟执行的交易签名
+    pub fn simulated_transactions(&self) -> Vec<Signature> {
+        self.state.lock().unwrap().simulated.clone()
+    }
+
+    /// -- 使接下来的 `count` 次空投失败
+    pub fn fail_next_airdrops(&self, count: usize) {
+        self.state.lock().unwrap().fail_airdrops = count;
+    }
+
+    /// -- 已成功的空投（地址，lamports）
+    pub fn airdrops(&self) -> Vec<(Pubkey, u64)> {
+        self.state.lock().unwrap().airdrops.clone()
+    }
+
+    /// -- 设置最近区块的优先费（micro-lamports），按顺序对应连续的 slot
+    pub fn set_prioritization_fees(&self, fees: Vec<u64>) {
+        self.state.lock().unwrap().prioritization_fees = fees;
+    }
+
+    /// -- 设置当前 epoch，用于计算质押账户的激活状态
+    pub fn set_epoch(&self, epoch: Epoch) {
+        self.state.lock().unwrap().epoch = epoch;
+    }
+
+    /// -- 模拟节点限流：开启后查询、模拟和发送请求都返回 429 错误
+    pub fn set_rate_limited(&self, rate_limited: bool) {
+        self.state.lock().unwrap().rate_limited = rate_limited;
+    }
+
+    /// -- 节点限流时返回错误
+    fn check_rate_limit(&self) -> ClientResult<()> {
+        if self.state.lock().unwrap().rate_limited {
+            return Err(mock_error("429 Too Many Requests"));
+        }
+        Ok(())
+    }
+}
+
+/// -- 构造模拟错误
+fn mock_error(message: impl Into<String>) -> ClientError {
+    ClientErrorKind::Custom(message.into()).into()
+}
+
+impl RpcApi for MockRpc {
+    fn commitment(&self) -> CommitmentConfig {
+        CommitmentConfig::confirmed()
+    }
+
+    fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Account> {
+        self.check_rate_limit()?;
+        let state = self.state.lock().unwrap();
+        if let Some(message) = state.account_errors.get(pubkey) {
+            return Err(mock_error(message.clone()));
+        }
+        state
+            .accounts
+            .get(pubkey)
+            .cloned()
+            .ok_or_else(|| mock_error(format!("AccountNotFound: pubkey={}", pubkey)))
+    }
+
+    fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        let state = self.state.lock().unwrap();
+        Ok(pubkeys
+            .iter()
+            .map(|pubkey| state.accounts.get(pubkey).cloned())
+            .collect())
+    }
+
+    fn get_balance(&self, pubkey: &Pubkey) -> ClientResult<u64> {
+        self.check_rate_limit()?;
+        Ok(self
+            .state
+            .lock()
+            .unwrap()
+            .accounts
+            .get(pubkey)
+            .map(|account| account.lamports)
+            .unwrap_or(0))
+    }
+
+    fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
+        Ok((data_len as u64 + ACCOUNT_STORAGE_OVERHEAD) * MOCK_LAMPORTS_PER_BYTE)
+    }
+
+    fn get_program_accounts_with_config(
+        &self,
+        program_id: &Pubkey,
+        config: RpcProgramAccountsConfig,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.check_rate_limit()?;
+        let filters = config.filters.unwrap_or_default();
+        let state = self.state.lock().unwrap();
+        Ok(state
+            .accounts
+            .iter()
+            .filter(|(_, account)| account.owner == *program_id)
+            .filter(|(_, account)| {
+                let shared = AccountSharedData::from((*account).clone());
+                filters.iter().all(|filter| filter.allows(&shared))
+            })
+            .map(|(pubkey, account)| (*pubkey, account.clone()))
+            .collect())
+    }
+
+    fn get_token_accounts_by_owner(
+        &self,
+        owner: &Pubkey,
+        filter: TokenAccountsFilter,
+    ) -> ClientResult<Vec<RpcKeyedAccount>> {
+        self.check_rate_limit()?;
+        let state = self.state.lock().unwrap();
+        let accounts = state.token_accounts.get(owner).into_iter().flatten();
+        let matches = |account: &&RpcKeyedAccount| match &filter {
+            TokenAccountsFilter::ProgramId(program_id) => {
+                account.account.owner == program_id.to_string()
+            }
+            TokenAccountsFilter::Mint(mint) => match &account.account.data {
+                UiAccountData::Json(parsed) => {
+                    parsed.parsed["info"]["mint"].as_str() == Some(mint.to_string().as_str())
+                }
+                _ => false,
+            },
+        };
+
+        Ok(accounts.filter(matches).cloned().collect())
+    }
+
+    fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        self.check_rate_limit()?;
+        Ok(Hash::new_unique())
+    }
+
+    fn get_slot(&self) -> ClientResult<Slot> {
+        // -- 每次查询前进一个 slot，等待新区块的逻辑无需真实延时
+        let mut state = self.state.lock().unwrap();
+        state.slot += 1;
+        Ok(state.slot)
+    }
+
+    fn get_epoch_info(&self) -> ClientResult<EpochInfo> {
+        let state = self.state.lock().unwrap();
+        Ok(EpochInfo {
+            epoch: state.epoch,
+            slot_index: 0,
+            slots_in_epoch: 432_000,
+            absolute_slot: state.slot,
+            block_height: state.slot,
+            transaction_count: None,
+        })
+    }
+
+    fn send_and_confirm_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature> {
+        self.check_rate_limit()?;
+        let mut state = self.state.lock().unwrap();
+        if state.fail_sends > 0 {
+            state.fail_sends -= 1;
+            return Err(mock_error("模拟发送失败"));
+        }
+        if state.expire_sends > 0 {
+            state.expire_sends -= 1;
+            return Err(mock_error("Blockhash not found"));
+        }
+
+        let signature = *transaction.get_signature();
+        state.sent.push(signature);
+        Ok(signature)
+    }
+
+    fn send_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature> {
+        self.send_and_confirm_transaction(transaction)
+    }
+
+    fn simulate_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<RpcSimulateTransactionResult> {
+        self.check_rate_limit()?;
+        let mut state = self.state.lock().unwrap();
+        state.simulated.push(*transaction.get_signature());
+
+        let err = if state.fail_simulations > 0 {
+            state.fail_simulations -= 1;
+            Some(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(0),
+            ))
+        } else {
+            None
+        };
+
+        Ok(RpcSimulateTransactionResult {
+            err,
+            logs: Some(vec!["模拟执行".to_string()]),
+            accounts: None,
+            units_consumed: Some(0),
+            return_data: None,
+            inner_instructions: None,
+            replacement_blockhash: None,
+        })
+    }
+
+    fn get_transaction_with_config(
+        &self,
+        signature: &Signature,
+        _config: RpcTransactionConfig,
+    ) -> ClientResult<EncodedConfirmedTransactionWithStatusMeta> {
+        self.state
+            .lock()
+            .unwrap()
+            .transactions
+            .get(signature)
+            .cloned()
+            .ok_or_else(|| mock_error(format!("交易不存在: {}", signature)))
+    }
+
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        let mut state = self.state.lock().unwrap();
+        if state.fail_airdrops > 0 {
+            state.fail_airdrops -= 1;
+            return Err(mock_error("模拟空投失败"));
+        }
+
+        state
+            .accounts
+            .entry(*pubkey)
+            .or_insert_with(|| Account::new(0, 0, &system_program::id()))
+            .lamports += lamports;
+        state.airdrops.push((*pubkey, lamports));
+        Ok(Signature::new_unique())
+    }
+
+    fn confirm_transaction(&self, _signature: &Signature) -> ClientResult<bool> {
+        Ok(true)
+    }
+
+    fn get_recent_prioritization_fees(
+        &self,
+        _addresses: &[Pubkey],
+    ) -> ClientResult<Vec<RpcPrioritizationFee>> {
+        Ok(self
+            .state
+            .lock()
+            .unwrap()
+            .prioritization_fees
+            .iter()
+            .enumerate()
+            .map(|(slot, fee)| RpcPrioritizationFee {
+                slot: slot as u64,
+                prioritization_fee: *fee,
+            })
+            .collect())
+    }
+
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        self.check_rate_limit()?;
+        Ok(self
+            .state
+            .lock()
+            .unwrap()
+            .address_signatures
+            .get(address)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(feature = "audit")]
+use crate::audit;
+use solana_client::{
+    client_error::Result as ClientResult,
+    rpc_client::{RpcClient, SerializableTransaction},
+    rpc_config::{RpcProgramAccountsConfig, RpcTransactionConfig},
+    rpc_request::TokenAccountsFilter,
+    rpc_response::{
+        RpcConfirmedTransactionStatusWithSignature, RpcKeyedAccount, RpcPrioritizationFee,
+        RpcSimulateTransactionResult,
+    },
+};
+use solana_sdk::{
+    account::Account, clock::Slot, commitment_config::CommitmentConfig, epoch_info::EpochInfo,
+    hash::Hash, pubkey::Pubkey, signature::Signature,
+};
+use solana_transaction_status::EncodedConfirmedTransactionWithStatusMeta;
+
+/// -- RPC 接口抽象
+///
+/// 覆盖工作空间中使用到的 RPC 调用，方法签名与 `RpcClient` 保持一致，
+/// 业务逻辑依赖该 trait 即可在单元测试中替换为 `MockRpc`（`mock` 特性），无需访问网络。
+/// 启用 `audit` 特性时，`RpcClient` 的实现会把每笔发送的交易写入审计日志。
+pub trait RpcApi: Send + Sync {
+    /// 当前使用的提交级别
+    fn commitment(&self) -> CommitmentConfig;
+
+    /// 获取账户
+    fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Account>;
+
+    /// 获取账户数据
+    fn get_account_data(&self, pubkey: &Pubkey) -> ClientResult<Vec<u8>> {
+        self.get_account(pubkey).map(|account| account.data)
+    }
+
+    /// 批量获取账户
+    fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>>;
+
+    /// 获取 SOL 余额（lamports）
+    fn get_balance(&self, pubkey: &Pubkey) -> ClientResult<u64>;
+
+    /// 获取租金豁免所需的最低余额
+    fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64>;
+
+    /// 获取指定程序拥有的、满足全部过滤条件的账户
+    fn get_program_accounts_with_config(
+        &self,
+        program_id: &Pubkey,
+        config: RpcProgramAccountsConfig,
+    ) -> ClientResult<Vec<(Pubkey, Account)>>;
+
+    /// 获取钱包的代币账户（jsonParsed 编码）
+    fn get_token_accounts_by_owner(
+        &self,
+        owner: &Pubkey,
+        filter: TokenAccountsFilter,
+    ) -> ClientResult<Vec<RpcKeyedAccount>>;
+
+    /// 获取最新区块哈希
+    fn get_latest_blockhash(&self) -> ClientResult<Hash>;
+
+    /// 获取当前 slot
+    fn get_slot(&self) -> ClientResult<Slot>;
+
+    /// 获取当前 epoch 信息
+    fn get_epoch_info(&self) -> ClientResult<EpochInfo>;
+
+    /// 发送交易并等待确认
+    fn send_and_confirm_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature>;
+
+    /// 发送交易，不等待确认
+    fn send_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature>;
+
+    /// 模拟执行交易，不上链
+    fn simulate_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<RpcSimulateTransactionResult>;
+
+    /// 获取交易详情
+    fn get_transaction_with_config(
+        &self,
+        signature: &Signature,
+        config: RpcTransactionConfig,
+    ) -> ClientResult<EncodedConfirmedTransactionWithStatusMeta>;
+
+    /// 请求空投（仅 devnet、testnet 和本地验证器可用）
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature>;
+
+    /// 查询交易是否已达到当前提交级别
+    fn confirm_transaction(&self, signature: &Signature) -> ClientResult<bool>;
+
+    /// 获取最近区块中写入指定账户的交易所支付的优先费
+    fn get_recent_prioritization_fees(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<RpcPrioritizationFee>>;
+
+    /// 获取涉及指定地址的交易签名，按时间从新到旧排列，最多返回 1000 条
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>>;
+}
+
+impl RpcApi for RpcClient {
+    fn commitment(&self) -> CommitmentConfig {
+        RpcClient::commitment(self)
+    }
+
+    fn get_account(&self, pubkey: &Pubkey) -> ClientResult<Account> {
+        RpcClient::get_account(self, pubkey)
+    }
+
+    fn get_account_data(&self, pubkey: &Pubkey) -> ClientResult<Vec<u8>> {
+        RpcClient::get_account_data(self, pubkey)
+    }
+
+    fn get_multiple_accounts(&self, pubkeys: &[Pubkey]) -> ClientResult<Vec<Option<Account>>> {
+        RpcClient::get_multiple_accounts(self, pubkeys)
+    }
+
+    fn get_balance(&self, pubkey: &Pubkey) -> ClientResult<u64> {
+        RpcClient::get_balance(self, pubkey)
+    }
+
+    fn get_minimum_balance_for_rent_exemption(&self, data_len: usize) -> ClientResult<u64> {
+        RpcClient::get_minimum_balance_for_rent_exemption(self, data_len)
+    }
+
+    fn get_program_accounts_with_config(
+        &self,
+        program_id: &Pubkey,
+        config: RpcProgramAccountsConfig,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        RpcClient::get_program_accounts_with_config(self, program_id, config)
+    }
+
+    fn get_token_accounts_by_owner(
+        &self,
+        owner: &Pubkey,
+        filter: TokenAccountsFilter,
+    ) -> ClientResult<Vec<RpcKeyedAccount>> {
+        RpcClient::get_token_accounts_by_owner(self, owner, filter)
+    }
+
+    fn get_latest_blockhash(&self) -> ClientResult<Hash> {
+        RpcClient::get_latest_blockhash(self)
+    }
+
+    fn get_slot(&self) -> ClientResult<Slot> {
+        RpcClient::get_slot(self)
+    }
+
+    fn get_epoch_info(&self) -> ClientResult<EpochInfo> {
+        RpcClient::get_epoch_info(self)
+    }
+
+    fn send_and_confirm_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature> {
+        let result = RpcClient::send_and_confirm_transaction(self, transaction);
+        #[cfg(feature = "audit")]
+        audit::record_transaction(transaction, &result);
+        result
+    }
+
+    fn send_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<Signature> {
+        let result = RpcClient::send_transaction(self, transaction);
+        #[cfg(feature = "audit")]
+        audit::record_transaction(transaction, &result);
+        result
+    }
+
+    fn simulate_transaction(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> ClientResult<RpcSimulateTransactionResult> {
+        RpcClient::simulate_transaction(self, transaction).map(|response| response.value)
+    }
+
+    fn get_transaction_with_config(
+        &self,
+        signature: &Signature,
+        config: RpcTransactionConfig,
+    ) -> ClientResult<EncodedConfirmedTransactionWithStatusMeta> {
+        RpcClient::get_transaction_with_config(self, signature, config)
+    }
+
+    fn request_airdrop(&self, pubkey: &Pubkey, lamports: u64) -> ClientResult<Signature> {
+        RpcClient::request_airdrop(self, pubkey, lamports)
+    }
+
+    fn confirm_transaction(&self, signature: &Signature) -> ClientResult<bool> {
+        RpcClient::confirm_transaction(self, signature)
+    }
+
+    fn get_recent_prioritization_fees(
+        &self,
+        addresses: &[Pubkey],
+    ) -> ClientResult<Vec<RpcPrioritizationFee>> {
+        RpcClient::get_recent_prioritization_fees(self, addresses)
+    }
+
+    fn get_signatures_for_address(
+        &self,
+        address: &Pubkey,
+    ) -> ClientResult<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+        RpcClient::get_signatures_for_address(self, address)
+    }
+}
@@ -0,0 +1,190 @@
+use anyhow::{anyhow, bail, ensure, Result};
+use std::{fmt, str::FromStr};
+use time::{Duration, OffsetDateTime, Time};
+
+/// -- 查找下一次执行时间时最多检查的步数，防止无法满足的表达式（如 2 月 30 日）死循环
+const MAX_SEARCH_STEPS: usize = 100_000;
+
+/// -- 月份名称，下标 + 1 为月份
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// -- 星期名称，下标为距周日的天数
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// -- cron 表达式
+///
+/// 支持标准 5 段格式 `分 时 日 月 周`，每段可使用 `*`、列表 `1,15`、范围 `1-5`、
+/// 步长 `*/15` 以及月份和星期英文缩写；另支持 `@hourly`、`@daily`、`@weekly`、
+/// `@monthly`、`@yearly` 简写。与 cron 一致，日和周同时限定时满足其一即可。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,        // -- 原始表达式
+    minutes: u64,              // -- 分钟位图（0-59）
+    hours: u64,                // -- 小时位图（0-23）
+    days: u64,                 // -- 日期位图（1-31）
+    months: u64,               // -- 月份位图（1-12）
+    weekdays: u64,             // -- 星期位图（0-6，0 为周日）
+    days_restricted: bool,     // -- 日期段是否有限定（不以 `*` 开头）
+    weekdays_restricted: bool, // -- 星期段是否有限定（不以 `*` 开头）
+}
+
+impl CronSchedule {
+    /// -- 计算严格晚于 `after` 的下一次执行时间（精确到分钟，沿用 `after` 的时区）
+    ///
+    /// # 参数
+    /// * `after` - 起始时间
+    ///
+    /// # 返回
+    /// * `Option<OffsetDateTime>` - 下一次执行时间，表达式无法满足时返回 None
+    pub fn next_after(&self, after: OffsetDateTime) -> Option<OffsetDateTime> {
+        let start = after.replace_time(Time::from_hms(after.hour(), after.minute(), 0).ok()?);
+        let mut next = start + Duration::minutes(1);
+
+        for _ in 0..MAX_SEARCH_STEPS {
+            if !bit(self.months, u8::from(next.month()) as u32) || !self.matches_day(next) {
+                next = next.replace_time(Time::MIDNIGHT) + Duration::days(1);
+            } else if !bit(self.hours, next.hour() as u32) {
+                next =
+                    next.replace_time(Time::from_hms(next.hour(), 0, 0).ok()?) + Duration::hours(1);
+            } else if !bit(self.minutes, next.minute() as u32) {
+                next += Duration::minutes(1);
+            } else {
+                return Some(next);
+            }
+        }
+
+        None
+    }
+
+    /// -- 原始表达式
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// -- 判断日期是否匹配日和周两段
+    fn matches_day(&self, time: OffsetDateTime) -> bool {
+        let day = bit(self.days, time.day() as u32);
+        let weekday = bit(
+            self.weekdays,
+            time.weekday().number_days_from_sunday() as u32,
+        );
+
+        if self.days_restricted && self.weekdays_restricted {
+            day || weekday
+        } else {
+            day && weekday
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = anyhow::Error;
+
+    fn from_str(expression: &str) -> Result<Self> {
+        let expanded = match expression.trim().to_ascii_lowercase().as_str() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            _ => expression,
+        };
+
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        ensure!(
+            fields.len() == 5,
+            "cron 表达式需要 5 段（分 时 日 月 周）: {}",
+            expression
+        );
+
+        // -- 星期段允许 7 表示周日
+        let weekdays = parse_field(fields[4], 0, 7, &WEEKDAY_NAMES, 0)?;
+        let weekdays = (weekdays | (weekdays >> 7)) & 0x7f;
+
+        Ok(Self {
+            expression: expression.trim().to_string(),
+            minutes: parse_field(fields[0], 0, 59, &[], 0)?,
+            hours: parse_field(fields[1], 0, 23, &[], 0)?,
+            days: parse_field(fields[2], 1, 31, &[], 0)?,
+            months: parse_field(fields[3], 1, 12, &MONTH_NAMES, 1)?,
+            weekdays,
+            days_restricted: !fields[2].starts_with('*'),
+            weekdays_restricted: !fields[4].starts_with('*'),
+        })
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+/// -- 解析单个字段为位图
+///
+/// # 参数
+/// * `field` - 字段内容
+/// * `min` / `max` - 取值范围
+/// * `names` - 可用的英文缩写
+/// * `name_base` - 缩写下标对应的起始值
+fn parse_field(field: &str, min: u32, max: u32, names: &[&str], name_base: u32) -> Result<u64> {
+    let mut bits = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse().map_err(|_| anyhow!("无效的步长: {}", part))?;
+                ensure!(step > 0, "步长必须大于 0: {}", part);
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, names, name_base)?,
+                parse_value(end, names, name_base)?,
+            )
+        } else {
+            let start = parse_value(range, names, name_base)?;
+            // -- `5/10` 表示从 5 开始每 10 个单位
+            (start, if step > 1 { max } else { start })
+        };
+
+        ensure!(
+            min <= start && start <= end && end <= max,
+            "取值超出范围 {}-{}: {}",
+            min,
+            max,
+            part
+        );
+
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+
+    Ok(bits)
+}
+
+/// -- 解析数字或英文缩写
+fn parse_value(value: &str, names: &[&str], name_base: u32) -> Result<u32> {
+    if let Ok(number) = value.parse() {
+        return Ok(number);
+    }
+
+    let lower = value.to_ascii_lowercase();
+    match names.iter().position(|name| *name == lower) {
+        Some(index) => Ok(index as u32 + name_base),
+        None => bail!("无效的取值: {}", value),
+    }
+}
+
+/// -- 判断位图中是否包含指定值
+fn bit(bits: u64, value: u32) -> bool {
+    bits & (1 << value) != 0
+}
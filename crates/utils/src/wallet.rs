@@ -0,0 +1,66 @@
+use crate::{TokenAccountError, TokenAccountResult};
+use solana_sdk::{
+    bs58,
+    signature::{keypair_from_seed_phrase_and_passphrase, Keypair},
+};
+use std::{env, fs::read_to_string, path::Path};
+
+/// -- 覆盖钱包文件的私钥环境变量
+pub const WALLET_PRIVATE_KEY_ENV: &str = "WALLET_PRIVATE_KEY";
+
+/// -- 助记词允许的单词数量
+const SEED_PHRASE_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+/// -- 加载钱包密钥对
+///
+/// 设置了 `WALLET_PRIVATE_KEY` 时优先使用环境变量中的私钥，否则读取密钥文件。
+/// 两者都支持 [`parse_keypair`] 中列出的所有格式。
+///
+/// # 参数
+/// * `path` - 钱包密钥文件路径
+///
+/// # 返回
+/// * `TokenAccountResult<Keypair>` - 解析出的密钥对
+pub fn load_keypair(path: impl AsRef<Path>) -> TokenAccountResult<Keypair> {
+    match env::var(WALLET_PRIVATE_KEY_ENV) {
+        Ok(value) if !value.trim().is_empty() => parse_keypair(&value),
+        _ => parse_keypair(&read_to_string(path)?),
+    }
+}
+
+/// -- 解析密钥对，自动识别格式
+///
+/// 支持的格式：
+/// - `solana-keygen` 生成的 64 字节 JSON 数组
+/// - base58 编码的私钥，可以是裸字符串或 JSON 字符串
+/// - 助记词（12 到 24 个单词），与 `solana-keygen recover` 的默认推导方式一致，不使用口令
+///
+/// # 参数
+/// * `value` - 密钥文件内容或私钥字符串
+///
+/// # 返回
+/// * `TokenAccountResult<Keypair>` - 解析出的密钥对
+pub fn parse_keypair(value: &str) -> TokenAccountResult<Keypair> {
+    let value = value.trim();
+
+    if value.starts_with('[') {
+        let bytes: Vec<u8> = serde_json::from_str(value)?;
+        return Keypair::from_bytes(&bytes).map_err(|_| TokenAccountError::InvalidKeyFormat);
+    }
+
+    if value.starts_with('"') {
+        let inner: String = serde_json::from_str(value)?;
+        return parse_keypair(&inner);
+    }
+
+    let words: Vec<&str> = value.split_whitespace().collect();
+    if SEED_PHRASE_WORD_COUNTS.contains(&words.len()) {
+        return keypair_from_seed_phrase_and_passphrase(&words.join(" "), "")
+            .map_err(|_| TokenAccountError::InvalidKeyFormat);
+    }
+
+    let bytes = bs58::decode(value)
+        .into_vec()
+        .map_err(|_| TokenAccountError::InvalidKeyFormat)?;
+    Keypair::from_bytes(&bytes).map_err(|_| TokenAccountError::InvalidKeyFormat)
+}
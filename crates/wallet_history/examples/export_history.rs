@@ -0,0 +1,27 @@
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use std::{error::Error, str::FromStr};
+use utils::{init_rpc_client, init_tracing, load_env};
+use wallet_history::{export_csv, HistoryConfig, WalletHistory};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // -- 初始化日志和环境变量
+    init_tracing();
+    load_env()?;
+
+    let wallet = Pubkey::from_str(&std::env::var("WALLET_ADDRESS")?)?;
+    let rpc_client = init_rpc_client(CommitmentConfig::confirmed())?;
+
+    // -- 拉取最近 500 笔交易并导出 CSV
+    let history = WalletHistory::new(
+        &rpc_client,
+        HistoryConfig {
+            max_transactions: 500,
+            ..HistoryConfig::default()
+        },
+    );
+    let ledger = history.build_ledger(&wallet).await?;
+    export_csv(&ledger, "wallet_history.csv")?;
+
+    Ok(())
+}
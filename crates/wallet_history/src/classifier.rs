@@ -0,0 +1,165 @@
+use raydium_monitor::extract::extract_amm_events;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiInstruction, UiMessage,
+    UiParsedInstruction, UiTransactionTokenBalance,
+};
+use std::collections::BTreeMap;
+
+use crate::model::{EventKind, LedgerEntry, TokenDelta};
+
+/// -- Raydium AMM v4 程序 ID
+const RAYDIUM_AMM_V4: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+/// -- Jupiter v6 程序 ID
+const JUPITER_V6: &str = "JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4";
+
+/// -- 将交易分类为账本条目
+///
+/// 根据钱包的 SOL / 代币余额变化以及指令类型判断事件类型，
+/// 盈亏字段由 `PnlTracker` 在按时间排序后统一计算。
+///
+/// # 参数
+/// * `wallet` - 钱包地址
+/// * `signature` - 交易签名
+/// * `tx` - 交易详情（jsonParsed 编码）
+pub fn classify_transaction(
+    wallet: &str,
+    signature: &str,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Option<LedgerEntry> {
+    let meta = tx.transaction.meta.as_ref()?;
+    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
+        return None;
+    };
+    let UiMessage::Parsed(message) = &ui_tx.message else {
+        return None;
+    };
+
+    let wallet_index = message
+        .account_keys
+        .iter()
+        .position(|key| key.pubkey == wallet)?;
+    let is_fee_payer = wallet_index == 0;
+    let fee_lamports = if is_fee_payer { meta.fee } else { 0 };
+
+    // -- SOL 变化量（扣除手续费影响）
+    let pre = *meta.pre_balances.get(wallet_index)? as i128;
+    let post = *meta.post_balances.get(wallet_index)? as i128;
+    let sol_delta_lamports = post - pre + fee_lamports as i128;
+
+    let token_deltas = token_deltas(wallet, &meta.pre_token_balances, &meta.post_token_balances);
+
+    let program_ids: Vec<String> = message
+        .instructions
+        .iter()
+        .filter_map(|ix| match ix {
+            UiInstruction::Parsed(UiParsedInstruction::Parsed(p)) => Some(p.program_id.clone()),
+            UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(p)) => {
+                Some(p.program_id.clone())
+            }
+            UiInstruction::Compiled(_) => None,
+        })
+        .collect();
+    let parsed_types: Vec<String> = message
+        .instructions
+        .iter()
+        .filter_map(|ix| match ix {
+            UiInstruction::Parsed(UiParsedInstruction::Parsed(p)) => p
+                .parsed
+                .get("type")
+                .and_then(|t| t.as_str())
+                .map(str::to_string),
+            _ => None,
+        })
+        .collect();
+
+    // -- 交易场所：直接调用 Raydium 且能解码出 AMM v4 事件时记为 Raydium，
+    // 经由 Jupiter 路由的 Raydium 兑换记为 Jupiter
+    let venue = if program_ids.iter().any(|id| id == RAYDIUM_AMM_V4)
+        && !extract_amm_events(tx, signature).is_empty()
+    {
+        Some("Raydium AMM v4".to_string())
+    } else if program_ids.iter().any(|id| id == JUPITER_V6) {
+        Some("Jupiter v6".to_string())
+    } else {
+        None
+    };
+
+    let has_in = sol_delta_lamports > 0 || token_deltas.iter().any(|d| d.amount > 0);
+    let has_out = sol_delta_lamports < 0 || token_deltas.iter().any(|d| d.amount < 0);
+    let closes_account = parsed_types.iter().any(|t| t == "closeAccount");
+    let creates_account = parsed_types
+        .iter()
+        .any(|t| t == "initializeAccount" || t == "initializeAccount3" || t == "create")
+        && token_deltas.iter().all(|d| d.amount == 0);
+
+    let kind = if meta.err.is_some() {
+        EventKind::Failed
+    } else if venue.is_some() || (!token_deltas.is_empty() && has_in && has_out) {
+        EventKind::Swap
+    } else if closes_account && sol_delta_lamports > 0 {
+        EventKind::RentRefund
+    } else if creates_account && sol_delta_lamports < 0 {
+        EventKind::RentPaid
+    } else if has_in && !has_out {
+        EventKind::TransferIn
+    } else if has_out && !has_in {
+        EventKind::TransferOut
+    } else {
+        EventKind::Other
+    };
+
+    Some(LedgerEntry {
+        signature: signature.to_string(),
+        slot: tx.slot,
+        block_time: tx.block_time,
+        kind,
+        venue,
+        fee_lamports,
+        sol_delta: sol_delta_lamports as f64 / LAMPORTS_PER_SOL as f64,
+        token_deltas,
+        realized_pnl_sol: 0.0,
+        cumulative_pnl_sol: 0.0,
+    })
+}
+
+/// -- 计算钱包在各个 Mint 上的余额变化
+fn token_deltas(
+    wallet: &str,
+    pre: &OptionSerializer<Vec<UiTransactionTokenBalance>>,
+    post: &OptionSerializer<Vec<UiTransactionTokenBalance>>,
+) -> Vec<TokenDelta> {
+    // -- mint -> (变化量, 精度)
+    let mut deltas: BTreeMap<String, (i128, u8)> = BTreeMap::new();
+
+    let mut apply = |balances: &OptionSerializer<Vec<UiTransactionTokenBalance>>, sign: i128| {
+        if let OptionSerializer::Some(balances) = balances {
+            for balance in balances {
+                let owned =
+                    matches!(&balance.owner, OptionSerializer::Some(owner) if owner == wallet);
+                if !owned {
+                    continue;
+                }
+                let amount = balance.ui_token_amount.amount.parse::<i128>().unwrap_or(0);
+                let entry = deltas
+                    .entry(balance.mint.clone())
+                    .or_insert((0, balance.ui_token_amount.decimals));
+                entry.0 += sign * amount;
+            }
+        }
+    };
+    apply(pre, -1);
+    apply(post, 1);
+
+    deltas
+        .into_iter()
+        .filter(|(_, (amount, _))| *amount != 0)
+        .map(|(mint, (amount, decimals))| TokenDelta {
+            mint,
+            amount,
+            decimals,
+            ui_amount: amount as f64 / 10f64.powi(decimals as i32),
+        })
+        .collect()
+}
@@ -0,0 +1,82 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::model::LedgerEntry;
+
+/// -- CSV 表头
+const CSV_HEADER: &str = "timestamp,signature,slot,kind,venue,fee_sol,sol_delta,mint,token_delta,realized_pnl_sol,cumulative_pnl_sol";
+
+/// -- 导出账本为 CSV
+///
+/// 每个代币变化单独一行，方便在表格中按 Mint 汇总；没有代币变化的条目输出一行空 Mint。
+pub fn export_csv(entries: &[LedgerEntry], path: impl AsRef<Path>) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_csv(entries, &mut writer)?;
+    writer.flush()
+}
+
+/// -- 将账本以 CSV 写入任意输出
+pub fn write_csv<W: Write>(entries: &[LedgerEntry], writer: &mut W) -> io::Result<()> {
+    writeln!(writer, "{}", CSV_HEADER)?;
+
+    for entry in entries {
+        let fee_sol = entry.fee_lamports as f64 / 1e9;
+        let venue = entry.venue.as_deref().unwrap_or("");
+        let timestamp = entry.block_time.map(|t| t.to_string()).unwrap_or_default();
+
+        if entry.token_deltas.is_empty() {
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},,,{},{}",
+                timestamp,
+                entry.signature,
+                entry.slot,
+                entry.kind.as_str(),
+                escape(venue),
+                fee_sol,
+                entry.sol_delta,
+                entry.realized_pnl_sol,
+                entry.cumulative_pnl_sol
+            )?;
+            continue;
+        }
+
+        for (index, delta) in entry.token_deltas.iter().enumerate() {
+            // -- SOL、手续费和盈亏只记在第一行，避免汇总时重复计算
+            let (fee_sol, sol_delta, pnl) = if index == 0 {
+                (fee_sol, entry.sol_delta, entry.realized_pnl_sol)
+            } else {
+                (0.0, 0.0, 0.0)
+            };
+            writeln!(
+                writer,
+                "{},{},{},{},{},{},{},{},{},{},{}",
+                timestamp,
+                entry.signature,
+                entry.slot,
+                entry.kind.as_str(),
+                escape(venue),
+                fee_sol,
+                sol_delta,
+                delta.mint,
+                delta.ui_amount,
+                pnl,
+                entry.cumulative_pnl_sol
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// -- 转义 CSV 字段
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
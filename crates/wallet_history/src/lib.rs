@@ -0,0 +1,201 @@
+//! # 钱包交易历史与盈亏
+//!
+//! 分页拉取钱包的交易历史，复用 raydium_monitor 的解码逻辑对转账、兑换、租金事件分类，
+//! 生成按时间排序的账本并计算已实现盈亏，支持导出为 CSV 用于报税；
+//! 账本可增量同步到事件存储，之后从存储回放盈亏。
+use anyhow::Result;
+use event_store::EventStore;
+use solana_client::{
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_config::RpcTransactionConfig,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status::UiTransactionEncoding;
+use std::{collections::HashSet, str::FromStr, time::Duration};
+use tracing::{info, instrument, warn};
+
+pub mod classifier;
+pub mod export;
+pub mod model;
+pub mod pnl;
+
+pub use classifier::classify_transaction;
+pub use export::{export_csv, write_csv};
+pub use model::*;
+pub use pnl::PnlTracker;
+
+/// -- 每页签名数量（RPC 上限为 1000）
+const PAGE_SIZE: usize = 1000;
+
+/// -- 历史拉取配置
+#[derive(Debug, Clone)]
+pub struct HistoryConfig {
+    /// 最多拉取的交易数量
+    pub max_transactions: usize,
+    /// 只拉取该时间之后的交易（Unix 秒）
+    pub since: Option<i64>,
+    /// 每笔交易请求之间的间隔，避免触发限流
+    pub request_delay: Duration,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            max_transactions: 5000,
+            since: None,
+            request_delay: Duration::from_millis(100),
+        }
+    }
+}
+
+/// -- 钱包历史拉取器
+pub struct WalletHistory<'a> {
+    rpc_client: &'a RpcClient,
+    config: HistoryConfig,
+}
+
+impl<'a> WalletHistory<'a> {
+    pub fn new(rpc_client: &'a RpcClient, config: HistoryConfig) -> Self {
+        Self { rpc_client, config }
+    }
+
+    /// -- 分页拉取钱包的交易签名（从新到旧）
+    #[instrument(skip(self))]
+    pub fn fetch_signatures(&self, wallet: &Pubkey) -> Result<Vec<String>> {
+        let mut signatures = Vec::new();
+        let mut before: Option<Signature> = None;
+
+        loop {
+            let page = self.rpc_client.get_signatures_for_address_with_config(
+                wallet,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until: None,
+                    limit: Some(PAGE_SIZE),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )?;
+            let page_len = page.len();
+
+            for status in page {
+                if let (Some(since), Some(block_time)) = (self.config.since, status.block_time) {
+                    if block_time < since {
+                        return Ok(signatures);
+                    }
+                }
+                before = Some(Signature::from_str(&status.signature)?);
+                signatures.push(status.signature);
+                if signatures.len() >= self.config.max_transactions {
+                    return Ok(signatures);
+                }
+            }
+
+            info!("已拉取 {} 个签名", signatures.len());
+            if page_len < PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(signatures)
+    }
+
+    /// -- 生成钱包账本
+    ///
+    /// 拉取交易详情、分类并计算已实现盈亏，结果按时间从旧到新排序。
+    pub async fn build_ledger(&self, wallet: &Pubkey) -> Result<Vec<LedgerEntry>> {
+        let signatures = self.fetch_signatures(wallet)?;
+        let mut entries = self.fetch_entries(wallet, &signatures).await?;
+
+        let mut tracker = PnlTracker::new();
+        tracker.apply(&mut entries);
+        info!(
+            "账本生成完成: {} 条, 累计已实现盈亏: {} SOL",
+            entries.len(),
+            tracker.cumulative()
+        );
+
+        Ok(entries)
+    }
+
+    /// -- 增量同步钱包账本到事件存储，并回放全部历史计算盈亏
+    ///
+    /// 只拉取存储中还没有的交易，新条目按时间从旧到新追加，
+    /// 重复运行时不会重复请求已处理的交易。
+    ///
+    /// # 参数
+    /// * `wallet` - 钱包地址
+    /// * `store` - 账本事件存储
+    ///
+    /// # 返回
+    /// * `Result<Vec<LedgerEntry>>` - 存储中的完整账本（已填充盈亏字段）
+    pub async fn sync_ledger(
+        &self,
+        wallet: &Pubkey,
+        store: &EventStore<LedgerEntry>,
+    ) -> Result<Vec<LedgerEntry>> {
+        let known: HashSet<String> = store
+            .replay()?
+            .into_iter()
+            .map(|stored| stored.event.signature)
+            .collect();
+        let signatures: Vec<String> = self
+            .fetch_signatures(wallet)?
+            .into_iter()
+            .filter(|signature| !known.contains(signature))
+            .collect();
+        info!(
+            "存储中已有 {} 笔交易, 新增 {} 笔",
+            known.len(),
+            signatures.len()
+        );
+
+        let mut entries = self.fetch_entries(wallet, &signatures).await?;
+        entries.sort_by_key(|e| (e.block_time.unwrap_or(0), e.slot));
+        for entry in entries {
+            store.append(entry)?;
+        }
+
+        let (tracker, entries) = PnlTracker::replay(store)?;
+        info!(
+            "账本同步完成: {} 条, 累计已实现盈亏: {} SOL",
+            entries.len(),
+            tracker.cumulative()
+        );
+
+        Ok(entries)
+    }
+
+    /// -- 拉取交易详情并分类
+    async fn fetch_entries(
+        &self,
+        wallet: &Pubkey,
+        signatures: &[String],
+    ) -> Result<Vec<LedgerEntry>> {
+        let wallet_str = wallet.to_string();
+        let config = RpcTransactionConfig {
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+            encoding: Some(UiTransactionEncoding::JsonParsed),
+        };
+
+        let mut entries = Vec::with_capacity(signatures.len());
+        for (index, signature) in signatures.iter().enumerate() {
+            let sig = Signature::from_str(signature)?;
+            match self.rpc_client.get_transaction_with_config(&sig, config) {
+                Ok(tx) => {
+                    if let Some(entry) = classify_transaction(&wallet_str, signature, &tx) {
+                        entries.push(entry);
+                    }
+                }
+                Err(e) => warn!("获取交易失败: {}, {}", signature, e),
+            }
+
+            if (index + 1) % 100 == 0 {
+                info!("已处理 {}/{} 笔交易", index + 1, signatures.len());
+            }
+            tokio::time::sleep(self.config.request_delay).await;
+        }
+
+        Ok(entries)
+    }
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+/// -- 交易事件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+    TransferIn,  // -- 转入
+    TransferOut, // -- 转出
+    Swap,        // -- 兑换
+    RentPaid,    // -- 创建账户支付租金
+    RentRefund,  // -- 关闭账户回收租金
+    Failed,      // -- 失败交易（只扣除手续费）
+    Other,       // -- 其他
+}
+
+impl EventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::TransferIn => "transfer_in",
+            EventKind::TransferOut => "transfer_out",
+            EventKind::Swap => "swap",
+            EventKind::RentPaid => "rent_paid",
+            EventKind::RentRefund => "rent_refund",
+            EventKind::Failed => "failed",
+            EventKind::Other => "other",
+        }
+    }
+}
+
+/// -- 单个代币的余额变化
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenDelta {
+    pub mint: String,   // -- 代币的 Mint 地址
+    pub amount: i128,   // -- 变化量（最小单位）
+    pub decimals: u8,   // -- 精度
+    pub ui_amount: f64, // -- 变化量（按精度换算）
+}
+
+/// -- 账本条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub signature: String,             // -- 交易签名
+    pub slot: u64,                     // -- 所在 slot
+    pub block_time: Option<i64>,       // -- 区块时间（Unix 秒）
+    pub kind: EventKind,               // -- 事件类型
+    pub venue: Option<String>,         // -- 交易场所（如 Raydium AMM v4）
+    pub fee_lamports: u64,             // -- 钱包支付的手续费
+    pub sol_delta: f64,                // -- SOL 变化量（不含手续费）
+    pub token_deltas: Vec<TokenDelta>, // -- 代币变化量
+    pub realized_pnl_sol: f64,         // -- 本条目实现的盈亏（SOL）
+    pub cumulative_pnl_sol: f64,       // -- 累计实现盈亏（SOL）
+}
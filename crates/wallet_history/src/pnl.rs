@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use event_store::{EventStore, StoreResult};
+
+use crate::model::{EventKind, LedgerEntry};
+
+/// -- 单个代币的持仓成本
+#[derive(Debug, Clone, Default)]
+pub struct Position {
+    pub quantity: f64, // -- 持仓数量
+    pub cost_sol: f64, // -- 总成本（SOL）
+}
+
+impl Position {
+    /// -- 平均成本（SOL / 单位代币）
+    pub fn average_cost(&self) -> f64 {
+        if self.quantity > 0.0 {
+            self.cost_sol / self.quantity
+        } else {
+            0.0
+        }
+    }
+}
+
+/// -- 已实现盈亏计算器
+///
+/// 以 SOL 计价、平均成本法计算：
+/// - 用 SOL 买入代币时累加成本
+/// - 卖出代币换回 SOL 时按平均成本结转盈亏
+/// - 代币之间的兑换和转账不产生盈亏，转入的代币成本记为 0
+#[derive(Debug, Default)]
+pub struct PnlTracker {
+    positions: HashMap<String, Position>,
+    cumulative: f64,
+}
+
+impl PnlTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// -- 按时间顺序处理账本，填充盈亏字段
+    pub fn apply(&mut self, entries: &mut [LedgerEntry]) {
+        entries.sort_by_key(|e| (e.block_time.unwrap_or(0), e.slot));
+
+        for entry in entries.iter_mut() {
+            let realized = match entry.kind {
+                EventKind::Swap => self.apply_swap(entry),
+                EventKind::TransferIn => {
+                    for delta in &entry.token_deltas {
+                        self.positions
+                            .entry(delta.mint.clone())
+                            .or_default()
+                            .quantity += delta.ui_amount;
+                    }
+                    0.0
+                }
+                EventKind::TransferOut => {
+                    for delta in &entry.token_deltas {
+                        self.reduce(&delta.mint, -delta.ui_amount);
+                    }
+                    0.0
+                }
+                _ => 0.0,
+            };
+
+            self.cumulative += realized;
+            entry.realized_pnl_sol = realized;
+            entry.cumulative_pnl_sol = self.cumulative;
+        }
+    }
+
+    /// -- 从事件存储回放账本，重新计算持仓和盈亏
+    ///
+    /// # 返回
+    /// * `StoreResult<(Self, Vec<LedgerEntry>)>` - 回放后的计算器和按时间排序的账本
+    pub fn replay(store: &EventStore<LedgerEntry>) -> StoreResult<(Self, Vec<LedgerEntry>)> {
+        let mut entries: Vec<LedgerEntry> = store
+            .replay()?
+            .into_iter()
+            .map(|stored| stored.event)
+            .collect();
+        let mut tracker = Self::new();
+        tracker.apply(&mut entries);
+
+        Ok((tracker, entries))
+    }
+
+    /// -- 当前持仓
+    pub fn positions(&self) -> &HashMap<String, Position> {
+        &self.positions
+    }
+
+    /// -- 累计已实现盈亏
+    pub fn cumulative(&self) -> f64 {
+        self.cumulative
+    }
+
+    fn apply_swap(&mut self, entry: &LedgerEntry) -> f64 {
+        let bought: Vec<_> = entry.token_deltas.iter().filter(|d| d.amount > 0).collect();
+        let sold: Vec<_> = entry.token_deltas.iter().filter(|d| d.amount < 0).collect();
+        let mut realized = 0.0;
+
+        // -- SOL -> 代币：记录成本
+        if entry.sol_delta < 0.0 && bought.len() == 1 && sold.is_empty() {
+            let position = self.positions.entry(bought[0].mint.clone()).or_default();
+            position.quantity += bought[0].ui_amount;
+            position.cost_sol += -entry.sol_delta;
+            return 0.0;
+        }
+
+        // -- 代币 -> SOL：结转盈亏
+        if entry.sol_delta > 0.0 && sold.len() == 1 && bought.is_empty() {
+            let quantity = -sold[0].ui_amount;
+            let cost = self.reduce(&sold[0].mint, quantity);
+            realized = entry.sol_delta - cost;
+            return realized;
+        }
+
+        // -- 代币之间兑换：成本按数量比例转移
+        if sold.len() == 1 && bought.len() == 1 {
+            let cost = self.reduce(&sold[0].mint, -sold[0].ui_amount);
+            let position = self.positions.entry(bought[0].mint.clone()).or_default();
+            position.quantity += bought[0].ui_amount;
+            position.cost_sol += cost;
+        }
+
+        realized
+    }
+
+    /// -- 减少持仓，返回对应的成本
+    fn reduce(&mut self, mint: &str, quantity: f64) -> f64 {
+        let Some(position) = self.positions.get_mut(mint) else {
+            return 0.0;
+        };
+        let quantity = quantity.min(position.quantity);
+        let cost = position.average_cost() * quantity;
+        position.quantity -= quantity;
+        position.cost_sol -= cost;
+        cost
+    }
+}